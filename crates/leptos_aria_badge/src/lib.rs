@@ -0,0 +1,3 @@
+pub use use_badge::*;
+
+mod use_badge;
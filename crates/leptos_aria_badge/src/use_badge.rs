@@ -0,0 +1,94 @@
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::FocusEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::Politeness;
+
+#[derive(TypedBuilder)]
+pub struct UseBadgeProps {
+  /// The status described by the badge, e.g. `"online"` or `"3 unread"`.
+  #[builder(setter(into))]
+  pub status: MaybeSignal<String>,
+
+  /// The id of the badge element. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+}
+
+/// The props for the element described by the badge, e.g. an avatar or a
+/// button.
+#[derive(Clone)]
+pub struct BadgeHostResult {
+  pub aria_describedby: String,
+  pub on_focus: BadgeCallback<FocusEvent>,
+  pub on_blur: BadgeCallback<FocusEvent>,
+}
+
+#[derive(Clone)]
+pub struct BadgeResult {
+  /// The id of the badge element.
+  pub id: String,
+  /// The text content of the badge.
+  pub text: Signal<String>,
+  /// The props for the host element.
+  pub host: BadgeHostResult,
+}
+
+/// Provides the accessibility implementation for a status badge such as the
+/// presence indicator of an avatar or the unread count of a button.
+///
+/// The badge describes its host through `aria-describedby`, so the status is
+/// read when the host is focused. When the status changes while the host is
+/// focused the new status is announced politely since screen readers don't
+/// re-read descriptions.
+pub fn use_badge(cx: Scope, props: UseBadgeProps) -> ReadSignal<BadgeResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let original_status = props.status;
+  let text = (move || original_status.get()).derive_signal(cx);
+  let is_host_focused = create_rw_signal(cx, false);
+
+  create_effect(cx, move |previous: Option<String>| {
+    let status = text.get();
+
+    if previous
+      .as_ref()
+      .map_or(false, |previous| previous != &status)
+      && is_host_focused.get_untracked()
+    {
+      announce(status.clone(), Politeness::Polite);
+    }
+
+    status
+  });
+
+  let on_focus: BadgeCallback<FocusEvent> = Rc::new(Box::new(move |_| is_host_focused.set(true)));
+  let on_blur: BadgeCallback<FocusEvent> = Rc::new(Box::new(move |_| is_host_focused.set(false)));
+
+  let (badge_result, _) = create_signal(
+    cx,
+    BadgeResult {
+      id: id.clone(),
+      text,
+      host: BadgeHostResult {
+        aria_describedby: id,
+        on_focus,
+        on_blur,
+      },
+    },
+  );
+
+  badge_result
+}
+
+pub(crate) type BadgeCallback<E> = Rc<Box<dyn Fn(E)>>;
@@ -0,0 +1,74 @@
+/// Which breadcrumbs are rendered inline and which are collapsed into a menu
+/// when there are more items than fit.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BreadcrumbsLayout {
+  /// The indexes of the items rendered before the menu. This is the root item
+  /// when items are collapsed.
+  pub leading: Vec<usize>,
+  /// The indexes of the items in the collapse menu.
+  pub collapsed: Vec<usize>,
+  /// The indexes of the items rendered after the menu.
+  pub trailing: Vec<usize>,
+}
+
+impl BreadcrumbsLayout {
+  /// Collapse the items so that at most `max_visible_items` are rendered
+  /// inline. The root and the last items, including the current page, stay
+  /// visible and everything in between is moved into the menu. The menu
+  /// counts as one of the visible items.
+  pub fn new(item_count: usize, max_visible_items: usize) -> Self {
+    // Keep at least the root, the menu and the current page.
+    let max_visible_items = max_visible_items.max(3);
+
+    if item_count <= max_visible_items {
+      return Self {
+        leading: (0..item_count).collect(),
+        ..Self::default()
+      };
+    }
+
+    let trailing_count = max_visible_items - 2;
+
+    Self {
+      leading: vec![0],
+      collapsed: (1..item_count - trailing_count).collect(),
+      trailing: (item_count - trailing_count..item_count).collect(),
+    }
+  }
+
+  /// Whether any items are collapsed into the menu.
+  pub fn is_collapsed(&self) -> bool {
+    !self.collapsed.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn all_items_fit() {
+    let layout = BreadcrumbsLayout::new(4, 4);
+
+    assert_eq!(layout.leading, vec![0, 1, 2, 3]);
+    assert!(!layout.is_collapsed());
+  }
+
+  #[test]
+  fn collapses_the_middle_items() {
+    let layout = BreadcrumbsLayout::new(7, 4);
+
+    assert_eq!(layout.leading, vec![0]);
+    assert_eq!(layout.collapsed, vec![1, 2, 3, 4]);
+    assert_eq!(layout.trailing, vec![5, 6]);
+  }
+
+  #[test]
+  fn keeps_the_root_and_current_page() {
+    let layout = BreadcrumbsLayout::new(5, 1);
+
+    assert_eq!(layout.leading, vec![0]);
+    assert_eq!(layout.collapsed, vec![1, 2, 3]);
+    assert_eq!(layout.trailing, vec![4]);
+  }
+}
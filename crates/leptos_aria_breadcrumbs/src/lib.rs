@@ -0,0 +1,7 @@
+pub use breadcrumbs_layout::*;
+pub use use_breadcrumb_item::*;
+pub use use_breadcrumbs::*;
+
+mod breadcrumbs_layout;
+mod use_breadcrumb_item;
+mod use_breadcrumbs;
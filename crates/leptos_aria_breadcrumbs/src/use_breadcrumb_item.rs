@@ -0,0 +1,86 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+
+/// How a breadcrumb is rendered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum BreadcrumbElementType {
+  /// An `<a>` element linking to the page.
+  #[default]
+  Link,
+  /// A heading for the current page, e.g. when the breadcrumbs double as the
+  /// page title.
+  Heading,
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseBreadcrumbItemProps {
+  /// The url of the page.
+  #[builder(default, setter(strip_option, into))]
+  pub href: Option<MaybeSignal<String>>,
+
+  /// Whether the item represents the current page. This is usually the last
+  /// item.
+  #[builder(default, setter(strip_option, into))]
+  pub is_current: Option<MaybeSignal<bool>>,
+
+  /// Whether the item is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// How the item is rendered.
+  #[builder(default)]
+  pub element_type: BreadcrumbElementType,
+}
+
+#[derive(Clone)]
+pub struct BreadcrumbItemResult {
+  pub element_type: BreadcrumbElementType,
+  /// The link is removed for the current page and disabled items so that they
+  /// aren't activated.
+  pub href: Signal<Option<String>>,
+  pub aria_current: Signal<Option<&'static str>>,
+  pub aria_disabled: Signal<Option<bool>>,
+}
+
+/// Provides the accessibility implementation for an item in
+/// [`use_breadcrumbs`](crate::use_breadcrumbs).
+///
+/// The current page is marked with `aria-current="page"`. Rendering it as a
+/// heading keeps it in the heading outline while it is still announced as the
+/// current breadcrumb.
+pub fn use_breadcrumb_item(
+  cx: Scope,
+  props: UseBreadcrumbItemProps,
+) -> ReadSignal<BreadcrumbItemResult> {
+  let original_is_current = props.is_current.unwrap_or(false.into());
+  let is_current = (move || original_is_current.get()).derive_signal(cx);
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let element_type = props.element_type;
+  let original_href = props.href;
+  let href = (move || {
+    if element_type == BreadcrumbElementType::Heading || is_current.get() || is_disabled.get() {
+      return None;
+    }
+
+    original_href.as_ref().map(|href| href.get())
+  })
+  .derive_signal(cx);
+
+  let (breadcrumb_item_result, _) = create_signal(
+    cx,
+    BreadcrumbItemResult {
+      element_type,
+      href,
+      aria_current: (move || is_current.get().then_some("page")).derive_signal(cx),
+      aria_disabled: (move || is_disabled.get().then_some(true)).derive_signal(cx),
+    },
+  );
+
+  breadcrumb_item_result
+}
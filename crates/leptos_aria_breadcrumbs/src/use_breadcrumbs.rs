@@ -0,0 +1,95 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+
+use crate::BreadcrumbsLayout;
+
+/// The default label of the breadcrumbs navigation landmark.
+pub const BREADCRUMBS_LABEL: &str = "Breadcrumbs";
+
+/// The default label of the menu containing the collapsed breadcrumbs.
+pub const BREADCRUMBS_MENU_LABEL: &str = "More breadcrumbs";
+
+#[derive(TypedBuilder)]
+pub struct UseBreadcrumbsProps {
+  /// The number of breadcrumbs.
+  #[builder(setter(into))]
+  pub item_count: MaybeSignal<usize>,
+
+  /// The maximum number of items rendered inline, including the collapse menu.
+  /// All items are rendered when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub max_visible_items: Option<MaybeSignal<usize>>,
+
+  /// The localized label of the navigation landmark. Defaults to
+  /// [`BREADCRUMBS_LABEL`].
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The localized label of the collapse menu trigger. Defaults to
+  /// [`BREADCRUMBS_MENU_LABEL`].
+  #[builder(default, setter(strip_option, into))]
+  pub menu_aria_label: Option<MaybeSignal<String>>,
+}
+
+/// The props for the button which opens the menu of collapsed breadcrumbs.
+#[derive(Clone)]
+pub struct BreadcrumbsMenuTriggerResult {
+  pub aria_label: Signal<String>,
+  pub aria_haspopup: &'static str,
+}
+
+#[derive(Clone)]
+pub struct BreadcrumbsResult {
+  /// Render the breadcrumbs in a `<nav>` element with an `<ol>` list.
+  pub element_type: &'static str,
+  pub aria_label: Signal<String>,
+  /// Which items are rendered inline and which are collapsed into the menu.
+  pub layout: Signal<BreadcrumbsLayout>,
+  pub menu_trigger: BreadcrumbsMenuTriggerResult,
+}
+
+/// Provides the accessibility implementation for a breadcrumbs navigation
+/// landmark. Use [`use_breadcrumb_item`](crate::use_breadcrumb_item) for each
+/// item.
+///
+/// When `max_visible_items` is set the items between the root and the last
+/// items are collapsed into a menu once there are too many to fit.
+pub fn use_breadcrumbs(cx: Scope, props: UseBreadcrumbsProps) -> ReadSignal<BreadcrumbsResult> {
+  let original_aria_label = props
+    .aria_label
+    .unwrap_or_else(|| BREADCRUMBS_LABEL.to_string().into());
+  let original_menu_aria_label = props
+    .menu_aria_label
+    .unwrap_or_else(|| BREADCRUMBS_MENU_LABEL.to_string().into());
+  let item_count = props.item_count;
+  let max_visible_items = props.max_visible_items;
+  let layout = (move || {
+    let item_count = item_count.get();
+    let max_visible_items = max_visible_items
+      .as_ref()
+      .map_or(item_count, |max_visible_items| max_visible_items.get());
+
+    BreadcrumbsLayout::new(item_count, max_visible_items)
+  })
+  .derive_signal(cx);
+
+  let (breadcrumbs_result, _) = create_signal(
+    cx,
+    BreadcrumbsResult {
+      element_type: "nav",
+      aria_label: (move || original_aria_label.get()).derive_signal(cx),
+      layout,
+      menu_trigger: BreadcrumbsMenuTriggerResult {
+        aria_label: (move || original_menu_aria_label.get()).derive_signal(cx),
+        aria_haspopup: "menu",
+      },
+    },
+  );
+
+  breadcrumbs_result
+}
@@ -0,0 +1,464 @@
+use std::fmt;
+
+use leptos::js_sys::Date;
+use leptos_aria_i18n::FormattableDate;
+use leptos_aria_utils::is_server;
+
+/// The regions where the week doesn't start on Sunday, with the day it starts
+/// on. Sorted by region for the binary search. Sourced from the CLDR week
+/// data.
+const WEEK_START_BY_REGION: &[(&str, u32)] = &[
+  ("001", 1),
+  ("AD", 1),
+  ("AE", 6),
+  ("AF", 6),
+  ("AI", 1),
+  ("AL", 1),
+  ("AM", 1),
+  ("AN", 1),
+  ("AR", 1),
+  ("AT", 1),
+  ("AU", 1),
+  ("AX", 1),
+  ("AZ", 1),
+  ("BA", 1),
+  ("BE", 1),
+  ("BG", 1),
+  ("BH", 6),
+  ("BM", 1),
+  ("BN", 1),
+  ("BY", 1),
+  ("CH", 1),
+  ("CL", 1),
+  ("CM", 1),
+  ("CN", 1),
+  ("CR", 1),
+  ("CY", 1),
+  ("CZ", 1),
+  ("DE", 1),
+  ("DJ", 6),
+  ("DK", 1),
+  ("DZ", 6),
+  ("EC", 1),
+  ("EE", 1),
+  ("EG", 6),
+  ("ES", 1),
+  ("FI", 1),
+  ("FJ", 1),
+  ("FO", 1),
+  ("FR", 1),
+  ("GB", 1),
+  ("GE", 1),
+  ("GF", 1),
+  ("GP", 1),
+  ("GR", 1),
+  ("HR", 1),
+  ("HU", 1),
+  ("IE", 1),
+  ("IQ", 6),
+  ("IR", 6),
+  ("IS", 1),
+  ("IT", 1),
+  ("JO", 6),
+  ("KG", 1),
+  ("KW", 6),
+  ("KZ", 1),
+  ("LB", 1),
+  ("LI", 1),
+  ("LK", 1),
+  ("LT", 1),
+  ("LU", 1),
+  ("LV", 1),
+  ("LY", 6),
+  ("MC", 1),
+  ("MD", 1),
+  ("ME", 1),
+  ("MK", 1),
+  ("MN", 1),
+  ("MQ", 1),
+  ("MV", 5),
+  ("MY", 1),
+  ("NL", 1),
+  ("NO", 1),
+  ("NZ", 1),
+  ("OM", 6),
+  ("PL", 1),
+  ("QA", 6),
+  ("RE", 1),
+  ("RO", 1),
+  ("RS", 1),
+  ("RU", 1),
+  ("SD", 6),
+  ("SE", 1),
+  ("SI", 1),
+  ("SK", 1),
+  ("SM", 1),
+  ("SY", 6),
+  ("TJ", 1),
+  ("TM", 1),
+  ("TR", 1),
+  ("UA", 1),
+  ("UY", 1),
+  ("UZ", 1),
+  ("VA", 1),
+  ("VN", 1),
+  ("XK", 1),
+];
+
+/// The region assumed for locales which only include a language, e.g. `de`.
+const DEFAULT_REGION_BY_LANGUAGE: &[(&str, &str)] = &[
+  ("ar", "EG"),
+  ("cs", "CZ"),
+  ("da", "DK"),
+  ("de", "DE"),
+  ("el", "GR"),
+  ("es", "ES"),
+  ("fa", "IR"),
+  ("fi", "FI"),
+  ("fr", "FR"),
+  ("hr", "HR"),
+  ("hu", "HU"),
+  ("it", "IT"),
+  ("nb", "NO"),
+  ("nl", "NL"),
+  ("pl", "PL"),
+  ("ro", "RO"),
+  ("ru", "RU"),
+  ("sk", "SK"),
+  ("sl", "SI"),
+  ("sv", "SE"),
+  ("tr", "TR"),
+  ("uk", "UA"),
+  ("zh", "CN"),
+];
+
+/// A date in the Gregorian calendar without a time or time zone.
+///
+/// Dates are ordered chronologically and displayed in ISO 8601 format, e.g.
+/// `2024-03-04`, which is also the format used for form values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CalendarDate {
+  pub year: i32,
+  /// The month from `1` to `12`.
+  pub month: u32,
+  /// The day of the month starting at `1`.
+  pub day: u32,
+}
+
+impl CalendarDate {
+  /// Create a date. Out of range months and days are clamped, so
+  /// `CalendarDate::new(2023, 2, 31)` is the 28th of February.
+  pub fn new(year: i32, month: u32, day: u32) -> Self {
+    let month = month.clamp(1, 12);
+    let day = day.clamp(1, days_in_month(year, month));
+
+    Self { year, month, day }
+  }
+
+  /// The current date in the local time zone.
+  pub fn today() -> Self {
+    if is_server() {
+      let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+
+      return Self::from_days((seconds / 86_400) as i64);
+    }
+
+    let now = leptos::js_sys::Date::new_0();
+    Self::new(
+      now.get_full_year() as i32,
+      now.get_month() + 1,
+      now.get_date(),
+    )
+  }
+
+  /// Parse an ISO 8601 date such as `2024-03-04`.
+  pub fn parse(value: &str) -> Option<Self> {
+    let mut parts = value.trim().splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+      return None;
+    }
+
+    Some(Self { year, month, day })
+  }
+
+  /// The number of days since `1970-01-01`.
+  pub fn to_days(&self) -> i64 {
+    let month = i64::from(self.month);
+    let year = i64::from(self.year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year =
+      (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+  }
+
+  /// The date which is `days` days after `1970-01-01`.
+  pub fn from_days(days: i64) -> Self {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+      (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+      month_index + 3
+    } else {
+      month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    Self {
+      year: year as i32,
+      month: month as u32,
+      day: day as u32,
+    }
+  }
+
+  pub fn add_days(&self, days: i64) -> Self {
+    Self::from_days(self.to_days() + days)
+  }
+
+  pub fn add_weeks(&self, weeks: i64) -> Self {
+    self.add_days(weeks * 7)
+  }
+
+  /// Move by whole months, keeping the day where possible. Moving from the
+  /// 31st of January by one month gives the last day of February.
+  pub fn add_months(&self, months: i32) -> Self {
+    let index = self.year * 12 + self.month as i32 - 1 + months;
+    Self::new(
+      index.div_euclid(12),
+      index.rem_euclid(12) as u32 + 1,
+      self.day,
+    )
+  }
+
+  pub fn add_years(&self, years: i32) -> Self {
+    Self::new(self.year + years, self.month, self.day)
+  }
+
+  /// The day of the week from `0` for Sunday to `6` for Saturday.
+  pub fn day_of_week(&self) -> u32 {
+    // The 1st of January 1970 was a Thursday.
+    (self.to_days() + 4).rem_euclid(7) as u32
+  }
+
+  pub fn start_of_month(&self) -> Self {
+    Self { day: 1, ..*self }
+  }
+
+  pub fn end_of_month(&self) -> Self {
+    Self {
+      day: days_in_month(self.year, self.month),
+      ..*self
+    }
+  }
+
+  /// The first day of the week containing this date, for weeks which start on
+  /// `first_day_of_week`.
+  pub fn start_of_week(&self, first_day_of_week: u32) -> Self {
+    let offset = (self.day_of_week() + 7 - first_day_of_week % 7) % 7;
+    self.add_days(-i64::from(offset))
+  }
+
+  pub fn end_of_week(&self, first_day_of_week: u32) -> Self {
+    self.start_of_week(first_day_of_week).add_days(6)
+  }
+
+  /// Whether both dates are in the same month of the same year.
+  pub fn is_same_month(&self, other: &Self) -> bool {
+    self.year == other.year && self.month == other.month
+  }
+
+  /// Limit the date to the range between `min` and `max`.
+  pub fn constrain(&self, min: Option<Self>, max: Option<Self>) -> Self {
+    match (min, max) {
+      (Some(min), _) if *self < min => min,
+      (_, Some(max)) if *self > max => max,
+      _ => *self,
+    }
+  }
+
+  /// The ISO date time at midnight without an offset, which JavaScript reads
+  /// in the local time zone. The year is always expanded to six digits with a
+  /// sign, so years before 1 and below 100 aren't treated as offsets from
+  /// 1900.
+  fn to_local_date_time(&self) -> String {
+    format!(
+      "{:+07}-{:02}-{:02}T00:00:00",
+      self.year, self.month, self.day
+    )
+  }
+}
+
+impl FormattableDate for CalendarDate {
+  /// A JavaScript date at midnight in the local time zone.
+  fn to_js_date(&self) -> Date {
+    Date::new(&self.to_local_date_time().into())
+  }
+
+  fn to_fallback_string(&self) -> String {
+    self.to_string()
+  }
+}
+
+impl fmt::Display for CalendarDate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+  }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+  year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// The number of days in the `month` from `1` to `12`.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+  match month {
+    2 if is_leap_year(year) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+/// The day the week starts on in the locale, from `0` for Sunday to `6` for
+/// Saturday. The week data is looked up by the region of the locale, e.g.
+/// `en-GB` starts on Monday while `en-US` starts on Sunday.
+pub fn first_day_of_week(locale: &str) -> u32 {
+  let mut subtags = locale.split(['-', '_']);
+  let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+
+  // Skip the script, e.g. `Hant` in `zh-Hant-TW`, and stop at extensions.
+  let region = subtags
+    .take_while(|subtag| subtag.len() > 1)
+    .find(|subtag| {
+      (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+    })
+    .map(|region| region.to_ascii_uppercase())
+    .or_else(|| {
+      DEFAULT_REGION_BY_LANGUAGE
+        .binary_search_by(|(candidate, _)| candidate.cmp(&language.as_str()))
+        .ok()
+        .map(|index| DEFAULT_REGION_BY_LANGUAGE[index].1.to_string())
+    });
+
+  region
+    .and_then(|region| {
+      WEEK_START_BY_REGION
+        .binary_search_by(|(candidate, _)| candidate.cmp(&region.as_str()))
+        .ok()
+    })
+    .map_or(0, |index| WEEK_START_BY_REGION[index].1)
+}
+
+/// The weeks which make up the grid of the month containing `date`. Each week
+/// starts on `first_day_of_week` so the first and last weeks include days
+/// from the adjacent months.
+pub fn month_grid(date: &CalendarDate, first_day_of_week: u32) -> Vec<[CalendarDate; 7]> {
+  let end = date.end_of_month();
+  let mut start = date.start_of_month().start_of_week(first_day_of_week);
+  let mut weeks = vec![];
+
+  while start <= end {
+    let mut week = [start; 7];
+
+    for (offset, day) in week.iter_mut().enumerate() {
+      *day = start.add_days(offset as i64);
+    }
+
+    weeks.push(week);
+    start = start.add_weeks(1);
+  }
+
+  weeks
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn date_arithmetic() {
+    let date = CalendarDate::new(2024, 1, 31);
+
+    assert_eq!(CalendarDate::from_days(0), CalendarDate::new(1970, 1, 1));
+    assert_eq!(CalendarDate::from_days(date.to_days()), date);
+    assert_eq!(date.add_days(30), CalendarDate::new(2024, 3, 1));
+    assert_eq!(date.add_months(1), CalendarDate::new(2024, 2, 29));
+    assert_eq!(date.add_months(-2), CalendarDate::new(2023, 11, 30));
+    assert_eq!(
+      CalendarDate::new(2024, 2, 29).add_years(1),
+      CalendarDate::new(2025, 2, 28)
+    );
+    assert_eq!(CalendarDate::new(2024, 3, 4).day_of_week(), 1);
+    assert_eq!(
+      CalendarDate::new(2024, 3, 6).start_of_week(1),
+      CalendarDate::new(2024, 3, 4)
+    );
+    assert_eq!(
+      CalendarDate::parse("2024-03-04"),
+      Some(CalendarDate::new(2024, 3, 4))
+    );
+    assert_eq!(CalendarDate::parse("2023-02-29"), None);
+    assert_eq!(CalendarDate::new(24, 3, 4).to_string(), "0024-03-04");
+  }
+
+  #[test]
+  fn local_date_time_keeps_the_sign_of_the_year() {
+    assert_eq!(
+      CalendarDate::new(2024, 3, 4).to_local_date_time(),
+      "+002024-03-04T00:00:00"
+    );
+    assert_eq!(
+      CalendarDate::new(24, 3, 4).to_local_date_time(),
+      "+000024-03-04T00:00:00"
+    );
+    assert_eq!(
+      CalendarDate::new(0, 1, 1).to_local_date_time(),
+      "+000000-01-01T00:00:00"
+    );
+    assert_eq!(
+      CalendarDate::new(-44, 3, 15).to_local_date_time(),
+      "-000044-03-15T00:00:00"
+    );
+  }
+
+  #[test]
+  fn locale_week_start() {
+    assert_eq!(first_day_of_week("en-US"), 0);
+    assert_eq!(first_day_of_week("en-GB"), 1);
+    assert_eq!(first_day_of_week("de"), 1);
+    assert_eq!(first_day_of_week("ar-EG"), 6);
+    assert_eq!(first_day_of_week("zh-Hant-TW"), 0);
+    assert_eq!(first_day_of_week("fr-FR-u-ca-gregory"), 1);
+    assert_eq!(first_day_of_week("ja"), 0);
+  }
+
+  #[test]
+  fn builds_month_grid() {
+    // March 2024 starts on a Friday and ends on a Sunday.
+    let date = CalendarDate::new(2024, 3, 15);
+
+    let weeks = month_grid(&date, 0);
+    assert_eq!(weeks.len(), 6);
+    assert_eq!(weeks[0][0], CalendarDate::new(2024, 2, 25));
+    assert_eq!(weeks[5][6], CalendarDate::new(2024, 4, 6));
+
+    let weeks = month_grid(&date, 1);
+    assert_eq!(weeks.len(), 5);
+    assert_eq!(weeks[0][0], CalendarDate::new(2024, 2, 26));
+    assert_eq!(weeks[4][6], CalendarDate::new(2024, 3, 31));
+  }
+}
@@ -0,0 +1,433 @@
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::use_locale;
+use leptos_aria_utils::ControlledState;
+use leptos_aria_utils::FieldState;
+
+use crate::first_day_of_week;
+use crate::month_grid;
+use crate::CalendarDate;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseCalendarStateProps {
+  /// The selected date (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub value: Option<MaybeSignal<Option<CalendarDate>>>,
+
+  /// The initially selected date (uncontrolled).
+  #[builder(default, setter(strip_option))]
+  pub default_value: Option<CalendarDate>,
+
+  /// The date which is initially focused. Defaults to the selected date or
+  /// today.
+  #[builder(default, setter(strip_option))]
+  pub default_focused_value: Option<CalendarDate>,
+
+  /// Handler that is called when the user selects a date.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(CalendarDate)>>,
+
+  /// The earliest date which can be focused and selected.
+  #[builder(default, setter(strip_option, into))]
+  pub min_value: Option<MaybeSignal<CalendarDate>>,
+
+  /// The latest date which can be focused and selected.
+  #[builder(default, setter(strip_option, into))]
+  pub max_value: Option<MaybeSignal<CalendarDate>>,
+
+  /// Whether a date can't be selected, e.g. because it is fully booked.
+  /// Unavailable dates can still be focused.
+  #[builder(default, setter(strip_option))]
+  pub is_date_unavailable: Option<Box<dyn Fn(&CalendarDate) -> bool>>,
+
+  /// The locale used to format dates and to determine the first day of the
+  /// week. Defaults to the locale from
+  /// [`use_locale`](leptos_aria_utils::use_locale).
+  #[builder(default, setter(strip_option, into))]
+  pub locale: Option<MaybeSignal<String>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+}
+
+/// The selected and focused date of a calendar showing a single month.
+#[derive(Clone)]
+pub struct CalendarState {
+  pub locale: Signal<String>,
+  pub min_value: Signal<Option<CalendarDate>>,
+  pub max_value: Signal<Option<CalendarDate>>,
+  pub field: FieldState,
+  value: ControlledState<Option<CalendarDate>>,
+  focused_date: RwSignal<CalendarDate>,
+  is_focused: RwSignal<bool>,
+  is_date_unavailable: Option<Rc<Box<dyn Fn(&CalendarDate) -> bool>>>,
+}
+
+pub fn use_calendar_state(cx: Scope, props: UseCalendarStateProps) -> CalendarState {
+  let locale = match props.locale {
+    Some(locale) => (move || locale.get()).derive_signal(cx),
+    None => use_locale(cx),
+  };
+  let min_value = match props.min_value {
+    Some(min_value) => (move || Some(min_value.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let max_value = match props.max_value {
+    Some(max_value) => (move || Some(max_value.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+
+  let on_change = props.on_change.map(|on_change| {
+    Box::new(move |date: Option<CalendarDate>| {
+      if let Some(date) = date {
+        on_change(date);
+      }
+    }) as Box<dyn Fn(Option<CalendarDate>)>
+  });
+  let value = use_controlled_state(cx, props.value, props.default_value, on_change);
+
+  let focused_date = props
+    .default_focused_value
+    .or_else(|| value.get_untracked())
+    .unwrap_or_else(CalendarDate::today)
+    .constrain(min_value.get_untracked(), max_value.get_untracked());
+
+  CalendarState {
+    locale,
+    min_value,
+    max_value,
+    field: use_field_state(cx, props.is_disabled, props.is_read_only),
+    value,
+    focused_date: create_rw_signal(cx, focused_date),
+    is_focused: create_rw_signal(cx, false),
+    is_date_unavailable: props.is_date_unavailable.map(Rc::new),
+  }
+}
+
+impl CalendarState {
+  pub fn value(&self) -> Option<CalendarDate> {
+    self.value.get()
+  }
+
+  pub fn value_untracked(&self) -> Option<CalendarDate> {
+    self.value.get_untracked()
+  }
+
+  /// The date which receives keyboard focus within the grid. The grid shows
+  /// the month containing this date.
+  pub fn focused_date(&self) -> CalendarDate {
+    self.focused_date.get()
+  }
+
+  pub fn focused_date_untracked(&self) -> CalendarDate {
+    self.focused_date.get_untracked()
+  }
+
+  /// Move focus to the date, constrained to the minimum and maximum values.
+  pub fn set_focused_date(&self, date: CalendarDate) {
+    let date = date.constrain(
+      self.min_value.get_untracked(),
+      self.max_value.get_untracked(),
+    );
+
+    if self.focused_date.get_untracked() != date {
+      self.focused_date.set(date);
+    }
+  }
+
+  /// Whether the grid currently contains focus.
+  pub fn is_focused(&self) -> bool {
+    self.is_focused.get()
+  }
+
+  pub fn set_focused(&self, is_focused: bool) {
+    self.is_focused.set(is_focused);
+  }
+
+  /// The first day of the week from `0` for Sunday to `6` for Saturday.
+  pub fn first_day_of_week(&self) -> u32 {
+    first_day_of_week(&self.locale.get())
+  }
+
+  /// The first day of the visible month.
+  pub fn visible_month(&self) -> CalendarDate {
+    self.focused_date().start_of_month()
+  }
+
+  /// The weeks of the visible month, including days from the adjacent months
+  /// which fill the first and last weeks.
+  pub fn weeks(&self) -> Vec<[CalendarDate; 7]> {
+    month_grid(&self.visible_month(), self.first_day_of_week())
+  }
+
+  pub fn is_selected(&self, date: &CalendarDate) -> bool {
+    self.value().as_ref() == Some(date)
+  }
+
+  /// Whether the date is outside the minimum and maximum values.
+  pub fn is_invalid(&self, date: &CalendarDate) -> bool {
+    self.min_value.get().map_or(false, |min| *date < min)
+      || self.max_value.get().map_or(false, |max| *date > max)
+  }
+
+  pub fn is_unavailable(&self, date: &CalendarDate) -> bool {
+    self
+      .is_date_unavailable
+      .as_ref()
+      .map_or(false, |is_date_unavailable| is_date_unavailable(date))
+  }
+
+  /// Whether the cell for the date can't be focused or selected, because the
+  /// calendar is disabled, the date is out of range or in another month.
+  pub fn is_cell_disabled(&self, date: &CalendarDate) -> bool {
+    self.field.is_disabled.get()
+      || self.is_invalid(date)
+      || !date.is_same_month(&self.visible_month())
+  }
+
+  /// Whether the previous month is entirely before the minimum value.
+  pub fn is_previous_page_disabled(&self) -> bool {
+    let previous = self.visible_month().add_days(-1);
+    self.field.is_disabled.get() || self.min_value.get().map_or(false, |min| previous < min)
+  }
+
+  /// Whether the next month is entirely after the maximum value.
+  pub fn is_next_page_disabled(&self) -> bool {
+    let next = self.visible_month().end_of_month().add_days(1);
+    self.field.is_disabled.get() || self.max_value.get().map_or(false, |max| next > max)
+  }
+
+  /// Select the date and focus it. Dates which are unavailable, out of range
+  /// or in a disabled or read only calendar are ignored.
+  pub fn select_date(&self, date: CalendarDate) {
+    let is_invalid = self
+      .min_value
+      .get_untracked()
+      .map_or(false, |min| date < min)
+      || self
+        .max_value
+        .get_untracked()
+        .map_or(false, |max| date > max);
+
+    if !self.field.can_change() || is_invalid || self.is_unavailable(&date) {
+      return;
+    }
+
+    self.set_focused_date(date);
+    self.value.set(Some(date));
+  }
+
+  pub fn select_focused_date(&self) {
+    self.select_date(self.focused_date_untracked());
+  }
+
+  pub fn focus_next_day(&self) {
+    self.set_focused_date(self.focused_date_untracked().add_days(1));
+  }
+
+  pub fn focus_previous_day(&self) {
+    self.set_focused_date(self.focused_date_untracked().add_days(-1));
+  }
+
+  pub fn focus_next_week(&self) {
+    self.set_focused_date(self.focused_date_untracked().add_weeks(1));
+  }
+
+  pub fn focus_previous_week(&self) {
+    self.set_focused_date(self.focused_date_untracked().add_weeks(-1));
+  }
+
+  /// Move focus to the same day of the next month.
+  pub fn focus_next_page(&self) {
+    self.set_focused_date(self.focused_date_untracked().add_months(1));
+  }
+
+  pub fn focus_previous_page(&self) {
+    self.set_focused_date(self.focused_date_untracked().add_months(-1));
+  }
+
+  /// Move focus to the same day of the next year.
+  pub fn focus_next_year(&self) {
+    self.set_focused_date(self.focused_date_untracked().add_years(1));
+  }
+
+  pub fn focus_previous_year(&self) {
+    self.set_focused_date(self.focused_date_untracked().add_years(-1));
+  }
+
+  pub fn focus_start_of_week(&self) {
+    let first_day_of_week = first_day_of_week(&self.locale.get_untracked());
+    self.set_focused_date(
+      self
+        .focused_date_untracked()
+        .start_of_week(first_day_of_week),
+    );
+  }
+
+  pub fn focus_end_of_week(&self) {
+    let first_day_of_week = first_day_of_week(&self.locale.get_untracked());
+    self.set_focused_date(self.focused_date_untracked().end_of_week(first_day_of_week));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+
+  #[test]
+  fn dates_outside_the_range_cannot_be_selected() {
+    let (value, focused_date) = run_scope(create_runtime(), |cx| {
+      let state = use_calendar_state(
+        cx,
+        UseCalendarStateProps::builder()
+          .default_focused_value(CalendarDate::new(2023, 3, 10))
+          .max_value(CalendarDate::new(2023, 3, 20))
+          .build(),
+      );
+      state.select_date(CalendarDate::new(2023, 3, 25));
+      state.focus_next_week();
+      state.select_focused_date();
+      (state.value_untracked(), state.focused_date_untracked())
+    });
+
+    assert_eq!(value, Some(CalendarDate::new(2023, 3, 17)));
+    assert_eq!(focused_date, CalendarDate::new(2023, 3, 17));
+  }
+
+  fn focus_after(
+    props: UseCalendarStateProps,
+    navigate: impl Fn(&CalendarState) + 'static,
+  ) -> CalendarDate {
+    run_scope(create_runtime(), move |cx| {
+      let state = use_calendar_state(cx, props);
+      navigate(&state);
+      state.focused_date_untracked()
+    })
+  }
+
+  fn focused_on(date: CalendarDate) -> UseCalendarStateProps {
+    UseCalendarStateProps::builder()
+      .default_focused_value(date)
+      .locale("en-US".to_string())
+      .build()
+  }
+
+  #[test]
+  fn page_keys_move_by_month_and_year() {
+    let date = CalendarDate::new(2024, 1, 31);
+
+    // PageDown and PageUp keep the day where the month allows.
+    assert_eq!(
+      focus_after(focused_on(date), CalendarState::focus_next_page),
+      CalendarDate::new(2024, 2, 29)
+    );
+    assert_eq!(
+      focus_after(focused_on(date), CalendarState::focus_previous_page),
+      CalendarDate::new(2023, 12, 31)
+    );
+
+    // Shift+PageDown and Shift+PageUp move by a year.
+    let leap_day = CalendarDate::new(2024, 2, 29);
+    assert_eq!(
+      focus_after(focused_on(leap_day), CalendarState::focus_next_year),
+      CalendarDate::new(2025, 2, 28)
+    );
+    assert_eq!(
+      focus_after(focused_on(leap_day), CalendarState::focus_previous_year),
+      CalendarDate::new(2023, 2, 28)
+    );
+  }
+
+  #[test]
+  fn home_and_end_move_within_the_week_of_the_locale() {
+    // A Wednesday.
+    let date = CalendarDate::new(2024, 3, 13);
+
+    assert_eq!(
+      focus_after(focused_on(date), CalendarState::focus_start_of_week),
+      CalendarDate::new(2024, 3, 10)
+    );
+    assert_eq!(
+      focus_after(focused_on(date), CalendarState::focus_end_of_week),
+      CalendarDate::new(2024, 3, 16)
+    );
+
+    // Weeks start on Monday in Germany.
+    let german = || {
+      UseCalendarStateProps::builder()
+        .default_focused_value(date)
+        .locale("de-DE".to_string())
+        .build()
+    };
+    assert_eq!(
+      focus_after(german(), CalendarState::focus_start_of_week),
+      CalendarDate::new(2024, 3, 11)
+    );
+    assert_eq!(
+      focus_after(german(), CalendarState::focus_end_of_week),
+      CalendarDate::new(2024, 3, 17)
+    );
+  }
+
+  #[test]
+  fn navigation_is_clamped_to_the_range() {
+    let bounded = |date: CalendarDate| {
+      UseCalendarStateProps::builder()
+        .default_focused_value(date)
+        .min_value(CalendarDate::new(2024, 3, 5))
+        .max_value(CalendarDate::new(2024, 4, 10))
+        .locale("en-US".to_string())
+        .build()
+    };
+    let date = CalendarDate::new(2024, 3, 20);
+
+    assert_eq!(
+      focus_after(bounded(date), CalendarState::focus_previous_page),
+      CalendarDate::new(2024, 3, 5)
+    );
+    assert_eq!(
+      focus_after(bounded(date), CalendarState::focus_next_page),
+      CalendarDate::new(2024, 4, 10)
+    );
+    assert_eq!(
+      focus_after(bounded(date), CalendarState::focus_previous_year),
+      CalendarDate::new(2024, 3, 5)
+    );
+    assert_eq!(
+      focus_after(bounded(date), CalendarState::focus_next_year),
+      CalendarDate::new(2024, 4, 10)
+    );
+
+    // The week of Wednesday the 6th starts before the minimum.
+    assert_eq!(
+      focus_after(
+        bounded(CalendarDate::new(2024, 3, 6)),
+        CalendarState::focus_start_of_week
+      ),
+      CalendarDate::new(2024, 3, 5)
+    );
+    assert_eq!(
+      focus_after(
+        bounded(CalendarDate::new(2024, 4, 9)),
+        CalendarState::focus_end_of_week
+      ),
+      CalendarDate::new(2024, 4, 10)
+    );
+  }
+}
@@ -0,0 +1,602 @@
+use leptos::create_effect;
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_i18n::DateFormatOptions;
+use leptos_aria_i18n::DateFormatter;
+use leptos_aria_i18n::DatePartStyle;
+use leptos_aria_i18n::LocalizedStringFormatter;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::use_locale;
+use leptos_aria_utils::ControlledState;
+use leptos_aria_utils::FieldState;
+
+use crate::days_in_month;
+use crate::CalendarDate;
+
+/// An editable part of a date field, or the literal text between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DateSegmentType {
+  Day,
+  Month,
+  Year,
+  Era,
+  Literal,
+}
+
+impl DateSegmentType {
+  /// The name of the segment, used as the value of its
+  /// [`DATA_SEGMENT_ATTRIBUTE`](crate::DATA_SEGMENT_ATTRIBUTE) and as the key
+  /// of its localized label.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Day => "day",
+      Self::Month => "month",
+      Self::Year => "year",
+      Self::Era => "era",
+      Self::Literal => "literal",
+    }
+  }
+
+  /// The key of the localized text shown while the segment is empty. The era
+  /// and literal segments are never empty.
+  pub fn placeholder_key(&self) -> Option<&'static str> {
+    match self {
+      Self::Day => Some("dayPlaceholder"),
+      Self::Month => Some("monthPlaceholder"),
+      Self::Year => Some("yearPlaceholder"),
+      Self::Era | Self::Literal => None,
+    }
+  }
+}
+
+/// A segment in the order it is displayed for the locale.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateSegment {
+  pub segment_type: DateSegmentType,
+  /// The text of a literal segment, e.g. `/`. Empty for editable segments.
+  pub text: String,
+}
+
+/// The values of the editable segments, which may be partially filled while
+/// the user is typing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DateSegmentValues {
+  /// The year within the era, starting at `1`.
+  pub year: Option<i32>,
+  pub month: Option<u32>,
+  pub day: Option<u32>,
+  /// Whether the year is before the common era.
+  pub is_bc: bool,
+}
+
+impl DateSegmentValues {
+  pub fn from_date(date: &CalendarDate) -> Self {
+    let is_bc = date.year <= 0;
+
+    Self {
+      year: Some(if is_bc { 1 - date.year } else { date.year }),
+      month: Some(date.month),
+      day: Some(date.day),
+      is_bc,
+    }
+  }
+
+  /// The date once every segment has a value. A day beyond the end of the
+  /// month is clamped to the last day.
+  pub fn to_date(&self) -> Option<CalendarDate> {
+    let year = self.year?;
+    let year = if self.is_bc { 1 - year } else { year };
+
+    Some(CalendarDate::new(year, self.month?, self.day?))
+  }
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseDateFieldStateProps {
+  /// The date (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub value: Option<MaybeSignal<Option<CalendarDate>>>,
+
+  /// The initial date (uncontrolled).
+  #[builder(default, setter(strip_option))]
+  pub default_value: Option<CalendarDate>,
+
+  /// The date which empty segments start from when incremented. Defaults to
+  /// today.
+  #[builder(default, setter(strip_option))]
+  pub placeholder_value: Option<CalendarDate>,
+
+  /// Handler that is called when every segment is filled, or when a complete
+  /// date is cleared.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(Option<CalendarDate>)>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub min_value: Option<MaybeSignal<CalendarDate>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub max_value: Option<MaybeSignal<CalendarDate>>,
+
+  /// Always show the era segment. It is otherwise only shown for dates before
+  /// the common era.
+  #[builder(default)]
+  pub show_era: bool,
+
+  /// The locale which determines the order of the segments. Defaults to the
+  /// locale from [`use_locale`](leptos_aria_utils::use_locale).
+  #[builder(default, setter(strip_option, into))]
+  pub locale: Option<MaybeSignal<String>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+}
+
+/// The segments of a date field and the date they make up.
+#[derive(Clone)]
+pub struct DateFieldState {
+  /// The id of the field, used to find the segments when moving focus.
+  pub id: String,
+  pub locale: Signal<String>,
+  pub min_value: Signal<Option<CalendarDate>>,
+  pub max_value: Signal<Option<CalendarDate>>,
+  pub placeholder_value: CalendarDate,
+  pub field: FieldState,
+  strings: Signal<LocalizedStringFormatter>,
+  show_era: bool,
+  value: ControlledState<Option<CalendarDate>>,
+  segment_values: RwSignal<DateSegmentValues>,
+  /// The segment which is being typed into, so further digits are appended.
+  typing: RwSignal<Option<DateSegmentType>>,
+}
+
+pub fn use_date_field_state(cx: Scope, props: UseDateFieldStateProps) -> DateFieldState {
+  let locale = match props.locale {
+    Some(locale) => (move || locale.get()).derive_signal(cx),
+    None => use_locale(cx),
+  };
+  let min_value = match props.min_value {
+    Some(min_value) => (move || Some(min_value.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let max_value = match props.max_value {
+    Some(max_value) => (move || Some(max_value.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+
+  let value = use_controlled_state(cx, props.value, props.default_value, props.on_change);
+  let segment_values = create_rw_signal(
+    cx,
+    value
+      .get_untracked()
+      .map(|date| DateSegmentValues::from_date(&date))
+      .unwrap_or_default(),
+  );
+
+  // Keep the segments in sync with a controlled value.
+  {
+    let value = value.clone();
+
+    create_effect(cx, move |_| {
+      let current = segment_values.get_untracked();
+
+      match value.get() {
+        Some(date) if current.to_date() != Some(date) => {
+          segment_values.set(DateSegmentValues::from_date(&date));
+        }
+        None if current.to_date().is_some() => segment_values.set(DateSegmentValues::default()),
+        _ => {}
+      }
+    });
+  }
+
+  DateFieldState {
+    id: use_id(cx),
+    locale,
+    min_value,
+    max_value,
+    placeholder_value: props.placeholder_value.unwrap_or_else(CalendarDate::today),
+    field: use_field_state(cx, props.is_disabled, props.is_read_only),
+    strings: use_localized_string_formatter(cx),
+    show_era: props.show_era,
+    value,
+    segment_values,
+    typing: create_rw_signal(cx, None),
+  }
+}
+
+impl DateFieldState {
+  pub fn value(&self) -> Option<CalendarDate> {
+    self.value.get()
+  }
+
+  pub fn segment_values(&self) -> DateSegmentValues {
+    self.segment_values.get()
+  }
+
+  /// Whether the date is outside the minimum and maximum values.
+  pub fn is_invalid(&self) -> bool {
+    self.value().map_or(false, |date| {
+      self.min_value.get().map_or(false, |min| date < min)
+        || self.max_value.get().map_or(false, |max| date > max)
+    })
+  }
+
+  /// The segments in the order they are displayed for the locale.
+  pub fn segments(&self) -> Vec<DateSegment> {
+    let show_era = self.show_era || self.segment_values().is_bc;
+    let options = DateFormatOptions {
+      year: Some(DatePartStyle::Numeric),
+      month: Some(DatePartStyle::TwoDigit),
+      day: Some(DatePartStyle::TwoDigit),
+      era: show_era.then_some(DatePartStyle::Short),
+      ..DateFormatOptions::default()
+    };
+
+    let parts =
+      DateFormatter::new(&self.locale.get(), &options).format_to_parts(&self.placeholder_value);
+    let mut segments: Vec<DateSegment> = parts
+      .into_iter()
+      .filter_map(|(part_type, text)| {
+        let segment_type = match part_type.as_str() {
+          "day" => DateSegmentType::Day,
+          "month" => DateSegmentType::Month,
+          "year" => DateSegmentType::Year,
+          "era" => DateSegmentType::Era,
+          "literal" => {
+            return Some(DateSegment {
+              segment_type: DateSegmentType::Literal,
+              text,
+            })
+          }
+          _ => return None,
+        };
+
+        Some(DateSegment {
+          segment_type,
+          text: String::new(),
+        })
+      })
+      .collect();
+
+    // The US layout is used when the parts are unavailable on the server.
+    if segments.is_empty() {
+      for segment_type in [
+        DateSegmentType::Month,
+        DateSegmentType::Day,
+        DateSegmentType::Year,
+      ] {
+        if !segments.is_empty() {
+          segments.push(DateSegment {
+            segment_type: DateSegmentType::Literal,
+            text: "/".into(),
+          });
+        }
+
+        segments.push(DateSegment {
+          segment_type,
+          text: String::new(),
+        });
+      }
+    }
+
+    segments
+  }
+
+  /// The numeric value of the segment. The era is `0` before the common era
+  /// and `1` after.
+  pub fn segment_value(&self, segment_type: DateSegmentType) -> Option<i32> {
+    let values = self.segment_values();
+
+    match segment_type {
+      DateSegmentType::Day => values.day.map(|day| day as i32),
+      DateSegmentType::Month => values.month.map(|month| month as i32),
+      DateSegmentType::Year => values.year,
+      DateSegmentType::Era => Some(i32::from(!values.is_bc)),
+      DateSegmentType::Literal => None,
+    }
+  }
+
+  /// The smallest and largest value of the segment.
+  pub fn segment_range(&self, segment_type: DateSegmentType) -> (i32, i32) {
+    match segment_type {
+      DateSegmentType::Day => {
+        let values = self.segment_values();
+        let year = values.year.unwrap_or(self.placeholder_value.year);
+        let days = values.month.map_or(31, |month| days_in_month(year, month));
+        (1, days as i32)
+      }
+      DateSegmentType::Month => (1, 12),
+      DateSegmentType::Year => (1, 9999),
+      DateSegmentType::Era => (0, 1),
+      DateSegmentType::Literal => (0, 0),
+    }
+  }
+
+  /// The text displayed for the segment, or `None` while it is empty.
+  pub fn segment_text(&self, segment_type: DateSegmentType) -> Option<String> {
+    let value = self.segment_value(segment_type)?;
+
+    match segment_type {
+      DateSegmentType::Day | DateSegmentType::Month => Some(format!("{value:02}")),
+      DateSegmentType::Year => Some(value.to_string()),
+      DateSegmentType::Era => {
+        let date = self
+          .segment_values()
+          .to_date()
+          .unwrap_or(self.placeholder_value);
+        let year = if value == 0 {
+          date.year.min(0)
+        } else {
+          date.year.max(1)
+        };
+        let date = CalendarDate::new(year, date.month, date.day);
+
+        let options = DateFormatOptions::builder()
+          .era(DatePartStyle::Short)
+          .build();
+
+        DateFormatter::new(&self.locale.get(), &options)
+          .format_to_parts(&date)
+          .into_iter()
+          .find(|(part_type, _)| part_type == "era")
+          .map(|(_, text)| text)
+          .or_else(|| Some(if value == 0 { "BC" } else { "AD" }.to_string()))
+      }
+      DateSegmentType::Literal => None,
+    }
+  }
+
+  /// The accessible label of the segment in the locale of the field.
+  pub fn segment_label(&self, segment_type: DateSegmentType) -> String {
+    self.strings().format(segment_type.as_str())
+  }
+
+  /// The text shown while the segment is empty, in the locale of the field.
+  pub fn segment_placeholder(&self, segment_type: DateSegmentType) -> String {
+    segment_type
+      .placeholder_key()
+      .map(|key| self.strings().format(key))
+      .unwrap_or_default()
+  }
+
+  /// The `aria-valuetext` of the segment, which includes the month name.
+  pub fn segment_value_text(&self, segment_type: DateSegmentType) -> String {
+    let Some(text) = self.segment_text(segment_type) else {
+      return self.strings().format("empty");
+    };
+
+    match (segment_type, self.segment_values().month) {
+      (DateSegmentType::Month, Some(month)) => {
+        let date = CalendarDate::new(self.placeholder_value.year, month, 1);
+        let options = DateFormatOptions::builder()
+          .month(DatePartStyle::Long)
+          .build();
+        let name = DateFormatter::new(&self.locale.get_untracked(), &options).format(&date);
+        format!("{month} – {name}")
+      }
+      _ => text,
+    }
+  }
+
+  /// Add `amount` to the segment, wrapping around within its range. An empty
+  /// segment starts from the placeholder value.
+  pub fn increment(&self, segment_type: DateSegmentType, amount: i32) {
+    let (min, max) = self.segment_range(segment_type);
+
+    let value = match self.segment_value(segment_type) {
+      Some(value) if segment_type == DateSegmentType::Year => (value + amount).max(min),
+      Some(value) => cycle_value(value, amount, min, max),
+      None => {
+        let placeholder = DateSegmentValues::from_date(&self.placeholder_value);
+        let value = match segment_type {
+          DateSegmentType::Day => placeholder.day.map(|day| day as i32),
+          DateSegmentType::Month => placeholder.month.map(|month| month as i32),
+          _ => placeholder.year,
+        };
+
+        value.map_or(min, |value| value.clamp(min, max))
+      }
+    };
+
+    self.typing.set(None);
+    self.set_segment(segment_type, Some(value));
+  }
+
+  /// Set the segment to its smallest or largest value.
+  pub fn set_segment_to_limit(&self, segment_type: DateSegmentType, is_max: bool) {
+    let (min, max) = self.segment_range(segment_type);
+    self.typing.set(None);
+    self.set_segment(segment_type, Some(if is_max { max } else { min }));
+  }
+
+  /// Type a digit into the segment. Returns `true` when no further digit
+  /// could be typed, so focus should move to the next segment.
+  pub fn enter_digit(&self, segment_type: DateSegmentType, digit: u32) -> bool {
+    let is_typing = self.typing.get_untracked() == Some(segment_type);
+    let (_, max) = self.segment_range(segment_type);
+    let (value, should_advance) = apply_digit(
+      self.segment_value(segment_type),
+      is_typing,
+      digit as i32,
+      max,
+    );
+
+    self.typing.set(Some(segment_type));
+    self.set_segment(segment_type, value);
+
+    if should_advance {
+      self.typing.set(None);
+    }
+
+    should_advance
+  }
+
+  /// Remove the last typed digit, clearing the segment when none are left.
+  pub fn remove_digit(&self, segment_type: DateSegmentType) {
+    let value = self
+      .segment_value(segment_type)
+      .map(|value| value / 10)
+      .filter(|value| *value > 0);
+
+    self.typing.set(value.map(|_| segment_type));
+    self.set_segment(segment_type, value);
+  }
+
+  /// Stop appending digits to the segment which was typed into.
+  pub fn end_typing(&self) {
+    self.typing.set(None);
+  }
+
+  /// Update the segment and commit the date when every segment is filled. A
+  /// day beyond the end of the month is moved to the last day, so the
+  /// segments always show the committed date.
+  pub fn set_segment(&self, segment_type: DateSegmentType, value: Option<i32>) {
+    if !self.field.can_change() {
+      return;
+    }
+
+    let mut values = self.segment_values.get_untracked();
+
+    match segment_type {
+      DateSegmentType::Day => values.day = value.map(|day| day as u32),
+      DateSegmentType::Month => values.month = value.map(|month| month as u32),
+      DateSegmentType::Year => values.year = value,
+      DateSegmentType::Era => values.is_bc = value == Some(0),
+      DateSegmentType::Literal => return,
+    }
+
+    let date = values.to_date();
+
+    if let Some(date) = date {
+      values = DateSegmentValues::from_date(&date);
+    }
+
+    self.segment_values.set(values);
+
+    match date {
+      Some(date) => self.value.set(Some(date)),
+      None if self.value.get_untracked().is_some() => self.value.set(None),
+      None => {}
+    }
+  }
+
+  fn strings(&self) -> LocalizedStringFormatter {
+    self.strings.get().for_locale(&self.locale.get())
+  }
+}
+
+/// Add `amount` to the value and wrap around within the range.
+pub(crate) fn cycle_value(value: i32, amount: i32, min: i32, max: i32) -> i32 {
+  let size = max - min + 1;
+  min + (value - min + amount).rem_euclid(size)
+}
+
+/// The value of a segment after typing a digit, and whether focus should move
+/// on because another digit would exceed the `max`. A digit which makes the
+/// value too large starts a new value. A leading zero leaves the segment
+/// empty.
+pub(crate) fn apply_digit(
+  current: Option<i32>,
+  is_typing: bool,
+  digit: i32,
+  max: i32,
+) -> (Option<i32>, bool) {
+  let candidate = match current {
+    Some(current) if is_typing => current * 10 + digit,
+    _ => digit,
+  };
+  let value = if candidate > max { digit } else { candidate };
+
+  if value == 0 {
+    return (None, false);
+  }
+
+  (Some(value), value * 10 > max)
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+
+  #[test]
+  fn cycles_segment_values() {
+    assert_eq!(cycle_value(12, 1, 1, 12), 1);
+    assert_eq!(cycle_value(1, -1, 1, 12), 12);
+    assert_eq!(cycle_value(5, 2, 1, 12), 7);
+    assert_eq!(cycle_value(30, 7, 1, 31), 6);
+  }
+
+  #[test]
+  fn types_digits() {
+    // Typing `1` into the month could still become `10`, `11` or `12`.
+    assert_eq!(apply_digit(None, false, 1, 12), (Some(1), false));
+    assert_eq!(apply_digit(Some(1), true, 2, 12), (Some(12), true));
+    assert_eq!(apply_digit(Some(1), true, 5, 12), (Some(5), true));
+    assert_eq!(apply_digit(None, false, 4, 12), (Some(4), true));
+    assert_eq!(apply_digit(Some(9), false, 3, 31), (Some(3), false));
+    assert_eq!(apply_digit(None, false, 0, 31), (None, false));
+    assert_eq!(apply_digit(Some(202), true, 4, 9999), (Some(2024), true));
+  }
+
+  #[test]
+  fn converts_eras() {
+    let values = DateSegmentValues::from_date(&CalendarDate::new(0, 3, 4));
+
+    assert_eq!(values.year, Some(1));
+    assert!(values.is_bc);
+    assert_eq!(values.to_date(), Some(CalendarDate::new(0, 3, 4)));
+    assert_eq!(DateSegmentValues::default().to_date(), None);
+  }
+
+  #[test]
+  fn shows_the_clamped_day() {
+    let (value, values) = run_scope(create_runtime(), |cx| {
+      let state = use_date_field_state(cx, UseDateFieldStateProps::default());
+      state.set_segment(DateSegmentType::Day, Some(31));
+      state.set_segment(DateSegmentType::Month, Some(2));
+      state.set_segment(DateSegmentType::Year, Some(2024));
+
+      (state.value(), state.segment_values())
+    });
+
+    assert_eq!(value, Some(CalendarDate::new(2024, 2, 29)));
+    assert_eq!(values.day, Some(29));
+  }
+
+  #[test]
+  fn localizes_the_segments() {
+    let (label, placeholder, empty) = run_scope(create_runtime(), |cx| {
+      let state = use_date_field_state(
+        cx,
+        UseDateFieldStateProps::builder()
+          .locale("de-DE".to_string())
+          .build(),
+      );
+
+      (
+        state.segment_label(DateSegmentType::Day),
+        state.segment_placeholder(DateSegmentType::Year),
+        state.segment_value_text(DateSegmentType::Month),
+      )
+    });
+
+    assert_eq!(label, "Tag");
+    assert_eq!(placeholder, "JJJJ");
+    assert_eq!(empty, "Leer");
+  }
+}
@@ -0,0 +1,15 @@
+pub use calendar_date::*;
+pub use calendar_state::*;
+pub use date_field_state::*;
+pub use use_calendar::*;
+pub use use_calendar_cell::*;
+pub use use_date_field::*;
+pub use use_date_segment::*;
+
+mod calendar_date;
+mod calendar_state;
+mod date_field_state;
+mod use_calendar;
+mod use_calendar_cell;
+mod use_date_field;
+mod use_date_segment;
@@ -0,0 +1,374 @@
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_signal;
+use leptos::request_animation_frame;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::Node;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_i18n::DateFormatOptions;
+use leptos_aria_i18n::DateFormatter;
+use leptos_aria_i18n::DatePartStyle;
+use leptos_aria_interactions::use_press;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_interactions::PressResult;
+use leptos_aria_interactions::UsePressProps;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::Politeness;
+
+use crate::CalendarDate;
+use crate::CalendarState;
+
+/// The attribute which identifies the date of a cell button, used to move DOM
+/// focus after navigating with the keyboard.
+pub const DATA_DATE_ATTRIBUTE: &str = "data-date";
+
+#[derive(TypedBuilder, Default)]
+pub struct UseCalendarProps {
+  /// The id of the calendar. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// An accessible label for the calendar, which is combined with the visible
+  /// month.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The label of the button which shows the previous month. Defaults to
+  /// `"Previous"`, translated for the locale.
+  #[builder(default, setter(strip_option, into))]
+  pub previous_label: Option<MaybeSignal<String>>,
+
+  /// The label of the button which shows the next month. Defaults to
+  /// `"Next"`, translated for the locale.
+  #[builder(default, setter(strip_option, into))]
+  pub next_label: Option<MaybeSignal<String>>,
+}
+
+/// The props for the element which wraps the whole calendar.
+#[derive(Clone)]
+pub struct CalendarElementResult {
+  pub id: String,
+  pub role: &'static str,
+  pub aria_label: Signal<String>,
+}
+
+/// The props for the table which contains the days.
+#[derive(Clone)]
+pub struct CalendarGridResult {
+  pub role: &'static str,
+  pub aria_label: Signal<String>,
+  pub aria_readonly: Signal<Option<bool>>,
+  pub aria_disabled: Signal<Option<bool>>,
+  pub on_key_down: CalendarCallback<KeyboardEvent>,
+  pub on_focus_in: CalendarCallback<FocusEvent>,
+  pub on_focus_out: CalendarCallback<FocusEvent>,
+}
+
+/// A column header of the grid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CalendarWeekday {
+  /// The abbreviated name of the day which is displayed, e.g. `"M"`.
+  pub label: String,
+  /// The full name of the day for the `abbr` attribute, e.g. `"Monday"`.
+  pub full_label: String,
+}
+
+/// The props for the buttons which change the visible month.
+#[derive(Clone)]
+pub struct CalendarButtonResult {
+  pub aria_label: Signal<String>,
+  pub is_disabled: Signal<bool>,
+  pub press: ReadSignal<PressResult>,
+}
+
+#[derive(Clone)]
+pub struct CalendarResult {
+  pub calendar: CalendarElementResult,
+  /// The visible month, e.g. `"March 2024"`, for the heading.
+  pub title: Signal<String>,
+  pub grid: CalendarGridResult,
+  /// The days of the week in the order they are displayed.
+  pub weekdays: Signal<Vec<CalendarWeekday>>,
+  pub previous_button: CalendarButtonResult,
+  pub next_button: CalendarButtonResult,
+}
+
+/// Provides the behavior and accessibility implementation for a calendar
+/// which shows a single month in a grid.
+///
+/// * The arrow keys move focus by a day or a week. <kbd>ArrowLeft</kbd> moves
+///   to the next day in right to left locales.
+/// * <kbd>PageUp</kbd> and <kbd>PageDown</kbd> move to the previous and next
+///   month, or year when <kbd>Shift</kbd> is held.
+/// * <kbd>Home</kbd> and <kbd>End</kbd> move to the start and end of the week.
+/// * Pressing a day, including with <kbd>Enter</kbd> and <kbd>Space</kbd>,
+///   selects it.
+///
+/// The weeks to render are available from [`CalendarState::weeks`] and each
+/// day is set up with [`use_calendar_cell`](crate::use_calendar_cell).
+pub fn use_calendar(
+  cx: Scope,
+  state: &CalendarState,
+  props: UseCalendarProps,
+) -> ReadSignal<CalendarResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+
+  let title = {
+    let state = state.clone();
+    let options = DateFormatOptions::builder()
+      .month(DatePartStyle::Long)
+      .year(DatePartStyle::Numeric)
+      .build();
+
+    (move || DateFormatter::new(&state.locale.get(), &options).format(&state.visible_month()))
+      .derive_signal(cx)
+  };
+
+  let aria_label = match props.aria_label {
+    Some(aria_label) => {
+      (move || format!("{}, {}", aria_label.get(), title.get())).derive_signal(cx)
+    }
+    None => title,
+  };
+
+  let weekdays = {
+    let state = state.clone();
+
+    let narrow = DateFormatOptions::builder()
+      .weekday(DatePartStyle::Narrow)
+      .build();
+    let long = DateFormatOptions::builder()
+      .weekday(DatePartStyle::Long)
+      .build();
+
+    (move || {
+      let locale = state.locale.get();
+      let narrow = DateFormatter::new(&locale, &narrow);
+      let long = DateFormatter::new(&locale, &long);
+      let start = state
+        .focused_date()
+        .start_of_week(state.first_day_of_week());
+
+      (0..7)
+        .map(|offset| {
+          let date = start.add_days(offset);
+
+          CalendarWeekday {
+            label: narrow.format(&date),
+            full_label: long.format(&date),
+          }
+        })
+        .collect::<Vec<_>>()
+    })
+    .derive_signal(cx)
+  };
+
+  let strings = use_localized_string_formatter(cx);
+
+  // Announce the selected date since the grid label only includes the month.
+  {
+    let state = state.clone();
+
+    create_effect(cx, move |previous: Option<Option<CalendarDate>>| {
+      let value = state.value();
+
+      // Skip the initial value.
+      if let (Some(previous), Some(date)) = (previous, value) {
+        if previous != value {
+          let locale = state.locale.get_untracked();
+          let label = DateFormatter::new(&locale, &full_date_options()).format(&date);
+          let message = strings
+            .get_untracked()
+            .for_locale(&locale)
+            .format_with("selectedDateDescription", &[("date", label.into())]);
+          announce(message, Politeness::Polite);
+        }
+      }
+
+      value
+    });
+  }
+
+  let on_key_down: CalendarCallback<KeyboardEvent> = {
+    let state = state.clone();
+    let direction = use_direction(cx);
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      let Some(grid) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      else {
+        return;
+      };
+
+      match KeyboardKey::from(&event).logical(direction.get_untracked()) {
+        KeyboardKey::ArrowRight => state.focus_next_day(),
+        KeyboardKey::ArrowLeft => state.focus_previous_day(),
+        KeyboardKey::ArrowDown => state.focus_next_week(),
+        KeyboardKey::ArrowUp => state.focus_previous_week(),
+        KeyboardKey::PageDown if event.shift_key() => state.focus_next_year(),
+        KeyboardKey::PageUp if event.shift_key() => state.focus_previous_year(),
+        KeyboardKey::PageDown => state.focus_next_page(),
+        KeyboardKey::PageUp => state.focus_previous_page(),
+        KeyboardKey::Home => state.focus_start_of_week(),
+        KeyboardKey::End => state.focus_end_of_week(),
+        _ => return,
+      }
+
+      event.prevent_default();
+      focus_date_cell(grid, state.focused_date_untracked());
+    }))
+  };
+
+  let on_focus_in: CalendarCallback<FocusEvent> = {
+    let state = state.clone();
+    Rc::new(Box::new(move |_: FocusEvent| state.set_focused(true)))
+  };
+
+  let on_focus_out: CalendarCallback<FocusEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |event: FocusEvent| {
+      let Some(grid) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Node>().ok())
+      else {
+        return;
+      };
+
+      let related_target: Option<Node> = event
+        .related_target()
+        .and_then(|target| target.dyn_into::<Node>().ok());
+
+      if !grid.contains(related_target.as_ref()) {
+        state.set_focused(false);
+      }
+    }))
+  };
+
+  let locale = state.locale;
+  let button_label = |label: Option<MaybeSignal<String>>, key: &'static str| {
+    match label {
+      Some(label) => (move || label.get()).derive_signal(cx),
+      None => (move || strings.get().for_locale(&locale.get()).format(key)).derive_signal(cx),
+    }
+  };
+
+  let previous_button = use_calendar_button(
+    cx,
+    button_label(props.previous_label, "previous"),
+    {
+      let state = state.clone();
+      (move || state.is_previous_page_disabled()).derive_signal(cx)
+    },
+    {
+      let state = state.clone();
+      move || state.focus_previous_page()
+    },
+  );
+
+  let next_button = use_calendar_button(
+    cx,
+    button_label(props.next_label, "next"),
+    {
+      let state = state.clone();
+      (move || state.is_next_page_disabled()).derive_signal(cx)
+    },
+    {
+      let state = state.clone();
+      move || state.focus_next_page()
+    },
+  );
+
+  let (calendar_result, _) = create_signal(
+    cx,
+    CalendarResult {
+      calendar: CalendarElementResult {
+        id,
+        role: "application",
+        aria_label,
+      },
+      title,
+      grid: CalendarGridResult {
+        role: "grid",
+        aria_label,
+        aria_readonly: state.field.aria_readonly(cx, "grid"),
+        aria_disabled: state.field.aria_disabled(cx),
+        on_key_down,
+        on_focus_in,
+        on_focus_out,
+      },
+      weekdays,
+      previous_button,
+      next_button,
+    },
+  );
+
+  calendar_result
+}
+
+pub(crate) type CalendarCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// The options for the full description of a date, e.g. `"Monday, March 4,
+/// 2024"`.
+pub(crate) fn full_date_options() -> DateFormatOptions {
+  DateFormatOptions::builder()
+    .weekday(DatePartStyle::Long)
+    .year(DatePartStyle::Numeric)
+    .month(DatePartStyle::Long)
+    .day(DatePartStyle::Numeric)
+    .build()
+}
+
+fn use_calendar_button(
+  cx: Scope,
+  aria_label: Signal<String>,
+  is_disabled: Signal<bool>,
+  on_press: impl Fn() + 'static,
+) -> CalendarButtonResult {
+  let press = use_press(
+    cx,
+    UsePressProps::builder()
+      .on_press(Box::new(move |_: &PressEvent| on_press()))
+      .is_disabled(is_disabled)
+      .build(),
+  );
+
+  CalendarButtonResult {
+    aria_label,
+    is_disabled,
+    press,
+  }
+}
+
+/// Move DOM focus to the cell of the date once the grid has rendered it,
+/// since moving to another month replaces the cells.
+fn focus_date_cell(grid: Element, date: CalendarDate) {
+  request_animation_frame(move || {
+    let selector = format!("[{DATA_DATE_ATTRIBUTE}=\"{date}\"]");
+
+    if let Some(cell) = grid
+      .query_selector(&selector)
+      .ok()
+      .flatten()
+      .and_then(|cell| cell.dyn_into::<HtmlElement>().ok())
+    {
+      let _ = cell.focus();
+    }
+  });
+}
@@ -0,0 +1,211 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::FocusEvent;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_i18n::DateFormatOptions;
+use leptos_aria_i18n::DateFormatter;
+use leptos_aria_i18n::DatePartStyle;
+use leptos_aria_interactions::use_press;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_interactions::PressResult;
+use leptos_aria_interactions::UsePressProps;
+
+use crate::use_calendar::full_date_options;
+use crate::use_calendar::CalendarCallback;
+use crate::CalendarDate;
+use crate::CalendarState;
+
+#[derive(TypedBuilder)]
+pub struct UseCalendarCellProps {
+  /// The date of the cell.
+  pub date: CalendarDate,
+}
+
+/// The props for the `<td>` element.
+#[derive(Clone)]
+pub struct CalendarCellElementResult {
+  pub role: &'static str,
+  pub aria_selected: Signal<Option<bool>>,
+  pub aria_disabled: Signal<Option<bool>>,
+}
+
+/// The props for the focusable element within the cell.
+#[derive(Clone)]
+pub struct CalendarCellButtonResult {
+  pub role: &'static str,
+  /// Only the focused date is in the tab order.
+  pub tab_index: Signal<Option<i32>>,
+  /// The full date, e.g. `"Today, Monday, March 4, 2024"`.
+  pub aria_label: Signal<String>,
+  pub aria_disabled: Signal<Option<bool>>,
+  pub aria_invalid: Signal<Option<bool>>,
+  /// The value for the [`DATA_DATE_ATTRIBUTE`](crate::DATA_DATE_ATTRIBUTE).
+  pub data_date: String,
+  pub on_focus: CalendarCallback<FocusEvent>,
+  pub press: ReadSignal<PressResult>,
+}
+
+#[derive(Clone)]
+pub struct CalendarCellResult {
+  pub cell: CalendarCellElementResult,
+  pub button: CalendarCellButtonResult,
+  /// The day of the month formatted for the locale.
+  pub formatted_date: Signal<String>,
+  pub is_selected: Signal<bool>,
+  pub is_focused: Signal<bool>,
+  /// Whether the date is out of range, in another month or the calendar is
+  /// disabled.
+  pub is_disabled: Signal<bool>,
+  pub is_unavailable: Signal<bool>,
+  /// Whether the date belongs to the month before or after the visible one.
+  pub is_outside_visible_month: Signal<bool>,
+  pub is_today: bool,
+}
+
+/// Provides the behavior and accessibility implementation for a day within
+/// the grid of a [`use_calendar`](crate::use_calendar).
+///
+/// Days from adjacent months are disabled, since they are only shown to fill
+/// the first and last weeks. Unavailable dates stay focusable but can't be
+/// selected.
+pub fn use_calendar_cell(
+  cx: Scope,
+  state: &CalendarState,
+  props: UseCalendarCellProps,
+) -> ReadSignal<CalendarCellResult> {
+  let date = props.date;
+  let is_today = date == CalendarDate::today();
+
+  let is_selected = {
+    let state = state.clone();
+    (move || state.is_selected(&date)).derive_signal(cx)
+  };
+
+  let is_focused = {
+    let state = state.clone();
+    (move || state.focused_date() == date).derive_signal(cx)
+  };
+
+  let is_disabled = {
+    let state = state.clone();
+    (move || state.is_cell_disabled(&date)).derive_signal(cx)
+  };
+
+  let is_unavailable = {
+    let state = state.clone();
+    (move || state.is_unavailable(&date)).derive_signal(cx)
+  };
+
+  let is_outside_visible_month = {
+    let state = state.clone();
+    (move || !date.is_same_month(&state.visible_month())).derive_signal(cx)
+  };
+
+  let formatted_date = {
+    let state = state.clone();
+    let options = DateFormatOptions::builder()
+      .day(DatePartStyle::Numeric)
+      .build();
+
+    (move || DateFormatter::new(&state.locale.get(), &options).format(&date)).derive_signal(cx)
+  };
+
+  let aria_label = {
+    let state = state.clone();
+    let strings = use_localized_string_formatter(cx);
+    let options = full_date_options();
+
+    (move || {
+      let locale = state.locale.get();
+      let label = DateFormatter::new(&locale, &options).format(&date);
+
+      if is_today {
+        strings
+          .get()
+          .for_locale(&locale)
+          .format_with("todayDate", &[("date", label.into())])
+      } else {
+        label
+      }
+    })
+    .derive_signal(cx)
+  };
+
+  let tab_index = (move || {
+    if is_disabled.get() {
+      None
+    } else if is_focused.get() {
+      Some(0)
+    } else {
+      Some(-1)
+    }
+  })
+  .derive_signal(cx);
+
+  let on_focus: CalendarCallback<FocusEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |_: FocusEvent| {
+      if !is_disabled.get() {
+        state.set_focused_date(date);
+      }
+    }))
+  };
+
+  let press = {
+    let state = state.clone();
+
+    use_press(
+      cx,
+      UsePressProps::builder()
+        .on_press(Box::new(move |_: &PressEvent| state.select_date(date)))
+        .is_disabled(is_disabled)
+        .build(),
+    )
+  };
+
+  // Unavailable dates are announced as disabled but can still be focused.
+  let aria_disabled =
+    (move || (is_disabled.get() || is_unavailable.get()).then_some(true)).derive_signal(cx);
+  let aria_invalid = {
+    let state = state.clone();
+    (move || (state.is_selected(&date) && state.is_unavailable(&date)).then_some(true))
+      .derive_signal(cx)
+  };
+
+  let (calendar_cell_result, _) = create_signal(
+    cx,
+    CalendarCellResult {
+      cell: CalendarCellElementResult {
+        role: "gridcell",
+        aria_selected: (move || is_selected.get().then_some(true)).derive_signal(cx),
+        aria_disabled,
+      },
+      button: CalendarCellButtonResult {
+        role: "button",
+        tab_index,
+        aria_label,
+        aria_disabled,
+        aria_invalid,
+        data_date: date.to_string(),
+        on_focus,
+        press,
+      },
+      formatted_date,
+      is_selected,
+      is_focused,
+      is_disabled,
+      is_unavailable,
+      is_outside_visible_month,
+      is_today,
+    },
+  );
+
+  calendar_cell_result
+}
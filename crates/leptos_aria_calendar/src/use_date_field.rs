@@ -0,0 +1,120 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+
+use crate::DateFieldState;
+use crate::DateSegment;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseDateFieldProps {
+  /// An accessible label for the field when there is no visible label.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the field.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+
+  /// The name of the hidden input which submits the date with a form.
+  #[builder(default, setter(strip_option, into))]
+  pub name: Option<String>,
+}
+
+/// The props for the element which contains the segments.
+#[derive(Clone)]
+pub struct DateFieldElementResult {
+  pub id: String,
+  pub role: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+  pub aria_disabled: Signal<Option<bool>>,
+  pub aria_invalid: Signal<Option<bool>>,
+}
+
+/// The props for a hidden input which submits the date in ISO 8601 format,
+/// e.g. `2024-03-04`. The value is empty until every segment is filled.
+#[derive(Clone)]
+pub struct DateFieldHiddenInputResult {
+  pub type_: &'static str,
+  pub name: Option<String>,
+  pub value: Signal<String>,
+  pub disabled: Signal<bool>,
+}
+
+#[derive(Clone)]
+pub struct DateFieldResult {
+  pub field: DateFieldElementResult,
+  pub hidden_input: DateFieldHiddenInputResult,
+  /// The segments to render in order, each editable one set up with
+  /// [`use_date_segment`](crate::use_date_segment).
+  pub segments: Signal<Vec<DateSegment>>,
+}
+
+/// Provides the accessibility implementation for a date field which is made
+/// up of editable segments for the day, month, year and era.
+///
+/// The segments are ordered for the locale, e.g. `mm/dd/yyyy` for `en-US` and
+/// `dd.mm.yyyy` for `de-DE`, and the literal text between them is taken from
+/// `Intl.DateTimeFormat`.
+pub fn use_date_field(
+  cx: Scope,
+  state: &DateFieldState,
+  props: UseDateFieldProps,
+) -> ReadSignal<DateFieldResult> {
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+
+  let aria_invalid = {
+    let state = state.clone();
+    (move || state.is_invalid().then_some(true)).derive_signal(cx)
+  };
+
+  let value = {
+    let state = state.clone();
+    (move || {
+      state
+        .value()
+        .map(|date| date.to_string())
+        .unwrap_or_default()
+    })
+    .derive_signal(cx)
+  };
+
+  let segments = {
+    let state = state.clone();
+    (move || state.segments()).derive_signal(cx)
+  };
+
+  let (date_field_result, _) = create_signal(
+    cx,
+    DateFieldResult {
+      field: DateFieldElementResult {
+        id: state.id.clone(),
+        role: "group",
+        aria_label,
+        aria_labelledby,
+        aria_disabled: state.field.aria_disabled(cx),
+        aria_invalid,
+      },
+      hidden_input: DateFieldHiddenInputResult {
+        type_: "hidden",
+        name: props.name,
+        value,
+        disabled: state.field.is_disabled,
+      },
+      segments,
+    },
+  );
+
+  date_field_result
+}
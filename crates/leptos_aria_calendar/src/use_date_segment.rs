@@ -0,0 +1,238 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::document;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::use_calendar::CalendarCallback;
+use crate::DateFieldState;
+use crate::DateSegmentType;
+
+/// The attribute which identifies the editable segments within a date field.
+pub const DATA_SEGMENT_ATTRIBUTE: &str = "data-segment";
+
+#[derive(TypedBuilder)]
+pub struct UseDateSegmentProps {
+  /// The type of the segment. Literal segments are rendered as plain text.
+  pub segment_type: DateSegmentType,
+}
+
+#[derive(Clone)]
+pub struct DateSegmentResult {
+  pub role: &'static str,
+  pub tab_index: Signal<Option<i32>>,
+  pub aria_label: Signal<String>,
+  pub aria_valuenow: Signal<Option<i32>>,
+  pub aria_valuemin: Signal<i32>,
+  pub aria_valuemax: Signal<i32>,
+  pub aria_valuetext: Signal<String>,
+  pub aria_disabled: Signal<Option<bool>>,
+  pub aria_readonly: Signal<Option<bool>>,
+  /// Shows a numeric keyboard on touch devices.
+  pub input_mode: &'static str,
+  /// The value for the [`DATA_SEGMENT_ATTRIBUTE`].
+  pub data_segment: &'static str,
+  /// The displayed value, or the placeholder while the segment is empty.
+  pub text: Signal<String>,
+  pub is_placeholder: Signal<bool>,
+  pub on_key_down: CalendarCallback<KeyboardEvent>,
+  pub on_blur: CalendarCallback<FocusEvent>,
+}
+
+/// Provides the behavior and accessibility implementation for an editable
+/// segment of a [`use_date_field`](crate::use_date_field).
+///
+/// * <kbd>ArrowUp</kbd> and <kbd>ArrowDown</kbd> increment and decrement the
+///   segment, wrapping around at the limits. <kbd>PageUp</kbd> and
+///   <kbd>PageDown</kbd> move by a larger step.
+/// * <kbd>Home</kbd> and <kbd>End</kbd> move to the smallest and largest value.
+/// * Typing digits enters the value and focus moves to the next segment once no
+///   further digit would fit.
+/// * <kbd>Backspace</kbd> removes the last digit.
+/// * <kbd>ArrowLeft</kbd> and <kbd>ArrowRight</kbd> move between segments,
+///   swapped in right to left locales.
+pub fn use_date_segment(
+  cx: Scope,
+  state: &DateFieldState,
+  props: UseDateSegmentProps,
+) -> ReadSignal<DateSegmentResult> {
+  let segment_type = props.segment_type;
+
+  let aria_valuenow = {
+    let state = state.clone();
+    (move || state.segment_value(segment_type)).derive_signal(cx)
+  };
+
+  let aria_valuemin = {
+    let state = state.clone();
+    (move || state.segment_range(segment_type).0).derive_signal(cx)
+  };
+
+  let aria_valuemax = {
+    let state = state.clone();
+    (move || state.segment_range(segment_type).1).derive_signal(cx)
+  };
+
+  let aria_valuetext = {
+    let state = state.clone();
+    (move || state.segment_value_text(segment_type)).derive_signal(cx)
+  };
+
+  let text = {
+    let state = state.clone();
+
+    (move || {
+      state
+        .segment_text(segment_type)
+        .unwrap_or_else(|| state.segment_placeholder(segment_type))
+    })
+    .derive_signal(cx)
+  };
+
+  let is_placeholder = {
+    let state = state.clone();
+    (move || state.segment_value(segment_type).is_none()).derive_signal(cx)
+  };
+
+  let tab_index = {
+    let is_disabled = state.field.is_disabled;
+    (move || (!is_disabled.get()).then_some(0)).derive_signal(cx)
+  };
+
+  let on_key_down: CalendarCallback<KeyboardEvent> = {
+    let state = state.clone();
+    let direction = use_direction(cx);
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if event.alt_key() || event.ctrl_key() || event.meta_key() {
+        return;
+      }
+
+      let Some(segment) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      else {
+        return;
+      };
+
+      match KeyboardKey::from(&event).logical(direction.get_untracked()) {
+        KeyboardKey::ArrowUp => state.increment(segment_type, 1),
+        KeyboardKey::ArrowDown => state.increment(segment_type, -1),
+        KeyboardKey::PageUp => state.increment(segment_type, page_step(segment_type)),
+        KeyboardKey::PageDown => state.increment(segment_type, -page_step(segment_type)),
+        KeyboardKey::Home if segment_type != DateSegmentType::Year => {
+          state.set_segment_to_limit(segment_type, false)
+        }
+        KeyboardKey::End if segment_type != DateSegmentType::Year => {
+          state.set_segment_to_limit(segment_type, true)
+        }
+        KeyboardKey::ArrowRight => focus_adjacent_segment(&state.id, &segment, true),
+        KeyboardKey::ArrowLeft => focus_adjacent_segment(&state.id, &segment, false),
+        KeyboardKey::Backspace | KeyboardKey::Delete => state.remove_digit(segment_type),
+        KeyboardKey::Character(character) if segment_type != DateSegmentType::Era => {
+          let Some(digit) = character
+            .chars()
+            .next()
+            .and_then(|character| character.to_digit(10))
+          else {
+            return;
+          };
+
+          if state.enter_digit(segment_type, digit) {
+            focus_adjacent_segment(&state.id, &segment, true);
+          }
+        }
+        _ => return,
+      }
+
+      event.prevent_default();
+    }))
+  };
+
+  let on_blur: CalendarCallback<FocusEvent> = {
+    let state = state.clone();
+    Rc::new(Box::new(move |_: FocusEvent| state.end_typing()))
+  };
+
+  let (date_segment_result, _) = create_signal(
+    cx,
+    DateSegmentResult {
+      role: "spinbutton",
+      tab_index,
+      aria_label: {
+        let state = state.clone();
+        (move || state.segment_label(segment_type)).derive_signal(cx)
+      },
+      aria_valuenow,
+      aria_valuemin,
+      aria_valuemax,
+      aria_valuetext,
+      aria_disabled: state.field.aria_disabled(cx),
+      aria_readonly: state.field.aria_readonly(cx, "spinbutton"),
+      input_mode: "numeric",
+      data_segment: segment_type.as_str(),
+      text,
+      is_placeholder,
+      on_key_down,
+      on_blur,
+    },
+  );
+
+  date_segment_result
+}
+
+/// The amount <kbd>PageUp</kbd> and <kbd>PageDown</kbd> change the segment
+/// by.
+fn page_step(segment_type: DateSegmentType) -> i32 {
+  match segment_type {
+    DateSegmentType::Day => 7,
+    DateSegmentType::Month => 2,
+    DateSegmentType::Year => 5,
+    DateSegmentType::Era | DateSegmentType::Literal => 1,
+  }
+}
+
+/// Focus the segment after, or before, the current one within the field.
+fn focus_adjacent_segment(field_id: &str, current: &Element, forward: bool) {
+  let Some(field) = document().get_element_by_id(field_id) else {
+    return;
+  };
+
+  let Ok(segments) = field.query_selector_all(&format!("[{DATA_SEGMENT_ATTRIBUTE}]")) else {
+    return;
+  };
+
+  let segments: Vec<HtmlElement> = (0..segments.length())
+    .filter_map(|index| segments.get(index)?.dyn_into::<HtmlElement>().ok())
+    .collect();
+  let Some(position) = segments
+    .iter()
+    .position(|segment| segment.unchecked_ref::<Element>() == current)
+  else {
+    return;
+  };
+
+  let next = if forward {
+    segments.get(position + 1)
+  } else {
+    position
+      .checked_sub(1)
+      .and_then(|position| segments.get(position))
+  };
+
+  if let Some(next) = next {
+    let _ = next.focus();
+  }
+}
@@ -0,0 +1,129 @@
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::ControlledState;
+use leptos_aria_utils::FieldState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseCheckboxGroupStateProps {
+  /// The current value (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub value: Option<MaybeSignal<Vec<String>>>,
+
+  /// The default value (uncontrolled).
+  #[builder(default)]
+  pub default_value: Vec<String>,
+
+  /// Handler that is called when the value changes.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(Vec<String>)>>,
+
+  /// Whether the checkbox group is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// Whether the value can be seen but not changed by the user.
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+
+  /// Whether at least one checkbox must be selected.
+  #[builder(default, setter(strip_option, into))]
+  pub is_required: Option<MaybeSignal<bool>>,
+
+  /// Whether the current value is invalid.
+  #[builder(default, setter(strip_option, into))]
+  pub is_invalid: Option<MaybeSignal<bool>>,
+}
+
+/// The state of a group of checkboxes.
+#[derive(Clone)]
+pub struct CheckboxGroupState {
+  pub field: FieldState,
+  pub is_required: Signal<bool>,
+  value: ControlledState<Vec<String>>,
+  is_invalid: Signal<bool>,
+}
+
+pub fn use_checkbox_group_state(
+  cx: Scope,
+  props: UseCheckboxGroupStateProps,
+) -> CheckboxGroupState {
+  let field = use_field_state(cx, props.is_disabled, props.is_read_only);
+  let original_is_required = props.is_required.unwrap_or(false.into());
+  let is_required = (move || original_is_required.get()).derive_signal(cx);
+  let original_is_invalid = props.is_invalid.unwrap_or(false.into());
+  let is_invalid = (move || original_is_invalid.get()).derive_signal(cx);
+
+  CheckboxGroupState {
+    field,
+    is_required,
+    value: use_controlled_state(cx, props.value, props.default_value, props.on_change),
+    is_invalid,
+  }
+}
+
+impl CheckboxGroupState {
+  /// The values of the selected checkboxes.
+  pub fn value(&self) -> Vec<String> {
+    self.value.get()
+  }
+
+  /// Whether the checkbox with the provided value is selected.
+  pub fn is_selected(&self, value: &str) -> bool {
+    self
+      .value
+      .signal()
+      .with(|values| values.iter().any(|v| v == value))
+  }
+
+  /// Whether the group is invalid. Validation is skipped when the group is
+  /// disabled or read only.
+  pub fn is_invalid(&self) -> bool {
+    if !self.field.should_validate() {
+      return false;
+    }
+
+    self.is_invalid.get() || (self.is_required.get() && self.value.signal().with(Vec::is_empty))
+  }
+
+  /// Replace the selected values.
+  pub fn set_value(&self, value: Vec<String>) {
+    if !self.field.can_change() {
+      return;
+    }
+
+    self.value.set(value);
+  }
+
+  /// Select the checkbox with the provided value.
+  pub fn add_value(&self, value: &str) {
+    let mut values = self.value.get_untracked();
+
+    if values.iter().any(|v| v == value) {
+      return;
+    }
+
+    values.push(value.to_string());
+    self.set_value(values);
+  }
+
+  /// Deselect the checkbox with the provided value.
+  pub fn remove_value(&self, value: &str) {
+    let mut values = self.value.get_untracked();
+    values.retain(|v| v != value);
+    self.set_value(values);
+  }
+
+  /// Toggle the checkbox with the provided value.
+  pub fn toggle_value(&self, value: &str) {
+    if self.value.get_untracked().iter().any(|v| v == value) {
+      self.remove_value(value);
+    } else {
+      self.add_value(value);
+    }
+  }
+}
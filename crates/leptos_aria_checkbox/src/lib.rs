@@ -0,0 +1,13 @@
+pub use checkbox_group_state::*;
+pub use switch_group_state::*;
+pub use toggle_state::*;
+pub use use_checkbox::*;
+pub use use_checkbox_group::*;
+pub use use_switch_group::*;
+
+mod checkbox_group_state;
+mod switch_group_state;
+mod toggle_state;
+mod use_checkbox;
+mod use_checkbox_group;
+mod use_switch_group;
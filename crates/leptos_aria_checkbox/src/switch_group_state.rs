@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::ControlledState;
+use leptos_aria_utils::FieldState;
+
+/// The on / off values of the switches in a group keyed by their value.
+pub type SwitchValues = BTreeMap<String, bool>;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseSwitchGroupStateProps {
+  /// The current values (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub values: Option<MaybeSignal<SwitchValues>>,
+
+  /// The default values (uncontrolled).
+  #[builder(default)]
+  pub default_values: SwitchValues,
+
+  /// Handler that is called with every value of the group whenever any switch
+  /// changes, so preferences can be saved in one place.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(SwitchValues)>>,
+
+  /// Whether every switch in the group is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// Whether the values can be seen but not changed by the user.
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+
+  /// Whether the group is a single tab stop where the arrow keys move between
+  /// the switches. By default every switch is in the tab order.
+  #[builder(default)]
+  pub is_single_tab_stop: bool,
+}
+
+/// The state of a group of switches such as a preference list.
+#[derive(Clone)]
+pub struct SwitchGroupState {
+  pub field: FieldState,
+  pub is_single_tab_stop: bool,
+  values: ControlledState<SwitchValues>,
+  /// The values of the rendered switches in registration order.
+  items: RwSignal<Vec<String>>,
+  /// The switch which is the tab stop of the group.
+  focused_value: RwSignal<Option<String>>,
+}
+
+pub fn use_switch_group_state(cx: Scope, props: UseSwitchGroupStateProps) -> SwitchGroupState {
+  SwitchGroupState {
+    field: use_field_state(cx, props.is_disabled, props.is_read_only),
+    is_single_tab_stop: props.is_single_tab_stop,
+    values: use_controlled_state(cx, props.values, props.default_values, props.on_change),
+    items: create_rw_signal(cx, vec![]),
+    focused_value: create_rw_signal(cx, None),
+  }
+}
+
+impl SwitchGroupState {
+  /// The values of every switch in the group.
+  pub fn values(&self) -> SwitchValues {
+    self.values.get()
+  }
+
+  /// Whether the switch with the provided value is on.
+  pub fn is_selected(&self, value: &str) -> bool {
+    self
+      .values
+      .signal()
+      .with(|values| values.get(value).copied().unwrap_or(false))
+  }
+
+  /// Turn the switch with the provided value on or off.
+  pub fn set_selected(&self, value: &str, is_selected: bool) {
+    self.set_many([(value.to_string(), is_selected)]);
+  }
+
+  /// Update several switches at once. The `on_change` handler is only called
+  /// once with the combined values.
+  pub fn set_many(&self, changes: impl IntoIterator<Item = (String, bool)>) {
+    if !self.field.can_change() {
+      return;
+    }
+
+    let current = self.values.get_untracked();
+    let mut values = current.clone();
+    values.extend(changes);
+
+    if values != current {
+      self.values.set(values);
+    }
+  }
+
+  /// Whether the switch with the provided value is the tab stop of the group.
+  /// This is the last focused switch or the first switch.
+  pub fn is_tab_stop(&self, value: &str) -> bool {
+    let focused_value = self.focused_value.get();
+
+    self.items.with(|items| {
+      match focused_value.filter(|focused| items.contains(focused)) {
+        Some(focused) => focused == value,
+        None => items.first().map_or(false, |first| first == value),
+      }
+    })
+  }
+
+  pub(crate) fn set_focused_value(&self, value: &str) {
+    if self.focused_value.get_untracked().as_deref() != Some(value) {
+      self.focused_value.set(Some(value.to_string()));
+    }
+  }
+
+  pub(crate) fn register(&self, value: &str) {
+    self.items.update(|items| {
+      if !items.iter().any(|item| item == value) {
+        items.push(value.to_string());
+      }
+    });
+  }
+
+  pub(crate) fn unregister(&self, value: &str) {
+    self
+      .items
+      .update(|items| items.retain(|item| item != value));
+  }
+}
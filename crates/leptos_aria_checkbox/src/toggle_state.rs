@@ -0,0 +1,109 @@
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseToggleStateProps {
+  /// Whether the element should be selected (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub is_selected: Option<MaybeSignal<bool>>,
+
+  /// Whether the element should be selected (uncontrolled).
+  #[builder(default)]
+  pub default_selected: bool,
+
+  /// Handler that is called when the element's selection state changes.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(bool)>>,
+
+  /// Whether the selection state can't be changed by the user.
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+}
+
+/// The selection state of a toggleable element like a checkbox or switch.
+#[derive(Clone)]
+pub struct ToggleState {
+  selected: ControlledState<bool>,
+  is_read_only: Signal<bool>,
+}
+
+pub fn use_toggle_state(cx: Scope, props: UseToggleStateProps) -> ToggleState {
+  let original_is_read_only = props.is_read_only.unwrap_or(false.into());
+
+  ToggleState {
+    selected: use_controlled_state(
+      cx,
+      props.is_selected,
+      props.default_selected,
+      props.on_change,
+    ),
+    is_read_only: (move || original_is_read_only.get()).derive_signal(cx),
+  }
+}
+
+impl ToggleState {
+  /// Whether the element is selected.
+  pub fn is_selected(&self) -> bool {
+    self.selected.get()
+  }
+
+  pub fn is_selected_untracked(&self) -> bool {
+    self.selected.get_untracked()
+  }
+
+  /// Update the selection state. This is ignored when the state is read only.
+  pub fn set_selected(&self, is_selected: bool) {
+    if self.is_read_only.get_untracked() {
+      return;
+    }
+
+    self.selected.set(is_selected);
+  }
+
+  /// Toggle the selection state.
+  pub fn toggle(&self) {
+    self.set_selected(!self.is_selected_untracked());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+
+  #[test]
+  fn toggles_the_selection() {
+    let is_selected = run_scope(create_runtime(), |cx| {
+      let state = use_toggle_state(cx, UseToggleStateProps::default());
+      state.toggle();
+      state.is_selected_untracked()
+    });
+
+    assert!(is_selected);
+  }
+
+  #[test]
+  fn read_only_state_cannot_change() {
+    let is_selected = run_scope(create_runtime(), |cx| {
+      let state = use_toggle_state(
+        cx,
+        UseToggleStateProps::builder()
+          .default_selected(true)
+          .is_read_only(true)
+          .build(),
+      );
+      state.toggle();
+      state.is_selected_untracked()
+    });
+
+    assert!(is_selected);
+  }
+}
@@ -0,0 +1,248 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Event;
+use leptos::web_sys::HtmlInputElement;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::FieldState;
+
+use crate::ToggleState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseCheckboxProps {
+  /// Whether the checkbox is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// Whether the checkbox can be focused but not changed by the user.
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+
+  /// Whether the checkbox must be checked.
+  #[builder(default, setter(strip_option, into))]
+  pub is_required: Option<MaybeSignal<bool>>,
+
+  /// Whether the checkbox value is invalid.
+  #[builder(default, setter(strip_option, into))]
+  pub is_invalid: Option<MaybeSignal<bool>>,
+
+  /// Indeterminism is presentational only. The indeterminate visual
+  /// representation remains regardless of user interaction.
+  #[builder(default, setter(strip_option, into))]
+  pub is_indeterminate: Option<MaybeSignal<bool>>,
+}
+
+/// The props for the `<input type="checkbox">` element.
+#[derive(Clone)]
+pub struct CheckboxResult {
+  pub input_type: &'static str,
+  pub checked: Signal<bool>,
+  /// This must be set as a property on the input element since there is no
+  /// html attribute for it.
+  pub indeterminate: Signal<bool>,
+  pub disabled: Signal<bool>,
+  pub aria_readonly: Signal<Option<bool>>,
+  pub aria_required: Signal<Option<bool>>,
+  pub aria_invalid: Signal<Option<bool>>,
+  /// Prevents the checkbox from toggling when it is read only.
+  pub on_click: CheckboxCallback<MouseEvent>,
+  pub on_change: CheckboxCallback<Event>,
+}
+
+/// Provides the behavior and accessibility implementation for a checkbox
+/// component. Checkboxes allow users to select multiple items from a list of
+/// individual items, or to mark one individual item as selected.
+pub fn use_checkbox(
+  cx: Scope,
+  state: &ToggleState,
+  props: UseCheckboxProps,
+) -> ReadSignal<CheckboxResult> {
+  let field = use_field_state(cx, props.is_disabled, props.is_read_only);
+  let original_is_required = props.is_required.unwrap_or(false.into());
+  let original_is_invalid = props.is_invalid.unwrap_or(false.into());
+  let original_is_indeterminate = props.is_indeterminate.unwrap_or(false.into());
+
+  let checked = {
+    let state = state.clone();
+    (move || state.is_selected()).derive_signal(cx)
+  };
+  let is_invalid = (move || field.should_validate() && original_is_invalid.get()).derive_signal(cx);
+  let set_checked: CheckboxCallback<bool> = {
+    let state = state.clone();
+    Rc::new(Box::new(move |is_checked| state.set_selected(is_checked)))
+  };
+
+  create_checkbox_result(
+    cx,
+    CheckboxOptions {
+      field,
+      checked,
+      indeterminate: (move || original_is_indeterminate.get()).derive_signal(cx),
+      is_required: (move || original_is_required.get()).derive_signal(cx),
+      is_invalid,
+      set_checked,
+    },
+  )
+}
+
+pub(crate) type CheckboxCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+pub(crate) struct CheckboxOptions {
+  pub field: FieldState,
+  pub checked: Signal<bool>,
+  pub indeterminate: Signal<bool>,
+  pub is_required: Signal<bool>,
+  pub is_invalid: Signal<bool>,
+  pub set_checked: CheckboxCallback<bool>,
+}
+
+/// Create the input props shared by standalone checkboxes and checkboxes
+/// within a group.
+pub(crate) fn create_checkbox_result(
+  cx: Scope,
+  options: CheckboxOptions,
+) -> ReadSignal<CheckboxResult> {
+  let CheckboxOptions {
+    field,
+    checked,
+    indeterminate,
+    is_required,
+    is_invalid,
+    set_checked,
+  } = options;
+
+  let on_click: CheckboxCallback<MouseEvent> = {
+    let handler = move |event: MouseEvent| {
+      // Read only checkboxes stay focusable, so the native toggle must be
+      // cancelled rather than relying on the `disabled` attribute.
+      if field.is_read_only.get_untracked() {
+        event.prevent_default();
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let on_change: CheckboxCallback<Event> = {
+    let handler = move |event: Event| {
+      if !field.can_change() {
+        return;
+      }
+
+      let Some(input) = event
+        .target()
+        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+      else {
+        return;
+      };
+
+      set_checked(input.checked());
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let (checkbox_result, _) = create_signal(
+    cx,
+    CheckboxResult {
+      input_type: "checkbox",
+      checked,
+      indeterminate,
+      disabled: field.is_disabled,
+      aria_readonly: field.aria_readonly(cx, "checkbox"),
+      aria_required: (move || is_required.get().then_some(true)).derive_signal(cx),
+      aria_invalid: (move || is_invalid.get().then_some(true)).derive_signal(cx),
+      on_click,
+      on_change,
+    },
+  );
+
+  checkbox_result
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+  use crate::use_toggle_state;
+  use crate::UseToggleStateProps;
+
+  #[test]
+  fn read_only_checkbox_is_announced_but_not_disabled() {
+    let (disabled, aria_readonly) = run_scope(create_runtime(), |cx| {
+      let state = use_toggle_state(cx, UseToggleStateProps::default());
+      let props = UseCheckboxProps::builder().is_read_only(true).build();
+      let result = use_checkbox(cx, &state, props).get_untracked();
+
+      (result.disabled.get(), result.aria_readonly.get())
+    });
+
+    assert!(!disabled);
+    assert_eq!(aria_readonly, Some(true));
+  }
+
+  #[cfg(target_arch = "wasm32")]
+  mod browser {
+    use leptos::document;
+    use leptos::wasm_bindgen::prelude::Closure;
+    use leptos::web_sys::HtmlElement;
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Render an input for the checkbox, click it and return whether the
+    /// checkbox is checked and whether the input could be focused.
+    fn click_checkbox(is_read_only: bool) -> (bool, bool) {
+      run_scope(create_runtime(), move |cx| {
+        let state = use_toggle_state(cx, UseToggleStateProps::default());
+        let props = UseCheckboxProps::builder()
+          .is_read_only(is_read_only)
+          .build();
+        let result = use_checkbox(cx, &state, props).get_untracked();
+        let input: HtmlInputElement = document().create_element("input").unwrap().unchecked_into();
+        input.set_type(result.input_type);
+        document().body().unwrap().append_child(&input).unwrap();
+
+        let on_click = result.on_click.clone();
+        let on_click = Closure::wrap(
+          Box::new(move |event: MouseEvent| on_click(event)) as Box<dyn Fn(MouseEvent)>
+        );
+        let on_change = result.on_change.clone();
+        let on_change =
+          Closure::wrap(Box::new(move |event: Event| on_change(event)) as Box<dyn Fn(Event)>);
+        input
+          .add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())
+          .unwrap();
+        input
+          .add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref())
+          .unwrap();
+
+        input.click();
+        input.unchecked_ref::<HtmlElement>().focus().unwrap();
+        let is_focused = document().active_element() == Some(input.clone().into());
+        input.remove();
+
+        (state.is_selected_untracked(), is_focused)
+      })
+    }
+
+    #[wasm_bindgen_test]
+    fn read_only_checkbox_ignores_clicks_but_stays_focusable() {
+      assert_eq!(click_checkbox(true), (false, true));
+      assert_eq!(click_checkbox(false), (true, true));
+    }
+  }
+}
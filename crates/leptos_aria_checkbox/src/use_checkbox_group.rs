@@ -0,0 +1,133 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::use_field_state;
+
+use crate::use_checkbox::create_checkbox_result;
+use crate::use_checkbox::CheckboxCallback;
+use crate::use_checkbox::CheckboxOptions;
+use crate::CheckboxGroupState;
+use crate::CheckboxResult;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseCheckboxGroupProps {
+  /// An accessible label for the group.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the group.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+}
+
+#[derive(Clone)]
+pub struct CheckboxGroupResult {
+  pub role: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+  pub aria_disabled: Signal<Option<bool>>,
+}
+
+/// Provides the behavior and accessibility implementation for a checkbox group
+/// component. Checkbox groups allow users to select multiple items from a list
+/// of options.
+pub fn use_checkbox_group(
+  cx: Scope,
+  state: &CheckboxGroupState,
+  props: UseCheckboxGroupProps,
+) -> ReadSignal<CheckboxGroupResult> {
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+
+  // `aria-readonly` isn't supported by the `group` role. Each checkbox within
+  // the group announces that it is read only instead.
+  let (checkbox_group_result, _) = create_signal(
+    cx,
+    CheckboxGroupResult {
+      role: "group",
+      aria_label,
+      aria_labelledby,
+      aria_disabled: state.field.aria_disabled(cx),
+    },
+  );
+
+  checkbox_group_result
+}
+
+#[derive(TypedBuilder)]
+pub struct UseCheckboxGroupItemProps {
+  /// The value of the checkbox within the group.
+  #[builder(setter(into))]
+  pub value: String,
+
+  /// Whether this checkbox is disabled. The checkbox is always disabled when
+  /// the group is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+/// Provides the behavior and accessibility implementation for a checkbox
+/// within a checkbox group.
+pub fn use_checkbox_group_item(
+  cx: Scope,
+  state: &CheckboxGroupState,
+  props: UseCheckboxGroupItemProps,
+) -> ReadSignal<CheckboxResult> {
+  let group_field = state.field;
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled: MaybeSignal<bool> =
+    (move || group_field.is_disabled.get() || original_is_disabled.get())
+      .derive_signal(cx)
+      .into();
+  let field = use_field_state(cx, Some(is_disabled), Some(group_field.is_read_only.into()));
+  let value = props.value;
+
+  let checked = {
+    let state = state.clone();
+    let value = value.clone();
+    (move || state.is_selected(&value)).derive_signal(cx)
+  };
+  let is_invalid = {
+    let state = state.clone();
+    (move || state.is_invalid()).derive_signal(cx)
+  };
+  let set_checked: CheckboxCallback<bool> = {
+    let state = state.clone();
+
+    let handler = move |is_checked: bool| {
+      if is_checked {
+        state.add_value(&value);
+      } else {
+        state.remove_value(&value);
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  create_checkbox_result(
+    cx,
+    CheckboxOptions {
+      field,
+      checked,
+      indeterminate: (move || false).derive_signal(cx),
+      // Only one checkbox in a required group needs to be checked so each
+      // checkbox isn't marked as required.
+      is_required: (move || false).derive_signal(cx),
+      is_invalid,
+      set_checked,
+    },
+  )
+}
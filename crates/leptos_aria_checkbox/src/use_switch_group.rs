@@ -0,0 +1,257 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::document;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::use_slot_id;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::use_checkbox::create_checkbox_result;
+use crate::use_checkbox::CheckboxCallback;
+use crate::use_checkbox::CheckboxOptions;
+use crate::CheckboxResult;
+use crate::SwitchGroupState;
+
+/// The attribute used to find the switches of a group when navigating with the
+/// arrow keys.
+pub const DATA_SWITCH_VALUE_ATTRIBUTE: &str = "data-switch-value";
+
+#[derive(TypedBuilder, Default)]
+pub struct UseSwitchGroupProps {
+  /// An accessible label for the group.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the group.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+}
+
+#[derive(Clone)]
+pub struct SwitchGroupResult {
+  pub role: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+  pub aria_disabled: Signal<Option<bool>>,
+  /// Moves focus between the switches when the group is a single tab stop.
+  pub on_key_down: CheckboxCallback<KeyboardEvent>,
+}
+
+/// Provides the behavior and accessibility implementation for a labelled group
+/// of switches, such as a list of preferences.
+///
+/// When the state is a single tab stop only one switch is in the tab order and
+/// the arrow keys, <kbd>Home</kbd> and <kbd>End</kbd> move between the
+/// switches.
+pub fn use_switch_group(
+  cx: Scope,
+  state: &SwitchGroupState,
+  props: UseSwitchGroupProps,
+) -> ReadSignal<SwitchGroupResult> {
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let is_single_tab_stop = state.is_single_tab_stop;
+  let direction = use_direction(cx);
+
+  let on_key_down: CheckboxCallback<KeyboardEvent> = {
+    let handler = move |event: KeyboardEvent| {
+      if !is_single_tab_stop {
+        return;
+      }
+
+      let Some(group) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      else {
+        return;
+      };
+
+      let switches = get_enabled_switches(&group);
+      let current = document().active_element().and_then(|active| {
+        switches
+          .iter()
+          .position(|switch| AsRef::<Element>::as_ref(switch) == &active)
+      });
+
+      let key = KeyboardKey::from(&event).logical(direction.get_untracked());
+      let next = match (key, current) {
+        (KeyboardKey::ArrowDown | KeyboardKey::ArrowRight, Some(index)) => {
+          (index + 1).min(switches.len() - 1)
+        }
+        (KeyboardKey::ArrowUp | KeyboardKey::ArrowLeft, Some(index)) => index.saturating_sub(1),
+        (KeyboardKey::Home, _) => 0,
+        (KeyboardKey::End, _) => switches.len().saturating_sub(1),
+        _ => return,
+      };
+
+      if let Some(switch) = switches.get(next) {
+        event.prevent_default();
+        switch.focus().ok();
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let (switch_group_result, _) = create_signal(
+    cx,
+    SwitchGroupResult {
+      role: "group",
+      aria_label,
+      aria_labelledby,
+      aria_disabled: state.field.aria_disabled(cx),
+      on_key_down,
+    },
+  );
+
+  switch_group_result
+}
+
+#[derive(TypedBuilder)]
+pub struct UseSwitchGroupItemProps {
+  /// The value of the switch within the group.
+  #[builder(setter(into))]
+  pub value: String,
+
+  /// Whether this switch is disabled. The switch is always disabled when the
+  /// group is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+/// The props for the description of a switch.
+#[derive(Clone)]
+pub struct SwitchDescriptionResult {
+  pub id: String,
+}
+
+#[derive(Clone)]
+pub struct SwitchGroupItemResult {
+  /// The props for the `<input type="checkbox">` element.
+  pub input: CheckboxResult,
+  pub role: &'static str,
+  pub data_switch_value: String,
+  /// The id of the description while it is rendered.
+  pub aria_describedby: Signal<Option<String>>,
+  /// `None` when every switch is in the tab order.
+  pub tab_index: Signal<Option<i32>>,
+  pub on_focus: CheckboxCallback<FocusEvent>,
+  pub description: SwitchDescriptionResult,
+}
+
+/// Provides the behavior and accessibility implementation for a switch within
+/// a switch group. The switch is a native checkbox with `role="switch"`.
+pub fn use_switch_group_item(
+  cx: Scope,
+  state: &SwitchGroupState,
+  props: UseSwitchGroupItemProps,
+) -> ReadSignal<SwitchGroupItemResult> {
+  let group_field = state.field;
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled: MaybeSignal<bool> =
+    (move || group_field.is_disabled.get() || original_is_disabled.get())
+      .derive_signal(cx)
+      .into();
+  let field = use_field_state(cx, Some(is_disabled), Some(group_field.is_read_only.into()));
+  let value = props.value;
+  let description = use_slot_id(cx);
+
+  state.register(&value);
+
+  {
+    let state = state.clone();
+    let value = value.clone();
+    on_cleanup(cx, move || state.unregister(&value));
+  }
+
+  let checked = {
+    let state = state.clone();
+    let value = value.clone();
+    (move || state.is_selected(&value)).derive_signal(cx)
+  };
+  let set_checked: CheckboxCallback<bool> = {
+    let state = state.clone();
+    let value = value.clone();
+    Rc::new(Box::new(move |is_checked: bool| {
+      state.set_selected(&value, is_checked)
+    }))
+  };
+
+  let input = create_checkbox_result(
+    cx,
+    CheckboxOptions {
+      field,
+      checked,
+      indeterminate: (move || false).derive_signal(cx),
+      is_required: (move || false).derive_signal(cx),
+      is_invalid: (move || false).derive_signal(cx),
+      set_checked,
+    },
+  )
+  .get_untracked();
+
+  let tab_index = {
+    let state = state.clone();
+    let value = value.clone();
+    let is_single_tab_stop = state.is_single_tab_stop;
+
+    (move || is_single_tab_stop.then(|| if state.is_tab_stop(&value) { 0 } else { -1 }))
+      .derive_signal(cx)
+  };
+
+  let on_focus: CheckboxCallback<FocusEvent> = {
+    let state = state.clone();
+    let value = value.clone();
+    Rc::new(Box::new(move |_: FocusEvent| {
+      state.set_focused_value(&value)
+    }))
+  };
+
+  let (switch_group_item_result, _) = create_signal(
+    cx,
+    SwitchGroupItemResult {
+      input,
+      role: "switch",
+      data_switch_value: value,
+      aria_describedby: description.rendered_id,
+      tab_index,
+      on_focus,
+      description: SwitchDescriptionResult { id: description.id },
+    },
+  );
+
+  switch_group_item_result
+}
+
+/// The enabled switches within the group in DOM order.
+fn get_enabled_switches(group: &Element) -> Vec<HtmlElement> {
+  let Ok(nodes) = group.query_selector_all(&format!("[{DATA_SWITCH_VALUE_ATTRIBUTE}]")) else {
+    return vec![];
+  };
+
+  (0..nodes.length())
+    .filter_map(|index| nodes.item(index))
+    .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+    .filter(|element| !element.has_attribute("disabled"))
+    .collect()
+}
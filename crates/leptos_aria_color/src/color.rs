@@ -0,0 +1,649 @@
+use std::fmt;
+
+use leptos_aria_i18n::LocalizedStringFormatter;
+
+/// The color space a [`Color`] is defined in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+  Rgb,
+  Hsl,
+  Hsb,
+}
+
+/// A channel of a color which can be adjusted by a color widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorChannel {
+  Red,
+  Green,
+  Blue,
+  Hue,
+  Saturation,
+  Lightness,
+  Brightness,
+  Alpha,
+}
+
+impl ColorChannel {
+  /// The key of the localized name of the channel.
+  pub fn key(&self) -> &'static str {
+    match self {
+      Self::Red => "red",
+      Self::Green => "green",
+      Self::Blue => "blue",
+      Self::Hue => "hue",
+      Self::Saturation => "saturation",
+      Self::Lightness => "lightness",
+      Self::Brightness => "brightness",
+      Self::Alpha => "alpha",
+    }
+  }
+
+  /// The name of the channel in the locale of the `strings`, used as the
+  /// accessible label of the widget.
+  pub fn name(&self, strings: &LocalizedStringFormatter) -> String {
+    strings.format(self.key())
+  }
+
+  /// The range of values for the channel.
+  pub fn range(&self) -> ColorChannelRange {
+    match self {
+      Self::Red | Self::Green | Self::Blue => {
+        ColorChannelRange {
+          min: 0.0,
+          max: 255.0,
+          step: 1.0,
+          page_step: 17.0,
+        }
+      }
+      Self::Hue => {
+        ColorChannelRange {
+          min: 0.0,
+          max: 360.0,
+          step: 1.0,
+          page_step: 15.0,
+        }
+      }
+      Self::Saturation | Self::Lightness | Self::Brightness => {
+        ColorChannelRange {
+          min: 0.0,
+          max: 100.0,
+          step: 1.0,
+          page_step: 10.0,
+        }
+      }
+      Self::Alpha => {
+        ColorChannelRange {
+          min: 0.0,
+          max: 1.0,
+          step: 0.01,
+          page_step: 0.1,
+        }
+      }
+    }
+  }
+
+  /// The color space the channel is read from when the color is in another
+  /// space, e.g. `Red` from an HSL color.
+  fn default_space(&self) -> Option<ColorSpace> {
+    match self {
+      Self::Red | Self::Green | Self::Blue => Some(ColorSpace::Rgb),
+      Self::Lightness => Some(ColorSpace::Hsl),
+      Self::Hue | Self::Saturation | Self::Brightness => Some(ColorSpace::Hsb),
+      Self::Alpha => None,
+    }
+  }
+}
+
+/// The limits and step sizes of a color channel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorChannelRange {
+  pub min: f64,
+  pub max: f64,
+  /// The amount the arrow keys change the value by.
+  pub step: f64,
+  /// The amount <kbd>PageUp</kbd> and <kbd>PageDown</kbd> change the value by.
+  pub page_step: f64,
+}
+
+/// A color with an alpha channel from `0` to `1`.
+///
+/// * RGB channels are from `0` to `255`.
+/// * Hue is in degrees from `0` to `360`.
+/// * Saturation, lightness and brightness are percentages from `0` to `100`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Color {
+  Rgb {
+    red: f64,
+    green: f64,
+    blue: f64,
+    alpha: f64,
+  },
+  Hsl {
+    hue: f64,
+    saturation: f64,
+    lightness: f64,
+    alpha: f64,
+  },
+  Hsb {
+    hue: f64,
+    saturation: f64,
+    brightness: f64,
+    alpha: f64,
+  },
+}
+
+impl Default for Color {
+  fn default() -> Self {
+    Self::Rgb {
+      red: 0.0,
+      green: 0.0,
+      blue: 0.0,
+      alpha: 1.0,
+    }
+  }
+}
+
+impl Color {
+  /// Parse a hex color (`#rgb`, `#rrggbb` or `#rrggbbaa`) or a CSS style
+  /// `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hsb()` or `hsba()` color.
+  pub fn parse(value: &str) -> Option<Self> {
+    let value = value.trim();
+
+    if let Some(hex) = value.strip_prefix('#') {
+      return Self::parse_hex(hex);
+    }
+
+    let (name, arguments) = value.strip_suffix(')')?.split_once('(')?;
+    let arguments: Vec<&str> = arguments
+      .split(|character| character == ',' || character == '/' || character == ' ')
+      .filter(|argument| !argument.is_empty())
+      .collect();
+
+    if arguments.len() < 3 || arguments.len() > 4 {
+      return None;
+    }
+
+    let number = |index: usize| -> Option<f64> {
+      let argument = arguments[index].trim_end_matches(['%', '°']);
+      argument
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+    };
+    let alpha = match arguments.get(3) {
+      Some(alpha) if alpha.ends_with('%') => number(3)? / 100.0,
+      Some(_) => number(3)?,
+      None => 1.0,
+    }
+    .clamp(0.0, 1.0);
+
+    let color = match name.trim().to_ascii_lowercase().as_str() {
+      "rgb" | "rgba" => {
+        Self::Rgb {
+          red: number(0)?.clamp(0.0, 255.0),
+          green: number(1)?.clamp(0.0, 255.0),
+          blue: number(2)?.clamp(0.0, 255.0),
+          alpha,
+        }
+      }
+      "hsl" | "hsla" => {
+        Self::Hsl {
+          hue: number(0)?.rem_euclid(360.0),
+          saturation: number(1)?.clamp(0.0, 100.0),
+          lightness: number(2)?.clamp(0.0, 100.0),
+          alpha,
+        }
+      }
+      "hsb" | "hsba" => {
+        Self::Hsb {
+          hue: number(0)?.rem_euclid(360.0),
+          saturation: number(1)?.clamp(0.0, 100.0),
+          brightness: number(2)?.clamp(0.0, 100.0),
+          alpha,
+        }
+      }
+      _ => return None,
+    };
+
+    Some(color)
+  }
+
+  /// Parse the digits of a 3, 6 or 8 digit hex color without the `#`.
+  pub fn parse_hex(hex: &str) -> Option<Self> {
+    if !hex.chars().all(|character| character.is_ascii_hexdigit()) {
+      return None;
+    }
+
+    let channel = |index: usize, size: usize| -> Option<f64> {
+      let digits = hex.get(index * size..index * size + size)?;
+      let value = u8::from_str_radix(digits, 16).ok()?;
+      Some(f64::from(if size == 1 { value * 17 } else { value }))
+    };
+
+    let (size, has_alpha) = match hex.len() {
+      3 => (1, false),
+      6 => (2, false),
+      8 => (2, true),
+      _ => return None,
+    };
+
+    Some(Self::Rgb {
+      red: channel(0, size)?,
+      green: channel(1, size)?,
+      blue: channel(2, size)?,
+      alpha: if has_alpha {
+        channel(3, size)? / 255.0
+      } else {
+        1.0
+      },
+    })
+  }
+
+  /// An opaque color from a number such as `0xff0000`.
+  pub fn from_hex_int(value: u32) -> Self {
+    Self::Rgb {
+      red: f64::from((value >> 16) & 0xff),
+      green: f64::from((value >> 8) & 0xff),
+      blue: f64::from(value & 0xff),
+      alpha: 1.0,
+    }
+  }
+
+  /// The RGB channels as a number, e.g. `0xff0000` for red. The alpha channel
+  /// is ignored.
+  pub fn to_hex_int(&self) -> u32 {
+    let Self::Rgb {
+      red, green, blue, ..
+    } = self.to_space(ColorSpace::Rgb)
+    else {
+      unreachable!();
+    };
+
+    ((red.round() as u32) << 16) | ((green.round() as u32) << 8) | blue.round() as u32
+  }
+
+  /// The color as `#RRGGBB`, or `#RRGGBBAA` when it is transparent.
+  pub fn to_hex(&self) -> String {
+    let hex = format!("#{:06X}", self.to_hex_int());
+    let alpha = self.alpha();
+
+    if alpha < 1.0 {
+      format!("{hex}{:02X}", (alpha * 255.0).round() as u8)
+    } else {
+      hex
+    }
+  }
+
+  pub fn color_space(&self) -> ColorSpace {
+    match self {
+      Self::Rgb { .. } => ColorSpace::Rgb,
+      Self::Hsl { .. } => ColorSpace::Hsl,
+      Self::Hsb { .. } => ColorSpace::Hsb,
+    }
+  }
+
+  /// The channels of the color space, excluding alpha.
+  pub fn channels(&self) -> [ColorChannel; 3] {
+    match self {
+      Self::Rgb { .. } => [ColorChannel::Red, ColorChannel::Green, ColorChannel::Blue],
+      Self::Hsl { .. } => {
+        [
+          ColorChannel::Hue,
+          ColorChannel::Saturation,
+          ColorChannel::Lightness,
+        ]
+      }
+      Self::Hsb { .. } => {
+        [
+          ColorChannel::Hue,
+          ColorChannel::Saturation,
+          ColorChannel::Brightness,
+        ]
+      }
+    }
+  }
+
+  pub fn alpha(&self) -> f64 {
+    match *self {
+      Self::Rgb { alpha, .. } | Self::Hsl { alpha, .. } | Self::Hsb { alpha, .. } => alpha,
+    }
+  }
+
+  /// Convert the color to another color space.
+  pub fn to_space(&self, space: ColorSpace) -> Self {
+    match (*self, space) {
+      (Self::Rgb { .. }, ColorSpace::Rgb)
+      | (Self::Hsl { .. }, ColorSpace::Hsl)
+      | (Self::Hsb { .. }, ColorSpace::Hsb) => *self,
+      (
+        Self::Hsl {
+          hue,
+          saturation,
+          lightness,
+          alpha,
+        },
+        ColorSpace::Hsb,
+      ) => {
+        let (saturation, lightness) = (saturation / 100.0, lightness / 100.0);
+        let brightness = lightness + saturation * lightness.min(1.0 - lightness);
+        let saturation = if brightness == 0.0 {
+          0.0
+        } else {
+          2.0 * (1.0 - lightness / brightness)
+        };
+
+        Self::Hsb {
+          hue,
+          saturation: saturation * 100.0,
+          brightness: brightness * 100.0,
+          alpha,
+        }
+      }
+      (
+        Self::Hsb {
+          hue,
+          saturation,
+          brightness,
+          alpha,
+        },
+        ColorSpace::Hsl,
+      ) => {
+        let (saturation, brightness) = (saturation / 100.0, brightness / 100.0);
+        let lightness = brightness * (1.0 - saturation / 2.0);
+        let saturation = if lightness == 0.0 || lightness == 1.0 {
+          0.0
+        } else {
+          (brightness - lightness) / lightness.min(1.0 - lightness)
+        };
+
+        Self::Hsl {
+          hue,
+          saturation: saturation * 100.0,
+          lightness: lightness * 100.0,
+          alpha,
+        }
+      }
+      (
+        Self::Hsl {
+          hue,
+          saturation,
+          lightness,
+          alpha,
+        },
+        ColorSpace::Rgb,
+      ) => {
+        let (saturation, lightness) = (saturation / 100.0, lightness / 100.0);
+        let amount = saturation * lightness.min(1.0 - lightness);
+        let channel = |n: f64| {
+          let k = (n + hue / 30.0) % 12.0;
+          (lightness - amount * (k - 3.0).min(9.0 - k).min(1.0).max(-1.0)) * 255.0
+        };
+
+        Self::Rgb {
+          red: channel(0.0),
+          green: channel(8.0),
+          blue: channel(4.0),
+          alpha,
+        }
+      }
+      (
+        Self::Hsb {
+          hue,
+          saturation,
+          brightness,
+          alpha,
+        },
+        ColorSpace::Rgb,
+      ) => {
+        let (saturation, brightness) = (saturation / 100.0, brightness / 100.0);
+        let channel = |n: f64| {
+          let k = (n + hue / 60.0) % 6.0;
+          (brightness - brightness * saturation * k.min(4.0 - k).min(1.0).max(0.0)) * 255.0
+        };
+
+        Self::Rgb {
+          red: channel(5.0),
+          green: channel(3.0),
+          blue: channel(1.0),
+          alpha,
+        }
+      }
+      (
+        Self::Rgb {
+          red,
+          green,
+          blue,
+          alpha,
+        },
+        space,
+      ) => {
+        let (red, green, blue) = (red / 255.0, green / 255.0, blue / 255.0);
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let delta = max - min;
+        let hue = if delta == 0.0 {
+          0.0
+        } else if max == red {
+          60.0 * ((green - blue) / delta).rem_euclid(6.0)
+        } else if max == green {
+          60.0 * ((blue - red) / delta + 2.0)
+        } else {
+          60.0 * ((red - green) / delta + 4.0)
+        };
+
+        if space == ColorSpace::Hsb {
+          Self::Hsb {
+            hue,
+            saturation: if max == 0.0 { 0.0 } else { delta / max * 100.0 },
+            brightness: max * 100.0,
+            alpha,
+          }
+        } else {
+          let lightness = (max + min) / 2.0;
+          let saturation = if delta == 0.0 {
+            0.0
+          } else {
+            delta / (1.0 - (2.0 * lightness - 1.0).abs())
+          };
+
+          Self::Hsl {
+            hue,
+            saturation: saturation * 100.0,
+            lightness: lightness * 100.0,
+            alpha,
+          }
+        }
+      }
+    }
+  }
+
+  /// The value of the channel. Channels of other color spaces are read from
+  /// the converted color.
+  pub fn channel_value(&self, channel: ColorChannel) -> f64 {
+    let color = self.color_for_channel(channel);
+
+    match (color, channel) {
+      (_, ColorChannel::Alpha) => color.alpha(),
+      (Self::Rgb { red, .. }, ColorChannel::Red) => red,
+      (Self::Rgb { green, .. }, ColorChannel::Green) => green,
+      (Self::Rgb { blue, .. }, ColorChannel::Blue) => blue,
+      (Self::Hsl { hue, .. } | Self::Hsb { hue, .. }, ColorChannel::Hue) => hue,
+      (Self::Hsl { saturation, .. } | Self::Hsb { saturation, .. }, ColorChannel::Saturation) => {
+        saturation
+      }
+      (Self::Hsl { lightness, .. }, ColorChannel::Lightness) => lightness,
+      (Self::Hsb { brightness, .. }, ColorChannel::Brightness) => brightness,
+      _ => 0.0,
+    }
+  }
+
+  /// A copy of the color with the channel set to the value, clamped to the
+  /// range of the channel. The color space is preserved.
+  pub fn with_channel_value(&self, channel: ColorChannel, value: f64) -> Self {
+    let range = channel.range();
+    let value = value.clamp(range.min, range.max);
+    let mut color = self.color_for_channel(channel);
+
+    match (&mut color, channel) {
+      (
+        Self::Rgb { alpha, .. } | Self::Hsl { alpha, .. } | Self::Hsb { alpha, .. },
+        ColorChannel::Alpha,
+      ) => *alpha = value,
+      (Self::Rgb { red, .. }, ColorChannel::Red) => *red = value,
+      (Self::Rgb { green, .. }, ColorChannel::Green) => *green = value,
+      (Self::Rgb { blue, .. }, ColorChannel::Blue) => *blue = value,
+      (Self::Hsl { hue, .. } | Self::Hsb { hue, .. }, ColorChannel::Hue) => *hue = value,
+      (Self::Hsl { saturation, .. } | Self::Hsb { saturation, .. }, ColorChannel::Saturation) => {
+        *saturation = value
+      }
+      (Self::Hsl { lightness, .. }, ColorChannel::Lightness) => *lightness = value,
+      (Self::Hsb { brightness, .. }, ColorChannel::Brightness) => *brightness = value,
+      _ => {}
+    }
+
+    color.to_space(self.color_space())
+  }
+
+  /// The value of the channel for `aria-valuetext`, e.g. `"50%"` or `"120°"`.
+  pub fn format_channel_value(&self, channel: ColorChannel) -> String {
+    let value = self.channel_value(channel);
+
+    match channel {
+      ColorChannel::Hue => format!("{}°", value.round()),
+      ColorChannel::Saturation | ColorChannel::Lightness | ColorChannel::Brightness => {
+        format!("{}%", value.round())
+      }
+      ColorChannel::Alpha => format!("{}%", (value * 100.0).round()),
+      ColorChannel::Red | ColorChannel::Green | ColorChannel::Blue => value.round().to_string(),
+    }
+  }
+
+  /// The color in the space the channel belongs to.
+  fn color_for_channel(&self, channel: ColorChannel) -> Self {
+    if self.channels().contains(&channel) {
+      return *self;
+    }
+
+    match channel.default_space() {
+      Some(space) => self.to_space(space),
+      None => *self,
+    }
+  }
+}
+
+/// Formats the color as a CSS color, e.g. `rgba(255, 0, 0, 1)`. HSB colors
+/// are converted to HSL since CSS doesn't support them.
+impl fmt::Display for Color {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.to_space(match self.color_space() {
+      ColorSpace::Rgb => ColorSpace::Rgb,
+      _ => ColorSpace::Hsl,
+    }) {
+      Self::Rgb {
+        red,
+        green,
+        blue,
+        alpha,
+      } => {
+        write!(
+          f,
+          "rgba({}, {}, {}, {alpha})",
+          red.round(),
+          green.round(),
+          blue.round()
+        )
+      }
+      Self::Hsl {
+        hue,
+        saturation,
+        lightness,
+        alpha,
+      } => {
+        write!(
+          f,
+          "hsla({}, {}%, {}%, {alpha})",
+          hue.round(),
+          saturation.round(),
+          lightness.round()
+        )
+      }
+      Self::Hsb { .. } => unreachable!(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_colors() {
+    assert_eq!(
+      Color::parse("#f00").map(|color| color.to_hex_int()),
+      Some(0xff0000)
+    );
+    assert_eq!(
+      Color::parse("#00ff0080").map(|color| color.to_hex()),
+      Some("#00FF0080".into())
+    );
+    assert_eq!(
+      Color::parse("hsl(120, 100%, 50%)").map(|color| color.to_hex_int()),
+      Some(0x00ff00)
+    );
+    assert_eq!(
+      Color::parse("rgb(0 0 255 / 50%)").map(|color| color.alpha()),
+      Some(0.5)
+    );
+    assert_eq!(Color::parse("#ff00"), None);
+    assert_eq!(Color::parse("#ggg"), None);
+    assert_eq!(Color::parse("cmyk(0, 0, 0, 0)"), None);
+  }
+
+  #[test]
+  fn converts_between_spaces() {
+    let orange = Color::from_hex_int(0xff8000);
+
+    let hsb = orange.to_space(ColorSpace::Hsb);
+    assert_eq!(hsb.channel_value(ColorChannel::Hue).round(), 30.0);
+    assert_eq!(hsb.channel_value(ColorChannel::Saturation).round(), 100.0);
+    assert_eq!(hsb.channel_value(ColorChannel::Brightness).round(), 100.0);
+    assert_eq!(hsb.to_hex_int(), 0xff8000);
+
+    let hsl = hsb.to_space(ColorSpace::Hsl);
+    assert_eq!(hsl.channel_value(ColorChannel::Lightness).round(), 50.0);
+    assert_eq!(hsl.to_space(ColorSpace::Hsb).to_hex_int(), 0xff8000);
+  }
+
+  #[test]
+  fn updates_channels() {
+    let red = Color::from_hex_int(0xff0000);
+
+    let blue = red.with_channel_value(ColorChannel::Hue, 240.0);
+    assert_eq!(blue.color_space(), ColorSpace::Rgb);
+    assert_eq!(blue.to_hex_int(), 0x0000ff);
+    assert_eq!(
+      red
+        .with_channel_value(ColorChannel::Red, 300.0)
+        .to_hex_int(),
+      0xff0000
+    );
+    assert_eq!(red.format_channel_value(ColorChannel::Hue), "0°");
+    assert_eq!(
+      red
+        .with_channel_value(ColorChannel::Alpha, 0.5)
+        .format_channel_value(ColorChannel::Alpha),
+      "50%"
+    );
+  }
+
+  #[test]
+  fn localizes_channel_names() {
+    let strings = LocalizedStringFormatter::new("de-DE", None);
+
+    assert_eq!(ColorChannel::Hue.name(&strings), "Farbton");
+    assert_eq!(
+      ColorChannel::Red.name(&LocalizedStringFormatter::new("en-US", None)),
+      "Red"
+    );
+  }
+}
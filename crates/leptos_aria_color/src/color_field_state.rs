@@ -0,0 +1,233 @@
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+
+use crate::Color;
+use crate::ColorChannel;
+
+/// The largest value of a color field, `#FFFFFF`.
+pub const MAX_COLOR_INT: u32 = 0xffffff;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseColorFieldStateProps {
+  /// The current color (controlled). `None` when the field is empty.
+  #[builder(default, setter(strip_option, into))]
+  pub value: Option<MaybeSignal<Option<Color>>>,
+
+  /// The initial color (uncontrolled).
+  #[builder(default, setter(strip_option))]
+  pub default_value: Option<Color>,
+
+  /// The amount the numeric value of the color changes with each step.
+  /// Defaults to `1`.
+  #[builder(default, setter(strip_option))]
+  pub step: Option<u32>,
+
+  /// Handler that is called when the color is committed, either by a step
+  /// or once the typed text is committed.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(Option<Color>)>>,
+}
+
+/// The value of a color field.
+///
+/// The color is edited as hex text. The typed text is kept separately from
+/// the color and only parsed once it is committed, so partial input such as
+/// `#ff` never changes the color.
+#[derive(Clone)]
+pub struct ColorFieldState {
+  pub step: u32,
+  value: ControlledState<Option<Color>>,
+  typed_text: RwSignal<Option<String>>,
+}
+
+pub fn use_color_field_state(cx: Scope, props: UseColorFieldStateProps) -> ColorFieldState {
+  ColorFieldState {
+    step: props.step.unwrap_or(1).max(1),
+    value: use_controlled_state(cx, props.value, props.default_value, props.on_change),
+    typed_text: create_rw_signal(cx, None),
+  }
+}
+
+impl ColorFieldState {
+  pub fn value(&self) -> Option<Color> {
+    self.value.get()
+  }
+
+  pub fn value_untracked(&self) -> Option<Color> {
+    self.value.get_untracked()
+  }
+
+  /// The text of the input, which is the typed text while editing and the
+  /// hex value of the color otherwise.
+  pub fn input_value(&self) -> String {
+    self.typed_text.get().unwrap_or_else(|| {
+      self
+        .value
+        .get()
+        .map(|color| color.to_hex())
+        .unwrap_or_default()
+    })
+  }
+
+  /// Whether the input holds typed text which hasn't been committed yet.
+  pub fn is_editing(&self) -> bool {
+    self.typed_text.with_untracked(Option::is_some)
+  }
+
+  /// Whether the input currently holds text which can't be parsed as a color.
+  pub fn is_input_invalid(&self) -> bool {
+    self.typed_text.get().map_or(false, |text| {
+      !text.trim().is_empty() && parse_hex_input(&text).is_none()
+    })
+  }
+
+  /// Update the typed text. Returns `false` when the text can never become a
+  /// valid hex color, in which case it is rejected.
+  pub fn set_input_value(&self, text: String) -> bool {
+    if !is_valid_partial_hex(&text) {
+      return false;
+    }
+
+    self.typed_text.set(Some(text));
+    true
+  }
+
+  /// Parse the typed text. Empty text clears the color and invalid text is
+  /// discarded so the previous color is shown again.
+  pub fn commit(&self) {
+    let Some(text) = self.typed_text.get_untracked() else {
+      return;
+    };
+
+    self.typed_text.set(None);
+
+    if text.trim().is_empty() {
+      self.value.set(None);
+    } else if let Some(color) = parse_hex_input(&text) {
+      self.value.set(Some(color));
+    }
+  }
+
+  /// Whether the color can be incremented.
+  pub fn can_increment(&self) -> bool {
+    self
+      .value
+      .get()
+      .map_or(true, |color| color.to_hex_int() < MAX_COLOR_INT)
+  }
+
+  /// Whether the color can be decremented.
+  pub fn can_decrement(&self) -> bool {
+    self
+      .value
+      .get()
+      .map_or(false, |color| color.to_hex_int() > 0)
+  }
+
+  /// Move the numeric value of the color by `steps` steps, clamped to
+  /// `#000000` and `#FFFFFF`. An empty field starts from `#000000`. The
+  /// alpha channel is preserved.
+  pub fn step_by(&self, steps: i64) {
+    let current = self.value.get_untracked();
+    let value = current.map_or(0, |color| color.to_hex_int());
+    let alpha = current.map_or(1.0, |color| color.alpha());
+    let next = if current.is_none() {
+      0
+    } else {
+      step_hex_int(value, steps * i64::from(self.step))
+    };
+
+    self.set_hex_int(next, alpha);
+  }
+
+  pub fn increment(&self) {
+    self.step_by(1);
+  }
+
+  pub fn decrement(&self) {
+    self.step_by(-1);
+  }
+
+  pub fn increment_to_max(&self) {
+    let alpha = self
+      .value
+      .get_untracked()
+      .map_or(1.0, |color| color.alpha());
+    self.set_hex_int(MAX_COLOR_INT, alpha);
+  }
+
+  pub fn decrement_to_min(&self) {
+    let alpha = self
+      .value
+      .get_untracked()
+      .map_or(1.0, |color| color.alpha());
+    self.set_hex_int(0, alpha);
+  }
+
+  fn set_hex_int(&self, value: u32, alpha: f64) {
+    self.typed_text.set(None);
+    self.value.set(Some(
+      Color::from_hex_int(value).with_channel_value(ColorChannel::Alpha, alpha),
+    ));
+  }
+}
+
+/// Parse the text of a color field, with or without the leading `#`.
+fn parse_hex_input(text: &str) -> Option<Color> {
+  let text = text.trim();
+  Color::parse_hex(text.strip_prefix('#').unwrap_or(text))
+}
+
+/// Whether the text could become a valid hex color by typing more digits.
+fn is_valid_partial_hex(text: &str) -> bool {
+  let text = text.trim();
+  let digits = text.strip_prefix('#').unwrap_or(text);
+
+  digits.len() <= 8
+    && digits
+      .chars()
+      .all(|character| character.is_ascii_hexdigit())
+}
+
+fn step_hex_int(value: u32, amount: i64) -> u32 {
+  (i64::from(value) + amount).clamp(0, i64::from(MAX_COLOR_INT)) as u32
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn accepts_partial_hex_input() {
+    assert!(is_valid_partial_hex(""));
+    assert!(is_valid_partial_hex("#"));
+    assert!(is_valid_partial_hex("#fF0"));
+    assert!(is_valid_partial_hex("ff00ff80"));
+    assert!(!is_valid_partial_hex("#ff00ff800"));
+    assert!(!is_valid_partial_hex("#ggg"));
+    assert!(!is_valid_partial_hex("##f"));
+  }
+
+  #[test]
+  fn parses_hex_input() {
+    let hex = |text: &str| parse_hex_input(text).map(|color| color.to_hex());
+
+    assert_eq!(hex("#f00"), Some("#FF0000".to_string()));
+    assert_eq!(hex(" 00ff00 "), Some("#00FF00".to_string()));
+    assert_eq!(hex("#0000ff80"), Some("#0000FF80".to_string()));
+    assert_eq!(hex("#ff00"), None);
+  }
+
+  #[test]
+  fn clamps_steps() {
+    assert_eq!(step_hex_int(0x000000, -1), 0);
+    assert_eq!(step_hex_int(0x0000ff, 1), 0x000100);
+    assert_eq!(step_hex_int(MAX_COLOR_INT, 16), MAX_COLOR_INT);
+  }
+}
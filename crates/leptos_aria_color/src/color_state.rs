@@ -0,0 +1,125 @@
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+
+use crate::Color;
+use crate::ColorChannel;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseColorStateProps {
+  /// The current color (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub value: Option<MaybeSignal<Color>>,
+
+  /// The initial color (uncontrolled).
+  #[builder(default, setter(strip_option))]
+  pub default_value: Option<Color>,
+
+  /// Handler that is called whenever the color changes, including while
+  /// dragging.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(Color)>>,
+
+  /// Handler that is called once the user stops changing the color, i.e.
+  /// when dragging ends or after a keyboard change.
+  #[builder(default, setter(strip_option))]
+  pub on_change_end: Option<Box<dyn Fn(Color)>>,
+}
+
+/// The color edited by a color slider, area or wheel.
+#[derive(Clone)]
+pub struct ColorState {
+  value: ControlledState<Color>,
+  is_dragging: RwSignal<bool>,
+  on_change_end: Option<Rc<Box<dyn Fn(Color)>>>,
+}
+
+pub fn use_color_state(cx: Scope, props: UseColorStateProps) -> ColorState {
+  ColorState {
+    value: use_controlled_state(
+      cx,
+      props.value,
+      props.default_value.unwrap_or_default(),
+      props.on_change,
+    ),
+    is_dragging: create_rw_signal(cx, false),
+    on_change_end: props.on_change_end.map(Rc::new),
+  }
+}
+
+impl ColorState {
+  pub fn value(&self) -> Color {
+    self.value.get()
+  }
+
+  pub fn value_untracked(&self) -> Color {
+    self.value.get_untracked()
+  }
+
+  pub fn set_value(&self, color: Color) {
+    self.value.set(color);
+  }
+
+  /// Set the channel, snapped to its step.
+  pub fn set_channel_value(&self, channel: ColorChannel, value: f64) {
+    let step = channel.range().step;
+    let value = (value / step).round() * step;
+    self.set_value(self.value_untracked().with_channel_value(channel, value));
+  }
+
+  /// Move the channel by `amount`. The hue wraps around, other channels stop
+  /// at their limits.
+  pub fn increment_channel(&self, channel: ColorChannel, amount: f64) {
+    let range = channel.range();
+    let mut value = self.value_untracked().channel_value(channel) + amount;
+
+    if channel == ColorChannel::Hue {
+      value = value.rem_euclid(range.max);
+    }
+
+    self.set_channel_value(channel, value);
+  }
+
+  /// The channel value as a fraction between `0` and `1` of its range.
+  pub fn channel_percent(&self, channel: ColorChannel) -> f64 {
+    let range = channel.range();
+    (self.value().channel_value(channel) - range.min) / (range.max - range.min)
+  }
+
+  /// Set the channel to the value at the fraction of its range.
+  pub fn set_channel_percent(&self, channel: ColorChannel, percent: f64) {
+    let range = channel.range();
+    self.set_channel_value(
+      channel,
+      range.min + percent.clamp(0.0, 1.0) * (range.max - range.min),
+    );
+  }
+
+  pub fn is_dragging(&self) -> bool {
+    self.is_dragging.get()
+  }
+
+  /// Update the dragging state. `on_change_end` is called once dragging
+  /// stops.
+  pub fn set_dragging(&self, is_dragging: bool) {
+    let was_dragging = self.is_dragging.get_untracked();
+    self.is_dragging.set(is_dragging);
+
+    if was_dragging && !is_dragging {
+      self.change_end();
+    }
+  }
+
+  /// Notify `on_change_end` of the current color.
+  pub fn change_end(&self) {
+    if let Some(ref on_change_end) = self.on_change_end {
+      on_change_end(self.value_untracked());
+    }
+  }
+}
@@ -0,0 +1,129 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use leptos::document;
+use leptos::web_sys::Element;
+use leptos::web_sys::PointerEvent;
+use leptos::JsCast;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_interactions::use_move;
+use leptos_aria_interactions::MoveEvent;
+use leptos_aria_interactions::UseMoveProps;
+use leptos_aria_utils::GlobalListeners;
+
+use crate::ColorCallback;
+use crate::ColorState;
+
+/// The pointer position relative to the top left corner of the element it
+/// was pressed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct DragPosition {
+  pub x: f64,
+  pub y: f64,
+  pub width: f64,
+  pub height: f64,
+}
+
+impl DragPosition {
+  /// The position as fractions of the element size.
+  pub fn percent(&self) -> (f64, f64) {
+    let percent = |value: f64, size: f64| {
+      if size > 0.0 {
+        (value / size).clamp(0.0, 1.0)
+      } else {
+        0.0
+      }
+    };
+
+    (percent(self.x, self.width), percent(self.y, self.height))
+  }
+}
+
+/// Tracks the pointer position while dragging over a color widget with
+/// [`use_move`]. The position is updated when the pointer is pressed and for
+/// every movement, and `on_change_end` is called once the pointer is
+/// released, whether or not it moved.
+///
+/// Returns the handler for the `pointerdown` event of the element.
+pub(crate) fn use_drag_position(
+  cx: Scope,
+  state: &ColorState,
+  should_start: impl Fn(&DragPosition) -> bool + 'static,
+  on_position: impl Fn(&DragPosition) + 'static,
+) -> ColorCallback<PointerEvent> {
+  let position = Rc::new(Cell::new(DragPosition::default()));
+  let on_position = Rc::new(on_position);
+
+  let on_move = {
+    let position = position.clone();
+    let on_position = on_position.clone();
+
+    move |event: &MoveEvent| {
+      let mut current = position.get();
+      current.x += event.delta_x;
+      current.y += event.delta_y;
+      position.set(current);
+      on_position(&current);
+    }
+  };
+
+  let move_result = use_move(
+    cx,
+    UseMoveProps::builder().on_move(Box::new(on_move)).build(),
+  );
+  let listeners = GlobalListeners::new(cx);
+  let state = state.clone();
+
+  Rc::new(Box::new(move |event: PointerEvent| {
+    if event.button() != 0 {
+      return;
+    }
+
+    let Some(element) = event
+      .current_target()
+      .and_then(|target| target.dyn_into::<Element>().ok())
+    else {
+      return;
+    };
+
+    let rect = element.get_bounding_client_rect();
+    let current = DragPosition {
+      x: f64::from(event.client_x()) - rect.left(),
+      y: f64::from(event.client_y()) - rect.top(),
+      width: rect.width(),
+      height: rect.height(),
+    };
+
+    if !should_start(&current) {
+      return;
+    }
+
+    event.prevent_default();
+    state.set_dragging(true);
+    position.set(current);
+    on_position(&current);
+
+    // The drag ends on release even when the pointer never moved, which
+    // `use_move` doesn't report.
+    let pointer_id = event.pointer_id();
+    let on_pointer_up = {
+      let state = state.clone();
+      let listeners = listeners.clone();
+
+      move |event: PointerEvent| {
+        if event.pointer_id() != pointer_id {
+          return;
+        }
+
+        listeners.remove_all_listeners();
+        state.set_dragging(false);
+      }
+    };
+
+    listeners.add_event_listener(document(), "pointerup", on_pointer_up.clone(), false);
+    listeners.add_event_listener(document(), "pointercancel", on_pointer_up, false);
+
+    (move_result.get_untracked().on_pointer_down)(event);
+  }))
+}
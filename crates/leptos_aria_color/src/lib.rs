@@ -0,0 +1,16 @@
+pub use color::*;
+pub use color_field_state::*;
+pub use color_state::*;
+pub use use_color_area::*;
+pub use use_color_field::*;
+pub use use_color_slider::*;
+pub use use_color_wheel::*;
+
+mod color;
+mod color_field_state;
+mod color_state;
+mod drag;
+mod use_color_area;
+mod use_color_field;
+mod use_color_slider;
+mod use_color_wheel;
@@ -0,0 +1,314 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Event;
+use leptos::web_sys::HtmlInputElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::visually_hidden_style;
+use leptos_aria_utils::FieldState;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::drag::use_drag_position;
+use crate::ColorCallback;
+use crate::ColorChannel;
+use crate::ColorState;
+
+#[derive(TypedBuilder)]
+pub struct UseColorAreaProps {
+  /// The channel adjusted along the horizontal axis.
+  #[builder(default = ColorChannel::Saturation)]
+  pub x_channel: ColorChannel,
+
+  /// The channel adjusted along the vertical axis.
+  #[builder(default = ColorChannel::Brightness)]
+  pub y_channel: ColorChannel,
+
+  /// The accessible name of the area.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+#[derive(Clone)]
+pub struct ColorAreaResult {
+  pub area: ColorAreaGroupResult,
+  /// Props for the visually hidden `<input type="range">` of the x channel.
+  pub x_input: ColorAreaInputResult,
+  /// Props for the visually hidden `<input type="range">` of the y channel.
+  pub y_input: ColorAreaInputResult,
+  /// The horizontal position of the thumb from the left of the area.
+  pub x_percent: Signal<f64>,
+  /// The vertical position of the thumb from the top of the area.
+  pub y_percent: Signal<f64>,
+}
+
+#[derive(Clone)]
+pub struct ColorAreaGroupResult {
+  pub role: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_disabled: Signal<Option<bool>>,
+  /// A gradient of the two channels across the area.
+  pub style: Signal<String>,
+  pub on_pointer_down: ColorCallback<PointerEvent>,
+}
+
+#[derive(Clone)]
+pub struct ColorAreaInputResult {
+  pub type_: &'static str,
+  pub aria_label: Signal<String>,
+  pub aria_roledescription: Signal<String>,
+  pub aria_valuetext: Signal<String>,
+  pub min: f64,
+  pub max: f64,
+  pub step: f64,
+  pub value: Signal<f64>,
+  pub tab_index: Signal<Option<i32>>,
+  pub disabled: Signal<bool>,
+  pub style: &'static str,
+  pub on_key_down: ColorCallback<KeyboardEvent>,
+  pub on_change: ColorCallback<Event>,
+}
+
+/// Provides the behavior and accessibility implementation for a color area,
+/// which adjusts two channels of a color at once, such as the saturation and
+/// brightness.
+///
+/// Each channel is exposed to assistive technology as a visually hidden range
+/// input. Only the x input is in the tab order and the keyboard handler is
+/// shared, so the left and right arrows adjust the x channel and the up and
+/// down arrows the y channel from either input. The x channel increases from
+/// the right in right to left locales.
+pub fn use_color_area(
+  cx: Scope,
+  state: &ColorState,
+  props: UseColorAreaProps,
+) -> ReadSignal<ColorAreaResult> {
+  let x_channel = props.x_channel;
+  let y_channel = props.y_channel;
+  let field = use_field_state(cx, props.is_disabled, None);
+  let direction = use_direction(cx);
+  let strings = use_localized_string_formatter(cx);
+
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+
+  let x_percent = {
+    let state = state.clone();
+    (move || {
+      let percent = state.channel_percent(x_channel);
+
+      if direction.get().is_rtl() {
+        1.0 - percent
+      } else {
+        percent
+      }
+    })
+    .derive_signal(cx)
+  };
+
+  let y_percent = {
+    let state = state.clone();
+    (move || 1.0 - state.channel_percent(y_channel)).derive_signal(cx)
+  };
+
+  let value_text = {
+    let state = state.clone();
+
+    (move || {
+      let color = state.value();
+      let strings = strings.get();
+
+      format!(
+        "{} {}, {} {}",
+        x_channel.name(&strings),
+        color.format_channel_value(x_channel),
+        y_channel.name(&strings),
+        color.format_channel_value(y_channel),
+      )
+    })
+    .derive_signal(cx)
+  };
+
+  let style = {
+    let state = state.clone();
+
+    (move || {
+      let color = state.value();
+      let x_range = x_channel.range();
+      let y_range = y_channel.range();
+      let side = if direction.get().is_rtl() {
+        "to left"
+      } else {
+        "to right"
+      };
+      let corner = |x: f64, y: f64| {
+        color
+          .with_channel_value(x_channel, x)
+          .with_channel_value(y_channel, y)
+          .to_string()
+      };
+
+      format!(
+        "background: linear-gradient(to top, {}, transparent), linear-gradient({side}, {}, {});",
+        corner(x_range.min, y_range.min),
+        corner(x_range.min, y_range.max),
+        corner(x_range.max, y_range.max),
+      )
+    })
+    .derive_signal(cx)
+  };
+
+  let on_key_down: ColorCallback<KeyboardEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if !field.can_change() {
+        return;
+      }
+
+      let key = KeyboardKey::from(&event).logical(direction.get_untracked());
+      let (channel, sign) = match key {
+        KeyboardKey::ArrowRight => (x_channel, 1.0),
+        KeyboardKey::ArrowLeft => (x_channel, -1.0),
+        KeyboardKey::ArrowUp => (y_channel, 1.0),
+        KeyboardKey::ArrowDown => (y_channel, -1.0),
+        KeyboardKey::PageUp => (y_channel, 1.0),
+        KeyboardKey::PageDown => (y_channel, -1.0),
+        _ => return,
+      };
+
+      let range = channel.range();
+      let is_page = event.shift_key() || matches!(key, KeyboardKey::PageUp | KeyboardKey::PageDown);
+      let step = if is_page { range.page_step } else { range.step };
+
+      event.prevent_default();
+      state.increment_channel(channel, sign * step);
+      state.change_end();
+    }))
+  };
+
+  let on_pointer_down = use_drag_position(cx, state, move |_| field.can_change(), {
+    let state = state.clone();
+
+    move |position| {
+      let (x, y) = position.percent();
+      let x = if direction.get_untracked().is_rtl() {
+        1.0 - x
+      } else {
+        x
+      };
+
+      state.set_channel_percent(x_channel, x);
+      state.set_channel_percent(y_channel, 1.0 - y);
+    }
+  });
+
+  let input = |channel: ColorChannel, is_tabbable: bool| {
+    let range = channel.range();
+
+    let value = {
+      let state = state.clone();
+      (move || state.value().channel_value(channel)).derive_signal(cx)
+    };
+
+    let tab_index = {
+      let is_disabled = field.is_disabled;
+
+      (move || {
+        Some(if is_tabbable && !is_disabled.get() {
+          0
+        } else {
+          -1
+        })
+      })
+      .derive_signal(cx)
+    };
+
+    ColorAreaInputResult {
+      type_: "range",
+      aria_label: (move || channel.name(&strings.get())).derive_signal(cx),
+      aria_roledescription: (move || strings.get().format("twoDimensionalSlider"))
+        .derive_signal(cx),
+      aria_valuetext: value_text,
+      min: range.min,
+      max: range.max,
+      step: range.step,
+      value,
+      tab_index,
+      disabled: field.is_disabled,
+      style: visually_hidden_style(),
+      on_key_down: on_key_down.clone(),
+      on_change: create_change_handler(state, field, channel),
+    }
+  };
+
+  let x_input = input(x_channel, true);
+  let y_input = input(y_channel, false);
+
+  let (color_area_result, _) = create_signal(
+    cx,
+    ColorAreaResult {
+      area: ColorAreaGroupResult {
+        role: "group",
+        aria_label,
+        aria_disabled: field.aria_disabled(cx),
+        style,
+        on_pointer_down,
+      },
+      x_input,
+      y_input,
+      x_percent,
+      y_percent,
+    },
+  );
+
+  color_area_result
+}
+
+/// Screen readers on touch devices adjust range inputs directly rather than
+/// with key presses, so the value of the input is applied as well.
+fn create_change_handler(
+  state: &ColorState,
+  field: FieldState,
+  channel: ColorChannel,
+) -> ColorCallback<Event> {
+  let state = state.clone();
+
+  Rc::new(Box::new(move |event: Event| {
+    if !field.can_change() {
+      return;
+    }
+
+    let Some(input) = event
+      .target()
+      .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+    else {
+      return;
+    };
+
+    let value = input.value_as_number();
+
+    if value.is_nan() {
+      return;
+    }
+
+    state.set_channel_value(channel, value);
+    state.change_end();
+  }))
+}
@@ -0,0 +1,291 @@
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::WheelEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_text_field;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::TextFieldResult;
+use leptos_aria_utils::UseTextFieldProps;
+
+use crate::ColorCallback;
+use crate::ColorFieldState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseColorFieldProps {
+  /// The id of the input. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+
+  /// The name of the input, submitted with the hex value of the color.
+  #[builder(default, setter(strip_option, into))]
+  pub name: Option<String>,
+
+  /// Whether the field is invalid, in addition to unparseable text.
+  #[builder(default, setter(strip_option, into))]
+  pub is_invalid: Option<MaybeSignal<bool>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+
+  /// Whether scrolling over the focused input changes the color. Defaults to
+  /// `true`.
+  #[builder(default, setter(strip_option))]
+  pub is_wheel_enabled: Option<bool>,
+}
+
+#[derive(Clone)]
+pub struct ColorFieldResult {
+  /// The props for the text input. The `value` is the typed text while
+  /// editing and `is_invalid` includes unparseable text.
+  pub input: TextFieldResult,
+  pub on_key_down: ColorCallback<KeyboardEvent>,
+  pub on_wheel: ColorCallback<WheelEvent>,
+  pub on_focus: ColorCallback<FocusEvent>,
+  pub on_blur: ColorCallback<FocusEvent>,
+}
+
+/// Provides the behavior and accessibility implementation for a color field,
+/// a text input for a hex color.
+///
+/// * Typed text is restricted to hex digits with an optional `#` and is parsed
+///   on <kbd>Enter</kbd> or blur. Text which isn't a valid 3, 6 or 8 digit
+///   color is discarded. <kbd>Enter</kbd> only submits the form when there is
+///   no typed text to commit.
+/// * The arrow keys and scrolling over the focused input step the numeric value
+///   of the color between `#000000` and `#FFFFFF`. <kbd>Home</kbd> and
+///   <kbd>End</kbd> move to the limits.
+/// * The input is a regular text input, so with a `name` the committed hex
+///   value is submitted with the form.
+pub fn use_color_field(
+  cx: Scope,
+  state: &ColorFieldState,
+  props: UseColorFieldProps,
+) -> ReadSignal<ColorFieldResult> {
+  let is_wheel_enabled = props.is_wheel_enabled.unwrap_or(true);
+  let is_focused = create_rw_signal(cx, false);
+
+  let is_invalid = {
+    let state = state.clone();
+    let original_is_invalid = props.is_invalid.unwrap_or(false.into());
+    (move || original_is_invalid.get() || state.is_input_invalid()).derive_signal(cx)
+  };
+
+  let value = {
+    let state = state.clone();
+    (move || state.input_value()).derive_signal(cx)
+  };
+
+  let input = use_text_field(
+    cx,
+    UseTextFieldProps {
+      id: props.id,
+      value: value.into(),
+      aria_label: props.aria_label,
+      aria_labelledby: props.aria_labelledby,
+      name: props.name,
+      is_invalid: Some(is_invalid.into()),
+      is_disabled: props.is_disabled,
+      is_read_only: props.is_read_only,
+      // Text which can't be part of a color is rejected.
+      on_input: Some(Box::new({
+        let state = state.clone();
+        move |text: String| state.set_input_value(text)
+      })),
+    },
+  )
+  .get_untracked();
+  let field = input.field;
+
+  let on_key_down: ColorCallback<KeyboardEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if !field.can_change() {
+        return;
+      }
+
+      match KeyboardKey::from(&event) {
+        KeyboardKey::ArrowUp | KeyboardKey::PageUp => state.increment(),
+        KeyboardKey::ArrowDown | KeyboardKey::PageDown => state.decrement(),
+        KeyboardKey::Home => state.decrement_to_min(),
+        KeyboardKey::End => state.increment_to_max(),
+        // Without typed text Enter is left to the browser to submit the form.
+        KeyboardKey::Enter if state.is_editing() => state.commit(),
+        _ => return,
+      }
+
+      event.prevent_default();
+    }))
+  };
+
+  let on_wheel: ColorCallback<WheelEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |event: WheelEvent| {
+      // Only the focused field responds so that scrolling the page over the
+      // field keeps working.
+      if !is_wheel_enabled || !is_focused.get_untracked() || !field.can_change() {
+        return;
+      }
+
+      // Horizontal scrolling is left to the browser.
+      if event.delta_x().abs() > event.delta_y().abs() {
+        return;
+      }
+
+      event.prevent_default();
+
+      if event.delta_y() < 0.0 {
+        state.increment();
+      } else if event.delta_y() > 0.0 {
+        state.decrement();
+      }
+    }))
+  };
+
+  let on_focus: ColorCallback<FocusEvent> = Rc::new(Box::new(move |_| is_focused.set(true)));
+
+  let on_blur: ColorCallback<FocusEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |_| {
+      is_focused.set(false);
+      state.commit();
+    }))
+  };
+
+  let (color_field_result, _) = create_signal(
+    cx,
+    ColorFieldResult {
+      input,
+      on_key_down,
+      on_wheel,
+      on_focus,
+      on_blur,
+    },
+  );
+
+  color_field_result
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+  use crate::use_color_field_state;
+  use crate::UseColorFieldStateProps;
+
+  #[test]
+  fn unparseable_text_is_invalid_until_committed() {
+    run_scope(create_runtime(), |cx| {
+      let state = use_color_field_state(cx, UseColorFieldStateProps::default());
+      let result = use_color_field(cx, &state, UseColorFieldProps::default()).get_untracked();
+
+      assert!(state.set_input_value("#ff".to_string()));
+      assert_eq!(result.input.value.get(), "#ff");
+      assert_eq!(result.input.aria_invalid.get(), Some(true));
+
+      state.commit();
+
+      assert_eq!(result.input.value.get(), "");
+      assert_eq!(result.input.aria_invalid.get(), None);
+    });
+  }
+
+  #[test]
+  fn read_only_fields_are_not_invalid() {
+    run_scope(create_runtime(), |cx| {
+      let state = use_color_field_state(cx, UseColorFieldStateProps::default());
+      let props = UseColorFieldProps::builder().is_read_only(true).build();
+      let result = use_color_field(cx, &state, props).get_untracked();
+
+      state.set_input_value("#ff".to_string());
+
+      assert_eq!(result.input.aria_invalid.get(), None);
+      assert_eq!(result.input.aria_readonly.get(), Some(true));
+    });
+  }
+
+  #[cfg(target_arch = "wasm32")]
+  mod browser {
+    use leptos::web_sys::KeyboardEventInit;
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn key_down(key: &str) -> KeyboardEvent {
+      let mut init = KeyboardEventInit::new();
+      init.key(key).bubbles(true).cancelable(true);
+      KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap()
+    }
+
+    fn hex(state: &ColorFieldState) -> Option<String> {
+      state.value_untracked().map(|color| color.to_hex())
+    }
+
+    #[wasm_bindgen_test]
+    fn enter_only_prevents_submission_while_editing() {
+      run_scope(create_runtime(), |cx| {
+        let state = use_color_field_state(cx, UseColorFieldStateProps::default());
+        let result = use_color_field(cx, &state, UseColorFieldProps::default()).get_untracked();
+
+        let event = key_down("Enter");
+        (result.on_key_down)(event.clone());
+        assert!(!event.default_prevented());
+
+        state.set_input_value("#f00".to_string());
+        let event = key_down("Enter");
+        (result.on_key_down)(event.clone());
+        assert!(event.default_prevented());
+        assert_eq!(hex(&state), Some("#FF0000".to_string()));
+      });
+    }
+
+    #[wasm_bindgen_test]
+    fn arrow_keys_step_the_color_unless_read_only() {
+      run_scope(create_runtime(), |cx| {
+        let is_read_only = leptos::create_rw_signal(cx, false);
+        let state = use_color_field_state(cx, UseColorFieldStateProps::default());
+        let props = UseColorFieldProps::builder()
+          .is_read_only((move || is_read_only.get()).derive_signal(cx))
+          .build();
+        let result = use_color_field(cx, &state, props).get_untracked();
+
+        state.set_input_value("#000010".to_string());
+        state.commit();
+
+        (result.on_key_down)(key_down("ArrowUp"));
+        assert_eq!(hex(&state), Some("#000011".to_string()));
+
+        (result.on_key_down)(key_down("End"));
+        assert_eq!(hex(&state), Some("#FFFFFF".to_string()));
+
+        is_read_only.set(true);
+        (result.on_key_down)(key_down("Home"));
+        assert_eq!(hex(&state), Some("#FFFFFF".to_string()));
+      });
+    }
+  }
+}
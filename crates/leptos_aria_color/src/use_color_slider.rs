@@ -0,0 +1,227 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::Orientation;
+
+use crate::drag::use_drag_position;
+use crate::ColorChannel;
+use crate::ColorState;
+
+#[derive(TypedBuilder)]
+pub struct UseColorSliderProps {
+  /// The channel which the slider adjusts.
+  pub channel: ColorChannel,
+
+  /// The id of the thumb. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// The accessible name of the slider. Defaults to the channel name.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  #[builder(default)]
+  pub orientation: Orientation,
+}
+
+#[derive(Clone)]
+pub struct ColorSliderTrackResult {
+  /// A gradient of the channel from its minimum to its maximum value.
+  pub style: Signal<String>,
+  pub on_pointer_down: ColorCallback<PointerEvent>,
+}
+
+#[derive(Clone)]
+pub struct ColorSliderThumbResult {
+  pub id: String,
+  pub role: &'static str,
+  pub tab_index: Signal<Option<i32>>,
+  pub aria_label: Signal<String>,
+  pub aria_valuenow: Signal<f64>,
+  pub aria_valuemin: f64,
+  pub aria_valuemax: f64,
+  pub aria_valuetext: Signal<String>,
+  pub aria_orientation: &'static str,
+  pub aria_disabled: Signal<Option<bool>>,
+  pub on_key_down: ColorCallback<KeyboardEvent>,
+}
+
+#[derive(Clone)]
+pub struct ColorSliderResult {
+  pub track: ColorSliderTrackResult,
+  pub thumb: ColorSliderThumbResult,
+  /// The position of the thumb as a fraction of the track, for styling.
+  pub percent: Signal<f64>,
+  /// The formatted channel value, for an `<output>` element.
+  pub value_text: Signal<String>,
+}
+
+/// Provides the behavior and accessibility implementation for a slider which
+/// adjusts a single channel of a color, such as the hue or alpha.
+///
+/// * The arrow keys move the channel by its step, or by its page step with
+///   <kbd>Shift</kbd>. <kbd>PageUp</kbd> and <kbd>PageDown</kbd> always move by
+///   the page step and <kbd>Home</kbd> and <kbd>End</kbd> to the limits.
+/// * Pressing the track moves the thumb to the pointer and dragging is tracked
+///   with [`use_move`](leptos_aria_interactions::use_move).
+/// * Horizontal sliders increase from the right in right to left locales.
+pub fn use_color_slider(
+  cx: Scope,
+  state: &ColorState,
+  props: UseColorSliderProps,
+) -> ReadSignal<ColorSliderResult> {
+  let channel = props.channel;
+  let range = channel.range();
+  let orientation = props.orientation;
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let field = use_field_state(cx, props.is_disabled, None);
+  let direction = use_direction(cx);
+
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || aria_label.get()).derive_signal(cx),
+    None => {
+      let strings = use_localized_string_formatter(cx);
+      (move || channel.name(&strings.get())).derive_signal(cx)
+    }
+  };
+
+  let value = {
+    let state = state.clone();
+    (move || state.value().channel_value(channel)).derive_signal(cx)
+  };
+
+  let value_text = {
+    let state = state.clone();
+    (move || state.value().format_channel_value(channel)).derive_signal(cx)
+  };
+
+  let percent = {
+    let state = state.clone();
+    (move || state.channel_percent(channel)).derive_signal(cx)
+  };
+
+  let style = {
+    let state = state.clone();
+
+    (move || {
+      let color = state.value();
+      let side = match orientation {
+        Orientation::Horizontal if direction.get().is_rtl() => "to left",
+        Orientation::Horizontal => "to right",
+        Orientation::Vertical => "to top",
+      };
+      let stops: Vec<String> = if channel == ColorChannel::Hue {
+        (0..=6)
+          .map(|index| {
+            color
+              .with_channel_value(channel, f64::from(index) * 60.0)
+              .to_string()
+          })
+          .collect()
+      } else {
+        [range.min, range.max]
+          .iter()
+          .map(|value| color.with_channel_value(channel, *value).to_string())
+          .collect()
+      };
+
+      format!("background: linear-gradient({side}, {});", stops.join(", "))
+    })
+    .derive_signal(cx)
+  };
+
+  let on_key_down: ColorCallback<KeyboardEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if !field.can_change() {
+        return;
+      }
+
+      let step = if event.shift_key() {
+        range.page_step
+      } else {
+        range.step
+      };
+
+      match KeyboardKey::from(&event).logical(direction.get_untracked()) {
+        KeyboardKey::ArrowRight | KeyboardKey::ArrowUp => state.increment_channel(channel, step),
+        KeyboardKey::ArrowLeft | KeyboardKey::ArrowDown => state.increment_channel(channel, -step),
+        KeyboardKey::PageUp => state.increment_channel(channel, range.page_step),
+        KeyboardKey::PageDown => state.increment_channel(channel, -range.page_step),
+        KeyboardKey::Home => state.set_channel_value(channel, range.min),
+        KeyboardKey::End => state.set_channel_value(channel, range.max),
+        _ => return,
+      }
+
+      event.prevent_default();
+      state.change_end();
+    }))
+  };
+
+  let on_pointer_down = use_drag_position(cx, state, move |_| field.can_change(), {
+    let state = state.clone();
+
+    move |position| {
+      let (x, y) = position.percent();
+      let percent = match orientation {
+        Orientation::Horizontal if direction.get_untracked().is_rtl() => 1.0 - x,
+        Orientation::Horizontal => x,
+        Orientation::Vertical => 1.0 - y,
+      };
+
+      state.set_channel_percent(channel, percent);
+    }
+  });
+
+  let tab_index = {
+    let is_disabled = field.is_disabled;
+    (move || (!is_disabled.get()).then_some(0)).derive_signal(cx)
+  };
+
+  let (color_slider_result, _) = create_signal(
+    cx,
+    ColorSliderResult {
+      track: ColorSliderTrackResult {
+        style,
+        on_pointer_down,
+      },
+      thumb: ColorSliderThumbResult {
+        id,
+        role: "slider",
+        tab_index,
+        aria_label,
+        aria_valuenow: value,
+        aria_valuemin: range.min,
+        aria_valuemax: range.max,
+        aria_valuetext: value_text,
+        aria_orientation: orientation.as_aria_orientation(),
+        aria_disabled: field.aria_disabled(cx),
+        on_key_down,
+      },
+      percent,
+      value_text,
+    },
+  );
+
+  color_slider_result
+}
+
+pub(crate) type ColorCallback<E> = Rc<Box<dyn Fn(E)>>;
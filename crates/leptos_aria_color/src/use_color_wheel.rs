@@ -0,0 +1,251 @@
+use std::f64::consts::PI;
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::drag::use_drag_position;
+use crate::drag::DragPosition;
+use crate::ColorCallback;
+use crate::ColorChannel;
+use crate::ColorState;
+
+#[derive(TypedBuilder)]
+pub struct UseColorWheelProps {
+  /// The radius of the inner edge of the track in pixels.
+  pub inner_radius: f64,
+
+  /// The radius of the outer edge of the track in pixels.
+  pub outer_radius: f64,
+
+  /// The id of the thumb. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// The accessible name of the wheel. Defaults to the name of the hue
+  /// channel.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+#[derive(Clone)]
+pub struct ColorWheelResult {
+  pub track: ColorWheelTrackResult,
+  pub thumb: ColorWheelThumbResult,
+  /// The position of the center of the thumb relative to the center of the
+  /// wheel in pixels, halfway between the inner and outer radius.
+  pub thumb_position: Signal<(f64, f64)>,
+}
+
+#[derive(Clone)]
+pub struct ColorWheelTrackResult {
+  /// A conic gradient of every hue, starting at the top.
+  pub style: Signal<String>,
+  pub on_pointer_down: ColorCallback<PointerEvent>,
+}
+
+#[derive(Clone)]
+pub struct ColorWheelThumbResult {
+  pub id: String,
+  pub role: &'static str,
+  pub tab_index: Signal<Option<i32>>,
+  pub aria_label: Signal<String>,
+  pub aria_valuenow: Signal<f64>,
+  pub aria_valuemin: f64,
+  pub aria_valuemax: f64,
+  pub aria_valuetext: Signal<String>,
+  pub aria_disabled: Signal<Option<bool>>,
+  pub on_key_down: ColorCallback<KeyboardEvent>,
+}
+
+/// Provides the behavior and accessibility implementation for a color wheel,
+/// which adjusts the hue of a color by rotating a thumb around a circular
+/// track.
+///
+/// Pressing inside the track moves the thumb to the angle of the pointer.
+/// The keys behave like a slider, except that the hue wraps around at the
+/// start and end of the track.
+pub fn use_color_wheel(
+  cx: Scope,
+  state: &ColorState,
+  props: UseColorWheelProps,
+) -> ReadSignal<ColorWheelResult> {
+  let channel = ColorChannel::Hue;
+  let range = channel.range();
+  let inner_radius = props.inner_radius;
+  let outer_radius = props.outer_radius;
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let field = use_field_state(cx, props.is_disabled, None);
+
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || aria_label.get()).derive_signal(cx),
+    None => {
+      let strings = use_localized_string_formatter(cx);
+      (move || channel.name(&strings.get())).derive_signal(cx)
+    }
+  };
+
+  let value = {
+    let state = state.clone();
+    (move || state.value().channel_value(channel)).derive_signal(cx)
+  };
+
+  let value_text = {
+    let state = state.clone();
+    (move || state.value().format_channel_value(channel)).derive_signal(cx)
+  };
+
+  let thumb_position = {
+    let radius = (inner_radius + outer_radius) / 2.0;
+    (move || hue_to_position(value.get(), radius)).derive_signal(cx)
+  };
+
+  let style = {
+    let state = state.clone();
+
+    (move || {
+      let color = state.value();
+      let stops: Vec<String> = (0..=6)
+        .map(|index| {
+          color
+            .with_channel_value(channel, f64::from(index) * 60.0)
+            .to_string()
+        })
+        .collect();
+
+      format!("background: conic-gradient({});", stops.join(", "))
+    })
+    .derive_signal(cx)
+  };
+
+  let on_key_down: ColorCallback<KeyboardEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if !field.can_change() {
+        return;
+      }
+
+      let step = if event.shift_key() {
+        range.page_step
+      } else {
+        range.step
+      };
+
+      match KeyboardKey::from(&event) {
+        KeyboardKey::ArrowRight | KeyboardKey::ArrowUp => state.increment_channel(channel, step),
+        KeyboardKey::ArrowLeft | KeyboardKey::ArrowDown => state.increment_channel(channel, -step),
+        KeyboardKey::PageUp => state.increment_channel(channel, range.page_step),
+        KeyboardKey::PageDown => state.increment_channel(channel, -range.page_step),
+        KeyboardKey::Home => state.set_channel_value(channel, range.min),
+        KeyboardKey::End => state.set_channel_value(channel, range.max - range.step),
+        _ => return,
+      }
+
+      event.prevent_default();
+      state.change_end();
+    }))
+  };
+
+  let to_center = |position: &DragPosition| {
+    (
+      position.x - position.width / 2.0,
+      position.y - position.height / 2.0,
+    )
+  };
+
+  let should_start = move |position: &DragPosition| {
+    let (x, y) = to_center(position);
+    let distance = x.hypot(y);
+
+    field.can_change() && distance >= inner_radius && distance <= outer_radius
+  };
+
+  let on_position = {
+    let state = state.clone();
+
+    move |position: &DragPosition| {
+      let (x, y) = to_center(position);
+      state.set_channel_value(channel, position_to_hue(x, y));
+    }
+  };
+
+  let on_pointer_down = use_drag_position(cx, state, should_start, on_position);
+
+  let tab_index = {
+    let is_disabled = field.is_disabled;
+    (move || (!is_disabled.get()).then_some(0)).derive_signal(cx)
+  };
+
+  let (color_wheel_result, _) = create_signal(
+    cx,
+    ColorWheelResult {
+      track: ColorWheelTrackResult {
+        style,
+        on_pointer_down,
+      },
+      thumb: ColorWheelThumbResult {
+        id,
+        role: "slider",
+        tab_index,
+        aria_label,
+        aria_valuenow: value,
+        aria_valuemin: range.min,
+        aria_valuemax: range.max,
+        aria_valuetext: value_text,
+        aria_disabled: field.aria_disabled(cx),
+        on_key_down,
+      },
+      thumb_position,
+    },
+  );
+
+  color_wheel_result
+}
+
+/// The hue at the point relative to the center of the wheel. The hue starts
+/// at the top and increases clockwise.
+fn position_to_hue(x: f64, y: f64) -> f64 {
+  (x.atan2(-y) * 180.0 / PI).rem_euclid(360.0)
+}
+
+/// The point at the hue on a circle of the radius, relative to the center.
+fn hue_to_position(hue: f64, radius: f64) -> (f64, f64) {
+  let angle = hue * PI / 180.0;
+  (radius * angle.sin(), -radius * angle.cos())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn hue_starts_at_the_top_and_increases_clockwise() {
+    assert_eq!(position_to_hue(0.0, -10.0), 0.0);
+    assert_eq!(position_to_hue(10.0, 0.0), 90.0);
+    assert_eq!(position_to_hue(0.0, 10.0), 180.0);
+    assert_eq!(position_to_hue(-10.0, 0.0), 270.0);
+  }
+
+  #[test]
+  fn hue_to_position_is_the_inverse() {
+    for hue in [0.0, 45.0, 120.0, 300.0] {
+      let (x, y) = hue_to_position(hue, 50.0);
+      assert!((position_to_hue(x, y) - hue).abs() < 1e-9);
+    }
+  }
+}
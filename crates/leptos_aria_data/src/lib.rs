@@ -0,0 +1,3 @@
+pub use use_async_list::*;
+
+mod use_async_list;
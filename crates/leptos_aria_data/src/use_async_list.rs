@@ -0,0 +1,246 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::Event;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos::UntrackedSettableSignal;
+use leptos_aria_selection::SortDescriptor;
+
+/// The distance in pixels from the end of the scroll container at which more
+/// items are loaded.
+pub const LOAD_MORE_THRESHOLD: f64 = 200.0;
+
+/// The loading state of an asynchronous collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LoadingState {
+  #[default]
+  Idle,
+  /// The first page is loading.
+  Loading,
+  /// The collection is reloading with a new sort descriptor.
+  Sorting,
+  /// The collection is reloading with new filter text.
+  Filtering,
+  /// The next page is loading.
+  LoadingMore,
+  /// The last request failed.
+  Error,
+}
+
+/// The options passed to the loader of an asynchronous list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsyncListLoadOptions {
+  /// The cursor of the page to load. `None` for the first page.
+  pub cursor: Option<String>,
+  pub sort_descriptor: Option<SortDescriptor>,
+  pub filter_text: String,
+  /// The reason for this request.
+  pub loading_state: LoadingState,
+}
+
+/// A page of items returned by the loader.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsyncListPage<T> {
+  pub items: Vec<T>,
+
+  /// The cursor of the next page. `None` when there are no more items.
+  pub cursor: Option<String>,
+}
+
+pub type AsyncListFuture<T> = Pin<Box<dyn Future<Output = Result<AsyncListPage<T>, String>>>>;
+pub type AsyncListLoader<T> = Box<dyn Fn(AsyncListLoadOptions) -> AsyncListFuture<T>>;
+
+#[derive(TypedBuilder)]
+pub struct UseAsyncListProps<T: 'static> {
+  /// Load a page of items.
+  pub load: AsyncListLoader<T>,
+
+  /// The sort descriptor used for the first load.
+  #[builder(default, setter(strip_option))]
+  pub initial_sort_descriptor: Option<SortDescriptor>,
+
+  /// The filter text used for the first load.
+  #[builder(default, setter(into))]
+  pub initial_filter_text: String,
+}
+
+/// The state of a collection which is loaded asynchronously, shared by the
+/// collection hooks (table, listbox, combobox) so they handle loading the same
+/// way.
+pub struct AsyncList<T: 'static> {
+  pub items: RwSignal<Vec<T>>,
+  pub loading_state: RwSignal<LoadingState>,
+  pub error: RwSignal<Option<String>>,
+  pub sort_descriptor: RwSignal<Option<SortDescriptor>>,
+  pub filter_text: RwSignal<String>,
+  cursor: RwSignal<Option<String>>,
+  /// Incremented for every request so that stale responses are ignored.
+  request: RwSignal<usize>,
+  load: Rc<AsyncListLoader<T>>,
+}
+
+impl<T> Clone for AsyncList<T> {
+  fn clone(&self) -> Self {
+    Self {
+      items: self.items,
+      loading_state: self.loading_state,
+      error: self.error,
+      sort_descriptor: self.sort_descriptor,
+      filter_text: self.filter_text,
+      cursor: self.cursor,
+      request: self.request,
+      load: self.load.clone(),
+    }
+  }
+}
+
+/// Manage the state of a collection which is loaded asynchronously. The first
+/// page is loaded immediately.
+pub fn use_async_list<T: Clone + 'static>(cx: Scope, props: UseAsyncListProps<T>) -> AsyncList<T> {
+  let list = AsyncList {
+    items: create_rw_signal(cx, vec![]),
+    loading_state: create_rw_signal(cx, LoadingState::Idle),
+    error: create_rw_signal(cx, None),
+    sort_descriptor: create_rw_signal(cx, props.initial_sort_descriptor),
+    filter_text: create_rw_signal(cx, props.initial_filter_text),
+    cursor: create_rw_signal(cx, None),
+    request: create_rw_signal(cx, 0),
+    load: Rc::new(props.load),
+  };
+
+  list.run(LoadingState::Loading);
+  list
+}
+
+impl<T: Clone + 'static> AsyncList<T> {
+  /// Whether any request is in flight.
+  pub fn is_loading(&self) -> bool {
+    !matches!(
+      self.loading_state.get(),
+      LoadingState::Idle | LoadingState::Error
+    )
+  }
+
+  /// Whether there are more pages to load.
+  pub fn has_more(&self) -> bool {
+    self.cursor.get().is_some()
+  }
+
+  /// The value of `aria-busy` for the collection container.
+  pub fn aria_busy(&self, cx: Scope) -> Signal<Option<bool>> {
+    let list = self.clone();
+    (move || list.is_loading().then_some(true)).derive_signal(cx)
+  }
+
+  /// Reload the collection from the first page.
+  pub fn reload(&self) {
+    self.run(LoadingState::Loading);
+  }
+
+  /// Load the next page. This is ignored while another request is in flight or
+  /// when there are no more pages.
+  pub fn load_more(&self) {
+    let is_idle = self.loading_state.get_untracked() == LoadingState::Idle;
+
+    if !is_idle || self.cursor.get_untracked().is_none() {
+      return;
+    }
+
+    self.run(LoadingState::LoadingMore);
+  }
+
+  /// Reload the collection sorted by the provided descriptor.
+  pub fn sort(&self, sort_descriptor: SortDescriptor) {
+    self.sort_descriptor.set(Some(sort_descriptor));
+    self.run(LoadingState::Sorting);
+  }
+
+  /// Reload the collection with the provided filter text.
+  pub fn set_filter_text(&self, filter_text: impl Into<String>) {
+    self.filter_text.set(filter_text.into());
+    self.run(LoadingState::Filtering);
+  }
+
+  /// A scroll handler for the scroll container which loads the next page when
+  /// the end of the collection is near. Use [`AsyncList::load_more`] directly
+  /// when loading is triggered by a sentinel element instead.
+  pub fn on_scroll(&self) -> Rc<Box<dyn Fn(Event)>> {
+    let list = self.clone();
+
+    let handler = move |event: Event| {
+      let Some(container) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      else {
+        return;
+      };
+
+      let remaining = f64::from(container.scroll_height())
+        - f64::from(container.scroll_top())
+        - f64::from(container.client_height());
+
+      if remaining <= LOAD_MORE_THRESHOLD {
+        list.load_more();
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  }
+
+  fn run(&self, loading_state: LoadingState) {
+    let request = self.request.get_untracked() + 1;
+    self.request.set_untracked(request);
+    self.loading_state.set(loading_state);
+
+    let is_loading_more = loading_state == LoadingState::LoadingMore;
+    let options = AsyncListLoadOptions {
+      cursor: if is_loading_more {
+        self.cursor.get_untracked()
+      } else {
+        None
+      },
+      sort_descriptor: self.sort_descriptor.get_untracked(),
+      filter_text: self.filter_text.get_untracked(),
+      loading_state,
+    };
+    let future = (self.load)(options);
+    let list = self.clone();
+
+    spawn_local(async move {
+      let result = future.await;
+
+      // A newer request has replaced this one.
+      if list.request.get_untracked() != request {
+        return;
+      }
+
+      match result {
+        Ok(page) => {
+          if is_loading_more {
+            list.items.update(|items| items.extend(page.items));
+          } else {
+            list.items.set(page.items);
+          }
+
+          list.cursor.set(page.cursor);
+          list.error.set(None);
+          list.loading_state.set(LoadingState::Idle);
+        }
+        Err(error) => {
+          list.error.set(Some(error));
+          list.loading_state.set(LoadingState::Error);
+        }
+      }
+    });
+  }
+}
@@ -0,0 +1,5 @@
+pub use use_dialog::*;
+pub use use_dialog_title::*;
+
+mod use_dialog;
+mod use_dialog_title;
@@ -0,0 +1,126 @@
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::provide_context;
+use leptos::request_animation_frame;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::Role;
+
+/// The role of the dialog.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DialogRole {
+  #[default]
+  Dialog,
+  AlertDialog,
+}
+
+impl DialogRole {
+  pub fn as_str(&self) -> &'static str {
+    Role::from(*self).as_str()
+  }
+}
+
+impl From<DialogRole> for Role {
+  fn from(role: DialogRole) -> Self {
+    match role {
+      DialogRole::Dialog => Role::Dialog,
+      DialogRole::AlertDialog => Role::AlertDialog,
+    }
+  }
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseDialogProps {
+  /// The accessibility role for the dialog.
+  #[builder(default)]
+  pub role: DialogRole,
+
+  /// The id of the dialog element. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// An accessible label for the dialog. When provided the title is not used
+  /// to label the dialog.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the dialog. Overrides the title.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+}
+
+#[derive(Clone)]
+pub struct DialogResult {
+  pub role: Role,
+  pub id: String,
+  /// The dialog should be focusable so that focus can be moved to it when it
+  /// opens.
+  pub tab_index: i32,
+  pub aria_label: Signal<Option<String>>,
+  /// Either the provided `aria_labelledby` or the id of the title registered
+  /// with [`use_dialog_title`](crate::use_dialog_title).
+  pub aria_labelledby: Signal<Option<String>>,
+}
+
+/// The id of the title registered within a dialog.
+#[derive(Clone, Copy)]
+pub(crate) struct DialogTitleContext(pub RwSignal<Option<String>>);
+
+/// Provides the behavior and accessibility implementation for a dialog
+/// component.
+///
+/// The heading of the dialog should use
+/// [`use_dialog_title`](crate::use_dialog_title) from within the dialog's
+/// children which labels the dialog automatically. In debug builds a warning is
+/// logged when the dialog has neither a title nor an `aria_label`.
+pub fn use_dialog(cx: Scope, props: UseDialogProps) -> ReadSignal<DialogResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let title_id = create_rw_signal::<Option<String>>(cx, None);
+  provide_context(cx, DialogTitleContext(title_id));
+
+  let has_aria_label = props.aria_label.is_some();
+  let has_aria_labelledby = props.aria_labelledby.is_some();
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None if has_aria_label => (move || None).derive_signal(cx),
+    None => (move || title_id.get()).derive_signal(cx),
+  };
+
+  if cfg!(debug_assertions) && !is_server() && !has_aria_label && !has_aria_labelledby {
+    // The title is registered by the children which are rendered after this
+    // hook runs.
+    request_animation_frame(move || {
+      if title_id.get_untracked().is_none() {
+        leptos::warn!(
+          "[use_dialog] the dialog has no accessible name. Add a heading with `use_dialog_title` \
+           or provide an `aria_label`."
+        );
+      }
+    });
+  }
+
+  let (dialog_result, _) = create_signal(
+    cx,
+    DialogResult {
+      role: props.role.into(),
+      id,
+      tab_index: -1,
+      aria_label,
+      aria_labelledby,
+    },
+  );
+
+  dialog_result
+}
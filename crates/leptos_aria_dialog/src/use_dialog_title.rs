@@ -0,0 +1,50 @@
+use leptos::create_signal;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::use_context;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_id;
+
+use crate::use_dialog::DialogTitleContext;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseDialogTitleProps {
+  /// The id of the heading element. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct DialogTitleResult {
+  pub id: String,
+}
+
+/// Registers the heading of a dialog so that it labels the dialog. This must be
+/// called from a component rendered within the dialog which called
+/// [`use_dialog`](crate::use_dialog).
+pub fn use_dialog_title(cx: Scope, props: UseDialogTitleProps) -> ReadSignal<DialogTitleResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+
+  match use_context::<DialogTitleContext>(cx) {
+    Some(DialogTitleContext(title_id)) => {
+      title_id.set(Some(id.clone()));
+
+      let registered_id = id.clone();
+      on_cleanup(cx, move || {
+        if title_id.get_untracked().as_ref() == Some(&registered_id) {
+          title_id.set(None);
+        }
+      });
+    }
+    None => {
+      #[cfg(debug_assertions)]
+      leptos::warn!("[use_dialog_title] must be used within a dialog created with `use_dialog`.");
+    }
+  }
+
+  let (dialog_title_result, _) = create_signal(cx, DialogTitleResult { id });
+
+  dialog_title_result
+}
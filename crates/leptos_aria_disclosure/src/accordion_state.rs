@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos_aria_selection::Key;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseAccordionStateProps {
+  /// The keys of the expanded items (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub expanded_keys: Option<MaybeSignal<HashSet<Key>>>,
+
+  /// The keys of the initially expanded items (uncontrolled).
+  #[builder(default)]
+  pub default_expanded_keys: HashSet<Key>,
+
+  /// Handler that is called when items are expanded or collapsed.
+  #[builder(default, setter(strip_option))]
+  pub on_expanded_change: Option<Box<dyn Fn(HashSet<Key>)>>,
+
+  /// Whether more than one item can be expanded at a time. When `false`
+  /// expanding an item collapses the others.
+  #[builder(default)]
+  pub allows_multiple_expanded: bool,
+}
+
+/// The expanded state of the items in an accordion.
+#[derive(Clone)]
+pub struct AccordionState {
+  pub allows_multiple_expanded: bool,
+  expanded_keys: ControlledState<HashSet<Key>>,
+}
+
+pub fn use_accordion_state(cx: Scope, props: UseAccordionStateProps) -> AccordionState {
+  AccordionState {
+    allows_multiple_expanded: props.allows_multiple_expanded,
+    expanded_keys: use_controlled_state(
+      cx,
+      props.expanded_keys,
+      props.default_expanded_keys,
+      props.on_expanded_change,
+    ),
+  }
+}
+
+impl AccordionState {
+  /// The keys of the expanded items.
+  pub fn expanded_keys(&self) -> HashSet<Key> {
+    self.expanded_keys.get()
+  }
+
+  /// Whether the item with the provided key is expanded.
+  pub fn is_expanded(&self, key: &Key) -> bool {
+    self.expanded_keys.signal().with(|keys| keys.contains(key))
+  }
+
+  pub fn expand(&self, key: &Key) {
+    let mut keys = if self.allows_multiple_expanded {
+      self.expanded_keys.get_untracked()
+    } else {
+      HashSet::new()
+    };
+
+    keys.insert(key.clone());
+    self.expanded_keys.set(keys);
+  }
+
+  pub fn collapse(&self, key: &Key) {
+    let mut keys = self.expanded_keys.get_untracked();
+
+    if keys.remove(key) {
+      self.expanded_keys.set(keys);
+    }
+  }
+
+  /// Toggle the expansion of the item.
+  pub fn toggle_key(&self, key: &Key) {
+    if self.expanded_keys.get_untracked().contains(key) {
+      self.collapse(key);
+    } else {
+      self.expand(key);
+    }
+  }
+}
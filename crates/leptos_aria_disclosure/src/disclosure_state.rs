@@ -0,0 +1,66 @@
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseDisclosureStateProps {
+  /// Whether the panel is expanded (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub is_expanded: Option<MaybeSignal<bool>>,
+
+  /// Whether the panel is expanded by default (uncontrolled).
+  #[builder(default)]
+  pub default_expanded: bool,
+
+  /// Handler that is called when the panel is expanded or collapsed.
+  #[builder(default, setter(strip_option))]
+  pub on_expanded_change: Option<Box<dyn Fn(bool)>>,
+}
+
+/// The expanded state of a disclosure.
+#[derive(Clone)]
+pub struct DisclosureState {
+  is_expanded: ControlledState<bool>,
+}
+
+pub fn use_disclosure_state(cx: Scope, props: UseDisclosureStateProps) -> DisclosureState {
+  DisclosureState {
+    is_expanded: use_controlled_state(
+      cx,
+      props.is_expanded,
+      props.default_expanded,
+      props.on_expanded_change,
+    ),
+  }
+}
+
+impl DisclosureState {
+  /// Whether the panel is expanded.
+  pub fn is_expanded(&self) -> bool {
+    self.is_expanded.get()
+  }
+
+  /// The expanded state as a signal.
+  pub fn is_expanded_signal(&self) -> Signal<bool> {
+    self.is_expanded.signal()
+  }
+
+  pub fn set_expanded(&self, is_expanded: bool) {
+    self.is_expanded.set(is_expanded);
+  }
+
+  pub fn expand(&self) {
+    self.set_expanded(true);
+  }
+
+  pub fn collapse(&self) {
+    self.set_expanded(false);
+  }
+
+  pub fn toggle(&self) {
+    self.set_expanded(!self.is_expanded.get_untracked());
+  }
+}
@@ -0,0 +1,9 @@
+pub use accordion_state::*;
+pub use disclosure_state::*;
+pub use use_accordion::*;
+pub use use_disclosure::*;
+
+mod accordion_state;
+mod disclosure_state;
+mod use_accordion;
+mod use_disclosure;
@@ -0,0 +1,185 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos_aria_selection::Key;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::use_disclosure::create_disclosure_result;
+use crate::use_disclosure::DisclosureCallback;
+use crate::AccordionState;
+use crate::DisclosureResult;
+
+/// The attribute which identifies the headers of an accordion. It is set on
+/// the trigger buttons so that arrow key navigation moves between headers
+/// instead of the focusable content of the panels.
+pub const DATA_ACCORDION_KEY_ATTRIBUTE: &str = "data-accordion-key";
+
+/// The attribute which identifies the element containing the items of an
+/// accordion. Headers inside a nested accordion belong to the closest
+/// container, so arrow key navigation skips them.
+pub const DATA_ACCORDION_ATTRIBUTE: &str = "data-accordion";
+
+#[derive(Clone)]
+pub struct AccordionResult {
+  /// Set as [`DATA_ACCORDION_ATTRIBUTE`] on the container.
+  pub data_accordion: bool,
+  pub on_key_down: DisclosureCallback<KeyboardEvent>,
+}
+
+/// Provides keyboard navigation between the headers of an accordion.
+///
+/// * <kbd>ArrowDown</kbd> and <kbd>ArrowUp</kbd> move focus to the next and
+///   previous header, wrapping around.
+/// * <kbd>Home</kbd> and <kbd>End</kbd> move focus to the first and last
+///   header.
+///
+/// Disabled headers and the headers of nested accordions are skipped. Spread
+/// the result onto the element containing the items.
+pub fn use_accordion(cx: Scope) -> ReadSignal<AccordionResult> {
+  let on_key_down: DisclosureCallback<KeyboardEvent> = Rc::new(Box::new(|event: KeyboardEvent| {
+    let Some(container) = event
+      .current_target()
+      .map(|target| target.unchecked_into::<Element>())
+    else {
+      return;
+    };
+
+    let Some(current) = event
+      .target()
+      .and_then(|target| target.dyn_into::<Element>().ok())
+      .filter(|target| target.has_attribute(DATA_ACCORDION_KEY_ATTRIBUTE))
+    else {
+      return;
+    };
+
+    let headers = get_headers(&container);
+    let Some(index) = headers
+      .iter()
+      .position(|header| AsRef::<Element>::as_ref(header) == &current)
+    else {
+      return;
+    };
+
+    let next = match KeyboardKey::from(&event) {
+      KeyboardKey::ArrowDown => headers.get((index + 1) % headers.len()),
+      KeyboardKey::ArrowUp => headers.get((index + headers.len() - 1) % headers.len()),
+      KeyboardKey::Home => headers.first(),
+      KeyboardKey::End => headers.last(),
+      _ => return,
+    };
+
+    event.prevent_default();
+    // An accordion containing this one shouldn't move focus as well.
+    event.stop_propagation();
+
+    if let Some(next) = next {
+      next.focus().ok();
+    }
+  }));
+
+  let (accordion_result, _) = create_signal(
+    cx,
+    AccordionResult {
+      data_accordion: true,
+      on_key_down,
+    },
+  );
+
+  accordion_result
+}
+
+#[derive(TypedBuilder)]
+pub struct UseAccordionItemProps {
+  /// The key of the item.
+  #[builder(setter(into))]
+  pub key: Key,
+
+  /// The id of the panel. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// Whether the item is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+#[derive(Clone)]
+pub struct AccordionItemResult {
+  /// Set as [`DATA_ACCORDION_KEY_ATTRIBUTE`] on the trigger.
+  pub data_accordion_key: Key,
+  pub disclosure: DisclosureResult,
+}
+
+/// Provides the trigger and panel props for an item of an accordion.
+pub fn use_accordion_item(
+  cx: Scope,
+  state: &AccordionState,
+  props: UseAccordionItemProps,
+) -> ReadSignal<AccordionItemResult> {
+  let key = props.key;
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let is_expanded = {
+    let state = state.clone();
+    let key = key.clone();
+    (move || state.is_expanded(&key)).derive_signal(cx)
+  };
+  let toggle = {
+    let state = state.clone();
+    let key = key.clone();
+    Rc::new(move || state.toggle_key(&key))
+  };
+
+  let disclosure = create_disclosure_result(
+    cx,
+    props.id.unwrap_or_else(|| use_id(cx)),
+    is_expanded,
+    is_disabled,
+    toggle,
+  );
+  let (accordion_item_result, _) = create_signal(
+    cx,
+    AccordionItemResult {
+      data_accordion_key: key,
+      disclosure,
+    },
+  );
+
+  accordion_item_result
+}
+
+/// The enabled headers of the accordion in document order, without the
+/// headers of nested accordions.
+fn get_headers(container: &Element) -> Vec<HtmlElement> {
+  let Ok(nodes) = container.query_selector_all(&format!("[{DATA_ACCORDION_KEY_ATTRIBUTE}]")) else {
+    return vec![];
+  };
+
+  (0..nodes.length())
+    .filter_map(|index| nodes.item(index))
+    .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+    .filter(|header| !header.has_attribute("disabled"))
+    .filter(|header| {
+      let Some(closest) = header
+        .closest(&format!("[{DATA_ACCORDION_ATTRIBUTE}]"))
+        .ok()
+        .flatten()
+      else {
+        return true;
+      };
+
+      // A container between the header and this accordion is a nested one.
+      &closest == container || !container.contains(Some(&*closest))
+    })
+    .collect()
+}
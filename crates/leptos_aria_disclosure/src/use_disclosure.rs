@@ -0,0 +1,122 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_custom_change_event;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::AriaCustomEvent;
+use leptos_aria_utils::Role;
+
+use crate::DisclosureState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseDisclosureProps {
+  /// The id of the panel. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// Whether the trigger is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+/// The props for the button which shows and hides the panel.
+#[derive(Clone)]
+pub struct DisclosureTriggerResult {
+  pub id: String,
+  pub aria_expanded: Signal<bool>,
+  pub aria_controls: String,
+  pub disabled: Signal<bool>,
+  pub on_click: DisclosureCallback<MouseEvent>,
+}
+
+/// The props for the panel which is shown and hidden.
+#[derive(Clone)]
+pub struct DisclosurePanelResult {
+  pub id: String,
+  pub role: Role,
+  pub aria_labelledby: String,
+  /// Whether the panel is collapsed. The panel can stay mounted so that its
+  /// content is found by the browser's find in page.
+  pub hidden: Signal<bool>,
+}
+
+#[derive(Clone)]
+pub struct DisclosureResult {
+  pub trigger: DisclosureTriggerResult,
+  pub panel: DisclosurePanelResult,
+}
+
+/// Provides the accessibility implementation for a disclosure, a button which
+/// shows and hides a panel of content. The trigger should be a native
+/// `<button>` so that it is activated with <kbd>Enter</kbd> and
+/// <kbd>Space</kbd>.
+pub fn use_disclosure(
+  cx: Scope,
+  state: &DisclosureState,
+  props: UseDisclosureProps,
+) -> ReadSignal<DisclosureResult> {
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let toggle = {
+    let state = state.clone();
+    Rc::new(move || state.toggle())
+  };
+
+  let result = create_disclosure_result(
+    cx,
+    props.id.unwrap_or_else(|| use_id(cx)),
+    state.is_expanded_signal(),
+    is_disabled,
+    toggle,
+  );
+  let (disclosure_result, _) = create_signal(cx, result);
+
+  disclosure_result
+}
+
+pub(crate) type DisclosureCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// Create the trigger and panel props shared by disclosures and accordion
+/// items.
+pub(crate) fn create_disclosure_result(
+  cx: Scope,
+  panel_id: String,
+  is_expanded: Signal<bool>,
+  is_disabled: Signal<bool>,
+  toggle: Rc<dyn Fn()>,
+) -> DisclosureResult {
+  let trigger_id = format!("{panel_id}-trigger");
+  use_custom_change_event(cx, trigger_id.clone(), is_expanded, |is_open| {
+    AriaCustomEvent::OpenChange { is_open }
+  });
+
+  let on_click: DisclosureCallback<MouseEvent> = Rc::new(Box::new(move |_| {
+    if !is_disabled.get_untracked() {
+      toggle();
+    }
+  }));
+
+  DisclosureResult {
+    trigger: DisclosureTriggerResult {
+      id: trigger_id.clone(),
+      aria_expanded: is_expanded,
+      aria_controls: panel_id.clone(),
+      disabled: is_disabled,
+      on_click,
+    },
+    panel: DisclosurePanelResult {
+      id: panel_id,
+      role: Role::Group,
+      aria_labelledby: trigger_id,
+      hidden: (move || !is_expanded.get()).derive_signal(cx),
+    },
+  }
+}
@@ -0,0 +1,227 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use leptos::document;
+use leptos::request_animation_frame;
+use leptos::web_sys::Element;
+use leptos::window;
+use leptos_aria_utils::get_scroll_parent;
+use leptos_aria_utils::is_scrollable;
+
+/// The distance from the edge of the scrollable element, in pixels, within
+/// which it scrolls.
+const EDGE_SIZE: f64 = 40.0;
+/// The distance scrolled in each frame at the very edge, before
+/// acceleration.
+const BASE_SPEED: f64 = 8.0;
+/// The number of frames after which scrolling reaches its maximum speed.
+const ACCELERATION_FRAMES: f64 = 60.0;
+/// The maximum speed as a multiple of [`BASE_SPEED`].
+const MAX_ACCELERATION: f64 = 4.0;
+
+#[derive(Default)]
+struct AutoScrollState {
+  scrollable: Option<Element>,
+  /// How close the pointer is to each edge, from `-1.0` at the start edge to
+  /// `1.0` at the end edge.
+  delta: (f64, f64),
+  frames: u32,
+  is_scrolling: bool,
+  /// Incremented whenever scrolling starts, so that the frame of a previous
+  /// scroll which was stopped doesn't continue.
+  generation: u32,
+}
+
+/// Scrolls the nearest scrollable ancestor of a drop target while a drag
+/// nears its edges, speeding up the longer the pointer stays there.
+#[derive(Clone, Default)]
+pub(crate) struct AutoScroller(Rc<RefCell<AutoScrollState>>);
+
+impl AutoScroller {
+  /// Update the position of the pointer, in client coordinates, while it
+  /// drags over the element.
+  pub(crate) fn update(&self, element: &Element, client_x: f64, client_y: f64) {
+    let Some(scrollable) = get_scrollable_parent(element) else {
+      self.stop();
+      return;
+    };
+
+    let (left, top, right, bottom) = get_viewport(&scrollable);
+    let delta = (
+      get_edge_delta(client_x, left, right),
+      get_edge_delta(client_y, top, bottom),
+    );
+
+    let should_start = {
+      let mut state = self.0.borrow_mut();
+
+      if delta == (0.0, 0.0) {
+        state.frames = 0;
+      }
+
+      state.delta = delta;
+      state.scrollable = Some(scrollable);
+      delta != (0.0, 0.0) && !state.is_scrolling
+    };
+
+    if should_start {
+      let generation = {
+        let mut state = self.0.borrow_mut();
+        state.is_scrolling = true;
+        state.generation = state.generation.wrapping_add(1);
+        state.generation
+      };
+
+      scroll_frame(self.0.clone(), generation);
+    }
+  }
+
+  /// Scroll the nearest scrollable ancestor of the element just enough to
+  /// show it, e.g. the target of a keyboard drag.
+  pub(crate) fn reveal(&self, element: &Element) {
+    let Some(scrollable) = get_scrollable_parent(element) else {
+      return;
+    };
+
+    let rect = element.get_bounding_client_rect();
+    let (left, top, right, bottom) = get_viewport(&scrollable);
+
+    scrollable.scroll_by_with_x_and_y(
+      get_reveal_offset(rect.left(), rect.right(), left, right),
+      get_reveal_offset(rect.top(), rect.bottom(), top, bottom),
+    );
+  }
+
+  /// Stop scrolling, e.g. when the drag leaves the target or ends.
+  pub(crate) fn stop(&self) {
+    let mut state = self.0.borrow_mut();
+    state.scrollable = None;
+    state.delta = (0.0, 0.0);
+    state.frames = 0;
+    state.is_scrolling = false;
+  }
+}
+
+fn scroll_frame(state: Rc<RefCell<AutoScrollState>>, generation: u32) {
+  let step = {
+    let mut state = state.borrow_mut();
+
+    if state.generation != generation {
+      return;
+    }
+
+    match state.scrollable.clone() {
+      Some(scrollable) if state.is_scrolling && state.delta != (0.0, 0.0) => {
+        state.frames += 1;
+        let (delta_x, delta_y) = state.delta;
+        let speed = get_speed(state.frames);
+        Some((scrollable, delta_x * speed, delta_y * speed))
+      }
+      _ => {
+        state.is_scrolling = false;
+        None
+      }
+    }
+  };
+
+  let Some((scrollable, x, y)) = step else {
+    return;
+  };
+
+  scrollable.scroll_by_with_x_and_y(x, y);
+  request_animation_frame(move || scroll_frame(state, generation));
+}
+
+/// The element itself when it scrolls, otherwise its nearest scrollable
+/// ancestor, falling back to the document.
+fn get_scrollable_parent(element: &Element) -> Option<Element> {
+  if is_scrollable(element) {
+    return Some(element.clone());
+  }
+
+  get_scroll_parent(element)
+}
+
+/// The visible area of the scrollable element in client coordinates, as
+/// `(left, top, right, bottom)`.
+fn get_viewport(scrollable: &Element) -> (f64, f64, f64, f64) {
+  if document().scrolling_element().as_ref() == Some(scrollable) {
+    let width = window().inner_width().ok().and_then(|width| width.as_f64());
+    let height = window()
+      .inner_height()
+      .ok()
+      .and_then(|height| height.as_f64());
+    return (
+      0.0,
+      0.0,
+      width.unwrap_or_default(),
+      height.unwrap_or_default(),
+    );
+  }
+
+  let rect = scrollable.get_bounding_client_rect();
+  (rect.left(), rect.top(), rect.right(), rect.bottom())
+}
+
+/// How far into the edge area the position is, from `-1.0` at the start to
+/// `1.0` at the end, and `0.0` away from the edges.
+fn get_edge_delta(position: f64, start: f64, end: f64) -> f64 {
+  let edge_size = EDGE_SIZE.min((end - start) / 4.0);
+
+  if edge_size <= 0.0 {
+    0.0
+  } else if position < start + edge_size {
+    -(1.0 - ((position - start) / edge_size).max(0.0))
+  } else if position > end - edge_size {
+    1.0 - ((end - position) / edge_size).max(0.0)
+  } else {
+    0.0
+  }
+}
+
+fn get_speed(frames: u32) -> f64 {
+  BASE_SPEED * (1.0 + f64::from(frames) / ACCELERATION_FRAMES).min(MAX_ACCELERATION)
+}
+
+/// The distance to scroll so that the item is within the view, preferring to
+/// show its start when it is larger than the view.
+fn get_reveal_offset(item_start: f64, item_end: f64, view_start: f64, view_end: f64) -> f64 {
+  if item_start < view_start {
+    item_start - view_start
+  } else if item_end > view_end {
+    (item_end - view_end).min(item_start - view_start)
+  } else {
+    0.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn scrolls_faster_nearer_the_edges() {
+    assert_eq!(get_edge_delta(200.0, 0.0, 400.0), 0.0);
+    assert_eq!(get_edge_delta(0.0, 0.0, 400.0), -1.0);
+    assert_eq!(get_edge_delta(20.0, 0.0, 400.0), -0.5);
+    assert_eq!(get_edge_delta(390.0, 0.0, 400.0), 0.75);
+    assert_eq!(get_edge_delta(500.0, 0.0, 400.0), 1.0);
+    // Small views shrink the edges so that the middle doesn't scroll.
+    assert_eq!(get_edge_delta(50.0, 0.0, 100.0), 0.0);
+  }
+
+  #[test]
+  fn accelerates_up_to_a_maximum() {
+    assert_eq!(get_speed(0), BASE_SPEED);
+    assert_eq!(get_speed(60), BASE_SPEED * 2.0);
+    assert_eq!(get_speed(10_000), BASE_SPEED * MAX_ACCELERATION);
+  }
+
+  #[test]
+  fn reveals_items_outside_the_view() {
+    assert_eq!(get_reveal_offset(50.0, 80.0, 0.0, 100.0), 0.0);
+    assert_eq!(get_reveal_offset(-30.0, 0.0, 0.0, 100.0), -30.0);
+    assert_eq!(get_reveal_offset(90.0, 120.0, 0.0, 100.0), 20.0);
+    assert_eq!(get_reveal_offset(40.0, 300.0, 0.0, 100.0), 40.0);
+  }
+}
@@ -0,0 +1,359 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use leptos::document;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::Node;
+use leptos::JsCast;
+use leptos_aria_i18n::LocalizedStringFormatter;
+use leptos_aria_selection::Key;
+use leptos_aria_utils::add_event_listener;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::Direction;
+use leptos_aria_utils::EventListenerHandle;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::Politeness;
+
+use crate::DragEndEvent;
+use crate::DragItem;
+use crate::DragTypes;
+use crate::DropEvent;
+use crate::DropOperation;
+
+/// A drop target registered by [`use_drop`](crate::use_drop), which can be
+/// chosen during a keyboard drag.
+pub(crate) struct DropTarget {
+  pub(crate) id: String,
+  pub(crate) get_drop_operation: Rc<dyn Fn(&DragTypes, &[DropOperation]) -> DropOperation>,
+  pub(crate) on_drop: Rc<dyn Fn(DropEvent)>,
+  pub(crate) on_drop_enter: Rc<dyn Fn()>,
+  pub(crate) on_drop_exit: Rc<dyn Fn()>,
+  /// Called with `true` when a keyboard drag starts which the target accepts,
+  /// and with `false` when it ends.
+  pub(crate) on_drag_active: Rc<dyn Fn(bool)>,
+  /// Moves between the positions within the target, e.g. the items of a
+  /// collection, when the arrow keys are pressed while it is the current
+  /// target. Called with `forward` after the target is entered.
+  pub(crate) navigate: Option<Rc<dyn Fn(&DragTypes, &[DropOperation], bool)>>,
+}
+
+/// A keyboard drag started by [`use_drag`](crate::use_drag).
+pub(crate) struct DragSession {
+  pub(crate) source_id: String,
+  pub(crate) items: Vec<DragItem>,
+  pub(crate) allowed_operations: Vec<DropOperation>,
+  pub(crate) on_drag_end: Rc<dyn Fn(DragEndEvent)>,
+  pub(crate) strings: LocalizedStringFormatter,
+  /// The reading direction of the source, which swaps the horizontal arrow
+  /// keys while dragging.
+  pub(crate) direction: Direction,
+}
+
+/// The keys dragged from a collection by
+/// [`use_draggable_item`](crate::use_draggable_item), so that a drop into the
+/// same collection can be handled as a reorder.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DraggedCollection {
+  pub(crate) collection_id: String,
+  pub(crate) keys: Vec<Key>,
+}
+
+struct ActiveSession {
+  session: DragSession,
+  types: DragTypes,
+  /// The targets which accept the items, in document order.
+  targets: Vec<Rc<DropTarget>>,
+  current: Option<usize>,
+  listener: EventListenerHandle,
+}
+
+thread_local! {
+  static DROP_TARGETS: RefCell<Vec<Rc<DropTarget>>> = RefCell::new(vec![]);
+  static ACTIVE_SESSION: RefCell<Option<ActiveSession>> = RefCell::new(None);
+  static DRAGGED_COLLECTION: RefCell<Option<DraggedCollection>> = RefCell::new(None);
+}
+
+pub(crate) fn set_dragged_collection(dragged: Option<DraggedCollection>) {
+  DRAGGED_COLLECTION.with(|current| *current.borrow_mut() = dragged);
+}
+
+pub(crate) fn dragged_collection() -> Option<DraggedCollection> {
+  DRAGGED_COLLECTION.with(|current| current.borrow().clone())
+}
+
+/// Drop targets are only tracked in the browser, since nothing can be dragged
+/// while rendering on the server.
+pub(crate) fn register_drop_target(target: DropTarget) {
+  if is_server() {
+    return;
+  }
+
+  DROP_TARGETS.with(|targets| targets.borrow_mut().push(Rc::new(target)));
+}
+
+pub(crate) fn unregister_drop_target(id: &str) {
+  DROP_TARGETS.with(|targets| targets.borrow_mut().retain(|target| target.id != id));
+
+  ACTIVE_SESSION.with(|active| {
+    if let Some(ref mut active) = *active.borrow_mut() {
+      let current_id = active.current.map(|index| active.targets[index].id.clone());
+      active.targets.retain(|target| target.id != id);
+      active.current = current_id.and_then(|current_id| {
+        active
+          .targets
+          .iter()
+          .position(|target| target.id == current_id)
+      });
+    }
+  });
+}
+
+pub(crate) fn is_keyboard_dragging() -> bool {
+  ACTIVE_SESSION.with(|active| active.borrow().is_some())
+}
+
+/// Start dragging with the keyboard. Focus moves to the first target which
+/// accepts the items, `Tab` and the arrow keys move between the targets,
+/// `Enter` drops the items and `Escape` cancels the drag. Within targets which
+/// have positions of their own the arrow keys move between the positions.
+/// <kbd>ArrowLeft</kbd> and <kbd>ArrowRight</kbd> are swapped in right to left
+/// locales.
+pub(crate) fn begin_keyboard_drag(session: DragSession) {
+  if is_keyboard_dragging() {
+    cancel_keyboard_drag();
+  }
+
+  let types = DragTypes::from_items(&session.items);
+  let mut targets: Vec<(Rc<DropTarget>, Node)> = DROP_TARGETS.with(|targets| {
+    targets
+      .borrow()
+      .iter()
+      .filter(|target| target.id != session.source_id)
+      .filter(|target| {
+        (target.get_drop_operation)(&types, &session.allowed_operations) != DropOperation::Cancel
+      })
+      .filter_map(|target| {
+        let element = document().get_element_by_id(&target.id)?;
+        Some((target.clone(), element.into()))
+      })
+      .collect()
+  });
+  targets.sort_by(|(_, a), (_, b)| document_order(a, b));
+  let targets: Vec<Rc<DropTarget>> = targets.into_iter().map(|(target, _)| target).collect();
+
+  let listener = add_event_listener(document(), "keydown", on_key_down, true);
+
+  announce(session.strings.format("dragStarted"), Politeness::Assertive);
+
+  for target in &targets {
+    (target.on_drag_active)(true);
+  }
+
+  ACTIVE_SESSION.with(|active| {
+    *active.borrow_mut() = Some(ActiveSession {
+      session,
+      types,
+      targets,
+      current: None,
+      listener,
+    });
+  });
+
+  move_to_target(true);
+}
+
+pub(crate) fn cancel_keyboard_drag() {
+  let Some(active) = take_active_session() else {
+    return;
+  };
+
+  if let Some(index) = active.current {
+    (active.targets[index].on_drop_exit)();
+  }
+
+  announce(
+    active.session.strings.format("dropCanceled"),
+    Politeness::Assertive,
+  );
+  focus_element_by_id(&active.session.source_id);
+  end_session(active, DropOperation::Cancel);
+}
+
+fn drop_on_current_target() {
+  let target = ACTIVE_SESSION.with(|active| {
+    let active = active.borrow();
+    let active = active.as_ref()?;
+    let target = active.targets.get(active.current?)?;
+    let operation = (target.get_drop_operation)(&active.types, &active.session.allowed_operations);
+    Some((target.clone(), operation))
+  });
+
+  let Some((target, operation)) = target else {
+    return;
+  };
+
+  if operation == DropOperation::Cancel {
+    cancel_keyboard_drag();
+    return;
+  }
+
+  let Some(active) = take_active_session() else {
+    return;
+  };
+
+  (target.on_drop_exit)();
+  (target.on_drop)(DropEvent {
+    items: active.session.items.clone(),
+    drop_operation: operation,
+  });
+  announce(
+    active.session.strings.format("dropComplete"),
+    Politeness::Assertive,
+  );
+  end_session(active, operation);
+}
+
+/// Move to the next or previous target, wrapping around at either end.
+fn move_to_target(forward: bool) {
+  let change = ACTIVE_SESSION.with(|active| {
+    let mut active = active.borrow_mut();
+    let active = active.as_mut()?;
+    let next = next_index(active.current, active.targets.len(), forward)?;
+    let previous = active.current.replace(next);
+
+    Some((
+      previous.map(|index| active.targets[index].clone()),
+      active.targets[next].clone(),
+      active.types.clone(),
+      active.session.allowed_operations.clone(),
+    ))
+  });
+
+  let Some((previous, next, types, allowed_operations)) = change else {
+    return;
+  };
+
+  if let Some(previous) = previous {
+    (previous.on_drop_exit)();
+  }
+
+  (next.on_drop_enter)();
+  focus_element_by_id(&next.id);
+
+  if let Some(ref navigate) = next.navigate {
+    navigate(&types, &allowed_operations, forward);
+  }
+}
+
+/// Move within the current target when it has positions of its own, and to
+/// the next or previous target otherwise.
+fn navigate_within_target(forward: bool) {
+  let navigation = ACTIVE_SESSION.with(|active| {
+    let active = active.borrow();
+    let active = active.as_ref()?;
+    let target = active.targets.get(active.current?)?;
+
+    Some((
+      target.navigate.clone()?,
+      active.types.clone(),
+      active.session.allowed_operations.clone(),
+    ))
+  });
+
+  match navigation {
+    Some((navigate, types, allowed_operations)) => navigate(&types, &allowed_operations, forward),
+    None => move_to_target(forward),
+  }
+}
+
+pub(crate) fn next_index(current: Option<usize>, len: usize, forward: bool) -> Option<usize> {
+  if len == 0 {
+    return None;
+  }
+
+  Some(match (current, forward) {
+    (None, true) => 0,
+    (None, false) => len - 1,
+    (Some(index), true) => (index + 1) % len,
+    (Some(index), false) => (index + len - 1) % len,
+  })
+}
+
+fn on_key_down(event: KeyboardEvent) {
+  let direction = ACTIVE_SESSION.with(|active| {
+    active
+      .borrow()
+      .as_ref()
+      .map(|active| active.session.direction)
+      .unwrap_or_default()
+  });
+
+  match KeyboardKey::from(&event).logical(direction) {
+    KeyboardKey::Tab => move_to_target(!event.shift_key()),
+    KeyboardKey::ArrowDown | KeyboardKey::ArrowRight => navigate_within_target(true),
+    KeyboardKey::ArrowUp | KeyboardKey::ArrowLeft => navigate_within_target(false),
+    KeyboardKey::Enter => drop_on_current_target(),
+    KeyboardKey::Escape => cancel_keyboard_drag(),
+    _ => return,
+  }
+
+  event.prevent_default();
+  event.stop_propagation();
+}
+
+fn take_active_session() -> Option<ActiveSession> {
+  ACTIVE_SESSION.with(|active| active.borrow_mut().take())
+}
+
+/// The callbacks are called after the session is removed, since they may
+/// update the registered targets.
+fn end_session(active: ActiveSession, operation: DropOperation) {
+  active.listener.remove();
+
+  for target in &active.targets {
+    (target.on_drag_active)(false);
+  }
+
+  (active.session.on_drag_end)(DragEndEvent {
+    drop_operation: operation,
+  });
+}
+
+fn document_order(a: &Node, b: &Node) -> Ordering {
+  let position = a.compare_document_position(b);
+
+  if position & Node::DOCUMENT_POSITION_FOLLOWING != 0 {
+    Ordering::Less
+  } else if position & Node::DOCUMENT_POSITION_PRECEDING != 0 {
+    Ordering::Greater
+  } else {
+    Ordering::Equal
+  }
+}
+
+fn focus_element_by_id(id: &str) {
+  if let Some(element) = document()
+    .get_element_by_id(id)
+    .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+  {
+    let _ = element.focus();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cycles_through_targets() {
+    assert_eq!(next_index(None, 0, true), None);
+    assert_eq!(next_index(None, 3, true), Some(0));
+    assert_eq!(next_index(None, 3, false), Some(2));
+    assert_eq!(next_index(Some(2), 3, true), Some(0));
+    assert_eq!(next_index(Some(0), 3, false), Some(2));
+    assert_eq!(next_index(Some(1), 3, true), Some(2));
+  }
+}
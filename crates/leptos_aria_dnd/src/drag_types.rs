@@ -0,0 +1,212 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+pub type DndCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// A dragged item, with its data in one or more formats so that each drop
+/// target can use the representation it understands, e.g. `text/plain` and
+/// `text/html`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DragItem {
+  data: BTreeMap<String, String>,
+}
+
+impl DragItem {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Add the data of a format while building the item, e.g.
+  /// `DragItem::new().with("text/plain", "Ada")`.
+  pub fn with(mut self, format: impl Into<String>, data: impl Into<String>) -> Self {
+    self.insert(format, data);
+    self
+  }
+
+  /// Add the data of a format, replacing any previous data for it.
+  pub fn insert(&mut self, format: impl Into<String>, data: impl Into<String>) {
+    self.data.insert(format.into(), data.into());
+  }
+
+  pub fn get(&self, format: &str) -> Option<&str> {
+    self.data.get(format).map(String::as_str)
+  }
+
+  /// The `text/plain` data of the item.
+  pub fn text(&self) -> Option<&str> {
+    self.get("text/plain")
+  }
+
+  pub fn formats(&self) -> impl Iterator<Item = &str> {
+    self.data.keys().map(String::as_str)
+  }
+}
+
+/// The formats of the dragged items, which is all a drop target can inspect
+/// before the items are dropped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DragTypes(BTreeSet<String>);
+
+impl DragTypes {
+  pub fn from_items(items: &[DragItem]) -> Self {
+    items
+      .iter()
+      .flat_map(DragItem::formats)
+      .map(String::from)
+      .collect()
+  }
+
+  pub fn has(&self, format: &str) -> bool {
+    self.0.contains(format)
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &str> {
+    self.0.iter().map(String::as_str)
+  }
+}
+
+impl FromIterator<String> for DragTypes {
+  fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+    Self(iter.into_iter().collect())
+  }
+}
+
+/// What happens to the dragged items when they are dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DropOperation {
+  Copy,
+  Link,
+  Move,
+  /// The items can't be dropped, or the drag was canceled.
+  Cancel,
+}
+
+impl DropOperation {
+  /// The value of `dataTransfer.dropEffect`.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Copy => "copy",
+      Self::Link => "link",
+      Self::Move => "move",
+      Self::Cancel => "none",
+    }
+  }
+
+  pub(crate) fn from_drop_effect(drop_effect: &str) -> Self {
+    match drop_effect {
+      "copy" => Self::Copy,
+      "link" => Self::Link,
+      "move" => Self::Move,
+      _ => Self::Cancel,
+    }
+  }
+}
+
+/// The operations of a drag source which doesn't restrict them.
+pub(crate) const ALL_OPERATIONS: [DropOperation; 3] = [
+  DropOperation::Move,
+  DropOperation::Copy,
+  DropOperation::Link,
+];
+
+/// The value of `dataTransfer.effectAllowed` for the operations.
+pub(crate) fn effect_allowed(operations: &[DropOperation]) -> &'static str {
+  let allows = |operation| operations.contains(&operation);
+
+  match (
+    allows(DropOperation::Copy),
+    allows(DropOperation::Link),
+    allows(DropOperation::Move),
+  ) {
+    (true, true, true) => "all",
+    (true, true, false) => "copyLink",
+    (true, false, true) => "copyMove",
+    (false, true, true) => "linkMove",
+    (true, false, false) => "copy",
+    (false, true, false) => "link",
+    (false, false, true) => "move",
+    (false, false, false) => "none",
+  }
+}
+
+/// The operations allowed by `dataTransfer.effectAllowed`. Drags from other
+/// applications are often `"uninitialized"`, which allows everything.
+pub(crate) fn allowed_operations(effect_allowed: &str) -> Vec<DropOperation> {
+  let effect_allowed = effect_allowed.to_lowercase();
+
+  if matches!(effect_allowed.as_str(), "all" | "uninitialized" | "") {
+    return ALL_OPERATIONS.to_vec();
+  }
+
+  ALL_OPERATIONS
+    .into_iter()
+    .filter(|operation| effect_allowed.contains(operation.as_str()))
+    .collect()
+}
+
+/// Accept the first operation the drag source allows.
+pub(crate) fn default_drop_operation(_: &DragTypes, allowed: &[DropOperation]) -> DropOperation {
+  allowed
+    .iter()
+    .copied()
+    .find(|operation| *operation != DropOperation::Cancel)
+    .unwrap_or(DropOperation::Cancel)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropEvent {
+  pub items: Vec<DragItem>,
+  pub drop_operation: DropOperation,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DragEndEvent {
+  /// [`DropOperation::Cancel`] when the items weren't dropped.
+  pub drop_operation: DropOperation,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn effect_allowed_round_trips() {
+    let cases: &[&[DropOperation]] = &[
+      &ALL_OPERATIONS,
+      &[DropOperation::Copy, DropOperation::Move],
+      &[DropOperation::Link],
+      &[],
+    ];
+
+    for operations in cases {
+      let mut parsed = allowed_operations(effect_allowed(operations));
+      let mut expected = operations.to_vec();
+      parsed.sort_by_key(|operation| operation.as_str());
+      expected.sort_by_key(|operation| operation.as_str());
+      assert_eq!(parsed, expected);
+    }
+
+    assert_eq!(allowed_operations("uninitialized"), ALL_OPERATIONS.to_vec());
+  }
+
+  #[test]
+  fn collects_the_formats_of_all_items() {
+    let items = [
+      DragItem::new()
+        .with("text/plain", "Ada")
+        .with("text/html", "<b>Ada</b>"),
+      DragItem::new().with("text/plain", "Bo"),
+    ];
+    let types = DragTypes::from_items(&items);
+
+    assert!(types.has("text/plain"));
+    assert!(types.has("text/html"));
+    assert!(!types.has("image/png"));
+    assert_eq!(
+      default_drop_operation(&types, &[DropOperation::Copy]),
+      DropOperation::Copy
+    );
+    assert_eq!(default_drop_operation(&types, &[]), DropOperation::Cancel);
+  }
+}
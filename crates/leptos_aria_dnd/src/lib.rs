@@ -0,0 +1,17 @@
+pub use drag_types::*;
+pub use use_clipboard::*;
+pub use use_drag::*;
+pub use use_draggable_collection::*;
+pub use use_drop::*;
+pub use use_drop_indicator::*;
+pub use use_droppable_collection::*;
+
+mod auto_scroll;
+mod drag_manager;
+mod drag_types;
+mod use_clipboard;
+mod use_drag;
+mod use_draggable_collection;
+mod use_drop;
+mod use_drop_indicator;
+mod use_droppable_collection;
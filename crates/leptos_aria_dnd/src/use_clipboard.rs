@@ -0,0 +1,267 @@
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::ClipboardEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_selection::Key;
+use leptos_aria_selection::SelectionManager;
+
+use crate::use_draggable_collection::get_selected_keys;
+use crate::use_drop::read_drag_items;
+use crate::DndCallback;
+use crate::DragItem;
+
+type ClipboardKeysCallback = Box<dyn Fn(&[Key])>;
+
+#[derive(TypedBuilder)]
+pub struct UseClipboardProps {
+  /// The items which are copied or cut. Copying isn't handled when not
+  /// provided.
+  #[builder(default, setter(strip_option))]
+  pub get_items: Option<Box<dyn Fn() -> Vec<DragItem>>>,
+
+  /// Handler that is called after the items are copied.
+  #[builder(default, setter(strip_option))]
+  pub on_copy: Option<Box<dyn Fn()>>,
+
+  /// Handler that is called after the items are cut, which should remove
+  /// them. Cutting isn't handled when not provided.
+  #[builder(default, setter(strip_option))]
+  pub on_cut: Option<Box<dyn Fn()>>,
+
+  /// Handler that is called with the pasted items. Pasting isn't handled
+  /// when not provided.
+  #[builder(default, setter(strip_option))]
+  pub on_paste: Option<Box<dyn Fn(Vec<DragItem>)>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+#[derive(Clone)]
+pub struct ClipboardResult {
+  pub on_copy: DndCallback<ClipboardEvent>,
+  pub on_cut: DndCallback<ClipboardEvent>,
+  pub on_paste: DndCallback<ClipboardEvent>,
+}
+
+/// Handles copying, cutting and pasting [`DragItem`]s while the element, or
+/// an element within it, is focused.
+///
+/// The data of each format is written to the clipboard with the data of
+/// every item joined by new lines. Pasted items are combined into a single
+/// item with the data of each format, as with native drops.
+///
+/// Events which aren't handled, e.g. copying when there are no items, keep
+/// their native behavior.
+pub fn use_clipboard(cx: Scope, props: UseClipboardProps) -> ReadSignal<ClipboardResult> {
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let get_items = props.get_items;
+
+  let write_items = move |event: &ClipboardEvent| {
+    if is_disabled.get_untracked() {
+      return false;
+    }
+
+    let (Some(get_items), Some(clipboard_data)) = (get_items.as_ref(), event.clipboard_data())
+    else {
+      return false;
+    };
+    let data = get_clipboard_data(&get_items());
+
+    if data.is_empty() {
+      return false;
+    }
+
+    for (format, value) in data {
+      if clipboard_data.set_data(&format, &value).is_err() {
+        return false;
+      }
+    }
+
+    event.prevent_default();
+    true
+  };
+  let write_items = Rc::new(write_items);
+
+  let on_copy: DndCallback<ClipboardEvent> = {
+    let write_items = write_items.clone();
+    let on_copy = props.on_copy;
+
+    Rc::new(Box::new(move |event: ClipboardEvent| {
+      if !write_items(&event) {
+        return;
+      }
+
+      if let Some(ref on_copy) = on_copy {
+        on_copy();
+      }
+    }))
+  };
+
+  let on_cut: DndCallback<ClipboardEvent> = {
+    let on_cut = props.on_cut;
+
+    Rc::new(Box::new(move |event: ClipboardEvent| {
+      let Some(ref on_cut) = on_cut else {
+        return;
+      };
+
+      if write_items(&event) {
+        on_cut();
+      }
+    }))
+  };
+
+  let on_paste: DndCallback<ClipboardEvent> = {
+    let on_paste = props.on_paste;
+
+    Rc::new(Box::new(move |event: ClipboardEvent| {
+      if is_disabled.get_untracked() {
+        return;
+      }
+
+      let (Some(on_paste), Some(clipboard_data)) = (on_paste.as_ref(), event.clipboard_data())
+      else {
+        return;
+      };
+
+      event.prevent_default();
+      on_paste(read_drag_items(&clipboard_data));
+    }))
+  };
+
+  let (clipboard, _) = create_signal(
+    cx,
+    ClipboardResult {
+      on_copy,
+      on_cut,
+      on_paste,
+    },
+  );
+
+  clipboard
+}
+
+#[derive(TypedBuilder)]
+pub struct UseCollectionClipboardProps {
+  pub selection_manager: SelectionManager,
+
+  /// The keys of the collection in the order they are displayed, which is
+  /// the order of the copied items.
+  #[builder(setter(into))]
+  pub keys: MaybeSignal<Vec<Key>>,
+
+  /// The items for the selected keys.
+  pub get_items: Box<dyn Fn(&[Key]) -> Vec<DragItem>>,
+
+  /// Handler that is called with the selected keys after they are copied.
+  #[builder(default, setter(strip_option))]
+  pub on_copy: Option<ClipboardKeysCallback>,
+
+  /// Handler that is called with the selected keys after they are cut, which
+  /// should remove them. Cutting isn't handled when not provided.
+  #[builder(default, setter(strip_option))]
+  pub on_cut: Option<ClipboardKeysCallback>,
+
+  /// Handler that is called with the pasted items. Pasting isn't handled
+  /// when not provided.
+  #[builder(default, setter(strip_option))]
+  pub on_paste: Option<Box<dyn Fn(Vec<DragItem>)>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+/// Copies and cuts the selected items of a collection such as a listbox,
+/// table or grid list, in the order they are displayed. Disabled items are
+/// left out.
+pub fn use_collection_clipboard(
+  cx: Scope,
+  props: UseCollectionClipboardProps,
+) -> ReadSignal<ClipboardResult> {
+  let selection_manager = props.selection_manager;
+  let original_keys = props.keys;
+  let keys = (move || original_keys.get()).derive_signal(cx);
+  let selected_keys = Rc::new(move || {
+    get_selected_keys(
+      &keys.get_untracked(),
+      &selection_manager.selected_keys_untracked(),
+      &selection_manager.disabled_keys.get_untracked(),
+    )
+  });
+
+  let get_items = {
+    let selected_keys = selected_keys.clone();
+    let get_items = props.get_items;
+
+    move || get_items(&selected_keys())
+  };
+
+  let mut clipboard_props = UseClipboardProps::builder()
+    .get_items(Box::new(get_items))
+    .build();
+  clipboard_props.on_paste = props.on_paste;
+  clipboard_props.is_disabled = props.is_disabled;
+
+  if let Some(on_copy) = props.on_copy {
+    let selected_keys = selected_keys.clone();
+    clipboard_props.on_copy = Some(Box::new(move || on_copy(&selected_keys())));
+  }
+
+  if let Some(on_cut) = props.on_cut {
+    clipboard_props.on_cut = Some(Box::new(move || on_cut(&selected_keys())));
+  }
+
+  use_clipboard(cx, clipboard_props)
+}
+
+/// The data of each format of the items, joined by new lines in the order of
+/// the items.
+fn get_clipboard_data(items: &[DragItem]) -> Vec<(String, String)> {
+  let mut data = BTreeMap::<&str, Vec<&str>>::new();
+
+  for item in items {
+    for format in item.formats() {
+      if let Some(value) = item.get(format) {
+        data.entry(format).or_default().push(value);
+      }
+    }
+  }
+
+  data
+    .into_iter()
+    .map(|(format, values)| (format.to_string(), values.join("\n")))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn joins_the_data_of_each_format() {
+    let items = [
+      DragItem::new()
+        .with("text/plain", "Ada")
+        .with("text/html", "<b>Ada</b>"),
+      DragItem::new().with("text/plain", "Bo"),
+    ];
+
+    assert_eq!(
+      get_clipboard_data(&items),
+      vec![
+        ("text/html".to_string(), "<b>Ada</b>".to_string()),
+        ("text/plain".to_string(), "Ada\nBo".to_string()),
+      ]
+    );
+    assert!(get_clipboard_data(&[]).is_empty());
+  }
+}
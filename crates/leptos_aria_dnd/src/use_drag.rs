@@ -0,0 +1,201 @@
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::DragEvent;
+use leptos::web_sys::KeyboardEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_utils::use_description;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::drag_manager::begin_keyboard_drag;
+use crate::drag_manager::DragSession;
+use crate::drag_types::effect_allowed;
+use crate::drag_types::ALL_OPERATIONS;
+use crate::DndCallback;
+use crate::DragEndEvent;
+use crate::DragItem;
+use crate::DropOperation;
+
+#[derive(TypedBuilder)]
+pub struct UseDragProps {
+  /// The items to drag, read when the drag starts.
+  pub get_items: Box<dyn Fn() -> Vec<DragItem>>,
+
+  /// The operations which the drop target may choose from. Defaults to all
+  /// of them.
+  #[builder(default, setter(strip_option))]
+  pub get_allowed_operations: Option<Box<dyn Fn() -> Vec<DropOperation>>>,
+
+  /// Handler that is called when the drag starts.
+  #[builder(default, setter(strip_option))]
+  pub on_drag_start: Option<Box<dyn Fn()>>,
+
+  /// Handler that is called when the drag ends, with the operation chosen by
+  /// the drop target.
+  #[builder(default, setter(strip_option))]
+  pub on_drag_end: Option<Box<dyn Fn(DragEndEvent)>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// The id of the draggable element, which receives focus again when a
+  /// keyboard drag is canceled. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct DragResult {
+  pub id: String,
+  pub draggable: Signal<bool>,
+  pub is_dragging: Signal<bool>,
+  /// Describes how to start a keyboard drag, while the element is enabled.
+  pub aria_describedby: Signal<Option<String>>,
+  pub on_drag_start: DndCallback<DragEvent>,
+  pub on_drag_end: DndCallback<DragEvent>,
+  pub on_key_down: DndCallback<KeyboardEvent>,
+}
+
+/// Makes an element draggable with the mouse, touch or the keyboard.
+///
+/// Pointer drags use the native HTML drag and drop events, with the data of
+/// each format of the items written to the `dataTransfer`. Since a
+/// `dataTransfer` holds one value for each format, the first item which has a
+/// format provides its data.
+///
+/// Pressing `Enter` on the element starts a keyboard drag, which moves focus
+/// between the targets registered with [`use_drop`](crate::use_drop) and
+/// announces when the drag starts, completes or is canceled.
+pub fn use_drag(cx: Scope, props: UseDragProps) -> ReadSignal<DragResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let get_items = Rc::new(props.get_items);
+  let get_allowed_operations = props.get_allowed_operations;
+  let on_drag_start = props.on_drag_start;
+  let on_drag_end = props.on_drag_end;
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let is_dragging = create_rw_signal(cx, false);
+  let strings = use_localized_string_formatter(cx);
+  let direction = use_direction(cx);
+  let description = (move || (!is_disabled.get()).then(|| strings.get().format("dragDescription")))
+    .derive_signal(cx);
+  let aria_describedby = use_description(cx, description);
+
+  let allowed_operations = move || {
+    get_allowed_operations
+      .as_ref()
+      .map_or_else(|| ALL_OPERATIONS.to_vec(), |get| get())
+  };
+  let allowed_operations = Rc::new(allowed_operations);
+
+  let end_drag = move |event: DragEndEvent| {
+    is_dragging.set(false);
+
+    if let Some(ref on_drag_end) = on_drag_end {
+      on_drag_end(event);
+    }
+  };
+  let end_drag = Rc::new(end_drag);
+
+  let start_drag = move || {
+    is_dragging.set(true);
+
+    if let Some(ref on_drag_start) = on_drag_start {
+      on_drag_start();
+    }
+  };
+  let start_drag = Rc::new(start_drag);
+
+  let on_native_drag_start: DndCallback<DragEvent> = {
+    let get_items = get_items.clone();
+    let allowed_operations = allowed_operations.clone();
+    let start_drag = start_drag.clone();
+
+    Rc::new(Box::new(move |event: DragEvent| {
+      if is_disabled.get_untracked() {
+        event.prevent_default();
+        return;
+      }
+
+      if let Some(data_transfer) = event.data_transfer() {
+        let items = get_items();
+
+        // Later items are written first so that the first item wins.
+        for item in items.iter().rev() {
+          for format in item.formats() {
+            let _ = data_transfer.set_data(format, item.get(format).unwrap_or_default());
+          }
+        }
+
+        data_transfer.set_effect_allowed(effect_allowed(&allowed_operations()));
+      }
+
+      start_drag();
+    }))
+  };
+
+  let on_native_drag_end: DndCallback<DragEvent> = {
+    let end_drag = end_drag.clone();
+
+    Rc::new(Box::new(move |event: DragEvent| {
+      let drop_operation = event
+        .data_transfer()
+        .map_or(DropOperation::Cancel, |data_transfer| {
+          DropOperation::from_drop_effect(&data_transfer.drop_effect())
+        });
+
+      end_drag(DragEndEvent { drop_operation });
+    }))
+  };
+
+  let on_key_down: DndCallback<KeyboardEvent> = {
+    let id = id.clone();
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if KeyboardKey::from(&event) != KeyboardKey::Enter
+        || is_disabled.get_untracked()
+        || is_dragging.get_untracked()
+      {
+        return;
+      }
+
+      event.prevent_default();
+      event.stop_propagation();
+      start_drag();
+
+      begin_keyboard_drag(DragSession {
+        source_id: id.clone(),
+        items: get_items(),
+        allowed_operations: allowed_operations(),
+        on_drag_end: end_drag.clone(),
+        strings: strings.get_untracked(),
+        direction: direction.get_untracked(),
+      });
+    }))
+  };
+
+  let (drag_result, _) = create_signal(
+    cx,
+    DragResult {
+      id,
+      draggable: (move || !is_disabled.get()).derive_signal(cx),
+      is_dragging: (move || is_dragging.get()).derive_signal(cx),
+      aria_describedby,
+      on_drag_start: on_native_drag_start,
+      on_drag_end: on_native_drag_end,
+      on_key_down,
+    },
+  );
+
+  drag_result
+}
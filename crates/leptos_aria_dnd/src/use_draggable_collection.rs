@@ -0,0 +1,242 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_selection::Key;
+use leptos_aria_selection::SelectionManager;
+use leptos_aria_utils::use_id;
+
+use crate::drag_manager::dragged_collection;
+use crate::drag_manager::set_dragged_collection;
+use crate::drag_manager::DraggedCollection;
+use crate::use_drag;
+use crate::DragEndEvent;
+use crate::DragItem;
+use crate::DragResult;
+use crate::DropOperation;
+use crate::UseDragProps;
+
+type DragKeysCallback = Box<dyn Fn(&[Key])>;
+type DragEndKeysCallback = Box<dyn Fn(&[Key], DragEndEvent)>;
+
+#[derive(TypedBuilder)]
+pub struct UseDraggableCollectionProps {
+  pub selection_manager: SelectionManager,
+
+  /// The keys of the collection in the order they are displayed, which is
+  /// the order of the dragged items.
+  #[builder(setter(into))]
+  pub keys: MaybeSignal<Vec<Key>>,
+
+  /// The items for the dragged keys.
+  pub get_items: Box<dyn Fn(&[Key]) -> Vec<DragItem>>,
+
+  /// The operations which the drop target may choose from. Defaults to all
+  /// of them.
+  #[builder(default, setter(strip_option))]
+  pub get_allowed_operations: Option<Box<dyn Fn() -> Vec<DropOperation>>>,
+
+  /// Handler that is called with the dragged keys when a drag starts.
+  #[builder(default, setter(strip_option))]
+  pub on_drag_start: Option<DragKeysCallback>,
+
+  /// Handler that is called with the dragged keys when the drag ends.
+  #[builder(default, setter(strip_option))]
+  pub on_drag_end: Option<DragEndKeysCallback>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// The id of the collection. Pass the same id to
+  /// [`use_droppable_collection`](crate::use_droppable_collection) so that
+  /// items dropped within the collection are reordered. Generated when not
+  /// provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+}
+
+/// The drag state of a collection, shared by its items.
+#[derive(Clone)]
+pub struct DraggableCollection {
+  pub id: String,
+  pub is_disabled: Signal<bool>,
+  selection_manager: SelectionManager,
+  keys: Signal<Vec<Key>>,
+  get_items: Rc<Box<dyn Fn(&[Key]) -> Vec<DragItem>>>,
+  get_allowed_operations: Option<Rc<Box<dyn Fn() -> Vec<DropOperation>>>>,
+  on_drag_start: Option<Rc<DragKeysCallback>>,
+  on_drag_end: Option<Rc<DragEndKeysCallback>>,
+}
+
+/// Makes the items of a collection such as a listbox, table or grid list
+/// draggable with [`use_draggable_item`].
+///
+/// Dragging a selected item drags every selected item, while dragging an
+/// item which isn't selected drags only that item.
+pub fn use_draggable_collection(
+  cx: Scope,
+  props: UseDraggableCollectionProps,
+) -> DraggableCollection {
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let original_keys = props.keys;
+
+  DraggableCollection {
+    id: props.id.unwrap_or_else(|| use_id(cx)),
+    is_disabled: (move || original_is_disabled.get()).derive_signal(cx),
+    selection_manager: props.selection_manager,
+    keys: (move || original_keys.get()).derive_signal(cx),
+    get_items: Rc::new(props.get_items),
+    get_allowed_operations: props.get_allowed_operations.map(Rc::new),
+    on_drag_start: props.on_drag_start.map(Rc::new),
+    on_drag_end: props.on_drag_end.map(Rc::new),
+  }
+}
+
+impl DraggableCollection {
+  /// The keys which are dragged with the item.
+  pub fn dragged_keys(&self, key: &Key) -> Vec<Key> {
+    get_dragged_keys(
+      key,
+      &self.keys.get_untracked(),
+      &self.selection_manager.selected_keys_untracked(),
+      &self.selection_manager.disabled_keys.get_untracked(),
+    )
+  }
+}
+
+#[derive(TypedBuilder)]
+pub struct UseDraggableItemProps {
+  #[builder(setter(into))]
+  pub key: Key,
+
+  /// The id of the item element. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+}
+
+/// Makes an item of a [`DraggableCollection`] draggable, with the mouse,
+/// touch or by pressing `Enter`.
+pub fn use_draggable_item(
+  cx: Scope,
+  props: UseDraggableItemProps,
+  collection: &DraggableCollection,
+) -> ReadSignal<DragResult> {
+  let key = props.key;
+  let is_disabled = {
+    let collection = collection.clone();
+    let key = key.clone();
+
+    (move || collection.is_disabled.get() || collection.selection_manager.is_disabled(&key))
+      .derive_signal(cx)
+  };
+
+  let get_items = {
+    let collection = collection.clone();
+    let key = key.clone();
+
+    move || (collection.get_items)(&collection.dragged_keys(&key))
+  };
+
+  let on_drag_start = {
+    let collection = collection.clone();
+    let key = key.clone();
+
+    move || {
+      let keys = collection.dragged_keys(&key);
+
+      if let Some(ref on_drag_start) = collection.on_drag_start {
+        on_drag_start(&keys);
+      }
+
+      set_dragged_collection(Some(DraggedCollection {
+        collection_id: collection.id.clone(),
+        keys,
+      }));
+    }
+  };
+
+  let on_drag_end = {
+    let collection = collection.clone();
+
+    move |event: DragEndEvent| {
+      let keys = dragged_collection()
+        .filter(|dragged| dragged.collection_id == collection.id)
+        .map(|dragged| dragged.keys)
+        .unwrap_or_else(|| collection.dragged_keys(&key));
+      set_dragged_collection(None);
+
+      if let Some(ref on_drag_end) = collection.on_drag_end {
+        on_drag_end(&keys, event);
+      }
+    }
+  };
+
+  let mut drag_props = UseDragProps::builder()
+    .get_items(Box::new(get_items))
+    .on_drag_start(Box::new(on_drag_start))
+    .on_drag_end(Box::new(on_drag_end))
+    .is_disabled(is_disabled)
+    .build();
+  drag_props.id = props.id;
+
+  if let Some(get_allowed_operations) = collection.get_allowed_operations.clone() {
+    drag_props.get_allowed_operations = Some(Box::new(move || get_allowed_operations()));
+  }
+
+  use_drag(cx, drag_props)
+}
+
+/// The selected keys in the order they are displayed when the item is
+/// selected, and otherwise only the item.
+fn get_dragged_keys(
+  key: &Key,
+  ordered_keys: &[Key],
+  selected_keys: &HashSet<Key>,
+  disabled_keys: &HashSet<Key>,
+) -> Vec<Key> {
+  if !selected_keys.contains(key) {
+    return vec![key.clone()];
+  }
+
+  get_selected_keys(ordered_keys, selected_keys, disabled_keys)
+}
+
+/// The selected keys which aren't disabled, in the order they are displayed.
+pub(crate) fn get_selected_keys(
+  ordered_keys: &[Key],
+  selected_keys: &HashSet<Key>,
+  disabled_keys: &HashSet<Key>,
+) -> Vec<Key> {
+  ordered_keys
+    .iter()
+    .filter(|key| selected_keys.contains(*key) && !disabled_keys.contains(*key))
+    .cloned()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn drags_the_selection_when_the_item_is_selected() {
+    let keys: Vec<Key> = ["a", "b", "c", "d"].into_iter().map(Key::from).collect();
+    let selected: HashSet<Key> = ["d", "b", "c"].into_iter().map(Key::from).collect();
+    let disabled: HashSet<Key> = [Key::from("c")].into_iter().collect();
+
+    assert_eq!(
+      get_dragged_keys(&"d".into(), &keys, &selected, &disabled),
+      vec![Key::from("b"), Key::from("d")]
+    );
+    assert_eq!(
+      get_dragged_keys(&"a".into(), &keys, &selected, &disabled),
+      vec![Key::from("a")]
+    );
+  }
+}
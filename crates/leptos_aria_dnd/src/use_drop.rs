@@ -0,0 +1,277 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::DataTransfer;
+use leptos::web_sys::DragEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_id;
+
+use crate::drag_manager::register_drop_target;
+use crate::drag_manager::unregister_drop_target;
+use crate::drag_manager::DropTarget;
+use crate::drag_types::allowed_operations;
+use crate::drag_types::default_drop_operation;
+use crate::DndCallback;
+use crate::DragItem;
+use crate::DragTypes;
+use crate::DropEvent;
+use crate::DropOperation;
+
+type GetDropOperation = Box<dyn Fn(&DragTypes, &[DropOperation]) -> DropOperation>;
+
+#[derive(TypedBuilder)]
+pub struct UseDropProps {
+  /// Handler that is called when items are dropped on the target.
+  pub on_drop: Box<dyn Fn(DropEvent)>,
+
+  /// Chooses the operation for the formats of the dragged items from those
+  /// allowed by the drag source, or [`DropOperation::Cancel`] to reject them.
+  /// Defaults to the first allowed operation.
+  #[builder(default, setter(strip_option))]
+  pub get_drop_operation: Option<GetDropOperation>,
+
+  /// Handler that is called when accepted items are dragged over the target.
+  #[builder(default, setter(strip_option))]
+  pub on_drop_enter: Option<Box<dyn Fn()>>,
+
+  /// Handler that is called when the items leave the target without being
+  /// dropped.
+  #[builder(default, setter(strip_option))]
+  pub on_drop_exit: Option<Box<dyn Fn()>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// The id of the drop target element, which receives focus during a
+  /// keyboard drag. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct DropResult {
+  pub id: String,
+  /// Whether accepted items are dragged over the target.
+  pub is_drop_target: Signal<bool>,
+  /// `-1` during a keyboard drag which the target accepts, so that focus can
+  /// move to it.
+  pub tab_index: Signal<Option<i32>>,
+  pub on_drag_enter: DndCallback<DragEvent>,
+  pub on_drag_over: DndCallback<DragEvent>,
+  pub on_drag_leave: DndCallback<DragEvent>,
+  pub on_drop: DndCallback<DragEvent>,
+}
+
+/// Makes an element a target for items dragged with
+/// [`use_drag`](crate::use_drag), or natively from other elements and
+/// applications.
+///
+/// Native drags only expose the formats of the items until they are dropped,
+/// so the drop operation is chosen from the formats alone. Dropped native
+/// items are combined into a single [`DragItem`] with the data of each
+/// format.
+pub fn use_drop(cx: Scope, props: UseDropProps) -> ReadSignal<DropResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let is_drop_target = create_rw_signal(cx, false);
+  let is_keyboard_target = create_rw_signal(cx, false);
+  // `dragenter` and `dragleave` are also dispatched for the children.
+  let enter_count = Rc::new(Cell::new(0_usize));
+
+  let get_drop_operation = props.get_drop_operation;
+  let get_drop_operation = move |types: &DragTypes, allowed: &[DropOperation]| {
+    if is_disabled.get_untracked() {
+      return DropOperation::Cancel;
+    }
+
+    match get_drop_operation {
+      Some(ref get_drop_operation) => get_drop_operation(types, allowed),
+      None => default_drop_operation(types, allowed),
+    }
+  };
+  let get_drop_operation = Rc::new(get_drop_operation);
+
+  let on_drop_enter = props.on_drop_enter;
+  let enter = move || {
+    if is_drop_target.get_untracked() {
+      return;
+    }
+
+    is_drop_target.set(true);
+
+    if let Some(ref on_drop_enter) = on_drop_enter {
+      on_drop_enter();
+    }
+  };
+  let enter = Rc::new(enter);
+
+  let on_drop_exit = props.on_drop_exit;
+  let exit = move || {
+    if !is_drop_target.get_untracked() {
+      return;
+    }
+
+    is_drop_target.set(false);
+
+    if let Some(ref on_drop_exit) = on_drop_exit {
+      on_drop_exit();
+    }
+  };
+  let exit = Rc::new(exit);
+
+  let on_drop: Rc<dyn Fn(DropEvent)> = Rc::from(props.on_drop);
+
+  let native_drop_operation = {
+    let get_drop_operation = get_drop_operation.clone();
+
+    move |data_transfer: &DataTransfer| {
+      get_drop_operation(
+        &get_drag_types(data_transfer),
+        &allowed_operations(&data_transfer.effect_allowed()),
+      )
+    }
+  };
+  let native_drop_operation = Rc::new(native_drop_operation);
+
+  let on_drag_enter: DndCallback<DragEvent> = {
+    let enter_count = enter_count.clone();
+    let native_drop_operation = native_drop_operation.clone();
+    let enter = enter.clone();
+
+    Rc::new(Box::new(move |event: DragEvent| {
+      enter_count.set(enter_count.get() + 1);
+
+      let Some(data_transfer) = event.data_transfer() else {
+        return;
+      };
+      let operation = native_drop_operation(&data_transfer);
+
+      if operation != DropOperation::Cancel {
+        event.prevent_default();
+        data_transfer.set_drop_effect(operation.as_str());
+        enter();
+      }
+    }))
+  };
+
+  let on_drag_over: DndCallback<DragEvent> = {
+    let native_drop_operation = native_drop_operation.clone();
+
+    Rc::new(Box::new(move |event: DragEvent| {
+      let Some(data_transfer) = event.data_transfer() else {
+        return;
+      };
+      let operation = native_drop_operation(&data_transfer);
+
+      // Dropping is only allowed when `dragover` is prevented.
+      if operation != DropOperation::Cancel {
+        event.prevent_default();
+      }
+
+      data_transfer.set_drop_effect(operation.as_str());
+    }))
+  };
+
+  let on_drag_leave: DndCallback<DragEvent> = {
+    let enter_count = enter_count.clone();
+    let exit = exit.clone();
+
+    Rc::new(Box::new(move |_: DragEvent| {
+      enter_count.set(enter_count.get().saturating_sub(1));
+
+      if enter_count.get() == 0 {
+        exit();
+      }
+    }))
+  };
+
+  let on_native_drop: DndCallback<DragEvent> = {
+    let on_drop = on_drop.clone();
+    let exit = exit.clone();
+
+    Rc::new(Box::new(move |event: DragEvent| {
+      event.prevent_default();
+      enter_count.set(0);
+      exit();
+
+      let Some(data_transfer) = event.data_transfer() else {
+        return;
+      };
+      let drop_operation = native_drop_operation(&data_transfer);
+
+      if drop_operation == DropOperation::Cancel {
+        return;
+      }
+
+      on_drop(DropEvent {
+        items: read_drag_items(&data_transfer),
+        drop_operation,
+      });
+    }))
+  };
+
+  register_drop_target(DropTarget {
+    id: id.clone(),
+    get_drop_operation,
+    on_drop,
+    on_drop_enter: enter,
+    on_drop_exit: exit,
+    on_drag_active: Rc::new(move |is_active: bool| is_keyboard_target.set(is_active)),
+    navigate: None,
+  });
+
+  {
+    let id = id.clone();
+    on_cleanup(cx, move || unregister_drop_target(&id));
+  }
+
+  let (drop_result, _) = create_signal(
+    cx,
+    DropResult {
+      id,
+      is_drop_target: (move || is_drop_target.get()).derive_signal(cx),
+      tab_index: (move || is_keyboard_target.get().then_some(-1)).derive_signal(cx),
+      on_drag_enter,
+      on_drag_over,
+      on_drag_leave,
+      on_drop: on_native_drop,
+    },
+  );
+
+  drop_result
+}
+
+/// The formats of the natively dragged items. Files are only available as
+/// `dataTransfer.files`, so their `"Files"` format is skipped.
+pub(crate) fn get_drag_types(data_transfer: &DataTransfer) -> DragTypes {
+  data_transfer
+    .types()
+    .iter()
+    .filter_map(|format| format.as_string())
+    .filter(|format| format != "Files")
+    .collect()
+}
+
+/// The natively dropped items, combined into a single item with the data of
+/// each format.
+pub(crate) fn read_drag_items(data_transfer: &DataTransfer) -> Vec<DragItem> {
+  let mut item = DragItem::new();
+
+  for format in get_drag_types(data_transfer).iter() {
+    if let Ok(data) = data_transfer.get_data(format) {
+      item.insert(format, data);
+    }
+  }
+
+  vec![item]
+}
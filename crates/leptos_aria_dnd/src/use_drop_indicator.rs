@@ -0,0 +1,79 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_utils::use_id;
+
+use crate::CollectionDropTarget;
+use crate::DroppableCollection;
+
+#[derive(TypedBuilder)]
+pub struct UseDropIndicatorProps {
+  /// The target which the indicator represents, usually the gap before an
+  /// item.
+  pub target: CollectionDropTarget,
+
+  /// The id of the indicator. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct DropIndicatorResult {
+  pub id: String,
+  pub aria_roledescription: Signal<String>,
+  /// Where the items land when dropped at the indicator.
+  pub aria_label: Signal<String>,
+  /// Whether the items would be dropped at the indicator. Show the indicator
+  /// only while this is `true`.
+  pub is_drop_target: Signal<bool>,
+  /// The indicators which aren't the target are hidden from assistive
+  /// technology.
+  pub aria_hidden: Signal<Option<bool>>,
+}
+
+/// An indicator of where dragged items would be dropped within a
+/// [`DroppableCollection`], rendered in the gap between two items.
+///
+/// Render an indicator for the gap before each item and one after the last
+/// item. The target of an indicator after an item which isn't the last
+/// should be the gap before the next item, since that is how the collection
+/// describes the gap.
+pub fn use_drop_indicator(
+  cx: Scope,
+  props: UseDropIndicatorProps,
+  collection: &DroppableCollection,
+) -> ReadSignal<DropIndicatorResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let strings = use_localized_string_formatter(cx);
+
+  let is_drop_target = {
+    let collection = collection.clone();
+    let target = props.target.clone();
+
+    (move || collection.is_drop_target(&target)).derive_signal(cx)
+  };
+
+  let aria_label = {
+    let collection = collection.clone();
+    let target = props.target;
+
+    (move || collection.describe_drop_target(&target)).derive_signal(cx)
+  };
+
+  let (drop_indicator, _) = create_signal(
+    cx,
+    DropIndicatorResult {
+      id,
+      aria_roledescription: (move || strings.get().format("dropIndicator")).derive_signal(cx),
+      aria_label,
+      is_drop_target,
+      aria_hidden: (move || (!is_drop_target.get()).then_some(true)).derive_signal(cx),
+    },
+  );
+
+  drop_indicator
+}
@@ -0,0 +1,785 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::document;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::DragEvent;
+use leptos::web_sys::Element;
+use leptos::web_sys::Node;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_i18n::LocalizedStringFormatter;
+use leptos_aria_i18n::MessageArgument;
+use leptos_aria_selection::find_element_by_key;
+use leptos_aria_selection::Key;
+use leptos_aria_selection::DATA_KEY_ATTRIBUTE;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::Orientation;
+use leptos_aria_utils::Politeness;
+
+use crate::auto_scroll::AutoScroller;
+use crate::drag_manager::dragged_collection;
+use crate::drag_manager::next_index;
+use crate::drag_manager::register_drop_target;
+use crate::drag_manager::unregister_drop_target;
+use crate::drag_manager::DropTarget;
+use crate::drag_types::allowed_operations;
+use crate::drag_types::default_drop_operation;
+use crate::use_drop::get_drag_types;
+use crate::use_drop::read_drag_items;
+use crate::DndCallback;
+use crate::DragItem;
+use crate::DragTypes;
+use crate::DropEvent;
+use crate::DropOperation;
+
+/// Where items are dropped relative to an item of a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DropPosition {
+  Before,
+  On,
+  After,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ItemDropTarget {
+  pub key: Key,
+  pub position: DropPosition,
+}
+
+/// Where items are dropped within a collection.
+///
+/// The gap between two items is always described as before the second one,
+/// so [`DropPosition::After`] is only used for the last item.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CollectionDropTarget {
+  /// The collection itself, e.g. while it is empty.
+  Root,
+  Item(ItemDropTarget),
+}
+
+/// Items from the same collection were dropped between two of its items.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectionReorderEvent {
+  pub keys: Vec<Key>,
+  pub target: ItemDropTarget,
+  pub drop_operation: DropOperation,
+}
+
+/// Items from elsewhere were dropped between two items of the collection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectionInsertEvent {
+  pub items: Vec<DragItem>,
+  pub target: ItemDropTarget,
+  pub drop_operation: DropOperation,
+}
+
+/// Items were dropped on the collection itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectionRootDropEvent {
+  pub items: Vec<DragItem>,
+  pub drop_operation: DropOperation,
+}
+
+/// Items were dropped on an item of the collection, e.g. a folder.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectionItemDropEvent {
+  pub items: Vec<DragItem>,
+  pub key: Key,
+  /// Whether the items were dragged from the same collection.
+  pub is_internal: bool,
+  pub drop_operation: DropOperation,
+}
+
+type ItemTextCallback = Box<dyn Fn(&Key) -> String>;
+type GetCollectionDropOperation =
+  Box<dyn Fn(&CollectionDropTarget, &DragTypes, &[DropOperation]) -> DropOperation>;
+
+#[derive(TypedBuilder)]
+pub struct UseDroppableCollectionProps {
+  /// The keys of the collection in the order they are displayed.
+  #[builder(setter(into))]
+  pub keys: MaybeSignal<Vec<Key>>,
+
+  /// Handler that is called when items of the collection are moved between
+  /// its items.
+  #[builder(default, setter(strip_option))]
+  pub on_reorder: Option<Box<dyn Fn(CollectionReorderEvent)>>,
+
+  /// Handler that is called when items from elsewhere are dropped between
+  /// the items of the collection.
+  #[builder(default, setter(strip_option))]
+  pub on_insert: Option<Box<dyn Fn(CollectionInsertEvent)>>,
+
+  /// Handler that is called when items are dropped on an item.
+  #[builder(default, setter(strip_option))]
+  pub on_item_drop: Option<Box<dyn Fn(CollectionItemDropEvent)>>,
+
+  /// Handler that is called when items are dropped on the collection
+  /// itself rather than on or between its items.
+  #[builder(default, setter(strip_option))]
+  pub on_root_drop: Option<Box<dyn Fn(CollectionRootDropEvent)>>,
+
+  /// The formats accepted from other drag sources. Defaults to every format.
+  #[builder(default, setter(strip_option))]
+  pub accepted_drag_types: Option<Vec<String>>,
+
+  /// Replaces the default choice of operation, which accepts the targets
+  /// that have a handler.
+  #[builder(default, setter(strip_option))]
+  pub get_drop_operation: Option<GetCollectionDropOperation>,
+
+  /// The text announced for an item during a keyboard drag. Defaults to the
+  /// key.
+  #[builder(default, setter(strip_option))]
+  pub get_item_text: Option<ItemTextCallback>,
+
+  /// The direction in which the items are laid out. Defaults to vertical.
+  #[builder(default, setter(strip_option, into))]
+  pub orientation: Option<MaybeSignal<Orientation>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// The id of the collection element, which receives focus during a
+  /// keyboard drag. Use the id of the
+  /// [`DraggableCollection`](crate::DraggableCollection) to reorder its items.
+  /// Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct DroppableCollection {
+  pub id: String,
+  /// Where the dragged items would be dropped.
+  pub drop_target: Signal<Option<CollectionDropTarget>>,
+  /// `-1` during a keyboard drag which the collection accepts, so that focus
+  /// can move to it.
+  pub tab_index: Signal<Option<i32>>,
+  pub on_drag_enter: DndCallback<DragEvent>,
+  pub on_drag_over: DndCallback<DragEvent>,
+  pub on_drag_leave: DndCallback<DragEvent>,
+  pub on_drop: DndCallback<DragEvent>,
+  keys: Signal<Vec<Key>>,
+  strings: Signal<LocalizedStringFormatter>,
+  get_item_text: Option<Rc<ItemTextCallback>>,
+}
+
+impl DroppableCollection {
+  pub fn is_drop_target(&self, target: &CollectionDropTarget) -> bool {
+    self.drop_target.get().as_ref() == Some(target)
+  }
+
+  /// Where items dropped on the target land, e.g. `"Insert between Apples
+  /// and Pears"`.
+  pub fn describe_drop_target(&self, target: &CollectionDropTarget) -> String {
+    describe_drop_target(
+      &self.strings.get(),
+      target,
+      &self.keys.get(),
+      self.get_item_text.as_deref(),
+    )
+  }
+}
+
+/// Which drops the collection handles, used to choose the default operation.
+struct DropSupport {
+  reorder: bool,
+  insert: bool,
+  item_drop: bool,
+  root_drop: bool,
+  accepted_drag_types: Option<Vec<String>>,
+}
+
+/// Makes a collection a target for dragged items, which are dropped before,
+/// after or on its items.
+///
+/// With the pointer the target is chosen from the position within the item
+/// under the pointer: the outer quarters insert before or after it, and the
+/// middle drops on it. During a keyboard drag the arrow keys move between
+/// the accepted targets and each is announced. The items to render should
+/// have the [`DATA_KEY_ATTRIBUTE`].
+///
+/// The nearest scrollable ancestor scrolls while the pointer is near its
+/// edges, and scrolls to reveal the item targeted with the keyboard.
+pub fn use_droppable_collection(
+  cx: Scope,
+  props: UseDroppableCollectionProps,
+) -> DroppableCollection {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let original_keys = props.keys;
+  let keys = (move || original_keys.get()).derive_signal(cx);
+  let original_orientation = props.orientation.unwrap_or(Orientation::Vertical.into());
+  let orientation = (move || original_orientation.get()).derive_signal(cx);
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let drop_target = create_rw_signal(cx, None::<CollectionDropTarget>);
+  let is_keyboard_target = create_rw_signal(cx, false);
+  let strings = use_localized_string_formatter(cx);
+  // `dragenter` and `dragleave` are also dispatched for the items.
+  let enter_count = Rc::new(Cell::new(0_usize));
+  let auto_scroller = AutoScroller::default();
+
+  let support = DropSupport {
+    reorder: props.on_reorder.is_some(),
+    insert: props.on_insert.is_some(),
+    item_drop: props.on_item_drop.is_some(),
+    root_drop: props.on_root_drop.is_some(),
+    accepted_drag_types: props.accepted_drag_types,
+  };
+
+  let internal_keys = {
+    let id = id.clone();
+
+    move || {
+      dragged_collection()
+        .filter(|dragged| dragged.collection_id == id)
+        .map(|dragged| dragged.keys)
+    }
+  };
+  let internal_keys = Rc::new(internal_keys);
+
+  let custom_drop_operation = props.get_drop_operation;
+  let get_drop_operation = {
+    let internal_keys = internal_keys.clone();
+
+    move |target: &CollectionDropTarget, types: &DragTypes, allowed: &[DropOperation]| {
+      if is_disabled.get_untracked() {
+        return DropOperation::Cancel;
+      }
+
+      match custom_drop_operation {
+        Some(ref get_drop_operation) => get_drop_operation(target, types, allowed),
+        None => {
+          collection_drop_operation(target, types, allowed, internal_keys().as_deref(), &support)
+        }
+      }
+    }
+  };
+  let get_drop_operation = Rc::new(get_drop_operation);
+
+  let on_reorder = props.on_reorder;
+  let on_insert = props.on_insert;
+  let on_item_drop = props.on_item_drop;
+  let on_root_drop = props.on_root_drop;
+  let perform_drop =
+    move |target: CollectionDropTarget, items: Vec<DragItem>, drop_operation: DropOperation| {
+      let CollectionDropTarget::Item(target) = target else {
+        if let Some(ref on_root_drop) = on_root_drop {
+          on_root_drop(CollectionRootDropEvent {
+            items,
+            drop_operation,
+          });
+        }
+
+        return;
+      };
+      let internal = internal_keys();
+
+      if target.position == DropPosition::On {
+        if let Some(ref on_item_drop) = on_item_drop {
+          on_item_drop(CollectionItemDropEvent {
+            items,
+            key: target.key,
+            is_internal: internal.is_some(),
+            drop_operation,
+          });
+        }
+
+        return;
+      }
+
+      match (internal, &on_reorder, &on_insert) {
+        (Some(keys), Some(on_reorder), _) => {
+          on_reorder(CollectionReorderEvent {
+            keys,
+            target,
+            drop_operation,
+          })
+        }
+        (_, _, Some(on_insert)) => {
+          on_insert(CollectionInsertEvent {
+            items,
+            target,
+            drop_operation,
+          })
+        }
+        _ => {}
+      }
+    };
+  let perform_drop = Rc::new(perform_drop);
+
+  let set_drop_target = move |target: Option<CollectionDropTarget>| {
+    if drop_target.get_untracked() != target {
+      drop_target.set(target);
+    }
+  };
+
+  let on_native_drag_over: DndCallback<DragEvent> = {
+    let get_drop_operation = get_drop_operation.clone();
+    let auto_scroller = auto_scroller.clone();
+
+    Rc::new(Box::new(move |event: DragEvent| {
+      if let Some(collection) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      {
+        auto_scroller.update(
+          &collection,
+          f64::from(event.client_x()),
+          f64::from(event.client_y()),
+        );
+      }
+
+      let Some(data_transfer) = event.data_transfer() else {
+        return;
+      };
+      let types = get_drag_types(&data_transfer);
+      let allowed = allowed_operations(&data_transfer.effect_allowed());
+      let accepts_on = |key: &Key| {
+        let target = CollectionDropTarget::Item(ItemDropTarget {
+          key: key.clone(),
+          position: DropPosition::On,
+        });
+        get_drop_operation(&target, &types, &allowed) != DropOperation::Cancel
+      };
+
+      let target = get_pointer_target(&event, orientation.get_untracked())
+        .map(|(key, offset, size)| {
+          let position = get_drop_position(offset, size, accepts_on(&key));
+          normalize_target(
+            CollectionDropTarget::Item(ItemDropTarget { key, position }),
+            &keys.get_untracked(),
+          )
+        })
+        .unwrap_or(CollectionDropTarget::Root);
+      let operation = get_drop_operation(&target, &types, &allowed);
+
+      // Dropping is only allowed when the event is prevented.
+      if operation == DropOperation::Cancel {
+        set_drop_target(None);
+      } else {
+        event.prevent_default();
+        set_drop_target(Some(target));
+      }
+
+      data_transfer.set_drop_effect(operation.as_str());
+    }))
+  };
+
+  let on_drag_enter: DndCallback<DragEvent> = {
+    let enter_count = enter_count.clone();
+    let on_native_drag_over = on_native_drag_over.clone();
+
+    Rc::new(Box::new(move |event: DragEvent| {
+      enter_count.set(enter_count.get() + 1);
+      on_native_drag_over(event);
+    }))
+  };
+
+  let on_drag_leave: DndCallback<DragEvent> = {
+    let enter_count = enter_count.clone();
+    let auto_scroller = auto_scroller.clone();
+
+    Rc::new(Box::new(move |_: DragEvent| {
+      enter_count.set(enter_count.get().saturating_sub(1));
+
+      if enter_count.get() == 0 {
+        auto_scroller.stop();
+        set_drop_target(None);
+      }
+    }))
+  };
+
+  let on_native_drop: DndCallback<DragEvent> = {
+    let get_drop_operation = get_drop_operation.clone();
+    let perform_drop = perform_drop.clone();
+    let auto_scroller = auto_scroller.clone();
+
+    Rc::new(Box::new(move |event: DragEvent| {
+      event.prevent_default();
+      enter_count.set(0);
+      auto_scroller.stop();
+
+      let target = drop_target.get_untracked();
+      set_drop_target(None);
+
+      let (Some(target), Some(data_transfer)) = (target, event.data_transfer()) else {
+        return;
+      };
+      let drop_operation = get_drop_operation(
+        &target,
+        &get_drag_types(&data_transfer),
+        &allowed_operations(&data_transfer.effect_allowed()),
+      );
+
+      if drop_operation != DropOperation::Cancel {
+        perform_drop(target, read_drag_items(&data_transfer), drop_operation);
+      }
+    }))
+  };
+
+  let keyboard_targets = {
+    let get_drop_operation = get_drop_operation.clone();
+
+    move |types: &DragTypes, allowed: &[DropOperation]| {
+      get_keyboard_targets(&keys.get_untracked())
+        .into_iter()
+        .filter(|target| get_drop_operation(target, types, allowed) != DropOperation::Cancel)
+        .collect::<Vec<_>>()
+    }
+  };
+  let keyboard_targets = Rc::new(keyboard_targets);
+
+  let get_item_text = props.get_item_text.map(Rc::new);
+  let announce_target = {
+    let get_item_text = get_item_text.clone();
+
+    move |target: &CollectionDropTarget| {
+      let message = describe_drop_target(
+        &strings.get_untracked(),
+        target,
+        &keys.get_untracked(),
+        get_item_text.as_deref(),
+      );
+      announce(message, Politeness::Assertive);
+    }
+  };
+
+  let navigate = {
+    let keyboard_targets = keyboard_targets.clone();
+    let id = id.clone();
+
+    move |types: &DragTypes, allowed: &[DropOperation], forward: bool| {
+      let targets = keyboard_targets(types, allowed);
+      let current = drop_target
+        .get_untracked()
+        .and_then(|current| targets.iter().position(|target| *target == current));
+      let Some(next) = next_index(current, targets.len(), forward) else {
+        return;
+      };
+
+      announce_target(&targets[next]);
+      drop_target.set(Some(targets[next].clone()));
+
+      // Scroll the item into view, since focus stays on the collection.
+      if let CollectionDropTarget::Item(ItemDropTarget { ref key, .. }) = targets[next] {
+        if let Some(item) = document()
+          .get_element_by_id(&id)
+          .and_then(|collection| find_element_by_key(&collection, DATA_KEY_ATTRIBUTE, key))
+        {
+          auto_scroller.reveal(&item);
+        }
+      }
+    }
+  };
+
+  let keyboard_drop_operation = move |types: &DragTypes, allowed: &[DropOperation]| {
+    match drop_target.get_untracked() {
+      Some(target) => get_drop_operation(&target, types, allowed),
+      None => {
+        keyboard_targets(types, allowed)
+          .first()
+          .map_or(DropOperation::Cancel, |target| {
+            get_drop_operation(target, types, allowed)
+          })
+      }
+    }
+  };
+
+  register_drop_target(DropTarget {
+    id: id.clone(),
+    get_drop_operation: Rc::new(keyboard_drop_operation),
+    on_drop: Rc::new(move |event: DropEvent| {
+      if let Some(target) = drop_target.get_untracked() {
+        perform_drop(target, event.items, event.drop_operation);
+      }
+    }),
+    on_drop_enter: Rc::new(|| {}),
+    on_drop_exit: Rc::new(move || drop_target.set(None)),
+    on_drag_active: Rc::new(move |is_active: bool| is_keyboard_target.set(is_active)),
+    navigate: Some(Rc::new(navigate)),
+  });
+
+  {
+    let id = id.clone();
+    on_cleanup(cx, move || unregister_drop_target(&id));
+  }
+
+  DroppableCollection {
+    id,
+    drop_target: (move || drop_target.get()).derive_signal(cx),
+    tab_index: (move || is_keyboard_target.get().then_some(-1)).derive_signal(cx),
+    on_drag_enter,
+    on_drag_over: on_native_drag_over,
+    on_drag_leave,
+    on_drop: on_native_drop,
+    keys,
+    strings,
+    get_item_text,
+  }
+}
+
+fn describe_drop_target(
+  strings: &LocalizedStringFormatter,
+  target: &CollectionDropTarget,
+  keys: &[Key],
+  get_item_text: Option<&ItemTextCallback>,
+) -> String {
+  let item_text = |key: &Key| -> MessageArgument {
+    get_item_text
+      .map_or_else(|| key.to_string(), |get_item_text| get_item_text(key))
+      .into()
+  };
+
+  let CollectionDropTarget::Item(ItemDropTarget { key, position }) = target else {
+    return strings.format("dropOnRoot");
+  };
+
+  let previous = keys
+    .iter()
+    .position(|other| other == key)
+    .and_then(|index| index.checked_sub(1))
+    .map(|index| &keys[index]);
+
+  match (position, previous) {
+    (DropPosition::Before, Some(previous)) => {
+      strings.format_with(
+        "insertBetween",
+        &[("before", item_text(previous)), ("after", item_text(key))],
+      )
+    }
+    (DropPosition::Before, None) => {
+      strings.format_with("insertBefore", &[("item", item_text(key))])
+    }
+    (DropPosition::On, _) => strings.format_with("dropOnItem", &[("item", item_text(key))]),
+    (DropPosition::After, _) => strings.format_with("insertAfter", &[("item", item_text(key))]),
+  }
+}
+
+/// The key of the item under the pointer, with the offset of the pointer
+/// within it and its size along the orientation of the collection.
+fn get_pointer_target(event: &DragEvent, orientation: Orientation) -> Option<(Key, f64, f64)> {
+  let collection = event.current_target()?.dyn_into::<Node>().ok()?;
+  let item = event
+    .target()?
+    .dyn_into::<Element>()
+    .ok()?
+    .closest(&format!("[{DATA_KEY_ATTRIBUTE}]"))
+    .ok()??;
+
+  if !collection.contains(Some(&*item)) {
+    return None;
+  }
+
+  let key = Key::from(item.get_attribute(DATA_KEY_ATTRIBUTE)?);
+  let rect = item.get_bounding_client_rect();
+
+  Some(match orientation {
+    Orientation::Vertical => (key, f64::from(event.client_y()) - rect.top(), rect.height()),
+    Orientation::Horizontal => (key, f64::from(event.client_x()) - rect.left(), rect.width()),
+  })
+}
+
+/// The outer quarters of an item insert before or after it, and the middle
+/// drops on it. Items which can't be dropped on are split in half.
+fn get_drop_position(offset: f64, size: f64, accepts_on: bool) -> DropPosition {
+  let (before, after) = if accepts_on {
+    (size * 0.25, size * 0.75)
+  } else {
+    (size * 0.5, size * 0.5)
+  };
+
+  if offset < before {
+    DropPosition::Before
+  } else if offset >= after {
+    DropPosition::After
+  } else {
+    DropPosition::On
+  }
+}
+
+/// Describe the gap after an item as before the next item.
+fn normalize_target(target: CollectionDropTarget, keys: &[Key]) -> CollectionDropTarget {
+  let CollectionDropTarget::Item(ItemDropTarget {
+    ref key,
+    position: DropPosition::After,
+  }) = target
+  else {
+    return target;
+  };
+
+  let next = keys
+    .iter()
+    .position(|other| other == key)
+    .and_then(|index| keys.get(index + 1));
+
+  match next {
+    Some(next) => {
+      CollectionDropTarget::Item(ItemDropTarget {
+        key: next.clone(),
+        position: DropPosition::Before,
+      })
+    }
+    None => target,
+  }
+}
+
+/// Every target in the order the keyboard moves through them.
+fn get_keyboard_targets(keys: &[Key]) -> Vec<CollectionDropTarget> {
+  let Some(last) = keys.last() else {
+    return vec![CollectionDropTarget::Root];
+  };
+
+  let item = |key: &Key, position| {
+    CollectionDropTarget::Item(ItemDropTarget {
+      key: key.clone(),
+      position,
+    })
+  };
+
+  keys
+    .iter()
+    .flat_map(|key| [item(key, DropPosition::Before), item(key, DropPosition::On)])
+    .chain([item(last, DropPosition::After)])
+    .collect()
+}
+
+/// Accept the targets which have a handler. Dragged items can't be dropped on
+/// themselves, and reordering moves the items.
+fn collection_drop_operation(
+  target: &CollectionDropTarget,
+  types: &DragTypes,
+  allowed: &[DropOperation],
+  internal_keys: Option<&[Key]>,
+  support: &DropSupport,
+) -> DropOperation {
+  let accepts_types = internal_keys.is_some()
+    || support
+      .accepted_drag_types
+      .as_ref()
+      .map_or(true, |accepted| {
+        accepted.iter().any(|format| types.has(format))
+      });
+
+  if !accepts_types {
+    return DropOperation::Cancel;
+  }
+
+  let CollectionDropTarget::Item(ItemDropTarget { key, position }) = target else {
+    return if support.root_drop {
+      default_drop_operation(types, allowed)
+    } else {
+      DropOperation::Cancel
+    };
+  };
+
+  match (position, internal_keys) {
+    (DropPosition::On, Some(keys)) if keys.contains(key) => DropOperation::Cancel,
+    (DropPosition::On, _) if support.item_drop => default_drop_operation(types, allowed),
+    (DropPosition::On, _) => DropOperation::Cancel,
+    (_, Some(_)) if support.reorder => {
+      if allowed.contains(&DropOperation::Move) {
+        DropOperation::Move
+      } else {
+        DropOperation::Cancel
+      }
+    }
+    (..) if support.insert => default_drop_operation(types, allowed),
+    (..) => DropOperation::Cancel,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn item(key: &str, position: DropPosition) -> CollectionDropTarget {
+    CollectionDropTarget::Item(ItemDropTarget {
+      key: key.into(),
+      position,
+    })
+  }
+
+  #[test]
+  fn chooses_the_position_from_the_offset() {
+    assert_eq!(get_drop_position(2.0, 40.0, true), DropPosition::Before);
+    assert_eq!(get_drop_position(20.0, 40.0, true), DropPosition::On);
+    assert_eq!(get_drop_position(35.0, 40.0, true), DropPosition::After);
+    assert_eq!(get_drop_position(19.0, 40.0, false), DropPosition::Before);
+    assert_eq!(get_drop_position(21.0, 40.0, false), DropPosition::After);
+  }
+
+  #[test]
+  fn describes_gaps_as_before_the_next_item() {
+    let keys: Vec<Key> = vec!["a".into(), "b".into()];
+
+    assert_eq!(
+      normalize_target(item("a", DropPosition::After), &keys),
+      item("b", DropPosition::Before)
+    );
+    assert_eq!(
+      normalize_target(item("b", DropPosition::After), &keys),
+      item("b", DropPosition::After)
+    );
+    assert_eq!(
+      get_keyboard_targets(&keys),
+      vec![
+        item("a", DropPosition::Before),
+        item("a", DropPosition::On),
+        item("b", DropPosition::Before),
+        item("b", DropPosition::On),
+        item("b", DropPosition::After),
+      ]
+    );
+    assert_eq!(get_keyboard_targets(&[]), vec![CollectionDropTarget::Root]);
+  }
+
+  #[test]
+  fn accepts_targets_with_handlers() {
+    let types: DragTypes = ["text/plain".to_string()].into_iter().collect();
+    let allowed = [DropOperation::Copy, DropOperation::Move];
+    let dragged: Vec<Key> = vec!["a".into()];
+    let support = DropSupport {
+      reorder: true,
+      insert: false,
+      item_drop: true,
+      root_drop: false,
+      accepted_drag_types: Some(vec!["text/uri-list".into()]),
+    };
+    let operation = |target: &CollectionDropTarget, internal: Option<&[Key]>| {
+      collection_drop_operation(target, &types, &allowed, internal, &support)
+    };
+
+    assert_eq!(
+      operation(&item("b", DropPosition::Before), Some(&dragged)),
+      DropOperation::Move
+    );
+    assert_eq!(
+      operation(&item("a", DropPosition::On), Some(&dragged)),
+      DropOperation::Cancel
+    );
+    assert_eq!(
+      operation(&item("b", DropPosition::On), Some(&dragged)),
+      DropOperation::Copy
+    );
+    // Other drag sources must provide an accepted format.
+    assert_eq!(
+      operation(&item("b", DropPosition::On), None),
+      DropOperation::Cancel
+    );
+    assert_eq!(
+      operation(&CollectionDropTarget::Root, Some(&dragged)),
+      DropOperation::Cancel
+    );
+  }
+}
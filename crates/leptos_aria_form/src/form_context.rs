@@ -0,0 +1,90 @@
+use leptos::create_rw_signal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+/// A field registered with the surrounding form through
+/// [`use_form_field`](crate::use_form_field).
+#[derive(Clone)]
+pub struct FormFieldEntry {
+  /// The id of the element which receives focus when the field is invalid.
+  pub id: String,
+  /// The label used when the field is listed in the error summary.
+  pub label: Signal<String>,
+  /// Whether the field currently fails validation.
+  pub is_invalid: Signal<bool>,
+  /// The message describing why the field is invalid.
+  pub error_message: Signal<Option<String>>,
+}
+
+/// The fields registered within a form. Provided as context by
+/// [`use_form`](crate::use_form).
+#[derive(Clone, Copy)]
+pub struct FormContext {
+  fields: RwSignal<Vec<FormFieldEntry>>,
+  summary_id: RwSignal<Option<String>>,
+}
+
+impl FormContext {
+  pub(crate) fn new(cx: Scope) -> Self {
+    Self {
+      fields: create_rw_signal(cx, vec![]),
+      summary_id: create_rw_signal(cx, None),
+    }
+  }
+
+  /// All registered fields in registration order.
+  pub fn fields(&self) -> Vec<FormFieldEntry> {
+    self.fields.get()
+  }
+
+  /// The registered fields which are currently invalid.
+  pub fn invalid_fields(&self) -> Vec<FormFieldEntry> {
+    self.fields.with(|fields| {
+      fields
+        .iter()
+        .filter(|field| field.is_invalid.get())
+        .cloned()
+        .collect()
+    })
+  }
+
+  /// Whether any registered field is invalid.
+  pub fn is_invalid(&self) -> bool {
+    self
+      .fields
+      .with(|fields| fields.iter().any(|field| field.is_invalid.get()))
+  }
+
+  pub(crate) fn invalid_fields_untracked(&self) -> Vec<FormFieldEntry> {
+    self
+      .fields
+      .get_untracked()
+      .into_iter()
+      .filter(|field| field.is_invalid.get_untracked())
+      .collect()
+  }
+
+  pub(crate) fn register(&self, entry: FormFieldEntry) {
+    self.fields.update(|fields| {
+      fields.retain(|field| field.id != entry.id);
+      fields.push(entry);
+    });
+  }
+
+  pub(crate) fn unregister(&self, id: &str) {
+    self
+      .fields
+      .update(|fields| fields.retain(|field| field.id != id));
+  }
+
+  /// The id of the error summary rendered within the form, if any.
+  pub(crate) fn summary_id_untracked(&self) -> Option<String> {
+    self.summary_id.get_untracked()
+  }
+
+  pub(crate) fn set_summary_id(&self, id: Option<String>) {
+    self.summary_id.set(id);
+  }
+}
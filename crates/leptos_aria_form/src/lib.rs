@@ -0,0 +1,9 @@
+pub use form_context::*;
+pub use use_form::*;
+pub use use_form_error_summary::*;
+pub use use_form_field::*;
+
+mod form_context;
+mod use_form;
+mod use_form_error_summary;
+mod use_form_field;
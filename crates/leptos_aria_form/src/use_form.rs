@@ -0,0 +1,178 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::document;
+use leptos::provide_context;
+use leptos::spawn_local;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::Node;
+use leptos::web_sys::SubmitEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_list_formatter;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_i18n::ListFormatOptions;
+use leptos_aria_i18n::ListFormatter;
+use leptos_aria_i18n::LocalizedStringFormatter;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::Politeness;
+
+use crate::FormContext;
+use crate::FormFieldEntry;
+
+pub type FormSubmitFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+#[derive(TypedBuilder)]
+pub struct UseFormProps {
+  /// Called when the form is submitted and every registered field is valid.
+  /// The form is submitting until the returned future resolves.
+  pub on_submit: Box<dyn Fn() -> FormSubmitFuture>,
+
+  /// Creates the message announced when submission is blocked by invalid
+  /// fields. Defaults to a summary listing the field labels, translated for
+  /// the locale.
+  #[builder(default, setter(strip_option))]
+  pub get_error_summary: Option<Box<dyn Fn(&[FormFieldEntry]) -> String>>,
+}
+
+#[derive(Clone)]
+pub struct FormResult {
+  /// Native validation is disabled since validation is handled by the
+  /// registered fields.
+  pub no_validate: bool,
+  /// Whether the `on_submit` future is still running. Use this to disable the
+  /// submit button.
+  pub is_submitting: Signal<bool>,
+  /// Whether any registered field is invalid.
+  pub is_invalid: Signal<bool>,
+  pub aria_busy: Signal<Option<bool>>,
+  pub on_submit: FormCallback<SubmitEvent>,
+}
+
+/// Coordinates the submission of a form.
+///
+/// Fields created with [`use_form_field`](crate::use_form_field) in a child
+/// scope register their validation state with the form. Submitting while any
+/// field is invalid is blocked: the first invalid field in document order is
+/// focused, or the [error summary](crate::use_form_error_summary) when one is
+/// rendered, and a summary of the errors is announced. Otherwise `on_submit` is
+/// called and `is_submitting` stays `true` until it resolves. Submissions while
+/// a previous submission is running are ignored.
+pub fn use_form(cx: Scope, props: UseFormProps) -> ReadSignal<FormResult> {
+  let context = FormContext::new(cx);
+  provide_context(cx, context);
+
+  let is_submitting = create_rw_signal(cx, false);
+  let submit = props.on_submit;
+  let get_error_summary = props.get_error_summary.unwrap_or_else(|| {
+    let list_formatter = use_list_formatter(cx, ListFormatOptions::default());
+    let strings = use_localized_string_formatter(cx);
+
+    Box::new(move |fields: &[FormFieldEntry]| {
+      default_error_summary(
+        fields,
+        &list_formatter.get_untracked(),
+        &strings.get_untracked(),
+      )
+    })
+  });
+
+  let on_submit: FormCallback<SubmitEvent> = Rc::new(Box::new(move |event: SubmitEvent| {
+    event.prevent_default();
+
+    if is_submitting.get_untracked() {
+      return;
+    }
+
+    let invalid_fields = context.invalid_fields_untracked();
+
+    if !invalid_fields.is_empty() {
+      // Prefer the error summary so that users hear every error before fixing
+      // the first one.
+      match context.summary_id_untracked() {
+        Some(summary_id) => focus_element_by_id(&summary_id),
+        None => focus_first_field(&invalid_fields),
+      }
+
+      announce(get_error_summary(&invalid_fields), Politeness::Assertive);
+      return;
+    }
+
+    is_submitting.set(true);
+    let future = submit();
+
+    spawn_local(async move {
+      future.await;
+      is_submitting.set(false);
+    });
+  }));
+
+  let (form_result, _) = create_signal(
+    cx,
+    FormResult {
+      no_validate: true,
+      is_submitting: (move || is_submitting.get()).derive_signal(cx),
+      is_invalid: (move || context.is_invalid()).derive_signal(cx),
+      aria_busy: (move || is_submitting.get().then_some(true)).derive_signal(cx),
+      on_submit,
+    },
+  );
+
+  form_result
+}
+
+fn default_error_summary(
+  fields: &[FormFieldEntry],
+  list_formatter: &ListFormatter,
+  strings: &LocalizedStringFormatter,
+) -> String {
+  let labels = list_formatter.format(
+    &fields
+      .iter()
+      .map(|field| field.label.get_untracked())
+      .collect::<Vec<_>>(),
+  );
+
+  strings.format_with(
+    "formErrorSummary",
+    &[("count", fields.len().into()), ("fields", labels.into())],
+  )
+}
+
+/// Focus the field which comes first in the document.
+fn focus_first_field(fields: &[FormFieldEntry]) {
+  let document = document();
+  let first = fields
+    .iter()
+    .filter_map(|field| document.get_element_by_id(&field.id))
+    .reduce(|first, element| {
+      if first.compare_document_position(&element) & Node::DOCUMENT_POSITION_PRECEDING != 0 {
+        element
+      } else {
+        first
+      }
+    });
+
+  if let Some(element) = first.and_then(|element| element.dyn_into::<HtmlElement>().ok()) {
+    element.focus().ok();
+  }
+}
+
+pub(crate) fn focus_element_by_id(id: &str) {
+  if let Some(element) = document()
+    .get_element_by_id(id)
+    .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+  {
+    element.focus().ok();
+  }
+}
+
+pub(crate) type FormCallback<E> = Rc<Box<dyn Fn(E)>>;
@@ -0,0 +1,119 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::use_context;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::use_id;
+
+use crate::use_form::focus_element_by_id;
+use crate::use_form::FormCallback;
+use crate::FormContext;
+
+#[derive(TypedBuilder)]
+pub struct UseFormErrorSummaryProps {
+  /// The id of the summary region. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+}
+
+/// A link in the error summary which moves focus to an invalid field.
+#[derive(Clone)]
+pub struct ErrorSummaryItem {
+  /// The id of the invalid field.
+  pub field_id: String,
+  /// The label of the invalid field.
+  pub label: String,
+  /// The error message of the invalid field, if any.
+  pub message: Option<String>,
+  /// The `href` of the anchor, pointing at the field.
+  pub href: String,
+  pub on_click: FormCallback<MouseEvent>,
+}
+
+#[derive(Clone)]
+pub struct FormErrorSummaryResult {
+  /// The id of the summary region.
+  pub id: String,
+  /// The id to set on the heading of the summary.
+  pub title_id: String,
+  pub role: &'static str,
+  pub aria_labelledby: String,
+  /// The summary is focusable programmatically so that it can be focused
+  /// after a failed submission.
+  pub tab_index: i32,
+  /// Whether there are any errors to summarise. Render the region only while
+  /// this is `true`.
+  pub has_errors: Signal<bool>,
+  /// The links to the invalid fields in registration order.
+  pub items: Signal<Vec<ErrorSummaryItem>>,
+}
+
+/// Aggregates the errors of the fields registered with the surrounding
+/// [`use_form`](crate::use_form) into a labelled summary region.
+///
+/// Each item links to its field. Activating the link focuses the field rather
+/// than only scrolling to it, since browsers don't consistently move focus for
+/// fragment links. A failed submission focuses the summary instead of the
+/// first invalid field. The hook must be called in a child scope of the form
+/// and warns when no form is found.
+pub fn use_form_error_summary(
+  cx: Scope,
+  props: UseFormErrorSummaryProps,
+) -> ReadSignal<FormErrorSummaryResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let title_id = format!("{id}-title");
+  let context = use_context::<FormContext>(cx);
+
+  match context {
+    Some(context) => {
+      context.set_summary_id(Some(id.clone()));
+      on_cleanup(cx, move || context.set_summary_id(None));
+    }
+    None => leptos::warn!("`use_form_error_summary` must be used within `use_form`"),
+  }
+
+  let items = (move || {
+    context
+      .map(|context| context.invalid_fields())
+      .unwrap_or_default()
+      .into_iter()
+      .map(|field| {
+        let field_id = field.id.clone();
+        let on_click: FormCallback<MouseEvent> = Rc::new(Box::new(move |event: MouseEvent| {
+          event.prevent_default();
+          focus_element_by_id(&field_id);
+        }));
+
+        ErrorSummaryItem {
+          href: format!("#{}", field.id),
+          field_id: field.id,
+          label: field.label.get(),
+          message: field.error_message.get(),
+          on_click,
+        }
+      })
+      .collect::<Vec<_>>()
+  })
+  .derive_signal(cx);
+
+  let (form_error_summary_result, _) = create_signal(
+    cx,
+    FormErrorSummaryResult {
+      id,
+      aria_labelledby: title_id.clone(),
+      title_id,
+      role: "region",
+      tab_index: -1,
+      has_errors: (move || items.with(|items| !items.is_empty())).derive_signal(cx),
+      items,
+    },
+  );
+
+  form_error_summary_result
+}
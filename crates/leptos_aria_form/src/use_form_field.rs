@@ -0,0 +1,112 @@
+use leptos::create_signal;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::use_context;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::use_rendered_id;
+
+use crate::FormContext;
+use crate::FormFieldEntry;
+
+#[derive(TypedBuilder)]
+pub struct UseFormFieldProps {
+  /// The id of the focusable element of the field. Generated when not
+  /// provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// The label of the field, used when listing the field in the error
+  /// summary.
+  #[builder(setter(into))]
+  pub label: MaybeSignal<String>,
+
+  /// Whether the value of the field is invalid.
+  #[builder(default, setter(strip_option, into))]
+  pub is_invalid: Option<MaybeSignal<bool>>,
+
+  /// The message describing why the value is invalid.
+  #[builder(default, setter(strip_option, into))]
+  pub error_message: Option<MaybeSignal<String>>,
+
+  /// Whether the field is disabled. Disabled fields are never invalid.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// Whether the field is read only. Read only fields are never invalid.
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+}
+
+#[derive(Clone)]
+pub struct FormFieldResult {
+  /// The id to set on the focusable element of the field.
+  pub id: String,
+  /// The id to set on the element containing the error message.
+  pub error_message_id: String,
+  /// Whether the field is invalid after disabled and read only fields are
+  /// excluded.
+  pub is_invalid: Signal<bool>,
+  /// The error message while the field is invalid.
+  pub error_message: Signal<Option<String>>,
+  pub aria_invalid: Signal<Option<bool>>,
+  pub aria_errormessage: Signal<Option<String>>,
+}
+
+/// Registers a field with the surrounding [`use_form`](crate::use_form) so that
+/// its validation state blocks submission. The field is unregistered when the
+/// scope is disposed.
+///
+/// The hook can also be used outside of a form, in which case it only provides
+/// the validation attributes.
+pub fn use_form_field(cx: Scope, props: UseFormFieldProps) -> ReadSignal<FormFieldResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let error_message_id = format!("{id}-error");
+  let field = use_field_state(cx, props.is_disabled, props.is_read_only);
+  let original_label = props.label;
+  let label = (move || original_label.get()).derive_signal(cx);
+  let original_is_invalid = props.is_invalid.unwrap_or(false.into());
+  let is_invalid = (move || field.should_validate() && original_is_invalid.get()).derive_signal(cx);
+  let error_message = match props.error_message {
+    Some(error_message) => {
+      (move || is_invalid.get().then(|| error_message.get())).derive_signal(cx)
+    }
+    None => (move || None).derive_signal(cx),
+  };
+
+  if let Some(form) = use_context::<FormContext>(cx) {
+    form.register(FormFieldEntry {
+      id: id.clone(),
+      label,
+      is_invalid,
+      error_message,
+    });
+
+    let id = id.clone();
+    on_cleanup(cx, move || form.unregister(&id));
+  }
+
+  let aria_errormessage = {
+    let rendered_id = use_rendered_id(cx, error_message_id.clone());
+    (move || error_message.get().and(rendered_id.get())).derive_signal(cx)
+  };
+
+  let (form_field_result, _) = create_signal(
+    cx,
+    FormFieldResult {
+      id,
+      error_message_id,
+      is_invalid,
+      error_message,
+      aria_invalid: (move || is_invalid.get().then_some(true)).derive_signal(cx),
+      aria_errormessage,
+    },
+  );
+
+  form_field_result
+}
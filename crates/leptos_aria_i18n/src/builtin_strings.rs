@@ -0,0 +1,1354 @@
+/// The strings used by the hooks themselves, for each supported locale.
+///
+/// * `dismiss`: the visually hidden button which closes an overlay.
+/// * `previous` and `next`: the buttons which change the visible month of a
+///   calendar.
+/// * `dragStarted`, `dropComplete` and `dropCanceled`: announced during a
+///   keyboard drag.
+/// * `insertBefore`, `insertAfter`, `dropOnItem` and `dropOnRoot`: announced
+///   while moving between the drop targets of a collection, with the `item`.
+///   `insertBetween` is used for the gap between the `before` and `after`
+///   items.
+/// * `dropIndicator`: the role description of a drop indicator.
+/// * `dragDescription`: describes how to start a keyboard drag.
+/// * `formErrorSummary`: announced when a form with invalid fields is
+///   submitted, with the `count` of errors and the list of `fields`.
+/// * `quantity`: the default name of a stepper field, and `increase` and
+///   `decrease` the labels of its buttons.
+/// * `reorderGrabbed`, `reorderMoving`, `reorderMoved`, `reorderDropped` and
+///   `reorderCancelled`: announced while reordering a collection with the
+///   keyboard, with the `item`, its one based `position` and the `count` of
+///   items.
+/// * `day`, `month`, `year` and `era`: the labels of the segments of a date
+///   field, `empty` the value text of an empty segment, and `dayPlaceholder`,
+///   `monthPlaceholder` and `yearPlaceholder` the text shown while a segment is
+///   empty.
+/// * `red`, `green`, `blue`, `hue`, `saturation`, `lightness`, `brightness` and
+///   `alpha`: the names of the channels of a color, and `twoDimensionalSlider`
+///   the role description of a color area.
+/// * `todayDate`: the label of the calendar cell of today, with the `date`, and
+///   `selectedDateDescription` announced when a date is selected.
+/// * `imageViewer`: the name of a lightbox, `previousImage` and `nextImage`
+///   the labels of its buttons and `imagePosition` the `position` of the shown
+///   image out of the `count` of images.
+/// * `navigatedTo`: announced after client side navigation to the `page`.
+pub(crate) const BUILTIN_STRINGS: &[(&str, &[(&str, &str)])] = &[
+  (
+    "ar-AE",
+    &[
+      ("dismiss", "تجاهل"),
+      ("previous", "السابق"),
+      ("next", "التالي"),
+      (
+        "dragStarted",
+        "بدأ السحب. اضغط على Tab للانتقال إلى هدف الإفلات، ثم اضغط على Enter للإفلات، أو اضغط على \
+         Escape للإلغاء.",
+      ),
+      ("dropComplete", "اكتمل الإفلات."),
+      ("dropCanceled", "تم إلغاء الإفلات."),
+      ("insertBefore", "إدراج قبل {item}"),
+      ("insertAfter", "إدراج بعد {item}"),
+      ("insertBetween", "إدراج بين {before} و{after}"),
+      ("dropOnItem", "إفلات على {item}"),
+      ("dropOnRoot", "إفلات على المجموعة"),
+      ("dropIndicator", "مؤشر الإفلات"),
+      ("dragDescription", "اضغط على Enter لبدء السحب."),
+      (
+        "formErrorSummary",
+        "{count, plural, one {يوجد خطأ واحد في النموذج: {fields}} two {يوجد خطآن في النموذج: \
+         {fields}} few {توجد # أخطاء في النموذج: {fields}} other {يوجد # خطأ في النموذج: \
+         {fields}}}",
+      ),
+      ("quantity", "الكمية"),
+      ("increase", "زيادة"),
+      ("decrease", "تقليل"),
+      (
+        "reorderGrabbed",
+        "تم التقاط {item}، الموضع {position} من {count}. استخدم مفاتيح الأسهم للتحريك، وEnter \
+         للإفلات، وEscape للإلغاء.",
+      ),
+      ("reorderMoving", "{item}، الموضع {position} من {count}."),
+      (
+        "reorderMoved",
+        "تم نقل {item} إلى الموضع {position} من {count}.",
+      ),
+      (
+        "reorderDropped",
+        "تم إفلات {item} في الموضع {position} من {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "تم إلغاء إعادة الترتيب. عاد {item} إلى الموضع {position}.",
+      ),
+      ("day", "يوم"),
+      ("month", "شهر"),
+      ("year", "السنة"),
+      ("era", "العصر"),
+      ("empty", "فارغ"),
+      ("dayPlaceholder", "يوم"),
+      ("monthPlaceholder", "شهر"),
+      ("yearPlaceholder", "سنة"),
+      ("red", "أحمر"),
+      ("green", "أخضر"),
+      ("blue", "أزرق"),
+      ("hue", "درجة اللون"),
+      ("saturation", "التشبع"),
+      ("lightness", "الإضاءة"),
+      ("brightness", "السطوع"),
+      ("alpha", "ألفا"),
+      ("twoDimensionalSlider", "مُنزلق ثنائي الأبعاد"),
+      ("todayDate", "اليوم، {date}"),
+      ("selectedDateDescription", "التاريخ المحدد: {date}"),
+      ("imageViewer", "عارض الصور"),
+      ("previousImage", "الصورة السابقة"),
+      ("nextImage", "الصورة التالية"),
+      ("imagePosition", "الصورة {position} من {count}"),
+      ("navigatedTo", "تم الانتقال إلى {page}"),
+    ],
+  ),
+  (
+    "de-DE",
+    &[
+      ("dismiss", "Schließen"),
+      ("previous", "Zurück"),
+      ("next", "Weiter"),
+      (
+        "dragStarted",
+        "Ziehen gestartet. Drücken Sie die Tabulatortaste, um zu einem Ablageziel zu navigieren, \
+         und drücken Sie dann die Eingabetaste zum Ablegen oder die Escape-Taste zum Abbrechen.",
+      ),
+      ("dropComplete", "Ablegen abgeschlossen."),
+      ("dropCanceled", "Ablegen abgebrochen."),
+      ("insertBefore", "Vor {item} einfügen"),
+      ("insertAfter", "Nach {item} einfügen"),
+      ("insertBetween", "Zwischen {before} und {after} einfügen"),
+      ("dropOnItem", "Auf {item} ablegen"),
+      ("dropOnRoot", "In der Sammlung ablegen"),
+      ("dropIndicator", "Ablegeanzeiger"),
+      (
+        "dragDescription",
+        "Drücken Sie die Eingabetaste, um mit dem Ziehen zu beginnen.",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, other {Das Formular enthält # Fehler: {fields}}}",
+      ),
+      ("quantity", "Menge"),
+      ("increase", "Erhöhen"),
+      ("decrease", "Verringern"),
+      (
+        "reorderGrabbed",
+        "{item} aufgenommen, Position {position} von {count}. Mit den Pfeiltasten verschieben, \
+         mit der Eingabetaste ablegen und mit der Escape-Taste abbrechen.",
+      ),
+      ("reorderMoving", "{item}, Position {position} von {count}."),
+      (
+        "reorderMoved",
+        "{item} an Position {position} von {count} verschoben.",
+      ),
+      (
+        "reorderDropped",
+        "{item} an Position {position} von {count} abgelegt.",
+      ),
+      (
+        "reorderCancelled",
+        "Neuanordnung abgebrochen. {item} ist wieder an Position {position}.",
+      ),
+      ("day", "Tag"),
+      ("month", "Monat"),
+      ("year", "Jahr"),
+      ("era", "Ära"),
+      ("empty", "Leer"),
+      ("dayPlaceholder", "TT"),
+      ("monthPlaceholder", "MM"),
+      ("yearPlaceholder", "JJJJ"),
+      ("red", "Rot"),
+      ("green", "Grün"),
+      ("blue", "Blau"),
+      ("hue", "Farbton"),
+      ("saturation", "Sättigung"),
+      ("lightness", "Helligkeit"),
+      ("brightness", "Leuchtkraft"),
+      ("alpha", "Alpha"),
+      ("twoDimensionalSlider", "2D-Schieberegler"),
+      ("todayDate", "Heute, {date}"),
+      ("selectedDateDescription", "Ausgewähltes Datum: {date}"),
+      ("imageViewer", "Bildbetrachter"),
+      ("previousImage", "Vorheriges Bild"),
+      ("nextImage", "Nächstes Bild"),
+      ("imagePosition", "Bild {position} von {count}"),
+      ("navigatedTo", "Navigiert zu {page}"),
+    ],
+  ),
+  (
+    "en-US",
+    &[
+      ("dismiss", "Dismiss"),
+      ("previous", "Previous"),
+      ("next", "Next"),
+      (
+        "dragStarted",
+        "Started dragging. Press Tab to navigate to a drop target, then press Enter to drop, or \
+         press Escape to cancel.",
+      ),
+      ("dropComplete", "Drop complete."),
+      ("dropCanceled", "Drop canceled."),
+      ("insertBefore", "Insert before {item}"),
+      ("insertAfter", "Insert after {item}"),
+      ("insertBetween", "Insert between {before} and {after}"),
+      ("dropOnItem", "Drop on {item}"),
+      ("dropOnRoot", "Drop on the collection"),
+      ("dropIndicator", "drop indicator"),
+      ("dragDescription", "Press Enter to start dragging."),
+      (
+        "formErrorSummary",
+        "{count, plural, one {There is # error in the form: {fields}} other {There are # errors \
+         in the form: {fields}}}",
+      ),
+      ("quantity", "Quantity"),
+      ("increase", "Increase"),
+      ("decrease", "Decrease"),
+      (
+        "reorderGrabbed",
+        "Grabbed {item}, position {position} of {count}. Use the arrow keys to move, Enter to \
+         drop and Escape to cancel.",
+      ),
+      ("reorderMoving", "{item}, position {position} of {count}."),
+      (
+        "reorderMoved",
+        "Moved {item} to position {position} of {count}.",
+      ),
+      (
+        "reorderDropped",
+        "Dropped {item} at position {position} of {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Reordering cancelled. {item} returned to position {position}.",
+      ),
+      ("day", "day"),
+      ("month", "month"),
+      ("year", "year"),
+      ("era", "era"),
+      ("empty", "Empty"),
+      ("dayPlaceholder", "dd"),
+      ("monthPlaceholder", "mm"),
+      ("yearPlaceholder", "yyyy"),
+      ("red", "Red"),
+      ("green", "Green"),
+      ("blue", "Blue"),
+      ("hue", "Hue"),
+      ("saturation", "Saturation"),
+      ("lightness", "Lightness"),
+      ("brightness", "Brightness"),
+      ("alpha", "Alpha"),
+      ("twoDimensionalSlider", "2D slider"),
+      ("todayDate", "Today, {date}"),
+      ("selectedDateDescription", "Selected date: {date}"),
+      ("imageViewer", "Image viewer"),
+      ("previousImage", "Previous image"),
+      ("nextImage", "Next image"),
+      ("imagePosition", "Image {position} of {count}"),
+      ("navigatedTo", "Navigated to {page}"),
+    ],
+  ),
+  (
+    "es-ES",
+    &[
+      ("dismiss", "Descartar"),
+      ("previous", "Anterior"),
+      ("next", "Siguiente"),
+      (
+        "dragStarted",
+        "Se ha empezado a arrastrar. Pulse Tab para ir a un destino de colocación y, a \
+         continuación, pulse Intro para soltar, o pulse Escape para cancelar.",
+      ),
+      ("dropComplete", "Colocación completada."),
+      ("dropCanceled", "Colocación cancelada."),
+      ("insertBefore", "Insertar antes de {item}"),
+      ("insertAfter", "Insertar después de {item}"),
+      ("insertBetween", "Insertar entre {before} y {after}"),
+      ("dropOnItem", "Soltar en {item}"),
+      ("dropOnRoot", "Soltar en la colección"),
+      ("dropIndicator", "indicador de colocación"),
+      ("dragDescription", "Pulse Intro para empezar a arrastrar."),
+      (
+        "formErrorSummary",
+        "{count, plural, one {Hay # error en el formulario: {fields}} other {Hay # errores en el \
+         formulario: {fields}}}",
+      ),
+      ("quantity", "Cantidad"),
+      ("increase", "Aumentar"),
+      ("decrease", "Reducir"),
+      (
+        "reorderGrabbed",
+        "Se ha seleccionado {item}, posición {position} de {count}. Use las teclas de flecha para \
+         mover, Intro para soltar y Escape para cancelar.",
+      ),
+      ("reorderMoving", "{item}, posición {position} de {count}."),
+      (
+        "reorderMoved",
+        "{item} se ha movido a la posición {position} de {count}.",
+      ),
+      (
+        "reorderDropped",
+        "{item} se ha soltado en la posición {position} de {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Reordenación cancelada. {item} ha vuelto a la posición {position}.",
+      ),
+      ("day", "día"),
+      ("month", "mes"),
+      ("year", "año"),
+      ("era", "era"),
+      ("empty", "Vacío"),
+      ("dayPlaceholder", "dd"),
+      ("monthPlaceholder", "mm"),
+      ("yearPlaceholder", "aaaa"),
+      ("red", "Rojo"),
+      ("green", "Verde"),
+      ("blue", "Azul"),
+      ("hue", "Tono"),
+      ("saturation", "Saturación"),
+      ("lightness", "Luminosidad"),
+      ("brightness", "Brillo"),
+      ("alpha", "Alfa"),
+      ("twoDimensionalSlider", "Control deslizante en 2D"),
+      ("todayDate", "Hoy, {date}"),
+      ("selectedDateDescription", "Fecha seleccionada: {date}"),
+      ("imageViewer", "Visor de imágenes"),
+      ("previousImage", "Imagen anterior"),
+      ("nextImage", "Imagen siguiente"),
+      ("imagePosition", "Imagen {position} de {count}"),
+      ("navigatedTo", "Se ha navegado a {page}"),
+    ],
+  ),
+  (
+    "fr-FR",
+    &[
+      ("dismiss", "Rejeter"),
+      ("previous", "Précédent"),
+      ("next", "Suivant"),
+      (
+        "dragStarted",
+        "Déplacement commencé. Appuyez sur Tabulation pour accéder à une cible de dépôt, puis \
+         appuyez sur Entrée pour déposer, ou appuyez sur Échap pour annuler.",
+      ),
+      ("dropComplete", "Dépôt terminé."),
+      ("dropCanceled", "Dépôt annulé."),
+      ("insertBefore", "Insérer avant {item}"),
+      ("insertAfter", "Insérer après {item}"),
+      ("insertBetween", "Insérer entre {before} et {after}"),
+      ("dropOnItem", "Déposer sur {item}"),
+      ("dropOnRoot", "Déposer dans la collection"),
+      ("dropIndicator", "indicateur de dépôt"),
+      (
+        "dragDescription",
+        "Appuyez sur Entrée pour commencer à faire glisser.",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, one {Le formulaire contient # erreur : {fields}} other {Le formulaire \
+         contient # erreurs : {fields}}}",
+      ),
+      ("quantity", "Quantité"),
+      ("increase", "Augmenter"),
+      ("decrease", "Diminuer"),
+      (
+        "reorderGrabbed",
+        "{item} saisi, position {position} sur {count}. Utilisez les touches fléchées pour \
+         déplacer, Entrée pour déposer et Échap pour annuler.",
+      ),
+      ("reorderMoving", "{item}, position {position} sur {count}."),
+      (
+        "reorderMoved",
+        "{item} déplacé à la position {position} sur {count}.",
+      ),
+      (
+        "reorderDropped",
+        "{item} déposé à la position {position} sur {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Réorganisation annulée. {item} est revenu à la position {position}.",
+      ),
+      ("day", "jour"),
+      ("month", "mois"),
+      ("year", "année"),
+      ("era", "ère"),
+      ("empty", "Vide"),
+      ("dayPlaceholder", "jj"),
+      ("monthPlaceholder", "mm"),
+      ("yearPlaceholder", "aaaa"),
+      ("red", "Rouge"),
+      ("green", "Vert"),
+      ("blue", "Bleu"),
+      ("hue", "Teinte"),
+      ("saturation", "Saturation"),
+      ("lightness", "Luminosité"),
+      ("brightness", "Luminance"),
+      ("alpha", "Alpha"),
+      ("twoDimensionalSlider", "Curseur 2D"),
+      ("todayDate", "Aujourd’hui, {date}"),
+      ("selectedDateDescription", "Date sélectionnée : {date}"),
+      ("imageViewer", "Visionneuse d’images"),
+      ("previousImage", "Image précédente"),
+      ("nextImage", "Image suivante"),
+      ("imagePosition", "Image {position} sur {count}"),
+      ("navigatedTo", "Navigation vers {page}"),
+    ],
+  ),
+  (
+    "he-IL",
+    &[
+      ("dismiss", "התעלם"),
+      ("previous", "הקודם"),
+      ("next", "הבא"),
+      (
+        "dragStarted",
+        "התחלת לגרור. הקש על Tab כדי לנווט לאזור השחרור, ולאחר מכן הקש על Enter כדי לשחרר, או על \
+         Escape כדי לבטל.",
+      ),
+      ("dropComplete", "השחרור הושלם."),
+      ("dropCanceled", "השחרור בוטל."),
+      ("insertBefore", "הוסף לפני {item}"),
+      ("insertAfter", "הוסף אחרי {item}"),
+      ("insertBetween", "הוסף בין {before} לבין {after}"),
+      ("dropOnItem", "שחרר על {item}"),
+      ("dropOnRoot", "שחרר באוסף"),
+      ("dropIndicator", "מחוון שחרור"),
+      ("dragDescription", "הקש Enter כדי להתחיל לגרור."),
+      (
+        "formErrorSummary",
+        "{count, plural, one {יש שגיאה אחת בטופס: {fields}} other {יש # שגיאות בטופס: {fields}}}",
+      ),
+      ("quantity", "כמות"),
+      ("increase", "הגדל"),
+      ("decrease", "הקטן"),
+      (
+        "reorderGrabbed",
+        "{item} נתפס, מיקום {position} מתוך {count}. השתמש במקשי החצים כדי להזיז, ב-Enter כדי \
+         לשחרר וב-Escape כדי לבטל.",
+      ),
+      ("reorderMoving", "{item}, מיקום {position} מתוך {count}."),
+      (
+        "reorderMoved",
+        "{item} הועבר למיקום {position} מתוך {count}.",
+      ),
+      (
+        "reorderDropped",
+        "{item} שוחרר במיקום {position} מתוך {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "שינוי הסדר בוטל. {item} חזר למיקום {position}.",
+      ),
+      ("day", "יום"),
+      ("month", "חודש"),
+      ("year", "שנה"),
+      ("era", "תקופה"),
+      ("empty", "ריק"),
+      ("dayPlaceholder", "יי"),
+      ("monthPlaceholder", "חח"),
+      ("yearPlaceholder", "שששש"),
+      ("red", "אדום"),
+      ("green", "ירוק"),
+      ("blue", "כחול"),
+      ("hue", "גוון"),
+      ("saturation", "רוויה"),
+      ("lightness", "בהירות"),
+      ("brightness", "עוצמת בהירות"),
+      ("alpha", "אלפא"),
+      ("twoDimensionalSlider", "מחוון דו-ממדי"),
+      ("todayDate", "היום, {date}"),
+      ("selectedDateDescription", "תאריך נבחר: {date}"),
+      ("imageViewer", "מציג תמונות"),
+      ("previousImage", "התמונה הקודמת"),
+      ("nextImage", "התמונה הבאה"),
+      ("imagePosition", "תמונה {position} מתוך {count}"),
+      ("navigatedTo", "עברת אל {page}"),
+    ],
+  ),
+  (
+    "it-IT",
+    &[
+      ("dismiss", "Ignora"),
+      ("previous", "Precedente"),
+      ("next", "Successivo"),
+      (
+        "dragStarted",
+        "Hai iniziato a trascinare. Premi Tab per passare a un punto di rilascio, quindi premi \
+         Invio per rilasciare o Esc per annullare.",
+      ),
+      ("dropComplete", "Rilascio completato."),
+      ("dropCanceled", "Rilascio annullato."),
+      ("insertBefore", "Inserisci prima di {item}"),
+      ("insertAfter", "Inserisci dopo {item}"),
+      ("insertBetween", "Inserisci tra {before} e {after}"),
+      ("dropOnItem", "Rilascia su {item}"),
+      ("dropOnRoot", "Rilascia nella raccolta"),
+      ("dropIndicator", "indicatore di rilascio"),
+      ("dragDescription", "Premi Invio per iniziare a trascinare."),
+      (
+        "formErrorSummary",
+        "{count, plural, one {C’è # errore nel modulo: {fields}} other {Ci sono # errori nel \
+         modulo: {fields}}}",
+      ),
+      ("quantity", "Quantità"),
+      ("increase", "Aumenta"),
+      ("decrease", "Riduci"),
+      (
+        "reorderGrabbed",
+        "{item} selezionato, posizione {position} di {count}. Usa i tasti freccia per spostare, \
+         Invio per rilasciare ed Esc per annullare.",
+      ),
+      ("reorderMoving", "{item}, posizione {position} di {count}."),
+      (
+        "reorderMoved",
+        "{item} spostato nella posizione {position} di {count}.",
+      ),
+      (
+        "reorderDropped",
+        "{item} rilasciato nella posizione {position} di {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Riordinamento annullato. {item} è tornato nella posizione {position}.",
+      ),
+      ("day", "giorno"),
+      ("month", "mese"),
+      ("year", "anno"),
+      ("era", "era"),
+      ("empty", "Vuoto"),
+      ("dayPlaceholder", "gg"),
+      ("monthPlaceholder", "mm"),
+      ("yearPlaceholder", "aaaa"),
+      ("red", "Rosso"),
+      ("green", "Verde"),
+      ("blue", "Blu"),
+      ("hue", "Tonalità"),
+      ("saturation", "Saturazione"),
+      ("lightness", "Luminosità"),
+      ("brightness", "Brillantezza"),
+      ("alpha", "Alfa"),
+      ("twoDimensionalSlider", "Cursore 2D"),
+      ("todayDate", "Oggi, {date}"),
+      ("selectedDateDescription", "Data selezionata: {date}"),
+      ("imageViewer", "Visualizzatore di immagini"),
+      ("previousImage", "Immagine precedente"),
+      ("nextImage", "Immagine successiva"),
+      ("imagePosition", "Immagine {position} di {count}"),
+      ("navigatedTo", "Passato a {page}"),
+    ],
+  ),
+  (
+    "ja-JP",
+    &[
+      ("dismiss", "閉じる"),
+      ("previous", "前へ"),
+      ("next", "次へ"),
+      (
+        "dragStarted",
+        "ドラッグを開始しました。Tab キーを押してドロップターゲットに移動し、Enter \
+         キーを押してドロップするか、Esc キーを押してキャンセルします。",
+      ),
+      ("dropComplete", "ドロップが完了しました。"),
+      ("dropCanceled", "ドロップがキャンセルされました。"),
+      ("insertBefore", "{item} の前に挿入"),
+      ("insertAfter", "{item} の後に挿入"),
+      ("insertBetween", "{before} と {after} の間に挿入"),
+      ("dropOnItem", "{item} にドロップ"),
+      ("dropOnRoot", "コレクションにドロップ"),
+      ("dropIndicator", "ドロップインジケーター"),
+      (
+        "dragDescription",
+        "Enter キーを押してドラッグを開始します。",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, other {フォームに # 件のエラーがあります: {fields}}}",
+      ),
+      ("quantity", "数量"),
+      ("increase", "増やす"),
+      ("decrease", "減らす"),
+      (
+        "reorderGrabbed",
+        "{item} をつかみました。位置 {position}/{count}。矢印キーで移動し、Enter \
+         キーでドロップ、Esc キーでキャンセルします。",
+      ),
+      ("reorderMoving", "{item}、位置 {position}/{count}。"),
+      (
+        "reorderMoved",
+        "{item} を位置 {position}/{count} に移動しました。",
+      ),
+      (
+        "reorderDropped",
+        "{item} を位置 {position}/{count} にドロップしました。",
+      ),
+      (
+        "reorderCancelled",
+        "並べ替えをキャンセルしました。{item} は位置 {position} に戻りました。",
+      ),
+      ("day", "日"),
+      ("month", "月"),
+      ("year", "年"),
+      ("era", "時代"),
+      ("empty", "空"),
+      ("dayPlaceholder", "日"),
+      ("monthPlaceholder", "月"),
+      ("yearPlaceholder", "年"),
+      ("red", "赤"),
+      ("green", "緑"),
+      ("blue", "青"),
+      ("hue", "色相"),
+      ("saturation", "彩度"),
+      ("lightness", "明度"),
+      ("brightness", "輝度"),
+      ("alpha", "アルファ"),
+      ("twoDimensionalSlider", "2D スライダー"),
+      ("todayDate", "本日、{date}"),
+      ("selectedDateDescription", "選択した日付：{date}"),
+      ("imageViewer", "画像ビューアー"),
+      ("previousImage", "前の画像"),
+      ("nextImage", "次の画像"),
+      ("imagePosition", "画像 {position}/{count}"),
+      ("navigatedTo", "{page} に移動しました"),
+    ],
+  ),
+  (
+    "ko-KR",
+    &[
+      ("dismiss", "무시"),
+      ("previous", "이전"),
+      ("next", "다음"),
+      (
+        "dragStarted",
+        "드래그를 시작했습니다. Tab 키를 눌러 드롭 대상으로 이동한 다음 Enter 키를 눌러 \
+         드롭하거나 Esc 키를 눌러 취소하세요.",
+      ),
+      ("dropComplete", "드롭이 완료되었습니다."),
+      ("dropCanceled", "드롭이 취소되었습니다."),
+      ("insertBefore", "{item} 앞에 삽입"),
+      ("insertAfter", "{item} 뒤에 삽입"),
+      ("insertBetween", "{before} 및 {after} 사이에 삽입"),
+      ("dropOnItem", "{item}에 드롭"),
+      ("dropOnRoot", "컬렉션에 드롭"),
+      ("dropIndicator", "드롭 표시기"),
+      (
+        "dragDescription",
+        "드래그를 시작하려면 Enter 키를 누르세요.",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, other {양식에 #개의 오류가 있습니다: {fields}}}",
+      ),
+      ("quantity", "수량"),
+      ("increase", "늘리기"),
+      ("decrease", "줄이기"),
+      (
+        "reorderGrabbed",
+        "{item}을(를) 잡았습니다. 위치 {position}/{count}. 화살표 키로 이동하고 Enter 키로 놓거나 \
+         Esc 키로 취소하세요.",
+      ),
+      ("reorderMoving", "{item}, 위치 {position}/{count}."),
+      (
+        "reorderMoved",
+        "{item}을(를) 위치 {position}/{count}(으)로 이동했습니다.",
+      ),
+      (
+        "reorderDropped",
+        "{item}을(를) 위치 {position}/{count}에 놓았습니다.",
+      ),
+      (
+        "reorderCancelled",
+        "순서 변경이 취소되었습니다. {item}이(가) 위치 {position}(으)로 돌아갔습니다.",
+      ),
+      ("day", "일"),
+      ("month", "월"),
+      ("year", "년"),
+      ("era", "연호"),
+      ("empty", "비어 있음"),
+      ("dayPlaceholder", "일"),
+      ("monthPlaceholder", "월"),
+      ("yearPlaceholder", "년"),
+      ("red", "빨간색"),
+      ("green", "녹색"),
+      ("blue", "파란색"),
+      ("hue", "색조"),
+      ("saturation", "채도"),
+      ("lightness", "밝기"),
+      ("brightness", "명도"),
+      ("alpha", "알파"),
+      ("twoDimensionalSlider", "2D 슬라이더"),
+      ("todayDate", "오늘, {date}"),
+      ("selectedDateDescription", "선택한 날짜: {date}"),
+      ("imageViewer", "이미지 뷰어"),
+      ("previousImage", "이전 이미지"),
+      ("nextImage", "다음 이미지"),
+      ("imagePosition", "이미지 {position}/{count}"),
+      ("navigatedTo", "{page}(으)로 이동함"),
+    ],
+  ),
+  (
+    "nl-NL",
+    &[
+      ("dismiss", "Negeren"),
+      ("previous", "Vorige"),
+      ("next", "Volgende"),
+      (
+        "dragStarted",
+        "Slepen gestart. Druk op Tab om naar een neerzetdoel te gaan, druk vervolgens op Enter om \
+         neer te zetten of op Escape om te annuleren.",
+      ),
+      ("dropComplete", "Neerzetten voltooid."),
+      ("dropCanceled", "Neerzetten geannuleerd."),
+      ("insertBefore", "Invoegen vóór {item}"),
+      ("insertAfter", "Invoegen na {item}"),
+      ("insertBetween", "Invoegen tussen {before} en {after}"),
+      ("dropOnItem", "Neerzetten op {item}"),
+      ("dropOnRoot", "Neerzetten in de verzameling"),
+      ("dropIndicator", "neerzetindicator"),
+      (
+        "dragDescription",
+        "Druk op Enter om te beginnen met slepen.",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, one {Het formulier bevat # fout: {fields}} other {Het formulier bevat # \
+         fouten: {fields}}}",
+      ),
+      ("quantity", "Aantal"),
+      ("increase", "Verhogen"),
+      ("decrease", "Verlagen"),
+      (
+        "reorderGrabbed",
+        "{item} opgepakt, positie {position} van {count}. Gebruik de pijltoetsen om te \
+         verplaatsen, Enter om neer te zetten en Escape om te annuleren.",
+      ),
+      ("reorderMoving", "{item}, positie {position} van {count}."),
+      (
+        "reorderMoved",
+        "{item} verplaatst naar positie {position} van {count}.",
+      ),
+      (
+        "reorderDropped",
+        "{item} neergezet op positie {position} van {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Herschikken geannuleerd. {item} is teruggezet op positie {position}.",
+      ),
+      ("day", "dag"),
+      ("month", "maand"),
+      ("year", "jaar"),
+      ("era", "tijdperk"),
+      ("empty", "Leeg"),
+      ("dayPlaceholder", "dd"),
+      ("monthPlaceholder", "mm"),
+      ("yearPlaceholder", "jjjj"),
+      ("red", "Rood"),
+      ("green", "Groen"),
+      ("blue", "Blauw"),
+      ("hue", "Tint"),
+      ("saturation", "Verzadiging"),
+      ("lightness", "Lichtheid"),
+      ("brightness", "Helderheid"),
+      ("alpha", "Alfa"),
+      ("twoDimensionalSlider", "2D-schuifregelaar"),
+      ("todayDate", "Vandaag, {date}"),
+      ("selectedDateDescription", "Geselecteerde datum: {date}"),
+      ("imageViewer", "Afbeeldingsviewer"),
+      ("previousImage", "Vorige afbeelding"),
+      ("nextImage", "Volgende afbeelding"),
+      ("imagePosition", "Afbeelding {position} van {count}"),
+      ("navigatedTo", "Genavigeerd naar {page}"),
+    ],
+  ),
+  (
+    "pl-PL",
+    &[
+      ("dismiss", "Zignoruj"),
+      ("previous", "Poprzedni"),
+      ("next", "Następny"),
+      (
+        "dragStarted",
+        "Rozpoczęto przeciąganie. Naciśnij Tab, aby przejść do miejsca upuszczenia, a następnie \
+         naciśnij Enter, aby upuścić, lub Escape, aby anulować.",
+      ),
+      ("dropComplete", "Upuszczanie zakończone."),
+      ("dropCanceled", "Upuszczanie anulowane."),
+      ("insertBefore", "Wstaw przed {item}"),
+      ("insertAfter", "Wstaw po {item}"),
+      ("insertBetween", "Wstaw między {before} a {after}"),
+      ("dropOnItem", "Upuść na {item}"),
+      ("dropOnRoot", "Upuść w kolekcji"),
+      ("dropIndicator", "wskaźnik upuszczania"),
+      (
+        "dragDescription",
+        "Naciśnij Enter, aby rozpocząć przeciąganie.",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, one {Formularz zawiera # błąd: {fields}} few {Formularz zawiera # błędy: \
+         {fields}} many {Formularz zawiera # błędów: {fields}} other {Formularz zawiera # błędu: \
+         {fields}}}",
+      ),
+      ("quantity", "Ilość"),
+      ("increase", "Zwiększ"),
+      ("decrease", "Zmniejsz"),
+      (
+        "reorderGrabbed",
+        "Chwycono {item}, pozycja {position} z {count}. Użyj klawiszy strzałek, aby przenieść, \
+         Enter, aby upuścić, lub Escape, aby anulować.",
+      ),
+      ("reorderMoving", "{item}, pozycja {position} z {count}."),
+      (
+        "reorderMoved",
+        "Przeniesiono {item} na pozycję {position} z {count}.",
+      ),
+      (
+        "reorderDropped",
+        "Upuszczono {item} na pozycji {position} z {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Anulowano zmianę kolejności. {item} wrócił na pozycję {position}.",
+      ),
+      ("day", "dzień"),
+      ("month", "miesiąc"),
+      ("year", "rok"),
+      ("era", "era"),
+      ("empty", "Pusty"),
+      ("dayPlaceholder", "dd"),
+      ("monthPlaceholder", "mm"),
+      ("yearPlaceholder", "rrrr"),
+      ("red", "Czerwony"),
+      ("green", "Zielony"),
+      ("blue", "Niebieski"),
+      ("hue", "Odcień"),
+      ("saturation", "Nasycenie"),
+      ("lightness", "Jasność"),
+      ("brightness", "Luminancja"),
+      ("alpha", "Alfa"),
+      ("twoDimensionalSlider", "Suwak 2D"),
+      ("todayDate", "Dzisiaj, {date}"),
+      ("selectedDateDescription", "Wybrana data: {date}"),
+      ("imageViewer", "Przeglądarka obrazów"),
+      ("previousImage", "Poprzedni obraz"),
+      ("nextImage", "Następny obraz"),
+      ("imagePosition", "Obraz {position} z {count}"),
+      ("navigatedTo", "Przejście do {page}"),
+    ],
+  ),
+  (
+    "pt-BR",
+    &[
+      ("dismiss", "Descartar"),
+      ("previous", "Anterior"),
+      ("next", "Próximo"),
+      (
+        "dragStarted",
+        "Arrastar iniciado. Pressione Tab para navegar até um destino de soltar, depois pressione \
+         Enter para soltar ou pressione Escape para cancelar.",
+      ),
+      ("dropComplete", "Soltar concluído."),
+      ("dropCanceled", "Soltar cancelado."),
+      ("insertBefore", "Inserir antes de {item}"),
+      ("insertAfter", "Inserir depois de {item}"),
+      ("insertBetween", "Inserir entre {before} e {after}"),
+      ("dropOnItem", "Soltar em {item}"),
+      ("dropOnRoot", "Soltar na coleção"),
+      ("dropIndicator", "indicador de soltar"),
+      (
+        "dragDescription",
+        "Pressione Enter para começar a arrastar.",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, one {Há # erro no formulário: {fields}} other {Há # erros no formulário: \
+         {fields}}}",
+      ),
+      ("quantity", "Quantidade"),
+      ("increase", "Aumentar"),
+      ("decrease", "Diminuir"),
+      (
+        "reorderGrabbed",
+        "{item} selecionado, posição {position} de {count}. Use as teclas de seta para mover, \
+         Enter para soltar e Escape para cancelar.",
+      ),
+      ("reorderMoving", "{item}, posição {position} de {count}."),
+      (
+        "reorderMoved",
+        "{item} movido para a posição {position} de {count}.",
+      ),
+      (
+        "reorderDropped",
+        "{item} solto na posição {position} de {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Reordenação cancelada. {item} voltou para a posição {position}.",
+      ),
+      ("day", "dia"),
+      ("month", "mês"),
+      ("year", "ano"),
+      ("era", "era"),
+      ("empty", "Vazio"),
+      ("dayPlaceholder", "dd"),
+      ("monthPlaceholder", "mm"),
+      ("yearPlaceholder", "aaaa"),
+      ("red", "Vermelho"),
+      ("green", "Verde"),
+      ("blue", "Azul"),
+      ("hue", "Matiz"),
+      ("saturation", "Saturação"),
+      ("lightness", "Luminosidade"),
+      ("brightness", "Brilho"),
+      ("alpha", "Alfa"),
+      ("twoDimensionalSlider", "Controle deslizante 2D"),
+      ("todayDate", "Hoje, {date}"),
+      ("selectedDateDescription", "Data selecionada: {date}"),
+      ("imageViewer", "Visualizador de imagens"),
+      ("previousImage", "Imagem anterior"),
+      ("nextImage", "Próxima imagem"),
+      ("imagePosition", "Imagem {position} de {count}"),
+      ("navigatedTo", "Navegou para {page}"),
+    ],
+  ),
+  (
+    "ru-RU",
+    &[
+      ("dismiss", "Пропустить"),
+      ("previous", "Назад"),
+      ("next", "Вперед"),
+      (
+        "dragStarted",
+        "Перетаскивание начато. Нажмите Tab, чтобы перейти к цели перетаскивания, затем нажмите \
+         Enter, чтобы отпустить, или Escape для отмены.",
+      ),
+      ("dropComplete", "Перетаскивание завершено."),
+      ("dropCanceled", "Перетаскивание отменено."),
+      ("insertBefore", "Вставить перед {item}"),
+      ("insertAfter", "Вставить после {item}"),
+      ("insertBetween", "Вставить между {before} и {after}"),
+      ("dropOnItem", "Поместить на {item}"),
+      ("dropOnRoot", "Поместить в коллекцию"),
+      ("dropIndicator", "индикатор перетаскивания"),
+      (
+        "dragDescription",
+        "Нажмите Enter, чтобы начать перетаскивание.",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, one {В форме # ошибка: {fields}} few {В форме # ошибки: {fields}} many \
+         {В форме # ошибок: {fields}} other {В форме # ошибки: {fields}}}",
+      ),
+      ("quantity", "Количество"),
+      ("increase", "Увеличить"),
+      ("decrease", "Уменьшить"),
+      (
+        "reorderGrabbed",
+        "{item} захвачен, позиция {position} из {count}. Используйте клавиши со стрелками для \
+         перемещения, Enter, чтобы отпустить, или Escape для отмены.",
+      ),
+      ("reorderMoving", "{item}, позиция {position} из {count}."),
+      (
+        "reorderMoved",
+        "{item} перемещен на позицию {position} из {count}.",
+      ),
+      (
+        "reorderDropped",
+        "{item} отпущен на позиции {position} из {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Изменение порядка отменено. {item} возвращен на позицию {position}.",
+      ),
+      ("day", "день"),
+      ("month", "месяц"),
+      ("year", "год"),
+      ("era", "эра"),
+      ("empty", "Не заполнено"),
+      ("dayPlaceholder", "дд"),
+      ("monthPlaceholder", "мм"),
+      ("yearPlaceholder", "гггг"),
+      ("red", "Красный"),
+      ("green", "Зеленый"),
+      ("blue", "Синий"),
+      ("hue", "Оттенок"),
+      ("saturation", "Насыщенность"),
+      ("lightness", "Освещенность"),
+      ("brightness", "Яркость"),
+      ("alpha", "Альфа"),
+      ("twoDimensionalSlider", "Двумерный ползунок"),
+      ("todayDate", "Сегодня, {date}"),
+      ("selectedDateDescription", "Выбранная дата: {date}"),
+      ("imageViewer", "Просмотр изображений"),
+      ("previousImage", "Предыдущее изображение"),
+      ("nextImage", "Следующее изображение"),
+      ("imagePosition", "Изображение {position} из {count}"),
+      ("navigatedTo", "Переход на {page}"),
+    ],
+  ),
+  (
+    "sv-SE",
+    &[
+      ("dismiss", "Avvisa"),
+      ("previous", "Föregående"),
+      ("next", "Nästa"),
+      (
+        "dragStarted",
+        "Dragning påbörjad. Tryck på Tabb för att navigera till ett släppmål, tryck sedan på \
+         Retur för att släppa eller på Escape för att avbryta.",
+      ),
+      ("dropComplete", "Släppningen är klar."),
+      ("dropCanceled", "Släppningen avbröts."),
+      ("insertBefore", "Infoga före {item}"),
+      ("insertAfter", "Infoga efter {item}"),
+      ("insertBetween", "Infoga mellan {before} och {after}"),
+      ("dropOnItem", "Släpp på {item}"),
+      ("dropOnRoot", "Släpp i samlingen"),
+      ("dropIndicator", "släppindikator"),
+      ("dragDescription", "Tryck på Enter för att börja dra."),
+      (
+        "formErrorSummary",
+        "{count, plural, other {Formuläret innehåller # fel: {fields}}}",
+      ),
+      ("quantity", "Antal"),
+      ("increase", "Öka"),
+      ("decrease", "Minska"),
+      (
+        "reorderGrabbed",
+        "{item} har greppats, position {position} av {count}. Använd piltangenterna för att \
+         flytta, Retur för att släppa och Escape för att avbryta.",
+      ),
+      ("reorderMoving", "{item}, position {position} av {count}."),
+      (
+        "reorderMoved",
+        "{item} har flyttats till position {position} av {count}.",
+      ),
+      (
+        "reorderDropped",
+        "{item} har släppts på position {position} av {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Omordningen avbröts. {item} har återgått till position {position}.",
+      ),
+      ("day", "dag"),
+      ("month", "månad"),
+      ("year", "år"),
+      ("era", "era"),
+      ("empty", "Tomt"),
+      ("dayPlaceholder", "dd"),
+      ("monthPlaceholder", "mm"),
+      ("yearPlaceholder", "åååå"),
+      ("red", "Röd"),
+      ("green", "Grön"),
+      ("blue", "Blå"),
+      ("hue", "Nyans"),
+      ("saturation", "Mättnad"),
+      ("lightness", "Ljushet"),
+      ("brightness", "Ljusstyrka"),
+      ("alpha", "Alfa"),
+      ("twoDimensionalSlider", "2D-reglage"),
+      ("todayDate", "Idag, {date}"),
+      ("selectedDateDescription", "Valt datum: {date}"),
+      ("imageViewer", "Bildvisare"),
+      ("previousImage", "Föregående bild"),
+      ("nextImage", "Nästa bild"),
+      ("imagePosition", "Bild {position} av {count}"),
+      ("navigatedTo", "Navigerade till {page}"),
+    ],
+  ),
+  (
+    "tr-TR",
+    &[
+      ("dismiss", "Kapat"),
+      ("previous", "Önceki"),
+      ("next", "Sonraki"),
+      (
+        "dragStarted",
+        "Sürükleme başlatıldı. Bir bırakma hedefine gitmek için Sekme tuşuna, ardından bırakmak \
+         için Enter tuşuna veya iptal etmek için Escape tuşuna basın.",
+      ),
+      ("dropComplete", "Bırakma tamamlandı."),
+      ("dropCanceled", "Bırakma iptal edildi."),
+      ("insertBefore", "{item} öğesinden önce ekle"),
+      ("insertAfter", "{item} öğesinden sonra ekle"),
+      ("insertBetween", "{before} ve {after} arasına ekle"),
+      ("dropOnItem", "{item} öğesinin üzerine bırak"),
+      ("dropOnRoot", "Koleksiyona bırak"),
+      ("dropIndicator", "bırakma göstergesi"),
+      (
+        "dragDescription",
+        "Sürüklemeye başlamak için Enter tuşuna basın.",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, other {Formda # hata var: {fields}}}",
+      ),
+      ("quantity", "Miktar"),
+      ("increase", "Artır"),
+      ("decrease", "Azalt"),
+      (
+        "reorderGrabbed",
+        "{item} tutuldu, konum {position}/{count}. Taşımak için ok tuşlarını, bırakmak için Enter \
+         tuşunu ve iptal etmek için Escape tuşunu kullanın.",
+      ),
+      ("reorderMoving", "{item}, konum {position}/{count}."),
+      (
+        "reorderMoved",
+        "{item}, {position}/{count} konumuna taşındı.",
+      ),
+      (
+        "reorderDropped",
+        "{item}, {position}/{count} konumuna bırakıldı.",
+      ),
+      (
+        "reorderCancelled",
+        "Yeniden sıralama iptal edildi. {item}, {position} konumuna geri döndü.",
+      ),
+      ("day", "gün"),
+      ("month", "ay"),
+      ("year", "yıl"),
+      ("era", "çağ"),
+      ("empty", "Boş"),
+      ("dayPlaceholder", "gg"),
+      ("monthPlaceholder", "aa"),
+      ("yearPlaceholder", "yyyy"),
+      ("red", "Kırmızı"),
+      ("green", "Yeşil"),
+      ("blue", "Mavi"),
+      ("hue", "Ton"),
+      ("saturation", "Doygunluk"),
+      ("lightness", "Açıklık"),
+      ("brightness", "Parlaklık"),
+      ("alpha", "Alfa"),
+      ("twoDimensionalSlider", "2B kaydırıcı"),
+      ("todayDate", "Bugün, {date}"),
+      ("selectedDateDescription", "Seçilen tarih: {date}"),
+      ("imageViewer", "Görüntü görüntüleyici"),
+      ("previousImage", "Önceki görüntü"),
+      ("nextImage", "Sonraki görüntü"),
+      ("imagePosition", "Görüntü {position}/{count}"),
+      ("navigatedTo", "{page} sayfasına gidildi"),
+    ],
+  ),
+  (
+    "uk-UA",
+    &[
+      ("dismiss", "Скасувати"),
+      ("previous", "Попередній"),
+      ("next", "Наступний"),
+      (
+        "dragStarted",
+        "Перетягування розпочато. Натисніть Tab, щоб перейти до цілі перетягування, потім \
+         натисніть Enter, щоб відпустити, або Escape, щоб скасувати.",
+      ),
+      ("dropComplete", "Перетягування завершено."),
+      ("dropCanceled", "Перетягування скасовано."),
+      ("insertBefore", "Вставити перед {item}"),
+      ("insertAfter", "Вставити після {item}"),
+      ("insertBetween", "Вставити між {before} і {after}"),
+      ("dropOnItem", "Перемістити на {item}"),
+      ("dropOnRoot", "Перемістити в колекцію"),
+      ("dropIndicator", "індикатор перетягування"),
+      (
+        "dragDescription",
+        "Натисніть Enter, щоб почати перетягування.",
+      ),
+      (
+        "formErrorSummary",
+        "{count, plural, one {У формі # помилка: {fields}} few {У формі # помилки: {fields}} many \
+         {У формі # помилок: {fields}} other {У формі # помилки: {fields}}}",
+      ),
+      ("quantity", "Кількість"),
+      ("increase", "Збільшити"),
+      ("decrease", "Зменшити"),
+      (
+        "reorderGrabbed",
+        "{item} захоплено, позиція {position} з {count}. Використовуйте клавіші зі стрілками для \
+         переміщення, Enter, щоб відпустити, або Escape, щоб скасувати.",
+      ),
+      ("reorderMoving", "{item}, позиція {position} з {count}."),
+      (
+        "reorderMoved",
+        "{item} переміщено на позицію {position} з {count}.",
+      ),
+      (
+        "reorderDropped",
+        "{item} відпущено на позиції {position} з {count}.",
+      ),
+      (
+        "reorderCancelled",
+        "Зміну порядку скасовано. {item} повернуто на позицію {position}.",
+      ),
+      ("day", "день"),
+      ("month", "місяць"),
+      ("year", "рік"),
+      ("era", "ера"),
+      ("empty", "Пусто"),
+      ("dayPlaceholder", "дд"),
+      ("monthPlaceholder", "мм"),
+      ("yearPlaceholder", "рррр"),
+      ("red", "Червоний"),
+      ("green", "Зелений"),
+      ("blue", "Синій"),
+      ("hue", "Відтінок"),
+      ("saturation", "Насиченість"),
+      ("lightness", "Освітленість"),
+      ("brightness", "Яскравість"),
+      ("alpha", "Альфа"),
+      ("twoDimensionalSlider", "Двовимірний повзунок"),
+      ("todayDate", "Сьогодні, {date}"),
+      ("selectedDateDescription", "Вибрана дата: {date}"),
+      ("imageViewer", "Переглядач зображень"),
+      ("previousImage", "Попереднє зображення"),
+      ("nextImage", "Наступне зображення"),
+      ("imagePosition", "Зображення {position} з {count}"),
+      ("navigatedTo", "Перехід до {page}"),
+    ],
+  ),
+  (
+    "zh-CN",
+    &[
+      ("dismiss", "取消"),
+      ("previous", "上一页"),
+      ("next", "下一页"),
+      (
+        "dragStarted",
+        "已开始拖动。按 Tab 导航到放置目标，然后按 Enter 放置，或按 Esc 取消。",
+      ),
+      ("dropComplete", "放置完成。"),
+      ("dropCanceled", "放置已取消。"),
+      ("insertBefore", "插入到 {item} 之前"),
+      ("insertAfter", "插入到 {item} 之后"),
+      ("insertBetween", "插入到 {before} 和 {after} 之间"),
+      ("dropOnItem", "放置在 {item} 上"),
+      ("dropOnRoot", "放置到集合中"),
+      ("dropIndicator", "放置指示器"),
+      ("dragDescription", "按 Enter 开始拖动。"),
+      (
+        "formErrorSummary",
+        "{count, plural, other {表单中有 # 个错误：{fields}}}",
+      ),
+      ("quantity", "数量"),
+      ("increase", "增加"),
+      ("decrease", "减少"),
+      (
+        "reorderGrabbed",
+        "已抓取 {item}，第 {position} 项，共 {count} 项。使用箭头键移动，按 Enter 放置，或按 Esc \
+         取消。",
+      ),
+      ("reorderMoving", "{item}，第 {position} 项，共 {count} 项。"),
+      (
+        "reorderMoved",
+        "已将 {item} 移动到第 {position} 项，共 {count} 项。",
+      ),
+      (
+        "reorderDropped",
+        "已将 {item} 放置在第 {position} 项，共 {count} 项。",
+      ),
+      (
+        "reorderCancelled",
+        "已取消重新排序。{item} 已返回第 {position} 项。",
+      ),
+      ("day", "日"),
+      ("month", "月"),
+      ("year", "年"),
+      ("era", "纪元"),
+      ("empty", "空"),
+      ("dayPlaceholder", "日"),
+      ("monthPlaceholder", "月"),
+      ("yearPlaceholder", "年"),
+      ("red", "红色"),
+      ("green", "绿色"),
+      ("blue", "蓝色"),
+      ("hue", "色相"),
+      ("saturation", "饱和度"),
+      ("lightness", "亮度"),
+      ("brightness", "明度"),
+      ("alpha", "Alpha"),
+      ("twoDimensionalSlider", "二维滑块"),
+      ("todayDate", "今天，{date}"),
+      ("selectedDateDescription", "选定的日期：{date}"),
+      ("imageViewer", "图像查看器"),
+      ("previousImage", "上一张图像"),
+      ("nextImage", "下一张图像"),
+      ("imagePosition", "第 {position} 张图像，共 {count} 张"),
+      ("navigatedTo", "已导航到 {page}"),
+    ],
+  ),
+  (
+    "zh-TW",
+    &[
+      ("dismiss", "關閉"),
+      ("previous", "上一頁"),
+      ("next", "下一頁"),
+      (
+        "dragStarted",
+        "已開始拖曳。按 Tab 導覽至放置目標，然後按 Enter 放置，或按 Esc 取消。",
+      ),
+      ("dropComplete", "放置完成。"),
+      ("dropCanceled", "放置已取消。"),
+      ("insertBefore", "插入至 {item} 之前"),
+      ("insertAfter", "插入至 {item} 之後"),
+      ("insertBetween", "插入至 {before} 和 {after} 之間"),
+      ("dropOnItem", "放置在 {item} 上"),
+      ("dropOnRoot", "放置到集合中"),
+      ("dropIndicator", "放置指示器"),
+      ("dragDescription", "按 Enter 鍵開始拖曳。"),
+      (
+        "formErrorSummary",
+        "{count, plural, other {表單中有 # 個錯誤：{fields}}}",
+      ),
+      ("quantity", "數量"),
+      ("increase", "增加"),
+      ("decrease", "減少"),
+      (
+        "reorderGrabbed",
+        "已抓取 {item}，第 {position} 項，共 {count} 項。使用方向鍵移動，按 Enter 放置，或按 Esc \
+         取消。",
+      ),
+      ("reorderMoving", "{item}，第 {position} 項，共 {count} 項。"),
+      (
+        "reorderMoved",
+        "已將 {item} 移動到第 {position} 項，共 {count} 項。",
+      ),
+      (
+        "reorderDropped",
+        "已將 {item} 放置在第 {position} 項，共 {count} 項。",
+      ),
+      (
+        "reorderCancelled",
+        "已取消重新排序。{item} 已返回第 {position} 項。",
+      ),
+      ("day", "日"),
+      ("month", "月"),
+      ("year", "年"),
+      ("era", "紀元"),
+      ("empty", "空白"),
+      ("dayPlaceholder", "日"),
+      ("monthPlaceholder", "月"),
+      ("yearPlaceholder", "年"),
+      ("red", "紅色"),
+      ("green", "綠色"),
+      ("blue", "藍色"),
+      ("hue", "色相"),
+      ("saturation", "飽和度"),
+      ("lightness", "亮度"),
+      ("brightness", "明度"),
+      ("alpha", "Alpha"),
+      ("twoDimensionalSlider", "2D 滑桿"),
+      ("todayDate", "今天，{date}"),
+      ("selectedDateDescription", "選定的日期：{date}"),
+      ("imageViewer", "影像檢視器"),
+      ("previousImage", "上一張影像"),
+      ("nextImage", "下一張影像"),
+      ("imagePosition", "第 {position} 張影像，共 {count} 張"),
+      ("navigatedTo", "已導覽至 {page}"),
+    ],
+  ),
+];
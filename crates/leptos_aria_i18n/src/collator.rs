@@ -0,0 +1,197 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use leptos::js_sys::Array;
+use leptos::js_sys::Intl;
+use leptos::js_sys::Object;
+use leptos::js_sys::Reflect;
+use leptos::typed_builder::TypedBuilder;
+use leptos::wasm_bindgen::JsValue;
+use leptos::IntoSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::use_locale;
+
+/// Whether the comparison is used for sorting or for matching.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CollatorUsage {
+  Sort,
+  Search,
+}
+
+impl CollatorUsage {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Sort => "sort",
+      Self::Search => "search",
+    }
+  }
+}
+
+/// Which differences between strings are significant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CollatorSensitivity {
+  /// Only different base letters, so `a`, `á` and `A` are equal.
+  Base,
+  /// Base letters and accents, so `a` and `A` are equal but `á` differs.
+  Accent,
+  /// Base letters and case, so `a` and `á` are equal but `A` differs.
+  Case,
+  /// Every difference is significant.
+  Variant,
+}
+
+impl CollatorSensitivity {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Base => "base",
+      Self::Accent => "accent",
+      Self::Case => "case",
+      Self::Variant => "variant",
+    }
+  }
+}
+
+/// The options of an `Intl.Collator`. Unset options use the defaults of the
+/// locale.
+#[derive(TypedBuilder, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CollatorOptions {
+  #[builder(default, setter(strip_option))]
+  pub usage: Option<CollatorUsage>,
+
+  #[builder(default, setter(strip_option))]
+  pub sensitivity: Option<CollatorSensitivity>,
+
+  /// Whether numbers are compared by value, so `"2"` sorts before `"10"`.
+  #[builder(default, setter(strip_option))]
+  pub numeric: Option<bool>,
+
+  #[builder(default, setter(strip_option))]
+  pub ignore_punctuation: Option<bool>,
+}
+
+impl CollatorOptions {
+  fn to_object(&self) -> Object {
+    let options = Object::new();
+    let set = |key: &str, value: JsValue| {
+      let _ = Reflect::set(&options, &key.into(), &value);
+    };
+
+    if let Some(usage) = self.usage {
+      set("usage", usage.as_str().into());
+    }
+
+    if let Some(sensitivity) = self.sensitivity {
+      set("sensitivity", sensitivity.as_str().into());
+    }
+
+    if let Some(numeric) = self.numeric {
+      set("numeric", numeric.into());
+    }
+
+    if let Some(ignore_punctuation) = self.ignore_punctuation {
+      set("ignorePunctuation", ignore_punctuation.into());
+    }
+
+    options
+  }
+}
+
+thread_local! {
+  /// Creating an `Intl.Collator` is expensive, so one instance is shared for
+  /// each combination of locale and options.
+  static COLLATORS: RefCell<HashMap<(String, CollatorOptions), Intl::Collator>> =
+    RefCell::new(HashMap::new());
+}
+
+/// Locale aware string comparison with `Intl.Collator`.
+///
+/// When rendering on the server strings are compared by their characters,
+/// ignoring case for the [`Base`](CollatorSensitivity::Base) and
+/// [`Accent`](CollatorSensitivity::Accent) sensitivities.
+#[derive(Clone)]
+pub struct Collator {
+  locale: String,
+  options: CollatorOptions,
+  collator: Option<Intl::Collator>,
+}
+
+impl Collator {
+  pub fn new(locale: &str, options: &CollatorOptions) -> Self {
+    let collator = (!is_server()).then(|| {
+      COLLATORS.with(|collators| {
+        collators
+          .borrow_mut()
+          .entry((locale.to_string(), options.clone()))
+          .or_insert_with(|| Intl::Collator::new(&Array::of1(&locale.into()), &options.to_object()))
+          .clone()
+      })
+    });
+
+    Self {
+      locale: locale.to_string(),
+      options: options.clone(),
+      collator,
+    }
+  }
+
+  pub fn locale(&self) -> &str {
+    &self.locale
+  }
+
+  pub fn compare(&self, a: &str, b: &str) -> Ordering {
+    let Some(ref collator) = self.collator else {
+      return fallback_compare(a, b, self.options.sensitivity);
+    };
+
+    collator
+      .compare()
+      .call2(&JsValue::UNDEFINED, &a.into(), &b.into())
+      .ok()
+      .and_then(|result| result.as_f64())
+      .map_or_else(
+        || fallback_compare(a, b, self.options.sensitivity),
+        |result| result.partial_cmp(&0.0).unwrap_or(Ordering::Equal),
+      )
+  }
+
+  /// Whether the strings are equal for the sensitivity of the collator.
+  pub fn equals(&self, a: &str, b: &str) -> bool {
+    self.compare(a, b) == Ordering::Equal
+  }
+}
+
+/// A collator for the locale from [`use_locale`], which is recreated when the
+/// locale changes.
+///
+/// Collators are cached, so calling this from every item of a collection
+/// shares a single `Intl.Collator`.
+pub fn use_collator(cx: Scope, options: CollatorOptions) -> Signal<Collator> {
+  let locale = use_locale(cx);
+  (move || Collator::new(&locale.get(), &options)).derive_signal(cx)
+}
+
+fn fallback_compare(a: &str, b: &str, sensitivity: Option<CollatorSensitivity>) -> Ordering {
+  match sensitivity {
+    Some(CollatorSensitivity::Base | CollatorSensitivity::Accent) => {
+      a.to_lowercase().cmp(&b.to_lowercase())
+    }
+    _ => a.cmp(b),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fallback_ignores_case_for_base_sensitivity() {
+    let base = Some(CollatorSensitivity::Base);
+
+    assert_eq!(fallback_compare("Apple", "apple", base), Ordering::Equal);
+    assert_eq!(fallback_compare("apple", "Banana", base), Ordering::Less);
+    assert_eq!(fallback_compare("Apple", "apple", None), Ordering::Less);
+  }
+}
@@ -0,0 +1,305 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use leptos::js_sys::Array;
+use leptos::js_sys::Date;
+use leptos::js_sys::Function;
+use leptos::js_sys::Intl;
+use leptos::js_sys::Object;
+use leptos::js_sys::Reflect;
+use leptos::typed_builder::TypedBuilder;
+use leptos::wasm_bindgen::JsValue;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::use_locale;
+
+/// The length of a date or time when formatted as a whole.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DateFormatStyle {
+  /// E.g. `"Monday, March 4, 2024"`.
+  Full,
+  /// E.g. `"March 4, 2024"`.
+  Long,
+  /// E.g. `"Mar 4, 2024"`.
+  Medium,
+  /// E.g. `"3/4/24"`.
+  Short,
+}
+
+impl DateFormatStyle {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Full => "full",
+      Self::Long => "long",
+      Self::Medium => "medium",
+      Self::Short => "short",
+    }
+  }
+}
+
+/// How an individual part of a date is displayed. Not every style is valid
+/// for every part, e.g. the day is only `Numeric` or `TwoDigit`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DatePartStyle {
+  Numeric,
+  TwoDigit,
+  Long,
+  Short,
+  Narrow,
+}
+
+impl DatePartStyle {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Numeric => "numeric",
+      Self::TwoDigit => "2-digit",
+      Self::Long => "long",
+      Self::Short => "short",
+      Self::Narrow => "narrow",
+    }
+  }
+}
+
+/// The options of an `Intl.DateTimeFormat`. Unset options use the defaults of
+/// the locale.
+#[derive(TypedBuilder, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DateFormatOptions {
+  /// Can't be combined with the options of individual parts.
+  #[builder(default, setter(strip_option))]
+  pub date_style: Option<DateFormatStyle>,
+
+  /// Can't be combined with the options of individual parts.
+  #[builder(default, setter(strip_option))]
+  pub time_style: Option<DateFormatStyle>,
+
+  #[builder(default, setter(strip_option))]
+  pub weekday: Option<DatePartStyle>,
+
+  #[builder(default, setter(strip_option))]
+  pub era: Option<DatePartStyle>,
+
+  #[builder(default, setter(strip_option))]
+  pub year: Option<DatePartStyle>,
+
+  #[builder(default, setter(strip_option))]
+  pub month: Option<DatePartStyle>,
+
+  #[builder(default, setter(strip_option))]
+  pub day: Option<DatePartStyle>,
+
+  #[builder(default, setter(strip_option))]
+  pub hour: Option<DatePartStyle>,
+
+  #[builder(default, setter(strip_option))]
+  pub minute: Option<DatePartStyle>,
+
+  #[builder(default, setter(strip_option))]
+  pub second: Option<DatePartStyle>,
+
+  /// Whether the 12 hour clock is used instead of the default of the locale.
+  #[builder(default, setter(strip_option))]
+  pub hour12: Option<bool>,
+
+  /// An IANA time zone, e.g. `"Europe/Berlin"`. Defaults to the time zone of
+  /// the browser.
+  #[builder(default, setter(strip_option, into))]
+  pub time_zone: Option<String>,
+}
+
+impl DateFormatOptions {
+  fn to_object(&self) -> Object {
+    let options = Object::new();
+    let set = |key: &str, value: JsValue| {
+      let _ = Reflect::set(&options, &key.into(), &value);
+    };
+
+    if let Some(date_style) = self.date_style {
+      set("dateStyle", date_style.as_str().into());
+    }
+
+    if let Some(time_style) = self.time_style {
+      set("timeStyle", time_style.as_str().into());
+    }
+
+    let parts = [
+      ("weekday", self.weekday),
+      ("era", self.era),
+      ("year", self.year),
+      ("month", self.month),
+      ("day", self.day),
+      ("hour", self.hour),
+      ("minute", self.minute),
+      ("second", self.second),
+    ];
+
+    for (key, style) in parts {
+      if let Some(style) = style {
+        set(key, style.as_str().into());
+      }
+    }
+
+    if let Some(hour12) = self.hour12 {
+      set("hour12", hour12.into());
+    }
+
+    if let Some(ref time_zone) = self.time_zone {
+      set("timeZone", time_zone.into());
+    }
+
+    options
+  }
+}
+
+/// A value which can be formatted by a [`DateFormatter`].
+pub trait FormattableDate {
+  fn to_js_date(&self) -> Date;
+
+  /// The text used when rendering on the server, where `Intl` is
+  /// unavailable, e.g. the ISO format of the date.
+  fn to_fallback_string(&self) -> String;
+}
+
+/// The options which were resolved for the locale, e.g. the default calendar
+/// and numbering system.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedDateFormatOptions {
+  pub locale: String,
+  pub calendar: String,
+  pub numbering_system: String,
+  pub time_zone: String,
+  /// `"h11"`, `"h12"`, `"h23"` or `"h24"` when the format includes the hour.
+  pub hour_cycle: Option<String>,
+}
+
+thread_local! {
+  /// Creating an `Intl.DateTimeFormat` is expensive, so one instance is shared
+  /// for each combination of locale and options.
+  static DATE_TIME_FORMATS: RefCell<HashMap<(String, DateFormatOptions), Intl::DateTimeFormat>> =
+    RefCell::new(HashMap::new());
+}
+
+/// Locale aware date formatting with `Intl.DateTimeFormat`.
+///
+/// When rendering on the server dates are formatted with
+/// [`to_fallback_string`](FormattableDate::to_fallback_string) and have no
+/// parts.
+#[derive(Clone)]
+pub struct DateFormatter {
+  locale: String,
+  date_time_format: Option<Intl::DateTimeFormat>,
+}
+
+impl DateFormatter {
+  pub fn new(locale: &str, options: &DateFormatOptions) -> Self {
+    let date_time_format = (!is_server()).then(|| {
+      DATE_TIME_FORMATS.with(|date_time_formats| {
+        date_time_formats
+          .borrow_mut()
+          .entry((locale.to_string(), options.clone()))
+          .or_insert_with(|| {
+            Intl::DateTimeFormat::new(&Array::of1(&locale.into()), &options.to_object())
+          })
+          .clone()
+      })
+    });
+
+    Self {
+      locale: locale.to_string(),
+      date_time_format,
+    }
+  }
+
+  pub fn locale(&self) -> &str {
+    &self.locale
+  }
+
+  pub fn format(&self, date: &impl FormattableDate) -> String {
+    let Some(ref date_time_format) = self.date_time_format else {
+      return date.to_fallback_string();
+    };
+
+    date_time_format
+      .format()
+      .call1(&JsValue::UNDEFINED, &date.to_js_date())
+      .ok()
+      .and_then(|formatted| formatted.as_string())
+      .unwrap_or_else(|| date.to_fallback_string())
+  }
+
+  /// The formatted date as pairs of the part type and value, e.g.
+  /// `[("month", "03"), ("literal", "/"), ...]`. Empty when rendering on the
+  /// server.
+  pub fn format_to_parts(&self, date: &impl FormattableDate) -> Vec<(String, String)> {
+    let Some(ref date_time_format) = self.date_time_format else {
+      return vec![];
+    };
+
+    date_time_format
+      .format_to_parts(&date.to_js_date())
+      .iter()
+      .filter_map(|part| {
+        let part_type = Reflect::get(&part, &"type".into()).ok()?.as_string()?;
+        let value = Reflect::get(&part, &"value".into()).ok()?.as_string()?;
+        Some((part_type, value))
+      })
+      .collect()
+  }
+
+  /// Format the range between two dates, sharing the parts they have in
+  /// common, e.g. `"March 4 – 10, 2024"`.
+  pub fn format_range(&self, start: &impl FormattableDate, end: &impl FormattableDate) -> String {
+    let fallback = || format!("{} – {}", self.format(start), self.format(end));
+    let Some(ref date_time_format) = self.date_time_format else {
+      return fallback();
+    };
+
+    // `formatRange` isn't bound by `js_sys`, and older browsers lack it.
+    Reflect::get(date_time_format, &"formatRange".into())
+      .ok()
+      .and_then(|format_range| format_range.dyn_into::<Function>().ok())
+      .and_then(|format_range| {
+        format_range
+          .call2(date_time_format, &start.to_js_date(), &end.to_js_date())
+          .ok()
+      })
+      .and_then(|formatted| formatted.as_string())
+      .unwrap_or_else(fallback)
+  }
+
+  pub fn resolved_options(&self) -> ResolvedDateFormatOptions {
+    let Some(ref date_time_format) = self.date_time_format else {
+      return ResolvedDateFormatOptions {
+        locale: self.locale.clone(),
+        calendar: "gregory".into(),
+        numbering_system: "latn".into(),
+        time_zone: "UTC".into(),
+        hour_cycle: None,
+      };
+    };
+
+    let options = date_time_format.resolved_options();
+    let get = |key: &str| {
+      Reflect::get(&options, &key.into())
+        .ok()
+        .and_then(|value| value.as_string())
+    };
+
+    ResolvedDateFormatOptions {
+      locale: get("locale").unwrap_or_else(|| self.locale.clone()),
+      calendar: get("calendar").unwrap_or_default(),
+      numbering_system: get("numberingSystem").unwrap_or_default(),
+      time_zone: get("timeZone").unwrap_or_default(),
+      hour_cycle: get("hourCycle"),
+    }
+  }
+}
+
+/// A date formatter for the locale from [`use_locale`], which is recreated
+/// when the locale changes.
+pub fn use_date_formatter(cx: Scope, options: DateFormatOptions) -> Signal<DateFormatter> {
+  let locale = use_locale(cx);
+  (move || DateFormatter::new(&locale.get(), &options)).derive_signal(cx)
+}
@@ -0,0 +1,192 @@
+use leptos::js_sys::JsString;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::is_server;
+
+use crate::use_collator;
+use crate::Collator;
+use crate::CollatorOptions;
+use crate::CollatorSensitivity;
+use crate::CollatorUsage;
+
+/// Locale aware matching of a query against text, e.g. to filter the options
+/// of a combobox or the suggestions of a search field.
+///
+/// Both strings are normalized to the composed Unicode form and compared with
+/// a search collator, so precomposed and decomposed accents match each other
+/// and, with the default base sensitivity, `"e"` matches `"É"`.
+#[derive(Copy, Clone)]
+pub struct Filter {
+  collator: Signal<Collator>,
+}
+
+/// A filter for the locale from [`use_locale`](leptos_aria_utils::use_locale).
+/// The `options` default to a search collator with base sensitivity.
+pub fn use_filter(cx: Scope, options: Option<CollatorOptions>) -> Filter {
+  let options = options.unwrap_or_else(|| {
+    CollatorOptions::builder()
+      .usage(CollatorUsage::Search)
+      .sensitivity(CollatorSensitivity::Base)
+      .build()
+  });
+
+  Filter {
+    collator: use_collator(cx, options),
+  }
+}
+
+impl Filter {
+  /// Whether the text starts with the query. An empty query always matches.
+  pub fn starts_with(&self, text: &str, query: &str) -> bool {
+    let text = normalize(text);
+    let query = normalize(query);
+    let window = query.chars().count();
+
+    text.chars().count() >= window && matches(&self.collator(), text.chars().take(window), &query)
+  }
+
+  /// Whether the text ends with the query. An empty query always matches.
+  pub fn ends_with(&self, text: &str, query: &str) -> bool {
+    let text = normalize(text);
+    let query = normalize(query);
+    let length = text.chars().count();
+    let window = query.chars().count();
+
+    length >= window && matches(&self.collator(), text.chars().skip(length - window), &query)
+  }
+
+  /// Whether the query is found anywhere in the text. An empty query always
+  /// matches.
+  pub fn contains(&self, text: &str, query: &str) -> bool {
+    let text = normalize(text);
+    let query = normalize(query);
+    let characters: Vec<char> = text.chars().collect();
+    let window = query.chars().count();
+
+    if window == 0 {
+      return true;
+    }
+
+    let collator = self.collator();
+
+    characters
+      .windows(window)
+      .any(|slice| matches(&collator, slice.iter().copied(), &query))
+  }
+
+  fn collator(&self) -> Collator {
+    self.collator.get_untracked()
+  }
+}
+
+fn matches(collator: &Collator, slice: impl Iterator<Item = char>, query: &str) -> bool {
+  let slice: String = slice.collect();
+  collator.equals(&slice, query)
+}
+
+/// The composed (NFC) form of the text. Returned unchanged when rendering on
+/// the server.
+fn normalize(text: &str) -> String {
+  if is_server() {
+    return text.to_string();
+  }
+
+  JsString::from(text)
+    .normalize("NFC")
+    .as_string()
+    .unwrap_or_else(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+  use leptos::IntoSignal;
+
+  use super::*;
+
+  fn filter(cx: Scope, locale: &str, sensitivity: CollatorSensitivity) -> Filter {
+    let options = CollatorOptions::builder()
+      .usage(CollatorUsage::Search)
+      .sensitivity(sensitivity)
+      .build();
+    let collator = Collator::new(locale, &options);
+
+    Filter {
+      collator: (move || collator.clone()).derive_signal(cx),
+    }
+  }
+
+  #[test]
+  fn ignores_case_for_base_sensitivity() {
+    run_scope(create_runtime(), |cx| {
+      let filter = filter(cx, "en-US", CollatorSensitivity::Base);
+
+      assert!(filter.starts_with("Apple", "aP"));
+      assert!(filter.ends_with("Apple", "PLE"));
+      assert!(filter.contains("Pineapple", "APP"));
+      assert!(!filter.contains("Pineapple", "pear"));
+      assert!(!filter.starts_with("Ap", "Apple"));
+      assert!(filter.contains("Apple", ""));
+    });
+  }
+
+  #[test]
+  fn matches_case_for_variant_sensitivity() {
+    run_scope(create_runtime(), |cx| {
+      let filter = filter(cx, "en-US", CollatorSensitivity::Variant);
+
+      assert!(filter.starts_with("Apple", "Ap"));
+      assert!(!filter.starts_with("Apple", "ap"));
+      assert!(!filter.contains("Pineapple", "APP"));
+    });
+  }
+
+  #[cfg(target_arch = "wasm32")]
+  mod browser {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn ignores_accents_for_base_sensitivity() {
+      run_scope(create_runtime(), |cx| {
+        let filter = filter(cx, "en-US", CollatorSensitivity::Base);
+
+        assert!(filter.starts_with("École", "ecole"));
+        assert!(filter.contains("Crème brûlée", "creme brulee"));
+        assert!(filter.ends_with("Café", "FE"));
+      });
+    }
+
+    #[wasm_bindgen_test]
+    fn matches_accents_for_accent_sensitivity() {
+      run_scope(create_runtime(), |cx| {
+        let filter = filter(cx, "en-US", CollatorSensitivity::Accent);
+
+        assert!(!filter.starts_with("École", "ecole"));
+        assert!(filter.starts_with("École", "école"));
+        // The decomposed form, `e` followed by a combining acute accent.
+        assert!(filter.starts_with("E\u{301}cole", "école"));
+        assert!(filter.contains("cafe\u{301} au lait", "Café"));
+      });
+    }
+
+    #[wasm_bindgen_test]
+    fn uses_the_collation_of_the_locale() {
+      run_scope(create_runtime(), |cx| {
+        let german = filter(cx, "de-DE", CollatorSensitivity::Base);
+        let swedish = filter(cx, "sv-SE", CollatorSensitivity::Base);
+
+        // `ä` is an `a` with an umlaut in German, but a separate letter in
+        // Swedish.
+        assert!(german.starts_with("Äpple", "apple"));
+        assert!(!swedish.starts_with("Äpple", "apple"));
+        assert!(swedish.starts_with("Äpple", "äpple"));
+      });
+    }
+  }
+}
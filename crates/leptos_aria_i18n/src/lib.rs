@@ -0,0 +1,18 @@
+pub use collator::*;
+pub use date_formatter::*;
+pub use filter::*;
+pub use list_formatter::*;
+pub use localized_strings::*;
+pub use message_format::MessageArgument;
+pub use number_formatter::*;
+pub use number_parser::*;
+
+mod builtin_strings;
+mod collator;
+mod date_formatter;
+mod filter;
+mod list_formatter;
+mod localized_strings;
+mod message_format;
+mod number_formatter;
+mod number_parser;
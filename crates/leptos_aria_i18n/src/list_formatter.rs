@@ -0,0 +1,213 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use leptos::js_sys::global;
+use leptos::js_sys::Array;
+use leptos::js_sys::Function;
+use leptos::js_sys::Object;
+use leptos::js_sys::Reflect;
+use leptos::typed_builder::TypedBuilder;
+use leptos::wasm_bindgen::JsValue;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::use_locale;
+
+/// How the items of the list relate to each other.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ListFormatType {
+  /// E.g. `"a, b, and c"`.
+  #[default]
+  Conjunction,
+  /// E.g. `"a, b, or c"`.
+  Disjunction,
+  /// A list of measurements, e.g. `"5 pounds, 12 ounces"`.
+  Unit,
+}
+
+impl ListFormatType {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Conjunction => "conjunction",
+      Self::Disjunction => "disjunction",
+      Self::Unit => "unit",
+    }
+  }
+}
+
+/// The length of the connecting words.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ListFormatStyle {
+  /// E.g. `"a, b, and c"`.
+  #[default]
+  Long,
+  /// E.g. `"a, b, & c"`.
+  Short,
+  /// E.g. `"a, b, c"`.
+  Narrow,
+}
+
+impl ListFormatStyle {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Long => "long",
+      Self::Short => "short",
+      Self::Narrow => "narrow",
+    }
+  }
+}
+
+/// The options of an `Intl.ListFormat`.
+#[derive(TypedBuilder, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ListFormatOptions {
+  #[builder(default)]
+  pub list_type: ListFormatType,
+
+  #[builder(default)]
+  pub style: ListFormatStyle,
+}
+
+impl ListFormatOptions {
+  fn to_object(&self) -> Object {
+    let options = Object::new();
+    let _ = Reflect::set(&options, &"type".into(), &self.list_type.as_str().into());
+    let _ = Reflect::set(&options, &"style".into(), &self.style.as_str().into());
+    options
+  }
+}
+
+thread_local! {
+  /// One `Intl.ListFormat` for each combination of locale and options, or
+  /// `None` when the browser doesn't support it.
+  static LIST_FORMATS: RefCell<HashMap<(String, ListFormatOptions), Option<Object>>> =
+    RefCell::new(HashMap::new());
+}
+
+/// Locale aware joining of lists with `Intl.ListFormat`, e.g. `"Name, Email
+/// and Phone"` rather than `"Name, Email, Phone"`.
+///
+/// When rendering on the server, or in browsers without `Intl.ListFormat`,
+/// lists are joined in English.
+#[derive(Clone)]
+pub struct ListFormatter {
+  locale: String,
+  options: ListFormatOptions,
+  list_format: Option<Object>,
+}
+
+impl ListFormatter {
+  pub fn new(locale: &str, options: &ListFormatOptions) -> Self {
+    let list_format = if is_server() {
+      None
+    } else {
+      LIST_FORMATS.with(|list_formats| {
+        list_formats
+          .borrow_mut()
+          .entry((locale.to_string(), options.clone()))
+          .or_insert_with(|| create_list_format(locale, options))
+          .clone()
+      })
+    };
+
+    Self {
+      locale: locale.to_string(),
+      options: options.clone(),
+      list_format,
+    }
+  }
+
+  pub fn locale(&self) -> &str {
+    &self.locale
+  }
+
+  pub fn format<T: AsRef<str>>(&self, items: &[T]) -> String {
+    let Some(ref list_format) = self.list_format else {
+      return fallback_format(items, &self.options);
+    };
+
+    let list = items
+      .iter()
+      .map(|item| JsValue::from(item.as_ref()))
+      .collect::<Array>();
+
+    Reflect::get(list_format, &"format".into())
+      .ok()
+      .and_then(|format| format.dyn_into::<Function>().ok())
+      .and_then(|format| format.call1(list_format, &list).ok())
+      .and_then(|formatted| formatted.as_string())
+      .unwrap_or_else(|| fallback_format(items, &self.options))
+  }
+}
+
+/// A list formatter for the locale from [`use_locale`], which is recreated
+/// when the locale changes.
+pub fn use_list_formatter(cx: Scope, options: ListFormatOptions) -> Signal<ListFormatter> {
+  let locale = use_locale(cx);
+  (move || ListFormatter::new(&locale.get(), &options)).derive_signal(cx)
+}
+
+/// `Intl.ListFormat` isn't bound by `js_sys`, so the constructor is read from
+/// the global `Intl` object.
+fn create_list_format(locale: &str, options: &ListFormatOptions) -> Option<Object> {
+  let intl = Reflect::get(&global(), &"Intl".into()).ok()?;
+  let constructor = Reflect::get(&intl, &"ListFormat".into())
+    .ok()?
+    .dyn_into::<Function>()
+    .ok()?;
+  let arguments = Array::of2(&Array::of1(&locale.into()), &options.to_object());
+
+  Reflect::construct(&constructor, &arguments)
+    .ok()?
+    .dyn_into::<Object>()
+    .ok()
+}
+
+/// Join the items like `Intl.ListFormat` does for `en-US`.
+fn fallback_format<T: AsRef<str>>(items: &[T], options: &ListFormatOptions) -> String {
+  let items: Vec<&str> = items.iter().map(AsRef::as_ref).collect();
+  let separator = match (options.list_type, options.style) {
+    (ListFormatType::Unit, ListFormatStyle::Narrow) => " ",
+    _ => ", ",
+  };
+  let conjunction = match (options.list_type, options.style) {
+    (ListFormatType::Conjunction, ListFormatStyle::Long) => Some("and"),
+    (ListFormatType::Conjunction, ListFormatStyle::Short) => Some("&"),
+    (ListFormatType::Disjunction, _) => Some("or"),
+    _ => None,
+  };
+
+  match (items.as_slice(), conjunction) {
+    ([], _) => String::new(),
+    ([item], _) => item.to_string(),
+    ([first, second], Some(conjunction)) => format!("{first} {conjunction} {second}"),
+    ([rest @ .., last], Some(conjunction)) => {
+      format!("{}, {conjunction} {last}", rest.join(", "))
+    }
+    (items, None) => items.join(separator),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fallback_joins_in_english() {
+    let and = ListFormatOptions::default();
+    let or = ListFormatOptions::builder()
+      .list_type(ListFormatType::Disjunction)
+      .build();
+    let narrow = ListFormatOptions::builder()
+      .style(ListFormatStyle::Narrow)
+      .build();
+
+    assert_eq!(fallback_format::<&str>(&[], &and), "");
+    assert_eq!(fallback_format(&["Name"], &and), "Name");
+    assert_eq!(fallback_format(&["Name", "Email"], &and), "Name and Email");
+    assert_eq!(fallback_format(&["a", "b", "c"], &and), "a, b, and c");
+    assert_eq!(fallback_format(&["a", "b", "c"], &or), "a, b, or c");
+    assert_eq!(fallback_format(&["a", "b", "c"], &narrow), "a, b, c");
+  }
+}
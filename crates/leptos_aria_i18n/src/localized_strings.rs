@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use leptos::provide_context;
+use leptos::use_context;
+use leptos::IntoSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::use_locale;
+
+use crate::builtin_strings::BUILTIN_STRINGS;
+use crate::message_format::format_message;
+use crate::MessageArgument;
+
+/// The locale used for strings which aren't translated for the current
+/// locale.
+const FALLBACK_LOCALE: &str = "en-US";
+
+/// Translated strings, by locale and key.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocalizedStrings {
+  strings: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl LocalizedStrings {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Add a translation, replacing any previous translation of the key for the
+  /// locale.
+  pub fn insert(
+    &mut self,
+    locale: impl Into<String>,
+    key: impl Into<String>,
+    value: impl Into<String>,
+  ) {
+    self
+      .strings
+      .entry(locale.into())
+      .or_default()
+      .insert(key.into(), value.into());
+  }
+
+  /// Add a translation while building the strings, e.g.
+  /// `LocalizedStrings::new().with("de-DE", "dismiss", "Ausblenden")`.
+  pub fn with(
+    mut self,
+    locale: impl Into<String>,
+    key: impl Into<String>,
+    value: impl Into<String>,
+  ) -> Self {
+    self.insert(locale, key, value);
+    self
+  }
+
+  /// Add every translation of `other`, replacing existing ones.
+  pub fn extend(&mut self, other: &Self) {
+    for (locale, strings) in &other.strings {
+      for (key, value) in strings {
+        self.insert(locale, key, value);
+      }
+    }
+  }
+
+  /// The translation for the locale. Falls back to a locale with the same
+  /// language, so `"de-AT"` uses the translation for `"de-DE"`.
+  pub fn get(&self, locale: &str, key: &str) -> Option<&str> {
+    self
+      .get_exact(locale, key)
+      .or_else(|| self.get_by_language(locale, key))
+  }
+
+  fn get_exact(&self, locale: &str, key: &str) -> Option<&str> {
+    self
+      .strings
+      .get(locale)
+      .and_then(|strings| strings.get(key))
+      .map(String::as_str)
+  }
+
+  fn get_by_language(&self, locale: &str, key: &str) -> Option<&str> {
+    let language = language_of(locale);
+
+    self
+      .strings
+      .iter()
+      .filter(|(other, _)| language_of(other) == language)
+      .find_map(|(_, strings)| strings.get(key))
+      .map(String::as_str)
+  }
+}
+
+thread_local! {
+  static BUILTIN: Rc<LocalizedStrings> = {
+    let mut builtin = LocalizedStrings::new();
+
+    for (locale, strings) in BUILTIN_STRINGS {
+      for (key, value) in *strings {
+        builtin.insert(*locale, *key, *value);
+      }
+    }
+
+    Rc::new(builtin)
+  };
+}
+
+#[derive(Clone)]
+struct LocalizedStringsContext(Rc<LocalizedStrings>);
+
+/// Override the built-in strings of the hooks, or add translations for other
+/// locales, in the scope and its children.
+///
+/// The strings are merged with those provided by parent scopes, and take
+/// precedence over them.
+pub fn provide_localized_strings(cx: Scope, strings: LocalizedStrings) {
+  let mut merged = use_context::<LocalizedStringsContext>(cx)
+    .map(|LocalizedStringsContext(parent)| (*parent).clone())
+    .unwrap_or_default();
+  merged.extend(&strings);
+  provide_context(cx, LocalizedStringsContext(Rc::new(merged)));
+}
+
+/// Looks up the strings used by the hooks, e.g. `"dismiss"`, for a locale.
+///
+/// Translations provided by the application are preferred over the built-in
+/// ones, and a locale with the same language is preferred over English.
+/// Unknown keys are returned as they are.
+#[derive(Clone)]
+pub struct LocalizedStringFormatter {
+  locale: String,
+  overrides: Option<Rc<LocalizedStrings>>,
+  builtin: Rc<LocalizedStrings>,
+}
+
+impl LocalizedStringFormatter {
+  pub fn new(locale: &str, overrides: Option<Rc<LocalizedStrings>>) -> Self {
+    Self {
+      locale: locale.to_string(),
+      overrides,
+      builtin: BUILTIN.with(Rc::clone),
+    }
+  }
+
+  pub fn locale(&self) -> &str {
+    &self.locale
+  }
+
+  /// The same strings for another locale, e.g. for a hook which accepts its
+  /// own locale.
+  pub fn for_locale(&self, locale: &str) -> Self {
+    Self {
+      locale: locale.to_string(),
+      ..self.clone()
+    }
+  }
+
+  pub fn format(&self, key: &str) -> String {
+    self.lookup(key).unwrap_or(key).to_string()
+  }
+
+  /// Format a string which uses the ICU message format, e.g. `"{count,
+  /// plural, one {# result} other {# results}} available"` with `[("count",
+  /// 3.into())]`.
+  ///
+  /// `{name}` is replaced with the argument, numbers are formatted for the
+  /// locale, `plural` chooses a branch with the plural rules of the locale
+  /// and `select` chooses the branch matching a text argument.
+  pub fn format_with(&self, key: &str, arguments: &[(&str, MessageArgument)]) -> String {
+    format_message(&self.format(key), arguments, &self.locale)
+  }
+
+  fn lookup(&self, key: &str) -> Option<&str> {
+    let sources = [self.overrides.as_deref(), Some(&*self.builtin)];
+
+    [self.locale.as_str(), FALLBACK_LOCALE]
+      .into_iter()
+      .find_map(|locale| {
+        sources
+          .into_iter()
+          .flatten()
+          .find_map(|strings| strings.get_exact(locale, key))
+          .or_else(|| {
+            sources
+              .into_iter()
+              .flatten()
+              .find_map(|strings| strings.get_by_language(locale, key))
+          })
+      })
+  }
+}
+
+/// A string formatter for the locale from [`use_locale`] and the strings
+/// provided with [`provide_localized_strings`].
+pub fn use_localized_string_formatter(cx: Scope) -> Signal<LocalizedStringFormatter> {
+  let locale = use_locale(cx);
+  let overrides = use_context::<LocalizedStringsContext>(cx).map(|context| context.0);
+
+  (move || LocalizedStringFormatter::new(&locale.get(), overrides.clone())).derive_signal(cx)
+}
+
+/// The language subtag of the locale, e.g. `"pt"` for `"pt-BR"`.
+fn language_of(locale: &str) -> &str {
+  locale.split(['-', '_']).next().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn falls_back_to_the_language_and_then_english() {
+    let formatter = LocalizedStringFormatter::new("de-AT", None);
+    assert_eq!(formatter.format("next"), "Weiter");
+
+    let formatter = LocalizedStringFormatter::new("xx-XX", None);
+    assert_eq!(formatter.format("next"), "Next");
+    assert_eq!(formatter.format("unknown"), "unknown");
+  }
+
+  #[test]
+  fn overrides_take_precedence() {
+    let overrides = LocalizedStrings::new()
+      .with("de-DE", "dismiss", "Ausblenden")
+      .with("fi-FI", "next", "Seuraava");
+
+    let formatter = LocalizedStringFormatter::new("de-DE", Some(Rc::new(overrides.clone())));
+    assert_eq!(formatter.format("dismiss"), "Ausblenden");
+    assert_eq!(formatter.format("previous"), "Zurück");
+
+    let formatter = LocalizedStringFormatter::new("fi-FI", Some(Rc::new(overrides)));
+    assert_eq!(formatter.format("next"), "Seuraava");
+    assert_eq!(formatter.format("previous"), "Previous");
+  }
+
+  #[test]
+  fn replaces_variables() {
+    let overrides = LocalizedStrings::new().with("en-US", "greeting", "Hello {name}");
+    let formatter = LocalizedStringFormatter::new("en-US", Some(Rc::new(overrides)));
+
+    assert_eq!(
+      formatter.format_with("greeting", &[("name", "Ada".into())]),
+      "Hello Ada"
+    );
+  }
+}
@@ -0,0 +1,463 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use leptos::js_sys::Array;
+use leptos::js_sys::Intl;
+use leptos::js_sys::Object;
+use leptos_aria_utils::is_server;
+
+use crate::NumberFormatOptions;
+use crate::NumberFormatter;
+
+/// A value which is interpolated into a localized string.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MessageArgument {
+  Text(String),
+  /// Formatted for the locale, and used to choose the branch of a `plural`.
+  Number(f64),
+}
+
+impl From<&str> for MessageArgument {
+  fn from(value: &str) -> Self {
+    Self::Text(value.to_string())
+  }
+}
+
+impl From<String> for MessageArgument {
+  fn from(value: String) -> Self {
+    Self::Text(value)
+  }
+}
+
+impl From<f64> for MessageArgument {
+  fn from(value: f64) -> Self {
+    Self::Number(value)
+  }
+}
+
+impl From<i32> for MessageArgument {
+  fn from(value: i32) -> Self {
+    Self::Number(value.into())
+  }
+}
+
+impl From<u32> for MessageArgument {
+  fn from(value: u32) -> Self {
+    Self::Number(value.into())
+  }
+}
+
+impl From<usize> for MessageArgument {
+  fn from(value: usize) -> Self {
+    Self::Number(value as f64)
+  }
+}
+
+impl MessageArgument {
+  fn as_number(&self) -> Option<f64> {
+    match self {
+      Self::Text(text) => text.trim().parse().ok(),
+      Self::Number(number) => Some(*number),
+    }
+  }
+}
+
+/// A parsed part of a message in the ICU message format.
+#[derive(Clone, Debug, PartialEq)]
+enum Part {
+  Text(String),
+  /// `{name}`
+  Argument(String),
+  /// The number of the enclosing `plural`.
+  Pound,
+  /// `{name, plural, =0 {...} one {...} other {...}}`
+  Plural {
+    name: String,
+    branches: Vec<(String, Vec<Part>)>,
+  },
+  /// `{name, select, a {...} other {...}}`
+  Select {
+    name: String,
+    branches: Vec<(String, Vec<Part>)>,
+  },
+}
+
+thread_local! {
+  static PLURAL_RULES: RefCell<HashMap<String, Intl::PluralRules>> = RefCell::new(HashMap::new());
+}
+
+/// Format a message such as `"{count, plural, one {# result} other {#
+/// results}}"` for the locale. Messages which can't be parsed are returned
+/// unchanged.
+pub(crate) fn format_message(
+  message: &str,
+  arguments: &[(&str, MessageArgument)],
+  locale: &str,
+) -> String {
+  let number_formatter = NumberFormatter::new(locale, &NumberFormatOptions::default());
+
+  format_message_with(
+    message,
+    arguments,
+    &|value| plural_category(locale, value),
+    &|value| number_formatter.format(value),
+  )
+}
+
+/// The plural category of the number for the locale, e.g. `"one"` or
+/// `"few"`. Uses the English rules when rendering on the server.
+fn plural_category(locale: &str, value: f64) -> String {
+  if is_server() {
+    return fallback_plural_category(value).into();
+  }
+
+  PLURAL_RULES.with(|plural_rules| {
+    plural_rules
+      .borrow_mut()
+      .entry(locale.to_string())
+      .or_insert_with(|| Intl::PluralRules::new(&Array::of1(&locale.into()), &Object::new()))
+      .select(value)
+      .into()
+  })
+}
+
+fn fallback_plural_category(value: f64) -> &'static str {
+  if value == 1.0 {
+    "one"
+  } else {
+    "other"
+  }
+}
+
+fn format_message_with(
+  message: &str,
+  arguments: &[(&str, MessageArgument)],
+  plural_category: &dyn Fn(f64) -> String,
+  format_number: &dyn Fn(f64) -> String,
+) -> String {
+  let characters: Vec<char> = message.chars().collect();
+  let mut position = 0;
+
+  let Some(parts) = parse_parts(&characters, &mut position, false, false) else {
+    return message.to_string();
+  };
+
+  if position < characters.len() {
+    return message.to_string();
+  }
+
+  let context = RenderContext {
+    arguments,
+    plural_category,
+    format_number,
+  };
+  let mut output = String::new();
+  context.render(&parts, None, &mut output);
+  output
+}
+
+/// Parse parts until the end of the message, or the `}` which closes a
+/// branch when `is_nested`.
+fn parse_parts(
+  characters: &[char],
+  position: &mut usize,
+  is_nested: bool,
+  in_plural: bool,
+) -> Option<Vec<Part>> {
+  let mut parts = vec![];
+  let mut text = String::new();
+
+  while let Some(&character) = characters.get(*position) {
+    match character {
+      '{' => {
+        if !text.is_empty() {
+          parts.push(Part::Text(std::mem::take(&mut text)));
+        }
+
+        *position += 1;
+        parts.push(parse_argument(characters, position, in_plural)?);
+        continue;
+      }
+      '}' if is_nested => break,
+      '#' if in_plural => {
+        if !text.is_empty() {
+          parts.push(Part::Text(std::mem::take(&mut text)));
+        }
+
+        parts.push(Part::Pound);
+      }
+      '\'' => {
+        match characters.get(*position + 1) {
+          // A doubled apostrophe is a literal apostrophe.
+          Some('\'') => {
+            text.push('\'');
+            *position += 1;
+          }
+          // Syntax characters are quoted until the next apostrophe.
+          Some('{' | '}' | '#') => {
+            *position += 1;
+
+            while let Some(&quoted) = characters.get(*position) {
+              if quoted == '\'' {
+                break;
+              }
+
+              text.push(quoted);
+              *position += 1;
+            }
+          }
+          _ => text.push('\''),
+        }
+      }
+      _ => text.push(character),
+    }
+
+    *position += 1;
+  }
+
+  if !text.is_empty() {
+    parts.push(Part::Text(text));
+  }
+
+  Some(parts)
+}
+
+/// Parse an argument after its opening `{`, including the closing `}`.
+fn parse_argument(characters: &[char], position: &mut usize, in_plural: bool) -> Option<Part> {
+  let name = read_until(characters, position, &[',', '}'])?;
+
+  if characters[*position] == '}' {
+    *position += 1;
+    return Some(Part::Argument(name));
+  }
+
+  *position += 1;
+  let kind = read_until(characters, position, &[',', '}'])?;
+
+  if !matches!(kind.as_str(), "plural" | "select") {
+    // Other types such as `number` are formatted like plain arguments.
+    let mut depth = 0;
+
+    while let Some(&character) = characters.get(*position) {
+      *position += 1;
+
+      match character {
+        '{' => depth += 1,
+        '}' if depth == 0 => return Some(Part::Argument(name)),
+        '}' => depth -= 1,
+        _ => {}
+      }
+    }
+
+    return None;
+  }
+
+  if characters[*position] != ',' {
+    return None;
+  }
+
+  *position += 1;
+  let is_plural = kind == "plural";
+  let mut branches = vec![];
+
+  loop {
+    skip_whitespace(characters, position);
+
+    match characters.get(*position)? {
+      '}' => {
+        *position += 1;
+        break;
+      }
+      _ => {
+        let selector = read_until(characters, position, &['{'])?;
+        *position += 1;
+        let parts = parse_parts(characters, position, true, in_plural || is_plural)?;
+
+        if characters.get(*position) != Some(&'}') {
+          return None;
+        }
+
+        *position += 1;
+        branches.push((selector, parts));
+      }
+    }
+  }
+
+  Some(if is_plural {
+    Part::Plural { name, branches }
+  } else {
+    Part::Select { name, branches }
+  })
+}
+
+/// Read and trim the text up to, but not including, one of the delimiters.
+fn read_until(characters: &[char], position: &mut usize, delimiters: &[char]) -> Option<String> {
+  let start = *position;
+
+  while !delimiters.contains(characters.get(*position)?) {
+    *position += 1;
+  }
+
+  Some(
+    characters[start..*position]
+      .iter()
+      .collect::<String>()
+      .trim()
+      .to_string(),
+  )
+}
+
+fn skip_whitespace(characters: &[char], position: &mut usize) {
+  while characters
+    .get(*position)
+    .map_or(false, |character| character.is_whitespace())
+  {
+    *position += 1;
+  }
+}
+
+struct RenderContext<'a> {
+  arguments: &'a [(&'a str, MessageArgument)],
+  plural_category: &'a dyn Fn(f64) -> String,
+  format_number: &'a dyn Fn(f64) -> String,
+}
+
+impl RenderContext<'_> {
+  fn argument(&self, name: &str) -> Option<&MessageArgument> {
+    self
+      .arguments
+      .iter()
+      .find(|(argument, _)| *argument == name)
+      .map(|(_, value)| value)
+  }
+
+  fn render(&self, parts: &[Part], pound: Option<f64>, output: &mut String) {
+    for part in parts {
+      match part {
+        Part::Text(text) => output.push_str(text),
+        Part::Argument(name) => {
+          match self.argument(name) {
+            Some(MessageArgument::Text(text)) => output.push_str(text),
+            Some(MessageArgument::Number(number)) => {
+              output.push_str(&(self.format_number)(*number))
+            }
+            None => output.push_str(&format!("{{{name}}}")),
+          }
+        }
+        Part::Pound => {
+          match pound {
+            Some(value) => output.push_str(&(self.format_number)(value)),
+            None => output.push('#'),
+          }
+        }
+        Part::Plural { name, branches } => {
+          let value = self.argument(name).and_then(MessageArgument::as_number);
+          let category = value.map(|value| (self.plural_category)(value));
+          let branch = branches
+            .iter()
+            .find(|(selector, _)| {
+              selector
+                .strip_prefix('=')
+                .and_then(|exact| exact.parse::<f64>().ok())
+                .zip(value)
+                .map_or(false, |(exact, value)| exact == value)
+            })
+            .or_else(|| {
+              branches
+                .iter()
+                .find(|(selector, _)| Some(selector) == category.as_ref())
+            })
+            .or_else(|| branches.iter().find(|(selector, _)| selector == "other"));
+
+          if let Some((_, parts)) = branch {
+            self.render(parts, value, output);
+          }
+        }
+        Part::Select { name, branches } => {
+          let value = match self.argument(name) {
+            Some(MessageArgument::Text(text)) => text.clone(),
+            Some(MessageArgument::Number(number)) => number.to_string(),
+            None => String::new(),
+          };
+          let branch = branches
+            .iter()
+            .find(|(selector, _)| *selector == value)
+            .or_else(|| branches.iter().find(|(selector, _)| selector == "other"));
+
+          if let Some((_, parts)) = branch {
+            self.render(parts, pound, output);
+          }
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn format(message: &str, arguments: &[(&str, MessageArgument)]) -> String {
+    format_message_with(
+      message,
+      arguments,
+      &|value| fallback_plural_category(value).into(),
+      &|value| value.to_string(),
+    )
+  }
+
+  #[test]
+  fn interpolates_variables() {
+    assert_eq!(
+      format("Hello {name}!", &[("name", "Ada".into())]),
+      "Hello Ada!"
+    );
+    assert_eq!(
+      format("{count, number} items", &[("count", 4.into())]),
+      "4 items"
+    );
+    assert_eq!(format("Hello {name}!", &[]), "Hello {name}!");
+    assert_eq!(format("It''s '{literal}'", &[]), "It's {literal}");
+  }
+
+  #[test]
+  fn chooses_plural_branches() {
+    let message = "{count, plural, =0 {No results} one {# result} other {# results}} available";
+
+    assert_eq!(
+      format(message, &[("count", 0.into())]),
+      "No results available"
+    );
+    assert_eq!(
+      format(message, &[("count", 1.into())]),
+      "1 result available"
+    );
+    assert_eq!(
+      format(message, &[("count", 3.into())]),
+      "3 results available"
+    );
+  }
+
+  #[test]
+  fn chooses_select_branches() {
+    let message = "{role, select, admin {{name} can edit} other {{name} can view}}";
+
+    assert_eq!(
+      format(message, &[("role", "admin".into()), ("name", "Ada".into())]),
+      "Ada can edit"
+    );
+    assert_eq!(
+      format(message, &[("role", "guest".into()), ("name", "Bo".into())]),
+      "Bo can view"
+    );
+  }
+
+  #[test]
+  fn returns_malformed_messages_unchanged() {
+    assert_eq!(
+      format("{count, plural, one {#", &[]),
+      "{count, plural, one {#"
+    );
+    assert_eq!(format("{name", &[]), "{name");
+  }
+}
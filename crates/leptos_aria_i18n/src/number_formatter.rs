@@ -0,0 +1,269 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use leptos::js_sys::Array;
+use leptos::js_sys::Intl;
+use leptos::js_sys::Object;
+use leptos::js_sys::Reflect;
+use leptos::typed_builder::TypedBuilder;
+use leptos::wasm_bindgen::JsValue;
+use leptos::IntoSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::use_locale;
+
+/// How a number is formatted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumberFormatStyle {
+  Decimal,
+  /// The number is a fraction, so `0.5` is formatted as `50%`.
+  Percent,
+  /// Requires [`currency`](NumberFormatOptions::currency).
+  Currency,
+  /// Requires [`unit`](NumberFormatOptions::unit).
+  Unit,
+}
+
+impl NumberFormatStyle {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Decimal => "decimal",
+      Self::Percent => "percent",
+      Self::Currency => "currency",
+      Self::Unit => "unit",
+    }
+  }
+}
+
+/// When the sign of the number is displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SignDisplay {
+  /// Only for negative numbers.
+  Auto,
+  Always,
+  /// For positive and negative numbers but not zero.
+  ExceptZero,
+  Never,
+}
+
+impl SignDisplay {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Auto => "auto",
+      Self::Always => "always",
+      Self::ExceptZero => "exceptZero",
+      Self::Never => "never",
+    }
+  }
+}
+
+/// The options of an `Intl.NumberFormat`. Unset options use the defaults of
+/// the locale.
+#[derive(TypedBuilder, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NumberFormatOptions {
+  #[builder(default, setter(strip_option))]
+  pub style: Option<NumberFormatStyle>,
+
+  /// An ISO 4217 currency code, e.g. `"EUR"`.
+  #[builder(default, setter(strip_option, into))]
+  pub currency: Option<String>,
+
+  /// A simple unit, e.g. `"kilometer-per-hour"`.
+  #[builder(default, setter(strip_option, into))]
+  pub unit: Option<String>,
+
+  #[builder(default, setter(strip_option))]
+  pub minimum_fraction_digits: Option<u8>,
+
+  #[builder(default, setter(strip_option))]
+  pub maximum_fraction_digits: Option<u8>,
+
+  /// Whether thousands are separated, e.g. `1,000`.
+  #[builder(default, setter(strip_option))]
+  pub use_grouping: Option<bool>,
+
+  #[builder(default, setter(strip_option))]
+  pub sign_display: Option<SignDisplay>,
+}
+
+impl NumberFormatOptions {
+  pub(crate) fn to_object(&self) -> Object {
+    let options = Object::new();
+    let set = |key: &str, value: JsValue| {
+      let _ = Reflect::set(&options, &key.into(), &value);
+    };
+
+    if let Some(style) = self.style {
+      set("style", style.as_str().into());
+    }
+
+    if let Some(ref currency) = self.currency {
+      set("currency", currency.into());
+    }
+
+    if let Some(ref unit) = self.unit {
+      set("unit", unit.into());
+    }
+
+    if let Some(minimum_fraction_digits) = self.minimum_fraction_digits {
+      set("minimumFractionDigits", minimum_fraction_digits.into());
+    }
+
+    if let Some(maximum_fraction_digits) = self.maximum_fraction_digits {
+      set("maximumFractionDigits", maximum_fraction_digits.into());
+    }
+
+    if let Some(use_grouping) = self.use_grouping {
+      set("useGrouping", use_grouping.into());
+    }
+
+    if let Some(sign_display) = self.sign_display {
+      set("signDisplay", sign_display.as_str().into());
+    }
+
+    options
+  }
+
+  pub(crate) fn is_percent(&self) -> bool {
+    self.style == Some(NumberFormatStyle::Percent)
+  }
+}
+
+thread_local! {
+  /// Creating an `Intl.NumberFormat` is expensive, so one instance is shared
+  /// for each combination of locale and options.
+  static NUMBER_FORMATS: RefCell<HashMap<(String, NumberFormatOptions), Intl::NumberFormat>> =
+    RefCell::new(HashMap::new());
+}
+
+/// The cached `Intl.NumberFormat` for the locale and options.
+pub(crate) fn get_number_format(locale: &str, options: &NumberFormatOptions) -> Intl::NumberFormat {
+  NUMBER_FORMATS.with(|number_formats| {
+    number_formats
+      .borrow_mut()
+      .entry((locale.to_string(), options.clone()))
+      .or_insert_with(|| Intl::NumberFormat::new(&Array::of1(&locale.into()), &options.to_object()))
+      .clone()
+  })
+}
+
+/// Locale aware number formatting with `Intl.NumberFormat`.
+///
+/// When rendering on the server numbers are formatted with latin digits and
+/// without grouping, which is corrected once the page hydrates.
+#[derive(Clone)]
+pub struct NumberFormatter {
+  locale: String,
+  options: NumberFormatOptions,
+  number_format: Option<Intl::NumberFormat>,
+}
+
+impl NumberFormatter {
+  pub fn new(locale: &str, options: &NumberFormatOptions) -> Self {
+    let number_format = (!is_server()).then(|| get_number_format(locale, options));
+
+    Self {
+      locale: locale.to_string(),
+      options: options.clone(),
+      number_format,
+    }
+  }
+
+  pub fn locale(&self) -> &str {
+    &self.locale
+  }
+
+  pub fn options(&self) -> &NumberFormatOptions {
+    &self.options
+  }
+
+  pub fn format(&self, value: f64) -> String {
+    let Some(ref number_format) = self.number_format else {
+      return fallback_format(value, &self.options);
+    };
+
+    number_format
+      .format()
+      .call1(&JsValue::UNDEFINED, &value.into())
+      .ok()
+      .and_then(|result| result.as_string())
+      .unwrap_or_else(|| fallback_format(value, &self.options))
+  }
+}
+
+/// A number formatter for the locale from [`use_locale`], which is recreated
+/// when the locale changes.
+pub fn use_number_formatter(cx: Scope, options: NumberFormatOptions) -> Signal<NumberFormatter> {
+  let locale = use_locale(cx);
+  (move || NumberFormatter::new(&locale.get(), &options)).derive_signal(cx)
+}
+
+/// Formats the number like `Intl.NumberFormat` for `en-US` without grouping.
+fn fallback_format(value: f64, options: &NumberFormatOptions) -> String {
+  let is_percent = options.is_percent();
+  let value = if is_percent { value * 100.0 } else { value };
+  let default_maximum = if is_percent { 0 } else { 3 };
+  let maximum = options.maximum_fraction_digits.unwrap_or(default_maximum);
+  let minimum = options.minimum_fraction_digits.unwrap_or(0).min(maximum);
+  let factor = 10_f64.powi(i32::from(maximum));
+  let rounded = (value * factor).round() / factor;
+
+  let mut text = format!("{:.*}", usize::from(maximum), rounded.abs());
+
+  if text.contains('.') {
+    let integer_length = text.find('.').unwrap_or(text.len());
+    let minimum_length = integer_length + 1 + usize::from(minimum);
+    let trimmed_length = text.trim_end_matches('0').len().max(minimum_length);
+    text.truncate(trimmed_length);
+
+    if text.ends_with('.') {
+      text.pop();
+    }
+  }
+
+  let sign = match options.sign_display.unwrap_or(SignDisplay::Auto) {
+    SignDisplay::Never => "",
+    _ if rounded < 0.0 => "-",
+    SignDisplay::Always => "+",
+    SignDisplay::ExceptZero if rounded > 0.0 => "+",
+    _ => "",
+  };
+
+  match options.style {
+    Some(NumberFormatStyle::Percent) => format!("{sign}{text}%"),
+    Some(NumberFormatStyle::Currency) => {
+      let currency = options.currency.as_deref().unwrap_or_default();
+      format!("{sign}{currency} {text}")
+    }
+    Some(NumberFormatStyle::Unit) => {
+      let unit = options.unit.as_deref().unwrap_or_default();
+      format!("{sign}{text} {unit}")
+    }
+    _ => format!("{sign}{text}"),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fallback_format_rounds_to_fraction_digits() {
+    let decimal = NumberFormatOptions::default();
+    let percent = NumberFormatOptions::builder()
+      .style(NumberFormatStyle::Percent)
+      .build();
+    let fixed = NumberFormatOptions::builder()
+      .minimum_fraction_digits(2)
+      .maximum_fraction_digits(2)
+      .sign_display(SignDisplay::ExceptZero)
+      .build();
+
+    assert_eq!(fallback_format(1234.56789, &decimal), "1234.568");
+    assert_eq!(fallback_format(-2.5, &decimal), "-2.5");
+    assert_eq!(fallback_format(0.256, &percent), "26%");
+    assert_eq!(fallback_format(3.0, &fixed), "+3.00");
+    assert_eq!(fallback_format(0.0, &fixed), "0.00");
+  }
+}
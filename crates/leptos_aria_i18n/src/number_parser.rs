@@ -0,0 +1,374 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use leptos::js_sys::Array;
+use leptos::js_sys::Reflect;
+use leptos::IntoSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::use_locale;
+
+use crate::number_formatter::get_number_format;
+use crate::NumberFormatOptions;
+
+/// The zero of each decimal numbering system. The other digits follow it.
+const ZERO_DIGITS: [char; 20] = [
+  '0', '\u{660}', '\u{6F0}', '\u{7C0}', '\u{966}', '\u{9E6}', '\u{A66}', '\u{AE6}', '\u{B66}',
+  '\u{BE6}', '\u{C66}', '\u{CE6}', '\u{D66}', '\u{E50}', '\u{ED0}', '\u{F20}', '\u{1040}',
+  '\u{17E0}', '\u{1810}', '\u{FF10}',
+];
+
+/// The digits of the `hanidec` numbering system, which are not contiguous.
+const HAN_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// Invisible characters which `Intl.NumberFormat` adds around signs and
+/// symbols in right to left locales.
+const BIDI_MARKS: [char; 12] = [
+  '\u{200E}', '\u{200F}', '\u{061C}', '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}',
+  '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+/// Languages which use a comma as the decimal separator and a period to group
+/// digits, e.g. `1.234,5`.
+const PERIOD_GROUP_LANGUAGES: [&str; 14] = [
+  "da", "de", "el", "es", "hr", "id", "it", "nl", "pt", "ro", "sl", "sr", "tr", "vi",
+];
+
+/// Languages which use a comma as the decimal separator and a space to group
+/// digits, e.g. `1 234,5`.
+const SPACE_GROUP_LANGUAGES: [&str; 15] = [
+  "bg", "cs", "et", "fi", "fr", "hu", "lt", "lv", "nb", "no", "pl", "ru", "sk", "sv", "uk",
+];
+
+/// The symbols of a locale which are needed to read its formatted numbers.
+#[derive(Clone, Debug, PartialEq)]
+struct NumberSymbols {
+  decimal: char,
+  group: char,
+  minus_sign: char,
+  /// The text around the number, e.g. a currency symbol or the percent sign.
+  literals: Vec<String>,
+  is_percent: bool,
+}
+
+impl NumberSymbols {
+  /// The symbols of the locale without `Intl`, which are used when rendering
+  /// on the server. The separators come from [`fallback_separators`] and the
+  /// other symbols are those of `en-US`.
+  fn fallback(locale: &str, options: &NumberFormatOptions) -> Self {
+    let literals = [
+      options.is_percent().then(|| "%".to_string()),
+      options.currency.clone(),
+      options.unit.clone(),
+    ];
+    let (decimal, group) = fallback_separators(locale);
+
+    Self {
+      decimal,
+      group,
+      minus_sign: '-',
+      literals: literals.into_iter().flatten().collect(),
+      is_percent: options.is_percent(),
+    }
+  }
+
+  /// Read the symbols from the parts of numbers formatted for the locale.
+  fn from_locale(locale: &str, options: &NumberFormatOptions) -> Self {
+    let mut symbols = Self::fallback(locale, options);
+    let separators = NumberFormatOptions::builder()
+      .minimum_fraction_digits(1)
+      .use_grouping(true)
+      .build();
+
+    for (kind, value) in format_to_parts(locale, &separators, -11111.1) {
+      let Some(character) = value
+        .chars()
+        .find(|character| !BIDI_MARKS.contains(character))
+      else {
+        continue;
+      };
+
+      match kind.as_str() {
+        "decimal" => symbols.decimal = character,
+        "group" => symbols.group = character,
+        "minusSign" => symbols.minus_sign = character,
+        _ => {}
+      }
+    }
+
+    symbols.literals = format_to_parts(locale, options, 1.0)
+      .into_iter()
+      .filter(|(kind, _)| {
+        matches!(
+          kind.as_str(),
+          "currency" | "unit" | "percentSign" | "literal"
+        )
+      })
+      .map(|(_, value)| strip_bidi_marks(&value).trim().to_string())
+      .filter(|value| !value.is_empty())
+      .chain(symbols.literals)
+      .collect();
+
+    symbols
+  }
+}
+
+thread_local! {
+  static NUMBER_SYMBOLS: RefCell<HashMap<(String, NumberFormatOptions), NumberSymbols>> =
+    RefCell::new(HashMap::new());
+}
+
+/// Parses numbers typed by the user in the format of a locale, so that text
+/// produced by [`NumberFormatter`](crate::NumberFormatter) round trips back to
+/// the same value.
+///
+/// Digits of every decimal numbering system are accepted regardless of the
+/// locale, e.g. `"١٢٫٥"` with Arabic-Indic digits is `12.5`. Group separators,
+/// currency symbols and units are ignored and percentages are converted back
+/// to fractions.
+#[derive(Clone)]
+pub struct NumberParser {
+  locale: String,
+  symbols: NumberSymbols,
+}
+
+impl NumberParser {
+  pub fn new(locale: &str, options: &NumberFormatOptions) -> Self {
+    let symbols = if is_server() {
+      NumberSymbols::fallback(locale, options)
+    } else {
+      NUMBER_SYMBOLS.with(|number_symbols| {
+        number_symbols
+          .borrow_mut()
+          .entry((locale.to_string(), options.clone()))
+          .or_insert_with(|| NumberSymbols::from_locale(locale, options))
+          .clone()
+      })
+    };
+
+    Self {
+      locale: locale.to_string(),
+      symbols,
+    }
+  }
+
+  pub fn locale(&self) -> &str {
+    &self.locale
+  }
+
+  /// The value of the text, or `None` when it isn't a complete number.
+  pub fn parse(&self, text: &str) -> Option<f64> {
+    parse_number(text, &self.symbols)
+  }
+
+  /// Whether the text could become a number as the user continues typing,
+  /// e.g. `"-"` or `"12,"`. Useful to reject invalid keystrokes.
+  pub fn is_valid_partial(&self, text: &str) -> bool {
+    normalize_number(text, &self.symbols).map_or(false, |normalized| {
+      let unsigned = normalized.trim_start_matches('-');
+      unsigned.len() + 1 >= normalized.len() && !unsigned.contains('-')
+    })
+  }
+}
+
+/// A number parser for the locale from [`use_locale`], which is recreated
+/// when the locale changes.
+pub fn use_number_parser(cx: Scope, options: NumberFormatOptions) -> Signal<NumberParser> {
+  let locale = use_locale(cx);
+  (move || NumberParser::new(&locale.get(), &options)).derive_signal(cx)
+}
+
+/// The decimal and group separators of a locale such as `"de-CH"` for when
+/// `Intl` isn't available. Locales which aren't known use those of `en-US`.
+fn fallback_separators(locale: &str) -> (char, char) {
+  let mut subtags = locale.split(['-', '_']);
+  let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+  let region = subtags
+    .find(|subtag| subtag.len() != 4)
+    .unwrap_or_default()
+    .to_ascii_uppercase();
+
+  match (language.as_str(), region.as_str()) {
+    ("de" | "it", "CH") => ('.', '\u{2019}'),
+    ("de", "AT") | ("pt", "PT") => (',', '\u{A0}'),
+    ("es", "MX" | "US" | "419") => ('.', ','),
+    ("fr", _) => (',', '\u{202F}'),
+    (language, _) if PERIOD_GROUP_LANGUAGES.contains(&language) => (',', '.'),
+    (language, _) if SPACE_GROUP_LANGUAGES.contains(&language) => (',', '\u{A0}'),
+    _ => ('.', ','),
+  }
+}
+
+/// The type and value of each part of the formatted number.
+fn format_to_parts(
+  locale: &str,
+  options: &NumberFormatOptions,
+  value: f64,
+) -> Vec<(String, String)> {
+  let parts: Array = get_number_format(locale, options).format_to_parts(value);
+
+  parts
+    .iter()
+    .filter_map(|part| {
+      let kind = Reflect::get(&part, &"type".into()).ok()?.as_string()?;
+      let value = Reflect::get(&part, &"value".into()).ok()?.as_string()?;
+      Some((kind, value))
+    })
+    .collect()
+}
+
+fn strip_bidi_marks(text: &str) -> String {
+  text
+    .chars()
+    .filter(|character| !BIDI_MARKS.contains(character))
+    .collect()
+}
+
+/// The value of a digit in any decimal numbering system.
+fn digit_value(character: char) -> Option<u32> {
+  if let Some(position) = HAN_DIGITS.iter().position(|digit| *digit == character) {
+    return u32::try_from(position).ok();
+  }
+
+  ZERO_DIGITS.iter().find_map(|zero| {
+    let offset = u32::from(character).checked_sub(u32::from(*zero))?;
+    (offset < 10).then_some(offset)
+  })
+}
+
+/// Rewrite the text with latin digits, `.` as the decimal separator and `-` as
+/// the minus sign, dropping group separators and literals. `None` when the
+/// text contains anything else.
+fn normalize_number(text: &str, symbols: &NumberSymbols) -> Option<String> {
+  let mut text = strip_bidi_marks(text);
+
+  for literal in &symbols.literals {
+    text = text.replace(literal.as_str(), "");
+  }
+
+  if symbols.is_percent {
+    text = text.replace(['%', '\u{066A}'], "");
+  }
+
+  let mut normalized = String::new();
+
+  for character in text.chars() {
+    if let Some(digit) = digit_value(character) {
+      normalized.push(char::from_digit(digit, 10)?);
+    } else if character == symbols.decimal {
+      if normalized.contains('.') {
+        return None;
+      }
+
+      normalized.push('.');
+    } else if character == symbols.minus_sign || character == '-' || character == '\u{2212}' {
+      normalized.push('-');
+    } else if character == symbols.group
+      || character == '+'
+      || character.is_whitespace()
+      || (symbols.group == '\u{2019}' && character == '\'')
+    {
+      continue;
+    } else {
+      return None;
+    }
+  }
+
+  Some(normalized)
+}
+
+fn parse_number(text: &str, symbols: &NumberSymbols) -> Option<f64> {
+  let normalized = normalize_number(text, symbols)?;
+  let value = normalized.parse::<f64>().ok()?;
+
+  Some(if symbols.is_percent {
+    value / 100.0
+  } else {
+    value
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn symbols(decimal: char, group: char, minus_sign: char) -> NumberSymbols {
+    NumberSymbols {
+      decimal,
+      group,
+      minus_sign,
+      literals: vec![],
+      is_percent: false,
+    }
+  }
+
+  #[test]
+  fn parses_separators_of_the_locale() {
+    let en = symbols('.', ',', '-');
+    let de = symbols(',', '.', '-');
+    let fr = symbols(',', '\u{202F}', '-');
+
+    assert_eq!(parse_number("-1,234.5", &en), Some(-1234.5));
+    assert_eq!(parse_number("1.234,5", &de), Some(1234.5));
+    assert_eq!(parse_number("1 234,5", &fr), Some(1234.5));
+    assert_eq!(parse_number("1.2.3", &en), None);
+    assert_eq!(parse_number("12abc", &en), None);
+    assert_eq!(parse_number("", &en), None);
+  }
+
+  #[test]
+  fn parses_digits_of_any_numbering_system() {
+    let ar = symbols('\u{066B}', '\u{066C}', '-');
+
+    assert_eq!(
+      parse_number("\u{061C}-١٢\u{066C}٣٤٥\u{066B}٦", &ar),
+      Some(-12345.6)
+    );
+    assert_eq!(parse_number("۱۲۳", &ar), Some(123.0));
+    assert_eq!(parse_number("१२", &ar), Some(12.0));
+    assert_eq!(parse_number("一〇", &ar), Some(10.0));
+  }
+
+  #[test]
+  fn removes_literals_and_converts_percentages() {
+    let percent = NumberSymbols {
+      literals: vec!["%".into()],
+      is_percent: true,
+      ..symbols('.', ',', '-')
+    };
+    let currency = NumberSymbols {
+      literals: vec!["€".into()],
+      ..symbols(',', '.', '-')
+    };
+
+    assert_eq!(parse_number("45%", &percent), Some(0.45));
+    assert_eq!(parse_number("1.000,50 €", &currency), Some(1000.5));
+  }
+
+  #[test]
+  fn fallback_separators_of_the_locale() {
+    assert_eq!(fallback_separators("en-US"), ('.', ','));
+    assert_eq!(fallback_separators("de-DE"), (',', '.'));
+    assert_eq!(fallback_separators("de-CH"), ('.', '\u{2019}'));
+    assert_eq!(fallback_separators("fr_FR"), (',', '\u{202F}'));
+    assert_eq!(fallback_separators("ru"), (',', '\u{A0}'));
+    assert_eq!(fallback_separators("es-MX"), ('.', ','));
+    assert_eq!(fallback_separators("sr-Latn-RS"), (',', '.'));
+    assert_eq!(fallback_separators(""), ('.', ','));
+  }
+
+  #[test]
+  fn uses_the_fallback_symbols_without_intl() {
+    let options = NumberFormatOptions::default();
+    let en = NumberParser::new("en-US", &options);
+    let de = NumberParser::new("de-DE", &options);
+    let fr = NumberParser::new("fr-FR", &options);
+
+    assert_eq!(en.parse("-1,234.5"), Some(-1234.5));
+    assert_eq!(de.parse("1,5"), Some(1.5));
+    assert_eq!(de.parse("-1.234,5"), Some(-1234.5));
+    assert!(de.is_valid_partial("12,"));
+    assert_eq!(fr.parse("1\u{202F}234,5"), Some(1234.5));
+  }
+}
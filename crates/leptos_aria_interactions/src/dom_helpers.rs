@@ -0,0 +1,241 @@
+//! Low level predicates used by the interaction hooks, useful when writing
+//! custom widgets.
+
+use leptos::web_sys::Element;
+use leptos::web_sys::HtmlAnchorElement;
+use leptos::web_sys::HtmlButtonElement;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::HtmlInputElement;
+use leptos::web_sys::HtmlTextAreaElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::JsCast;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::Rect;
+
+/// The input types which don't accept text, so <kbd>Enter</kbd> and
+/// <kbd>Space</kbd> can activate them.
+const NON_TEXT_INPUT_TYPES: &[&str; 9] = &[
+  "checkbox", "radio", "range", "color", "file", "image", "button", "submit", "reset",
+];
+
+/// Whether `rect` overlaps with any of the `rects`, e.g. an element with the
+/// area touched by a finger. Touching edges count as overlapping.
+pub fn are_rectangles_overlapping(rect: &Rect, rects: &[Rect]) -> bool {
+  rects.iter().any(|other| {
+    // They can't overlap on the x axis or the y axis.
+    let is_apart_x = rect.left > other.right || rect.right < other.left;
+    let is_apart_y = rect.top > other.bottom || rect.bottom < other.top;
+
+    !is_apart_x && !is_apart_y
+  })
+}
+
+/// Whether the keyboard event should activate the element it was dispatched
+/// to, as a press with <kbd>Enter</kbd> or <kbd>Space</kbd>.
+///
+/// Text inputs, text areas and content editable elements keep the keys for
+/// typing. Links are only activated with <kbd>Enter</kbd>, and native links
+/// are left to the browser unless they have `role="button"`.
+pub fn is_valid_keyboard_event(
+  event: impl AsRef<KeyboardEvent>,
+  current_target: impl AsRef<Element>,
+) -> bool {
+  let event = event.as_ref();
+  let current_target = current_target.as_ref();
+  let key = KeyboardKey::from(event);
+  let element = current_target.unchecked_ref::<HtmlElement>();
+
+  let role = element.get_attribute("role");
+
+  // Accessibility for keyboards. Space and Enter only.
+  matches!(key, KeyboardKey::Enter | KeyboardKey::Space)
+    && !((element.is_instance_of::<HtmlInputElement>()
+      && !{
+        is_valid_input_key(element.unchecked_ref(), &key)
+      })
+      || element.is_instance_of::<HtmlTextAreaElement>()
+      || element.is_content_editable())
+  // A link with a valid href should be handled natively,
+  // unless is also has `role="button"` and was triggered using `Space`.
+  && (!is_html_anchor_link(element) || (role.as_ref().map_or(false, |role| role == "button" )&& key != KeyboardKey::Enter))
+  && !(role.as_ref().map_or(false, |role| role == "link") && key != KeyboardKey::Enter)
+}
+
+/// Whether the element accepts typed text: a text input, a text area or a
+/// content editable element.
+pub fn is_editable_element(target: impl AsRef<Element>) -> bool {
+  let element = target.as_ref();
+
+  if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+    return !NON_TEXT_INPUT_TYPES.contains(&input.type_().as_str());
+  }
+
+  element.is_instance_of::<HtmlTextAreaElement>()
+    || element
+      .dyn_ref::<HtmlElement>()
+      .map_or(false, HtmlElement::is_content_editable)
+}
+
+/// Whether the element is an `<a>` element which links somewhere.
+pub fn is_html_anchor_link(target: impl AsRef<Element>) -> bool {
+  let element = target.as_ref();
+  element.is_instance_of::<HtmlAnchorElement>()
+    || (element.tag_name() == "A" && element.has_attribute("href"))
+}
+
+/// Whether the element is a native link or has `role="link"`.
+pub fn has_link_role(target: impl AsRef<Element>) -> bool {
+  let element = target.as_ref();
+  is_html_anchor_link(element)
+    || element
+      .get_attribute("role")
+      .as_ref()
+      .map_or(false, |role| role == "link")
+}
+
+/// Whether the default behavior of the key should be prevented when it
+/// presses the element. Text inputs keep their keys and only submit buttons
+/// are prevented from submitting their form twice.
+pub fn should_prevent_default_keyboard(
+  target: impl AsRef<Element>,
+  key: impl Into<KeyboardKey>,
+) -> bool {
+  let element = target.as_ref();
+
+  if element.is_instance_of::<HtmlInputElement>() {
+    !is_valid_input_key(element.unchecked_ref(), &key.into())
+  } else if element.is_instance_of::<HtmlButtonElement>() {
+    element.unchecked_ref::<HtmlButtonElement>().type_() == "submit"
+  } else {
+    true
+  }
+}
+
+fn is_valid_input_key(target: &HtmlInputElement, key: &KeyboardKey) -> bool {
+  // Only space should toggle checkboxes and radios, not enter.
+  if target.type_() == "checkbox" || target.type_() == "radio" {
+    *key == KeyboardKey::Space
+  } else {
+    NON_TEXT_INPUT_TYPES.contains(&target.type_().as_str())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::document;
+  use leptos::web_sys::KeyboardEventInit;
+  use wasm_bindgen_test::*;
+
+  use super::*;
+
+  fn rect(top: f64, left: f64, size: f64) -> Rect {
+    Rect {
+      top,
+      right: left + size,
+      bottom: top + size,
+      left,
+    }
+  }
+
+  fn create_element(html: &str) -> Element {
+    let container = document().create_element("div").unwrap();
+    container.set_inner_html(html);
+    container.first_element_child().unwrap()
+  }
+
+  fn key_down(key: &str) -> KeyboardEvent {
+    let mut init = KeyboardEventInit::new();
+    init.key(key);
+    KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap()
+  }
+
+  #[test]
+  fn rectangles_overlap() {
+    let target = rect(0.0, 0.0, 10.0);
+
+    assert!(are_rectangles_overlapping(&target, &[rect(5.0, 5.0, 10.0)]));
+    assert!(are_rectangles_overlapping(
+      &target,
+      &[rect(10.0, 10.0, 1.0)]
+    ));
+    assert!(are_rectangles_overlapping(
+      &target,
+      &[rect(20.0, 20.0, 1.0), rect(2.0, 2.0, 1.0)]
+    ));
+    assert!(!are_rectangles_overlapping(
+      &target,
+      &[rect(11.0, 0.0, 5.0)]
+    ));
+    assert!(!are_rectangles_overlapping(&target, &[]));
+  }
+
+  #[wasm_bindgen_test]
+  fn links() {
+    assert!(is_html_anchor_link(create_element(
+      "<a href=\"/\">Home</a>"
+    )));
+    assert!(!is_html_anchor_link(create_element("<span>Home</span>")));
+    assert!(has_link_role(create_element(
+      "<span role=\"link\">Home</span>"
+    )));
+    assert!(!has_link_role(create_element("<button>Home</button>")));
+  }
+
+  #[wasm_bindgen_test]
+  fn keyboard_events() {
+    let button = create_element("<button>Save</button>");
+    assert!(is_valid_keyboard_event(key_down("Enter"), &button));
+    assert!(is_valid_keyboard_event(key_down(" "), &button));
+    assert!(!is_valid_keyboard_event(key_down("a"), &button));
+
+    let text_input = create_element("<input type=\"text\">");
+    assert!(!is_valid_keyboard_event(key_down(" "), &text_input));
+
+    let link = create_element("<span role=\"link\">Home</span>");
+    assert!(is_valid_keyboard_event(key_down("Enter"), &link));
+    assert!(!is_valid_keyboard_event(key_down(" "), &link));
+  }
+
+  #[wasm_bindgen_test]
+  fn prevents_default_keyboard() {
+    assert!(should_prevent_default_keyboard(
+      create_element("<div role=\"button\"></div>"),
+      " "
+    ));
+    assert!(should_prevent_default_keyboard(
+      create_element("<button type=\"submit\"></button>"),
+      "Enter"
+    ));
+    assert!(!should_prevent_default_keyboard(
+      create_element("<button type=\"button\"></button>"),
+      "Enter"
+    ));
+    assert!(!should_prevent_default_keyboard(
+      create_element("<input type=\"text\">"),
+      " "
+    ));
+    assert!(should_prevent_default_keyboard(
+      create_element("<input type=\"checkbox\">"),
+      "Enter"
+    ));
+  }
+
+  #[wasm_bindgen_test]
+  fn editable_elements() {
+    assert!(is_editable_element(create_element("<input type=\"text\">")));
+    assert!(is_editable_element(create_element("<input>")));
+    assert!(is_editable_element(create_element("<textarea></textarea>")));
+
+    // Only connected elements are content editable.
+    let editable = create_element("<div contenteditable=\"true\"></div>");
+    document().body().unwrap().append_child(&editable).unwrap();
+    assert!(is_editable_element(&editable));
+    editable.remove();
+
+    assert!(!is_editable_element(create_element(
+      "<input type=\"checkbox\">"
+    )));
+    assert!(!is_editable_element(create_element("<button></button>")));
+  }
+}
@@ -0,0 +1,190 @@
+use leptos::create_rw_signal;
+use leptos::js_sys::Array;
+use leptos::js_sys::Reflect;
+use leptos::web_sys::window;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::ContextProvider;
+
+/// The vibration played when an interaction gives haptic feedback.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HapticFeedback {
+  /// A short tick, suitable for most presses.
+  Light,
+  Medium,
+  Heavy,
+  /// Alternating vibration and pause durations in milliseconds.
+  Pattern(Vec<u32>),
+}
+
+impl HapticFeedback {
+  /// The vibration pattern in milliseconds.
+  pub fn pattern(&self) -> Vec<u32> {
+    match self {
+      Self::Light => vec![10],
+      Self::Medium => vec![20],
+      Self::Heavy => vec![40],
+      Self::Pattern(pattern) => pattern.clone(),
+    }
+  }
+}
+
+/// Whether haptic feedback is disabled for every hook within the scope, e.g.
+/// from an app setting.
+#[derive(Copy, Clone)]
+pub struct HapticsContext(RwSignal<bool>);
+
+impl ContextProvider for HapticsContext {
+  type Value = bool;
+
+  fn from_leptos_scope(cx: Scope) -> Self {
+    Self(create_rw_signal(cx, false))
+  }
+
+  fn get(&self) -> Self::Value {
+    self.0.get_untracked()
+  }
+
+  fn set(&self, value: Self::Value) {
+    self.0.set(value);
+  }
+}
+
+/// Disable or re-enable haptic feedback for all interactions in the scope.
+pub fn set_haptics_disabled(cx: Scope, is_disabled: bool) {
+  HapticsContext::provide(cx).set(is_disabled);
+}
+
+/// Play the haptic feedback unless it is disabled through the
+/// [`HapticsContext`]. Nothing happens when the browser doesn't support
+/// `navigator.vibrate` or hasn't been granted permission to vibrate.
+pub fn trigger_haptic_feedback(cx: Scope, feedback: &HapticFeedback) {
+  if HapticsContext::provide(cx).get() {
+    return;
+  }
+
+  let Some(navigator) = window().map(|window| window.navigator()) else {
+    return;
+  };
+
+  if !Reflect::has(&navigator, &"vibrate".into()).unwrap_or(false) {
+    return;
+  }
+
+  let pattern = feedback
+    .pattern()
+    .into_iter()
+    .map(leptos::wasm_bindgen::JsValue::from)
+    .collect::<Array>();
+
+  navigator.vibrate_with_pattern(&pattern);
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+
+  #[test]
+  fn feedback_patterns() {
+    assert_eq!(HapticFeedback::Light.pattern(), vec![10]);
+    assert_eq!(HapticFeedback::Heavy.pattern(), vec![40]);
+    assert_eq!(
+      HapticFeedback::Pattern(vec![5, 10, 5]).pattern(),
+      vec![5, 10, 5]
+    );
+  }
+
+  #[test]
+  fn disables_haptics_for_the_scope() {
+    let values = run_scope(create_runtime(), |cx| {
+      let initial = HapticsContext::provide(cx).get();
+      set_haptics_disabled(cx, true);
+      let disabled = HapticsContext::provide(cx).get();
+      set_haptics_disabled(cx, false);
+
+      (initial, disabled, HapticsContext::provide(cx).get())
+    });
+
+    assert_eq!(values, (false, true, false));
+  }
+
+  #[test]
+  fn child_scopes_share_the_setting() {
+    let is_disabled = run_scope(create_runtime(), |cx| {
+      HapticsContext::provide(cx);
+      let is_disabled = std::rc::Rc::new(std::cell::Cell::new(false));
+
+      cx.child_scope({
+        let is_disabled = is_disabled.clone();
+        move |cx| {
+          set_haptics_disabled(cx, true);
+          is_disabled.set(HapticsContext::provide(cx).get());
+        }
+      });
+
+      is_disabled.get() && HapticsContext::provide(cx).get()
+    });
+
+    assert!(is_disabled);
+  }
+
+  #[cfg(target_arch = "wasm32")]
+  mod browser {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use leptos::wasm_bindgen::closure::Closure;
+    use leptos::wasm_bindgen::JsValue;
+    use leptos::JsCast;
+    use wasm_bindgen_test::*;
+
+    use super::super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Replace `navigator.vibrate` with a stub, run the test and return the
+    /// patterns it was called with.
+    fn record_vibrations(run: impl FnOnce()) -> Vec<Vec<u32>> {
+      let navigator = window().unwrap().navigator();
+      let calls = Rc::new(RefCell::new(Vec::new()));
+      let stub = Closure::wrap(Box::new({
+        let calls = calls.clone();
+        move |pattern: JsValue| {
+          let pattern = Array::from(&pattern)
+            .iter()
+            .filter_map(|value| value.as_f64())
+            .map(|value| value as u32)
+            .collect();
+          calls.borrow_mut().push(pattern);
+          true
+        }
+      }) as Box<dyn Fn(JsValue) -> bool>);
+
+      Reflect::set(&navigator, &"vibrate".into(), stub.as_ref().unchecked_ref()).unwrap();
+      run();
+      Reflect::delete_property(&navigator, &"vibrate".into()).unwrap();
+
+      let calls = calls.borrow().clone();
+      calls
+    }
+
+    #[wasm_bindgen_test]
+    fn vibrates_unless_disabled() {
+      let calls = record_vibrations(|| {
+        leptos::run_scope(leptos::create_runtime(), |cx| {
+          trigger_haptic_feedback(cx, &HapticFeedback::Light);
+          set_haptics_disabled(cx, true);
+          trigger_haptic_feedback(cx, &HapticFeedback::Heavy);
+          set_haptics_disabled(cx, false);
+          trigger_haptic_feedback(cx, &HapticFeedback::Pattern(vec![5, 10]));
+        });
+      });
+
+      assert_eq!(calls, vec![vec![10], vec![5, 10]]);
+    }
+  }
+}
@@ -1,15 +1,27 @@
 pub use context::*;
+pub use haptics::*;
 use leptos::Scope;
 use leptos_aria_utils::ContextProvider;
 pub(crate) use text_selection::*;
+pub use use_interact_outside::*;
+pub use use_keyboard_shortcut::*;
+pub use use_move::*;
 pub use use_press::*;
+pub use use_safe_triangle::*;
 
 pub fn inject_providers(cx: Scope) {
   UserSelectContext::provide(cx);
   ElementMapContext::provide(cx);
   SelectionContext::provide(cx);
+  HapticsContext::provide(cx);
 }
 
 mod context;
+pub mod dom_helpers;
+mod haptics;
 mod text_selection;
+mod use_interact_outside;
+mod use_keyboard_shortcut;
+mod use_move;
 mod use_press;
+mod use_safe_triangle;
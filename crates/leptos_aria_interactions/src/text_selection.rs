@@ -13,10 +13,10 @@ use leptos::RwSignal;
 use leptos::Scope;
 use leptos::UntrackedGettableSignal;
 use leptos::UntrackedSettableSignal;
-use leptos_aria_utils::is_ios;
 use leptos_aria_utils::run_after_transition;
 use leptos_aria_utils::ContextProvider;
-use leptos_aria_utils::Map;
+use leptos_aria_utils::PlatformContext;
+use leptos_aria_utils::WeakElementMap;
 
 #[derive(Copy, Clone)]
 pub(crate) struct SelectionContext(RwSignal<Selection>);
@@ -56,7 +56,7 @@ impl ContextProvider for UserSelectContext {
   }
 }
 
-type ElementMap = Map<Element, JsString>;
+type ElementMap = WeakElementMap<JsString>;
 
 #[derive(Copy, Clone)]
 pub(crate) struct ElementMapContext(RwSignal<ElementMap>);
@@ -84,7 +84,7 @@ impl ContextProvider for ElementMapContext {
 }
 
 pub(crate) fn disable_text_selection(cx: Scope, element: &Option<impl AsRef<Element>>) {
-  if is_ios() {
+  if PlatformContext::provide(cx).get().is_ios() {
     let selection = SelectionContext::provide(cx);
     let user_select = UserSelectContext::provide(cx);
 
@@ -138,7 +138,7 @@ pub(crate) fn disable_text_selection(cx: Scope, element: &Option<impl AsRef<Elem
 /// instead to avoid possible performance issues that arise from applying and
 /// removing user-select: none to the entire page (see https://github.com/adobe/react-spectrum/issues/1609).
 pub(crate) fn restore_text_selection(cx: Scope, element: impl AsRef<Element>) {
-  if is_ios() {
+  if PlatformContext::provide(cx).get().is_ios() {
     let selection = SelectionContext::provide(cx);
     let user_select = UserSelectContext::provide(cx);
 
@@ -0,0 +1,100 @@
+use leptos::create_rw_signal;
+use leptos::document;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Node;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos::UntrackedSettableSignal;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::GlobalListeners;
+
+#[derive(TypedBuilder)]
+pub struct UseInteractOutsideProps {
+  /// The id of the element which interactions should be outside of.
+  #[builder(setter(into))]
+  pub id: MaybeSignal<String>,
+
+  /// Handler that is called when an interaction outside of the element ends.
+  pub on_interact_outside: Box<dyn Fn(PointerEvent)>,
+
+  /// Handler that is called when an interaction outside of the element
+  /// starts.
+  #[builder(default, setter(strip_option))]
+  pub on_interact_outside_start: Option<Box<dyn Fn(PointerEvent)>>,
+
+  /// Whether the interact outside events should be disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+/// Detects pointer interactions outside of an element, e.g. to close a popover
+/// when the user clicks elsewhere.
+///
+/// An interaction only counts when both the pointer down and the pointer up
+/// happen outside of the element, so dragging out of the element doesn't
+/// trigger it. The listeners are registered on the document in the capture
+/// phase and removed when the scope is disposed.
+pub fn use_interact_outside(cx: Scope, props: UseInteractOutsideProps) {
+  // There is no document to listen to on the server.
+  if is_server() {
+    return;
+  }
+
+  let original_id = props.id;
+  let id = (move || original_id.get()).derive_signal(cx);
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let is_pointer_down = create_rw_signal(cx, false);
+  let on_interact_outside = props.on_interact_outside;
+  let on_interact_outside_start = props.on_interact_outside_start;
+  let listeners = GlobalListeners::new(cx);
+
+  let is_valid_event = move |event: &PointerEvent| {
+    if is_disabled.get_untracked() || event.button() > 0 {
+      return false;
+    }
+
+    let Some(target) = event
+      .target()
+      .and_then(|target| target.dyn_into::<Node>().ok())
+    else {
+      return false;
+    };
+
+    // Ignore targets which were removed from the document by the interaction.
+    if !document().contains(Some(&target)) {
+      return false;
+    }
+
+    document()
+      .get_element_by_id(&id.get_untracked())
+      .map_or(false, |element| !element.contains(Some(&target)))
+  };
+
+  let on_pointer_down = move |event: PointerEvent| {
+    if !is_valid_event(&event) {
+      return;
+    }
+
+    if let Some(ref on_interact_outside_start) = on_interact_outside_start {
+      on_interact_outside_start(event);
+    }
+
+    is_pointer_down.set_untracked(true);
+  };
+
+  let on_pointer_up = move |event: PointerEvent| {
+    if is_pointer_down.get_untracked() && is_valid_event(&event) {
+      on_interact_outside(event);
+    }
+
+    is_pointer_down.set_untracked(false);
+  };
+
+  listeners.add_event_listener(document(), "pointerdown", on_pointer_down, true);
+  listeners.add_event_listener(document(), "pointerup", on_pointer_up, true);
+}
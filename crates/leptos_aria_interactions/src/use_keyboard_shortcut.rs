@@ -0,0 +1,706 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::document;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::Node;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::add_event_listener;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::use_platform;
+use leptos_aria_utils::EventListenerHandle;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::PlatformInfo;
+
+use crate::dom_helpers::is_editable_element;
+
+/// A key combination such as `Mod+Shift+K`.
+///
+/// `Mod` is the platform's primary modifier: <kbd>Cmd</kbd> on Apple devices
+/// and <kbd>Ctrl</kbd> elsewhere.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KeyCombination {
+  /// The key, with characters in upper case, e.g. `K`, `Enter` or `F6`.
+  pub key: KeyboardKey,
+  pub mod_key: bool,
+  pub ctrl_key: bool,
+  pub meta_key: bool,
+  pub alt_key: bool,
+  pub shift_key: bool,
+}
+
+impl KeyCombination {
+  /// Parse a combination of modifiers and a key separated by `+`, e.g.
+  /// `Mod+Shift+K` or `Alt+ArrowDown`. Returns `None` without a key.
+  pub fn parse(shortcut: &str) -> Option<Self> {
+    let mut combination = Self::default();
+
+    for part in shortcut.split('+').map(str::trim) {
+      match part.to_lowercase().as_str() {
+        "mod" => combination.mod_key = true,
+        "ctrl" | "control" => combination.ctrl_key = true,
+        "meta" | "cmd" | "command" => combination.meta_key = true,
+        "alt" | "option" => combination.alt_key = true,
+        "shift" => combination.shift_key = true,
+        "" => return None,
+        _ if combination.key == KeyboardKey::Unidentified => {
+          combination.key = normalize_key(KeyboardKey::from(part));
+        }
+        _ => return None,
+      }
+    }
+
+    (combination.key != KeyboardKey::Unidentified).then_some(combination)
+  }
+
+  /// Resolve `Mod` to the modifier of the platform.
+  fn resolve(&self, is_apple_device: bool) -> Self {
+    Self {
+      mod_key: false,
+      ctrl_key: self.ctrl_key || (self.mod_key && !is_apple_device),
+      meta_key: self.meta_key || (self.mod_key && is_apple_device),
+      ..self.clone()
+    }
+  }
+
+  /// The value of the `aria-keyshortcuts` attribute, e.g.
+  /// `Meta+Shift+K`.
+  pub fn aria_keyshortcuts(&self, is_apple_device: bool) -> String {
+    let combination = self.resolve(is_apple_device);
+    let mut parts = vec![];
+
+    if combination.ctrl_key {
+      parts.push("Control");
+    }
+
+    if combination.meta_key {
+      parts.push("Meta");
+    }
+
+    if combination.alt_key {
+      parts.push("Alt");
+    }
+
+    if combination.shift_key {
+      parts.push("Shift");
+    }
+
+    parts.push(combination.key.name());
+    parts.join("+")
+  }
+
+  /// A label for the combination using the platform's naming, e.g. `⇧⌘K` on
+  /// Apple devices and `Ctrl+Shift+K` elsewhere.
+  pub fn label(&self, is_apple_device: bool) -> String {
+    let combination = self.resolve(is_apple_device);
+    let key = combination.key.name();
+
+    if is_apple_device {
+      let mut label = String::new();
+
+      for (is_pressed, symbol) in [
+        (combination.ctrl_key, "⌃"),
+        (combination.alt_key, "⌥"),
+        (combination.shift_key, "⇧"),
+        (combination.meta_key, "⌘"),
+      ] {
+        if is_pressed {
+          label.push_str(symbol);
+        }
+      }
+
+      label.push_str(key);
+      return label;
+    }
+
+    let mut parts = vec![];
+
+    for (is_pressed, name) in [
+      (combination.ctrl_key, "Ctrl"),
+      (combination.meta_key, "Win"),
+      (combination.alt_key, "Alt"),
+      (combination.shift_key, "Shift"),
+    ] {
+      if is_pressed {
+        parts.push(name);
+      }
+    }
+
+    parts.push(key);
+    parts.join("+")
+  }
+
+  /// Whether the keyboard event matches the combination. Unlisted modifiers
+  /// must not be pressed.
+  pub fn matches(&self, event: &KeyboardEvent, is_apple_device: bool) -> bool {
+    let combination = self.resolve(is_apple_device);
+
+    normalize_key(KeyboardKey::from(event)) == combination.key
+      && event.ctrl_key() == combination.ctrl_key
+      && event.meta_key() == combination.meta_key
+      && event.alt_key() == combination.alt_key
+      && event.shift_key() == combination.shift_key
+  }
+}
+
+/// Single characters are compared in upper case so that `Shift` doesn't
+/// change the key.
+fn normalize_key(key: KeyboardKey) -> KeyboardKey {
+  match key {
+    KeyboardKey::Character(character) => KeyboardKey::Character(character.to_uppercase()),
+    key => key,
+  }
+}
+
+/// A registered shortcut, listed with [`registered_shortcuts`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortcutInfo {
+  pub combination: KeyCombination,
+  pub description: String,
+  /// The id of the element focus must be within for the shortcut to apply.
+  /// `None` for global shortcuts.
+  pub scope_id: Option<String>,
+}
+
+/// The key presses which shortcuts skip unless they opt in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct KeyDownInfo {
+  /// The key was pressed in a text input, text area or content editable
+  /// element.
+  is_editable_target: bool,
+  /// Another handler already handled the key.
+  is_default_prevented: bool,
+  /// An input method editor is composing text.
+  is_composing: bool,
+}
+
+impl KeyDownInfo {
+  fn from_event(event: &KeyboardEvent) -> Self {
+    Self {
+      is_editable_target: event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .map_or(false, is_editable_element),
+      is_default_prevented: event.default_prevented(),
+      is_composing: event.is_composing(),
+    }
+  }
+}
+
+/// The key presses a shortcut opted in to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct AllowedKeyDowns {
+  in_editable: bool,
+  default_prevented: bool,
+  composing: bool,
+}
+
+impl AllowedKeyDowns {
+  fn allows(&self, key_down: &KeyDownInfo) -> bool {
+    (self.in_editable || !key_down.is_editable_target)
+      && (self.default_prevented || !key_down.is_default_prevented)
+      && (self.composing || !key_down.is_composing)
+  }
+}
+
+struct ShortcutEntry {
+  id: usize,
+  info: ShortcutInfo,
+  is_disabled: Signal<bool>,
+  allowed: AllowedKeyDowns,
+  /// The platform from the scope which registered the shortcut.
+  platform: Signal<PlatformInfo>,
+  handler: Rc<dyn Fn(KeyboardEvent)>,
+}
+
+thread_local! {
+  static SHORTCUTS: RefCell<Vec<ShortcutEntry>> = RefCell::new(vec![]);
+  static NEXT_ID: Cell<usize> = Cell::new(0);
+
+  /// The document listener which runs the shortcuts, added while there are
+  /// registered shortcuts.
+  static KEY_DOWN_LISTENER: RefCell<Option<EventListenerHandle>> = RefCell::new(None);
+}
+
+/// The registered shortcuts in registration order, e.g. to list them in a
+/// help dialog.
+pub fn registered_shortcuts() -> Vec<ShortcutInfo> {
+  SHORTCUTS.with(|shortcuts| {
+    shortcuts
+      .borrow()
+      .iter()
+      .map(|shortcut| shortcut.info.clone())
+      .collect()
+  })
+}
+
+/// The shortcuts which conflict with `shortcut`: they have the same
+/// combination on the current platform and the same scope.
+pub fn find_conflicting_shortcuts<'a>(
+  shortcut: &ShortcutInfo,
+  shortcuts: impl IntoIterator<Item = &'a ShortcutInfo>,
+  is_apple_device: bool,
+) -> Vec<&'a ShortcutInfo> {
+  let combination = shortcut.combination.resolve(is_apple_device);
+
+  shortcuts
+    .into_iter()
+    .filter(|other| {
+      other.scope_id == shortcut.scope_id
+        && other.combination.resolve(is_apple_device) == combination
+    })
+    .collect()
+}
+
+#[derive(TypedBuilder)]
+pub struct UseKeyboardShortcutProps {
+  /// The key combination, e.g. `Mod+K`. See [`KeyCombination::parse`].
+  #[builder(setter(into))]
+  pub shortcut: String,
+
+  /// What the shortcut does, shown when listing the shortcuts.
+  #[builder(setter(into))]
+  pub description: String,
+
+  /// The id of the element focus must be within for the shortcut to apply.
+  /// Shortcuts of the innermost focused scope take precedence over global
+  /// shortcuts with the same combination.
+  #[builder(default, setter(strip_option, into))]
+  pub scope_id: Option<String>,
+
+  /// Whether the shortcut is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// Run the shortcut while typing into a text input, text area or content
+  /// editable element. The keys are otherwise left for typing.
+  #[builder(default)]
+  pub allow_in_editable: bool,
+
+  /// Run the shortcut when another handler already prevented the default of
+  /// the key press.
+  #[builder(default)]
+  pub allow_default_prevented: bool,
+
+  /// Run the shortcut while an input method editor is composing text.
+  #[builder(default)]
+  pub allow_composing: bool,
+
+  /// Handler that is called when the shortcut is pressed.
+  pub on_shortcut: Box<dyn Fn(KeyboardEvent)>,
+}
+
+#[derive(Clone)]
+pub struct KeyboardShortcutResult {
+  /// The value for the `aria-keyshortcuts` attribute of the element which the
+  /// shortcut activates.
+  pub aria_keyshortcuts: Signal<Option<String>>,
+  /// The platform specific label of the shortcut, e.g. `⌘K`. Updates once
+  /// the platform is known in the browser.
+  pub label: Signal<Option<String>>,
+}
+
+/// Register a keyboard shortcut for as long as the scope is alive.
+///
+/// A warning is logged in debug builds when the shortcut can't be parsed or
+/// another shortcut with the same combination and scope is registered.
+pub fn use_keyboard_shortcut(
+  cx: Scope,
+  props: UseKeyboardShortcutProps,
+) -> ReadSignal<KeyboardShortcutResult> {
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let combination = KeyCombination::parse(&props.shortcut);
+  let platform = use_platform(cx);
+
+  let aria_keyshortcuts = {
+    let combination = combination.clone();
+
+    (move || {
+      let is_apple_device = platform.get().is_apple_device();
+      combination
+        .as_ref()
+        .map(|combination| combination.aria_keyshortcuts(is_apple_device))
+    })
+    .derive_signal(cx)
+  };
+
+  let label = {
+    let combination = combination.clone();
+
+    (move || {
+      let is_apple_device = platform.get().is_apple_device();
+      combination
+        .as_ref()
+        .map(|combination| combination.label(is_apple_device))
+    })
+    .derive_signal(cx)
+  };
+
+  let (keyboard_shortcut_result, _) = create_signal(
+    cx,
+    KeyboardShortcutResult {
+      aria_keyshortcuts,
+      label,
+    },
+  );
+
+  let Some(combination) = combination else {
+    leptos::warn!(
+      "[use_keyboard_shortcut] Invalid shortcut `{}`.",
+      props.shortcut
+    );
+    return keyboard_shortcut_result;
+  };
+
+  if is_server() {
+    return keyboard_shortcut_result;
+  }
+
+  let info = ShortcutInfo {
+    combination,
+    description: props.description,
+    scope_id: props.scope_id,
+  };
+
+  if cfg!(debug_assertions) {
+    let shortcuts = registered_shortcuts();
+
+    for conflict in find_conflicting_shortcuts(
+      &info,
+      &shortcuts,
+      platform.get_untracked().is_apple_device(),
+    ) {
+      leptos::warn!(
+        "[use_keyboard_shortcut] `{}` ({}) conflicts with `{}`.",
+        props.shortcut,
+        info.description,
+        conflict.description
+      );
+    }
+  }
+
+  let id = NEXT_ID.with(|next_id| {
+    let id = next_id.get();
+    next_id.set(id + 1);
+    id
+  });
+
+  SHORTCUTS.with(|shortcuts| {
+    shortcuts.borrow_mut().push(ShortcutEntry {
+      id,
+      info,
+      is_disabled,
+      allowed: AllowedKeyDowns {
+        in_editable: props.allow_in_editable,
+        default_prevented: props.allow_default_prevented,
+        composing: props.allow_composing,
+      },
+      platform,
+      handler: Rc::from(props.on_shortcut),
+    });
+  });
+
+  add_key_down_listener();
+
+  on_cleanup(cx, move || {
+    let is_empty = SHORTCUTS.with(|shortcuts| {
+      let mut shortcuts = shortcuts.borrow_mut();
+      shortcuts.retain(|shortcut| shortcut.id != id);
+      shortcuts.is_empty()
+    });
+
+    if is_empty {
+      remove_key_down_listener();
+    }
+  });
+
+  keyboard_shortcut_result
+}
+
+/// Run the shortcut which matches the event. Scoped shortcuts, with the
+/// innermost focused scope first, take precedence over global shortcuts.
+///
+/// Key presses in editable elements, already handled key presses and key
+/// presses during composition only run the shortcuts which opted in to them.
+fn handle_key_down(event: KeyboardEvent) {
+  let active_element: Option<Node> = document().active_element().map(Into::into);
+  let key_down = KeyDownInfo::from_event(&event);
+
+  let handler = SHORTCUTS.with(|shortcuts| {
+    let shortcuts = shortcuts.borrow();
+    let mut matching: Vec<(Option<Node>, Rc<dyn Fn(KeyboardEvent)>)> = vec![];
+
+    for shortcut in shortcuts.iter() {
+      let is_apple_device = shortcut.platform.get_untracked().is_apple_device();
+
+      if shortcut.is_disabled.get_untracked()
+        || !shortcut.allowed.allows(&key_down)
+        || !shortcut.info.combination.matches(&event, is_apple_device)
+      {
+        continue;
+      }
+
+      let scope = match shortcut.info.scope_id {
+        Some(ref scope_id) => {
+          let Some(scope) = document().get_element_by_id(scope_id) else {
+            continue;
+          };
+
+          if !scope.contains(active_element.as_ref()) {
+            continue;
+          }
+
+          Some(Node::from(scope))
+        }
+        None => None,
+      };
+
+      matching.push((scope, shortcut.handler.clone()));
+    }
+
+    // The innermost scope is contained by every other matching scope.
+    matching
+      .iter()
+      .find(|(scope, _)| {
+        matching.iter().all(|(other, _)| {
+          match (scope, other) {
+            (Some(scope), Some(other)) => other.contains(Some(scope)),
+            (Some(_), None) => true,
+            (None, other) => other.is_none(),
+          }
+        })
+      })
+      .map(|(_, handler)| handler.clone())
+  });
+
+  if let Some(handler) = handler {
+    event.prevent_default();
+    handler(event);
+  }
+}
+
+fn add_key_down_listener() {
+  KEY_DOWN_LISTENER.with(|listener| {
+    let mut listener = listener.borrow_mut();
+
+    if listener.is_some() {
+      return;
+    }
+
+    *listener = Some(add_event_listener(
+      document(),
+      "keydown",
+      handle_key_down,
+      false,
+    ));
+  });
+}
+
+fn remove_key_down_listener() {
+  let _listener = KEY_DOWN_LISTENER.with(|listener| listener.borrow_mut().take());
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_shortcuts() {
+    let combination = KeyCombination::parse("Mod+Shift+k").unwrap();
+    assert!(combination.mod_key);
+    assert!(combination.shift_key);
+    assert_eq!(combination.key, KeyboardKey::Character("K".into()));
+
+    assert_eq!(KeyCombination::parse("Ctrl+Alt").map(|c| c.key), None);
+    assert_eq!(KeyCombination::parse("Ctrl+K+L"), None);
+    assert_eq!(KeyCombination::parse("Ctrl+"), None);
+  }
+
+  #[test]
+  fn platform_names() {
+    let combination = KeyCombination::parse("Mod+Shift+K").unwrap();
+
+    assert_eq!(combination.aria_keyshortcuts(true), "Meta+Shift+K");
+    assert_eq!(combination.aria_keyshortcuts(false), "Control+Shift+K");
+    assert_eq!(combination.label(true), "⇧⌘K");
+    assert_eq!(combination.label(false), "Ctrl+Shift+K");
+
+    let combination = KeyCombination::parse("Alt+Space").unwrap();
+
+    assert_eq!(combination.key, KeyboardKey::Space);
+    assert_eq!(combination.aria_keyshortcuts(false), "Alt+Space");
+  }
+
+  #[test]
+  fn detects_conflicts() {
+    let shortcut = |shortcut: &str, scope_id: Option<&str>| {
+      ShortcutInfo {
+        combination: KeyCombination::parse(shortcut).unwrap(),
+        description: shortcut.to_string(),
+        scope_id: scope_id.map(String::from),
+      }
+    };
+    let shortcuts = [
+      shortcut("Ctrl+K", None),
+      shortcut("Mod+K", Some("editor")),
+      shortcut("Meta+K", None),
+    ];
+
+    // `Mod` is `Ctrl` on other platforms and `Meta` on Apple devices.
+    let conflicts = find_conflicting_shortcuts(&shortcut("Mod+K", None), &shortcuts, false);
+    assert_eq!(conflicts, vec![&shortcuts[0]]);
+
+    let conflicts = find_conflicting_shortcuts(&shortcut("Mod+K", None), &shortcuts, true);
+    assert_eq!(conflicts, vec![&shortcuts[2]]);
+  }
+
+  #[test]
+  fn skips_key_downs_unless_allowed() {
+    let editable = KeyDownInfo {
+      is_editable_target: true,
+      ..KeyDownInfo::default()
+    };
+    let prevented = KeyDownInfo {
+      is_default_prevented: true,
+      ..KeyDownInfo::default()
+    };
+    let composing = KeyDownInfo {
+      is_composing: true,
+      ..KeyDownInfo::default()
+    };
+    let allowed = AllowedKeyDowns::default();
+
+    assert!(allowed.allows(&KeyDownInfo::default()));
+    assert!(!allowed.allows(&editable));
+    assert!(!allowed.allows(&prevented));
+    assert!(!allowed.allows(&composing));
+
+    let in_editable = AllowedKeyDowns {
+      in_editable: true,
+      ..allowed
+    };
+    assert!(in_editable.allows(&editable));
+    assert!(!in_editable.allows(&prevented));
+
+    let default_prevented = AllowedKeyDowns {
+      default_prevented: true,
+      ..allowed
+    };
+    assert!(default_prevented.allows(&prevented));
+    assert!(!default_prevented.allows(&composing));
+
+    let composing_allowed = AllowedKeyDowns {
+      composing: true,
+      ..allowed
+    };
+    assert!(composing_allowed.allows(&composing));
+    assert!(!composing_allowed.allows(&editable));
+  }
+
+  #[cfg(target_arch = "wasm32")]
+  mod browser {
+    use leptos::web_sys::KeyboardEventInit;
+    use wasm_bindgen_test::*;
+
+    use super::super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn key_down(is_composing: bool) -> KeyboardEvent {
+      let mut init = KeyboardEventInit::new();
+      init
+        .key("F2")
+        .bubbles(true)
+        .cancelable(true)
+        .is_composing(is_composing);
+      KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap()
+    }
+
+    /// Register an `F2` shortcut, press it on an element created from the
+    /// `html` and return whether the shortcut ran.
+    fn runs_shortcut(
+      html: &str,
+      event: KeyboardEvent,
+      configure: impl FnOnce(&mut UseKeyboardShortcutProps) + 'static,
+    ) -> bool {
+      let ran = Rc::new(Cell::new(false));
+      let container = document().create_element("div").unwrap();
+      container.set_inner_html(html);
+      document().body().unwrap().append_child(&container).unwrap();
+
+      leptos::run_scope(leptos::create_runtime(), {
+        let ran = ran.clone();
+        let target = container.first_element_child().unwrap();
+
+        move |cx| {
+          let mut props = UseKeyboardShortcutProps::builder()
+            .shortcut("F2")
+            .description("Rename")
+            .on_shortcut(Box::new(move |_| ran.set(true)))
+            .build();
+          configure(&mut props);
+
+          use_keyboard_shortcut(cx, props);
+          target.dispatch_event(&event).unwrap();
+        }
+      });
+
+      container.remove();
+      ran.get()
+    }
+
+    #[wasm_bindgen_test]
+    fn skips_editable_targets() {
+      for html in [
+        "<input type=\"text\">",
+        "<textarea></textarea>",
+        "<div contenteditable=\"true\"></div>",
+      ] {
+        assert!(!runs_shortcut(html, key_down(false), |_| {}));
+        assert!(runs_shortcut(html, key_down(false), |props| {
+          props.allow_in_editable = true;
+        }));
+      }
+
+      assert!(runs_shortcut("<button></button>", key_down(false), |_| {}));
+    }
+
+    #[wasm_bindgen_test]
+    fn skips_handled_key_downs() {
+      let prevented = || {
+        let event = key_down(false);
+        event.prevent_default();
+        event
+      };
+
+      assert!(!runs_shortcut("<button></button>", prevented(), |_| {}));
+      assert!(runs_shortcut("<button></button>", prevented(), |props| {
+        props.allow_default_prevented = true;
+      }));
+    }
+
+    #[wasm_bindgen_test]
+    fn skips_composition() {
+      assert!(!runs_shortcut("<button></button>", key_down(true), |_| {}));
+      assert!(runs_shortcut(
+        "<button></button>",
+        key_down(true),
+        |props| {
+          props.allow_composing = true;
+        }
+      ));
+    }
+  }
+}
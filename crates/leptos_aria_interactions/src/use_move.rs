@@ -0,0 +1,250 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::document;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::PointerEvent;
+use leptos::JsCast;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos_aria_utils::GlobalListeners;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::text_selection::disable_text_selection;
+use crate::text_selection::restore_text_selection;
+use crate::PointerType;
+
+/// The stage of a move interaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveEventType {
+  MoveStart,
+  Move,
+  MoveEnd,
+}
+
+#[derive(Clone, Debug)]
+pub struct MoveEvent {
+  pub event_type: MoveEventType,
+  pub pointer_type: PointerType,
+  /// The horizontal distance in pixels since the previous move event. Arrow
+  /// keys move by `1`. Always `0` for the start and end events.
+  pub delta_x: f64,
+  /// The vertical distance since the previous move event.
+  pub delta_y: f64,
+  pub shift_key: bool,
+  pub ctrl_key: bool,
+  pub meta_key: bool,
+  pub alt_key: bool,
+}
+
+type BoxedMoveCallback = Box<dyn Fn(&MoveEvent)>;
+type MoveCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseMoveProps {
+  /// Handler that is called when a pointer starts moving or an arrow key is
+  /// pressed.
+  #[builder(default, setter(strip_option))]
+  pub on_move_start: Option<BoxedMoveCallback>,
+
+  /// Handler that is called for each movement.
+  #[builder(default, setter(strip_option))]
+  pub on_move: Option<BoxedMoveCallback>,
+
+  /// Handler that is called when the pointer is released or after the arrow
+  /// key movement.
+  #[builder(default, setter(strip_option))]
+  pub on_move_end: Option<BoxedMoveCallback>,
+}
+
+#[derive(Clone)]
+pub struct MoveResult {
+  pub on_pointer_down: MoveCallback<PointerEvent>,
+  pub on_key_down: MoveCallback<KeyboardEvent>,
+}
+
+/// Handles move interactions across mouse, pen, touch and keyboard, such as
+/// dragging the thumb of a slider.
+///
+/// Pointer movement is tracked on the document once the pointer is pressed
+/// on the element, so it continues when the pointer leaves the element. Each
+/// arrow key press is reported as a complete move of one unit. Text selection
+/// is disabled while moving.
+pub fn use_move(cx: Scope, props: UseMoveProps) -> ReadSignal<MoveResult> {
+  let on_move_start = props.on_move_start.map(Rc::new);
+  let on_move = props.on_move.map(Rc::new);
+  let on_move_end = props.on_move_end.map(Rc::new);
+
+  let listeners = GlobalListeners::new(cx);
+
+  let dispatch = move |event_type: MoveEventType,
+                       pointer_type: PointerType,
+                       (delta_x, delta_y): (f64, f64),
+                       modifiers: (bool, bool, bool, bool)| {
+    let callback = match event_type {
+      MoveEventType::MoveStart => &on_move_start,
+      MoveEventType::Move => &on_move,
+      MoveEventType::MoveEnd => &on_move_end,
+    };
+
+    if let Some(callback) = callback {
+      let (shift_key, ctrl_key, meta_key, alt_key) = modifiers;
+
+      callback(&MoveEvent {
+        event_type,
+        pointer_type,
+        delta_x,
+        delta_y,
+        shift_key,
+        ctrl_key,
+        meta_key,
+        alt_key,
+      });
+    }
+  };
+  let dispatch = Rc::new(dispatch);
+
+  let on_pointer_down: MoveCallback<PointerEvent> = {
+    let dispatch = dispatch.clone();
+
+    Rc::new(Box::new(move |event: PointerEvent| {
+      if event.button() != 0 {
+        return;
+      }
+
+      let Some(target) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      else {
+        return;
+      };
+
+      disable_text_selection(cx, &Some(target.clone()));
+
+      let pointer_id = event.pointer_id();
+      let pointer_type = PointerType::from(event.pointer_type().as_str());
+      let last_position = Rc::new(Cell::new((
+        f64::from(event.page_x()),
+        f64::from(event.page_y()),
+      )));
+      let did_move = Rc::new(Cell::new(false));
+
+      let on_pointer_move = {
+        let dispatch = dispatch.clone();
+        let did_move = did_move.clone();
+        let pointer_type = pointer_type.clone();
+
+        move |event: PointerEvent| {
+          if event.pointer_id() != pointer_id {
+            return;
+          }
+
+          let modifiers = get_modifiers(&event);
+          let (page_x, page_y) = (f64::from(event.page_x()), f64::from(event.page_y()));
+          let (last_x, last_y) = last_position.get();
+          let delta = (page_x - last_x, page_y - last_y);
+
+          if delta == (0.0, 0.0) {
+            return;
+          }
+
+          if !did_move.get() {
+            did_move.set(true);
+            dispatch(
+              MoveEventType::MoveStart,
+              pointer_type.clone(),
+              (0.0, 0.0),
+              modifiers,
+            );
+          }
+
+          last_position.set((page_x, page_y));
+          dispatch(MoveEventType::Move, pointer_type.clone(), delta, modifiers);
+        }
+      };
+
+      let on_pointer_up = {
+        let dispatch = dispatch.clone();
+        let listeners = listeners.clone();
+
+        move |event: PointerEvent| {
+          if event.pointer_id() != pointer_id {
+            return;
+          }
+
+          if did_move.get() {
+            let modifiers = get_modifiers(&event);
+            dispatch(
+              MoveEventType::MoveEnd,
+              pointer_type.clone(),
+              (0.0, 0.0),
+              modifiers,
+            );
+          }
+
+          restore_text_selection(cx, &target);
+          listeners.remove_all_listeners();
+        }
+      };
+
+      listeners.add_event_listener(document(), "pointermove", on_pointer_move, false);
+      listeners.add_event_listener(document(), "pointerup", on_pointer_up.clone(), false);
+      listeners.add_event_listener(document(), "pointercancel", on_pointer_up, false);
+    }))
+  };
+
+  let on_key_down: MoveCallback<KeyboardEvent> = Rc::new(Box::new(move |event: KeyboardEvent| {
+    let delta = match KeyboardKey::from(&event) {
+      KeyboardKey::ArrowLeft => (-1.0, 0.0),
+      KeyboardKey::ArrowRight => (1.0, 0.0),
+      KeyboardKey::ArrowUp => (0.0, -1.0),
+      KeyboardKey::ArrowDown => (0.0, 1.0),
+      _ => return,
+    };
+
+    event.prevent_default();
+
+    let modifiers = (
+      event.shift_key(),
+      event.ctrl_key(),
+      event.meta_key(),
+      event.alt_key(),
+    );
+
+    dispatch(
+      MoveEventType::MoveStart,
+      PointerType::Keyboard,
+      (0.0, 0.0),
+      modifiers,
+    );
+    dispatch(MoveEventType::Move, PointerType::Keyboard, delta, modifiers);
+    dispatch(
+      MoveEventType::MoveEnd,
+      PointerType::Keyboard,
+      (0.0, 0.0),
+      modifiers,
+    );
+  }));
+
+  let (move_result, _) = create_signal(
+    cx,
+    MoveResult {
+      on_pointer_down,
+      on_key_down,
+    },
+  );
+
+  move_result
+}
+
+fn get_modifiers(event: &PointerEvent) -> (bool, bool, bool, bool) {
+  (
+    event.shift_key(),
+    event.ctrl_key(),
+    event.meta_key(),
+    event.alt_key(),
+  )
+}
@@ -1,18 +1,18 @@
+use std::cell::Cell;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::time::Duration;
 
 use leptos::create_rw_signal;
 use leptos::document;
-use leptos::js_sys::Function;
+use leptos::set_timeout;
+use leptos::store_value;
 use leptos::typed_builder::TypedBuilder;
-use leptos::wasm_bindgen::prelude::Closure;
 use leptos::web_sys::DragEvent;
 use leptos::web_sys::Element;
-use leptos::web_sys::HtmlAnchorElement;
+use leptos::web_sys::Event;
 use leptos::web_sys::HtmlElement;
-use leptos::web_sys::HtmlInputElement;
-use leptos::web_sys::HtmlTextAreaElement;
 use leptos::web_sys::KeyboardEvent;
 use leptos::web_sys::MouseEvent;
 use leptos::web_sys::PointerEvent;
@@ -24,20 +24,41 @@ use leptos::MaybeSignal;
 use leptos::Scope;
 use leptos::Signal;
 use leptos::UntrackedGettableSignal;
-use leptos::UntrackedSettableSignal;
 use leptos::*;
+use leptos_aria_utils::add_event_listener;
+use leptos_aria_utils::dispatch_custom_event;
 use leptos_aria_utils::focus_without_scrolling;
+use leptos_aria_utils::is_event_within;
+use leptos_aria_utils::is_server;
 use leptos_aria_utils::is_virtual_click;
 use leptos_aria_utils::is_virtual_pointer_event;
+use leptos_aria_utils::use_document_visibility;
+use leptos_aria_utils::use_platform;
+use leptos_aria_utils::use_window_focus;
+use leptos_aria_utils::warn_on_change_during_hydration;
+use leptos_aria_utils::AriaCustomEvent;
+use leptos_aria_utils::DelegatedEvent;
+use leptos_aria_utils::DelegatedListeners;
 use leptos_aria_utils::FocusableElement;
-use leptos_aria_utils::GlobalListeners;
+use leptos_aria_utils::InteractionKind;
+use leptos_aria_utils::InteractionSpan;
+use leptos_aria_utils::KeyboardKey;
 use leptos_aria_utils::ToFocusableElement;
 use web_sys::DomRect;
-use web_sys::HtmlButtonElement;
-use web_sys::Node;
 
+use crate::dom_helpers::are_rectangles_overlapping;
+use crate::dom_helpers::has_link_role;
+use crate::dom_helpers::is_html_anchor_link;
+use crate::dom_helpers::is_valid_keyboard_event;
+use crate::dom_helpers::should_prevent_default_keyboard;
 use crate::text_selection::disable_text_selection;
 use crate::text_selection::restore_text_selection;
+use crate::trigger_haptic_feedback;
+use crate::HapticFeedback;
+
+/// The time in milliseconds a touch press is held before it becomes a long
+/// press, e.g. to pick up a draggable item.
+pub const LONG_PRESS_THRESHOLD: u64 = 500;
 
 /// ## Features
 ///
@@ -58,6 +79,7 @@ use crate::text_selection::restore_text_selection;
 /// * Handles disabling text selection on mobile while the press interaction is
 ///   active
 /// * Handles canceling press interactions on scroll
+/// * Cancels the press when the tab is hidden or the window loses focus
 /// * Normalizes many cross browser inconsistencies
 ///
 /// Read the [`react-aria` blog post](https://react-spectrum.adobe.com/blog/building-a-button-part-1.html) about the complexities of press event handling to learn more.
@@ -66,16 +88,21 @@ use crate::text_selection::restore_text_selection;
 ///
 /// `use_press` returns props that you should add the target component (spread
 /// is not yet supported in `leptos`):
+///
+/// ## Server rendering
+///
+/// With the `ssr` feature enabled the hook never touches the `window` or
+/// `document`. The returned signals only reflect the provided props and the
+/// event handlers do nothing.
 pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
+  if is_server() {
+    return use_press_ssr(cx, props);
+  }
+
   // internal state
-  let listeners = Arc::new(RwLock::new(GlobalListeners::default()));
-  let ignore_emulated_mouse_events = create_rw_signal(cx, false);
-  let ignore_click_after_press = create_rw_signal(cx, false);
-  let did_fire_press_start = create_rw_signal(cx, false);
-  let active_pointer_id = create_rw_signal::<Option<i32>>(cx, None);
-  let target = create_rw_signal::<Option<Element>>(cx, None);
-  let is_over_target = create_rw_signal(cx, false);
-  let pointer_type = create_rw_signal(cx, PointerType::Unsupported);
+  let listeners = Arc::new(RwLock::new(DelegatedListeners::default()));
+  let state = store_value(cx, PressState::default());
+  let platform = use_platform(cx);
 
   let original_is_disabled = props.is_disabled.unwrap_or(false.into());
   let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
@@ -90,6 +117,24 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
   let allow_text_selection_on_press =
     (move || original_allow_text_selection_on_press.get()).derive_signal(cx);
 
+  let haptics = props.haptics;
+  let long_press_haptics = props.long_press_haptics;
+  // Incremented whenever a press starts or ends, so the long press timeout of
+  // an earlier press doesn't play the haptic feedback.
+  let long_press_generation = Rc::new(Cell::new(0_u32));
+
+  // The current target is always on the composed path of its own event, so
+  // only the element the hook is attached to can tell whether an event which
+  // reached the handlers originated within it, e.g. rather than being
+  // forwarded from content rendered through a portal.
+  let element: Option<Rc<dyn Fn() -> Option<Element>>> = props.element.map(Rc::from);
+  let is_within_element: Rc<dyn Fn(&Event) -> bool> = Rc::new(move |event: &Event| {
+    element
+      .as_ref()
+      .and_then(|element| element())
+      .map_or(true, |element| is_event_within(&element, event))
+  });
+
   let wrapped_on_press: Option<WrappedPressCallback> = props.on_press.map(Rc::new);
   let wrapped_on_press_start: Option<WrappedPressCallback> = props.on_press_start.map(Rc::new);
   let wrapped_on_press_end: Option<WrappedPressCallback> = props.on_press_end.map(Rc::new);
@@ -98,17 +143,22 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
   let wrapped_on_press_up: Option<WrappedPressCallback> = props.on_press_up.map(Rc::new);
 
   let is_pressed = create_rw_signal(cx, false);
+  let pointer_type = create_rw_signal(cx, PointerType::Unsupported);
   let original_is_pressed = props.is_pressed.unwrap_or(false.into());
   let derived_is_pressed =
     (move || original_is_pressed.get() || is_pressed.get()).derive_signal(cx);
 
+  warn_on_change_during_hydration("use_press", "is_disabled", is_disabled);
+  warn_on_change_during_hydration("use_press", "is_pressed", derived_is_pressed);
+
   // Trigger the beginning of a custom press event.
   let trigger_press_start = {
     let wrapped_on_press_start = wrapped_on_press_start.clone();
     let wrapped_on_press_change = wrapped_on_press_change.clone();
+    let long_press_generation = long_press_generation.clone();
 
     move |focusable_event: &FocusableEvent, pointer: PointerType| {
-      if is_disabled.get() || did_fire_press_start.get_untracked() {
+      if is_disabled.get() || state.with(|state| state.did_fire_press_start) {
         return;
       }
 
@@ -116,8 +166,37 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
       call_event(&wrapped_on_press_start, &event);
       call_event(&wrapped_on_press_change, true);
 
-      did_fire_press_start.set_untracked(true);
+      state.update(|state| {
+        state.did_fire_press_start = true;
+        state.span = InteractionSpan::start(
+          InteractionKind::Press,
+          pointer.as_str(),
+          Some(&focusable_event.current_target()),
+        );
+      });
       is_pressed.set(true);
+      pointer_type.set(pointer.clone());
+
+      if let (Some(feedback), PointerType::Touch) = (&long_press_haptics, &pointer) {
+        let generation = long_press_generation.get().wrapping_add(1);
+        long_press_generation.set(generation);
+
+        let long_press_generation = long_press_generation.clone();
+        let feedback = feedback.clone();
+
+        set_timeout(
+          move || {
+            if long_press_generation.get() == generation {
+              trigger_haptic_feedback(cx, &feedback);
+            }
+          },
+          Duration::from_millis(LONG_PRESS_THRESHOLD),
+        );
+      }
+
+      if let (Some(haptics), PointerType::Touch) = (&haptics, pointer) {
+        trigger_haptic_feedback(cx, haptics);
+      }
     }
   };
 
@@ -127,25 +206,39 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
 
     let callback =
       move |focusable_event: &FocusableEvent, pointer: PointerType, was_pressed: bool| {
-        if !did_fire_press_start.get_untracked() {
+        if !state.with(|state| state.did_fire_press_start) {
           return;
         }
 
-        ignore_click_after_press.set_untracked(true);
-        did_fire_press_start.set_untracked(false);
+        long_press_generation.set(long_press_generation.get().wrapping_add(1));
+
+        state.update(|state| {
+          state.ignore_click_after_press = true;
+          state.did_fire_press_start = false;
+        });
 
         let event = PressEvent::create(&pointer, PressEventType::PressEnd, focusable_event);
         call_event(&wrapped_on_press_end.clone(), &event);
         call_event(&wrapped_on_press_change.clone(), false);
 
         is_pressed.set(false);
+        pointer_type.set(PointerType::Unsupported);
 
         if !was_pressed || is_disabled.get() {
+          state.with(|state| state.span.end("press_end"));
           return;
         }
 
+        state.with(|state| state.span.end("press"));
+
         let event = PressEvent::create(&pointer, PressEventType::Press, focusable_event);
         call_event(&wrapped_on_press, &event);
+        dispatch_custom_event(
+          &event.target,
+          AriaCustomEvent::Press {
+            pointer_type: pointer.as_str().to_string(),
+          },
+        );
       };
 
     Rc::new(Box::new(callback))
@@ -169,23 +262,26 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
     let listeners = listeners.clone();
 
     let callback = move |focusable_event: &FocusableEvent| {
-      if !is_pressed.get_untracked() {
+      if !state.with(|state| state.is_pressed) {
         return;
       }
 
-      if is_over_target.get_untracked() {
-        trigger_press_end(focusable_event, pointer_type.get_untracked(), false);
+      state.with(|state| state.span.event("cancel"));
+
+      if state.with(|state| state.is_over_target) {
+        trigger_press_end(
+          focusable_event,
+          state.with(|state| state.pointer_type.clone()),
+          false,
+        );
       }
 
-      is_pressed.set_untracked(false);
-      is_over_target.set_untracked(false);
-      active_pointer_id.set_untracked(None);
-      pointer_type.set_untracked(PointerType::Unsupported);
+      state.update(PressState::reset);
 
       listeners.write().unwrap().remove_all_listeners();
 
       if !allow_text_selection_on_press.get() {
-        if let Some(ref element) = target.get_untracked() {
+        if let Some(ref element) = state.with(|state| state.target.clone()) {
           restore_text_selection(cx, element);
         }
       }
@@ -195,22 +291,22 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
   };
 
   let on_key_up: PressCallback<KeyboardEvent> = {
+    let is_within_element = is_within_element.clone();
     let trigger_press_up = trigger_press_up.clone();
     let handler = move |event: KeyboardEvent| {
       let event_current_target: Element = event.current_target().unwrap().unchecked_into();
-      let event_target: Option<Node> = event.target().map(|target| target.unchecked_into());
 
       if !is_valid_keyboard_event(&event, &event_current_target)
         || event.repeat()
-        || !event_current_target.contains(event_target.as_ref())
+        || !is_within_element(&event)
       {
         return;
       }
 
       let focusable_event = FocusableEvent::Keyboard(
         event,
-        target
-          .get_untracked()
+        state
+          .with(|state| state.target.clone())
           .map(|target| target.to_focusable_element()),
       );
 
@@ -226,9 +322,10 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
 
     let handler = move |event: KeyboardEvent| {
       let event_current_target: Element = event.current_target().unwrap().unchecked_into();
-      let event_target: Option<Node> = event.target().map(|target| target.unchecked_into());
 
-      if !is_pressed.get_untracked() || !is_valid_keyboard_event(&event, &event_current_target) {
+      if !state.with(|state| state.is_pressed)
+        || !is_valid_keyboard_event(&event, &event_current_target)
+      {
         return;
       }
 
@@ -237,31 +334,26 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
       }
 
       event.stop_propagation();
-      is_pressed.set_untracked(false);
+      state.update(|state| state.is_pressed = false);
+      let contains_target = state
+        .with(|state| state.target.clone())
+        .map(|element| is_event_within(&element, &event))
+        .unwrap_or(false);
       let focusable_event = FocusableEvent::Keyboard(
         event,
-        target
-          .get_untracked()
+        state
+          .with(|state| state.target.clone())
           .map(|target| target.to_focusable_element()),
       );
 
-      let contains_target = target
-        .get_untracked()
-        .as_ref()
-        .map(|element| element.contains(event_target.as_ref()))
-        .unwrap_or(false);
-
       trigger_press_end(&focusable_event, PointerType::Keyboard, contains_target);
       listeners.write().unwrap().remove_all_listeners();
 
-      let Some(ref element) = target.get_untracked() else {
+      let Some(ref element) = state.with(|state| state.target.clone()) else {
         return;
       };
 
-      if !element.is_instance_of::<HtmlElement>()
-        || !element.contains(event_target.as_ref())
-        || !has_link_role(element)
-      {
+      if !element.is_instance_of::<HtmlElement>() || !contains_target || !has_link_role(element) {
         return;
       }
 
@@ -272,18 +364,16 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
   };
 
   let on_key_down: PressCallback<KeyboardEvent> = {
+    let is_within_element = is_within_element.clone();
     let global_on_key_up = global_on_key_up.clone();
     let trigger_press_start = trigger_press_start.clone();
     let listeners = listeners.clone();
 
     let handler = move |event: KeyboardEvent| {
       let event_current_target: Element = event.current_target().unwrap().unchecked_into();
-      let event_target: Option<Node> = event.target().map(|target| target.unchecked_into());
 
-      if is_valid_keyboard_event(&event, &event_current_target)
-        && event_current_target.contains(event_target.as_ref())
-      {
-        if should_prevent_default_keyboard(&event_current_target, event.key()) {
+      if is_valid_keyboard_event(&event, &event_current_target) && is_within_element(&event) {
+        if should_prevent_default_keyboard(&event_current_target, KeyboardKey::from(&event)) {
           event.prevent_default();
         }
 
@@ -292,33 +382,27 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
         // If the event is repeating, it may have started on a different element
         // after which focus moved to the current element. Ignore these events and
         // only handle the first key down event.
-        if !is_pressed.get_untracked() && !event.repeat() {
-          target.set_untracked(Some(event_current_target));
-          is_pressed.set_untracked(true);
+        if !state.with(|state| state.is_pressed) && !event.repeat() {
+          state.update(|state| {
+            state.target = Some(event_current_target);
+            state.is_pressed = true;
+          });
           let focusable_event = FocusableEvent::Keyboard(event, None);
           trigger_press_start(&focusable_event, PointerType::Keyboard);
 
-          let function = {
-            let global_on_key_up = global_on_key_up.clone();
-
-            let callback = move |event: KeyboardEvent| {
-              global_on_key_up(event);
-            };
-
-            Closure::wrap(Box::new(callback) as Box<dyn Fn(KeyboardEvent)>)
-              .as_ref()
-              .unchecked_ref::<Function>()
-              .clone()
-          };
-
           // Focus may move before the key up event, so register the event on the document
           // instead of the same element where the key down event occurred.
+          let global_on_key_up = global_on_key_up.clone();
           listeners
             .write()
             .unwrap()
-            .add_listener(document(), "keyup", function, false);
+            .add(DelegatedEvent::KeyUp, move |event: KeyboardEvent| {
+              global_on_key_up(event)
+            });
         }
-      } else if event.key() == "Enter" && is_html_anchor_link(&event_current_target) {
+      } else if KeyboardKey::from(&event) == KeyboardKey::Enter
+        && is_html_anchor_link(&event_current_target)
+      {
         // If the target is a link, we won't have handled this above because we want the
         // default browser behavior to open the link when pressing Enter. But we
         // still need to prevent default so that elements above do not also handle
@@ -331,6 +415,7 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
   };
 
   let on_click: PressCallback<MouseEvent> = {
+    let is_within_element = is_within_element.clone();
     let trigger_press_start = trigger_press_start.clone();
     let trigger_press_up = trigger_press_up.clone();
     let trigger_press_end = trigger_press_end.clone();
@@ -341,9 +426,8 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
       };
 
       let event_current_target: Element = event_current_target.unchecked_into();
-      let event_target: Option<Node> = event.target().map(|target| target.unchecked_into());
 
-      if !event_current_target.contains(event_target.as_ref()) {
+      if !is_within_element(&event) {
         return;
       }
 
@@ -360,9 +444,10 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
 
       // If triggered from a screen reader or by using element.click(),
       // trigger as if it were a keyboard click.
-      if !ignore_click_after_press.get_untracked()
-        && !ignore_emulated_mouse_events.get_untracked()
-        && (pointer_type.get_untracked() == PointerType::Virtual || is_virtual_click(&event))
+      if !state.with(|state| state.ignore_click_after_press)
+        && !state.with(|state| state.ignore_emulated_mouse_events)
+        && (state.with(|state| state.pointer_type.clone()) == PointerType::Virtual
+          || is_virtual_click(&event, &platform.get_untracked()))
       {
         if !is_disabled.get_untracked() || !prevent_focus_on_press.get_untracked() {
           focus_without_scrolling(cx, &event_current_target);
@@ -374,21 +459,21 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
         trigger_press_end(&focusable_event, PointerType::Virtual, true);
       }
 
-      ignore_emulated_mouse_events.set_untracked(false);
-      ignore_click_after_press.set_untracked(false);
+      state.update(|state| {
+        state.ignore_emulated_mouse_events = false;
+        state.ignore_click_after_press = false;
+      });
     };
 
     Rc::new(Box::new(callback))
   };
 
   let on_drag_start: PressCallback<DragEvent> = {
+    let is_within_element = is_within_element.clone();
     let cancel = cancel.clone();
 
     let handler = move |event: DragEvent| {
-      let event_current_target: Element = event.current_target().unwrap().unchecked_into();
-      let event_target: Option<Node> = event.target().map(|target| target.unchecked_into());
-
-      if !event_current_target.contains(event_target.as_ref()) {
+      if !is_within_element(&event) {
         return;
       }
 
@@ -402,11 +487,11 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
   };
 
   let on_mouse_down: PressCallback<MouseEvent> = {
+    let is_within_element = is_within_element.clone();
     let handler = move |event: MouseEvent| {
       let event_current_target: Element = event.current_target().unwrap().unchecked_into();
-      let event_target: Option<Node> = event.target().map(|target| target.unchecked_into());
 
-      if event_current_target.contains(event_target.as_ref()) {
+      if !is_within_element(&event) {
         return;
       }
 
@@ -457,23 +542,30 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
     let cancel = cancel.clone();
 
     let handler = move |event: PointerEvent| {
-      if Some(event.pointer_id()) != active_pointer_id.get_untracked() {
+      if Some(event.pointer_id()) != state.with(|state| state.active_pointer_id) {
         return;
       }
 
-      let Some(ref element) = target.get_untracked() else {
+      let Some(ref element) = state.with(|state| state.target.clone()) else {
         return;
       };
 
       let focusable_event =
         FocusableEvent::Pointer(event.clone(), Some(element.to_focusable_element()));
 
-      if is_above_target(&event, element) && !is_over_target.get_untracked() {
-        is_over_target.set_untracked(true);
-        trigger_press_start(&focusable_event, pointer_type.get_untracked());
-      } else if is_over_target.get_untracked() {
-        is_over_target.set_untracked(false);
-        trigger_press_end(&focusable_event, pointer_type.get_untracked(), false);
+      if is_above_target(&event, element) && !state.with(|state| state.is_over_target) {
+        state.update(|state| state.is_over_target = true);
+        trigger_press_start(
+          &focusable_event,
+          state.with(|state| state.pointer_type.clone()),
+        );
+      } else if state.with(|state| state.is_over_target) {
+        state.update(|state| state.is_over_target = false);
+        trigger_press_end(
+          &focusable_event,
+          state.with(|state| state.pointer_type.clone()),
+          false,
+        );
 
         if should_cancel_on_pointer_exit.get_untracked() {
           cancel(&focusable_event);
@@ -485,14 +577,14 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
   };
 
   let on_pointer_up: PressCallback<PointerEvent> = {
+    let is_within_element = is_within_element.clone();
     let handler = move |event: PointerEvent| {
       // iOS fires pointerup with zero width and height, so check the pointerType
       // recorded during pointerdown.
       let event_current_target: Element = event.current_target().unwrap().unchecked_into();
-      let event_target: Option<Node> = event.target().map(|target| target.unchecked_into());
 
-      if !event_current_target.contains(event_target.as_ref())
-        || pointer_type.get_untracked() == PointerType::Virtual
+      if !is_within_element(&event)
+        || state.with(|state| state.pointer_type.clone()) == PointerType::Virtual
       {
         return;
       }
@@ -516,14 +608,14 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
     let listeners = listeners.clone();
 
     let handler = move |event: PointerEvent| {
-      if Some(event.pointer_id()) != active_pointer_id.get_untracked()
-        || !is_pressed.get_untracked()
+      if Some(event.pointer_id()) != state.with(|state| state.active_pointer_id)
+        || !state.with(|state| state.is_pressed)
         || event.button() != 0
       {
         return;
       }
 
-      let Some(ref element) = target.get_untracked() else {
+      let Some(ref element) = state.with(|state| state.target.clone()) else {
         return;
       };
 
@@ -531,15 +623,20 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
         FocusableEvent::Pointer(event.clone(), Some(element.to_focusable_element()));
 
       if is_above_target(&event, element) {
-        trigger_press_end(&focusable_event, pointer_type.get_untracked(), true);
-      } else if is_over_target.get_untracked() {
-        trigger_press_end(&focusable_event, pointer_type.get_untracked(), false);
+        trigger_press_end(
+          &focusable_event,
+          state.with(|state| state.pointer_type.clone()),
+          true,
+        );
+      } else if state.with(|state| state.is_over_target) {
+        trigger_press_end(
+          &focusable_event,
+          state.with(|state| state.pointer_type.clone()),
+          false,
+        );
       }
 
-      is_pressed.set_untracked(false);
-      is_over_target.set_untracked(false);
-      active_pointer_id.set_untracked(None);
-      pointer_type.set_untracked(PointerType::Unsupported);
+      state.update(PressState::reset);
       listeners.write().unwrap().remove_all_listeners();
 
       if !allow_text_selection_on_press.get_untracked() {
@@ -551,17 +648,17 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
   };
 
   let on_pointer_down: PressCallback<PointerEvent> = {
+    let is_within_element = is_within_element.clone();
     let trigger_press_start = trigger_press_start.clone();
     let on_pointer_move = on_pointer_move.clone();
     let on_pointer_cancel = on_pointer_cancel.clone();
-    // let listeners = listeners.clone();
+    let listeners = listeners.clone();
 
     let handler = move |event: PointerEvent| {
       let event_current_target: Element = event.current_target().unwrap().unchecked_into();
-      let event_target: Option<Node> = event.target().map(|target| target.unchecked_into());
 
       // Only handle left clicks, and ignore events that bubbled through portals.
-      if event.button() == 0 || !event_current_target.contains(event_target.as_ref()) {
+      if event.button() != 0 || !is_within_element(&event) {
         return;
       }
 
@@ -571,7 +668,7 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
       // https://bugs.webkit.org/show_bug.cgi?id=223202
 
       if is_virtual_pointer_event(&event) {
-        pointer_type.set_untracked(PointerType::Virtual);
+        state.update(|state| state.pointer_type = PointerType::Virtual);
         return;
       }
 
@@ -582,75 +679,87 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
         event.prevent_default();
       }
 
-      pointer_type.set_untracked(event.pointer_type().into());
+      state.update(|state| state.pointer_type = event.pointer_type().into());
       event.stop_propagation();
 
-      if is_pressed.get_untracked() {
+      if state.with(|state| state.is_pressed) {
         return;
       }
 
-      is_pressed.set_untracked(true);
-      is_over_target.set_untracked(true);
-      active_pointer_id.set_untracked(Some(event.pointer_id()));
-      target.set_untracked(Some(event_current_target.clone()));
+      state.update(|state| {
+        state.is_pressed = true;
+        state.is_over_target = true;
+        state.active_pointer_id = Some(event.pointer_id());
+        state.target = Some(event_current_target.clone());
+      });
 
       if !is_disabled.get_untracked() || !prevent_focus_on_press.get_untracked() {
         focus_without_scrolling(cx, &event_current_target);
       }
 
       if allow_text_selection_on_press.get_untracked() {
-        disable_text_selection(cx, &target.get_untracked());
+        disable_text_selection(cx, &state.with(|state| state.target.clone()));
       }
 
       let focusable_event = FocusableEvent::Pointer(event, None);
-      trigger_press_start(&focusable_event, pointer_type.get_untracked());
-
-      let pointer_move_function = {
-        let on_pointer_move = on_pointer_move.clone();
-        let callback = move |event: PointerEvent| {
-          on_pointer_move(event);
-        };
-        Closure::wrap(Box::new(callback) as Box<dyn Fn(PointerEvent)>)
-          .as_ref()
-          .unchecked_ref::<Function>()
-          .clone()
-      };
-
-      let pointer_up_function = {
-        let on_pointer_up = global_on_pointer_up.clone();
-        let callback = move |event: PointerEvent| {
-          on_pointer_up(event);
-        };
-        Closure::wrap(Box::new(callback) as Box<dyn Fn(PointerEvent)>)
-          .as_ref()
-          .unchecked_ref::<Function>()
-          .clone()
-      };
-
-      let pointer_cancel_function = {
-        let on_pointer_cancel = on_pointer_cancel.clone();
-        let callback = move |event: PointerEvent| {
-          on_pointer_cancel(event);
-        };
-        Closure::wrap(Box::new(callback) as Box<dyn Fn(PointerEvent)>)
-          .as_ref()
-          .unchecked_ref::<Function>()
-          .clone()
-      };
+      trigger_press_start(
+        &focusable_event,
+        state.with(|state| state.pointer_type.clone()),
+      );
 
-      let mut global_listener = listeners.write().unwrap();
-      global_listener.add_listener(document(), "pointermove", pointer_move_function, false);
-      global_listener.add_listener(document(), "pointerup", pointer_up_function, false);
-      global_listener.add_listener(document(), "pointercancel", pointer_cancel_function, false);
+      // The pointer can be released outside of the element, so listen on the
+      // document for the rest of the press.
+      let mut listeners = listeners.write().unwrap();
+      let on_pointer_move = on_pointer_move.clone();
+      listeners.add(DelegatedEvent::PointerMove, move |event: PointerEvent| {
+        on_pointer_move(event)
+      });
+      let global_on_pointer_up = global_on_pointer_up.clone();
+      listeners.add(DelegatedEvent::PointerUp, move |event: PointerEvent| {
+        global_on_pointer_up(event)
+      });
+      let on_pointer_cancel = on_pointer_cancel.clone();
+      listeners.add(DelegatedEvent::PointerCancel, move |event: PointerEvent| {
+        on_pointer_cancel(event)
+      });
     };
 
     Rc::new(Box::new(handler))
   };
 
+  // The pointer or key may be released while the tab is hidden or another
+  // window has focus, without the target ever receiving the event, so the
+  // press is canceled instead of being left stuck.
+  {
+    let cancel = cancel.clone();
+    let is_document_visible = use_document_visibility(cx);
+    let has_window_focus = use_window_focus(cx);
+
+    create_effect(cx, move |_| {
+      if is_document_visible.get() && has_window_focus.get() {
+        return;
+      }
+
+      let target = state.with(|state| state.target.clone().filter(|_| state.is_pressed));
+
+      if let Some(target) = target {
+        let cancel = cancel.clone();
+        cancel_with_synthetic_event(&target, move |event: &FocusableEvent| cancel(event));
+      }
+    });
+  }
+
+  // The delegated handlers reference the listeners, so remove them when the
+  // scope is disposed during a press.
+  on_cleanup(cx, move || {
+    listeners.write().unwrap().remove_all_listeners()
+  });
+
   let (press_result, _) = create_signal(
     cx,
     PressResult {
       is_pressed: derived_is_pressed,
+      pointer_type: pointer_type.into(),
       is_disabled,
       prevent_focus_on_press,
       should_cancel_on_pointer_exit,
@@ -670,6 +779,91 @@ pub fn use_press(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
   press_result
 }
 
+/// The inert press result rendered on the server. The press state can't change
+/// without user interaction so only the controlled props are reflected.
+fn use_press_ssr(cx: Scope, props: UsePressProps) -> ReadSignal<PressResult> {
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let original_is_pressed = props.is_pressed.unwrap_or(false.into());
+  let original_prevent_focus_on_press = props.prevent_focus_on_press.unwrap_or(false.into());
+  let original_should_cancel_on_pointer_exit =
+    props.should_cancel_on_pointer_exit.unwrap_or(false.into());
+  let original_allow_text_selection_on_press =
+    props.allow_text_selection_on_press.unwrap_or(false.into());
+
+  let (press_result, _) = create_signal(
+    cx,
+    PressResult {
+      is_pressed: (move || original_is_pressed.get()).derive_signal(cx),
+      pointer_type: (|| PointerType::Unsupported).derive_signal(cx),
+      is_disabled: (move || original_is_disabled.get()).derive_signal(cx),
+      prevent_focus_on_press: (move || original_prevent_focus_on_press.get()).derive_signal(cx),
+      should_cancel_on_pointer_exit: (move || original_should_cancel_on_pointer_exit.get())
+        .derive_signal(cx),
+      allow_text_selection_on_press: (move || original_allow_text_selection_on_press.get())
+        .derive_signal(cx),
+      on_click: noop_callback(),
+      on_drag_start: noop_callback(),
+      on_key_down: noop_callback(),
+      on_key_up: noop_callback(),
+      on_mouse_down: noop_callback(),
+      on_pointer_down: noop_callback(),
+      on_pointer_enter: noop_callback(),
+      on_pointer_leave: noop_callback(),
+      on_pointer_up: noop_callback(),
+    },
+  );
+
+  press_result
+}
+
+fn noop_callback<E>() -> PressCallback<E> {
+  Rc::new(Box::new(|_| {}))
+}
+
+/// The state of the press interaction. It is only read by the event handlers
+/// so it isn't reactive, other than the `is_pressed` and `pointer_type` of
+/// [`PressResult`].
+#[derive(Clone)]
+struct PressState {
+  is_pressed: bool,
+  ignore_emulated_mouse_events: bool,
+  ignore_click_after_press: bool,
+  did_fire_press_start: bool,
+  active_pointer_id: Option<i32>,
+  target: Option<Element>,
+  is_over_target: bool,
+  pointer_type: PointerType,
+  /// The traced press, with the `tracing` feature.
+  span: InteractionSpan,
+}
+
+impl Default for PressState {
+  fn default() -> Self {
+    Self {
+      is_pressed: false,
+      ignore_emulated_mouse_events: false,
+      ignore_click_after_press: false,
+      did_fire_press_start: false,
+      active_pointer_id: None,
+      target: None,
+      is_over_target: false,
+      pointer_type: PointerType::Unsupported,
+      span: InteractionSpan::default(),
+    }
+  }
+}
+
+impl PressState {
+  /// Reset the state once the press ends. The target is kept for the events
+  /// which follow the press, e.g. `click`.
+  fn reset(&mut self) {
+    self.is_pressed = false;
+    self.is_over_target = false;
+    self.active_pointer_id = None;
+    self.pointer_type = PointerType::Unsupported;
+  }
+}
+
 type BoxedPressCallback = Box<dyn Fn(&PressEvent)>;
 type WrappedPressCallback = Rc<BoxedPressCallback>;
 type WrappedPressChangeCallback = Rc<Box<dyn Fn(bool)>>;
@@ -680,6 +874,9 @@ pub struct PressResult {
   pub allow_text_selection_on_press: Signal<bool>,
   pub is_disabled: Signal<bool>,
   pub is_pressed: Signal<bool>,
+  /// The type of pointer of the current press, or `Unsupported` when the
+  /// element isn't pressed.
+  pub pointer_type: Signal<PointerType>,
   pub prevent_focus_on_press: Signal<bool>,
   pub should_cancel_on_pointer_exit: Signal<bool>,
   pub on_click: PressCallback<MouseEvent>,
@@ -693,6 +890,22 @@ pub struct PressResult {
   pub on_pointer_up: PressCallback<PointerEvent>,
 }
 
+/// Cancel the press with a synthetic `pointercancel` event, which is
+/// dispatched on the target so that the press events have a current target.
+fn cancel_with_synthetic_event(target: &Element, cancel: impl Fn(&FocusableEvent) + 'static) {
+  let Ok(event) = PointerEvent::new("pointercancel") else {
+    return;
+  };
+
+  let _listener = add_event_listener(
+    target,
+    "pointercancel",
+    move |event: PointerEvent| cancel(&FocusableEvent::Pointer(event, None)),
+    false,
+  );
+  let _ = target.dispatch_event(&event);
+}
+
 fn call_event<E>(callback: &Option<PressCallback<E>>, event: E) {
   if let Some(ref callback) = callback {
     let cb = callback.clone();
@@ -700,29 +913,8 @@ fn call_event<E>(callback: &Option<PressCallback<E>>, event: E) {
   }
 }
 
-fn are_rectangles_overlapping(dom_rect: &DomRect, rects: &Vec<Rect>) -> bool {
-  let mut is_overlapping = false;
-
-  for rect in rects {
-    // check if they cannot overlap on x axis
-    if dom_rect.left() > rect.right || dom_rect.right() < rect.left {
-      continue;
-    }
-
-    // check if they cannot overlap on y axis
-    if dom_rect.top() > rect.bottom || dom_rect.bottom() < rect.top {
-      continue;
-    }
-
-    is_overlapping = true;
-    break;
-  }
-
-  is_overlapping
-}
-
 fn is_above_target(point: &impl GetRects, target: &Element) -> bool {
-  let rect = target.get_bounding_client_rect();
+  let rect = Rect::from(&target.get_bounding_client_rect());
   let point_rects = point.get_rects();
   are_rectangles_overlapping(&rect, &point_rects)
 }
@@ -734,72 +926,6 @@ fn should_prevent_default(target: impl AsRef<Element>) -> bool {
   !element.is_instance_of::<HtmlElement>() || !element.unchecked_ref::<HtmlElement>().draggable()
 }
 
-fn should_prevent_default_keyboard(target: impl AsRef<Element>, key: String) -> bool {
-  let element = target.as_ref();
-
-  if element.is_instance_of::<HtmlInputElement>() {
-    !is_valid_input_key(element.unchecked_ref(), key)
-  } else if element.is_instance_of::<HtmlButtonElement>() {
-    element.unchecked_ref::<HtmlButtonElement>().type_() == "submit"
-  } else {
-    true
-  }
-}
-
-fn is_valid_keyboard_event(
-  event: impl AsRef<KeyboardEvent>,
-  current_target: impl AsRef<Element>,
-) -> bool {
-  let event = event.as_ref();
-  let current_target = current_target.as_ref();
-  let key = event.key();
-  let code = event.code();
-  let element = current_target.unchecked_ref::<HtmlElement>();
-
-  let role = element.get_attribute("role");
-
-  // Accessibility for keyboards. Space and Enter only.
-  (key == "Enter" || key == " " || code == "Space")
-    && !((element.is_instance_of::<HtmlInputElement>()
-      && !{
-        is_valid_input_key(element.unchecked_ref(), &key)
-      })
-      || element.is_instance_of::<HtmlTextAreaElement>()
-      || element.is_content_editable())
-  // A link with a valid href should be handled natively,
-  // unless is also has `role="button"` and was triggered using `Space`.
-  && (!is_html_anchor_link(element) || (role.as_ref().map_or(false, |role| role == "button" )&& key != "Enter"))
-  && !(role.as_ref().map_or(false, |role| role == "link") && key != "Enter")
-}
-
-fn is_html_anchor_link(target: impl AsRef<Element>) -> bool {
-  let element = target.as_ref();
-  element.is_instance_of::<HtmlAnchorElement>()
-    || (element.tag_name() == "A" && element.has_attribute("href"))
-}
-
-fn has_link_role(target: impl AsRef<Element>) -> bool {
-  let element = target.as_ref();
-  is_html_anchor_link(element)
-    || element
-      .get_attribute("role")
-      .as_ref()
-      .map_or(false, |role| role == "link")
-}
-
-fn is_valid_input_key(target: &HtmlInputElement, key: impl AsRef<str>) -> bool {
-  // Only space should toggle checkboxes and radios, not enter.
-  if target.type_() == "checkbox" || target.type_() == "radio" {
-    key.as_ref() == " "
-  } else {
-    NON_TEXT_INPUT_TYPES.contains(&target.type_().as_str())
-  }
-}
-
-const NON_TEXT_INPUT_TYPES: &[&str; 9] = &[
-  "checkbox", "radio", "range", "color", "file", "image", "button", "submit", "reset",
-];
-
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Rect {
   pub top: f64,
@@ -808,6 +934,17 @@ pub struct Rect {
   pub left: f64,
 }
 
+impl From<&DomRect> for Rect {
+  fn from(dom_rect: &DomRect) -> Self {
+    Self {
+      top: dom_rect.top(),
+      right: dom_rect.right(),
+      bottom: dom_rect.bottom(),
+      left: dom_rect.left(),
+    }
+  }
+}
+
 pub trait GetRects {
   fn get_rects(&self) -> Vec<Rect>;
 }
@@ -1104,11 +1241,27 @@ pub struct UsePressProps {
   /// Whether text selection should be enabled on the pressable element.
   #[builder(default, setter(strip_option, into))]
   pub allow_text_selection_on_press: Option<MaybeSignal<bool>>,
+
+  /// The haptic feedback played when a touch press starts. Respects the
+  /// [`HapticsContext`](crate::HapticsContext).
+  #[builder(default, setter(strip_option))]
+  pub haptics: Option<HapticFeedback>,
+
+  /// The haptic feedback played once a touch press has been held for
+  /// [`LONG_PRESS_THRESHOLD`] without ending, e.g. to signal that a draggable
+  /// item was picked up. Respects the
+  /// [`HapticsContext`](crate::HapticsContext).
+  #[builder(default, setter(strip_option))]
+  pub long_press_haptics: Option<HapticFeedback>,
+
+  /// The element the press handlers are attached to, e.g. the element of a
+  /// `NodeRef`. Events which reach the handlers without originating within
+  /// it, such as events forwarded from portal content, are ignored. Events
+  /// from within its shadow roots are still handled.
+  #[builder(default, setter(strip_option))]
+  pub element: Option<Box<dyn Fn() -> Option<Element>>>,
   // /// The children of this provider.
   // /// pub children: Box<dyn FnOnce(Scope) -> Fragment>,
-  // /// The ref.
-  // #[builder(setter(into))]
-  // pub _ref: NodeRef<AnyElement>,
 }
 
 #[derive(TypedBuilder, Clone, Debug)]
@@ -1180,6 +1333,20 @@ pub enum PointerType {
   Virtual,
 }
 
+impl PointerType {
+  /// The pointer type as reported by `PointerEvent.pointerType`.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Mouse => "mouse",
+      Self::Pen => "pen",
+      Self::Touch => "touch",
+      Self::Keyboard => "keyboard",
+      Self::Virtual => "virtual",
+      Self::Unsupported => "",
+    }
+  }
+}
+
 impl From<&str> for PointerType {
   fn from(value: &str) -> Self {
     match value {
@@ -1207,6 +1374,7 @@ impl From<PointerEvent> for PointerType {
 
 #[cfg(test)]
 mod tests {
+  use leptos::wasm_bindgen::prelude::Closure;
   use leptos::*;
   use wasm_bindgen_test::*;
 
@@ -1254,4 +1422,83 @@ mod tests {
     assert_eq!(button.inner_html(), "Example");
     button.click();
   }
+
+  /// Add the pointer down handler of a press attached to `element` to
+  /// `listener`, dispatch a pointer down from `source` and record whether the
+  /// press started.
+  fn press_starts(listener: &Element, source: &Element, element: &Element) -> bool {
+    let started = Rc::new(std::cell::Cell::new(false));
+    let listener = listener.clone();
+    let source = source.clone();
+    let element = element.clone();
+
+    run_scope(create_runtime(), {
+      let started = started.clone();
+      move |cx| {
+        let props = UsePressProps::builder()
+          .on_press_start(Box::new(move |_: &PressEvent| started.set(true)))
+          .element(Box::new(move || Some(element.clone())))
+          .build();
+        let press = use_press(cx, props).get_untracked();
+        let callback = Closure::wrap(Box::new(move |event: PointerEvent| {
+          (press.on_pointer_down)(event);
+        }) as Box<dyn Fn(PointerEvent)>);
+
+        listener
+          .add_event_listener_with_callback("pointerdown", callback.as_ref().unchecked_ref())
+          .unwrap();
+
+        let mut init = web_sys::PointerEventInit::new();
+        init
+          .bubbles(true)
+          .composed(true)
+          .pointer_id(1)
+          .pointer_type("mouse")
+          .width(1.0)
+          .height(1.0)
+          .pressure(0.5);
+        let event = PointerEvent::new_with_event_init_dict("pointerdown", &init).unwrap();
+        source.dispatch_event(&event).unwrap();
+
+        listener
+          .remove_event_listener_with_callback("pointerdown", callback.as_ref().unchecked_ref())
+          .unwrap();
+      }
+    });
+
+    started.get()
+  }
+
+  #[wasm_bindgen_test]
+  fn press_through_shadow_root() {
+    let host = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&host).unwrap();
+    let shadow_root = host
+      .attach_shadow(&web_sys::ShadowRootInit::new(web_sys::ShadowRootMode::Open))
+      .unwrap();
+    let button = document().create_element("button").unwrap();
+    shadow_root.append_child(&button).unwrap();
+
+    // The handlers are attached to the host, which the event is retargeted to.
+    assert!(press_starts(&host, &button, &host));
+
+    host.remove();
+  }
+
+  #[wasm_bindgen_test]
+  fn press_through_portal_is_ignored() {
+    let trigger = document().create_element("div").unwrap();
+    let portal = document().create_element("div").unwrap();
+    let body = document().body().unwrap();
+    body.append_child(&trigger).unwrap();
+    body.append_child(&portal).unwrap();
+
+    // The portal forwards its events to the press handlers of the trigger,
+    // the way they would bubble through the component tree.
+    assert!(!press_starts(&portal, &portal, &trigger));
+    assert!(press_starts(&trigger, &trigger, &trigger));
+
+    trigger.remove();
+    portal.remove();
+  }
 }
@@ -0,0 +1,324 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::create_effect;
+use leptos::create_rw_signal;
+use leptos::document;
+use leptos::on_cleanup;
+use leptos::set_timeout;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::trace_interaction;
+use leptos_aria_utils::DelegatedEvent;
+use leptos_aria_utils::DelegatedListeners;
+use leptos_aria_utils::InteractionKind;
+
+use crate::Rect;
+
+/// The time in milliseconds the pointer can rest inside the safe triangle
+/// before the pointer is no longer considered to be moving toward the
+/// submenu.
+pub const SAFE_TRIANGLE_TIMEOUT: u64 = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+  pub x: f64,
+  pub y: f64,
+}
+
+/// Tracks whether the pointer is moving toward a submenu.
+///
+/// The triangle spans from the previous pointer position to the two corners of
+/// the submenu edge which faces the pointer. While the pointer stays within
+/// this triangle it is heading toward the submenu, even when it crosses other
+/// items on the way.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SafeTriangle {
+  previous: Option<Point>,
+}
+
+impl SafeTriangle {
+  /// Record a pointer movement and return whether the pointer is moving
+  /// toward the target.
+  pub fn update(&mut self, point: Point, target: Rect) -> bool {
+    let previous = self.previous.replace(point);
+
+    let Some(previous) = previous else {
+      return false;
+    };
+
+    if is_point_in_rect(point, target) || point == previous {
+      return false;
+    }
+
+    let (first, second) = if previous.x <= target.left {
+      (
+        Point {
+          x: target.left,
+          y: target.top,
+        },
+        Point {
+          x: target.left,
+          y: target.bottom,
+        },
+      )
+    } else if previous.x >= target.right {
+      (
+        Point {
+          x: target.right,
+          y: target.top,
+        },
+        Point {
+          x: target.right,
+          y: target.bottom,
+        },
+      )
+    } else {
+      // The pointer is above or below the submenu.
+      let y = if previous.y <= target.top {
+        target.top
+      } else {
+        target.bottom
+      };
+
+      (Point { x: target.left, y }, Point { x: target.right, y })
+    };
+
+    is_point_in_triangle(point, (previous, first, second))
+  }
+
+  /// Forget the previous pointer position.
+  pub fn reset(&mut self) {
+    self.previous = None;
+  }
+}
+
+/// Whether the point is within the triangle, including its edges.
+pub fn is_point_in_triangle(point: Point, (a, b, c): (Point, Point, Point)) -> bool {
+  let sign =
+    |p1: Point, p2: Point, p3: Point| (p1.x - p3.x) * (p2.y - p3.y) - (p2.x - p3.x) * (p1.y - p3.y);
+
+  let d1 = sign(point, a, b);
+  let d2 = sign(point, b, c);
+  let d3 = sign(point, c, a);
+
+  let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+  let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+
+  !(has_negative && has_positive)
+}
+
+fn is_point_in_rect(point: Point, rect: Rect) -> bool {
+  point.x >= rect.left && point.x <= rect.right && point.y >= rect.top && point.y <= rect.bottom
+}
+
+#[derive(TypedBuilder)]
+pub struct UseSafeTriangleProps {
+  /// The id of the submenu or hover card the pointer is moving toward.
+  #[builder(setter(into))]
+  pub target_id: MaybeSignal<String>,
+
+  /// Whether the submenu is open. The pointer is only tracked while open.
+  #[builder(setter(into))]
+  pub is_open: MaybeSignal<bool>,
+
+  /// The time in milliseconds the pointer can rest before it is no longer
+  /// considered to be moving toward the submenu. Defaults to
+  /// [`SAFE_TRIANGLE_TIMEOUT`].
+  #[builder(default, setter(strip_option))]
+  pub timeout: Option<u64>,
+}
+
+#[derive(Clone)]
+pub struct SafeTriangleResult {
+  /// Whether the pointer is moving toward the submenu. Hover handlers of the
+  /// parent items should not close the submenu or open a sibling while this
+  /// is `true`.
+  pub is_moving_toward_target: Signal<bool>,
+}
+
+/// Tracks the pointer while a submenu or hover card is open so that moving the
+/// mouse diagonally from the parent item toward it doesn't close it
+/// prematurely.
+///
+/// ```rust,ignore
+/// let safe_triangle = use_safe_triangle(cx, UseSafeTriangleProps::builder()
+///   .target_id(submenu_id)
+///   .is_open(is_submenu_open)
+///   .build());
+///
+/// let on_pointer_enter = move |_| {
+///   if !safe_triangle.is_moving_toward_target.get_untracked() {
+///     open_submenu_for_this_item();
+///   }
+/// };
+/// ```
+pub fn use_safe_triangle(cx: Scope, props: UseSafeTriangleProps) -> SafeTriangleResult {
+  let is_moving_toward_target = create_rw_signal(cx, false);
+  let result = SafeTriangleResult {
+    is_moving_toward_target: is_moving_toward_target.into(),
+  };
+
+  // Pointer movements only happen in the browser.
+  if is_server() {
+    return result;
+  }
+
+  let original_target_id = props.target_id;
+  let target_id = (move || original_target_id.get()).derive_signal(cx);
+  let original_is_open = props.is_open;
+  let is_open = (move || original_is_open.get()).derive_signal(cx);
+  let timeout = props.timeout.unwrap_or(SAFE_TRIANGLE_TIMEOUT);
+  let triangle = Rc::new(RefCell::new(SafeTriangle::default()));
+  let generation = Rc::new(Cell::new(0_usize));
+  let listeners = Rc::new(RefCell::new(DelegatedListeners::default()));
+
+  let on_pointer_move = {
+    let triangle = triangle.clone();
+
+    move |event: PointerEvent| {
+      if event.pointer_type() == "touch" {
+        return;
+      }
+
+      let Some(target) = document().get_element_by_id(&target_id.get_untracked()) else {
+        return;
+      };
+
+      let rect = target.get_bounding_client_rect();
+      let point = Point {
+        x: event.client_x().into(),
+        y: event.client_y().into(),
+      };
+      let is_moving = triangle.borrow_mut().update(
+        point,
+        Rect {
+          top: rect.top(),
+          right: rect.right(),
+          bottom: rect.bottom(),
+          left: rect.left(),
+        },
+      );
+
+      if is_moving_toward_target.get_untracked() != is_moving {
+        trace_interaction(
+          InteractionKind::Hover,
+          if is_moving {
+            "safe_triangle_start"
+          } else {
+            "safe_triangle_end"
+          },
+          Some(&target),
+        );
+        is_moving_toward_target.set(is_moving);
+      }
+
+      if !is_moving {
+        return;
+      }
+
+      // Stop protecting the submenu once the pointer rests, so the user can
+      // still move to a sibling item.
+      let current = generation.get() + 1;
+      generation.set(current);
+      let generation = generation.clone();
+
+      set_timeout(
+        move || {
+          if generation.get() == current {
+            trace_interaction(InteractionKind::Hover, "safe_triangle_rest", None);
+            is_moving_toward_target.set(false);
+          }
+        },
+        Duration::from_millis(timeout),
+      );
+    }
+  };
+
+  let on_pointer_move = Rc::new(on_pointer_move);
+
+  {
+    let listeners = listeners.clone();
+
+    create_effect(cx, move |_| {
+      let mut listeners = listeners.borrow_mut();
+      listeners.remove_all_listeners();
+      triangle.borrow_mut().reset();
+
+      if is_open.get() {
+        let on_pointer_move = on_pointer_move.clone();
+        listeners.add(DelegatedEvent::PointerMove, move |event: PointerEvent| {
+          on_pointer_move(event)
+        });
+      } else if is_moving_toward_target.get_untracked() {
+        is_moving_toward_target.set(false);
+      }
+    });
+  }
+
+  on_cleanup(cx, move || listeners.borrow_mut().remove_all_listeners());
+
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn submenu() -> Rect {
+    Rect {
+      top: 0.0,
+      right: 300.0,
+      bottom: 200.0,
+      left: 200.0,
+    }
+  }
+
+  #[test]
+  fn moving_diagonally_toward_the_submenu() {
+    let mut triangle = SafeTriangle::default();
+
+    assert!(!triangle.update(Point { x: 100.0, y: 150.0 }, submenu()));
+    assert!(triangle.update(Point { x: 120.0, y: 140.0 }, submenu()));
+    assert!(triangle.update(Point { x: 150.0, y: 150.0 }, submenu()));
+  }
+
+  #[test]
+  fn moving_away_from_the_submenu() {
+    let mut triangle = SafeTriangle::default();
+
+    triangle.update(Point { x: 100.0, y: 150.0 }, submenu());
+    assert!(!triangle.update(Point { x: 90.0, y: 150.0 }, submenu()));
+    assert!(!triangle.update(Point { x: 95.0, y: 260.0 }, submenu()));
+  }
+
+  #[test]
+  fn entering_the_submenu() {
+    let mut triangle = SafeTriangle::default();
+
+    triangle.update(Point { x: 190.0, y: 100.0 }, submenu());
+    assert!(!triangle.update(Point { x: 210.0, y: 100.0 }, submenu()));
+  }
+
+  #[test]
+  fn submenu_on_the_left() {
+    let mut triangle = SafeTriangle::default();
+    let submenu = Rect {
+      top: 0.0,
+      right: 100.0,
+      bottom: 200.0,
+      left: 0.0,
+    };
+
+    triangle.update(Point { x: 200.0, y: 50.0 }, submenu);
+    assert!(triangle.update(Point { x: 180.0, y: 60.0 }, submenu));
+  }
+}
@@ -0,0 +1,311 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+use leptos::document;
+use leptos::web_sys::Element;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::Node;
+use leptos::JsCast;
+use leptos_aria_utils::add_event_listener;
+use leptos_aria_utils::EventListenerHandle;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::Role;
+
+/// The role of a landmark region.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LandmarkRole {
+  Banner,
+  Complementary,
+  ContentInfo,
+  Form,
+  Main,
+  Navigation,
+  Region,
+  Search,
+}
+
+impl LandmarkRole {
+  /// The value of the `role` attribute.
+  pub fn as_str(&self) -> &'static str {
+    Role::from(*self).as_str()
+  }
+}
+
+impl From<LandmarkRole> for Role {
+  fn from(role: LandmarkRole) -> Self {
+    match role {
+      LandmarkRole::Banner => Role::Banner,
+      LandmarkRole::Complementary => Role::Complementary,
+      LandmarkRole::ContentInfo => Role::ContentInfo,
+      LandmarkRole::Form => Role::Form,
+      LandmarkRole::Main => Role::Main,
+      LandmarkRole::Navigation => Role::Navigation,
+      LandmarkRole::Region => Role::Region,
+      LandmarkRole::Search => Role::Search,
+    }
+  }
+}
+
+/// A landmark registered with [`use_landmark`](crate::use_landmark).
+struct LandmarkEntry {
+  id: String,
+  role: LandmarkRole,
+  label: Option<String>,
+  /// The element which was last focused within the landmark, restored when
+  /// navigating back to it.
+  last_focused: Option<Element>,
+}
+
+thread_local! {
+  static LANDMARKS: RefCell<Vec<LandmarkEntry>> = RefCell::new(vec![]);
+
+  /// The document listener which handles <kbd>F6</kbd>, added while there
+  /// are registered landmarks.
+  static KEY_DOWN_LISTENER: RefCell<Option<EventListenerHandle>> = RefCell::new(None);
+}
+
+pub(crate) fn register_landmark(id: &str, role: LandmarkRole, label: Option<String>) {
+  LANDMARKS.with(|landmarks| {
+    landmarks.borrow_mut().push(LandmarkEntry {
+      id: id.to_string(),
+      role,
+      label,
+      last_focused: None,
+    });
+  });
+
+  add_key_down_listener();
+  warn_on_duplicate_landmarks(role);
+}
+
+pub(crate) fn unregister_landmark(id: &str) {
+  let is_empty = LANDMARKS.with(|landmarks| {
+    let mut landmarks = landmarks.borrow_mut();
+    landmarks.retain(|landmark| landmark.id != id);
+    landmarks.is_empty()
+  });
+
+  if is_empty {
+    remove_key_down_listener();
+  }
+}
+
+pub(crate) fn update_landmark_label(id: &str, label: Option<String>) {
+  let role = LANDMARKS.with(|landmarks| {
+    let mut landmarks = landmarks.borrow_mut();
+    let landmark = landmarks.iter_mut().find(|landmark| landmark.id == id)?;
+    landmark.label = label;
+    Some(landmark.role)
+  });
+
+  if let Some(role) = role {
+    warn_on_duplicate_landmarks(role);
+  }
+}
+
+pub(crate) fn set_last_focused(id: &str, element: Element) {
+  LANDMARKS.with(|landmarks| {
+    if let Some(landmark) = landmarks
+      .borrow_mut()
+      .iter_mut()
+      .find(|landmark| landmark.id == id)
+    {
+      landmark.last_focused = Some(element);
+    }
+  });
+}
+
+/// Move focus to the next landmark in document order, or the previous one
+/// when `backward` is set, wrapping around at the end. Focus returns to the
+/// element which was last focused within the landmark, or the landmark
+/// itself. Returns `true` when focus was moved.
+pub fn focus_next_landmark(backward: bool) -> bool {
+  let mut elements: Vec<(String, HtmlElement)> = LANDMARKS.with(|landmarks| {
+    landmarks
+      .borrow()
+      .iter()
+      .filter_map(|landmark| {
+        let element = document().get_element_by_id(&landmark.id)?;
+        Some((landmark.id.clone(), element.dyn_into::<HtmlElement>().ok()?))
+      })
+      .collect()
+  });
+
+  elements.sort_by(|(_, a), (_, b)| compare_document_position(a, b));
+
+  // The innermost landmark containing focus is the last one in document
+  // order.
+  let active_element: Option<Node> = document().active_element().map(Into::into);
+  let current = elements
+    .iter()
+    .rposition(|(_, element)| element.contains(active_element.as_ref()));
+
+  let Some(next) = next_landmark_index(current, elements.len(), backward) else {
+    return false;
+  };
+
+  let (id, element) = &elements[next];
+  let last_focused = LANDMARKS.with(|landmarks| {
+    landmarks
+      .borrow()
+      .iter()
+      .find(|landmark| &landmark.id == id)
+      .and_then(|landmark| landmark.last_focused.clone())
+  });
+
+  match last_focused {
+    Some(last_focused) if last_focused.is_connected() && element.contains(Some(&last_focused)) => {
+      let _ = last_focused.unchecked_into::<HtmlElement>().focus();
+    }
+    _ => {
+      if !element.has_attribute("tabindex") {
+        let _ = element.set_attribute("tabindex", "-1");
+      }
+
+      let _ = element.focus();
+    }
+  }
+
+  true
+}
+
+/// The index of the landmark to focus next. Without a current landmark the
+/// first, or the last when moving backward, is used.
+pub fn next_landmark_index(current: Option<usize>, len: usize, backward: bool) -> Option<usize> {
+  if len == 0 {
+    return None;
+  }
+
+  let next = match (current, backward) {
+    (None, false) => 0,
+    (None, true) => len - 1,
+    (Some(current), false) => (current + 1) % len,
+    (Some(current), true) => (current + len - 1) % len,
+  };
+
+  Some(next)
+}
+
+/// The roles which are used by several landmarks that can't be told apart,
+/// because at least one of them has no label or the labels are the same.
+pub fn find_duplicate_landmark_roles<'a>(
+  landmarks: impl IntoIterator<Item = (LandmarkRole, Option<&'a str>)>,
+) -> Vec<LandmarkRole> {
+  let mut by_role: Vec<(LandmarkRole, Vec<Option<&str>>)> = vec![];
+
+  for (role, label) in landmarks {
+    match by_role.iter_mut().find(|(existing, _)| *existing == role) {
+      Some((_, labels)) => labels.push(label),
+      None => by_role.push((role, vec![label])),
+    }
+  }
+
+  by_role
+    .into_iter()
+    .filter(|(_, labels)| {
+      labels.len() > 1
+        && labels
+          .iter()
+          .enumerate()
+          .any(|(index, label)| label.is_none() || labels[index + 1..].contains(label))
+    })
+    .map(|(role, _)| role)
+    .collect()
+}
+
+fn warn_on_duplicate_landmarks(role: LandmarkRole) {
+  if !cfg!(debug_assertions) {
+    return;
+  }
+
+  let is_duplicate = LANDMARKS.with(|landmarks| {
+    find_duplicate_landmark_roles(
+      landmarks
+        .borrow()
+        .iter()
+        .map(|landmark| (landmark.role, landmark.label.as_deref())),
+    )
+    .contains(&role)
+  });
+
+  if is_duplicate {
+    leptos::warn!(
+      "[use_landmark] There are multiple landmarks with the role `{}`. Give each of them a unique \
+       label so they can be told apart.",
+      role.as_str()
+    );
+  }
+}
+
+fn compare_document_position(a: &Node, b: &Node) -> Ordering {
+  if a == b {
+    Ordering::Equal
+  } else if a.compare_document_position(b) & Node::DOCUMENT_POSITION_FOLLOWING != 0 {
+    Ordering::Less
+  } else {
+    Ordering::Greater
+  }
+}
+
+fn add_key_down_listener() {
+  KEY_DOWN_LISTENER.with(|listener| {
+    let mut listener = listener.borrow_mut();
+
+    if listener.is_some() {
+      return;
+    }
+
+    let handler = |event: KeyboardEvent| {
+      if KeyboardKey::from(&event) != KeyboardKey::F(6)
+        || event.alt_key()
+        || event.ctrl_key()
+        || event.meta_key()
+      {
+        return;
+      }
+
+      if focus_next_landmark(event.shift_key()) {
+        event.prevent_default();
+        event.stop_propagation();
+      }
+    };
+
+    *listener = Some(add_event_listener(document(), "keydown", handler, false));
+  });
+}
+
+fn remove_key_down_listener() {
+  let _listener = KEY_DOWN_LISTENER.with(|listener| listener.borrow_mut().take());
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cycles_through_landmarks() {
+    assert_eq!(next_landmark_index(None, 3, false), Some(0));
+    assert_eq!(next_landmark_index(None, 3, true), Some(2));
+    assert_eq!(next_landmark_index(Some(2), 3, false), Some(0));
+    assert_eq!(next_landmark_index(Some(0), 3, true), Some(2));
+    assert_eq!(next_landmark_index(None, 0, false), None);
+  }
+
+  #[test]
+  fn detects_duplicate_roles() {
+    use LandmarkRole::*;
+
+    let duplicates = find_duplicate_landmark_roles([
+      (Main, None),
+      (Navigation, Some("Primary")),
+      (Navigation, Some("Footer")),
+      (Region, None),
+      (Region, Some("Filters")),
+      (Search, Some("Site")),
+      (Search, Some("Site")),
+    ]);
+
+    assert_eq!(duplicates, vec![Region, Search]);
+  }
+}
@@ -0,0 +1,5 @@
+pub use landmark_manager::*;
+pub use use_landmark::*;
+
+mod landmark_manager;
+mod use_landmark;
@@ -0,0 +1,117 @@
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_signal;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::FocusEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::use_id;
+
+use crate::landmark_manager::register_landmark;
+use crate::landmark_manager::set_last_focused;
+use crate::landmark_manager::unregister_landmark;
+use crate::landmark_manager::update_landmark_label;
+use crate::LandmarkRole;
+
+pub(crate) type LandmarkCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+#[derive(TypedBuilder)]
+pub struct UseLandmarkProps {
+  /// The role of the landmark.
+  pub role: LandmarkRole,
+
+  /// The id of the landmark element. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// The accessible name of the landmark, required when several landmarks
+  /// share a role.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the landmark.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+}
+
+#[derive(Clone)]
+pub struct LandmarkResult {
+  pub id: String,
+  pub role: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+  /// Remembers the focused element so focus returns to it when navigating
+  /// back to the landmark.
+  pub on_focus_in: LandmarkCallback<FocusEvent>,
+}
+
+/// Registers a landmark region so that <kbd>F6</kbd> and
+/// <kbd>Shift</kbd> + <kbd>F6</kbd> move focus between the landmarks of the
+/// page in document order.
+///
+/// A warning is logged in debug builds when several landmarks with the same
+/// role can't be told apart by their labels.
+pub fn use_landmark(cx: Scope, props: UseLandmarkProps) -> ReadSignal<LandmarkResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let role = props.role;
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  // The labelling element's id is enough to tell landmarks apart.
+  let label = move || aria_label.get().or_else(|| aria_labelledby.get());
+
+  if !is_server() {
+    register_landmark(&id, role, label());
+
+    let effect_id = id.clone();
+    create_effect(cx, move |previous: Option<()>| {
+      let label = label();
+
+      if previous.is_some() {
+        update_landmark_label(&effect_id, label);
+      }
+    });
+
+    let cleanup_id = id.clone();
+    on_cleanup(cx, move || unregister_landmark(&cleanup_id));
+  }
+
+  let on_focus_in: LandmarkCallback<FocusEvent> = {
+    let id = id.clone();
+
+    Rc::new(Box::new(move |event: FocusEvent| {
+      if let Some(target) = event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      {
+        set_last_focused(&id, target);
+      }
+    }))
+  };
+
+  let (landmark_result, _) = create_signal(
+    cx,
+    LandmarkResult {
+      id,
+      role: role.as_str(),
+      aria_label,
+      aria_labelledby,
+      on_focus_in,
+    },
+  );
+
+  landmark_result
+}
@@ -0,0 +1,7 @@
+pub use lightbox_state::*;
+pub use use_lightbox::*;
+pub use use_lightbox_trigger::*;
+
+mod lightbox_state;
+mod use_lightbox;
+mod use_lightbox_trigger;
@@ -0,0 +1,115 @@
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_id;
+
+#[derive(TypedBuilder)]
+pub struct UseLightboxStateProps {
+  /// The number of images in the collection.
+  #[builder(setter(into))]
+  pub image_count: MaybeSignal<usize>,
+
+  /// Whether navigation wraps from the last image to the first.
+  #[builder(default)]
+  pub should_wrap: bool,
+}
+
+/// Which image of a collection is shown in the lightbox.
+#[derive(Clone)]
+pub struct LightboxState {
+  pub image_count: Signal<usize>,
+  pub should_wrap: bool,
+  open_index: RwSignal<Option<usize>>,
+  id: String,
+}
+
+pub fn use_lightbox_state(cx: Scope, props: UseLightboxStateProps) -> LightboxState {
+  let original_image_count = props.image_count;
+
+  LightboxState {
+    image_count: (move || original_image_count.get()).derive_signal(cx),
+    should_wrap: props.should_wrap,
+    open_index: create_rw_signal(cx, None),
+    id: use_id(cx),
+  }
+}
+
+impl LightboxState {
+  /// The index of the shown image while the lightbox is open.
+  pub fn open_index(&self) -> Option<usize> {
+    self.open_index.get()
+  }
+
+  pub fn open_index_untracked(&self) -> Option<usize> {
+    self.open_index.get_untracked()
+  }
+
+  pub fn is_open(&self) -> bool {
+    self.open_index.with(Option::is_some)
+  }
+
+  pub fn open(&self, index: usize) {
+    if index < self.image_count.get_untracked() {
+      self.open_index.set(Some(index));
+    }
+  }
+
+  pub fn close(&self) {
+    self.open_index.set(None);
+  }
+
+  /// Whether there is an image after the shown one.
+  pub fn has_next(&self) -> bool {
+    self.should_wrap
+      || self
+        .open_index()
+        .map_or(false, |index| index + 1 < self.image_count.get())
+  }
+
+  /// Whether there is an image before the shown one.
+  pub fn has_previous(&self) -> bool {
+    self.should_wrap || self.open_index().map_or(false, |index| index > 0)
+  }
+
+  pub fn next(&self) {
+    let count = self.image_count.get_untracked();
+    let Some(index) = self.open_index.get_untracked() else {
+      return;
+    };
+
+    if index + 1 < count {
+      self.open_index.set(Some(index + 1));
+    } else if self.should_wrap && count > 0 {
+      self.open_index.set(Some(0));
+    }
+  }
+
+  pub fn previous(&self) {
+    let count = self.image_count.get_untracked();
+    let Some(index) = self.open_index.get_untracked() else {
+      return;
+    };
+
+    if index > 0 {
+      self.open_index.set(Some(index - 1));
+    } else if self.should_wrap && count > 0 {
+      self.open_index.set(Some(count - 1));
+    }
+  }
+
+  /// The id of the trigger of the image at the index. Focus returns to this
+  /// trigger when the lightbox closes.
+  pub fn trigger_id(&self, index: usize) -> String {
+    format!("{}-trigger-{index}", self.id)
+  }
+
+  /// The id of the lightbox dialog.
+  pub fn dialog_id(&self) -> String {
+    format!("{}-dialog", self.id)
+  }
+}
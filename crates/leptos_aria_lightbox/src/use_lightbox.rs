@@ -0,0 +1,301 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_signal;
+use leptos::document;
+use leptos::request_animation_frame;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_dialog::use_dialog;
+use leptos_aria_dialog::DialogResult;
+use leptos_aria_dialog::UseDialogProps;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_overlays::aria_hide_outside;
+use leptos_aria_overlays::use_overlay;
+use leptos_aria_overlays::RestoreFn;
+use leptos_aria_overlays::UnderlayResult;
+use leptos_aria_overlays::UseOverlayProps;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::Politeness;
+
+use crate::LightboxState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseLightboxProps {
+  /// The accessible name of the lightbox. Defaults to `"Image viewer"`,
+  /// translated for the locale.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// Describes the position of the shown image from its zero based index and
+  /// the number of images. Defaults to e.g. `"Image 2 of 8"`, translated for
+  /// the locale.
+  #[builder(default, setter(strip_option))]
+  pub get_position_label: Option<Box<dyn Fn(usize, usize) -> String>>,
+
+  /// The label of the previous button. Defaults to `"Previous image"`,
+  /// translated for the locale.
+  #[builder(default, setter(strip_option, into))]
+  pub previous_label: Option<MaybeSignal<String>>,
+
+  /// The label of the next button. Defaults to `"Next image"`, translated for
+  /// the locale.
+  #[builder(default, setter(strip_option, into))]
+  pub next_label: Option<MaybeSignal<String>>,
+
+  /// The label of the close button. Defaults to `"Dismiss"`, translated for
+  /// the locale.
+  #[builder(default, setter(strip_option, into))]
+  pub close_label: Option<MaybeSignal<String>>,
+}
+
+/// The props for the previous, next and close buttons.
+#[derive(Clone)]
+pub struct LightboxButtonResult {
+  pub aria_label: Signal<String>,
+  pub disabled: Signal<bool>,
+  pub on_click: LightboxCallback<MouseEvent>,
+}
+
+#[derive(Clone)]
+pub struct LightboxResult {
+  /// The props for the dialog containing the shown image.
+  pub dialog: DialogResult,
+  /// Handles navigation and dismissal. Add it to the dialog element.
+  pub on_key_down: LightboxCallback<KeyboardEvent>,
+  /// The props for the backdrop behind the dialog.
+  pub underlay: UnderlayResult,
+  /// The position of the shown image, e.g. "Image 2 of 8".
+  pub position_label: Signal<String>,
+  pub previous_button: LightboxButtonResult,
+  pub next_button: LightboxButtonResult,
+  pub close_button: LightboxButtonResult,
+}
+
+/// Provides a modal lightbox for a collection of images opened with
+/// [`use_lightbox_trigger`](crate::use_lightbox_trigger).
+///
+/// * Opening the lightbox focuses the dialog and hides the rest of the page
+///   from assistive technology. Closing it restores the page and returns focus
+///   to the trigger of the last shown image.
+/// * <kbd>Escape</kbd> and pressing outside of the dialog close it.
+/// * <kbd>ArrowLeft</kbd> and <kbd>ArrowRight</kbd> move between images and the
+///   new position is announced. The keys are swapped in right to left locales.
+pub fn use_lightbox(
+  cx: Scope,
+  state: &LightboxState,
+  props: UseLightboxProps,
+) -> ReadSignal<LightboxResult> {
+  let dialog_id = state.dialog_id();
+  let strings = use_localized_string_formatter(cx);
+  let label = |label: Option<MaybeSignal<String>>, key: &'static str| {
+    match label {
+      Some(label) => (move || label.get()).derive_signal(cx),
+      None => (move || strings.get().format(key)).derive_signal(cx),
+    }
+  };
+  let aria_label = label(props.aria_label, "imageViewer");
+  let get_position_label: Rc<dyn Fn(usize, usize) -> String> = match props.get_position_label {
+    Some(get_position_label) => Rc::new(get_position_label),
+    None => {
+      Rc::new(move |index, count| {
+        strings.get().format_with(
+          "imagePosition",
+          &[("position", (index + 1).into()), ("count", count.into())],
+        )
+      })
+    }
+  };
+
+  let dialog = use_dialog(
+    cx,
+    UseDialogProps::builder()
+      .id(dialog_id.clone())
+      .aria_label(aria_label)
+      .build(),
+  )
+  .get_untracked();
+
+  let is_open = {
+    let state = state.clone();
+    (move || state.is_open()).derive_signal(cx)
+  };
+
+  let overlay = use_overlay(
+    cx,
+    UseOverlayProps::builder()
+      .id(dialog_id.clone())
+      .is_open(is_open)
+      .is_dismissable(true)
+      .on_close(Box::new({
+        let state = state.clone();
+        move || state.close()
+      }))
+      .build(),
+  )
+  .get_untracked();
+
+  let position_label = {
+    let state = state.clone();
+    let get_position_label = get_position_label.clone();
+
+    (move || {
+      state
+        .open_index()
+        .map(|index| get_position_label(index, state.image_count.get()))
+        .unwrap_or_default()
+    })
+    .derive_signal(cx)
+  };
+
+  let restore: Rc<RefCell<Option<RestoreFn>>> = Rc::default();
+
+  {
+    let state = state.clone();
+    let dialog_id = dialog_id.clone();
+
+    create_effect(cx, move |previous: Option<Option<usize>>| {
+      let current = state.open_index();
+
+      match (previous.flatten(), current) {
+        (None, Some(index)) => {
+          announce(
+            label_at(&get_position_label, &state, index),
+            Politeness::Polite,
+          );
+          let dialog_id = dialog_id.clone();
+          let restore = restore.clone();
+
+          // Wait for the dialog to be rendered.
+          request_animation_frame(move || {
+            if let Some(dialog) = document().get_element_by_id(&dialog_id) {
+              *restore.borrow_mut() = Some(aria_hide_outside(&[dialog]));
+            }
+
+            focus_element_by_id(&dialog_id);
+          });
+        }
+        (Some(previous), Some(index)) if previous != index => {
+          announce(
+            label_at(&get_position_label, &state, index),
+            Politeness::Polite,
+          );
+        }
+        (Some(previous), None) => {
+          if let Some(restore) = restore.borrow_mut().take() {
+            restore();
+          }
+
+          focus_element_by_id(&state.trigger_id(previous));
+        }
+        _ => {}
+      }
+
+      current
+    });
+  }
+
+  let direction = use_direction(cx);
+
+  let on_key_down: LightboxCallback<KeyboardEvent> = {
+    let state = state.clone();
+    let on_overlay_key_down = overlay.overlay.on_key_down.clone();
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      match KeyboardKey::from(&event).logical(direction.get_untracked()) {
+        KeyboardKey::ArrowRight => state.next(),
+        KeyboardKey::ArrowLeft => state.previous(),
+        _ => {
+          on_overlay_key_down(event);
+          return;
+        }
+      }
+
+      event.prevent_default();
+    }))
+  };
+
+  let previous_button = {
+    let disabled = {
+      let state = state.clone();
+      (move || !state.has_previous()).derive_signal(cx)
+    };
+    let state = state.clone();
+
+    LightboxButtonResult {
+      aria_label: label(props.previous_label, "previousImage"),
+      disabled,
+      on_click: Rc::new(Box::new(move |_| state.previous())),
+    }
+  };
+
+  let next_button = {
+    let disabled = {
+      let state = state.clone();
+      (move || !state.has_next()).derive_signal(cx)
+    };
+    let state = state.clone();
+
+    LightboxButtonResult {
+      aria_label: label(props.next_label, "nextImage"),
+      disabled,
+      on_click: Rc::new(Box::new(move |_| state.next())),
+    }
+  };
+
+  let close_button = {
+    let state = state.clone();
+
+    LightboxButtonResult {
+      aria_label: label(props.close_label, "dismiss"),
+      disabled: (move || false).derive_signal(cx),
+      on_click: Rc::new(Box::new(move |_| state.close())),
+    }
+  };
+
+  let (lightbox_result, _) = create_signal(
+    cx,
+    LightboxResult {
+      dialog,
+      on_key_down,
+      underlay: overlay.underlay,
+      position_label,
+      previous_button,
+      next_button,
+      close_button,
+    },
+  );
+
+  lightbox_result
+}
+
+pub(crate) type LightboxCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+fn label_at(
+  get_position_label: &Rc<dyn Fn(usize, usize) -> String>,
+  state: &LightboxState,
+  index: usize,
+) -> String {
+  get_position_label(index, state.image_count.get_untracked())
+}
+
+fn focus_element_by_id(id: &str) {
+  if let Some(element) = document()
+    .get_element_by_id(id)
+    .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+  {
+    element.focus().ok();
+  }
+}
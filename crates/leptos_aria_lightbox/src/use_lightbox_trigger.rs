@@ -0,0 +1,69 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_interactions::use_press;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_interactions::PressResult;
+use leptos_aria_interactions::UsePressProps;
+
+use crate::LightboxState;
+
+#[derive(TypedBuilder)]
+pub struct UseLightboxTriggerProps {
+  /// The index of the image in the collection.
+  pub index: usize,
+}
+
+#[derive(Clone)]
+pub struct LightboxTriggerResult {
+  pub id: String,
+  pub role: &'static str,
+  pub tab_index: i32,
+  pub aria_haspopup: &'static str,
+  pub aria_expanded: Signal<bool>,
+  pub press: ReadSignal<PressResult>,
+}
+
+/// Provides the props for a pressable image which opens the lightbox at its
+/// index. Focus returns to the trigger when the lightbox closes.
+pub fn use_lightbox_trigger(
+  cx: Scope,
+  state: &LightboxState,
+  props: UseLightboxTriggerProps,
+) -> ReadSignal<LightboxTriggerResult> {
+  let index = props.index;
+
+  let on_press = {
+    let state = state.clone();
+    move |_: &PressEvent| state.open(index)
+  };
+
+  let press = use_press(
+    cx,
+    UsePressProps::builder()
+      .on_press(Box::new(on_press))
+      .build(),
+  );
+
+  let aria_expanded = {
+    let state = state.clone();
+    (move || state.open_index() == Some(index)).derive_signal(cx)
+  };
+
+  let (lightbox_trigger_result, _) = create_signal(
+    cx,
+    LightboxTriggerResult {
+      id: state.trigger_id(index),
+      role: "button",
+      tab_index: 0,
+      aria_haspopup: "dialog",
+      aria_expanded,
+      press,
+    },
+  );
+
+  lightbox_trigger_result
+}
@@ -0,0 +1,11 @@
+pub use link_router::*;
+pub use open_link::*;
+pub use use_link::*;
+pub use use_route_announcer::*;
+pub use use_skip_link::*;
+
+mod link_router;
+mod open_link;
+mod use_link;
+mod use_route_announcer;
+mod use_skip_link;
@@ -0,0 +1,207 @@
+use std::rc::Rc;
+
+use leptos::provide_context;
+use leptos::use_context;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::MouseEvent;
+use leptos::Scope;
+
+/// Navigates to the provided href without a full page load, e.g. with the
+/// `navigate` function from `leptos_router`.
+pub type NavigateCallback = Rc<dyn Fn(&str)>;
+
+/// The client side router used by links within the scope. Provided with
+/// [`provide_link_router`].
+#[derive(Clone)]
+pub struct LinkRouter {
+  pub navigate: NavigateCallback,
+}
+
+/// Use the provided callback to navigate when a link within the scope is
+/// activated, instead of letting the browser load the page.
+///
+/// ```ignore
+/// let navigate = leptos_router::use_navigate(cx);
+/// provide_link_router(cx, move |href| {
+///   let _ = navigate(href, Default::default());
+/// });
+/// ```
+pub fn provide_link_router(cx: Scope, navigate: impl Fn(&str) + 'static) {
+  provide_context(
+    cx,
+    LinkRouter {
+      navigate: Rc::new(navigate),
+    },
+  );
+}
+
+/// The router provided by a parent scope.
+pub fn use_link_router(cx: Scope) -> Option<LinkRouter> {
+  use_context::<LinkRouter>(cx)
+}
+
+/// The modifier keys and the mouse button used to activate a link.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LinkModifiers {
+  pub meta_key: bool,
+  pub ctrl_key: bool,
+  pub shift_key: bool,
+  pub alt_key: bool,
+  /// The mouse button, `0` for the main button and `1` for the middle button.
+  pub button: i16,
+}
+
+impl LinkModifiers {
+  /// Whether the browser opens the link elsewhere, in a new tab or window or
+  /// as a download, rather than in the current page.
+  ///
+  /// The new tab modifier is `meta` on Apple devices and `ctrl` elsewhere.
+  pub fn opens_elsewhere(&self, is_apple_device: bool) -> bool {
+    let new_tab_key = if is_apple_device {
+      self.meta_key
+    } else {
+      self.ctrl_key
+    };
+
+    new_tab_key || self.shift_key || self.alt_key || self.button != 0
+  }
+}
+
+impl From<&MouseEvent> for LinkModifiers {
+  fn from(event: &MouseEvent) -> Self {
+    Self {
+      meta_key: event.meta_key(),
+      ctrl_key: event.ctrl_key(),
+      shift_key: event.shift_key(),
+      alt_key: event.alt_key(),
+      button: event.button(),
+    }
+  }
+}
+
+impl From<&KeyboardEvent> for LinkModifiers {
+  /// The modifiers of a key press, e.g. `Enter` on a focused row.
+  fn from(event: &KeyboardEvent) -> Self {
+    Self {
+      meta_key: event.meta_key(),
+      ctrl_key: event.ctrl_key(),
+      shift_key: event.shift_key(),
+      alt_key: event.alt_key(),
+      button: 0,
+    }
+  }
+}
+
+/// Whether the link should be handled by the client side router. Links which
+/// open in another browsing context, downloads and links to other origins are
+/// left to the browser.
+pub fn should_client_navigate(
+  href: &str,
+  target: Option<&str>,
+  is_download: bool,
+  origin: &str,
+) -> bool {
+  if is_download || !matches!(target, None | Some("") | Some("_self")) {
+    return false;
+  }
+
+  let is_absolute = href.starts_with("//") || href.contains("://");
+
+  if !is_absolute {
+    // Relative links, but not `mailto:` and other schemes.
+    return !href
+      .split_once(':')
+      .map_or(false, |(scheme, _)| !scheme.contains(['/', '?', '#']));
+  }
+
+  let href = href.strip_prefix("//").map_or_else(
+    || href.to_string(),
+    |href| {
+      let protocol = origin.split_once("//").map_or("", |(protocol, _)| protocol);
+      format!("{protocol}//{href}")
+    },
+  );
+
+  href == origin
+    || href
+      .strip_prefix(origin)
+      .map_or(false, |path| path.starts_with(['/', '?', '#']))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ORIGIN: &str = "https://example.com";
+
+  #[test]
+  fn navigates_same_origin_links() {
+    assert!(should_client_navigate("/about", None, false, ORIGIN));
+    assert!(should_client_navigate(
+      "about?tab=1",
+      Some("_self"),
+      false,
+      ORIGIN
+    ));
+    assert!(should_client_navigate("#section", None, false, ORIGIN));
+    assert!(should_client_navigate(
+      "https://example.com/about",
+      None,
+      false,
+      ORIGIN
+    ));
+    assert!(should_client_navigate(
+      "//example.com/about",
+      None,
+      false,
+      ORIGIN
+    ));
+  }
+
+  #[test]
+  fn leaves_other_links_to_the_browser() {
+    assert!(!should_client_navigate(
+      "/about",
+      Some("_blank"),
+      false,
+      ORIGIN
+    ));
+    assert!(!should_client_navigate("/report.pdf", None, true, ORIGIN));
+    assert!(!should_client_navigate(
+      "https://example.org",
+      None,
+      false,
+      ORIGIN
+    ));
+    assert!(!should_client_navigate(
+      "https://example.com.evil.org/",
+      None,
+      false,
+      ORIGIN
+    ));
+    assert!(!should_client_navigate(
+      "mailto:a@example.com",
+      None,
+      false,
+      ORIGIN
+    ));
+  }
+
+  #[test]
+  fn modifiers_open_elsewhere() {
+    assert!(!LinkModifiers::default().opens_elsewhere(true));
+
+    let ctrl = LinkModifiers {
+      ctrl_key: true,
+      ..Default::default()
+    };
+    assert!(ctrl.opens_elsewhere(false));
+    assert!(!ctrl.opens_elsewhere(true));
+
+    let middle = LinkModifiers {
+      button: 1,
+      ..Default::default()
+    };
+    assert!(middle.opens_elsewhere(false));
+  }
+}
@@ -0,0 +1,165 @@
+use std::rc::Rc;
+
+use leptos::document;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::HtmlAnchorElement;
+use leptos::web_sys::MouseEvent;
+use leptos::web_sys::MouseEventInit;
+use leptos::window;
+use leptos::JsCast;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_platform;
+use leptos_aria_utils::PlatformInfo;
+
+use crate::should_client_navigate;
+use crate::use_link_router;
+use crate::LinkModifiers;
+use crate::NavigateCallback;
+
+/// A link to open with [`open_link`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, TypedBuilder)]
+pub struct LinkOptions {
+  /// The url of the link.
+  #[builder(setter(into))]
+  pub href: String,
+
+  /// Where to open the link, e.g. `_blank`.
+  #[builder(default, setter(strip_option, into))]
+  pub target: Option<String>,
+
+  /// The relationship of the linked page, e.g. `noopener noreferrer`.
+  #[builder(default, setter(strip_option, into))]
+  pub rel: Option<String>,
+
+  /// Download the linked url instead of navigating to it. The value is the
+  /// suggested file name and may be empty.
+  #[builder(default, setter(strip_option, into))]
+  pub download: Option<String>,
+}
+
+/// Navigate to the link with the client side router, when the link opens in
+/// the current page. Returns `false` when the link is left to the browser,
+/// e.g. because a modifier opens it in a new tab.
+pub fn client_navigate(
+  link: &LinkOptions,
+  modifiers: LinkModifiers,
+  navigate: Option<&NavigateCallback>,
+  platform: &PlatformInfo,
+) -> bool {
+  let Some(navigate) = navigate else {
+    return false;
+  };
+
+  let origin = window().location().origin().unwrap_or_default();
+  let is_client_link = !modifiers.opens_elsewhere(platform.is_apple_device())
+    && should_client_navigate(
+      &link.href,
+      link.target.as_deref(),
+      link.download.is_some(),
+      &origin,
+    );
+
+  if is_client_link {
+    navigate(&link.href);
+  }
+
+  is_client_link
+}
+
+/// Open the link as if the user had clicked an anchor with the modifiers,
+/// e.g. when a menu item or a table row with an `href` is activated.
+///
+/// <kbd>Cmd</kbd> on Apple devices, <kbd>Ctrl</kbd> elsewhere and the middle
+/// button open a new tab, <kbd>Shift</kbd> a new window and <kbd>Alt</kbd>
+/// downloads the link. Links which open in the current page are handed to the
+/// client side router when there is one, and the browser handles the rest
+/// natively with a temporary anchor.
+///
+/// The modifiers are resolved for the platform from
+/// [`use_platform`](leptos_aria_utils::use_platform).
+pub fn open_link(
+  link: &LinkOptions,
+  modifiers: LinkModifiers,
+  navigate: Option<&NavigateCallback>,
+  platform: &PlatformInfo,
+) {
+  if !client_navigate(link, modifiers, navigate, platform) {
+    open_with_anchor(link, modifiers, platform);
+  }
+}
+
+/// [`open_link`] with the router provided by
+/// [`provide_link_router`](crate::provide_link_router).
+///
+/// ```ignore
+/// let open = use_open_link(cx);
+/// let on_action = move |event: KeyboardEvent| {
+///   open(&LinkOptions::builder().href(row.href.clone()).build(), LinkModifiers::from(&event));
+/// };
+/// ```
+pub fn use_open_link(cx: Scope) -> Rc<dyn Fn(&LinkOptions, LinkModifiers)> {
+  let navigate = use_link_router(cx).map(|router| router.navigate);
+  let platform = use_platform(cx);
+
+  Rc::new(move |link: &LinkOptions, modifiers: LinkModifiers| {
+    open_link(
+      link,
+      modifiers,
+      navigate.as_ref(),
+      &platform.get_untracked(),
+    );
+  })
+}
+
+/// Open the link with a temporary anchor so the browser applies its native
+/// behavior for the modifier keys, e.g. opening a new tab.
+pub(crate) fn open_with_anchor(
+  link: &LinkOptions,
+  modifiers: LinkModifiers,
+  platform: &PlatformInfo,
+) {
+  let Ok(anchor) = document().create_element("a") else {
+    return;
+  };
+  let anchor: HtmlAnchorElement = anchor.unchecked_into();
+  anchor.set_href(&link.href);
+
+  if let Some(ref target) = link.target {
+    anchor.set_target(target);
+  }
+
+  if let Some(ref rel) = link.rel {
+    anchor.set_rel(rel);
+  }
+
+  if let Some(ref download) = link.download {
+    anchor.set_download(download);
+  }
+
+  // A synthetic middle click is ignored, so it's opened with the new tab
+  // modifier instead.
+  let is_middle_click = modifiers.button == 1;
+  let is_apple_device = platform.is_apple_device();
+  let mut init = MouseEventInit::new();
+  init
+    .bubbles(true)
+    .cancelable(true)
+    .meta_key(modifiers.meta_key || (is_middle_click && is_apple_device))
+    .ctrl_key(modifiers.ctrl_key || (is_middle_click && !is_apple_device))
+    .shift_key(modifiers.shift_key)
+    .alt_key(modifiers.alt_key);
+
+  let Ok(event) = MouseEvent::new_with_mouse_event_init_dict("click", &init) else {
+    return;
+  };
+
+  let Some(body) = document().body() else {
+    return;
+  };
+
+  if body.append_child(&anchor).is_ok() {
+    let _ = anchor.dispatch_event(&event);
+    anchor.remove();
+  }
+}
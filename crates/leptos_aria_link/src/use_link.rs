@@ -0,0 +1,204 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_interactions::use_press;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_interactions::PressResult;
+use leptos_aria_interactions::UsePressProps;
+use leptos_aria_utils::use_platform;
+
+use crate::client_navigate;
+use crate::open_link::open_with_anchor;
+use crate::use_link_router;
+use crate::LinkModifiers;
+use crate::LinkOptions;
+use crate::NavigateCallback;
+
+pub(crate) type LinkCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// The element a link is rendered as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LinkElementType {
+  /// An `<a>` element, which the browser handles natively.
+  #[default]
+  Anchor,
+  /// Any other element, which is given `role="link"` and opens the link when
+  /// activated.
+  Other,
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseLinkProps {
+  /// The url the link points to.
+  #[builder(default, setter(strip_option, into))]
+  pub href: Option<MaybeSignal<String>>,
+
+  /// Where to open the link, e.g. `_blank`.
+  #[builder(default, setter(strip_option, into))]
+  pub target: Option<MaybeSignal<String>>,
+
+  /// The relationship of the linked page, e.g. `noopener noreferrer`.
+  #[builder(default, setter(strip_option, into))]
+  pub rel: Option<MaybeSignal<String>>,
+
+  /// Download the linked url instead of navigating to it. The value is the
+  /// suggested file name and may be empty.
+  #[builder(default, setter(strip_option, into))]
+  pub download: Option<MaybeSignal<String>>,
+
+  /// Whether the link is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// The element the link is rendered as.
+  #[builder(default)]
+  pub element_type: LinkElementType,
+
+  /// Handler that is called when the link is pressed.
+  #[builder(default, setter(strip_option))]
+  pub on_press: Option<Box<dyn Fn(&PressEvent)>>,
+
+  /// Navigate without a full page load. Overrides the router provided with
+  /// [`provide_link_router`](crate::provide_link_router).
+  #[builder(default, setter(strip_option))]
+  pub on_navigate: Option<Box<dyn Fn(&str)>>,
+}
+
+#[derive(Clone)]
+pub struct LinkResult {
+  pub role: Option<&'static str>,
+  pub tab_index: Signal<Option<i32>>,
+  pub aria_disabled: Signal<Option<bool>>,
+  /// The native link attributes. These are only set for anchor elements.
+  pub href: Signal<Option<String>>,
+  pub target: Signal<Option<String>>,
+  pub rel: Signal<Option<String>>,
+  pub download: Signal<Option<String>>,
+  pub press: ReadSignal<PressResult>,
+  /// Replaces the `on_click` handler of [`LinkResult::press`].
+  pub on_click: LinkCallback<MouseEvent>,
+  /// Opens non anchor links in a new tab when they are middle clicked.
+  pub on_aux_click: LinkCallback<MouseEvent>,
+}
+
+/// Provides the behavior and accessibility implementation for a link.
+///
+/// * Anchor elements keep their native behavior. Any other element is given
+///   `role="link"`, is activated with `Enter` and opens the link itself,
+///   respecting the modifier keys so `Cmd`, `Ctrl`, `Shift` and middle clicks
+///   still open new tabs and windows.
+/// * Same origin links which open in the current page are handed to the client
+///   side router, when there is one.
+pub fn use_link(cx: Scope, props: UseLinkProps) -> ReadSignal<LinkResult> {
+  let element_type = props.element_type;
+  let is_anchor = element_type == LinkElementType::Anchor;
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+
+  let attribute = |value: Option<MaybeSignal<String>>| {
+    match value {
+      Some(value) => (move || Some(value.get())).derive_signal(cx),
+      None => (move || None).derive_signal(cx),
+    }
+  };
+  let original_href = attribute(props.href);
+  let original_target = attribute(props.target);
+  let original_rel = attribute(props.rel);
+  let original_download = attribute(props.download);
+
+  let native = move |value: Signal<Option<String>>| {
+    (move || {
+      (is_anchor && !is_disabled.get())
+        .then(|| value.get())
+        .flatten()
+    })
+    .derive_signal(cx)
+  };
+
+  let navigate: Option<NavigateCallback> = match props.on_navigate {
+    Some(on_navigate) => Some(Rc::from(on_navigate)),
+    None => use_link_router(cx).map(|router| router.navigate),
+  };
+
+  let mut press_props = UsePressProps::builder().is_disabled(is_disabled).build();
+  press_props.on_press = props.on_press;
+  let press = use_press(cx, press_props);
+  let platform = use_platform(cx);
+
+  let open = move |event: &MouseEvent, modifiers: LinkModifiers| {
+    let Some(href) = original_href.get_untracked() else {
+      return;
+    };
+
+    let link = LinkOptions {
+      href,
+      target: original_target.get_untracked(),
+      rel: original_rel.get_untracked(),
+      download: original_download.get_untracked(),
+    };
+
+    let platform = platform.get_untracked();
+
+    if client_navigate(&link, modifiers, navigate.as_ref(), &platform) {
+      event.prevent_default();
+    } else if !is_anchor {
+      event.prevent_default();
+      open_with_anchor(&link, modifiers, &platform);
+    }
+  };
+
+  let on_click: LinkCallback<MouseEvent> = {
+    let press = press.get_untracked();
+    let open = open.clone();
+
+    Rc::new(Box::new(move |event: MouseEvent| {
+      (press.on_click)(event.clone());
+
+      if is_disabled.get_untracked() {
+        event.prevent_default();
+        return;
+      }
+
+      if event.default_prevented() {
+        return;
+      }
+
+      open(&event, LinkModifiers::from(&event));
+    }))
+  };
+
+  let on_aux_click: LinkCallback<MouseEvent> = Rc::new(Box::new(move |event: MouseEvent| {
+    // Anchors handle the middle click natively.
+    if is_anchor || event.button() != 1 || is_disabled.get_untracked() {
+      return;
+    }
+
+    open(&event, LinkModifiers::from(&event));
+  }));
+
+  let (link_result, _) = create_signal(
+    cx,
+    LinkResult {
+      role: (!is_anchor).then_some("link"),
+      tab_index: (move || (!is_anchor && !is_disabled.get()).then_some(0)).derive_signal(cx),
+      aria_disabled: (move || is_disabled.get().then_some(true)).derive_signal(cx),
+      href: native(original_href),
+      target: native(original_target),
+      rel: native(original_rel),
+      download: native(original_download),
+      press,
+      on_click,
+      on_aux_click,
+    },
+  );
+
+  link_result
+}
@@ -0,0 +1,131 @@
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::document;
+use leptos::request_animation_frame;
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_i18n::LocalizedStringFormatter;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::Politeness;
+
+use crate::use_skip_link::focus_skip_target;
+
+#[derive(TypedBuilder)]
+pub struct UseRouteAnnouncerProps {
+  /// The current path, e.g. `use_location(cx).pathname` from
+  /// `leptos_router`. Every change is announced.
+  #[builder(setter(into))]
+  pub pathname: MaybeSignal<String>,
+
+  /// The title of the new page. Defaults to the document title once the page
+  /// has been rendered.
+  #[builder(default, setter(strip_option, into))]
+  pub title: Option<MaybeSignal<String>>,
+
+  /// The id of the element which receives focus after navigating, usually
+  /// the main landmark. Focus isn't moved when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub focus_target_id: Option<String>,
+
+  /// Creates the announced message from the page title. Defaults to
+  /// [`route_announcement`].
+  #[builder(default, setter(strip_option))]
+  pub get_message: Option<Box<dyn Fn(&str) -> String>>,
+}
+
+/// The default announcement for a page, e.g. `"Navigated to Settings"`,
+/// translated for the locale. The path is used when the page has no title.
+pub fn route_announcement(
+  strings: &LocalizedStringFormatter,
+  title: &str,
+  pathname: &str,
+) -> String {
+  let title = title.trim();
+  let page = if title.is_empty() { pathname } else { title };
+
+  strings.format_with("navigatedTo", &[("page", page.into())])
+}
+
+/// Announce client side navigation to screen readers, which otherwise don't
+/// notice that the page changed, and optionally move focus to the new
+/// content. The initial page load isn't announced.
+pub fn use_route_announcer(cx: Scope, props: UseRouteAnnouncerProps) {
+  if is_server() {
+    return;
+  }
+
+  let pathname = props.pathname;
+  let title = props.title;
+  let focus_target_id = props.focus_target_id;
+  let get_message: Option<Rc<dyn Fn(&str) -> String>> = props.get_message.map(Rc::from);
+  let strings = use_localized_string_formatter(cx);
+
+  create_effect(cx, move |previous: Option<String>| {
+    let current = pathname.get();
+
+    if previous
+      .as_ref()
+      .map_or(true, |previous| previous == &current)
+    {
+      return current;
+    }
+
+    // Wait for the new page, and its title, to be rendered.
+    let pathname = current.clone();
+    let title = title.clone();
+    let focus_target_id = focus_target_id.clone();
+    let get_message = get_message.clone();
+
+    request_animation_frame(move || {
+      let title = title
+        .map(|title| title.get())
+        .unwrap_or_else(|| document().title());
+      let message = match get_message {
+        Some(get_message) => get_message(&title),
+        None => route_announcement(&strings.get_untracked(), &title, &pathname),
+      };
+
+      announce(message, Politeness::Assertive);
+
+      if let Some(focus_target_id) = focus_target_id {
+        focus_skip_target(&focus_target_id);
+      }
+    });
+
+    current
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn announces_the_title_or_the_path() {
+    let strings = LocalizedStringFormatter::new("en-US", None);
+
+    assert_eq!(
+      route_announcement(&strings, "Settings", "/settings"),
+      "Navigated to Settings"
+    );
+    assert_eq!(
+      route_announcement(&strings, "  ", "/settings"),
+      "Navigated to /settings"
+    );
+  }
+
+  #[test]
+  fn announces_in_the_locale() {
+    let strings = LocalizedStringFormatter::new("de-DE", None);
+
+    assert_eq!(
+      route_announcement(&strings, "Einstellungen", "/settings"),
+      "Navigiert zu Einstellungen"
+    );
+  }
+}
@@ -0,0 +1,144 @@
+use std::rc::Rc;
+
+use leptos::component;
+use leptos::create_signal;
+use leptos::document;
+use leptos::typed_builder::TypedBuilder;
+use leptos::view;
+use leptos::wasm_bindgen::prelude::Closure;
+use leptos::web_sys::AddEventListenerOptions;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::MouseEvent;
+use leptos::Fragment;
+use leptos::IntoView;
+use leptos::JsCast;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_visually_hidden;
+use leptos_aria_utils::UseVisuallyHiddenProps;
+
+use crate::LinkCallback;
+
+#[derive(TypedBuilder)]
+pub struct UseSkipLinkProps {
+  /// The id of the element to skip to, usually the main landmark.
+  #[builder(setter(into))]
+  pub target_id: String,
+}
+
+#[derive(Clone)]
+pub struct SkipLinkResult {
+  pub href: String,
+  /// Hides the link until it is focused.
+  pub style: Signal<Option<&'static str>>,
+  pub on_click: LinkCallback<MouseEvent>,
+  pub on_focus_in: LinkCallback<FocusEvent>,
+  pub on_focus_out: LinkCallback<FocusEvent>,
+}
+
+/// Provides the behavior for a link which lets keyboard users skip past
+/// repeated content such as the navigation.
+///
+/// The link is visually hidden until it is focused. Activating it moves focus
+/// to the target, rather than only scrolling to it, so the next
+/// <kbd>Tab</kbd> continues from the target. A target which isn't focusable
+/// gets `tabindex="-1"` until it loses focus.
+pub fn use_skip_link(cx: Scope, props: UseSkipLinkProps) -> ReadSignal<SkipLinkResult> {
+  let target_id = props.target_id;
+  let visually_hidden = use_visually_hidden(
+    cx,
+    UseVisuallyHiddenProps::builder().is_focusable(true).build(),
+  )
+  .get_untracked();
+
+  let on_click: LinkCallback<MouseEvent> = {
+    let target_id = target_id.clone();
+
+    Rc::new(Box::new(move |event: MouseEvent| {
+      if focus_skip_target(&target_id) {
+        event.prevent_default();
+      }
+    }))
+  };
+
+  let (skip_link_result, _) = create_signal(
+    cx,
+    SkipLinkResult {
+      href: format!("#{target_id}"),
+      style: visually_hidden.style,
+      on_click,
+      on_focus_in: visually_hidden.on_focus_in,
+      on_focus_out: visually_hidden.on_focus_out,
+    },
+  );
+
+  skip_link_result
+}
+
+/// Focus the element with the provided id, making it focusable while it has
+/// focus. Returns `false` when the element doesn't exist.
+pub(crate) fn focus_skip_target(target_id: &str) -> bool {
+  let Some(target) = document()
+    .get_element_by_id(target_id)
+    .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+  else {
+    return false;
+  };
+
+  if !target.has_attribute("tabindex") && target.tab_index() < 0 {
+    let _ = target.set_attribute("tabindex", "-1");
+
+    let on_blur = {
+      let target = target.clone();
+      Closure::wrap(Box::new(move || {
+        let _ = target.remove_attribute("tabindex");
+      }) as Box<dyn Fn()>)
+      .into_js_value()
+    };
+
+    let mut options = AddEventListenerOptions::new();
+    options.once(true);
+    let _ = target.add_event_listener_with_callback_and_add_event_listener_options(
+      "blur",
+      on_blur.unchecked_ref(),
+      &options,
+    );
+  }
+
+  let _ = target.focus();
+  target.scroll_into_view();
+
+  true
+}
+
+/// A link which is visually hidden until it is focused and moves focus to the
+/// element with `target_id`.
+#[component]
+pub fn SkipLink(
+  cx: Scope,
+  children: Box<dyn Fn(Scope) -> Fragment>,
+  #[prop(into)] target_id: String,
+) -> impl IntoView {
+  let skip_link =
+    use_skip_link(cx, UseSkipLinkProps::builder().target_id(target_id).build()).get_untracked();
+  let style = skip_link.style;
+  let on_click = skip_link.on_click;
+  let on_focus_in = skip_link.on_focus_in;
+  let on_focus_out = skip_link.on_focus_out;
+
+  view! {
+    cx,
+    <a
+      href=skip_link.href
+      style=move || style.get()
+      on:click=move |event| on_click(event)
+      on:focusin=move |event| on_focus_in(event)
+      on:focusout=move |event| on_focus_out(event)
+    >
+      {children(cx)}
+    </a>
+  }
+}
@@ -0,0 +1,5 @@
+pub use media_time::*;
+pub use use_media_controls::*;
+
+mod media_time;
+mod use_media_controls;
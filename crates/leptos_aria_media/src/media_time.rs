@@ -0,0 +1,68 @@
+/// Format a time in seconds as a clock, e.g. `"1:05"` or `"1:02:03"`.
+pub fn format_media_time(seconds: f64) -> String {
+  let (hours, minutes, seconds) = split_time(seconds);
+
+  if hours > 0 {
+    format!("{hours}:{minutes:02}:{seconds:02}")
+  } else {
+    format!("{minutes}:{seconds:02}")
+  }
+}
+
+/// Describe a time in seconds in words, e.g. `"1 minute 5 seconds"`. Used for
+/// `aria-valuetext` since screen readers read clock times inconsistently.
+pub fn describe_media_time(seconds: f64) -> String {
+  let (hours, minutes, seconds) = split_time(seconds);
+  let plural = |count: u64, unit: &str| {
+    if count == 1 {
+      format!("{count} {unit}")
+    } else {
+      format!("{count} {unit}s")
+    }
+  };
+
+  let mut parts = vec![];
+
+  if hours > 0 {
+    parts.push(plural(hours, "hour"));
+  }
+
+  if minutes > 0 {
+    parts.push(plural(minutes, "minute"));
+  }
+
+  if seconds > 0 || parts.is_empty() {
+    parts.push(plural(seconds, "second"));
+  }
+
+  parts.join(" ")
+}
+
+fn split_time(seconds: f64) -> (u64, u64, u64) {
+  let total = if seconds.is_finite() && seconds > 0.0 {
+    seconds.floor() as u64
+  } else {
+    0
+  };
+
+  (total / 3600, (total % 3600) / 60, total % 60)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn formats_clock_times() {
+    assert_eq!(format_media_time(65.4), "1:05");
+    assert_eq!(format_media_time(3723.0), "1:02:03");
+    assert_eq!(format_media_time(f64::NAN), "0:00");
+  }
+
+  #[test]
+  fn describes_times_in_words() {
+    assert_eq!(describe_media_time(65.0), "1 minute 5 seconds");
+    assert_eq!(describe_media_time(7200.0), "2 hours");
+    assert_eq!(describe_media_time(0.0), "0 seconds");
+  }
+}
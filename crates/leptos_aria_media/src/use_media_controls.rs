@@ -0,0 +1,341 @@
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::document;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::Event;
+use leptos::web_sys::HtmlMediaElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_slider::use_slider;
+use leptos_aria_slider::use_slider_state;
+use leptos_aria_slider::SliderResult;
+use leptos_aria_slider::UseSliderProps;
+use leptos_aria_slider::UseSliderStateProps;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::ValueLabelOptions;
+
+use crate::describe_media_time;
+
+/// The default number of seconds moved by the arrow key shortcuts.
+pub const MEDIA_SEEK_STEP: f64 = 5.0;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseMediaControlsProps {
+  /// The id of the `<audio>` or `<video>` element. Generated when not
+  /// provided.
+  #[builder(default, setter(strip_option, into))]
+  pub media_id: Option<String>,
+
+  /// The number of seconds moved by the arrow key shortcuts. Defaults to
+  /// [`MEDIA_SEEK_STEP`].
+  #[builder(default, setter(strip_option))]
+  pub seek_step: Option<f64>,
+}
+
+/// The props for the media element, which keep the controls in sync with
+/// playback started elsewhere, e.g. from the system media keys.
+#[derive(Clone)]
+pub struct MediaElementResult {
+  pub id: String,
+  pub on_play: MediaCallback<Event>,
+  pub on_pause: MediaCallback<Event>,
+  pub on_time_update: MediaCallback<Event>,
+  pub on_duration_change: MediaCallback<Event>,
+  pub on_volume_change: MediaCallback<Event>,
+}
+
+/// The props for the element containing the player, which handles the
+/// keyboard shortcuts.
+#[derive(Clone)]
+pub struct MediaPlayerResult {
+  pub role: &'static str,
+  pub aria_label: &'static str,
+  pub on_key_down: MediaCallback<KeyboardEvent>,
+}
+
+/// The props for the play and mute buttons. The label describes the action
+/// the button performs.
+#[derive(Clone)]
+pub struct MediaButtonResult {
+  pub aria_label: Signal<&'static str>,
+  pub on_click: MediaCallback<MouseEvent>,
+}
+
+#[derive(Clone)]
+pub struct MediaControlsResult {
+  pub media: MediaElementResult,
+  pub player: MediaPlayerResult,
+  pub play_button: MediaButtonResult,
+  pub mute_button: MediaButtonResult,
+  /// The seek slider, described as e.g. "1 minute 5 seconds of 3 minutes".
+  pub seek_slider: SliderResult,
+  /// The volume slider, described as a percentage.
+  pub volume_slider: SliderResult,
+  pub is_playing: Signal<bool>,
+  pub is_muted: Signal<bool>,
+  pub current_time: Signal<f64>,
+  pub duration: Signal<f64>,
+}
+
+/// Provides accessible controls for an `<audio>` or `<video>` element: a play
+/// toggle, a mute toggle, a seek slider and a volume slider built on
+/// `use_slider`.
+///
+/// The player container handles these shortcuts unless a control already
+/// handled the key:
+///
+/// * <kbd>Space</kbd> plays or pauses while the container itself is focused,
+///   and <kbd>K</kbd> anywhere within it.
+/// * <kbd>M</kbd> mutes or unmutes.
+/// * <kbd>ArrowLeft</kbd> and <kbd>ArrowRight</kbd> seek backward and forward.
+pub fn use_media_controls(
+  cx: Scope,
+  props: UseMediaControlsProps,
+) -> ReadSignal<MediaControlsResult> {
+  let media_id = props.media_id.unwrap_or_else(|| use_id(cx));
+  let seek_step = props.seek_step.unwrap_or(MEDIA_SEEK_STEP);
+  let is_playing = create_rw_signal(cx, false);
+  let is_muted = create_rw_signal(cx, false);
+  let current_time = create_rw_signal(cx, 0.0);
+  let duration = create_rw_signal(cx, 0.0);
+  let volume = create_rw_signal(cx, 1.0);
+
+  let get_media = {
+    let media_id = media_id.clone();
+    Rc::new(move || {
+      document()
+        .get_element_by_id(&media_id)
+        .and_then(|element| element.dyn_into::<HtmlMediaElement>().ok())
+    })
+  };
+
+  let toggle_play: Rc<dyn Fn()> = {
+    let get_media = get_media.clone();
+    Rc::new(move || {
+      let Some(media) = get_media() else {
+        return;
+      };
+
+      if media.paused() {
+        media.play().ok();
+      } else {
+        media.pause().ok();
+      }
+    })
+  };
+
+  let toggle_mute: Rc<dyn Fn()> = {
+    let get_media = get_media.clone();
+    Rc::new(move || {
+      if let Some(media) = get_media() {
+        media.set_muted(!media.muted());
+      }
+    })
+  };
+
+  let seek_by: Rc<dyn Fn(f64)> = {
+    let get_media = get_media.clone();
+    Rc::new(move |seconds: f64| {
+      if let Some(media) = get_media() {
+        let time = (media.current_time() + seconds).clamp(0.0, finite_or_zero(media.duration()));
+        media.set_current_time(time);
+      }
+    })
+  };
+
+  let seek_state = use_slider_state(
+    cx,
+    UseSliderStateProps::builder()
+      .value((move || current_time.get()).derive_signal(cx))
+      .max_value((move || duration.get()).derive_signal(cx))
+      .step(1.0)
+      .on_change(Box::new({
+        let get_media = get_media.clone();
+        move |time: f64| {
+          if let Some(media) = get_media() {
+            media.set_current_time(time);
+          }
+        }
+      }))
+      .build(),
+  );
+
+  let volume_state = use_slider_state(
+    cx,
+    UseSliderStateProps::builder()
+      .value((move || volume.get()).derive_signal(cx))
+      .max_value(1.0)
+      .step(0.05)
+      .on_change(Box::new({
+        let get_media = get_media.clone();
+        move |value: f64| {
+          if let Some(media) = get_media() {
+            media.set_volume(value);
+            media.set_muted(value == 0.0);
+          }
+        }
+      }))
+      .build(),
+  );
+
+  let seek_slider = use_slider(
+    cx,
+    &seek_state,
+    UseSliderProps::builder()
+      .aria_label("Seek".to_string())
+      .value_label(
+        ValueLabelOptions::builder()
+          .get_value_label(Box::new(move |time: f64| {
+            format!(
+              "{} of {}",
+              describe_media_time(time),
+              describe_media_time(duration.get())
+            )
+          }))
+          .build(),
+      )
+      .build(),
+  )
+  .get_untracked();
+
+  let volume_slider = use_slider(
+    cx,
+    &volume_state,
+    UseSliderProps::builder()
+      .aria_label("Volume".to_string())
+      .value_label(
+        ValueLabelOptions::builder()
+          .get_value_label(Box::new(|value: f64| {
+            format!("{}%", (value * 100.0).round())
+          }))
+          .build(),
+      )
+      .build(),
+  )
+  .get_untracked();
+
+  let on_key_down: MediaCallback<KeyboardEvent> = {
+    let toggle_play = toggle_play.clone();
+    let toggle_mute = toggle_mute.clone();
+    let direction = use_direction(cx);
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if event.default_prevented()
+        || event.ctrl_key()
+        || event.meta_key()
+        || event.alt_key()
+        || is_text_input(&event)
+      {
+        return;
+      }
+
+      match KeyboardKey::from(&event).logical(direction.get_untracked()) {
+        // Buttons, sliders and links within the player use Space themselves.
+        KeyboardKey::Space if is_player_target(&event) => toggle_play(),
+        KeyboardKey::Character(character) if character.eq_ignore_ascii_case("k") => toggle_play(),
+        KeyboardKey::Character(character) if character.eq_ignore_ascii_case("m") => toggle_mute(),
+        KeyboardKey::ArrowLeft => seek_by(-seek_step),
+        KeyboardKey::ArrowRight => seek_by(seek_step),
+        _ => return,
+      }
+
+      event.prevent_default();
+    }))
+  };
+
+  let play_button = MediaButtonResult {
+    aria_label: (move || if is_playing.get() { "Pause" } else { "Play" }).derive_signal(cx),
+    on_click: Rc::new(Box::new(move |_| toggle_play())),
+  };
+
+  let mute_button = MediaButtonResult {
+    aria_label: (move || if is_muted.get() { "Unmute" } else { "Mute" }).derive_signal(cx),
+    on_click: Rc::new(Box::new(move |_| toggle_mute())),
+  };
+
+  let media = MediaElementResult {
+    id: media_id,
+    on_play: Rc::new(Box::new(move |_| is_playing.set(true))),
+    on_pause: Rc::new(Box::new(move |_| is_playing.set(false))),
+    on_time_update: sync_media(move |media| current_time.set(media.current_time())),
+    on_duration_change: sync_media(move |media| duration.set(finite_or_zero(media.duration()))),
+    on_volume_change: sync_media(move |media| {
+      volume.set(media.volume());
+      is_muted.set(media.muted());
+    }),
+  };
+
+  let (media_controls_result, _) = create_signal(
+    cx,
+    MediaControlsResult {
+      media,
+      player: MediaPlayerResult {
+        role: "group",
+        aria_label: "Media player",
+        on_key_down,
+      },
+      play_button,
+      mute_button,
+      seek_slider,
+      volume_slider,
+      is_playing: (move || is_playing.get()).derive_signal(cx),
+      is_muted: (move || is_muted.get()).derive_signal(cx),
+      current_time: (move || current_time.get()).derive_signal(cx),
+      duration: (move || duration.get()).derive_signal(cx),
+    },
+  );
+
+  media_controls_result
+}
+
+pub(crate) type MediaCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// Create a handler for a media event which reads the media element.
+fn sync_media(update: impl Fn(&HtmlMediaElement) + 'static) -> MediaCallback<Event> {
+  Rc::new(Box::new(move |event: Event| {
+    if let Some(media) = event
+      .current_target()
+      .and_then(|target| target.dyn_into::<HtmlMediaElement>().ok())
+    {
+      update(&media);
+    }
+  }))
+}
+
+/// The duration is `NaN` before the metadata loads and infinite for streams.
+fn finite_or_zero(value: f64) -> f64 {
+  if value.is_finite() {
+    value
+  } else {
+    0.0
+  }
+}
+
+/// Whether the key was pressed on the player container itself rather than an
+/// element within it.
+fn is_player_target(event: &KeyboardEvent) -> bool {
+  event.target().is_some() && event.target() == event.current_target()
+}
+
+/// Whether the key was pressed in a text field, where the shortcuts would
+/// interfere with typing.
+fn is_text_input(event: &KeyboardEvent) -> bool {
+  event
+    .target()
+    .and_then(|target| target.dyn_into::<Element>().ok())
+    .map_or(false, |element| {
+      matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA")
+        || element.has_attribute("contenteditable")
+    })
+}
@@ -0,0 +1,5 @@
+pub use stepper_field_state::*;
+pub use use_stepper_field::*;
+
+mod stepper_field_state;
+mod use_stepper_field;
@@ -0,0 +1,132 @@
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::clamp_value;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseStepperFieldStateProps {
+  /// The current value (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub value: Option<MaybeSignal<f64>>,
+
+  /// The initial value (uncontrolled).
+  #[builder(default)]
+  pub default_value: f64,
+
+  /// The smallest allowed value.
+  #[builder(default, setter(strip_option))]
+  pub min_value: Option<f64>,
+
+  /// The largest allowed value.
+  #[builder(default, setter(strip_option))]
+  pub max_value: Option<f64>,
+
+  /// The amount the value changes with each step. Defaults to `1`.
+  #[builder(default, setter(strip_option))]
+  pub step: Option<f64>,
+
+  /// Handler that is called once the user has finished changing the value.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(f64)>>,
+}
+
+/// The value of a stepper field.
+///
+/// Steps are collected in a pending value which is only committed, and
+/// reported through `on_change`, once the interaction ends. Holding an
+/// increment button therefore results in a single change event.
+#[derive(Clone)]
+pub struct StepperFieldState {
+  pub min_value: Option<f64>,
+  pub max_value: Option<f64>,
+  pub step: f64,
+  value: ControlledState<f64>,
+  pending: RwSignal<Option<f64>>,
+}
+
+pub fn use_stepper_field_state(cx: Scope, props: UseStepperFieldStateProps) -> StepperFieldState {
+  StepperFieldState {
+    min_value: props.min_value,
+    max_value: props.max_value,
+    step: props.step.unwrap_or(1.0),
+    value: use_controlled_state(cx, props.value, props.default_value, props.on_change),
+    pending: create_rw_signal(cx, None),
+  }
+}
+
+impl StepperFieldState {
+  /// The displayed value, including uncommitted steps.
+  pub fn value(&self) -> f64 {
+    self.pending.get().unwrap_or_else(|| self.value.get())
+  }
+
+  pub fn value_untracked(&self) -> f64 {
+    self
+      .pending
+      .get_untracked()
+      .unwrap_or_else(|| self.value.get_untracked())
+  }
+
+  /// Whether the value can be incremented.
+  pub fn can_increment(&self) -> bool {
+    self
+      .max_value
+      .map_or(true, |max_value| self.value() < max_value)
+  }
+
+  /// Whether the value can be decremented.
+  pub fn can_decrement(&self) -> bool {
+    self
+      .min_value
+      .map_or(true, |min_value| self.value() > min_value)
+  }
+
+  /// Move the pending value by `steps` steps. Returns `false` when the value
+  /// didn't change because it is already at the limit.
+  pub fn step_by(&self, steps: f64) -> bool {
+    let current = self.value_untracked();
+    let next = self.clamp(current + steps * self.step);
+
+    if next == current {
+      return false;
+    }
+
+    self.pending.set(Some(next));
+    true
+  }
+
+  pub fn increment(&self) -> bool {
+    self.step_by(1.0)
+  }
+
+  pub fn decrement(&self) -> bool {
+    self.step_by(-1.0)
+  }
+
+  /// Set the pending value, clamped to the limits and snapped to the step.
+  pub fn set_pending_value(&self, value: f64) {
+    self.pending.set(Some(self.clamp(value)));
+  }
+
+  /// Commit the pending value, calling `on_change` if it changed.
+  pub fn commit(&self) {
+    if let Some(value) = self.pending.get_untracked() {
+      self.pending.set(None);
+      self.value.set(value);
+    }
+  }
+
+  /// Discard the pending value.
+  pub fn reset(&self) {
+    self.pending.set(None);
+  }
+
+  fn clamp(&self, value: f64) -> f64 {
+    clamp_value(value, self.min_value, self.max_value, self.step)
+  }
+}
@@ -0,0 +1,574 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::set_timeout;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Event;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::HtmlInputElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::MouseEvent;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos::UntrackedSettableSignal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_i18n::use_number_formatter;
+use leptos_aria_i18n::use_number_parser;
+use leptos_aria_i18n::NumberFormatOptions;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::use_value_label;
+use leptos_aria_utils::FieldState;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::ValueLabelOptions;
+
+use crate::StepperFieldState;
+
+/// The delay in milliseconds before holding a button starts repeating.
+const REPEAT_DELAY: u64 = 400;
+
+/// The interval in milliseconds between repeated steps.
+const REPEAT_INTERVAL: u64 = 60;
+
+/// The number of steps moved by <kbd>PageUp</kbd> and <kbd>PageDown</kbd>.
+const PAGE_STEPS: f64 = 10.0;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseStepperFieldProps {
+  /// The id of the group. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// The accessible name shared by the input and both buttons. Defaults to
+  /// the localized `"quantity"` string, e.g. "Quantity".
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The label of the increment button, read before the shared name.
+  /// Defaults to the localized `"increase"` string.
+  #[builder(default, setter(strip_option, into))]
+  pub increment_aria_label: Option<MaybeSignal<String>>,
+
+  /// The label of the decrement button, read before the shared name.
+  /// Defaults to the localized `"decrease"` string.
+  #[builder(default, setter(strip_option, into))]
+  pub decrement_aria_label: Option<MaybeSignal<String>>,
+
+  /// Whether the field is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// Whether the field is read only.
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+
+  /// How the value is described to assistive technology, e.g. with a unit.
+  #[builder(default)]
+  pub value_label: ValueLabelOptions,
+
+  /// How the value is formatted in the input. Typed text is parsed with the
+  /// same options, and the value is described with them when `value_label`
+  /// has no `format_value`. Defaults to a decimal number in the format of the
+  /// locale.
+  #[builder(default, setter(strip_option))]
+  pub format_options: Option<NumberFormatOptions>,
+}
+
+#[derive(Clone)]
+pub struct StepperGroupResult {
+  pub id: String,
+  pub role: &'static str,
+  pub aria_label: Signal<String>,
+  pub aria_disabled: Signal<Option<bool>>,
+}
+
+#[derive(Clone)]
+pub struct StepperInputResult {
+  pub id: String,
+  pub role: &'static str,
+  pub input_mode: &'static str,
+  pub aria_labelledby: String,
+  pub aria_valuenow: Signal<f64>,
+  pub aria_valuemin: Option<f64>,
+  pub aria_valuemax: Option<f64>,
+  /// Only set when the value is described with a unit or a custom label.
+  pub aria_valuetext: Signal<Option<String>>,
+  pub aria_readonly: Signal<Option<bool>>,
+  pub disabled: Signal<bool>,
+  pub read_only: Signal<bool>,
+  /// The text of the input, which is the typed text while editing.
+  pub value: Signal<String>,
+  pub on_input: StepperCallback<Event>,
+  pub on_key_down: StepperCallback<KeyboardEvent>,
+  pub on_blur: StepperCallback<FocusEvent>,
+}
+
+#[derive(Clone)]
+pub struct StepperButtonResult {
+  pub id: String,
+  pub aria_label: Signal<String>,
+  /// Combines the button label with the shared name, e.g. "Increase
+  /// Quantity".
+  pub aria_labelledby: String,
+  pub aria_controls: String,
+  pub disabled: Signal<bool>,
+  /// The buttons are skipped in the tab order since the arrow keys of the
+  /// input provide the same behavior.
+  pub tab_index: i32,
+  pub on_pointer_down: StepperCallback<PointerEvent>,
+  pub on_pointer_up: StepperCallback<PointerEvent>,
+  pub on_pointer_leave: StepperCallback<PointerEvent>,
+  pub on_click: StepperCallback<MouseEvent>,
+}
+
+#[derive(Clone)]
+pub struct StepperFieldResult {
+  pub group: StepperGroupResult,
+  pub input: StepperInputResult,
+  pub increment_button: StepperButtonResult,
+  pub decrement_button: StepperButtonResult,
+}
+
+/// Provides the accessibility implementation for a compact quantity selector:
+/// a numeric input between a decrement and an increment button.
+///
+/// * The input and both buttons share the accessible name of the group.
+/// * Holding a button steps repeatedly until it is released or the limit is
+///   reached.
+/// * The input supports the arrow keys, <kbd>PageUp</kbd>, <kbd>PageDown</kbd>,
+///   <kbd>Home</kbd> and <kbd>End</kbd>. Typed text is committed on
+///   <kbd>Enter</kbd> or blur.
+/// * The value is formatted for the locale and typed text is parsed in any
+///   numbering system, so `"١٢"` with Arabic-Indic digits is `12`.
+///
+/// Every interaction results in a single `on_change` of the state.
+pub fn use_stepper_field(
+  cx: Scope,
+  state: &StepperFieldState,
+  props: UseStepperFieldProps,
+) -> ReadSignal<StepperFieldResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let input_id = format!("{id}-input");
+  let field = use_field_state(cx, props.is_disabled, props.is_read_only);
+  let aria_label = localized_label(cx, props.aria_label, "quantity");
+  let typed_text = create_rw_signal::<Option<String>>(cx, None);
+  let format_options = props.format_options.unwrap_or_default();
+  let formatter = use_number_formatter(cx, format_options.clone());
+  let parser = use_number_parser(cx, format_options);
+  let mut value_label_options = props.value_label;
+
+  if value_label_options.format_value.is_none() {
+    value_label_options.format_value = Some(Box::new(move |value| formatter.get().format(value)));
+  }
+
+  let value_label = use_value_label(cx, value_label_options);
+
+  let value = {
+    let state = state.clone();
+    (move || {
+      typed_text
+        .get()
+        .unwrap_or_else(|| formatter.get().format(state.value()))
+    })
+    .derive_signal(cx)
+  };
+
+  let aria_valuenow = {
+    let state = state.clone();
+    (move || state.value()).derive_signal(cx)
+  };
+
+  let commit_typed_text = {
+    let state = state.clone();
+    move || {
+      if let Some(text) = typed_text.get_untracked() {
+        typed_text.set(None);
+
+        // Invalid text is discarded and the previous value is shown again.
+        if let Some(value) = parser.get_untracked().parse(&text) {
+          state.set_pending_value(value);
+        }
+      }
+
+      state.commit();
+    }
+  };
+
+  let on_input: StepperCallback<Event> = Rc::new(Box::new(move |event: Event| {
+    if let Some(input) = event
+      .target()
+      .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+    {
+      typed_text.set(Some(input.value()));
+    }
+  }));
+
+  let on_key_down: StepperCallback<KeyboardEvent> = {
+    let state = state.clone();
+    let commit_typed_text = commit_typed_text.clone();
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if !field.can_change() {
+        return;
+      }
+
+      match KeyboardKey::from(&event) {
+        KeyboardKey::ArrowUp => state.step_by(1.0),
+        KeyboardKey::ArrowDown => state.step_by(-1.0),
+        KeyboardKey::PageUp => state.step_by(PAGE_STEPS),
+        KeyboardKey::PageDown => state.step_by(-PAGE_STEPS),
+        KeyboardKey::Home => {
+          match state.min_value {
+            Some(min_value) => {
+              state.set_pending_value(min_value);
+              true
+            }
+            None => return,
+          }
+        }
+        KeyboardKey::End => {
+          match state.max_value {
+            Some(max_value) => {
+              state.set_pending_value(max_value);
+              true
+            }
+            None => return,
+          }
+        }
+        KeyboardKey::Enter => {
+          commit_typed_text();
+          return;
+        }
+        _ => return,
+      };
+
+      event.prevent_default();
+      typed_text.set(None);
+      state.commit();
+    }))
+  };
+
+  let on_blur: StepperCallback<FocusEvent> = Rc::new(Box::new(move |_| commit_typed_text()));
+
+  let increment_button = create_button_result(
+    cx,
+    ButtonOptions {
+      id: format!("{id}-increment"),
+      group_id: id.clone(),
+      input_id: input_id.clone(),
+      aria_label: props.increment_aria_label,
+      default_aria_label: "increase",
+      direction: 1.0,
+    },
+    state,
+    field,
+  );
+
+  let decrement_button = create_button_result(
+    cx,
+    ButtonOptions {
+      id: format!("{id}-decrement"),
+      group_id: id.clone(),
+      input_id: input_id.clone(),
+      aria_label: props.decrement_aria_label,
+      default_aria_label: "decrease",
+      direction: -1.0,
+    },
+    state,
+    field,
+  );
+
+  let (stepper_field_result, _) = create_signal(
+    cx,
+    StepperFieldResult {
+      group: StepperGroupResult {
+        id: id.clone(),
+        role: "group",
+        aria_label,
+        aria_disabled: field.aria_disabled(cx),
+      },
+      input: StepperInputResult {
+        id: input_id,
+        role: "spinbutton",
+        input_mode: "decimal",
+        aria_labelledby: id,
+        aria_valuenow,
+        aria_valuemin: state.min_value,
+        aria_valuemax: state.max_value,
+        aria_valuetext: value_label.aria_valuetext(cx, aria_valuenow),
+        aria_readonly: field.aria_readonly(cx, "spinbutton"),
+        disabled: field.is_disabled,
+        read_only: field.is_read_only,
+        value,
+        on_input,
+        on_key_down,
+        on_blur,
+      },
+      increment_button,
+      decrement_button,
+    },
+  );
+
+  stepper_field_result
+}
+
+pub(crate) type StepperCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+struct ButtonOptions {
+  id: String,
+  group_id: String,
+  input_id: String,
+  aria_label: Option<MaybeSignal<String>>,
+  /// The key of the localized label used without an `aria_label`.
+  default_aria_label: &'static str,
+  direction: f64,
+}
+
+/// The provided label, or the localized string for the key.
+fn localized_label(
+  cx: Scope,
+  aria_label: Option<MaybeSignal<String>>,
+  key: &'static str,
+) -> Signal<String> {
+  match aria_label {
+    Some(aria_label) => (move || aria_label.get()).derive_signal(cx),
+    None => {
+      let strings = use_localized_string_formatter(cx);
+      (move || strings.get().format(key)).derive_signal(cx)
+    }
+  }
+}
+
+fn create_button_result(
+  cx: Scope,
+  options: ButtonOptions,
+  state: &StepperFieldState,
+  field: FieldState,
+) -> StepperButtonResult {
+  let ButtonOptions {
+    id,
+    group_id,
+    input_id,
+    aria_label,
+    default_aria_label,
+    direction,
+  } = options;
+  let aria_label = localized_label(cx, aria_label, default_aria_label);
+  // Incremented to cancel the pending repeat.
+  let generation = create_rw_signal(cx, 0_usize);
+
+  let disabled = {
+    let state = state.clone();
+    (move || {
+      field.is_disabled.get()
+        || field.is_read_only.get()
+        || if direction > 0.0 {
+          !state.can_increment()
+        } else {
+          !state.can_decrement()
+        }
+    })
+    .derive_signal(cx)
+  };
+
+  let on_pointer_down: StepperCallback<PointerEvent> = {
+    let state = state.clone();
+    Rc::new(Box::new(move |event: PointerEvent| {
+      if event.button() != 0 || !field.can_change() {
+        return;
+      }
+
+      // Keep focus where it is so that the input doesn't blur and commit.
+      event.prevent_default();
+      let current = generation.get_untracked() + 1;
+      generation.set_untracked(current);
+      step_repeatedly(state.clone(), generation, current, direction, REPEAT_DELAY);
+    }))
+  };
+
+  let stop = {
+    let state = state.clone();
+    move || {
+      generation.set_untracked(generation.get_untracked() + 1);
+      state.commit();
+    }
+  };
+
+  let on_pointer_up: StepperCallback<PointerEvent> = {
+    let stop = stop.clone();
+    Rc::new(Box::new(move |_| stop()))
+  };
+  let on_pointer_leave: StepperCallback<PointerEvent> = Rc::new(Box::new(move |_| stop()));
+
+  // Screen readers and keyboard activation fire a click without pointer
+  // events, which is recognised by a `detail` of zero.
+  let on_click: StepperCallback<MouseEvent> = {
+    let state = state.clone();
+    Rc::new(Box::new(move |event: MouseEvent| {
+      if event.detail() != 0 || !field.can_change() {
+        return;
+      }
+
+      state.step_by(direction);
+      state.commit();
+    }))
+  };
+
+  StepperButtonResult {
+    aria_label,
+    aria_labelledby: format!("{id} {group_id}"),
+    aria_controls: input_id,
+    id,
+    disabled,
+    tab_index: -1,
+    on_pointer_down,
+    on_pointer_up,
+    on_pointer_leave,
+    on_click,
+  }
+}
+
+/// Step immediately and schedule the next step until the generation changes or
+/// the limit is reached.
+///
+/// The button is disabled once the limit is reached, so it never receives the
+/// `pointerup` which ends the press. The press ends here instead, committing
+/// the value as a single change.
+fn step_repeatedly(
+  state: StepperFieldState,
+  generation: RwSignal<usize>,
+  current: usize,
+  direction: f64,
+  delay: u64,
+) {
+  if generation.get_untracked() != current {
+    return;
+  }
+
+  if !state.step_by(direction) || is_at_limit(&state, direction) {
+    generation.set_untracked(current + 1);
+    state.commit();
+    return;
+  }
+
+  set_timeout(
+    move || step_repeatedly(state, generation, current, direction, REPEAT_INTERVAL),
+    Duration::from_millis(delay),
+  );
+}
+
+/// Whether the value can't move any further in the direction.
+fn is_at_limit(state: &StepperFieldState, direction: f64) -> bool {
+  let value = state.value_untracked();
+
+  if direction > 0.0 {
+    state
+      .max_value
+      .map_or(false, |max_value| value >= max_value)
+  } else {
+    state
+      .min_value
+      .map_or(false, |min_value| value <= min_value)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+  use leptos_aria_utils::provide_locale;
+
+  use super::*;
+  use crate::use_stepper_field_state;
+  use crate::UseStepperFieldStateProps;
+
+  #[test]
+  fn labels_are_localized() {
+    let labels = run_scope(create_runtime(), |cx| {
+      provide_locale(cx, "de-DE");
+      let state = use_stepper_field_state(cx, UseStepperFieldStateProps::default());
+      let result = use_stepper_field(cx, &state, UseStepperFieldProps::default()).get_untracked();
+
+      [
+        result.group.aria_label.get(),
+        result.increment_button.aria_label.get(),
+        result.decrement_button.aria_label.get(),
+      ]
+    });
+
+    assert_eq!(labels, ["Menge", "Erhöhen", "Verringern"]);
+  }
+
+  #[test]
+  fn holding_past_the_limit_ends_the_press() {
+    let changes = Rc::new(std::cell::RefCell::new(Vec::new()));
+
+    let (value, is_pending) = run_scope(create_runtime(), {
+      let changes = changes.clone();
+      move |cx| {
+        let state = use_stepper_field_state(
+          cx,
+          UseStepperFieldStateProps::builder()
+            .default_value(4.0)
+            .max_value(5.0)
+            .on_change(Box::new(move |value| changes.borrow_mut().push(value)))
+            .build(),
+        );
+        let generation = create_rw_signal(cx, 1_usize);
+
+        step_repeatedly(state.clone(), generation, 1, 1.0, REPEAT_DELAY);
+        let is_pending = generation.get_untracked() == 1;
+
+        // A repeat which was already scheduled doesn't step or commit again.
+        step_repeatedly(state.clone(), generation, 1, 1.0, REPEAT_INTERVAL);
+
+        (state.value_untracked(), is_pending)
+      }
+    });
+
+    assert_eq!(value, 5.0);
+    assert!(!is_pending);
+    assert_eq!(*changes.borrow(), vec![5.0]);
+  }
+
+  #[test]
+  fn value_text_uses_the_value_label() {
+    let texts = run_scope(create_runtime(), |cx| {
+      let state = use_stepper_field_state(
+        cx,
+        UseStepperFieldStateProps::builder()
+          .default_value(2.0)
+          .build(),
+      );
+      let plain = use_stepper_field(cx, &state, UseStepperFieldProps::default()).get_untracked();
+      let with_unit = use_stepper_field(
+        cx,
+        &state,
+        UseStepperFieldProps::builder()
+          .value_label(
+            ValueLabelOptions::builder()
+              .unit("tickets".to_string())
+              .build(),
+          )
+          .build(),
+      )
+      .get_untracked();
+
+      (
+        plain.input.aria_valuetext.get(),
+        with_unit.input.aria_valuetext.get(),
+      )
+    });
+
+    assert_eq!(texts, (None, Some("2 tickets".to_string())));
+  }
+}
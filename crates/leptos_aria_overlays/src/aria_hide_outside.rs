@@ -0,0 +1,202 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use leptos::document;
+use leptos::js_sys::Array;
+use leptos::js_sys::Number;
+use leptos::wasm_bindgen::prelude::Closure;
+use leptos::web_sys::Element;
+use leptos::web_sys::MutationObserver;
+use leptos::web_sys::MutationObserverInit;
+use leptos::web_sys::MutationRecord;
+use leptos::JsCast;
+use leptos_aria_utils::Map;
+
+/// Restores the elements hidden by [`aria_hide_outside`].
+pub type RestoreFn = Box<dyn FnOnce()>;
+
+thread_local! {
+  /// The number of active calls which hid each element.
+  static HIDDEN_COUNTS: Map<Element, Number> = Map::new();
+
+  /// The observers of the active calls. Only the most recent call observes the
+  /// document so that nested calls don't fight over new nodes.
+  static OBSERVER_STACK: RefCell<Vec<(usize, MutationObserver)>> = RefCell::new(vec![]);
+
+  static NEXT_ID: Cell<usize> = Cell::new(0);
+}
+
+/// Hide every element outside of the `targets` from assistive technology by
+/// setting `aria-hidden` on the siblings of the targets and of their
+/// ancestors. Elements inserted while the targets are shown are hidden too.
+///
+/// Calls can be nested, e.g. for a popover within a modal. Elements are
+/// reference counted so they stay hidden until every call which hid them is
+/// restored, and elements which were already hidden by the application are
+/// left alone. Live regions are never hidden so that announcements still work.
+///
+/// Call the returned function to restore the elements.
+pub fn aria_hide_outside(targets: &[Element]) -> RestoreFn {
+  let targets: Rc<Vec<Element>> = Rc::new(targets.to_vec());
+  let hidden: Rc<RefCell<Vec<Element>>> = Rc::default();
+  let Some(body) = document().body() else {
+    return Box::new(|| {});
+  };
+
+  walk(&body, &targets, &hidden);
+
+  let observer = {
+    let targets = targets.clone();
+    let hidden = hidden.clone();
+    let callback = move |records: Array, _: MutationObserver| {
+      for record in records.iter() {
+        let record: MutationRecord = record.unchecked_into();
+        let added_nodes = record.added_nodes();
+
+        for index in 0..added_nodes.length() {
+          let Some(element) = added_nodes
+            .item(index)
+            .and_then(|node| node.dyn_into::<Element>().ok())
+          else {
+            continue;
+          };
+
+          let is_within_hidden = hidden
+            .borrow()
+            .iter()
+            .any(|hidden| hidden.contains(Some(&element)));
+          let is_related = targets
+            .iter()
+            .any(|target| target.contains(Some(&element)) || element.contains(Some(target)));
+
+          if !is_within_hidden && !is_related {
+            hide(&element, &hidden);
+          }
+        }
+      }
+    };
+
+    let callback = Closure::wrap(Box::new(callback) as Box<dyn Fn(Array, MutationObserver)>);
+    MutationObserver::new(callback.into_js_value().unchecked_ref()).ok()
+  };
+
+  let id = NEXT_ID.with(|next_id| {
+    let id = next_id.get();
+    next_id.set(id + 1);
+    id
+  });
+
+  if let Some(ref observer) = observer {
+    OBSERVER_STACK.with(|stack| {
+      let mut stack = stack.borrow_mut();
+
+      if let Some((_, previous)) = stack.last() {
+        previous.disconnect();
+      }
+
+      observe(observer);
+      stack.push((id, observer.clone()));
+    });
+  }
+
+  Box::new(move || {
+    if let Some(observer) = observer {
+      observer.disconnect();
+
+      OBSERVER_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let was_top = stack.last().map_or(false, |(top, _)| *top == id);
+        stack.retain(|(existing, _)| *existing != id);
+
+        if was_top {
+          if let Some((_, previous)) = stack.last() {
+            observe(previous);
+          }
+        }
+      });
+    }
+
+    for element in hidden.borrow().iter() {
+      restore(element);
+    }
+  })
+}
+
+fn observe(observer: &MutationObserver) {
+  if let Some(body) = document().body() {
+    let mut init = MutationObserverInit::new();
+    init.child_list(true).subtree(true);
+    observer.observe_with_options(&body, &init).ok();
+  }
+}
+
+/// Hide the children of `root` which neither are nor contain a target, and
+/// descend into those which contain one.
+fn walk(root: &Element, targets: &[Element], hidden: &Rc<RefCell<Vec<Element>>>) {
+  let children = root.children();
+
+  for index in 0..children.length() {
+    let Some(child) = children.item(index) else {
+      continue;
+    };
+
+    if targets.iter().any(|target| target == &child) {
+      continue;
+    }
+
+    if targets.iter().any(|target| child.contains(Some(target))) {
+      walk(&child, targets, hidden);
+    } else {
+      hide(&child, hidden);
+    }
+  }
+}
+
+fn hide(element: &Element, hidden: &Rc<RefCell<Vec<Element>>>) {
+  if is_live_region(element) {
+    return;
+  }
+
+  let count = HIDDEN_COUNTS.with(|counts| {
+    counts
+      .get(element)
+      .map_or(0, |count| count.value_of() as u32)
+  });
+
+  // Leave elements which the application hid itself untouched.
+  if count == 0 && element.get_attribute("aria-hidden").as_deref() == Some("true") {
+    return;
+  }
+
+  if count == 0 {
+    element.set_attribute("aria-hidden", "true").ok();
+  }
+
+  HIDDEN_COUNTS.with(|counts| counts.set(element, &Number::from(count + 1)));
+  hidden.borrow_mut().push(element.clone());
+}
+
+fn restore(element: &Element) {
+  HIDDEN_COUNTS.with(|counts| {
+    let count = counts
+      .get(element)
+      .map_or(0, |count| count.value_of() as u32);
+
+    if count <= 1 {
+      counts.delete(element);
+      element.remove_attribute("aria-hidden").ok();
+    } else {
+      counts.set(element, &Number::from(count - 1));
+    }
+  });
+}
+
+fn is_live_region(element: &Element) -> bool {
+  element.has_attribute("aria-live")
+    || element.has_attribute("data-live-announcer")
+    || matches!(
+      element.get_attribute("role").as_deref(),
+      Some("alert" | "status" | "log")
+    )
+}
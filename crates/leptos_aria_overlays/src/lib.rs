@@ -0,0 +1,17 @@
+pub use aria_hide_outside::*;
+pub use overlay_stack::*;
+pub use overlay_trigger_state::*;
+pub use use_dismiss_button::*;
+pub use use_modal::*;
+pub use use_overlay::*;
+pub use use_overlay_position::*;
+pub use use_overlay_trigger::*;
+
+mod aria_hide_outside;
+mod overlay_stack;
+mod overlay_trigger_state;
+mod use_dismiss_button;
+mod use_modal;
+mod use_overlay;
+mod use_overlay_position;
+mod use_overlay_trigger;
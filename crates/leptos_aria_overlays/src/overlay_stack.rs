@@ -0,0 +1,27 @@
+use std::cell::RefCell;
+
+thread_local! {
+  /// The ids of the open overlays, ordered from the bottom to the top.
+  static OVERLAY_STACK: RefCell<Vec<String>> = RefCell::new(vec![]);
+}
+
+/// Whether the overlay with the provided id is the topmost open overlay. Only
+/// the topmost overlay responds to dismissal so that pressing
+/// <kbd>Escape</kbd> in a nested popover doesn't also close its dialog.
+pub fn is_topmost_overlay(id: &str) -> bool {
+  OVERLAY_STACK.with(|stack| stack.borrow().last().map_or(false, |top| top == id))
+}
+
+/// Add the overlay to the top of the stack.
+pub(crate) fn push_overlay(id: &str) {
+  OVERLAY_STACK.with(|stack| {
+    let mut stack = stack.borrow_mut();
+    stack.retain(|existing| existing != id);
+    stack.push(id.to_string());
+  });
+}
+
+/// Remove the overlay from the stack.
+pub(crate) fn remove_overlay(id: &str) {
+  OVERLAY_STACK.with(|stack| stack.borrow_mut().retain(|existing| existing != id));
+}
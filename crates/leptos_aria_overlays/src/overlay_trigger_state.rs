@@ -0,0 +1,148 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_interactions::PointerType;
+use leptos_aria_utils::trace_interaction;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+use leptos_aria_utils::InteractionKind;
+
+/// Why an overlay was opened or closed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum OpenChangeReason {
+  /// The trigger was pressed with a pointer.
+  Press,
+  /// The trigger was activated with the keyboard.
+  Keyboard,
+  /// The state was changed from code, e.g. by a dismiss button.
+  #[default]
+  Programmatic,
+}
+
+impl OpenChangeReason {
+  /// The reason for a press with the provided pointer type.
+  pub fn from_pointer_type(pointer_type: &PointerType) -> Self {
+    match pointer_type {
+      PointerType::Keyboard => Self::Keyboard,
+      _ => Self::Press,
+    }
+  }
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseOverlayTriggerStateProps {
+  /// Whether the overlay is open (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub is_open: Option<MaybeSignal<bool>>,
+
+  /// Whether the overlay is open by default (uncontrolled).
+  #[builder(default)]
+  pub default_open: bool,
+
+  /// Handler that is called with the new open state and the reason it
+  /// changed.
+  #[builder(default, setter(strip_option))]
+  pub on_open_change: Option<Box<dyn Fn(bool, OpenChangeReason)>>,
+}
+
+/// The open state of an overlay and the reason it last changed.
+#[derive(Clone)]
+pub struct OverlayTriggerState {
+  is_open: ControlledState<bool>,
+  pending_reason: Rc<Cell<OpenChangeReason>>,
+  reason: RwSignal<Option<OpenChangeReason>>,
+}
+
+pub fn use_overlay_trigger_state(
+  cx: Scope,
+  props: UseOverlayTriggerStateProps,
+) -> OverlayTriggerState {
+  let pending_reason = Rc::new(Cell::new(OpenChangeReason::default()));
+  let on_change = props.on_open_change.map(|on_open_change| {
+    let pending_reason = pending_reason.clone();
+    Box::new(move |is_open| on_open_change(is_open, pending_reason.get())) as Box<dyn Fn(bool)>
+  });
+
+  OverlayTriggerState {
+    is_open: use_controlled_state(cx, props.is_open, props.default_open, on_change),
+    pending_reason,
+    reason: create_rw_signal(cx, None),
+  }
+}
+
+impl OverlayTriggerState {
+  /// Whether the overlay is open.
+  pub fn is_open(&self) -> bool {
+    self.is_open.get()
+  }
+
+  /// The open state as a signal.
+  pub fn is_open_signal(&self) -> Signal<bool> {
+    self.is_open.signal()
+  }
+
+  /// The reason of the last open state change made through this state.
+  /// `None` until the state has been changed.
+  pub fn reason(&self) -> Option<OpenChangeReason> {
+    self.reason.get()
+  }
+
+  pub fn open(&self, reason: OpenChangeReason) {
+    self.set_open(true, reason);
+  }
+
+  pub fn close(&self, reason: OpenChangeReason) {
+    self.set_open(false, reason);
+  }
+
+  pub fn toggle(&self, reason: OpenChangeReason) {
+    self.set_open(!self.is_open.get_untracked(), reason);
+  }
+
+  /// Set the open state. Nothing happens, and `on_open_change` isn't called,
+  /// when the state doesn't change.
+  pub fn set_open(&self, is_open: bool, reason: OpenChangeReason) {
+    if self.is_open.get_untracked() == is_open {
+      return;
+    }
+
+    let action = if is_open { "open" } else { "close" };
+    trace_interaction(
+      InteractionKind::Overlay,
+      format_args!("{action} ({reason:?})"),
+      None,
+    );
+
+    self.pending_reason.set(reason);
+    self.reason.set(Some(reason));
+    self.is_open.set(is_open);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn keyboard_presses_have_the_keyboard_reason() {
+    assert_eq!(
+      OpenChangeReason::from_pointer_type(&PointerType::Keyboard),
+      OpenChangeReason::Keyboard
+    );
+    assert_eq!(
+      OpenChangeReason::from_pointer_type(&PointerType::Touch),
+      OpenChangeReason::Press
+    );
+    assert_eq!(
+      OpenChangeReason::from_pointer_type(&PointerType::Virtual),
+      OpenChangeReason::Press
+    );
+  }
+}
@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_utils::VISUALLY_HIDDEN_STYLE;
+
+use crate::use_overlay::OverlayCallback;
+
+#[derive(TypedBuilder)]
+pub struct UseDismissButtonProps {
+  /// Handler that is called when the button is activated.
+  pub on_dismiss: Box<dyn Fn()>,
+
+  /// The label of the button. Defaults to `"Dismiss"`, translated for the
+  /// locale.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of an element which labels the button instead of `aria_label`.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct DismissButtonResult {
+  pub aria_label: Signal<String>,
+  pub aria_labelledby: Option<String>,
+  /// Removed from the tab order since keyboard users close overlays with
+  /// <kbd>Escape</kbd>. Screen readers still reach the button with their
+  /// virtual cursor.
+  pub tab_index: i32,
+  /// Hides the button visually without removing it from the accessibility
+  /// tree.
+  pub style: &'static str,
+  pub on_click: OverlayCallback<MouseEvent>,
+}
+
+/// Provides a visually hidden button which closes an overlay.
+///
+/// Render it at the start and end of popovers and menus so that screen reader
+/// users on touch devices, who can't press <kbd>Escape</kbd>, are able to
+/// close overlays which don't trap focus.
+pub fn use_dismiss_button(
+  cx: Scope,
+  props: UseDismissButtonProps,
+) -> ReadSignal<DismissButtonResult> {
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || aria_label.get()).derive_signal(cx),
+    None => {
+      let strings = use_localized_string_formatter(cx);
+      (move || strings.get().format("dismiss")).derive_signal(cx)
+    }
+  };
+  let on_dismiss = props.on_dismiss;
+  let on_click: OverlayCallback<MouseEvent> = Rc::new(Box::new(move |_| on_dismiss()));
+
+  let (dismiss_button_result, _) = create_signal(
+    cx,
+    DismissButtonResult {
+      aria_label,
+      aria_labelledby: props.aria_labelledby,
+      tab_index: -1,
+      style: VISUALLY_HIDDEN_STYLE,
+      on_click,
+    },
+  );
+
+  dismiss_button_result
+}
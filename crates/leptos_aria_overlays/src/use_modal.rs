@@ -0,0 +1,146 @@
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::document;
+use leptos::on_cleanup;
+use leptos::provide_context;
+use leptos::use_context;
+use leptos::web_sys::Element;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::use_id;
+
+/// Tracks the modals open within a [`use_modal_provider`] scope.
+///
+/// Providers can be nested, in which case opening a modal also counts towards
+/// every ancestor provider so that all of the content behind it is hidden.
+#[derive(Clone)]
+pub struct ModalContext {
+  /// The id of the element which modal content is rendered into.
+  pub container_id: String,
+  modal_count: RwSignal<usize>,
+  parent: Option<Box<ModalContext>>,
+}
+
+impl ModalContext {
+  /// The number of open modals within the provider.
+  pub fn modal_count(&self) -> usize {
+    self.modal_count.get()
+  }
+
+  /// The container element, once it is mounted.
+  pub fn container_element(&self) -> Option<Element> {
+    document().get_element_by_id(&self.container_id)
+  }
+
+  fn add_modal(&self) {
+    self.modal_count.update(|count| *count += 1);
+
+    if let Some(ref parent) = self.parent {
+      parent.add_modal();
+    }
+  }
+
+  fn remove_modal(&self) {
+    self
+      .modal_count
+      .update(|count| *count = count.saturating_sub(1));
+
+    if let Some(ref parent) = self.parent {
+      parent.remove_modal();
+    }
+  }
+}
+
+/// The props for the content hidden behind open modals, usually the root of
+/// the application.
+#[derive(Clone)]
+pub struct ModalProviderResult {
+  /// Hides the content from assistive technology while a modal is open.
+  pub aria_hidden: Signal<Option<bool>>,
+  /// Makes the content non-interactive while a modal is open, in browsers
+  /// which support the `inert` attribute.
+  pub inert: Signal<bool>,
+  /// The props for the container which modal content is rendered into. It
+  /// should be a sibling of the hidden content so that it stays accessible.
+  pub container: ModalContainerResult,
+}
+
+#[derive(Clone)]
+pub struct ModalContainerResult {
+  pub id: String,
+  pub data_overlay_container: bool,
+}
+
+/// Provides a [`ModalContext`] for the scope. Render the hidden content and
+/// the modal container next to each other:
+///
+/// * the content receives `aria-hidden` and `inert` while any modal within the
+///   provider is open.
+/// * modals are rendered into the container, which is never hidden.
+///
+/// Modals are reference counted, so the content is only restored once the last
+/// modal closes.
+pub fn use_modal_provider(cx: Scope) -> ReadSignal<ModalProviderResult> {
+  let context = ModalContext {
+    container_id: use_id(cx),
+    modal_count: create_rw_signal(cx, 0),
+    parent: use_context::<ModalContext>(cx).map(Box::new),
+  };
+  let modal_count = context.modal_count;
+  let container_id = context.container_id.clone();
+
+  provide_context(cx, context);
+
+  let (modal_provider_result, _) = create_signal(
+    cx,
+    ModalProviderResult {
+      aria_hidden: (move || (modal_count.get() > 0).then_some(true)).derive_signal(cx),
+      inert: (move || modal_count.get() > 0).derive_signal(cx),
+      container: ModalContainerResult {
+        id: container_id,
+        data_overlay_container: true,
+      },
+    },
+  );
+
+  modal_provider_result
+}
+
+#[derive(Clone)]
+pub struct ModalResult {
+  pub data_ismodal: bool,
+  /// The id of the container which the modal should be rendered into.
+  pub container_id: Option<String>,
+}
+
+/// Registers a modal with the surrounding [`use_modal_provider`] for as long
+/// as the scope lives. Call it in the scope of the modal content, which is only
+/// rendered while the modal is open.
+pub fn use_modal(cx: Scope) -> ReadSignal<ModalResult> {
+  let context = use_context::<ModalContext>(cx);
+
+  match context {
+    Some(ref context) => {
+      context.add_modal();
+
+      let context = context.clone();
+      on_cleanup(cx, move || context.remove_modal());
+    }
+    None => {
+      leptos::warn!("`use_modal` must be used within `use_modal_provider`");
+    }
+  }
+
+  let (modal_result, _) = create_signal(
+    cx,
+    ModalResult {
+      data_ismodal: true,
+      container_id: context.map(|context| context.container_id),
+    },
+  );
+
+  modal_result
+}
@@ -0,0 +1,214 @@
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_signal;
+use leptos::document;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::Node;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_interactions::use_interact_outside;
+use leptos_aria_interactions::UseInteractOutsideProps;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::is_topmost_overlay;
+use crate::overlay_stack::push_overlay;
+use crate::overlay_stack::remove_overlay;
+
+#[derive(TypedBuilder)]
+pub struct UseOverlayProps {
+  /// The id of the overlay element. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// Whether the overlay is currently open.
+  #[builder(setter(into))]
+  pub is_open: MaybeSignal<bool>,
+
+  /// Handler that is called when the overlay should close.
+  #[builder(default, setter(strip_option))]
+  pub on_close: Option<Box<dyn Fn()>>,
+
+  /// Whether to close the overlay when the user interacts outside of it.
+  #[builder(default)]
+  pub is_dismissable: bool,
+
+  /// Whether the overlay should close when focus moves outside of it.
+  #[builder(default)]
+  pub should_close_on_blur: bool,
+
+  /// Whether pressing <kbd>Escape</kbd> should not close the overlay.
+  #[builder(default)]
+  pub is_keyboard_dismiss_disabled: bool,
+
+  /// Called with the target of an interaction outside of the overlay. Return
+  /// `false` to keep the overlay open, e.g. when the target is the trigger
+  /// which toggles the overlay itself.
+  #[builder(default, setter(strip_option))]
+  pub should_close_on_interact_outside: Option<Box<dyn Fn(&Element) -> bool>>,
+}
+
+/// The props for the overlay element.
+#[derive(Clone)]
+pub struct OverlayElementResult {
+  pub id: String,
+  pub on_key_down: OverlayCallback<KeyboardEvent>,
+  pub on_focus_out: OverlayCallback<FocusEvent>,
+}
+
+/// The props for the element rendered behind a modal overlay.
+#[derive(Clone)]
+pub struct UnderlayResult {
+  pub on_pointer_down: OverlayCallback<PointerEvent>,
+}
+
+#[derive(Clone)]
+pub struct OverlayResult {
+  pub overlay: OverlayElementResult,
+  pub underlay: UnderlayResult,
+}
+
+/// Provides the behavior for overlays such as popovers, dialogs and menus.
+///
+/// * <kbd>Escape</kbd> closes the overlay unless `is_keyboard_dismiss_disabled`
+///   is set.
+/// * Interacting outside closes a dismissable overlay.
+/// * Moving focus outside closes the overlay when `should_close_on_blur` is
+///   set.
+///
+/// Open overlays are kept in a global stack and only the topmost overlay
+/// responds to dismissal.
+pub fn use_overlay(cx: Scope, props: UseOverlayProps) -> ReadSignal<OverlayResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let original_is_open = props.is_open;
+  let is_open = (move || original_is_open.get()).derive_signal(cx);
+  let on_close: Rc<dyn Fn()> = match props.on_close {
+    Some(on_close) => Rc::new(on_close),
+    None => Rc::new(|| {}),
+  };
+
+  {
+    let id = id.clone();
+    create_effect(cx, move |_| {
+      if is_open.get() {
+        push_overlay(&id);
+      } else {
+        remove_overlay(&id);
+      }
+    });
+  }
+
+  {
+    let id = id.clone();
+    on_cleanup(cx, move || remove_overlay(&id));
+  }
+
+  {
+    let id = id.clone();
+    let on_close = on_close.clone();
+    let should_close_on_interact_outside = props.should_close_on_interact_outside;
+    let is_dismissable = props.is_dismissable;
+
+    use_interact_outside(
+      cx,
+      UseInteractOutsideProps::builder()
+        .id(id.clone())
+        .is_disabled((move || !is_dismissable || !is_open.get()).derive_signal(cx))
+        .on_interact_outside(Box::new(move |event: PointerEvent| {
+          if !is_topmost_overlay(&id) {
+            return;
+          }
+
+          let should_close = match should_close_on_interact_outside {
+            Some(ref should_close) => {
+              event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .map_or(true, |target| should_close(&target))
+            }
+            None => true,
+          };
+
+          if should_close {
+            on_close();
+          }
+        }))
+        .build(),
+    );
+  }
+
+  let on_key_down: OverlayCallback<KeyboardEvent> = {
+    let id = id.clone();
+    let on_close = on_close.clone();
+    let is_keyboard_dismiss_disabled = props.is_keyboard_dismiss_disabled;
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if KeyboardKey::from(&event) != KeyboardKey::Escape
+        || is_keyboard_dismiss_disabled
+        || !is_topmost_overlay(&id)
+      {
+        return;
+      }
+
+      event.stop_propagation();
+      event.prevent_default();
+      on_close();
+    }))
+  };
+
+  let on_focus_out: OverlayCallback<FocusEvent> = {
+    let id = id.clone();
+    let should_close_on_blur = props.should_close_on_blur;
+
+    Rc::new(Box::new(move |event: FocusEvent| {
+      if !should_close_on_blur || !is_open.get_untracked() {
+        return;
+      }
+
+      let related_target = event
+        .related_target()
+        .and_then(|target| target.dyn_into::<Node>().ok());
+      let is_within = document()
+        .get_element_by_id(&id)
+        .map_or(false, |element| element.contains(related_target.as_ref()));
+
+      if !is_within {
+        on_close();
+      }
+    }))
+  };
+
+  // Prevent focus from leaving the overlay when the underlay itself is
+  // pressed.
+  let on_pointer_down: OverlayCallback<PointerEvent> = Rc::new(Box::new(|event: PointerEvent| {
+    if event.target() == event.current_target() {
+      event.prevent_default();
+    }
+  }));
+
+  let (overlay_result, _) = create_signal(
+    cx,
+    OverlayResult {
+      overlay: OverlayElementResult {
+        id,
+        on_key_down,
+        on_focus_out,
+      },
+      underlay: UnderlayResult { on_pointer_down },
+    },
+  );
+
+  overlay_result
+}
+
+pub(crate) type OverlayCallback<E> = Rc<Box<dyn Fn(E)>>;
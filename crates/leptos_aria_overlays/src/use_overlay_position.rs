@@ -0,0 +1,586 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::create_effect;
+use leptos::create_rw_signal;
+use leptos::document;
+use leptos::js_sys::Function;
+use leptos::on_cleanup;
+use leptos::request_animation_frame;
+use leptos::typed_builder::TypedBuilder;
+use leptos::wasm_bindgen::prelude::Closure;
+use leptos::web_sys::Element;
+use leptos::web_sys::Event;
+use leptos::web_sys::Node;
+use leptos::window;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_interactions::Rect;
+use leptos_aria_utils::create_listener_closure;
+use leptos_aria_utils::is_scroll_container;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::throttle;
+use leptos_aria_utils::GlobalListeners;
+use leptos_aria_utils::ResizeObserver;
+
+/// The minimum distance in pixels between the overlay and the edges of the
+/// viewport.
+pub const OVERLAY_CONTAINER_PADDING: f64 = 12.0;
+
+/// The minimum time between repositioning the overlay while the window is
+/// resized, about a frame.
+const RESIZE_THROTTLE: Duration = Duration::from_millis(16);
+
+/// The side of the trigger the overlay is placed on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Placement {
+  #[default]
+  Bottom,
+  Top,
+  Left,
+  Right,
+}
+
+impl Placement {
+  /// The placement on the opposite side of the trigger.
+  pub fn flipped(self) -> Self {
+    match self {
+      Self::Bottom => Self::Top,
+      Self::Top => Self::Bottom,
+      Self::Left => Self::Right,
+      Self::Right => Self::Left,
+    }
+  }
+
+  /// The space available for the overlay on this side of the trigger.
+  fn available_space(self, trigger: Rect, viewport: Size, offset: f64, padding: f64) -> f64 {
+    match self {
+      Self::Bottom => viewport.height - trigger.bottom - offset - padding,
+      Self::Top => trigger.top - offset - padding,
+      Self::Left => trigger.left - offset - padding,
+      Self::Right => viewport.width - trigger.right - offset - padding,
+    }
+  }
+
+  fn is_vertical(self) -> bool {
+    matches!(self, Self::Bottom | Self::Top)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Size {
+  pub width: f64,
+  pub height: f64,
+}
+
+/// The position of the overlay relative to the viewport.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OverlayPosition {
+  pub top: f64,
+  pub left: f64,
+  pub max_height: f64,
+  /// The placement after flipping.
+  pub placement: Placement,
+}
+
+impl OverlayPosition {
+  /// The inline style for a `position: fixed` overlay.
+  pub fn style(&self) -> String {
+    format!(
+      "position: fixed; top: {}px; left: {}px; max-height: {}px;",
+      self.top, self.left, self.max_height
+    )
+  }
+}
+
+/// Calculate where the overlay should be placed. The overlay flips to the
+/// opposite side when it doesn't fit and there is more space there, and is
+/// kept within the viewport along the other axis.
+pub fn calculate_overlay_position(
+  trigger: Rect,
+  overlay: Size,
+  viewport: Size,
+  placement: Placement,
+  offset: f64,
+  padding: f64,
+  should_flip: bool,
+) -> OverlayPosition {
+  let size = |placement: Placement| {
+    if placement.is_vertical() {
+      overlay.height
+    } else {
+      overlay.width
+    }
+  };
+  let space = |placement: Placement| placement.available_space(trigger, viewport, offset, padding);
+
+  let placement = if should_flip
+    && space(placement) < size(placement)
+    && space(placement.flipped()) > space(placement)
+  {
+    placement.flipped()
+  } else {
+    placement
+  };
+
+  let clamp = |value: f64, size: f64, max: f64| value.min(max - padding - size).max(padding);
+
+  let (top, left) = match placement {
+    Placement::Bottom => {
+      (
+        trigger.bottom + offset,
+        clamp(trigger.left, overlay.width, viewport.width),
+      )
+    }
+    Placement::Top => {
+      (
+        trigger.top - offset - overlay.height.min(space(placement)),
+        clamp(trigger.left, overlay.width, viewport.width),
+      )
+    }
+    Placement::Left => {
+      (
+        clamp(trigger.top, overlay.height, viewport.height),
+        trigger.left - offset - overlay.width,
+      )
+    }
+    Placement::Right => {
+      (
+        clamp(trigger.top, overlay.height, viewport.height),
+        trigger.right + offset,
+      )
+    }
+  };
+
+  let max_height = if placement.is_vertical() {
+    space(placement)
+  } else {
+    viewport.height - padding * 2.0
+  };
+
+  OverlayPosition {
+    top,
+    left,
+    max_height: max_height.max(0.0),
+    placement,
+  }
+}
+
+/// Whether any part of the rect is visible within the clipping rect.
+pub fn is_rect_visible(rect: Rect, clip: Rect) -> bool {
+  rect.bottom > clip.top
+    && rect.top < clip.bottom
+    && rect.right > clip.left
+    && rect.left < clip.right
+}
+
+#[derive(TypedBuilder)]
+pub struct UseOverlayPositionProps {
+  /// The id of the element which triggers the overlay.
+  #[builder(setter(into))]
+  pub trigger_id: MaybeSignal<String>,
+
+  /// The id of the overlay element.
+  #[builder(setter(into))]
+  pub overlay_id: MaybeSignal<String>,
+
+  /// Whether the overlay is currently open. The position is only tracked
+  /// while open.
+  #[builder(setter(into))]
+  pub is_open: MaybeSignal<bool>,
+
+  /// The preferred side of the trigger. Defaults to [`Placement::Bottom`].
+  #[builder(default, setter(strip_option, into))]
+  pub placement: Option<MaybeSignal<Placement>>,
+
+  /// The distance in pixels between the trigger and the overlay.
+  #[builder(default)]
+  pub offset: f64,
+
+  /// The minimum distance between the overlay and the viewport edges.
+  /// Defaults to [`OVERLAY_CONTAINER_PADDING`].
+  #[builder(default, setter(strip_option))]
+  pub container_padding: Option<f64>,
+
+  /// Whether the overlay flips to the opposite side when it doesn't fit.
+  #[builder(default = true)]
+  pub should_flip: bool,
+
+  /// Whether to close the overlay, instead of repositioning it, when a scroll
+  /// container of the trigger scrolls.
+  #[builder(default)]
+  pub should_close_on_scroll: bool,
+
+  /// Handler that is called when the overlay should close, either because
+  /// `should_close_on_scroll` is set or because the trigger scrolled out of
+  /// view.
+  #[builder(default, setter(strip_option))]
+  pub on_close: Option<Box<dyn Fn()>>,
+}
+
+#[derive(Clone)]
+pub struct OverlayPositionResult {
+  /// The inline style of the overlay. The overlay is hidden until it has been
+  /// measured.
+  pub style: Signal<String>,
+  /// The placement after flipping.
+  pub placement: Signal<Placement>,
+  /// Recalculate the position, e.g. after the content of the overlay changed.
+  pub update_position: Rc<dyn Fn()>,
+}
+
+/// Positions an overlay such as a popover relative to its trigger.
+///
+/// While open, the position is updated when the trigger or the overlay
+/// resize, when the window resizes and when any scroll container scrolls.
+/// When the trigger scrolls out of view `on_close` is called.
+pub fn use_overlay_position(cx: Scope, props: UseOverlayPositionProps) -> OverlayPositionResult {
+  let original_trigger_id = props.trigger_id;
+  let trigger_id = (move || original_trigger_id.get()).derive_signal(cx);
+  let original_overlay_id = props.overlay_id;
+  let overlay_id = (move || original_overlay_id.get()).derive_signal(cx);
+  let original_is_open = props.is_open;
+  let is_open = (move || original_is_open.get()).derive_signal(cx);
+  let original_placement = props.placement.unwrap_or(Placement::Bottom.into());
+  let placement = (move || original_placement.get()).derive_signal(cx);
+  let offset = props.offset;
+  let padding = props.container_padding.unwrap_or(OVERLAY_CONTAINER_PADDING);
+  let should_flip = props.should_flip;
+  let should_close_on_scroll = props.should_close_on_scroll;
+  let on_close: Option<Rc<dyn Fn()>> = props.on_close.map(Rc::from);
+  let position = create_rw_signal::<Option<OverlayPosition>>(cx, None);
+
+  let update_position: Rc<dyn Fn()> = {
+    let on_close = on_close.clone();
+
+    Rc::new(move || {
+      let (Some(trigger), Some(overlay)) = (
+        document().get_element_by_id(&trigger_id.get_untracked()),
+        document().get_element_by_id(&overlay_id.get_untracked()),
+      ) else {
+        return;
+      };
+
+      let trigger_rect = to_rect(&trigger);
+      let viewport = viewport_size();
+
+      if !is_trigger_visible(&trigger, trigger_rect, viewport) {
+        if let Some(on_close) = &on_close {
+          on_close();
+          return;
+        }
+      }
+
+      let next = calculate_overlay_position(
+        trigger_rect,
+        Size {
+          width: overlay.scroll_width().into(),
+          height: overlay.scroll_height().into(),
+        },
+        viewport,
+        placement.get_untracked(),
+        offset,
+        padding,
+        should_flip,
+      );
+
+      if position.get_untracked() != Some(next) {
+        position.set(Some(next));
+      }
+    })
+  };
+
+  let style = (move || {
+    position.get().map_or_else(
+      || "position: fixed; visibility: hidden;".to_string(),
+      |position| position.style(),
+    )
+  })
+  .derive_signal(cx);
+  let current_placement = (move || {
+    position
+      .get()
+      .map_or_else(|| placement.get(), |position| position.placement)
+  })
+  .derive_signal(cx);
+
+  let result = OverlayPositionResult {
+    style,
+    placement: current_placement,
+    update_position: update_position.clone(),
+  };
+
+  if is_server() {
+    return result;
+  }
+
+  let listeners = GlobalListeners::new(cx);
+  let observer = Rc::new(RefCell::new(None::<ResizeObserver>));
+
+  let on_resize = {
+    let update_position = update_position.clone();
+
+    // Resizing fires many events, so reposition at most once per frame.
+    let update_position = throttle(cx, RESIZE_THROTTLE, move |()| update_position());
+
+    create_listener_closure(move |_: Event| update_position(()))
+  };
+
+  let on_scroll = {
+    let update_position = update_position.clone();
+
+    let handler = move |event: Event| {
+      let Some(target) = event
+        .target()
+        .and_then(|target| target.dyn_into::<Node>().ok())
+      else {
+        return;
+      };
+      let Some(trigger) = document().get_element_by_id(&trigger_id.get_untracked()) else {
+        return;
+      };
+
+      // Scrolling within the overlay or in an unrelated container doesn't move
+      // the trigger.
+      let is_within_overlay = document()
+        .get_element_by_id(&overlay_id.get_untracked())
+        .map_or(false, |overlay| overlay.contains(Some(&target)));
+
+      if is_within_overlay || !target.contains(Some(trigger.as_ref())) {
+        return;
+      }
+
+      match &on_close {
+        Some(on_close) if should_close_on_scroll => on_close(),
+        _ => update_position(),
+      }
+    };
+
+    create_listener_closure(handler)
+  };
+
+  {
+    let listeners = listeners.clone();
+    let observer = observer.clone();
+
+    create_effect(cx, move |_| {
+      // Reposition when the preferred placement changes.
+      let _ = placement.get();
+      listeners.remove_all_listeners();
+      disconnect(&observer);
+
+      if !is_open.get() {
+        if position.get_untracked().is_some() {
+          position.set(None);
+        }
+
+        return;
+      }
+
+      listeners.add_closure_listener(window(), "resize", on_resize.clone(), false);
+      // Scroll events don't bubble, so listen in the capture phase to be
+      // notified about every scroll container.
+      listeners.add_closure_listener(document(), "scroll", on_scroll.clone(), true);
+
+      let update_position = update_position.clone();
+      let observer = observer.clone();
+
+      // Wait for the overlay to be rendered before measuring it.
+      request_animation_frame(move || {
+        if !is_open.get_untracked() {
+          return;
+        }
+
+        update_position();
+
+        let callback = Closure::wrap(Box::new(move || update_position()) as Box<dyn Fn()>)
+          .into_js_value()
+          .unchecked_into::<Function>();
+        let Ok(resize_observer) = ResizeObserver::new(&callback) else {
+          return;
+        };
+
+        for id in [trigger_id.get_untracked(), overlay_id.get_untracked()] {
+          if let Some(element) = document().get_element_by_id(&id) {
+            resize_observer.observe(&element);
+          }
+        }
+
+        disconnect(&observer);
+        *observer.borrow_mut() = Some(resize_observer);
+      });
+    });
+  }
+
+  on_cleanup(cx, move || disconnect(&observer));
+
+  result
+}
+
+fn disconnect(observer: &RefCell<Option<ResizeObserver>>) {
+  if let Some(observer) = observer.borrow_mut().take() {
+    observer.disconnect();
+  }
+}
+
+fn to_rect(element: &Element) -> Rect {
+  let rect = element.get_bounding_client_rect();
+
+  Rect {
+    top: rect.top(),
+    right: rect.right(),
+    bottom: rect.bottom(),
+    left: rect.left(),
+  }
+}
+
+fn viewport_size() -> Size {
+  let element = document().document_element();
+
+  Size {
+    width: element
+      .as_ref()
+      .map_or(0.0, |element| element.client_width().into()),
+    height: element
+      .as_ref()
+      .map_or(0.0, |element| element.client_height().into()),
+  }
+}
+
+/// Whether the trigger is visible within the viewport and all of its scroll
+/// containers.
+fn is_trigger_visible(trigger: &Element, rect: Rect, viewport: Size) -> bool {
+  let viewport = Rect {
+    top: 0.0,
+    right: viewport.width,
+    bottom: viewport.height,
+    left: 0.0,
+  };
+
+  if !is_rect_visible(rect, viewport) {
+    return false;
+  }
+
+  let mut parent = trigger.parent_element();
+
+  while let Some(element) = parent {
+    if is_scroll_container(&element) && !is_rect_visible(rect, to_rect(&element)) {
+      return false;
+    }
+
+    parent = element.parent_element();
+  }
+
+  true
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const VIEWPORT: Size = Size {
+    width: 1000.0,
+    height: 800.0,
+  };
+
+  fn trigger(top: f64, left: f64) -> Rect {
+    Rect {
+      top,
+      right: left + 100.0,
+      bottom: top + 40.0,
+      left,
+    }
+  }
+
+  #[test]
+  fn places_below_the_trigger() {
+    let overlay = Size {
+      width: 200.0,
+      height: 300.0,
+    };
+    let position = calculate_overlay_position(
+      trigger(100.0, 50.0),
+      overlay,
+      VIEWPORT,
+      Placement::Bottom,
+      8.0,
+      12.0,
+      true,
+    );
+
+    assert_eq!(position.placement, Placement::Bottom);
+    assert_eq!(position.top, 148.0);
+    assert_eq!(position.left, 50.0);
+    assert_eq!(position.max_height, 640.0);
+  }
+
+  #[test]
+  fn flips_when_there_is_more_space_on_the_other_side() {
+    let overlay = Size {
+      width: 200.0,
+      height: 300.0,
+    };
+    let position = calculate_overlay_position(
+      trigger(600.0, 50.0),
+      overlay,
+      VIEWPORT,
+      Placement::Bottom,
+      0.0,
+      12.0,
+      true,
+    );
+
+    assert_eq!(position.placement, Placement::Top);
+    assert_eq!(position.top, 300.0);
+
+    let position = calculate_overlay_position(
+      trigger(600.0, 50.0),
+      overlay,
+      VIEWPORT,
+      Placement::Bottom,
+      0.0,
+      12.0,
+      false,
+    );
+
+    assert_eq!(position.placement, Placement::Bottom);
+  }
+
+  #[test]
+  fn stays_within_the_viewport() {
+    let overlay = Size {
+      width: 300.0,
+      height: 100.0,
+    };
+    let position = calculate_overlay_position(
+      trigger(100.0, 900.0),
+      overlay,
+      VIEWPORT,
+      Placement::Bottom,
+      0.0,
+      12.0,
+      true,
+    );
+
+    assert_eq!(position.left, 688.0);
+  }
+
+  #[test]
+  fn detects_hidden_rects() {
+    let clip = Rect {
+      top: 0.0,
+      right: 500.0,
+      bottom: 500.0,
+      left: 0.0,
+    };
+
+    assert!(is_rect_visible(trigger(480.0, 0.0), clip));
+    assert!(!is_rect_visible(trigger(500.0, 0.0), clip));
+    assert!(!is_rect_visible(trigger(-40.0, 0.0), clip));
+  }
+}
@@ -0,0 +1,114 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_interactions::use_press;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_interactions::PressResult;
+use leptos_aria_interactions::UsePressProps;
+use leptos_aria_utils::use_id;
+
+use crate::OpenChangeReason;
+use crate::OverlayTriggerState;
+
+/// The kind of overlay opened by the trigger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum OverlayTriggerType {
+  #[default]
+  Dialog,
+  Menu,
+  Listbox,
+  Tree,
+  Grid,
+}
+
+impl OverlayTriggerType {
+  /// The value of `aria-haspopup`.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Dialog => "dialog",
+      Self::Menu => "menu",
+      Self::Listbox => "listbox",
+      Self::Tree => "tree",
+      Self::Grid => "grid",
+    }
+  }
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseOverlayTriggerProps {
+  /// The kind of overlay opened by the trigger.
+  #[builder(default)]
+  pub overlay_type: OverlayTriggerType,
+
+  /// The id of the overlay element. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub overlay_id: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct OverlayTriggerElementResult {
+  pub aria_haspopup: &'static str,
+  pub aria_expanded: Signal<bool>,
+  /// Only set while the overlay is open.
+  pub aria_controls: Signal<Option<String>>,
+  /// Toggles the overlay with the [`OpenChangeReason::Press`] or
+  /// [`OpenChangeReason::Keyboard`] reason.
+  pub press: ReadSignal<PressResult>,
+}
+
+#[derive(Clone)]
+pub struct OverlayTriggerOverlayResult {
+  pub id: String,
+}
+
+#[derive(Clone)]
+pub struct OverlayTriggerResult {
+  pub trigger: OverlayTriggerElementResult,
+  pub overlay: OverlayTriggerOverlayResult,
+}
+
+/// Provides the props for a trigger which toggles an overlay, such as a
+/// popover or a menu, and connects it to the overlay.
+pub fn use_overlay_trigger(
+  cx: Scope,
+  state: &OverlayTriggerState,
+  props: UseOverlayTriggerProps,
+) -> ReadSignal<OverlayTriggerResult> {
+  let overlay_id = props.overlay_id.unwrap_or_else(|| use_id(cx));
+  let is_open = state.is_open_signal();
+
+  let on_press = {
+    let state = state.clone();
+    move |event: &PressEvent| state.toggle(OpenChangeReason::from_pointer_type(&event.pointer_type))
+  };
+
+  let press = use_press(
+    cx,
+    UsePressProps::builder()
+      .on_press(Box::new(on_press))
+      .build(),
+  );
+
+  let aria_controls = {
+    let overlay_id = overlay_id.clone();
+    (move || is_open.get().then(|| overlay_id.clone())).derive_signal(cx)
+  };
+
+  let (overlay_trigger_result, _) = create_signal(
+    cx,
+    OverlayTriggerResult {
+      trigger: OverlayTriggerElementResult {
+        aria_haspopup: props.overlay_type.as_str(),
+        aria_expanded: is_open,
+        aria_controls,
+        press,
+      },
+      overlay: OverlayTriggerOverlayResult { id: overlay_id },
+    },
+  );
+
+  overlay_trigger_result
+}
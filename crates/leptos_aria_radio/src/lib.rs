@@ -0,0 +1,7 @@
+pub use radio_group_state::*;
+pub use use_radio::*;
+pub use use_radio_group::*;
+
+mod radio_group_state;
+mod use_radio;
+mod use_radio_group;
@@ -0,0 +1,124 @@
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::ControlledState;
+use leptos_aria_utils::FieldState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseRadioGroupStateProps {
+  /// The value of the selected radio (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub value: Option<MaybeSignal<Option<String>>>,
+
+  /// The value of the radio selected by default (uncontrolled).
+  #[builder(default, setter(strip_option, into))]
+  pub default_value: Option<String>,
+
+  /// Handler that is called when the value changes.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(Option<String>)>>,
+
+  /// The name of the radio group, used when submitting an HTML form. A unique
+  /// name is generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub name: Option<String>,
+
+  /// Whether the radio group is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// Whether the value can be seen but not changed by the user.
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+
+  /// Whether a radio must be selected.
+  #[builder(default, setter(strip_option, into))]
+  pub is_required: Option<MaybeSignal<bool>>,
+
+  /// Whether the current value is invalid.
+  #[builder(default, setter(strip_option, into))]
+  pub is_invalid: Option<MaybeSignal<bool>>,
+}
+
+/// The state of a radio group.
+#[derive(Clone)]
+pub struct RadioGroupState {
+  pub name: String,
+  pub field: FieldState,
+  pub is_required: Signal<bool>,
+  selected_value: ControlledState<Option<String>>,
+  last_focused_value: RwSignal<Option<String>>,
+  is_invalid: Signal<bool>,
+}
+
+pub fn use_radio_group_state(cx: Scope, props: UseRadioGroupStateProps) -> RadioGroupState {
+  let name = props.name.unwrap_or_else(|| use_id(cx));
+  let field = use_field_state(cx, props.is_disabled, props.is_read_only);
+  let original_is_required = props.is_required.unwrap_or(false.into());
+  let original_is_invalid = props.is_invalid.unwrap_or(false.into());
+
+  RadioGroupState {
+    name,
+    field,
+    is_required: (move || original_is_required.get()).derive_signal(cx),
+    selected_value: use_controlled_state(cx, props.value, props.default_value, props.on_change),
+    last_focused_value: create_rw_signal(cx, None),
+    is_invalid: (move || original_is_invalid.get()).derive_signal(cx),
+  }
+}
+
+impl RadioGroupState {
+  /// The value of the selected radio.
+  pub fn selected_value(&self) -> Option<String> {
+    self.selected_value.get()
+  }
+
+  pub fn selected_value_untracked(&self) -> Option<String> {
+    self.selected_value.get_untracked()
+  }
+
+  /// Whether the radio with the provided value is selected.
+  pub fn is_selected(&self, value: &str) -> bool {
+    self
+      .selected_value
+      .signal()
+      .with(|selected| selected.as_deref() == Some(value))
+  }
+
+  /// Select the radio with the provided value. This is ignored when the group
+  /// is disabled or read only.
+  pub fn set_selected_value(&self, value: impl Into<String>) {
+    if !self.field.can_change() {
+      return;
+    }
+
+    self.selected_value.set(Some(value.into()));
+  }
+
+  /// The value of the last focused radio.
+  pub fn last_focused_value(&self) -> Option<String> {
+    self.last_focused_value.get()
+  }
+
+  pub fn set_last_focused_value(&self, value: Option<String>) {
+    self.last_focused_value.set(value);
+  }
+
+  /// Whether the group is invalid. Validation is skipped when the group is
+  /// disabled or read only.
+  pub fn is_invalid(&self) -> bool {
+    if !self.field.should_validate() {
+      return false;
+    }
+
+    self.is_invalid.get()
+      || (self.is_required.get() && self.selected_value.signal().with(Option::is_none))
+  }
+}
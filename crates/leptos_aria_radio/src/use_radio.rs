@@ -0,0 +1,208 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Event;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+use crate::use_radio_group::RadioCallback;
+use crate::RadioGroupState;
+
+#[derive(TypedBuilder)]
+pub struct UseRadioProps {
+  /// The value of the radio, used when submitting an HTML form.
+  #[builder(setter(into))]
+  pub value: String,
+
+  /// Whether this radio is disabled. The radio is always disabled when the
+  /// group is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+}
+
+/// The props for the `<input type="radio">` element.
+#[derive(Clone)]
+pub struct RadioResult {
+  pub input_type: &'static str,
+  pub name: String,
+  pub value: String,
+  pub checked: Signal<bool>,
+  pub disabled: Signal<bool>,
+  /// Prevents the radio from being selected when the group is read only.
+  pub on_click: RadioCallback<MouseEvent>,
+  pub on_change: RadioCallback<Event>,
+  pub on_focus: RadioCallback<FocusEvent>,
+}
+
+/// Provides the behavior and accessibility implementation for an individual
+/// radio button in a radio group.
+pub fn use_radio(
+  cx: Scope,
+  state: &RadioGroupState,
+  props: UseRadioProps,
+) -> ReadSignal<RadioResult> {
+  let value = props.value;
+  let is_group_disabled = state.field.is_disabled;
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let disabled = (move || is_group_disabled.get() || original_is_disabled.get()).derive_signal(cx);
+
+  let checked = {
+    let state = state.clone();
+    let value = value.clone();
+    (move || state.is_selected(&value)).derive_signal(cx)
+  };
+
+  let on_click: RadioCallback<MouseEvent> = {
+    let is_read_only = state.field.is_read_only;
+
+    let handler = move |event: MouseEvent| {
+      if is_read_only.get_untracked() {
+        event.prevent_default();
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let on_change: RadioCallback<Event> = {
+    let state = state.clone();
+    let value = value.clone();
+
+    let handler = move |_: Event| {
+      if disabled.get_untracked() {
+        return;
+      }
+
+      state.set_selected_value(value.clone());
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let on_focus: RadioCallback<FocusEvent> = {
+    let state = state.clone();
+    let value = value.clone();
+
+    let handler = move |_: FocusEvent| {
+      state.set_last_focused_value(Some(value.clone()));
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let (radio_result, _) = create_signal(
+    cx,
+    RadioResult {
+      input_type: "radio",
+      name: state.name.clone(),
+      value,
+      checked,
+      disabled,
+      on_click,
+      on_change,
+      on_focus,
+    },
+  );
+
+  radio_result
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+  use crate::use_radio_group_state;
+  use crate::UseRadioGroupStateProps;
+
+  #[test]
+  fn read_only_group_keeps_the_radios_enabled() {
+    let (disabled, is_selected) = run_scope(create_runtime(), |cx| {
+      let state = use_radio_group_state(
+        cx,
+        UseRadioGroupStateProps::builder()
+          .is_read_only(true)
+          .build(),
+      );
+      let result =
+        use_radio(cx, &state, UseRadioProps::builder().value("a").build()).get_untracked();
+      state.set_selected_value("a");
+
+      (
+        result.disabled.get(),
+        state.selected_value_untracked().is_some(),
+      )
+    });
+
+    assert!(!disabled);
+    assert!(!is_selected);
+  }
+
+  #[cfg(target_arch = "wasm32")]
+  mod browser {
+    use leptos::document;
+    use leptos::wasm_bindgen::prelude::Closure;
+    use leptos::web_sys::HtmlElement;
+    use leptos::web_sys::HtmlInputElement;
+    use leptos::JsCast;
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Render an input for the radio, click it and return the selected value
+    /// and whether the input could be focused.
+    fn click_radio(is_read_only: bool) -> (Option<String>, bool) {
+      run_scope(create_runtime(), move |cx| {
+        let state = use_radio_group_state(
+          cx,
+          UseRadioGroupStateProps::builder()
+            .is_read_only(is_read_only)
+            .build(),
+        );
+        let result =
+          use_radio(cx, &state, UseRadioProps::builder().value("a").build()).get_untracked();
+        let input: HtmlInputElement = document().create_element("input").unwrap().unchecked_into();
+        input.set_type(result.input_type);
+        input.set_name(&result.name);
+        document().body().unwrap().append_child(&input).unwrap();
+
+        let on_click = result.on_click.clone();
+        let on_click = Closure::wrap(
+          Box::new(move |event: MouseEvent| on_click(event)) as Box<dyn Fn(MouseEvent)>
+        );
+        let on_change = result.on_change.clone();
+        let on_change =
+          Closure::wrap(Box::new(move |event: Event| on_change(event)) as Box<dyn Fn(Event)>);
+        input
+          .add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())
+          .unwrap();
+        input
+          .add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref())
+          .unwrap();
+
+        input.click();
+        input.unchecked_ref::<HtmlElement>().focus().unwrap();
+        let is_focused = document().active_element() == Some(input.clone().into());
+        input.remove();
+
+        (state.selected_value_untracked(), is_focused)
+      })
+    }
+
+    #[wasm_bindgen_test]
+    fn read_only_radio_ignores_clicks_but_stays_focusable() {
+      assert_eq!(click_radio(true), (None, true));
+      assert_eq!(click_radio(false), (Some("a".to_string()), true));
+    }
+  }
+}
@@ -0,0 +1,159 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::EventTarget;
+use leptos::web_sys::HtmlInputElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::Orientation;
+
+use crate::RadioGroupState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseRadioGroupProps {
+  /// The axis the radio group is laid out along.
+  #[builder(default, setter(strip_option, into))]
+  pub orientation: Option<MaybeSignal<Orientation>>,
+
+  /// An accessible label for the group.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the group.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+}
+
+#[derive(Clone)]
+pub struct RadioGroupResult {
+  pub role: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+  pub aria_orientation: Signal<&'static str>,
+  pub aria_disabled: Signal<Option<bool>>,
+  pub aria_readonly: Signal<Option<bool>>,
+  pub aria_required: Signal<Option<bool>>,
+  pub aria_invalid: Signal<Option<bool>>,
+  pub on_key_down: RadioCallback<KeyboardEvent>,
+}
+
+/// Provides the behavior and accessibility implementation for a radio group
+/// component. Radio groups allow users to select a single item from a list of
+/// mutually exclusive options.
+///
+/// The arrow keys move focus between the radios and select the focused radio.
+/// <kbd>ArrowLeft</kbd> moves forward in right to left locales. When the
+/// group is read only focus still moves but the value is unchanged.
+pub fn use_radio_group(
+  cx: Scope,
+  state: &RadioGroupState,
+  props: UseRadioGroupProps,
+) -> ReadSignal<RadioGroupResult> {
+  let original_orientation = props.orientation.unwrap_or(Orientation::Vertical.into());
+  let aria_orientation =
+    (move || original_orientation.get().as_aria_orientation()).derive_signal(cx);
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_required = {
+    let is_required = state.is_required;
+    (move || is_required.get().then_some(true)).derive_signal(cx)
+  };
+  let aria_invalid = {
+    let state = state.clone();
+    (move || state.is_invalid().then_some(true)).derive_signal(cx)
+  };
+
+  let direction = use_direction(cx);
+
+  let on_key_down: RadioCallback<KeyboardEvent> = {
+    let state = state.clone();
+
+    let handler = move |event: KeyboardEvent| {
+      let forward = match KeyboardKey::from(&event).logical(direction.get_untracked()) {
+        KeyboardKey::ArrowDown | KeyboardKey::ArrowRight => true,
+        KeyboardKey::ArrowUp | KeyboardKey::ArrowLeft => false,
+        _ => return,
+      };
+
+      let Some(group) = event
+        .current_target()
+        .map(|target| target.unchecked_into::<Element>())
+      else {
+        return;
+      };
+
+      let radios = get_enabled_radios(&group);
+
+      if radios.is_empty() {
+        return;
+      }
+
+      event.prevent_default();
+
+      let current = event.target().and_then(|target| {
+        radios
+          .iter()
+          .position(|radio| AsRef::<EventTarget>::as_ref(radio) == &target)
+      });
+      let next = match (current, forward) {
+        (Some(index), true) => (index + 1) % radios.len(),
+        (Some(index), false) => (index + radios.len() - 1) % radios.len(),
+        (None, true) => 0,
+        (None, false) => radios.len() - 1,
+      };
+
+      let radio = &radios[next];
+      radio.focus().ok();
+      state.set_selected_value(radio.value());
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let (radio_group_result, _) = create_signal(
+    cx,
+    RadioGroupResult {
+      role: "radiogroup",
+      aria_label,
+      aria_labelledby,
+      aria_orientation,
+      aria_disabled: state.field.aria_disabled(cx),
+      aria_readonly: state.field.aria_readonly(cx, "radiogroup"),
+      aria_required,
+      aria_invalid,
+      on_key_down,
+    },
+  );
+
+  radio_group_result
+}
+
+pub(crate) type RadioCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// All the radios within the group which can receive focus.
+fn get_enabled_radios(group: &Element) -> Vec<HtmlInputElement> {
+  let Ok(node_list) = group.query_selector_all("input[type=\"radio\"]:not([disabled])") else {
+    return vec![];
+  };
+
+  (0..node_list.length())
+    .filter_map(|index| node_list.item(index))
+    .filter_map(|node| node.dyn_into::<HtmlInputElement>().ok())
+    .collect()
+}
@@ -0,0 +1,5 @@
+pub use recent_searches_store::*;
+pub use use_recent_searches::*;
+
+mod recent_searches_store;
+mod use_recent_searches;
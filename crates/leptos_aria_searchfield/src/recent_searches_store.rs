@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+
+use leptos::js_sys::Array;
+use leptos::js_sys::JsString;
+use leptos::js_sys::JSON;
+use leptos::wasm_bindgen::JsValue;
+use leptos::web_sys::window;
+use leptos::web_sys::Storage;
+use leptos::JsCast;
+
+/// Persists the recent queries of a search field.
+pub trait RecentSearchesStore {
+  /// Load the stored queries, most recent first.
+  fn load(&self) -> Vec<String>;
+
+  /// Replace the stored queries.
+  fn save(&self, searches: &[String]);
+}
+
+/// Stores the recent queries in memory for the lifetime of the store.
+#[derive(Default)]
+pub struct MemoryRecentSearches(RefCell<Vec<String>>);
+
+impl RecentSearchesStore for MemoryRecentSearches {
+  fn load(&self) -> Vec<String> {
+    self.0.borrow().clone()
+  }
+
+  fn save(&self, searches: &[String]) {
+    *self.0.borrow_mut() = searches.to_vec();
+  }
+}
+
+/// Stores the recent queries as a JSON array in `localStorage`.
+///
+/// Reading and writing fail silently, e.g. when storage is disabled in private
+/// browsing, in which case the history is simply empty.
+pub struct LocalStorageRecentSearches {
+  key: String,
+}
+
+impl LocalStorageRecentSearches {
+  /// Create a store which saves the queries under the provided storage key.
+  pub fn new(key: impl Into<String>) -> Self {
+    Self { key: key.into() }
+  }
+
+  fn storage() -> Option<Storage> {
+    window()?.local_storage().ok().flatten()
+  }
+}
+
+impl RecentSearchesStore for LocalStorageRecentSearches {
+  fn load(&self) -> Vec<String> {
+    let Some(value) =
+      Self::storage().and_then(|storage| storage.get_item(&self.key).ok().flatten())
+    else {
+      return vec![];
+    };
+
+    JSON::parse(&value)
+      .ok()
+      .and_then(|value| value.dyn_into::<Array>().ok())
+      .map(|array| array.iter().filter_map(|item| item.as_string()).collect())
+      .unwrap_or_default()
+  }
+
+  fn save(&self, searches: &[String]) {
+    let Some(storage) = Self::storage() else {
+      return;
+    };
+
+    let array = searches
+      .iter()
+      .map(|search| JsValue::from(JsString::from(search.as_str())))
+      .collect::<Array>();
+
+    if let Some(value) = JSON::stringify(&array)
+      .ok()
+      .and_then(|value| value.as_string())
+    {
+      storage.set_item(&self.key, &value).ok();
+    }
+  }
+}
@@ -0,0 +1,228 @@
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_selection::Key;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::on_browser;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::Politeness;
+
+use crate::RecentSearchesStore;
+
+/// The default number of recent queries which are kept.
+pub const MAX_RECENT_SEARCHES: usize = 5;
+
+/// The prefix of the keys of recent searches in a collection, which keeps them
+/// distinct from the keys of the suggestions in the same collection.
+pub const RECENT_SEARCH_KEY_PREFIX: &str = "recent-search:";
+
+#[derive(TypedBuilder)]
+pub struct UseRecentSearchesProps {
+  /// Where the recent queries are persisted.
+  pub store: Rc<dyn RecentSearchesStore>,
+
+  /// The number of recent queries which are kept. Defaults to
+  /// [`MAX_RECENT_SEARCHES`].
+  #[builder(default, setter(strip_option))]
+  pub max_items: Option<usize>,
+}
+
+/// The recent queries of a search field.
+#[derive(Clone)]
+pub struct RecentSearches {
+  store: Rc<dyn RecentSearchesStore>,
+  max_items: usize,
+  items: RwSignal<Vec<String>>,
+}
+
+/// The recent queries of a search field, kept in the given store.
+///
+/// The history starts empty and is loaded from the store once the component
+/// is mounted in the browser, so the server and the hydrated markup match.
+pub fn use_recent_searches(cx: Scope, props: UseRecentSearchesProps) -> RecentSearches {
+  let items = create_rw_signal(cx, vec![]);
+
+  on_browser(cx, {
+    let store = props.store.clone();
+    move || items.set(store.load())
+  });
+
+  RecentSearches {
+    store: props.store,
+    max_items: props.max_items.unwrap_or(MAX_RECENT_SEARCHES),
+    items,
+  }
+}
+
+impl RecentSearches {
+  /// The recent queries, most recent first.
+  pub fn items(&self) -> Vec<String> {
+    self.items.get()
+  }
+
+  /// Record a submitted query. Blank queries are ignored and repeated queries
+  /// move to the top.
+  pub fn add(&self, query: &str) {
+    let query = query.trim();
+
+    if query.is_empty() {
+      return;
+    }
+
+    let mut items = self.items.get_untracked();
+    items.retain(|item| item != query);
+    items.insert(0, query.to_string());
+    items.truncate(self.max_items);
+    self.set_items(items);
+  }
+
+  /// Remove a single query from the history.
+  pub fn remove(&self, query: &str) {
+    let mut items = self.items.get_untracked();
+    items.retain(|item| item != query);
+    self.set_items(items);
+  }
+
+  /// Remove every query from the history.
+  pub fn clear(&self) {
+    self.set_items(vec![]);
+  }
+
+  /// The collection key of a recent query.
+  pub fn key_for(query: &str) -> Key {
+    Key::from(format!("{RECENT_SEARCH_KEY_PREFIX}{query}"))
+  }
+
+  /// The query of a collection key created by [`RecentSearches::key_for`].
+  pub fn query_for(key: &Key) -> Option<&str> {
+    key.as_str().strip_prefix(RECENT_SEARCH_KEY_PREFIX)
+  }
+
+  fn set_items(&self, items: Vec<String>) {
+    self.store.save(&items);
+    self.items.set(items);
+  }
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseRecentSearchesSectionProps {
+  /// The heading of the section. Defaults to "Recent searches".
+  #[builder(default, setter(strip_option, into))]
+  pub heading: Option<MaybeSignal<String>>,
+
+  /// The label of the clear history button. Defaults to "Clear recent
+  /// searches".
+  #[builder(default, setter(strip_option, into))]
+  pub clear_aria_label: Option<MaybeSignal<String>>,
+
+  /// The message announced once the history is cleared. Defaults to "Recent
+  /// searches cleared".
+  #[builder(default, setter(strip_option, into))]
+  pub cleared_message: Option<MaybeSignal<String>>,
+}
+
+/// An item of the recent searches section.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecentSearchItem {
+  pub key: Key,
+  pub text_value: String,
+}
+
+#[derive(Clone)]
+pub struct RecentSearchesClearButtonResult {
+  pub aria_label: Signal<String>,
+  pub on_click: RecentSearchesCallback<MouseEvent>,
+}
+
+#[derive(Clone)]
+pub struct RecentSearchesSectionResult {
+  /// The section is a group in the suggestions collection, e.g. a listbox.
+  pub role: &'static str,
+  pub aria_labelledby: String,
+  /// The id of the heading element.
+  pub heading_id: String,
+  pub heading: Signal<String>,
+  /// Whether there is any history. The section should only be rendered while
+  /// this is `true`.
+  pub has_items: Signal<bool>,
+  /// The items to render as options in the section.
+  pub items: Signal<Vec<RecentSearchItem>>,
+  pub clear_button: RecentSearchesClearButtonResult,
+}
+
+/// Provides the props for a section of a combobox or search suggestions
+/// collection listing the recent queries.
+///
+/// The items use keys created with [`RecentSearches::key_for`] so they can be
+/// navigated and selected with the keyboard like any other option. The clear
+/// button is a regular button and announces that the history was cleared.
+pub fn use_recent_searches_section(
+  cx: Scope,
+  recent_searches: &RecentSearches,
+  props: UseRecentSearchesSectionProps,
+) -> ReadSignal<RecentSearchesSectionResult> {
+  let heading_id = use_id(cx);
+  let original_heading = props
+    .heading
+    .unwrap_or_else(|| "Recent searches".to_string().into());
+  let original_clear_aria_label = props
+    .clear_aria_label
+    .unwrap_or_else(|| "Clear recent searches".to_string().into());
+  let cleared_message = props
+    .cleared_message
+    .unwrap_or_else(|| "Recent searches cleared".to_string().into());
+  let recent_items = recent_searches.items;
+
+  let items = (move || {
+    recent_items
+      .get()
+      .into_iter()
+      .map(|query| {
+        RecentSearchItem {
+          key: RecentSearches::key_for(&query),
+          text_value: query,
+        }
+      })
+      .collect::<Vec<_>>()
+  })
+  .derive_signal(cx);
+
+  let on_click: RecentSearchesCallback<MouseEvent> = {
+    let recent_searches = recent_searches.clone();
+
+    Rc::new(Box::new(move |_| {
+      recent_searches.clear();
+      announce(cleared_message.get(), Politeness::Polite);
+    }))
+  };
+
+  let (recent_searches_section_result, _) = create_signal(
+    cx,
+    RecentSearchesSectionResult {
+      role: "group",
+      aria_labelledby: heading_id.clone(),
+      heading_id,
+      heading: (move || original_heading.get()).derive_signal(cx),
+      has_items: (move || recent_items.with(|items| !items.is_empty())).derive_signal(cx),
+      items,
+      clear_button: RecentSearchesClearButtonResult {
+        aria_label: (move || original_clear_aria_label.get()).derive_signal(cx),
+        on_click,
+      },
+    },
+  );
+
+  recent_searches_section_result
+}
+
+pub(crate) type RecentSearchesCallback<E> = Rc<Box<dyn Fn(E)>>;
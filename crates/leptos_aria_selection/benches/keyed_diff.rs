@@ -0,0 +1,57 @@
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use leptos_aria_selection::diff_keys;
+use leptos_aria_selection::Key;
+
+fn keys(count: usize) -> Vec<Key> {
+  (0..count).map(Key::from).collect()
+}
+
+fn bench_diff_keys(c: &mut Criterion) {
+  let mut group = c.benchmark_group("diff_keys");
+
+  for count in [100, 1_000, 10_000] {
+    let previous = keys(count);
+
+    group.bench_with_input(BenchmarkId::new("unchanged", count), &count, |b, _| {
+      let next = previous.clone();
+      b.iter(|| diff_keys(black_box(&previous), black_box(&next)));
+    });
+
+    group.bench_with_input(BenchmarkId::new("append", count), &count, |b, _| {
+      let mut next = previous.clone();
+      next.push(Key::from("appended"));
+      b.iter(|| diff_keys(black_box(&previous), black_box(&next)));
+    });
+
+    group.bench_with_input(BenchmarkId::new("remove_middle", count), &count, |b, _| {
+      let mut next = previous.clone();
+      next.remove(count / 2);
+      b.iter(|| diff_keys(black_box(&previous), black_box(&next)));
+    });
+
+    group.bench_with_input(
+      BenchmarkId::new("move_last_to_first", count),
+      &count,
+      |b, _| {
+        let mut next = previous.clone();
+        next.rotate_right(1);
+        b.iter(|| diff_keys(black_box(&previous), black_box(&next)));
+      },
+    );
+
+    group.bench_with_input(BenchmarkId::new("reverse", count), &count, |b, _| {
+      let mut next = previous.clone();
+      next.reverse();
+      b.iter(|| diff_keys(black_box(&previous), black_box(&next)));
+    });
+  }
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_diff_keys);
+criterion_main!(benches);
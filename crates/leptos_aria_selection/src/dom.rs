@@ -0,0 +1,35 @@
+use leptos::web_sys::Element;
+use leptos::JsCast;
+
+use crate::Key;
+
+/// The attribute used to associate collection items in the DOM with their
+/// [`Key`].
+pub const DATA_KEY_ATTRIBUTE: &str = "data-key";
+
+/// Find the descendant of `container` with the provided `attribute` set to the
+/// provided `key`.
+///
+/// The attribute values are compared directly rather than through a CSS
+/// selector so that keys don't need to be escaped.
+pub fn find_element_by_key(container: &Element, attribute: &str, key: &Key) -> Option<Element> {
+  let nodes = container
+    .query_selector_all(&format!("[{attribute}]"))
+    .ok()?;
+
+  (0..nodes.length())
+    .filter_map(|index| nodes.item(index))
+    .filter_map(|node| node.dyn_into::<Element>().ok())
+    .find(|element| element.get_attribute(attribute).as_deref() == Some(key.as_str()))
+}
+
+/// Find the key of the closest collection item containing the provided
+/// element.
+pub fn get_closest_key(element: &Element, attribute: &str) -> Option<Key> {
+  element
+    .closest(&format!("[{attribute}]"))
+    .ok()
+    .flatten()
+    .and_then(|item| item.get_attribute(attribute))
+    .map(Key::from)
+}
@@ -0,0 +1,46 @@
+use leptos::document;
+use leptos::request_animation_frame;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::FocusableElement;
+
+#[derive(TypedBuilder)]
+pub struct UseEmptyStateProps {
+  /// The id of the element which receives focus when the collection becomes
+  /// empty, e.g. the empty state heading or an "Add item" button. Elements
+  /// which aren't focusable, like headings, need `tabindex="-1"`.
+  #[builder(setter(into))]
+  pub focus_target_id: MaybeSignal<String>,
+}
+
+/// Where focus lands when the last item of a focused collection is removed.
+/// Pass it to the collection hook, which moves focus automatically.
+#[derive(Clone, Copy)]
+pub struct EmptyState {
+  pub focus_target_id: Signal<String>,
+}
+
+pub fn use_empty_state(cx: Scope, props: UseEmptyStateProps) -> EmptyState {
+  let original_focus_target_id = props.focus_target_id;
+
+  EmptyState {
+    focus_target_id: (move || original_focus_target_id.get()).derive_signal(cx),
+  }
+}
+
+impl EmptyState {
+  /// Focus the target once the empty state has been rendered.
+  pub fn focus(&self) {
+    let focus_target_id = self.focus_target_id;
+
+    request_animation_frame(move || {
+      if let Some(element) = document().get_element_by_id(&focus_target_id.get_untracked()) {
+        FocusableElement::from(element).focus();
+      }
+    });
+  }
+}
@@ -0,0 +1,100 @@
+/// The secondary content rendered within a collection item such as an option,
+/// a menu item or a tree node.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ItemSlotsOptions {
+  /// Whether the item renders a description below its label.
+  pub has_description: bool,
+
+  /// The keyboard shortcut which activates the item, in the format of
+  /// `aria-keyshortcuts`, e.g. `Control+Shift+N`. A visual hint is rendered
+  /// for it.
+  pub keyboard_shortcut: Option<String>,
+}
+
+/// The props for an element within an item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemSlotResult {
+  pub id: String,
+}
+
+/// The ids which connect the label, description and keyboard shortcut hint of
+/// an item to the item element.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemSlots {
+  /// The primary text of the item. Typeahead only matches this text, so the
+  /// `text_value` of the item should only contain the label.
+  pub label: ItemSlotResult,
+  pub description: Option<ItemSlotResult>,
+  /// The visual hint for the keyboard shortcut, e.g. `⌘N`.
+  pub keyboard: Option<ItemSlotResult>,
+  /// Set on the item element so that only the label is its accessible name.
+  pub aria_labelledby: String,
+  /// The description and the keyboard hint.
+  pub aria_describedby: Option<String>,
+  pub aria_keyshortcuts: Option<String>,
+}
+
+/// Create the slot props for the item with the provided id.
+pub fn create_item_slots(item_id: &str, options: ItemSlotsOptions) -> ItemSlots {
+  let label = ItemSlotResult {
+    id: format!("{item_id}-label"),
+  };
+  let description = options.has_description.then(|| {
+    ItemSlotResult {
+      id: format!("{item_id}-description"),
+    }
+  });
+  let keyboard = options.keyboard_shortcut.as_ref().map(|_| {
+    ItemSlotResult {
+      id: format!("{item_id}-keyboard"),
+    }
+  });
+
+  let described_by = description
+    .iter()
+    .chain(keyboard.iter())
+    .map(|slot| slot.id.as_str())
+    .collect::<Vec<_>>();
+
+  ItemSlots {
+    aria_labelledby: label.id.clone(),
+    aria_describedby: (!described_by.is_empty()).then(|| described_by.join(" ")),
+    aria_keyshortcuts: options.keyboard_shortcut,
+    label,
+    description,
+    keyboard,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn only_the_label_by_default() {
+    let slots = create_item_slots("item", ItemSlotsOptions::default());
+
+    assert_eq!(slots.aria_labelledby, "item-label");
+    assert_eq!(slots.aria_describedby, None);
+    assert_eq!(slots.aria_keyshortcuts, None);
+    assert!(slots.description.is_none());
+    assert!(slots.keyboard.is_none());
+  }
+
+  #[test]
+  fn describes_with_description_and_shortcut() {
+    let slots = create_item_slots(
+      "item",
+      ItemSlotsOptions {
+        has_description: true,
+        keyboard_shortcut: Some("Control+N".into()),
+      },
+    );
+
+    assert_eq!(
+      slots.aria_describedby.as_deref(),
+      Some("item-description item-keyboard")
+    );
+    assert_eq!(slots.aria_keyshortcuts.as_deref(), Some("Control+N"));
+  }
+}
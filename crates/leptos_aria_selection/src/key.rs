@@ -0,0 +1,68 @@
+use std::fmt;
+use std::rc::Rc;
+
+/// A unique identifier for an item within a collection.
+///
+/// Keys are cheap to clone and can be created from strings and integers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Key(Rc<str>);
+
+impl Key {
+  pub fn new(value: impl AsRef<str>) -> Self {
+    Self(Rc::from(value.as_ref()))
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl AsRef<str> for Key {
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl fmt::Display for Key {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl From<&str> for Key {
+  fn from(value: &str) -> Self {
+    Self::new(value)
+  }
+}
+
+impl From<String> for Key {
+  fn from(value: String) -> Self {
+    Self(Rc::from(value))
+  }
+}
+
+impl From<&String> for Key {
+  fn from(value: &String) -> Self {
+    Self::new(value)
+  }
+}
+
+impl From<&Key> for Key {
+  fn from(value: &Key) -> Self {
+    value.clone()
+  }
+}
+
+macro_rules! impl_from_number {
+  ($($number:ty),*) => {
+    $(
+      impl From<$number> for Key {
+        fn from(value: $number) -> Self {
+          Self::new(value.to_string())
+        }
+      }
+    )*
+  };
+}
+
+impl_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use crate::Key;
+
+/// A single change required to turn one ordered list of keys into another.
+///
+/// Removal indexes refer to the previous keys. Insertion and move targets refer
+/// to the next keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyedChange {
+  Insert { key: Key, index: usize },
+  Remove { key: Key, index: usize },
+  Move { key: Key, from: usize, to: usize },
+}
+
+/// Compare two ordered lists of keys and return the insertions, removals and
+/// moves which turn `previous` into `next`.
+///
+/// Keys which keep their relative order are left in place so that only the
+/// minimum number of moves is reported. Removals are listed first, followed by
+/// insertions and moves in the order of `next`. Identical lists produce no
+/// changes.
+pub fn diff_keys(previous: &[Key], next: &[Key]) -> Vec<KeyedChange> {
+  if previous == next {
+    return vec![];
+  }
+
+  let previous_positions: HashMap<&Key, usize> = previous
+    .iter()
+    .enumerate()
+    .map(|(index, key)| (key, index))
+    .collect();
+  let next_positions: HashMap<&Key, usize> = next
+    .iter()
+    .enumerate()
+    .map(|(index, key)| (key, index))
+    .collect();
+
+  let mut changes = previous
+    .iter()
+    .enumerate()
+    .filter(|(_, key)| !next_positions.contains_key(key))
+    .map(|(index, key)| {
+      KeyedChange::Remove {
+        key: key.clone(),
+        index,
+      }
+    })
+    .collect::<Vec<_>>();
+
+  // The previous positions of the retained keys in the order of `next`. Keys
+  // on the longest increasing run of positions stay where they are.
+  let retained = next
+    .iter()
+    .enumerate()
+    .filter_map(|(index, key)| previous_positions.get(key).map(|from| (index, *from)))
+    .collect::<Vec<_>>();
+  let stable =
+    longest_increasing_subsequence(&retained.iter().map(|(_, from)| *from).collect::<Vec<_>>());
+  let mut stable = stable
+    .into_iter()
+    .map(|position| retained[position].0)
+    .peekable();
+
+  for (index, key) in next.iter().enumerate() {
+    match previous_positions.get(key) {
+      None => {
+        changes.push(KeyedChange::Insert {
+          key: key.clone(),
+          index,
+        });
+      }
+      Some(_) if stable.peek() == Some(&index) => {
+        stable.next();
+      }
+      Some(from) => {
+        changes.push(KeyedChange::Move {
+          key: key.clone(),
+          from: *from,
+          to: index,
+        });
+      }
+    }
+  }
+
+  changes
+}
+
+/// The positions within `values` which form the longest strictly increasing
+/// subsequence, in ascending order.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<usize> {
+  // `tails[length]` is the position of the smallest value which ends an
+  // increasing run of `length + 1` values.
+  let mut tails: Vec<usize> = vec![];
+  let mut predecessors = vec![None; values.len()];
+
+  for (position, value) in values.iter().enumerate() {
+    let length = tails.partition_point(|tail| values[*tail] < *value);
+
+    if length > 0 {
+      predecessors[position] = Some(tails[length - 1]);
+    }
+
+    if length == tails.len() {
+      tails.push(position);
+    } else {
+      tails[length] = position;
+    }
+  }
+
+  let mut subsequence = vec![];
+  let mut current = tails.last().copied();
+
+  while let Some(position) = current {
+    subsequence.push(position);
+    current = predecessors[position];
+  }
+
+  subsequence.reverse();
+  subsequence
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn keys(values: &[&str]) -> Vec<Key> {
+    values.iter().map(|value| Key::from(*value)).collect()
+  }
+
+  #[test]
+  fn identical_keys_have_no_changes() {
+    assert!(diff_keys(&keys(&["a", "b"]), &keys(&["a", "b"])).is_empty());
+  }
+
+  #[test]
+  fn reports_insertions_and_removals() {
+    let changes = diff_keys(&keys(&["a", "b", "c"]), &keys(&["a", "c", "d"]));
+
+    assert_eq!(
+      changes,
+      vec![
+        KeyedChange::Remove {
+          key: "b".into(),
+          index: 1,
+        },
+        KeyedChange::Insert {
+          key: "d".into(),
+          index: 2,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn reports_the_minimum_number_of_moves() {
+    let changes = diff_keys(&keys(&["a", "b", "c", "d"]), &keys(&["d", "a", "b", "c"]));
+
+    assert_eq!(
+      changes,
+      vec![KeyedChange::Move {
+        key: "d".into(),
+        from: 3,
+        to: 0,
+      }]
+    );
+  }
+
+  #[test]
+  fn reversed_keys_keep_one_in_place() {
+    let changes = diff_keys(&keys(&["a", "b", "c"]), &keys(&["c", "b", "a"]));
+
+    assert_eq!(changes.len(), 2);
+    assert!(changes
+      .iter()
+      .all(|change| matches!(change, KeyedChange::Move { .. })));
+  }
+}
@@ -0,0 +1,21 @@
+pub use dom::*;
+pub use empty_state::*;
+pub use item_slots::*;
+pub use key::*;
+pub use keyed_diff::*;
+pub use selection_manager::*;
+pub use sort::*;
+pub use use_collection::*;
+pub use use_reorder::*;
+pub use use_type_select::*;
+
+mod dom;
+mod empty_state;
+mod item_slots;
+mod key;
+mod keyed_diff;
+mod selection_manager;
+mod sort;
+mod use_collection;
+mod use_reorder;
+mod use_type_select;
@@ -0,0 +1,501 @@
+use std::collections::HashSet;
+
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos::UntrackedSettableSignal;
+use leptos_aria_interactions::PointerType;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::use_platform;
+use leptos_aria_utils::ControlledState;
+use leptos_aria_utils::PlatformInfo;
+
+use crate::Key;
+
+/// The type of selection that is allowed in a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+  #[default]
+  None,
+  Single,
+  Multiple,
+}
+
+/// How multiple selection should behave in a collection.
+///
+/// * `Toggle` - pressing an item toggles it without affecting other items.
+/// * `Replace` - pressing an item replaces the selection unless a modifier key
+///   is held.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SelectionBehavior {
+  #[default]
+  Toggle,
+  Replace,
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseMultipleSelectionStateProps {
+  /// The type of selection that is allowed in the collection.
+  #[builder(default, setter(strip_option, into))]
+  pub selection_mode: Option<MaybeSignal<SelectionMode>>,
+
+  /// How multiple selection should behave in the collection.
+  #[builder(default, setter(strip_option, into))]
+  pub selection_behavior: Option<MaybeSignal<SelectionBehavior>>,
+
+  /// The currently selected keys in the collection (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub selected_keys: Option<MaybeSignal<HashSet<Key>>>,
+
+  /// The initial selected keys in the collection (uncontrolled).
+  #[builder(default, setter(strip_option))]
+  pub default_selected_keys: Option<HashSet<Key>>,
+
+  /// Handler that is called when the selection changes.
+  #[builder(default, setter(strip_option))]
+  pub on_selection_change: Option<Box<dyn Fn(HashSet<Key>)>>,
+
+  /// The item keys that are disabled. These items cannot be selected.
+  #[builder(default, setter(strip_option, into))]
+  pub disabled_keys: Option<MaybeSignal<HashSet<Key>>>,
+
+  /// Whether the collection allows empty selection.
+  #[builder(default, setter(strip_option, into))]
+  pub disallow_empty_selection: Option<MaybeSignal<bool>>,
+
+  /// Whether moving focus with the keyboard also selects the focused item,
+  /// as in tabs with automatic activation. Holding <kbd>Ctrl</kbd> (or
+  /// <kbd>Cmd</kbd> on macOS) moves focus without selecting.
+  #[builder(default, setter(strip_option, into))]
+  pub selection_follows_focus: Option<MaybeSignal<bool>>,
+}
+
+/// Manages the selection and focus state of a collection.
+///
+/// This is shared by all the collection hooks (tables, trees, lists) so that
+/// selection behaves the same everywhere.
+#[derive(Clone)]
+pub struct SelectionManager {
+  pub selection_mode: Signal<SelectionMode>,
+  pub selection_behavior: Signal<SelectionBehavior>,
+  pub disabled_keys: Signal<HashSet<Key>>,
+  pub disallow_empty_selection: Signal<bool>,
+  pub selection_follows_focus: Signal<bool>,
+  selected_keys: ControlledState<HashSet<Key>>,
+  focused_key: RwSignal<Option<Key>>,
+  is_focused: RwSignal<bool>,
+  anchor_key: RwSignal<Option<Key>>,
+  platform: Signal<PlatformInfo>,
+}
+
+/// Create the selection state for a collection.
+pub fn use_multiple_selection_state(
+  cx: Scope,
+  props: UseMultipleSelectionStateProps,
+) -> SelectionManager {
+  let original_selection_mode = props.selection_mode.unwrap_or(SelectionMode::None.into());
+  let selection_mode = (move || original_selection_mode.get()).derive_signal(cx);
+  let original_selection_behavior = props
+    .selection_behavior
+    .unwrap_or(SelectionBehavior::Toggle.into());
+  let selection_behavior = (move || original_selection_behavior.get()).derive_signal(cx);
+  let original_disabled_keys = props.disabled_keys.unwrap_or(HashSet::new().into());
+  let disabled_keys = (move || original_disabled_keys.get()).derive_signal(cx);
+  let original_disallow_empty_selection = props.disallow_empty_selection.unwrap_or(false.into());
+  let disallow_empty_selection =
+    (move || original_disallow_empty_selection.get()).derive_signal(cx);
+  let original_selection_follows_focus = props.selection_follows_focus.unwrap_or(false.into());
+  let selection_follows_focus = (move || original_selection_follows_focus.get()).derive_signal(cx);
+
+  let selected_keys = use_controlled_state(
+    cx,
+    props.selected_keys,
+    props.default_selected_keys.unwrap_or_default(),
+    props.on_selection_change,
+  );
+
+  SelectionManager {
+    selection_mode,
+    selection_behavior,
+    disabled_keys,
+    disallow_empty_selection,
+    selection_follows_focus,
+    selected_keys,
+    focused_key: create_rw_signal(cx, None),
+    is_focused: create_rw_signal(cx, false),
+    anchor_key: create_rw_signal(cx, None),
+    platform: use_platform(cx),
+  }
+}
+
+impl SelectionManager {
+  /// The currently selected keys.
+  pub fn selected_keys(&self) -> HashSet<Key> {
+    self.selected_keys.get()
+  }
+
+  /// The currently selected keys without tracking.
+  pub fn selected_keys_untracked(&self) -> HashSet<Key> {
+    self.selected_keys.get_untracked()
+  }
+
+  /// Whether the provided key is selected.
+  pub fn is_selected(&self, key: &Key) -> bool {
+    self.selection_mode.get() != SelectionMode::None && self.selected_keys.get().contains(key)
+  }
+
+  /// Whether nothing is currently selected.
+  pub fn is_empty(&self) -> bool {
+    self.selected_keys.get().is_empty()
+  }
+
+  /// Whether the provided key is disabled.
+  pub fn is_disabled(&self, key: &Key) -> bool {
+    self.disabled_keys.get().contains(key)
+  }
+
+  /// Whether the item with the provided key can be selected.
+  pub fn can_select_item(&self, key: &Key) -> bool {
+    self.selection_mode.get_untracked() != SelectionMode::None
+      && !self.disabled_keys.get_untracked().contains(key)
+  }
+
+  /// The key of the item which currently has focus.
+  pub fn focused_key(&self) -> Option<Key> {
+    self.focused_key.get()
+  }
+
+  /// The focused key without tracking.
+  pub fn focused_key_untracked(&self) -> Option<Key> {
+    self.focused_key.get_untracked()
+  }
+
+  pub fn set_focused_key(&self, key: Option<Key>) {
+    if self.focused_key.get_untracked() != key {
+      self.focused_key.set(key);
+    }
+  }
+
+  /// Whether the collection is currently focused.
+  pub fn is_focused(&self) -> bool {
+    self.is_focused.get()
+  }
+
+  /// Whether the collection is currently focused without tracking.
+  pub fn is_focused_untracked(&self) -> bool {
+    self.is_focused.get_untracked()
+  }
+
+  pub fn set_focused(&self, is_focused: bool) {
+    if self.is_focused.get_untracked() != is_focused {
+      self.is_focused.set(is_focused);
+    }
+  }
+
+  /// Replace the current selection with the provided keys.
+  pub fn set_selected_keys(&self, keys: impl IntoIterator<Item = Key>) {
+    if self.selection_mode.get_untracked() == SelectionMode::None {
+      return;
+    }
+
+    let disabled_keys = self.disabled_keys.get_untracked();
+    let keys: HashSet<Key> = keys
+      .into_iter()
+      .filter(|key| !disabled_keys.contains(key))
+      .collect();
+
+    if keys.is_empty() && self.disallow_empty_selection.get_untracked() {
+      return;
+    }
+
+    self.selected_keys.set(keys);
+  }
+
+  /// Toggle the selection of the provided key.
+  pub fn toggle_selection(&self, key: &Key) {
+    let selection_mode = self.selection_mode.get_untracked();
+
+    if selection_mode == SelectionMode::None || !self.can_select_item(key) {
+      return;
+    }
+
+    if selection_mode == SelectionMode::Single {
+      if self.selected_keys.get_untracked().contains(key) {
+        self.set_selected_keys([]);
+      } else {
+        self.replace_selection(key);
+      }
+
+      return;
+    }
+
+    let mut keys = self.selected_keys.get_untracked();
+
+    if keys.contains(key) {
+      if keys.len() == 1 && self.disallow_empty_selection.get_untracked() {
+        return;
+      }
+
+      keys.remove(key);
+    } else {
+      keys.insert(key.clone());
+    }
+
+    self.anchor_key.set_untracked(Some(key.clone()));
+    self.selected_keys.set(keys);
+  }
+
+  /// Replace the selection with only the provided key.
+  pub fn replace_selection(&self, key: &Key) {
+    if !self.can_select_item(key) {
+      return;
+    }
+
+    self.anchor_key.set_untracked(Some(key.clone()));
+    self.selected_keys.set(HashSet::from([key.clone()]));
+  }
+
+  /// Extend the selection from the anchor key to the provided key. The
+  /// `ordered_keys` are all the keys of the collection in display order.
+  pub fn extend_selection(&self, to_key: &Key, ordered_keys: &[Key]) {
+    if self.selection_mode.get_untracked() != SelectionMode::Multiple {
+      self.replace_selection(to_key);
+      return;
+    }
+
+    let anchor_key = self
+      .anchor_key
+      .get_untracked()
+      .unwrap_or_else(|| to_key.clone());
+    let from = ordered_keys.iter().position(|key| key == &anchor_key);
+    let to = ordered_keys.iter().position(|key| key == to_key);
+
+    let (Some(from), Some(to)) = (from, to) else {
+      self.replace_selection(to_key);
+      return;
+    };
+
+    let (start, end) = if from <= to { (from, to) } else { (to, from) };
+    let disabled_keys = self.disabled_keys.get_untracked();
+    let keys: HashSet<Key> = ordered_keys[start..=end]
+      .iter()
+      .filter(|key| !disabled_keys.contains(key))
+      .cloned()
+      .collect();
+
+    self.anchor_key.set_untracked(Some(anchor_key));
+    self.selected_keys.set(keys);
+  }
+
+  /// Update the selection after keyboard navigation moved focus to `key`.
+  ///
+  /// * With <kbd>Shift</kbd> the selection is extended in multiple selection.
+  /// * Otherwise the selection follows focus when `selection_follows_focus` is
+  ///   set, unless `ctrl_key` is held to move focus without selecting.
+  pub fn select_on_focus_move(
+    &self,
+    key: &Key,
+    shift_key: bool,
+    ctrl_key: bool,
+    ordered_keys: &[Key],
+  ) {
+    let selection_mode = self.selection_mode.get_untracked();
+
+    if shift_key && selection_mode == SelectionMode::Multiple {
+      self.extend_selection(key, ordered_keys);
+    } else if !ctrl_key
+      && selection_mode != SelectionMode::None
+      && self.selection_follows_focus.get_untracked()
+    {
+      self.replace_selection(key);
+    }
+  }
+
+  /// Select all the provided keys. Only valid for multiple selection.
+  pub fn select_all(&self, keys: impl IntoIterator<Item = Key>) {
+    if self.selection_mode.get_untracked() == SelectionMode::Multiple {
+      self.set_selected_keys(keys);
+    }
+  }
+
+  /// Whether all the provided keys are selected.
+  pub fn is_select_all(&self, keys: &[Key]) -> bool {
+    let selected_keys = self.selected_keys.get();
+    let disabled_keys = self.disabled_keys.get();
+    let mut selectable = keys.iter().filter(|key| !disabled_keys.contains(key));
+    let mut has_selectable = false;
+
+    let all_selected = selectable.all(|key| {
+      has_selectable = true;
+      selected_keys.contains(key)
+    });
+
+    has_selectable && all_selected
+  }
+
+  /// Remove all keys from the selection.
+  pub fn clear_selection(&self) {
+    if !self.disallow_empty_selection.get_untracked() {
+      self.anchor_key.set_untracked(None);
+      self.selected_keys.set(HashSet::new());
+    }
+  }
+
+  /// Select the provided key according to the selection mode, behavior and the
+  /// modifiers of the press event which triggered the selection.
+  pub fn select(&self, key: &Key, event: &PressEvent, ordered_keys: &[Key]) {
+    let modifiers = SelectionModifiers::from_press_event(event, &self.platform.get_untracked());
+    self.select_with_modifiers(key, modifiers, ordered_keys);
+  }
+
+  /// Select the provided key according to the selection mode, behavior and the
+  /// provided modifiers. This doesn't need an event, which allows the
+  /// selection to be driven without the DOM.
+  pub fn select_with_modifiers(
+    &self,
+    key: &Key,
+    modifiers: SelectionModifiers,
+    ordered_keys: &[Key],
+  ) {
+    match self.selection_mode.get_untracked() {
+      SelectionMode::None => {}
+      SelectionMode::Single => {
+        if self.selected_keys.get_untracked().contains(key)
+          && !self.disallow_empty_selection.get_untracked()
+        {
+          self.toggle_selection(key);
+        } else {
+          self.replace_selection(key);
+        }
+      }
+      SelectionMode::Multiple => {
+        if modifiers.shift_key {
+          self.extend_selection(key, ordered_keys);
+        } else if self.selection_behavior.get_untracked() == SelectionBehavior::Toggle
+          || modifiers.toggle_key
+          || modifiers.is_touch_or_virtual
+        {
+          self.toggle_selection(key);
+        } else {
+          self.replace_selection(key);
+        }
+      }
+    }
+  }
+}
+
+/// The state of the interaction which selects an item.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SelectionModifiers {
+  /// Extend the selection from the anchor key.
+  pub shift_key: bool,
+
+  /// Toggle the item in `Replace` selection behavior. This is <kbd>Ctrl</kbd>,
+  /// or <kbd>Cmd</kbd> on macOS.
+  pub toggle_key: bool,
+
+  /// Touch and screen readers always toggle, since there is no way to hold a
+  /// modifier key.
+  pub is_touch_or_virtual: bool,
+}
+
+impl SelectionModifiers {
+  /// The modifiers of the press event on the platform.
+  pub fn from_press_event(event: &PressEvent, platform: &PlatformInfo) -> Self {
+    Self {
+      shift_key: event.shift_key,
+      toggle_key: is_ctrl_key_pressed(event, platform),
+      is_touch_or_virtual: matches!(
+        event.pointer_type,
+        PointerType::Touch | PointerType::Virtual
+      ),
+    }
+  }
+}
+
+/// On mac the meta key is used in place of the control key for selection.
+pub fn is_ctrl_key_pressed(event: &PressEvent, platform: &PlatformInfo) -> bool {
+  if platform.is_mac() {
+    event.meta_key
+  } else {
+    event.ctrl_key
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+
+  fn keys() -> Vec<Key> {
+    ["a", "b", "c", "d"].into_iter().map(Key::from).collect()
+  }
+
+  fn manager(cx: Scope, behavior: SelectionBehavior) -> SelectionManager {
+    use_multiple_selection_state(
+      cx,
+      UseMultipleSelectionStateProps::builder()
+        .selection_mode(SelectionMode::Multiple)
+        .selection_behavior(behavior)
+        .disabled_keys(HashSet::from([Key::from("c")]))
+        .build(),
+    )
+  }
+
+  #[test]
+  fn toggle_behavior_adds_to_the_selection() {
+    let selected = run_scope(create_runtime(), |cx| {
+      let manager = manager(cx, SelectionBehavior::Toggle);
+      let modifiers = SelectionModifiers::default();
+      manager.select_with_modifiers(&"a".into(), modifiers, &keys());
+      manager.select_with_modifiers(&"b".into(), modifiers, &keys());
+      manager.selected_keys_untracked()
+    });
+
+    assert_eq!(selected, HashSet::from(["a".into(), "b".into()]));
+  }
+
+  #[test]
+  fn replace_behavior_toggles_with_modifiers() {
+    let selected = run_scope(create_runtime(), |cx| {
+      let manager = manager(cx, SelectionBehavior::Replace);
+      let toggle = SelectionModifiers {
+        toggle_key: true,
+        ..Default::default()
+      };
+      manager.select_with_modifiers(&"a".into(), Default::default(), &keys());
+      manager.select_with_modifiers(&"b".into(), Default::default(), &keys());
+      manager.select_with_modifiers(&"d".into(), toggle, &keys());
+      manager.selected_keys_untracked()
+    });
+
+    assert_eq!(selected, HashSet::from(["b".into(), "d".into()]));
+  }
+
+  #[test]
+  fn shift_extends_the_selection_without_disabled_keys() {
+    let selected = run_scope(create_runtime(), |cx| {
+      let manager = manager(cx, SelectionBehavior::Replace);
+      let shift = SelectionModifiers {
+        shift_key: true,
+        ..Default::default()
+      };
+      manager.select_with_modifiers(&"a".into(), Default::default(), &keys());
+      manager.select_with_modifiers(&"d".into(), shift, &keys());
+      manager.selected_keys_untracked()
+    });
+
+    assert_eq!(
+      selected,
+      HashSet::from(["a".into(), "b".into(), "d".into()])
+    );
+  }
+}
@@ -0,0 +1,25 @@
+use crate::Key;
+
+/// The direction a column is sorted in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+  Ascending,
+  Descending,
+}
+
+impl SortDirection {
+  /// The value of the `aria-sort` attribute for this direction.
+  pub fn as_aria_sort(&self) -> &'static str {
+    match self {
+      SortDirection::Ascending => "ascending",
+      SortDirection::Descending => "descending",
+    }
+  }
+}
+
+/// The column a collection is sorted by and the direction of the sort.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SortDescriptor {
+  pub column: Key,
+  pub direction: SortDirection,
+}
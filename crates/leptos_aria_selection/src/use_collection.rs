@@ -0,0 +1,223 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+
+use crate::diff_keys;
+use crate::EmptyState;
+use crate::Key;
+use crate::KeyedChange;
+use crate::SelectionManager;
+
+#[derive(TypedBuilder)]
+pub struct UseCollectionProps<T: 'static> {
+  /// The items of the collection in display order.
+  #[builder(setter(into))]
+  pub items: MaybeSignal<Vec<T>>,
+
+  /// Get the unique key of an item.
+  pub key: Box<dyn Fn(&T) -> Key>,
+
+  /// The selection manager of the collection. When the focused item is
+  /// removed, focus moves to the item which takes its place.
+  #[builder(default, setter(strip_option))]
+  pub selection_manager: Option<SelectionManager>,
+
+  /// Where focus moves when the last item is removed while the collection has
+  /// focus. Requires the `selection_manager`.
+  #[builder(default, setter(strip_option))]
+  pub empty_state: Option<EmptyState>,
+}
+
+/// A collection which stores a signal per item.
+///
+/// Updates are diffed by key. Only the signals of items whose value changed
+/// are notified and the list of keys is only notified when items are
+/// inserted, removed or moved. Views which render an item through
+/// [`Collection::item`] are not re-rendered when a different item changes.
+pub struct Collection<T: 'static> {
+  cx: Scope,
+  keys: RwSignal<Vec<Key>>,
+  items: Rc<RefCell<HashMap<Key, RwSignal<T>>>>,
+}
+
+impl<T> Clone for Collection<T> {
+  fn clone(&self) -> Self {
+    Self {
+      cx: self.cx,
+      keys: self.keys,
+      items: self.items.clone(),
+    }
+  }
+}
+
+/// Keep a keyed [`Collection`] in sync with the provided items.
+pub fn use_collection<T>(cx: Scope, props: UseCollectionProps<T>) -> Collection<T>
+where
+  T: Clone + PartialEq + 'static,
+{
+  let collection = Collection::new(cx);
+  let items = props.items;
+  let get_key = props.key;
+  let selection_manager = props.selection_manager;
+  let empty_state = props.empty_state;
+
+  // Populate the collection immediately since effects don't run on the server.
+  items.with(|items| collection.update(items, &get_key));
+
+  create_effect(cx, {
+    let collection = collection.clone();
+
+    move |_| {
+      let previous_keys = collection.keys.get_untracked();
+      let changes = items.with(|next| collection.update(next, &get_key));
+
+      if let Some(manager) = &selection_manager {
+        preserve_focused_key(
+          manager,
+          &previous_keys,
+          &collection.keys_untracked(),
+          &changes,
+          empty_state.as_ref(),
+        );
+      }
+    }
+  });
+
+  collection
+}
+
+impl<T> Collection<T>
+where
+  T: Clone + PartialEq + 'static,
+{
+  pub fn new(cx: Scope) -> Self {
+    Self {
+      cx,
+      keys: create_rw_signal(cx, vec![]),
+      items: Default::default(),
+    }
+  }
+
+  /// The keys of the items in display order. This only notifies when items
+  /// are inserted, removed or moved.
+  pub fn keys(&self) -> Vec<Key> {
+    self.keys.get()
+  }
+
+  pub fn keys_untracked(&self) -> Vec<Key> {
+    self.keys.get_untracked()
+  }
+
+  /// The item with the provided key. This only notifies when this item
+  /// changes.
+  pub fn item(&self, key: &Key) -> Option<T> {
+    let signal = self.items.borrow().get(key).copied();
+    signal.map(|signal| signal.get())
+  }
+
+  pub fn item_untracked(&self, key: &Key) -> Option<T> {
+    let signal = self.items.borrow().get(key).copied();
+    signal.map(|signal| signal.get_untracked())
+  }
+
+  pub fn len(&self) -> usize {
+    self.keys.with(Vec::len)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Apply the next items to the collection and return the keyed changes.
+  ///
+  /// Existing signals are reused for items with the same key and only set
+  /// when the value is different, so only new and changed items are cloned.
+  pub fn update(&self, next: &[T], get_key: &dyn Fn(&T) -> Key) -> Vec<KeyedChange> {
+    let next_keys = next.iter().map(get_key).collect::<Vec<_>>();
+    let changes = self
+      .keys
+      .with_untracked(|previous_keys| diff_keys(previous_keys, &next_keys));
+
+    let mut updated = vec![];
+
+    {
+      let mut items = self.items.borrow_mut();
+
+      for change in &changes {
+        if let KeyedChange::Remove { key, .. } = change {
+          items.remove(key);
+        }
+      }
+
+      for (key, item) in next_keys.iter().zip(next) {
+        match items.get(key) {
+          Some(signal) => {
+            if signal.with_untracked(|current| current != item) {
+              updated.push((*signal, item.clone()));
+            }
+          }
+          None => {
+            items.insert(key.clone(), create_rw_signal(self.cx, item.clone()));
+          }
+        }
+      }
+    }
+
+    // Notify after the borrow is released since subscribers may read from the
+    // collection.
+    for (signal, item) in updated {
+      signal.set(item);
+    }
+
+    if !changes.is_empty() {
+      self.keys.set(next_keys);
+    }
+
+    changes
+  }
+}
+
+/// Move focus to the item which took the place of the focused item when it is
+/// removed, or to the empty state when no items remain. Focus and selection
+/// are otherwise left untouched since keys are stable across updates.
+fn preserve_focused_key(
+  manager: &SelectionManager,
+  previous_keys: &[Key],
+  next_keys: &[Key],
+  changes: &[KeyedChange],
+  empty_state: Option<&EmptyState>,
+) {
+  let Some(focused_key) = manager.focused_key_untracked() else {
+    return;
+  };
+
+  let was_removed = changes
+    .iter()
+    .any(|change| matches!(change, KeyedChange::Remove { key, .. } if key == &focused_key));
+
+  if !was_removed {
+    return;
+  }
+
+  let index = previous_keys
+    .iter()
+    .position(|key| key == &focused_key)
+    .unwrap_or(0);
+  let replacement = next_keys.get(index).or_else(|| next_keys.last()).cloned();
+
+  if replacement.is_none() && manager.is_focused_untracked() {
+    if let Some(empty_state) = empty_state {
+      empty_state.focus();
+    }
+  }
+
+  manager.set_focused_key(replacement);
+}
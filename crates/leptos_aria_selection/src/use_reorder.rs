@@ -0,0 +1,361 @@
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::KeyboardEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_i18n::LocalizedStringFormatter;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::use_platform;
+use leptos_aria_utils::Direction;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::PlatformInfo;
+use leptos_aria_utils::Politeness;
+
+use crate::Key;
+
+/// Describes an item which was moved within a collection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReorderEvent {
+  pub key: Key,
+  pub from_index: usize,
+  pub to_index: usize,
+}
+
+/// The kind of reorder announcement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReorderAnnouncementType {
+  /// An item was grabbed with [`Reorder::grab`].
+  Grabbed,
+  /// The grabbed item was moved with the arrow keys.
+  Moving,
+  /// An item was moved immediately with the modifier and arrow keys.
+  Moved,
+  /// The grabbed item was dropped.
+  Dropped,
+  /// Reordering was cancelled and the item returned to its original position.
+  Cancelled,
+}
+
+/// The details of a reorder announcement, used to build its message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReorderAnnouncement<'a> {
+  pub announcement_type: ReorderAnnouncementType,
+  /// The text value of the item.
+  pub item: &'a str,
+  /// The one based position of the item after the change.
+  pub position: usize,
+  /// The number of items in the collection.
+  pub count: usize,
+}
+
+impl<'a> ReorderAnnouncement<'a> {
+  /// The built-in message, localized with the `reorder*` strings.
+  pub fn default_message(&self, strings: &LocalizedStringFormatter) -> String {
+    let key = match self.announcement_type {
+      ReorderAnnouncementType::Grabbed => "reorderGrabbed",
+      ReorderAnnouncementType::Moving => "reorderMoving",
+      ReorderAnnouncementType::Moved => "reorderMoved",
+      ReorderAnnouncementType::Dropped => "reorderDropped",
+      ReorderAnnouncementType::Cancelled => "reorderCancelled",
+    };
+
+    strings.format_with(
+      key,
+      &[
+        ("item", self.item.into()),
+        ("position", self.position.into()),
+        ("count", self.count.into()),
+      ],
+    )
+  }
+}
+
+/// Builds the message of a reorder announcement. Return `None` to use the
+/// built-in message, e.g. for announcements that haven't been translated.
+pub type ReorderAnnouncementTemplate = Box<dyn Fn(&ReorderAnnouncement) -> Option<String>>;
+
+#[derive(TypedBuilder)]
+pub struct UseReorderProps {
+  /// Handler that is called when an item is moved. The collection should be
+  /// updated to move the item from `from_index` to `to_index`.
+  pub on_reorder: Box<dyn Fn(ReorderEvent)>,
+
+  /// Whether reordering is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// Customize the announced messages, e.g. to change their phrasing. The
+  /// built-in messages are localized for the locale from `use_locale`.
+  #[builder(default, setter(strip_option))]
+  pub announcement_template: Option<ReorderAnnouncementTemplate>,
+}
+
+/// The item which has been grabbed and its index before it was grabbed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Grabbed {
+  key: Key,
+  original_index: usize,
+}
+
+/// Keyboard reordering for a collection, usable without drag and drop.
+///
+/// * <kbd>Ctrl</kbd> + <kbd>ArrowUp</kbd> / <kbd>ArrowDown</kbd>
+///   (<kbd>Cmd</kbd> on mac) moves the focused item immediately.
+/// * After [`Reorder::grab`] is called, e.g. from a drag handle, the arrow keys
+///   move the grabbed item, <kbd>Enter</kbd> or <kbd>Space</kbd> drop it and
+///   <kbd>Escape</kbd> returns it to where it started. <kbd>ArrowLeft</kbd>
+///   moves the item later in right to left locales.
+///
+/// Every move is announced through the live announcer.
+#[derive(Clone)]
+pub struct Reorder {
+  grabbed: RwSignal<Option<Grabbed>>,
+  is_disabled: Signal<bool>,
+  direction: Signal<Direction>,
+  platform: Signal<PlatformInfo>,
+  strings: Signal<LocalizedStringFormatter>,
+  on_reorder: Rc<Box<dyn Fn(ReorderEvent)>>,
+  announcement_template: Option<Rc<ReorderAnnouncementTemplate>>,
+}
+
+pub fn use_reorder(cx: Scope, props: UseReorderProps) -> Reorder {
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+
+  Reorder {
+    grabbed: create_rw_signal(cx, None),
+    is_disabled: (move || original_is_disabled.get()).derive_signal(cx),
+    direction: use_direction(cx),
+    platform: use_platform(cx),
+    strings: use_localized_string_formatter(cx),
+    on_reorder: Rc::new(props.on_reorder),
+    announcement_template: props.announcement_template.map(Rc::new),
+  }
+}
+
+impl Reorder {
+  /// Whether the item with the provided key is grabbed.
+  pub fn is_grabbed(&self, key: &Key) -> bool {
+    self.grabbed.with(|grabbed| {
+      grabbed
+        .as_ref()
+        .map_or(false, |grabbed| &grabbed.key == key)
+    })
+  }
+
+  /// Grab the item so that it can be moved with the arrow keys. The `items`
+  /// are `(key, text_value)` pairs in display order.
+  pub fn grab<'a>(&self, key: &Key, items: impl IntoIterator<Item = (&'a Key, &'a str)>) {
+    if self.is_disabled.get_untracked() {
+      return;
+    }
+
+    let items: Vec<(&Key, &str)> = items.into_iter().collect();
+    let Some(index) = items.iter().position(|(item, _)| *item == key) else {
+      return;
+    };
+
+    self.grabbed.set(Some(Grabbed {
+      key: key.clone(),
+      original_index: index,
+    }));
+
+    self.announce(ReorderAnnouncement {
+      announcement_type: ReorderAnnouncementType::Grabbed,
+      item: items[index].1,
+      position: index + 1,
+      count: items.len(),
+    });
+  }
+
+  /// Handle a key down event on the collection. Returns `true` when the event
+  /// was used for reordering, in which case the collection shouldn't handle it.
+  pub fn handle_key_down<'a>(
+    &self,
+    event: &KeyboardEvent,
+    focused_key: &Key,
+    items: impl IntoIterator<Item = (&'a Key, &'a str)>,
+  ) -> bool {
+    if self.is_disabled.get_untracked() {
+      return false;
+    }
+
+    let items: Vec<(&Key, &str)> = items.into_iter().collect();
+    let key = KeyboardKey::from(event).logical(self.direction.get_untracked());
+    let grabbed = self.grabbed.get_untracked();
+    let ctrl_key = if self.platform.get_untracked().is_mac() {
+      event.meta_key()
+    } else {
+      event.ctrl_key()
+    };
+
+    let handled = match (grabbed, key) {
+      (Some(grabbed), KeyboardKey::ArrowUp | KeyboardKey::ArrowLeft) => {
+        self.move_by(&grabbed.key, &items, -1, false);
+        true
+      }
+      (Some(grabbed), KeyboardKey::ArrowDown | KeyboardKey::ArrowRight) => {
+        self.move_by(&grabbed.key, &items, 1, false);
+        true
+      }
+      (Some(grabbed), KeyboardKey::Enter | KeyboardKey::Space) => {
+        self.drop_grabbed(&grabbed.key, &items);
+        true
+      }
+      (Some(grabbed), KeyboardKey::Escape) => {
+        self.cancel_grabbed(&grabbed, &items);
+        true
+      }
+      (None, KeyboardKey::ArrowUp) if ctrl_key => {
+        self.move_by(focused_key, &items, -1, true);
+        true
+      }
+      (None, KeyboardKey::ArrowDown) if ctrl_key => {
+        self.move_by(focused_key, &items, 1, true);
+        true
+      }
+      _ => false,
+    };
+
+    if handled {
+      event.prevent_default();
+      event.stop_propagation();
+    }
+
+    handled
+  }
+
+  fn move_by(&self, key: &Key, items: &[(&Key, &str)], delta: isize, is_immediate: bool) {
+    let Some(from_index) = items.iter().position(|(item, _)| *item == key) else {
+      return;
+    };
+
+    let Some(to_index) = from_index
+      .checked_add_signed(delta)
+      .filter(|index| *index < items.len())
+    else {
+      return;
+    };
+
+    (self.on_reorder)(ReorderEvent {
+      key: key.clone(),
+      from_index,
+      to_index,
+    });
+
+    self.announce(ReorderAnnouncement {
+      announcement_type: if is_immediate {
+        ReorderAnnouncementType::Moved
+      } else {
+        ReorderAnnouncementType::Moving
+      },
+      item: items[from_index].1,
+      position: to_index + 1,
+      count: items.len(),
+    });
+  }
+
+  fn drop_grabbed(&self, key: &Key, items: &[(&Key, &str)]) {
+    self.grabbed.set(None);
+
+    if let Some(index) = items.iter().position(|(item, _)| *item == key) {
+      self.announce(ReorderAnnouncement {
+        announcement_type: ReorderAnnouncementType::Dropped,
+        item: items[index].1,
+        position: index + 1,
+        count: items.len(),
+      });
+    }
+  }
+
+  fn cancel_grabbed(&self, grabbed: &Grabbed, items: &[(&Key, &str)]) {
+    self.grabbed.set(None);
+
+    let Some(from_index) = items.iter().position(|(item, _)| *item == &grabbed.key) else {
+      return;
+    };
+
+    if from_index != grabbed.original_index {
+      (self.on_reorder)(ReorderEvent {
+        key: grabbed.key.clone(),
+        from_index,
+        to_index: grabbed.original_index,
+      });
+    }
+
+    self.announce(ReorderAnnouncement {
+      announcement_type: ReorderAnnouncementType::Cancelled,
+      item: items[from_index].1,
+      position: grabbed.original_index + 1,
+      count: items.len(),
+    });
+  }
+
+  /// Announce with the custom template, falling back to the built-in message
+  /// when there is no template or it has no message for the announcement.
+  fn announce(&self, announcement: ReorderAnnouncement) {
+    let message = self
+      .announcement_template
+      .as_ref()
+      .and_then(|template| template(&announcement))
+      .filter(|message| !message.trim().is_empty())
+      .unwrap_or_else(|| announcement.default_message(&self.strings.get_untracked()));
+
+    announce(message, Politeness::Assertive);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_messages() {
+    let strings = LocalizedStringFormatter::new("en-US", None);
+    let announcement = ReorderAnnouncement {
+      announcement_type: ReorderAnnouncementType::Moved,
+      item: "Apples",
+      position: 2,
+      count: 5,
+    };
+    assert_eq!(
+      announcement.default_message(&strings),
+      "Moved Apples to position 2 of 5."
+    );
+
+    let announcement = ReorderAnnouncement {
+      announcement_type: ReorderAnnouncementType::Cancelled,
+      ..announcement
+    };
+    assert_eq!(
+      announcement.default_message(&strings),
+      "Reordering cancelled. Apples returned to position 2."
+    );
+  }
+
+  #[test]
+  fn localized_default_messages() {
+    let announcement = ReorderAnnouncement {
+      announcement_type: ReorderAnnouncementType::Dropped,
+      item: "Äpfel",
+      position: 3,
+      count: 4,
+    };
+
+    assert_eq!(
+      announcement.default_message(&LocalizedStringFormatter::new("de-DE", None)),
+      "Äpfel an Position 3 von 4 abgelegt."
+    );
+    assert_eq!(
+      announcement.default_message(&LocalizedStringFormatter::new("fr-CA", None)),
+      "Äpfel déposé à la position 3 sur 4."
+    );
+  }
+}
@@ -0,0 +1,113 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::create_rw_signal;
+use leptos::store_value;
+use leptos::web_sys::KeyboardEvent;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::StoredValue;
+use leptos::UntrackedGettableSignal;
+use leptos::UntrackedSettableSignal;
+use leptos_aria_i18n::use_collator;
+use leptos_aria_i18n::Collator;
+use leptos_aria_i18n::CollatorOptions;
+use leptos_aria_i18n::CollatorSensitivity;
+use leptos_aria_i18n::CollatorUsage;
+use leptos_aria_utils::debounce;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::Key;
+
+/// The time without typing before the typeahead search string is reset.
+const TYPEAHEAD_DEBOUNCE_WAIT: Duration = Duration::from_millis(1000);
+
+/// Typeahead state for a collection. Typing characters while the collection is
+/// focused moves focus to the next item whose text starts with the search
+/// string.
+///
+/// Text is matched with a collator for the current locale which ignores case
+/// and accents, so typing `e` also matches `Éclair`.
+#[derive(Copy, Clone)]
+pub struct TypeSelect {
+  search: RwSignal<String>,
+  reset_search: StoredValue<Rc<dyn Fn(())>>,
+  collator: Signal<Collator>,
+}
+
+pub fn use_type_select(cx: Scope) -> TypeSelect {
+  let search = create_rw_signal(cx, String::new());
+  let reset_search = debounce(cx, TYPEAHEAD_DEBOUNCE_WAIT, move |()| {
+    search.set_untracked(String::new());
+  });
+
+  TypeSelect {
+    search,
+    reset_search: store_value(cx, reset_search),
+    collator: use_collator(
+      cx,
+      CollatorOptions::builder()
+        .usage(CollatorUsage::Search)
+        .sensitivity(CollatorSensitivity::Base)
+        .build(),
+    ),
+  }
+}
+
+impl TypeSelect {
+  /// Get the printable character from a keyboard event. Returns `None` for
+  /// modified keys and non-printable keys.
+  pub fn get_character(&self, event: &KeyboardEvent) -> Option<String> {
+    if event.ctrl_key() || event.meta_key() {
+      return None;
+    }
+
+    match KeyboardKey::from(event) {
+      KeyboardKey::Character(character) => Some(character),
+      // A space is only part of the search when a search is already in
+      // progress, otherwise it is used to select the focused item.
+      KeyboardKey::Space if !self.search.get_untracked().trim().is_empty() => Some(" ".to_string()),
+      _ => None,
+    }
+  }
+
+  /// Whether a search is currently in progress.
+  pub fn is_searching(&self) -> bool {
+    !self.search.get_untracked().is_empty()
+  }
+
+  /// Add the character to the search string and find the matching key. Items
+  /// are `(key, text_value)` pairs in display order. The search starts from the
+  /// item after `from_key` and wraps around.
+  pub fn search<'a>(
+    &self,
+    character: &str,
+    items: impl IntoIterator<Item = (&'a Key, &'a str)>,
+    from_key: Option<&Key>,
+  ) -> Option<Key> {
+    let mut search = self.search.get_untracked();
+    search.push_str(character);
+    self.search.set_untracked(search.clone());
+    self.reset_search.with(|reset_search| reset_search(()));
+
+    let items: Vec<(&Key, &str)> = items.into_iter().collect();
+    let collator = self.collator.get_untracked();
+    let search_length = search.chars().count();
+    let start = from_key
+      .and_then(|from_key| items.iter().position(|(key, _)| *key == from_key))
+      .unwrap_or(0);
+
+    // When the search is a single character start from the next item so that
+    // repeatedly typing the same letter cycles through the matches.
+    let offset = usize::from(search_length == 1 && from_key.is_some());
+
+    (0..items.len())
+      .map(|index| &items[(start + offset + index) % items.len()])
+      .find(|(_, text)| {
+        let prefix: String = text.chars().take(search_length).collect();
+        collator.equals(&prefix, &search)
+      })
+      .map(|(key, _)| (*key).clone())
+  }
+}
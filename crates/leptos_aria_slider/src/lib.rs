@@ -0,0 +1,9 @@
+pub use range_slider_state::*;
+pub use slider_state::*;
+pub use use_price_range_slider::*;
+pub use use_slider::*;
+
+mod range_slider_state;
+mod slider_state;
+mod use_price_range_slider;
+mod use_slider;
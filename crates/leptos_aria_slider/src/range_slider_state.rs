@@ -0,0 +1,347 @@
+use std::rc::Rc;
+
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos_aria_numberfield::use_stepper_field_state;
+use leptos_aria_numberfield::StepperFieldState;
+use leptos_aria_numberfield::UseStepperFieldStateProps;
+use leptos_aria_utils::clamp_value;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+
+use crate::use_slider_state;
+use crate::SliderState;
+use crate::UseSliderStateProps;
+
+/// The selected range of a two thumb slider.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct RangeValue {
+  pub start: f64,
+  pub end: f64,
+}
+
+/// One of the thumbs of a range slider.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeThumb {
+  Start,
+  End,
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseRangeSliderStateProps {
+  /// The current range (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub value: Option<MaybeSignal<RangeValue>>,
+
+  /// The initial range (uncontrolled). Defaults to the whole range.
+  #[builder(default, setter(strip_option))]
+  pub default_value: Option<RangeValue>,
+
+  /// The smallest value. Defaults to `0`.
+  #[builder(default, setter(strip_option))]
+  pub min_value: Option<f64>,
+
+  /// The largest value. Defaults to `100`.
+  #[builder(default, setter(strip_option))]
+  pub max_value: Option<f64>,
+
+  /// The amount the value changes with each step. Defaults to `1`.
+  #[builder(default, setter(strip_option))]
+  pub step: Option<f64>,
+
+  /// The smallest allowed distance between the two thumbs. Defaults to `0`.
+  #[builder(default)]
+  pub min_gap: f64,
+
+  /// Handler that is called whenever the range changes, including while
+  /// dragging.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(RangeValue)>>,
+
+  /// Handler that is called once the user stops changing the range.
+  #[builder(default, setter(strip_option))]
+  pub on_change_end: Option<Box<dyn Fn(RangeValue)>>,
+}
+
+/// The state of a slider with a start and an end thumb, each synced with a
+/// number field.
+///
+/// Both thumbs span the whole range so they share the same track, but they
+/// can't pass each other: every change is constrained to keep `min_gap`
+/// between the start and the end value.
+#[derive(Clone)]
+pub struct RangeSliderState {
+  pub start: SliderState,
+  pub end: SliderState,
+  pub start_field: StepperFieldState,
+  pub end_field: StepperFieldState,
+  pub min_value: f64,
+  pub max_value: f64,
+  pub step: f64,
+  pub min_gap: f64,
+  value: ControlledState<RangeValue>,
+}
+
+pub fn use_range_slider_state(cx: Scope, props: UseRangeSliderStateProps) -> RangeSliderState {
+  let min_value = props.min_value.unwrap_or(0.0);
+  let max_value = props.max_value.unwrap_or(100.0);
+  let step = props.step.unwrap_or(1.0);
+  let min_gap = props.min_gap.max(0.0);
+  let default_value = props.default_value.unwrap_or(RangeValue {
+    start: min_value,
+    end: max_value,
+  });
+  let value = use_controlled_state(cx, props.value, default_value, props.on_change);
+  let on_change_end = props.on_change_end.map(Rc::new);
+
+  let set_thumb = {
+    let value = value.clone();
+
+    move |thumb: RangeThumb, thumb_value: f64| {
+      let next = constrain_range(
+        value.get_untracked(),
+        thumb,
+        thumb_value,
+        min_value,
+        max_value,
+        step,
+        min_gap,
+      );
+
+      value.set(next);
+    }
+  };
+
+  let start_signal = {
+    let value = value.clone();
+    (move || value.get().start).derive_signal(cx)
+  };
+  let end_signal = {
+    let value = value.clone();
+    (move || value.get().end).derive_signal(cx)
+  };
+
+  let change_end = {
+    let value = value.clone();
+
+    move |_: f64| {
+      if let Some(ref on_change_end) = on_change_end {
+        on_change_end(value.get_untracked());
+      }
+    }
+  };
+
+  let start = {
+    let set_thumb = set_thumb.clone();
+
+    use_slider_state(
+      cx,
+      UseSliderStateProps::builder()
+        .value(start_signal)
+        .min_value(min_value)
+        .max_value(max_value)
+        .step(step)
+        .on_change(Box::new(move |start| set_thumb(RangeThumb::Start, start)))
+        .on_change_end(Box::new(change_end.clone()))
+        .build(),
+    )
+  };
+
+  let end = {
+    let set_thumb = set_thumb.clone();
+
+    use_slider_state(
+      cx,
+      UseSliderStateProps::builder()
+        .value(end_signal)
+        .min_value(min_value)
+        .max_value(max_value)
+        .step(step)
+        .on_change(Box::new(move |end| set_thumb(RangeThumb::End, end)))
+        .on_change_end(Box::new(change_end.clone()))
+        .build(),
+    )
+  };
+
+  // The fields are limited to the whole range and constrained by the other
+  // thumb once committed, so typing a value never gets stuck half way.
+  let start_field = {
+    let set_thumb = set_thumb.clone();
+    let change_end = change_end.clone();
+
+    use_stepper_field_state(
+      cx,
+      UseStepperFieldStateProps::builder()
+        .value(start_signal)
+        .min_value(min_value)
+        .max_value(max_value)
+        .step(step)
+        .on_change(Box::new(move |start| {
+          set_thumb(RangeThumb::Start, start);
+          change_end(start);
+        }))
+        .build(),
+    )
+  };
+
+  let end_field = use_stepper_field_state(
+    cx,
+    UseStepperFieldStateProps::builder()
+      .value(end_signal)
+      .min_value(min_value)
+      .max_value(max_value)
+      .step(step)
+      .on_change(Box::new(move |end| {
+        set_thumb(RangeThumb::End, end);
+        change_end(end);
+      }))
+      .build(),
+  );
+
+  RangeSliderState {
+    start,
+    end,
+    start_field,
+    end_field,
+    min_value,
+    max_value,
+    step,
+    min_gap,
+    value,
+  }
+}
+
+impl RangeSliderState {
+  pub fn value(&self) -> RangeValue {
+    self.value.get()
+  }
+
+  pub fn value_untracked(&self) -> RangeValue {
+    self.value.get_untracked()
+  }
+
+  /// Move one thumb, keeping it within the range and away from the other
+  /// thumb.
+  pub fn set_thumb_value(&self, thumb: RangeThumb, value: f64) {
+    self.value.set(constrain_range(
+      self.value_untracked(),
+      thumb,
+      value,
+      self.min_value,
+      self.max_value,
+      self.step,
+      self.min_gap,
+    ));
+  }
+
+  /// The thumb closest to the provided value, which is moved when the track is
+  /// pressed.
+  pub fn closest_thumb(&self, value: f64) -> RangeThumb {
+    closest_thumb(self.value_untracked(), value)
+  }
+
+  /// The state of the provided thumb.
+  pub fn thumb(&self, thumb: RangeThumb) -> &SliderState {
+    match thumb {
+      RangeThumb::Start => &self.start,
+      RangeThumb::End => &self.end,
+    }
+  }
+}
+
+/// Move one thumb of the range. The value is snapped to the step and clamped
+/// between the limits and the other thumb.
+pub fn constrain_range(
+  range: RangeValue,
+  thumb: RangeThumb,
+  value: f64,
+  min_value: f64,
+  max_value: f64,
+  step: f64,
+  min_gap: f64,
+) -> RangeValue {
+  match thumb {
+    RangeThumb::Start => {
+      let max = (range.end - min_gap).max(min_value);
+      RangeValue {
+        start: clamp_value(value, Some(min_value), Some(max_value), step).min(max),
+        ..range
+      }
+    }
+    RangeThumb::End => {
+      let min = (range.start + min_gap).min(max_value);
+      RangeValue {
+        end: clamp_value(value, Some(min_value), Some(max_value), step).max(min),
+        ..range
+      }
+    }
+  }
+}
+
+/// The thumb closest to the value. When both thumbs are at the same position
+/// the end thumb is moved for values above it.
+pub fn closest_thumb(range: RangeValue, value: f64) -> RangeThumb {
+  let start_distance = (value - range.start).abs();
+  let end_distance = (value - range.end).abs();
+
+  if end_distance < start_distance || (end_distance == start_distance && value > range.end) {
+    RangeThumb::End
+  } else {
+    RangeThumb::Start
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const RANGE: RangeValue = RangeValue {
+    start: 20.0,
+    end: 80.0,
+  };
+
+  #[test]
+  fn thumbs_cannot_pass_each_other() {
+    let range = constrain_range(RANGE, RangeThumb::Start, 95.0, 0.0, 100.0, 1.0, 10.0);
+    assert_eq!(
+      range,
+      RangeValue {
+        start: 70.0,
+        end: 80.0
+      }
+    );
+
+    let range = constrain_range(RANGE, RangeThumb::End, 5.0, 0.0, 100.0, 1.0, 10.0);
+    assert_eq!(
+      range,
+      RangeValue {
+        start: 20.0,
+        end: 30.0
+      }
+    );
+  }
+
+  #[test]
+  fn values_are_clamped_and_snapped() {
+    let range = constrain_range(RANGE, RangeThumb::Start, -10.0, 0.0, 100.0, 5.0, 0.0);
+    assert_eq!(range.start, 0.0);
+
+    let range = constrain_range(RANGE, RangeThumb::End, 86.0, 0.0, 100.0, 5.0, 0.0);
+    assert_eq!(range.end, 85.0);
+  }
+
+  #[test]
+  fn picks_the_closest_thumb() {
+    assert_eq!(closest_thumb(RANGE, 30.0), RangeThumb::Start);
+    assert_eq!(closest_thumb(RANGE, 60.0), RangeThumb::End);
+
+    let collapsed = RangeValue {
+      start: 50.0,
+      end: 50.0,
+    };
+    assert_eq!(closest_thumb(collapsed, 40.0), RangeThumb::Start);
+    assert_eq!(closest_thumb(collapsed, 60.0), RangeThumb::End);
+  }
+}
@@ -0,0 +1,182 @@
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::clamp_value;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseSliderStateProps {
+  /// The current value (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub value: Option<MaybeSignal<f64>>,
+
+  /// The initial value (uncontrolled). Defaults to the minimum value.
+  #[builder(default, setter(strip_option))]
+  pub default_value: Option<f64>,
+
+  /// The smallest value. Defaults to `0`.
+  #[builder(default, setter(strip_option, into))]
+  pub min_value: Option<MaybeSignal<f64>>,
+
+  /// The largest value. Defaults to `100`.
+  #[builder(default, setter(strip_option, into))]
+  pub max_value: Option<MaybeSignal<f64>>,
+
+  /// The amount the value changes with each step. Defaults to `1`.
+  #[builder(default, setter(strip_option))]
+  pub step: Option<f64>,
+
+  /// Handler that is called whenever the value changes, including while
+  /// dragging.
+  #[builder(default, setter(strip_option))]
+  pub on_change: Option<Box<dyn Fn(f64)>>,
+
+  /// Handler that is called once the user stops changing the value, i.e. when
+  /// dragging ends or after a keyboard change.
+  #[builder(default, setter(strip_option))]
+  pub on_change_end: Option<Box<dyn Fn(f64)>>,
+}
+
+/// The value of a slider.
+#[derive(Clone)]
+pub struct SliderState {
+  pub min_value: Signal<f64>,
+  pub max_value: Signal<f64>,
+  pub step: f64,
+  value: ControlledState<f64>,
+  is_dragging: RwSignal<bool>,
+  on_change_end: Option<Rc<Box<dyn Fn(f64)>>>,
+}
+
+pub fn use_slider_state(cx: Scope, props: UseSliderStateProps) -> SliderState {
+  let original_min_value = props.min_value.unwrap_or(0.0.into());
+  let min_value = (move || original_min_value.get()).derive_signal(cx);
+  let original_max_value = props.max_value.unwrap_or(100.0.into());
+  let max_value = (move || original_max_value.get()).derive_signal(cx);
+  let default_value = props
+    .default_value
+    .unwrap_or_else(|| min_value.get_untracked());
+
+  SliderState {
+    min_value,
+    max_value,
+    step: props.step.unwrap_or(1.0),
+    value: use_controlled_state(cx, props.value, default_value, props.on_change),
+    is_dragging: create_rw_signal(cx, false),
+    on_change_end: props.on_change_end.map(Rc::new),
+  }
+}
+
+impl SliderState {
+  pub fn value(&self) -> f64 {
+    self.value.get()
+  }
+
+  pub fn value_untracked(&self) -> f64 {
+    self.value.get_untracked()
+  }
+
+  /// The value as a fraction between `0` and `1` of the range.
+  pub fn percent(&self) -> f64 {
+    let min_value = self.min_value.get();
+    let range = self.max_value.get() - min_value;
+
+    if range <= 0.0 {
+      return 0.0;
+    }
+
+    (self.value() - min_value) / range
+  }
+
+  /// The value at the provided fraction of the range, snapped to the step.
+  pub fn value_for_percent(&self, percent: f64) -> f64 {
+    let min_value = self.min_value.get_untracked();
+    let max_value = self.max_value.get_untracked();
+    self.clamp(min_value + percent.clamp(0.0, 1.0) * (max_value - min_value))
+  }
+
+  /// Set the value, clamped to the range and snapped to the step.
+  pub fn set_value(&self, value: f64) {
+    self.value.set(self.clamp(value));
+  }
+
+  /// Move the value by `steps` steps.
+  pub fn increment(&self, steps: f64) {
+    self.set_value(self.value_untracked() + steps * self.step);
+  }
+
+  pub fn decrement(&self, steps: f64) {
+    self.increment(-steps);
+  }
+
+  /// The number of steps moved by <kbd>PageUp</kbd> and <kbd>PageDown</kbd>,
+  /// which is a tenth of the range.
+  pub fn page_steps(&self) -> f64 {
+    let range = self.max_value.get_untracked() - self.min_value.get_untracked();
+    ((range / 10.0) / self.step).round().max(1.0)
+  }
+
+  pub fn is_dragging(&self) -> bool {
+    self.is_dragging.get()
+  }
+
+  /// Update the dragging state. `on_change_end` is called once dragging
+  /// stops.
+  pub fn set_dragging(&self, is_dragging: bool) {
+    let was_dragging = self.is_dragging.get_untracked();
+    self.is_dragging.set(is_dragging);
+
+    if was_dragging && !is_dragging {
+      self.change_end();
+    }
+  }
+
+  /// Notify `on_change_end` of the current value.
+  pub fn change_end(&self) {
+    if let Some(ref on_change_end) = self.on_change_end {
+      on_change_end(self.value_untracked());
+    }
+  }
+
+  fn clamp(&self, value: f64) -> f64 {
+    clamp_value(
+      value,
+      Some(self.min_value.get_untracked()),
+      Some(self.max_value.get_untracked()),
+      self.step,
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+
+  #[test]
+  fn values_are_clamped_and_snapped_to_the_step() {
+    let values = run_scope(create_runtime(), |cx| {
+      let state = use_slider_state(cx, UseSliderStateProps::builder().step(5.0).build());
+      state.set_value(42.0);
+      let snapped = state.value_untracked();
+      state.increment(20.0);
+      (
+        snapped,
+        state.value_untracked(),
+        state.value_for_percent(0.5),
+      )
+    });
+
+    assert_eq!(values, (40.0, 100.0, 50.0));
+  }
+}
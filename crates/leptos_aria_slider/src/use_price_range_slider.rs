@@ -0,0 +1,226 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_numberfield::use_stepper_field;
+use leptos_aria_numberfield::StepperFieldResult;
+use leptos_aria_numberfield::UseStepperFieldProps;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::Orientation;
+use leptos_aria_utils::ValueLabelOptions;
+
+use crate::use_slider;
+use crate::use_slider::get_percent;
+use crate::use_slider::SliderCallback;
+use crate::RangeSliderState;
+use crate::RangeThumb;
+use crate::SliderResult;
+use crate::SliderTrackResult;
+use crate::UseSliderProps;
+
+/// The default label of the start thumb and its number field.
+pub const PRICE_RANGE_START_LABEL: &str = "Minimum price";
+
+/// The default label of the end thumb and its number field.
+pub const PRICE_RANGE_END_LABEL: &str = "Maximum price";
+
+#[derive(TypedBuilder, Default)]
+pub struct UsePriceRangeSliderProps {
+  /// The accessible name of the whole range, e.g. "Price".
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the whole range.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+
+  /// The label of the start thumb and field. Defaults to
+  /// [`PRICE_RANGE_START_LABEL`].
+  #[builder(default, setter(strip_option, into))]
+  pub start_aria_label: Option<MaybeSignal<String>>,
+
+  /// The label of the end thumb and field. Defaults to
+  /// [`PRICE_RANGE_END_LABEL`].
+  #[builder(default, setter(strip_option, into))]
+  pub end_aria_label: Option<MaybeSignal<String>>,
+
+  /// Whether the slider and the fields are disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// Format a value for `aria-valuetext`, e.g. `"$20"`.
+  #[builder(default, setter(strip_option))]
+  pub format_value: Option<Rc<dyn Fn(f64) -> String>>,
+}
+
+#[derive(Clone)]
+pub struct PriceRangeGroupResult {
+  pub role: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+}
+
+#[derive(Clone)]
+pub struct PriceRangeSliderResult {
+  pub group: PriceRangeGroupResult,
+  /// The shared track, which moves the closest thumb to the pressed position.
+  pub track: SliderTrackResult,
+  pub start: SliderResult,
+  pub end: SliderResult,
+  pub start_field: StepperFieldResult,
+  pub end_field: StepperFieldResult,
+}
+
+/// Provides the accessibility implementation for a range filter: a slider
+/// with a start and an end thumb, each synced with a number field.
+///
+/// * The thumbs keep `min_gap` between them. Each thumb's `aria-valuemin` or
+///   `aria-valuemax` is the value of the other thumb.
+/// * Committing a number field moves the matching thumb and dragging a thumb
+///   updates the matching field.
+pub fn use_price_range_slider(
+  cx: Scope,
+  state: &RangeSliderState,
+  props: UsePriceRangeSliderProps,
+) -> ReadSignal<PriceRangeSliderResult> {
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let original_start_aria_label = props
+    .start_aria_label
+    .unwrap_or_else(|| PRICE_RANGE_START_LABEL.to_string().into());
+  let start_aria_label = (move || original_start_aria_label.get()).derive_signal(cx);
+  let original_end_aria_label = props
+    .end_aria_label
+    .unwrap_or_else(|| PRICE_RANGE_END_LABEL.to_string().into());
+  let end_aria_label = (move || original_end_aria_label.get()).derive_signal(cx);
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+  let format_value = props.format_value;
+
+  let value_label = || {
+    match format_value.clone() {
+      Some(format_value) => {
+        ValueLabelOptions::builder()
+          .get_value_label(Box::new(move |value| format_value(value)))
+          .build()
+      }
+      None => ValueLabelOptions::default(),
+    }
+  };
+
+  let mut start = use_slider(
+    cx,
+    &state.start,
+    UseSliderProps::builder()
+      .aria_label(start_aria_label)
+      .is_disabled(is_disabled)
+      .value_label(value_label())
+      .build(),
+  )
+  .get_untracked();
+  let mut end = use_slider(
+    cx,
+    &state.end,
+    UseSliderProps::builder()
+      .aria_label(end_aria_label)
+      .is_disabled(is_disabled)
+      .value_label(value_label())
+      .build(),
+  )
+  .get_untracked();
+
+  // Each thumb is limited by the other thumb.
+  {
+    let range = state.clone();
+    start.thumb.aria_valuemax = (move || range.value().end - range.min_gap).derive_signal(cx);
+  }
+  {
+    let range = state.clone();
+    end.thumb.aria_valuemin = (move || range.value().start + range.min_gap).derive_signal(cx);
+  }
+
+  let direction = use_direction(cx);
+
+  let on_pointer_down: SliderCallback<PointerEvent> = {
+    let state = state.clone();
+    let start_on_pointer_down = start.track.on_pointer_down.clone();
+    let end_on_pointer_down = end.track.on_pointer_down.clone();
+
+    Rc::new(Box::new(move |event: PointerEvent| {
+      let Some(track) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      else {
+        return;
+      };
+
+      let percent = get_percent(
+        &track,
+        &event,
+        Orientation::Horizontal,
+        direction.get_untracked(),
+      );
+      let value = state.start.value_for_percent(percent);
+
+      // The thumb's own track handler moves it and tracks the drag.
+      match state.closest_thumb(value) {
+        RangeThumb::Start => start_on_pointer_down(event),
+        RangeThumb::End => end_on_pointer_down(event),
+      }
+    }))
+  };
+
+  let start_field = use_stepper_field(
+    cx,
+    &state.start_field,
+    UseStepperFieldProps::builder()
+      .aria_label(start_aria_label)
+      .is_disabled(is_disabled)
+      .value_label(value_label())
+      .build(),
+  )
+  .get_untracked();
+  let end_field = use_stepper_field(
+    cx,
+    &state.end_field,
+    UseStepperFieldProps::builder()
+      .aria_label(end_aria_label)
+      .is_disabled(is_disabled)
+      .value_label(value_label())
+      .build(),
+  )
+  .get_untracked();
+
+  let (price_range_slider_result, _) = create_signal(
+    cx,
+    PriceRangeSliderResult {
+      group: PriceRangeGroupResult {
+        role: "group",
+        aria_label,
+        aria_labelledby,
+      },
+      track: SliderTrackResult { on_pointer_down },
+      start,
+      end,
+      start_field,
+      end_field,
+    },
+  );
+
+  price_range_slider_result
+}
@@ -0,0 +1,285 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::document;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_i18n::use_number_formatter;
+use leptos_aria_i18n::NumberFormatOptions;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::use_field_state;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::use_value_label;
+use leptos_aria_utils::Direction;
+use leptos_aria_utils::GlobalListeners;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_utils::Orientation;
+use leptos_aria_utils::ValueLabelOptions;
+
+use crate::SliderState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseSliderProps {
+  /// The id of the thumb. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// The accessible name of the slider when there is no visible label.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the slider.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+
+  /// Whether the slider is disabled.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// The orientation of the slider.
+  #[builder(default)]
+  pub orientation: Orientation,
+
+  /// How the value is described to assistive technology.
+  #[builder(default)]
+  pub value_label: ValueLabelOptions,
+
+  /// How the value is formatted when `value_label` has no `format_value`.
+  /// Defaults to a decimal number in the format of the locale.
+  #[builder(default, setter(strip_option))]
+  pub format_options: Option<NumberFormatOptions>,
+}
+
+/// The props for the track, which moves the thumb to the pressed position.
+#[derive(Clone)]
+pub struct SliderTrackResult {
+  pub on_pointer_down: SliderCallback<PointerEvent>,
+}
+
+/// The props for the focusable thumb.
+#[derive(Clone)]
+pub struct SliderThumbResult {
+  pub id: String,
+  pub role: &'static str,
+  pub tab_index: Signal<Option<i32>>,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+  pub aria_valuenow: Signal<f64>,
+  pub aria_valuemin: Signal<f64>,
+  pub aria_valuemax: Signal<f64>,
+  pub aria_valuetext: Signal<Option<String>>,
+  pub aria_orientation: &'static str,
+  pub aria_disabled: Signal<Option<bool>>,
+  pub on_key_down: SliderCallback<KeyboardEvent>,
+}
+
+#[derive(Clone)]
+pub struct SliderResult {
+  pub track: SliderTrackResult,
+  pub thumb: SliderThumbResult,
+  /// The position of the thumb as a fraction of the track, for styling.
+  pub percent: Signal<f64>,
+  /// The text describing the value, for an `<output>` element.
+  pub value_text: Signal<String>,
+}
+
+/// Provides the behavior and accessibility implementation for a single thumb
+/// slider.
+///
+/// * The arrow keys move the value by one step, <kbd>PageUp</kbd> and
+///   <kbd>PageDown</kbd> by a tenth of the range and <kbd>Home</kbd> and
+///   <kbd>End</kbd> to the limits.
+/// * In right to left locales horizontal sliders increase from the right, so
+///   <kbd>ArrowLeft</kbd> increments the value.
+/// * Pressing the track moves the thumb to the pointer and dragging continues
+///   until the pointer is released anywhere in the document.
+pub fn use_slider(
+  cx: Scope,
+  state: &SliderState,
+  props: UseSliderProps,
+) -> ReadSignal<SliderResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let field = use_field_state(cx, props.is_disabled, None);
+  let orientation = props.orientation;
+  let mut value_label_options = props.value_label;
+
+  if value_label_options.format_value.is_none() {
+    let formatter = use_number_formatter(cx, props.format_options.unwrap_or_default());
+    value_label_options.format_value = Some(Box::new(move |value| formatter.get().format(value)));
+  }
+
+  let value_label = use_value_label(cx, value_label_options);
+  let direction = use_direction(cx);
+
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+
+  let value = {
+    let state = state.clone();
+    (move || state.value()).derive_signal(cx)
+  };
+
+  let percent = {
+    let state = state.clone();
+    (move || state.percent()).derive_signal(cx)
+  };
+
+  let value_text = {
+    let value_label = value_label.clone();
+    (move || value_label.label(value.get())).derive_signal(cx)
+  };
+
+  let on_key_down: SliderCallback<KeyboardEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |event: KeyboardEvent| {
+      if !field.can_change() {
+        return;
+      }
+
+      let page_steps = state.page_steps();
+
+      match KeyboardKey::from(&event).logical(direction.get_untracked()) {
+        KeyboardKey::ArrowRight | KeyboardKey::ArrowUp => state.increment(1.0),
+        KeyboardKey::ArrowLeft | KeyboardKey::ArrowDown => state.decrement(1.0),
+        KeyboardKey::PageUp => state.increment(page_steps),
+        KeyboardKey::PageDown => state.decrement(page_steps),
+        KeyboardKey::Home => state.set_value(state.min_value.get_untracked()),
+        KeyboardKey::End => state.set_value(state.max_value.get_untracked()),
+        _ => return,
+      }
+
+      event.prevent_default();
+      state.change_end();
+    }))
+  };
+
+  let listeners = GlobalListeners::new(cx);
+
+  let on_pointer_down: SliderCallback<PointerEvent> = {
+    let state = state.clone();
+
+    Rc::new(Box::new(move |event: PointerEvent| {
+      if event.button() != 0 || !field.can_change() {
+        return;
+      }
+
+      let Some(track) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      else {
+        return;
+      };
+
+      event.prevent_default();
+      state.set_dragging(true);
+      state.set_value(state.value_for_percent(get_percent(
+        &track,
+        &event,
+        orientation,
+        direction.get_untracked(),
+      )));
+
+      let on_pointer_move = {
+        let state = state.clone();
+        move |event: PointerEvent| {
+          state.set_value(state.value_for_percent(get_percent(
+            &track,
+            &event,
+            orientation,
+            direction.get_untracked(),
+          )));
+        }
+      };
+
+      let on_pointer_up = {
+        let state = state.clone();
+        let listeners = listeners.clone();
+        move |_: PointerEvent| {
+          state.set_dragging(false);
+          listeners.remove_all_listeners();
+        }
+      };
+
+      listeners.add_event_listener(document(), "pointermove", on_pointer_move, false);
+      listeners.add_event_listener(document(), "pointerup", on_pointer_up, false);
+    }))
+  };
+
+  let tab_index = {
+    let is_disabled = field.is_disabled;
+    (move || (!is_disabled.get()).then_some(0)).derive_signal(cx)
+  };
+
+  let (slider_result, _) = create_signal(
+    cx,
+    SliderResult {
+      track: SliderTrackResult { on_pointer_down },
+      thumb: SliderThumbResult {
+        id,
+        role: "slider",
+        tab_index,
+        aria_label,
+        aria_labelledby,
+        aria_valuenow: value,
+        aria_valuemin: state.min_value,
+        aria_valuemax: state.max_value,
+        aria_valuetext: value_label.aria_valuetext(cx, value),
+        aria_orientation: orientation.as_aria_orientation(),
+        aria_disabled: field.aria_disabled(cx),
+        on_key_down,
+      },
+      percent,
+      value_text,
+    },
+  );
+
+  slider_result
+}
+
+pub(crate) type SliderCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// The position of the pointer as a fraction of the track. Vertical sliders
+/// increase from the bottom and horizontal sliders from the right in right to
+/// left locales.
+pub(crate) fn get_percent(
+  track: &Element,
+  event: &PointerEvent,
+  orientation: Orientation,
+  direction: Direction,
+) -> f64 {
+  let rect = track.get_bounding_client_rect();
+
+  let percent = match orientation {
+    Orientation::Horizontal if rect.width() > 0.0 => {
+      let percent = (f64::from(event.client_x()) - rect.left()) / rect.width();
+
+      if direction.is_rtl() {
+        1.0 - percent
+      } else {
+        percent
+      }
+    }
+    Orientation::Vertical if rect.height() > 0.0 => {
+      1.0 - (f64::from(event.client_y()) - rect.top()) / rect.height()
+    }
+    _ => 0.0,
+  };
+
+  percent.clamp(0.0, 1.0)
+}
@@ -0,0 +1,223 @@
+/// The prefix of the element ids created with `use_id` and `generate_id`.
+const GENERATED_ID_PREFIX: &str = "leptos-aria-";
+
+/// The segment after the prefix of the ids from `generate_id`, e.g.
+/// `leptos-aria-gen-3`.
+const GENERATED_ID_SEGMENT: &str = "gen-";
+
+/// Attributes which are kept in the snapshot alongside `role` and `aria-*`.
+const SNAPSHOT_ATTRIBUTES: [&str; 7] = [
+  "disabled", "for", "hidden", "href", "id", "inert", "tabindex",
+];
+
+/// Reduce server rendered html to the accessibility relevant attributes of
+/// each element.
+///
+/// Every element with a `role`, an `aria-*` attribute or one of the other
+/// attributes which affect the accessibility tree is written on its own line
+/// with the attributes sorted by name. Elements without any of these are
+/// omitted, as are hydration markers and text content.
+///
+/// Generated ids are replaced with stable placeholders (`id-1`, `id-2`, ...)
+/// in order of appearance so the snapshot doesn't change when unrelated hooks
+/// create more ids.
+pub fn extract_aria_attributes(html: &str) -> String {
+  let html = normalize_generated_ids(html);
+  let mut lines = vec![];
+  let mut rest = html.as_str();
+
+  while let Some(start) = rest.find('<') {
+    rest = &rest[start + 1..];
+
+    if rest.starts_with('/') || rest.starts_with('!') {
+      rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+      continue;
+    }
+
+    let (tag, attributes, remaining) = parse_start_tag(rest);
+    rest = remaining;
+
+    let mut kept = attributes
+      .into_iter()
+      .filter(|(name, value)| is_snapshot_attribute(name) && !is_hydration_key(name, value))
+      .collect::<Vec<_>>();
+
+    if kept.is_empty() {
+      continue;
+    }
+
+    kept.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let attributes = kept
+      .iter()
+      .map(|(name, value)| {
+        match value {
+          Some(value) => format!("{name}=\"{value}\""),
+          None => name.clone(),
+        }
+      })
+      .collect::<Vec<_>>()
+      .join(" ");
+
+    lines.push(format!("<{tag} {attributes}>"));
+  }
+
+  lines.join("\n")
+}
+
+fn is_snapshot_attribute(name: &str) -> bool {
+  name == "role" || name.starts_with("aria-") || SNAPSHOT_ATTRIBUTES.contains(&name)
+}
+
+/// Leptos marks hydrated elements with generated `_0-1` style ids which are
+/// unrelated to the accessibility tree.
+fn is_hydration_key(name: &str, value: &Option<String>) -> bool {
+  name == "id"
+    && value
+      .as_deref()
+      .map_or(false, |value| value.starts_with('_'))
+}
+
+type Attribute = (String, Option<String>);
+
+/// Parse the tag name and attributes of a start tag. The input begins directly
+/// after the opening `<`.
+fn parse_start_tag(input: &str) -> (String, Vec<Attribute>, &str) {
+  let name_end = input
+    .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+    .unwrap_or(input.len());
+  let tag = input[..name_end].to_lowercase();
+  let mut rest = &input[name_end..];
+  let mut attributes = vec![];
+
+  loop {
+    rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+
+    if rest.is_empty() {
+      break;
+    }
+
+    if let Some(remaining) = rest.strip_prefix('>') {
+      rest = remaining;
+      break;
+    }
+
+    let name_end = rest
+      .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+      .unwrap_or(rest.len());
+    let name = rest[..name_end].to_lowercase();
+    rest = &rest[name_end..];
+
+    let Some(remaining) = rest.trim_start().strip_prefix('=') else {
+      attributes.push((name, None));
+      continue;
+    };
+
+    let remaining = remaining.trim_start();
+    let (value, remaining) = match remaining.chars().next() {
+      Some(quote @ ('"' | '\'')) => {
+        let inner = &remaining[1..];
+        let end = inner.find(quote).unwrap_or(inner.len());
+        (&inner[..end], inner.get(end + 1..).unwrap_or(""))
+      }
+      _ => {
+        let end = remaining
+          .find(|c: char| c.is_whitespace() || c == '>')
+          .unwrap_or(remaining.len());
+        (&remaining[..end], &remaining[end..])
+      }
+    };
+
+    attributes.push((name, Some(value.to_string())));
+    rest = remaining;
+  }
+
+  (tag, attributes, rest)
+}
+
+/// Replace every generated id with a placeholder numbered in order of first
+/// appearance in the document.
+fn normalize_generated_ids(html: &str) -> String {
+  let mut seen: Vec<&str> = vec![];
+  let mut output = String::with_capacity(html.len());
+  let mut rest = html;
+
+  while let Some(start) = rest.find(GENERATED_ID_PREFIX) {
+    output.push_str(&rest[..start]);
+
+    let after_prefix = &rest[start + GENERATED_ID_PREFIX.len()..];
+    let segment = if after_prefix.starts_with(GENERATED_ID_SEGMENT) {
+      GENERATED_ID_SEGMENT.len()
+    } else {
+      0
+    };
+    let after_segment = &after_prefix[segment..];
+    let digits = after_segment
+      .find(|c: char| !c.is_ascii_digit())
+      .unwrap_or(after_segment.len());
+
+    if digits == 0 {
+      output.push_str(GENERATED_ID_PREFIX);
+      rest = after_prefix;
+      continue;
+    }
+
+    let id = &rest[start..start + GENERATED_ID_PREFIX.len() + segment + digits];
+    let index = match seen.iter().position(|existing| *existing == id) {
+      Some(index) => index,
+      None => {
+        seen.push(id);
+        seen.len() - 1
+      }
+    };
+
+    output.push_str(&format!("id-{}", index + 1));
+    rest = &after_segment[digits..];
+  }
+
+  output.push_str(rest);
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn keeps_only_accessibility_attributes() {
+    let html = r#"<div data-hk="0-1" class="wrapper"><button id="_0-2" type="button" aria-expanded="false" id="trigger" aria-controls="panel">Toggle</button><!--hk=0-2--><div role="group" id="panel" hidden>Content</div></div>"#;
+
+    assert_eq!(
+      extract_aria_attributes(html),
+      "<button aria-controls=\"panel\" aria-expanded=\"false\" id=\"trigger\">\n<div hidden \
+       id=\"panel\" role=\"group\">"
+    );
+  }
+
+  #[test]
+  fn normalizes_generated_ids() {
+    let html = r#"<label id="leptos-aria-12" for="leptos-aria-7"></label><input id="leptos-aria-7" aria-labelledby="leptos-aria-12 leptos-aria-7-suffix"/>"#;
+
+    assert_eq!(
+      extract_aria_attributes(html),
+      "<label for=\"id-2\" id=\"id-1\">\n<input aria-labelledby=\"id-1 id-2-suffix\" id=\"id-2\">"
+    );
+
+    let html = r#"<div id="leptos-aria-gen-4" aria-describedby="leptos-aria-4"></div>"#;
+
+    assert_eq!(
+      extract_aria_attributes(html),
+      "<div aria-describedby=\"id-2\" id=\"id-1\">"
+    );
+  }
+
+  #[test]
+  fn handles_unquoted_and_self_closing_attributes() {
+    let html = "<input type=checkbox aria-checked=mixed disabled/><img alt='' role=presentation>";
+
+    assert_eq!(
+      extract_aria_attributes(html),
+      "<input aria-checked=\"mixed\" disabled>\n<img role=\"presentation\">"
+    );
+  }
+}
@@ -0,0 +1,7 @@
+pub use aria_snapshot::*;
+pub use permutations::*;
+pub use render::*;
+
+mod aria_snapshot;
+mod permutations;
+mod render;
@@ -0,0 +1,103 @@
+/// One combination of boolean states passed to the render function of
+/// [`snapshot_permutations`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatePermutation {
+  values: Vec<(&'static str, bool)>,
+}
+
+impl StatePermutation {
+  /// The value of the named state. Returns `false` for unknown names.
+  pub fn get(&self, name: &str) -> bool {
+    self
+      .values
+      .iter()
+      .find(|(key, _)| *key == name)
+      .map_or(false, |(_, value)| *value)
+  }
+
+  /// The heading used for this permutation in the snapshot.
+  pub fn label(&self) -> String {
+    if self.values.is_empty() {
+      return "default".into();
+    }
+
+    self
+      .values
+      .iter()
+      .map(|(key, value)| format!("{key}={value}"))
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+}
+
+/// Every combination of the provided boolean states, starting with all states
+/// `false`.
+pub fn state_permutations(names: &[&'static str]) -> Vec<StatePermutation> {
+  (0..1_usize << names.len())
+    .map(|mask| {
+      StatePermutation {
+        values: names
+          .iter()
+          .enumerate()
+          .map(|(index, name)| (*name, mask & (1 << (names.len() - 1 - index)) != 0))
+          .collect(),
+      }
+    })
+    .collect()
+}
+
+/// Render every combination of the provided boolean states and join the
+/// results into a single snapshot with a heading for each permutation.
+///
+/// Pair this with [`render_aria_snapshot`](crate::render_aria_snapshot) and
+/// `insta::assert_snapshot!` to catch attribute regressions for every state a
+/// hook supports.
+pub fn snapshot_permutations(
+  names: &[&'static str],
+  render: impl Fn(&StatePermutation) -> String,
+) -> String {
+  state_permutations(names)
+    .iter()
+    .map(|permutation| format!("## {}\n{}", permutation.label(), render(permutation)))
+    .collect::<Vec<_>>()
+    .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn creates_every_permutation() {
+    let permutations = state_permutations(&["is_disabled", "is_expanded"]);
+    let labels = permutations
+      .iter()
+      .map(StatePermutation::label)
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      labels,
+      vec![
+        "is_disabled=false, is_expanded=false",
+        "is_disabled=false, is_expanded=true",
+        "is_disabled=true, is_expanded=false",
+        "is_disabled=true, is_expanded=true",
+      ]
+    );
+    assert!(permutations[1].get("is_expanded"));
+    assert!(!permutations[1].get("is_disabled"));
+  }
+
+  #[test]
+  fn joins_snapshots_with_headings() {
+    let snapshot = snapshot_permutations(&["is_selected"], |permutation| {
+      format!("<div aria-selected=\"{}\">", permutation.get("is_selected"))
+    });
+
+    assert_eq!(
+      snapshot,
+      "## is_selected=false\n<div aria-selected=\"false\">\n\n## is_selected=true\n<div \
+       aria-selected=\"true\">"
+    );
+  }
+}
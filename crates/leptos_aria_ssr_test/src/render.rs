@@ -0,0 +1,28 @@
+use leptos::ssr::render_to_string;
+use leptos::IntoView;
+use leptos::Scope;
+
+use crate::extract_aria_attributes;
+
+/// Render a view on the server and return the accessibility relevant
+/// attributes of the output. See [`extract_aria_attributes`] for the format.
+///
+/// ```ignore
+/// let snapshot = render_aria_snapshot(|cx| {
+///   let state = use_disclosure_state(cx, Default::default());
+///   let result = use_disclosure(cx, &state, Default::default());
+///
+///   view! { cx, <button aria-expanded=result.trigger.aria_expanded.get().to_string()>"Toggle"</button> }
+/// });
+/// ```
+///
+/// Boolean attributes are rendered by leptos as present or absent. Convert
+/// the value with `.to_string()` for `aria-*` attributes where `"false"` is
+/// meaningful.
+pub fn render_aria_snapshot<F, N>(view: F) -> String
+where
+  F: FnOnce(Scope) -> N + 'static,
+  N: IntoView,
+{
+  extract_aria_attributes(&render_to_string(view))
+}
@@ -0,0 +1,73 @@
+use leptos::*;
+use leptos_aria_disclosure::use_disclosure;
+use leptos_aria_disclosure::use_disclosure_state;
+use leptos_aria_disclosure::UseDisclosureProps;
+use leptos_aria_disclosure::UseDisclosureStateProps;
+use leptos_aria_ssr_test::render_aria_snapshot;
+use leptos_aria_ssr_test::snapshot_permutations;
+
+#[test]
+fn disclosure_attributes() {
+  let snapshot = snapshot_permutations(&["is_disabled", "is_expanded"], |permutation| {
+    let is_disabled = permutation.get("is_disabled");
+    let is_expanded = permutation.get("is_expanded");
+
+    render_aria_snapshot(move |cx| {
+      let state = use_disclosure_state(
+        cx,
+        UseDisclosureStateProps::builder()
+          .default_expanded(is_expanded)
+          .build(),
+      );
+      let result = use_disclosure(
+        cx,
+        &state,
+        UseDisclosureProps::builder()
+          .is_disabled(is_disabled)
+          .build(),
+      )
+      .get();
+      let trigger = result.trigger;
+      let panel = result.panel;
+
+      view! { cx,
+        <div>
+          <button
+            id=trigger.id
+            aria-expanded=trigger.aria_expanded.get().to_string()
+            aria-controls=trigger.aria_controls
+            disabled=trigger.disabled.get()
+          >
+            "Toggle"
+          </button>
+          <div
+            id=panel.id
+            role=panel.role
+            aria-labelledby=panel.aria_labelledby
+            hidden=panel.hidden.get()
+          >
+            "Content"
+          </div>
+        </div>
+      }
+    })
+  });
+
+  insta::assert_snapshot!(snapshot, @r###"
+  ## is_disabled=false, is_expanded=false
+  <button aria-controls="id-1" aria-expanded="false" id="id-1-trigger">
+  <div aria-labelledby="id-1-trigger" hidden id="id-1" role="group">
+
+  ## is_disabled=false, is_expanded=true
+  <button aria-controls="id-1" aria-expanded="true" id="id-1-trigger">
+  <div aria-labelledby="id-1-trigger" id="id-1" role="group">
+
+  ## is_disabled=true, is_expanded=false
+  <button aria-controls="id-1" aria-expanded="false" disabled id="id-1-trigger">
+  <div aria-labelledby="id-1-trigger" hidden id="id-1" role="group">
+
+  ## is_disabled=true, is_expanded=true
+  <button aria-controls="id-1" aria-expanded="true" disabled id="id-1-trigger">
+  <div aria-labelledby="id-1-trigger" id="id-1" role="group">
+  "###);
+}
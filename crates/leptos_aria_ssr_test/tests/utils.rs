@@ -0,0 +1,113 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use leptos::*;
+use leptos_aria_ssr_test::render_aria_snapshot;
+use leptos_aria_utils::announce;
+use leptos_aria_utils::generate_id;
+use leptos_aria_utils::is_apple_device;
+use leptos_aria_utils::is_server;
+use leptos_aria_utils::on_browser;
+use leptos_aria_utils::run_after_transition;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::use_pointer_capabilities;
+use leptos_aria_utils::use_provider;
+use leptos_aria_utils::use_slot_id;
+use leptos_aria_utils::PointerCapabilities;
+use leptos_aria_utils::Politeness;
+
+#[test]
+fn utilities_are_inert_on_the_server() {
+  assert!(is_server());
+
+  let snapshot = render_aria_snapshot(|cx| {
+    use_provider(cx);
+    run_after_transition(cx, || panic!("transitions don't run on the server"));
+    on_browser(cx, || panic!("browser callbacks don't run on the server"));
+    announce("Loaded", Politeness::Polite);
+
+    assert_eq!(
+      use_pointer_capabilities(cx).get_untracked(),
+      PointerCapabilities::default()
+    );
+
+    view! { cx, <div aria-label=is_apple_device().to_string()>"Content"</div> }
+  });
+
+  insta::assert_snapshot!(snapshot, @r###"<div aria-label="false">"###);
+}
+
+#[test]
+fn ids_are_counted_for_each_render() {
+  let render = || {
+    leptos::ssr::render_to_string(|cx| {
+      use_provider(cx);
+      let label_id = use_id(cx);
+      let description_id = use_slot_id(cx);
+
+      view! { cx,
+        <div aria-labelledby=label_id aria-describedby=description_id.rendered_id.get()>"Content"</div>
+      }
+    })
+  };
+
+  let first = render();
+
+  assert!(first.contains(r#"aria-labelledby="leptos-aria-0""#));
+  assert!(first.contains(r#"aria-describedby="leptos-aria-1""#));
+  assert_eq!(first, render());
+}
+
+#[test]
+fn ids_match_between_the_server_and_the_client() {
+  let server = leptos::ssr::render_to_string(|cx| {
+    use_provider(cx);
+    let label_id = use_id(cx);
+    let input_id = use_id(cx);
+
+    view! { cx,
+      <div>
+        <label id=label_id.clone() for=input_id.clone()>"Name"</label>
+        <input id=input_id aria-labelledby=label_id/>
+      </div>
+    }
+  });
+
+  // `use_id` counts from the provider scope in the browser as well, so
+  // running the same hooks outside of a server render gives the ids the
+  // client hydrates with. Ids generated in between, e.g. for a description,
+  // have their own counter and don't shift them.
+  let client = Rc::new(RefCell::new(vec![]));
+
+  {
+    let client = client.clone();
+
+    create_scope(create_runtime(), move |cx| {
+      use_provider(cx);
+      let label_id = use_id(cx);
+      let description_id = generate_id();
+      let input_id = use_id(cx);
+
+      assert!(!is_use_id(&description_id));
+      client.borrow_mut().extend([label_id, input_id]);
+    })
+    .dispose();
+  }
+
+  let client = client.borrow();
+
+  assert_eq!(client.as_slice(), ["leptos-aria-0", "leptos-aria-1"]);
+
+  for id in client.iter() {
+    assert!(
+      server.contains(&format!(r#"id="{id}""#)),
+      "{id} isn't in {server}"
+    );
+  }
+}
+
+/// Whether the id has the prefix and counter of `use_id`.
+fn is_use_id(id: &str) -> bool {
+  id.strip_prefix("leptos-aria-")
+    .map_or(false, |count| count.parse::<usize>().is_ok())
+}
@@ -0,0 +1,304 @@
+use leptos_aria_selection::Key;
+
+use crate::TableCollection;
+
+/// The element within the grid which has keyboard focus.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridFocus {
+  Row(Key),
+  Cell { row: Key, column: Key },
+  ColumnHeader(Key),
+}
+
+impl GridFocus {
+  /// The key of the focused row, if any.
+  pub fn row_key(&self) -> Option<&Key> {
+    match self {
+      GridFocus::Row(row) | GridFocus::Cell { row, .. } => Some(row),
+      GridFocus::ColumnHeader(_) => None,
+    }
+  }
+
+  /// The key of the focused column, if any.
+  pub fn column_key(&self) -> Option<&Key> {
+    match self {
+      GridFocus::Row(_) => None,
+      GridFocus::Cell { column, .. } | GridFocus::ColumnHeader(column) => Some(column),
+    }
+  }
+}
+
+/// Whether keyboard navigation focuses whole rows or individual cells.
+///
+/// * `Row` - rows are focused when moving up and down. Pressing ArrowRight
+///   moves focus into the cells of the row, and moving past the first or last
+///   cell returns focus to the row.
+/// * `Cell` - cells are always focused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum GridFocusMode {
+  #[default]
+  Row,
+  Cell,
+}
+
+/// Calculates the next focus target within a grid for each navigation key.
+pub struct GridNavigation<'a> {
+  collection: &'a TableCollection,
+  focus_mode: GridFocusMode,
+}
+
+impl<'a> GridNavigation<'a> {
+  pub fn new(collection: &'a TableCollection, focus_mode: GridFocusMode) -> Self {
+    Self {
+      collection,
+      focus_mode,
+    }
+  }
+
+  /// The focus target used when the grid receives focus with nothing focused.
+  pub fn first(&self) -> Option<GridFocus> {
+    let row = self.collection.rows.first()?;
+    self.row_focus(&row.key, None)
+  }
+
+  pub fn below(&self, focus: &GridFocus) -> Option<GridFocus> {
+    match focus {
+      GridFocus::ColumnHeader(column) => {
+        let row = self.collection.rows.first()?;
+        self.row_focus(&row.key, Some(column))
+      }
+      _ => {
+        let position = self.collection.row_position(focus.row_key()?)?;
+        let row = self.collection.rows.get(position + 1)?;
+        self.row_focus(&row.key, focus.column_key())
+      }
+    }
+  }
+
+  pub fn above(&self, focus: &GridFocus) -> Option<GridFocus> {
+    let position = self.collection.row_position(focus.row_key()?)?;
+
+    match position.checked_sub(1) {
+      Some(position) => {
+        let row = self.collection.rows.get(position)?;
+        self.row_focus(&row.key, focus.column_key())
+      }
+      // Move from the first row into the column headers.
+      None => {
+        let column = focus.column_key().cloned().or_else(|| {
+          self
+            .collection
+            .columns
+            .first()
+            .map(|column| column.key.clone())
+        })?;
+        Some(GridFocus::ColumnHeader(column))
+      }
+    }
+  }
+
+  pub fn right_of(&self, focus: &GridFocus) -> Option<GridFocus> {
+    match focus {
+      GridFocus::Row(row) => {
+        let column = self.collection.columns.first()?;
+        Some(self.cell(row, &column.key))
+      }
+      GridFocus::Cell { row, column } => {
+        let position = self.collection.column_position(column)?;
+
+        match self.collection.columns.get(position + 1) {
+          Some(next) => Some(self.cell(row, &next.key)),
+          None if self.focus_mode == GridFocusMode::Row => Some(GridFocus::Row(row.clone())),
+          None => None,
+        }
+      }
+      GridFocus::ColumnHeader(column) => {
+        let position = self.collection.column_position(column)?;
+        let next = self.collection.columns.get(position + 1)?;
+        Some(GridFocus::ColumnHeader(next.key.clone()))
+      }
+    }
+  }
+
+  pub fn left_of(&self, focus: &GridFocus) -> Option<GridFocus> {
+    match focus {
+      GridFocus::Row(row) => {
+        let column = self.collection.columns.last()?;
+        Some(self.cell(row, &column.key))
+      }
+      GridFocus::Cell { row, column } => {
+        let position = self.collection.column_position(column)?;
+
+        match position.checked_sub(1) {
+          Some(position) => Some(self.cell(row, &self.collection.columns.get(position)?.key)),
+          None if self.focus_mode == GridFocusMode::Row => Some(GridFocus::Row(row.clone())),
+          None => None,
+        }
+      }
+      GridFocus::ColumnHeader(column) => {
+        let position = self.collection.column_position(column)?.checked_sub(1)?;
+        let previous = self.collection.columns.get(position)?;
+        Some(GridFocus::ColumnHeader(previous.key.clone()))
+      }
+    }
+  }
+
+  /// The target of the `Home` key. With `ctrl_key` the focus moves to the
+  /// first row.
+  pub fn first_in_row(&self, focus: &GridFocus, ctrl_key: bool) -> Option<GridFocus> {
+    if ctrl_key || matches!(focus, GridFocus::Row(_)) {
+      let row = self.collection.rows.first()?;
+      return self.row_focus(&row.key, focus.column_key());
+    }
+
+    let column = self.collection.columns.first()?;
+
+    match focus {
+      GridFocus::ColumnHeader(_) => Some(GridFocus::ColumnHeader(column.key.clone())),
+      _ => Some(self.cell(focus.row_key()?, &column.key)),
+    }
+  }
+
+  /// The target of the `End` key. With `ctrl_key` the focus moves to the last
+  /// row.
+  pub fn last_in_row(&self, focus: &GridFocus, ctrl_key: bool) -> Option<GridFocus> {
+    if ctrl_key || matches!(focus, GridFocus::Row(_)) {
+      let row = self.collection.rows.last()?;
+      return self.row_focus(&row.key, focus.column_key());
+    }
+
+    let column = self.collection.columns.last()?;
+
+    match focus {
+      GridFocus::ColumnHeader(_) => Some(GridFocus::ColumnHeader(column.key.clone())),
+      _ => Some(self.cell(focus.row_key()?, &column.key)),
+    }
+  }
+
+  /// Move down by `page_size` rows, stopping at the last row.
+  pub fn page_below(&self, focus: &GridFocus, page_size: usize) -> Option<GridFocus> {
+    let position = match focus.row_key() {
+      Some(row) => self.collection.row_position(row)?,
+      None => 0,
+    };
+    let last = self.collection.rows.len().checked_sub(1)?;
+    let row = &self.collection.rows[(position + page_size.max(1)).min(last)];
+    self.row_focus(&row.key, focus.column_key())
+  }
+
+  /// Move up by `page_size` rows, stopping at the first row.
+  pub fn page_above(&self, focus: &GridFocus, page_size: usize) -> Option<GridFocus> {
+    let position = self.collection.row_position(focus.row_key()?)?;
+    let row = self
+      .collection
+      .rows
+      .get(position.saturating_sub(page_size.max(1)))?;
+    self.row_focus(&row.key, focus.column_key())
+  }
+
+  fn row_focus(&self, row: &Key, column: Option<&Key>) -> Option<GridFocus> {
+    match (column, self.focus_mode) {
+      (Some(column), _) => Some(self.cell(row, column)),
+      (None, GridFocusMode::Row) => Some(GridFocus::Row(row.clone())),
+      (None, GridFocusMode::Cell) => {
+        let column = self.collection.columns.first()?;
+        Some(self.cell(row, &column.key))
+      }
+    }
+  }
+
+  fn cell(&self, row: &Key, column: &Key) -> GridFocus {
+    GridFocus::Cell {
+      row: row.clone(),
+      column: column.clone(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Column;
+  use crate::Row;
+
+  fn collection() -> TableCollection {
+    TableCollection::new(
+      vec![Column::new("name", "Name"), Column::new("type", "Type")],
+      vec![
+        Row::new("1", "Games"),
+        Row::new("2", "Program Files"),
+        Row::new("3", "bootmgr"),
+      ],
+    )
+  }
+
+  fn cell(row: &str, column: &str) -> GridFocus {
+    GridFocus::Cell {
+      row: row.into(),
+      column: column.into(),
+    }
+  }
+
+  #[test]
+  fn row_mode_moves_between_rows_and_cells() {
+    let collection = collection();
+    let navigation = GridNavigation::new(&collection, GridFocusMode::Row);
+    let first = navigation.first().unwrap();
+
+    assert_eq!(first, GridFocus::Row("1".into()));
+    assert_eq!(navigation.below(&first), Some(GridFocus::Row("2".into())));
+    assert_eq!(navigation.right_of(&first), Some(cell("1", "name")));
+    assert_eq!(navigation.left_of(&cell("1", "name")), Some(first.clone()));
+    assert_eq!(navigation.right_of(&cell("1", "type")), Some(first));
+  }
+
+  #[test]
+  fn cell_mode_stops_at_edges() {
+    let collection = collection();
+    let navigation = GridNavigation::new(&collection, GridFocusMode::Cell);
+
+    assert_eq!(navigation.first(), Some(cell("1", "name")));
+    assert_eq!(navigation.left_of(&cell("1", "name")), None);
+    assert_eq!(
+      navigation.below(&cell("2", "type")),
+      Some(cell("3", "type"))
+    );
+    assert_eq!(navigation.below(&cell("3", "type")), None);
+  }
+
+  #[test]
+  fn moves_into_column_headers() {
+    let collection = collection();
+    let navigation = GridNavigation::new(&collection, GridFocusMode::Cell);
+    let header = GridFocus::ColumnHeader("type".into());
+
+    assert_eq!(navigation.above(&cell("1", "type")), Some(header.clone()));
+    assert_eq!(navigation.below(&header), Some(cell("1", "type")));
+    assert_eq!(navigation.above(&header), None);
+  }
+
+  #[test]
+  fn pages_are_clamped() {
+    let collection = collection();
+    let navigation = GridNavigation::new(&collection, GridFocusMode::Row);
+
+    assert_eq!(
+      navigation.page_below(&GridFocus::Row("1".into()), 10),
+      Some(GridFocus::Row("3".into()))
+    );
+    assert_eq!(
+      navigation.page_above(&GridFocus::Row("3".into()), 10),
+      Some(GridFocus::Row("1".into()))
+    );
+  }
+
+  #[test]
+  fn aria_indexes_account_for_virtualization() {
+    let collection = collection().virtualized(20, 100);
+
+    assert_eq!(collection.aria_row_index(&"1".into()), Some(22));
+    assert_eq!(collection.aria_row_count(), 101);
+    assert_eq!(collection.aria_col_index(&"type".into()), Some(2));
+    assert!(collection.is_virtualized());
+  }
+}
@@ -0,0 +1,17 @@
+pub use grid_navigation::*;
+pub use table_collection::*;
+pub use table_state::*;
+pub use use_cell::*;
+pub use use_column_header::*;
+pub use use_row::*;
+pub use use_table::*;
+pub use use_table_selection_checkbox::*;
+
+mod grid_navigation;
+mod table_collection;
+mod table_state;
+mod use_cell;
+mod use_column_header;
+mod use_row;
+mod use_table;
+mod use_table_selection_checkbox;
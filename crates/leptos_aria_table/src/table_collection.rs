@@ -0,0 +1,173 @@
+use leptos_aria_selection::Key;
+
+/// The reserved key used for the selection checkbox column.
+pub const SELECTION_COLUMN_KEY: &str = "__leptos_aria_selection__";
+
+/// A column within a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Column {
+  pub key: Key,
+
+  /// The text content of the column header. Used for typeahead and labelling.
+  pub text_value: String,
+
+  /// Whether the column can be sorted by pressing the column header.
+  pub allows_sorting: bool,
+
+  /// Whether the cells of this column label their row, in which case they are
+  /// rendered with `role="rowheader"`.
+  pub is_row_header: bool,
+
+  /// Whether this is the selection checkbox column.
+  pub is_selection_cell: bool,
+}
+
+impl Column {
+  pub fn new(key: impl Into<Key>, text_value: impl Into<String>) -> Self {
+    Self {
+      key: key.into(),
+      text_value: text_value.into(),
+      allows_sorting: false,
+      is_row_header: false,
+      is_selection_cell: false,
+    }
+  }
+
+  /// A column containing a selection checkbox for each row. Use
+  /// `use_table_selection_checkbox` to render the checkbox within the cells
+  /// and `use_table_select_all_checkbox` for the column header.
+  pub fn selection_checkbox() -> Self {
+    Self {
+      is_selection_cell: true,
+      ..Self::new(SELECTION_COLUMN_KEY, "")
+    }
+  }
+
+  pub fn allows_sorting(mut self, allows_sorting: bool) -> Self {
+    self.allows_sorting = allows_sorting;
+    self
+  }
+
+  pub fn is_row_header(mut self, is_row_header: bool) -> Self {
+    self.is_row_header = is_row_header;
+    self
+  }
+}
+
+/// A row within a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row {
+  pub key: Key,
+
+  /// The text content of the row. Used for typeahead.
+  pub text_value: String,
+}
+
+impl Row {
+  pub fn new(key: impl Into<Key>, text_value: impl Into<String>) -> Self {
+    Self {
+      key: key.into(),
+      text_value: text_value.into(),
+    }
+  }
+}
+
+/// The rows and columns which make up a table.
+///
+/// When the table is virtualized only the rendered rows need to be provided.
+/// Set `row_offset` to the index of the first rendered row and
+/// `total_row_count` to the number of rows in the full data set so that
+/// `aria-rowindex` and `aria-rowcount` describe the true positions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableCollection {
+  pub columns: Vec<Column>,
+  pub rows: Vec<Row>,
+
+  /// The number of header rows. Defaults to `1`.
+  pub header_row_count: usize,
+
+  /// The index of the first provided row within the full data set.
+  pub row_offset: usize,
+
+  /// The total number of body rows when not all rows are provided.
+  pub total_row_count: Option<usize>,
+}
+
+impl TableCollection {
+  pub fn new(columns: Vec<Column>, rows: Vec<Row>) -> Self {
+    Self {
+      columns,
+      rows,
+      header_row_count: 1,
+      row_offset: 0,
+      total_row_count: None,
+    }
+  }
+
+  /// Describe a virtualized window of rows.
+  pub fn virtualized(mut self, row_offset: usize, total_row_count: usize) -> Self {
+    self.row_offset = row_offset;
+    self.total_row_count = Some(total_row_count);
+    self
+  }
+
+  pub fn row(&self, key: &Key) -> Option<&Row> {
+    self.rows.iter().find(|row| &row.key == key)
+  }
+
+  pub fn column(&self, key: &Key) -> Option<&Column> {
+    self.columns.iter().find(|column| &column.key == key)
+  }
+
+  pub fn row_keys(&self) -> Vec<Key> {
+    self.rows.iter().map(|row| row.key.clone()).collect()
+  }
+
+  pub fn column_keys(&self) -> Vec<Key> {
+    self
+      .columns
+      .iter()
+      .map(|column| column.key.clone())
+      .collect()
+  }
+
+  /// The position of the row within the provided rows.
+  pub fn row_position(&self, key: &Key) -> Option<usize> {
+    self.rows.iter().position(|row| &row.key == key)
+  }
+
+  /// The position of the column within the columns.
+  pub fn column_position(&self, key: &Key) -> Option<usize> {
+    self.columns.iter().position(|column| &column.key == key)
+  }
+
+  /// The 1-based `aria-rowindex` of the row, taking the header rows and the
+  /// virtualized offset into account.
+  pub fn aria_row_index(&self, key: &Key) -> Option<usize> {
+    self
+      .row_position(key)
+      .map(|position| self.header_row_count + self.row_offset + position + 1)
+  }
+
+  /// The 1-based `aria-colindex` of the column.
+  pub fn aria_col_index(&self, key: &Key) -> Option<usize> {
+    self.column_position(key).map(|position| position + 1)
+  }
+
+  /// The `aria-rowcount` of the table including the header rows.
+  pub fn aria_row_count(&self) -> usize {
+    self.header_row_count + self.total_row_count.unwrap_or(self.rows.len())
+  }
+
+  /// The `aria-colcount` of the table.
+  pub fn aria_col_count(&self) -> usize {
+    self.columns.len()
+  }
+
+  /// Whether not all rows are provided.
+  pub fn is_virtualized(&self) -> bool {
+    self
+      .total_row_count
+      .map_or(false, |total| total != self.rows.len())
+  }
+}
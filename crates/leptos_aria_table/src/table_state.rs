@@ -0,0 +1,250 @@
+use std::rc::Rc;
+
+use leptos::create_memo;
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Memo;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_selection::use_collection;
+use leptos_aria_selection::use_multiple_selection_state;
+use leptos_aria_selection::Collection;
+use leptos_aria_selection::EmptyState;
+use leptos_aria_selection::Key;
+use leptos_aria_selection::SelectionManager;
+use leptos_aria_selection::SortDescriptor;
+use leptos_aria_selection::SortDirection;
+use leptos_aria_selection::UseCollectionProps;
+use leptos_aria_selection::UseMultipleSelectionStateProps;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::ControlledState;
+use leptos_aria_virtualizer::Virtualizer;
+
+use crate::GridFocus;
+use crate::GridFocusMode;
+use crate::GridNavigation;
+use crate::Row;
+use crate::TableCollection;
+
+#[derive(TypedBuilder)]
+pub struct UseTableStateProps {
+  /// The rows and columns of the table.
+  #[builder(setter(into))]
+  pub collection: MaybeSignal<TableCollection>,
+
+  /// Whether keyboard navigation focuses rows or cells.
+  #[builder(default, setter(strip_option, into))]
+  pub focus_mode: Option<MaybeSignal<GridFocusMode>>,
+
+  /// The current sort descriptor of the table (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub sort_descriptor: Option<MaybeSignal<Option<SortDescriptor>>>,
+
+  /// Handler that is called when a sortable column header is pressed.
+  #[builder(default, setter(strip_option))]
+  pub on_sort_change: Option<Box<dyn Fn(Option<SortDescriptor>)>>,
+
+  /// The selection props of the table.
+  #[builder(default)]
+  pub selection: UseMultipleSelectionStateProps,
+
+  /// The virtualizer which renders a window of the rows. The `collection`
+  /// should contain every row when this is provided.
+  #[builder(default, setter(strip_option))]
+  pub virtualizer: Option<Virtualizer>,
+
+  /// Where focus moves when the last row is deleted while the table has
+  /// focus.
+  #[builder(default, setter(strip_option))]
+  pub empty_state: Option<EmptyState>,
+}
+
+/// The state of a table shared between all the table hooks.
+#[derive(Clone)]
+pub struct TableState {
+  /// The id of the table, used as the prefix for the ids of its cells.
+  pub id: String,
+  pub collection: Signal<TableCollection>,
+  /// The body rows diffed by key. Prefer this over `collection` in row and
+  /// cell hooks so that changing one row doesn't update every other row.
+  pub rows: Collection<Row>,
+  pub focus_mode: Signal<GridFocusMode>,
+  pub selection_manager: SelectionManager,
+  pub virtualizer: Option<Virtualizer>,
+  sort_descriptor: ControlledState<Option<SortDescriptor>>,
+  focus: RwSignal<Option<GridFocus>>,
+  is_virtualized: Memo<bool>,
+  row_index_offset: Memo<usize>,
+  row_header_columns: Memo<Vec<Key>>,
+}
+
+pub fn use_table_state(cx: Scope, props: UseTableStateProps) -> TableState {
+  let original_collection = props.collection;
+  // Memoized so that reading the collection, which happens for every row and
+  // cell, doesn't clone it.
+  let collection: Signal<TableCollection> =
+    create_memo(cx, move |_| original_collection.get()).into();
+  let original_focus_mode = props.focus_mode.unwrap_or(GridFocusMode::Row.into());
+  let focus_mode = (move || original_focus_mode.get()).derive_signal(cx);
+  let sort_descriptor = use_controlled_state(cx, props.sort_descriptor, None, props.on_sort_change);
+  let selection_manager = use_multiple_selection_state(cx, props.selection);
+  let body_rows = create_memo(cx, move |_| {
+    collection.with(|collection| collection.rows.clone())
+  });
+  let rows = use_collection(
+    cx,
+    UseCollectionProps {
+      items: Signal::from(body_rows).into(),
+      key: Box::new(|row: &Row| row.key.clone()),
+      selection_manager: Some(selection_manager.clone()),
+      empty_state: props.empty_state,
+    },
+  );
+
+  // Derived values which rarely change are memoized so row and cell hooks are
+  // only notified when they actually change.
+  let has_virtualizer = props.virtualizer.is_some();
+  let is_virtualized = create_memo(cx, move |_| {
+    has_virtualizer || collection.with(TableCollection::is_virtualized)
+  });
+  let row_index_offset = create_memo(cx, move |_| {
+    collection.with(|collection| collection.header_row_count + collection.row_offset)
+  });
+  let row_header_columns = create_memo(cx, move |_| {
+    collection.with(|collection| {
+      collection
+        .columns
+        .iter()
+        .filter(|column| column.is_row_header)
+        .map(|column| column.key.clone())
+        .collect()
+    })
+  });
+
+  TableState {
+    id: use_id(cx),
+    collection,
+    rows,
+    focus_mode,
+    selection_manager,
+    virtualizer: props.virtualizer,
+    sort_descriptor,
+    focus: create_rw_signal(cx, None),
+    is_virtualized,
+    row_index_offset,
+    row_header_columns,
+  }
+}
+
+impl TableState {
+  /// The element of the grid which should have keyboard focus.
+  pub fn focus(&self) -> Option<GridFocus> {
+    self.focus.get()
+  }
+
+  pub fn focus_untracked(&self) -> Option<GridFocus> {
+    self.focus.get_untracked()
+  }
+
+  /// Update the focused element and keep the focused key of the selection
+  /// manager in sync with the focused row.
+  pub fn set_focus(&self, focus: Option<GridFocus>) {
+    if self.focus.get_untracked() == focus {
+      return;
+    }
+
+    self
+      .selection_manager
+      .set_focused_key(focus.as_ref().and_then(|focus| focus.row_key()).cloned());
+    self.focus.set(focus);
+  }
+
+  /// The id of the cell in the provided row and column.
+  pub fn cell_id(&self, row: &Key, column: &Key) -> String {
+    let sanitize = |key: &Key| key.as_str().replace(char::is_whitespace, "_");
+    format!("{}-{}-{}", self.id, sanitize(row), sanitize(column))
+  }
+
+  /// The space separated ids of the row header cells of the row, which label
+  /// the row and anything else which acts on it such as its selection
+  /// checkbox. `None` when no column is marked as a row header.
+  pub fn row_header_ids(&self, row: &Key) -> Option<String> {
+    let ids = self.row_header_columns.with(|columns| {
+      columns
+        .iter()
+        .map(|column| self.cell_id(row, column))
+        .collect::<Vec<_>>()
+    });
+
+    (!ids.is_empty()).then(|| ids.join(" "))
+  }
+
+  /// Whether only some of the rows are rendered, in which case the row and
+  /// column counts and indexes are added to the table elements.
+  pub fn is_virtualized(&self) -> bool {
+    self.is_virtualized.get()
+  }
+
+  /// The 1-based `aria-rowindex` of the row. Only notified when rows are
+  /// inserted, removed or moved.
+  pub fn aria_row_index(&self, row: &Key) -> Option<usize> {
+    let offset = self.row_index_offset.get();
+    let position = self.rows.keys().iter().position(|key| key == row)?;
+
+    Some(offset + position + 1)
+  }
+
+  /// Whether the provided target is the tab stop of the grid. This is the
+  /// focused element or the first row when nothing has been focused yet.
+  pub fn is_tab_stop(&self, target: &GridFocus) -> bool {
+    let focus = self.focus.get().or_else(|| {
+      let focus_mode = self.focus_mode.get();
+      self
+        .collection
+        .with(|collection| GridNavigation::new(collection, focus_mode).first())
+    });
+
+    focus.as_ref() == Some(target)
+  }
+
+  /// The current sort descriptor.
+  pub fn sort_descriptor(&self) -> Option<SortDescriptor> {
+    self.sort_descriptor.get()
+  }
+
+  /// Sort by the provided column. When the table is already sorted by this
+  /// column the direction is reversed.
+  pub fn sort(&self, column: &Key) {
+    let allows_sorting = self
+      .collection
+      .get_untracked()
+      .column(column)
+      .map_or(false, |column| column.allows_sorting);
+
+    if !allows_sorting {
+      return;
+    }
+
+    let direction = match self.sort_descriptor.get_untracked() {
+      Some(descriptor)
+        if &descriptor.column == column && descriptor.direction == SortDirection::Ascending =>
+      {
+        SortDirection::Descending
+      }
+      _ => SortDirection::Ascending,
+    };
+
+    self.sort_descriptor.set(Some(SortDescriptor {
+      column: column.clone(),
+      direction,
+    }));
+  }
+}
+
+/// Shared callback type for the table hooks.
+pub(crate) type TableCallback<E> = Rc<Box<dyn Fn(E)>>;
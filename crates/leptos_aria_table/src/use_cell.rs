@@ -0,0 +1,124 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_selection::Key;
+
+use crate::GridFocus;
+use crate::TableState;
+
+#[derive(TypedBuilder)]
+pub struct UseCellProps {
+  /// The key of the row containing the cell.
+  #[builder(setter(into))]
+  pub row_key: Key,
+
+  /// The key of the column the cell belongs to.
+  #[builder(setter(into))]
+  pub column_key: Key,
+}
+
+#[derive(Clone)]
+pub struct CellResult {
+  /// Referenced by the row and its selection checkbox when the cell is a row
+  /// header.
+  pub id: String,
+  /// Either `gridcell` or `rowheader` when the column labels the row.
+  pub role: Signal<&'static str>,
+  /// The value for the `data-column-key` attribute which is used to find the
+  /// cell when navigating with the keyboard.
+  pub data_column_key: String,
+  /// Only set when the table is virtualized.
+  pub aria_rowindex: Signal<Option<usize>>,
+  /// Only set when the table is virtualized.
+  pub aria_colindex: Signal<Option<usize>>,
+  pub tab_index: Signal<i32>,
+}
+
+/// Provides the accessibility implementation for a cell in a table.
+///
+/// Cells in a column marked with [`Column::is_row_header`] get the
+/// `rowheader` role and label their row. When the table is virtualized the
+/// cell includes its position in the full table so that screen readers
+/// announce the true row and column.
+///
+/// [`Column::is_row_header`]: crate::Column::is_row_header
+pub fn use_cell(cx: Scope, state: &TableState, props: UseCellProps) -> ReadSignal<CellResult> {
+  let collection = state.collection;
+  let row_key = props.row_key;
+  let column_key = props.column_key;
+  let id = state.cell_id(&row_key, &column_key);
+
+  let role = {
+    let column_key = column_key.clone();
+
+    (move || {
+      let is_row_header = collection.with(|collection| {
+        collection
+          .column(&column_key)
+          .map_or(false, |column| column.is_row_header)
+      });
+
+      if is_row_header {
+        "rowheader"
+      } else {
+        "gridcell"
+      }
+    })
+    .derive_signal(cx)
+  };
+
+  let aria_rowindex = {
+    let state = state.clone();
+    let row_key = row_key.clone();
+
+    (move || {
+      if !state.is_virtualized() {
+        return None;
+      }
+
+      state.aria_row_index(&row_key)
+    })
+    .derive_signal(cx)
+  };
+
+  let aria_colindex = {
+    let state = state.clone();
+    let column_key = column_key.clone();
+
+    (move || {
+      if !state.is_virtualized() {
+        return None;
+      }
+
+      collection.with(|collection| collection.aria_col_index(&column_key))
+    })
+    .derive_signal(cx)
+  };
+
+  let tab_index = {
+    let state = state.clone();
+    let target = GridFocus::Cell {
+      row: row_key,
+      column: column_key.clone(),
+    };
+
+    (move || if state.is_tab_stop(&target) { 0 } else { -1 }).derive_signal(cx)
+  };
+
+  let (cell_result, _) = create_signal(
+    cx,
+    CellResult {
+      id,
+      role,
+      data_column_key: column_key.to_string(),
+      aria_rowindex,
+      aria_colindex,
+      tab_index,
+    },
+  );
+
+  cell_result
+}
@@ -0,0 +1,133 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_interactions::use_press;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_interactions::PressResult;
+use leptos_aria_interactions::UsePressProps;
+use leptos_aria_selection::Key;
+
+use crate::GridFocus;
+use crate::TableState;
+
+#[derive(TypedBuilder)]
+pub struct UseColumnHeaderProps {
+  /// The key of the column.
+  #[builder(setter(into))]
+  pub column_key: Key,
+}
+
+#[derive(Clone)]
+pub struct ColumnHeaderResult {
+  pub role: &'static str,
+  /// The value for the `data-column-key` attribute which is used to find the
+  /// column header when navigating with the keyboard.
+  pub data_column_key: String,
+  /// `ascending`, `descending` or `none` for sortable columns.
+  pub aria_sort: Signal<Option<&'static str>>,
+  /// Only set when the table is virtualized.
+  pub aria_colindex: Signal<Option<usize>>,
+  pub tab_index: Signal<i32>,
+  /// The press props which sort the column. These should be added to the
+  /// column header element.
+  pub press: ReadSignal<PressResult>,
+}
+
+/// Provides the behavior and accessibility implementation for a column header
+/// in a table. Pressing a sortable column header sorts the table by the
+/// column.
+pub fn use_column_header(
+  cx: Scope,
+  state: &TableState,
+  props: UseColumnHeaderProps,
+) -> ReadSignal<ColumnHeaderResult> {
+  let collection = state.collection;
+  let column_key = props.column_key;
+
+  let allows_sorting = {
+    let column_key = column_key.clone();
+
+    (move || {
+      collection.with(|collection| {
+        collection
+          .column(&column_key)
+          .map_or(false, |column| column.allows_sorting)
+      })
+    })
+    .derive_signal(cx)
+  };
+
+  let aria_sort = {
+    let state = state.clone();
+    let column_key = column_key.clone();
+
+    (move || {
+      if !allows_sorting.get() {
+        return None;
+      }
+
+      match state.sort_descriptor() {
+        Some(descriptor) if descriptor.column == column_key => {
+          Some(descriptor.direction.as_aria_sort())
+        }
+        _ => Some("none"),
+      }
+    })
+    .derive_signal(cx)
+  };
+
+  let aria_colindex = {
+    let state = state.clone();
+    let column_key = column_key.clone();
+
+    (move || {
+      if !state.is_virtualized() {
+        return None;
+      }
+
+      collection.with(|collection| collection.aria_col_index(&column_key))
+    })
+    .derive_signal(cx)
+  };
+
+  let tab_index = {
+    let state = state.clone();
+    let target = GridFocus::ColumnHeader(column_key.clone());
+
+    (move || if state.is_tab_stop(&target) { 0 } else { -1 }).derive_signal(cx)
+  };
+
+  let on_press = {
+    let state = state.clone();
+    let column_key = column_key.clone();
+
+    move |_: &PressEvent| {
+      state.sort(&column_key);
+    }
+  };
+
+  let press = use_press(
+    cx,
+    UsePressProps::builder()
+      .on_press(Box::new(on_press))
+      .is_disabled((move || !allows_sorting.get()).derive_signal(cx))
+      .build(),
+  );
+
+  let (column_header_result, _) = create_signal(
+    cx,
+    ColumnHeaderResult {
+      role: "columnheader",
+      data_column_key: column_key.to_string(),
+      aria_sort,
+      aria_colindex,
+      tab_index,
+      press,
+    },
+  );
+
+  column_header_result
+}
@@ -0,0 +1,127 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_interactions::use_press;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_interactions::PressResult;
+use leptos_aria_interactions::UsePressProps;
+use leptos_aria_selection::Key;
+use leptos_aria_selection::SelectionMode;
+
+use crate::GridFocus;
+use crate::TableState;
+
+#[derive(TypedBuilder)]
+pub struct UseRowProps {
+  /// The key of the row.
+  #[builder(setter(into))]
+  pub key: Key,
+}
+
+#[derive(Clone)]
+pub struct RowResult {
+  pub role: &'static str,
+  /// The value for the `data-key` attribute which is used to find the row
+  /// when navigating with the keyboard.
+  pub data_key: String,
+  pub aria_selected: Signal<Option<bool>>,
+  pub aria_disabled: Signal<Option<bool>>,
+  /// Only set when the table is virtualized.
+  pub aria_rowindex: Signal<Option<usize>>,
+  /// The ids of the row header cells of the row.
+  pub aria_labelledby: Signal<Option<String>>,
+  pub tab_index: Signal<i32>,
+  /// The press props which select the row. These should be added to the row
+  /// element.
+  pub press: ReadSignal<PressResult>,
+}
+
+/// Provides the behavior and accessibility implementation for a row in a
+/// table.
+pub fn use_row(cx: Scope, state: &TableState, props: UseRowProps) -> ReadSignal<RowResult> {
+  let key = props.key;
+  let selection_manager = state.selection_manager.clone();
+
+  let aria_selected = {
+    let selection_manager = selection_manager.clone();
+    let key = key.clone();
+
+    (move || {
+      (selection_manager.selection_mode.get() != SelectionMode::None)
+        .then(|| selection_manager.is_selected(&key))
+    })
+    .derive_signal(cx)
+  };
+
+  let is_disabled = {
+    let selection_manager = selection_manager.clone();
+    let key = key.clone();
+    (move || selection_manager.is_disabled(&key)).derive_signal(cx)
+  };
+
+  let aria_disabled = (move || is_disabled.get().then_some(true)).derive_signal(cx);
+
+  let aria_rowindex = {
+    let state = state.clone();
+    let key = key.clone();
+
+    (move || {
+      if !state.is_virtualized() {
+        return None;
+      }
+
+      state.aria_row_index(&key)
+    })
+    .derive_signal(cx)
+  };
+
+  let aria_labelledby = {
+    let state = state.clone();
+    let key = key.clone();
+    (move || state.row_header_ids(&key)).derive_signal(cx)
+  };
+
+  let tab_index = {
+    let state = state.clone();
+    let target = GridFocus::Row(key.clone());
+
+    (move || if state.is_tab_stop(&target) { 0 } else { -1 }).derive_signal(cx)
+  };
+
+  let on_press = {
+    let state = state.clone();
+    let key = key.clone();
+
+    move |event: &PressEvent| {
+      let ordered_keys = state.rows.keys_untracked();
+      state.selection_manager.select(&key, event, &ordered_keys);
+    }
+  };
+
+  let press = use_press(
+    cx,
+    UsePressProps::builder()
+      .on_press(Box::new(on_press))
+      .is_disabled(is_disabled)
+      .build(),
+  );
+
+  let (row_result, _) = create_signal(
+    cx,
+    RowResult {
+      role: "row",
+      data_key: key.to_string(),
+      aria_selected,
+      aria_disabled,
+      aria_rowindex,
+      aria_labelledby,
+      tab_index,
+      press,
+    },
+  );
+
+  row_result
+}
@@ -0,0 +1,418 @@
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_signal;
+use leptos::document;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::Node;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_selection::find_element_by_key;
+use leptos_aria_selection::get_closest_key;
+use leptos_aria_selection::use_type_select;
+use leptos_aria_selection::Reorder;
+use leptos_aria_selection::SelectionMode;
+use leptos_aria_selection::DATA_KEY_ATTRIBUTE;
+use leptos_aria_utils::use_custom_change_event;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::AriaCustomEvent;
+use leptos_aria_utils::FocusableElement;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_virtualizer::focus_when_mounted;
+use leptos_aria_virtualizer::restore_scroll_anchor;
+use leptos_aria_virtualizer::ScrollAnchor;
+
+use crate::table_state::TableCallback;
+use crate::GridFocus;
+use crate::GridNavigation;
+use crate::TableCollection;
+use crate::TableState;
+
+/// The attribute used to associate cells and column headers with their column.
+pub const DATA_COLUMN_KEY_ATTRIBUTE: &str = "data-column-key";
+
+#[derive(TypedBuilder, Default)]
+pub struct UseTableProps {
+  /// An accessible label for the table.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the table.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+
+  /// Whether the rows are loading, e.g. from
+  /// `leptos_aria_data::AsyncList::is_loading`.
+  #[builder(default, setter(strip_option, into))]
+  pub is_loading: Option<MaybeSignal<bool>>,
+
+  /// Allows the rows to be reordered with the keyboard.
+  #[builder(default, setter(strip_option))]
+  pub reorder: Option<Reorder>,
+
+  /// What to keep in view when rows are inserted, removed or moved, e.g.
+  /// after filtering. Defaults to [`ScrollAnchor::FocusedItem`].
+  #[builder(default, setter(strip_option, into))]
+  pub scroll_anchor: Option<MaybeSignal<ScrollAnchor>>,
+}
+
+#[derive(Clone)]
+pub struct TableResult {
+  /// The id of the grid element. Used to find the rows when restoring the
+  /// scroll position.
+  pub id: String,
+  pub role: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+  pub aria_multiselectable: Signal<Option<bool>>,
+  pub aria_busy: Signal<Option<bool>>,
+  /// Only set when the table is virtualized.
+  pub aria_rowcount: Signal<Option<usize>>,
+  /// Only set when the table is virtualized.
+  pub aria_colcount: Signal<Option<usize>>,
+  pub on_key_down: TableCallback<KeyboardEvent>,
+  pub on_focus_in: TableCallback<FocusEvent>,
+  pub on_focus_out: TableCallback<FocusEvent>,
+}
+
+/// Provides the behavior and accessibility implementation for a table
+/// following the ARIA grid pattern.
+///
+/// * Arrow keys move focus between rows and cells. See [`GridFocusMode`] for
+///   the difference between row and cell focus. <kbd>ArrowLeft</kbd> and
+///   <kbd>ArrowRight</kbd> are swapped in right to left locales.
+/// * <kbd>Home</kbd> and <kbd>End</kbd> move to the first and last cell in the
+///   row, or the first and last row when <kbd>Ctrl</kbd> is held.
+/// * <kbd>PageUp</kbd> and <kbd>PageDown</kbd> move by the number of visible
+///   rows.
+/// * Typing moves focus to the row matching the typed text.
+/// * <kbd>Ctrl</kbd> + <kbd>A</kbd> selects all rows and <kbd>Escape</kbd>
+///   clears the selection.
+///
+/// The props returned should be added to the element with `role="grid"`. The
+/// rows, cells and column headers are set up with [`use_row`](crate::use_row),
+/// [`use_cell`](crate::use_cell) and
+/// [`use_column_header`](crate::use_column_header).
+///
+/// [`GridFocusMode`]: crate::GridFocusMode
+pub fn use_table(cx: Scope, state: &TableState, props: UseTableProps) -> ReadSignal<TableResult> {
+  let type_select = use_type_select(cx);
+  let direction = use_direction(cx);
+  let collection = state.collection;
+  let selection_manager = state.selection_manager.clone();
+
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let original_is_loading = props.is_loading.unwrap_or(false.into());
+  let aria_busy = (move || original_is_loading.get().then_some(true)).derive_signal(cx);
+  let aria_multiselectable = {
+    let selection_mode = selection_manager.selection_mode;
+    (move || (selection_mode.get() == SelectionMode::Multiple).then_some(true)).derive_signal(cx)
+  };
+  let selected_keys = {
+    let selection_manager = selection_manager.clone();
+    (move || selection_manager.selected_keys()).derive_signal(cx)
+  };
+  use_custom_change_event(cx, state.id.clone(), selected_keys, |keys| {
+    let mut selected_keys = keys
+      .iter()
+      .map(|key| key.as_str().to_string())
+      .collect::<Vec<_>>();
+    selected_keys.sort();
+    AriaCustomEvent::SelectionChange { selected_keys }
+  });
+  let aria_rowcount = {
+    let state = state.clone();
+
+    (move || {
+      state
+        .is_virtualized()
+        .then(|| collection.with(TableCollection::aria_row_count))
+    })
+    .derive_signal(cx)
+  };
+  let aria_colcount = {
+    let state = state.clone();
+
+    (move || {
+      state
+        .is_virtualized()
+        .then(|| collection.with(TableCollection::aria_col_count))
+    })
+    .derive_signal(cx)
+  };
+
+  {
+    let state = state.clone();
+    let original_scroll_anchor = props
+      .scroll_anchor
+      .unwrap_or(ScrollAnchor::FocusedItem.into());
+    let scroll_anchor = (move || original_scroll_anchor.get()).derive_signal(cx);
+
+    // The keyed rows only notify when rows are inserted, removed or moved, so
+    // updating a row in place doesn't scroll.
+    create_effect(cx, move |previous: Option<()>| {
+      let keys = state.rows.keys();
+
+      // Nothing to restore on the initial render.
+      if previous.is_none() {
+        return;
+      }
+
+      let manager = &state.selection_manager;
+      let Some(key) = scroll_anchor.get_untracked().target_key(
+        &keys,
+        manager.focused_key_untracked().as_ref(),
+        &manager.selected_keys_untracked(),
+      ) else {
+        return;
+      };
+
+      let id = state.id.clone();
+      restore_scroll_anchor(cx, key, keys, state.virtualizer.clone(), move |key| {
+        let grid = document().get_element_by_id(&id)?;
+        find_element_by_key(&grid, DATA_KEY_ATTRIBUTE, key)
+      });
+    });
+  }
+
+  let on_key_down: TableCallback<KeyboardEvent> = {
+    let state = state.clone();
+    let reorder = props.reorder;
+
+    let handler = move |event: KeyboardEvent| {
+      let Some(grid) = event
+        .current_target()
+        .map(|target| target.unchecked_into::<Element>())
+      else {
+        return;
+      };
+
+      let collection = state.collection.get_untracked();
+      let navigation = GridNavigation::new(&collection, state.focus_mode.get_untracked());
+      let Some(focus) = state.focus_untracked().or_else(|| navigation.first()) else {
+        return;
+      };
+
+      if let (Some(reorder), Some(row)) = (&reorder, focus.row_key()) {
+        let items = collection
+          .rows
+          .iter()
+          .map(|row| (&row.key, row.text_value.as_str()));
+
+        if reorder.handle_key_down(&event, row, items) {
+          // The row element may be recreated when it moves.
+          let target = focus.clone();
+          focus_when_mounted(move || find_grid_element(&grid, &target));
+          return;
+        }
+      }
+
+      let manager = &state.selection_manager;
+      let ctrl_key = event.ctrl_key() || event.meta_key();
+      let key = KeyboardKey::from(&event).logical(direction.get_untracked());
+      let next = match key {
+        KeyboardKey::ArrowDown => navigation.below(&focus),
+        KeyboardKey::ArrowUp => navigation.above(&focus),
+        KeyboardKey::ArrowRight => navigation.right_of(&focus),
+        KeyboardKey::ArrowLeft => navigation.left_of(&focus),
+        KeyboardKey::Home => navigation.first_in_row(&focus, ctrl_key),
+        KeyboardKey::End => navigation.last_in_row(&focus, ctrl_key),
+        KeyboardKey::PageDown => navigation.page_below(&focus, get_page_size(&grid, &focus)),
+        KeyboardKey::PageUp => navigation.page_above(&focus, get_page_size(&grid, &focus)),
+        KeyboardKey::Character(character)
+          if character.eq_ignore_ascii_case("a")
+            && ctrl_key
+            && manager.selection_mode.get_untracked() == SelectionMode::Multiple =>
+        {
+          event.prevent_default();
+          manager.select_all(collection.row_keys());
+          return;
+        }
+        KeyboardKey::Escape if !manager.selected_keys_untracked().is_empty() => {
+          event.prevent_default();
+          manager.clear_selection();
+          return;
+        }
+        _ => {
+          let Some(character) = type_select.get_character(&event) else {
+            return;
+          };
+
+          let items = collection
+            .rows
+            .iter()
+            .map(|row| (&row.key, row.text_value.as_str()));
+          let Some(key) = type_select.search(&character, items, focus.row_key()) else {
+            return;
+          };
+
+          let column = match focus {
+            GridFocus::Cell { column, .. } => Some(column),
+            _ => None,
+          };
+
+          match column {
+            Some(column) => Some(GridFocus::Cell { row: key, column }),
+            None => Some(GridFocus::Row(key)),
+          }
+        }
+      };
+
+      event.prevent_default();
+
+      let Some(next) = next else {
+        return;
+      };
+
+      if let Some(row) = next.row_key() {
+        manager.select_on_focus_move(row, event.shift_key(), ctrl_key, &collection.row_keys());
+      }
+
+      match state.virtualizer {
+        Some(ref virtualizer) => {
+          if let Some(row) = next.row_key() {
+            virtualizer.scroll_to_key(row, &collection.row_keys());
+          }
+
+          let target = next.clone();
+          focus_when_mounted(move || find_grid_element(&grid, &target));
+        }
+        None => focus_grid_element(&grid, &next),
+      }
+
+      state.set_focus(Some(next));
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let on_focus_in: TableCallback<FocusEvent> = {
+    let state = state.clone();
+
+    let handler = move |event: FocusEvent| {
+      let Some(target) = event
+        .target()
+        .map(|target| target.unchecked_into::<Element>())
+      else {
+        return;
+      };
+
+      state.selection_manager.set_focused(true);
+
+      if let Some(focus) = get_grid_focus(&target) {
+        state.set_focus(Some(focus));
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let on_focus_out: TableCallback<FocusEvent> = {
+    let state = state.clone();
+
+    let handler = move |event: FocusEvent| {
+      let Some(grid) = event
+        .current_target()
+        .map(|target| target.unchecked_into::<Element>())
+      else {
+        return;
+      };
+
+      let related_target: Option<Node> = event
+        .related_target()
+        .and_then(|target| target.dyn_into::<Node>().ok());
+
+      if !grid.contains(related_target.as_ref()) {
+        state.selection_manager.set_focused(false);
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let (table_result, _) = create_signal(
+    cx,
+    TableResult {
+      id: state.id.clone(),
+      role: "grid",
+      aria_label,
+      aria_labelledby,
+      aria_multiselectable,
+      aria_busy,
+      aria_rowcount,
+      aria_colcount,
+      on_key_down,
+      on_focus_in,
+      on_focus_out,
+    },
+  );
+
+  table_result
+}
+
+/// Find the grid element which matches the focus target.
+pub(crate) fn find_grid_element(grid: &Element, focus: &GridFocus) -> Option<Element> {
+  match focus {
+    GridFocus::Row(row) => find_element_by_key(grid, DATA_KEY_ATTRIBUTE, row),
+    GridFocus::Cell { row, column } => {
+      let row = find_element_by_key(grid, DATA_KEY_ATTRIBUTE, row)?;
+      find_element_by_key(&row, DATA_COLUMN_KEY_ATTRIBUTE, column)
+    }
+    // The header row is rendered before the body so the first match is the
+    // column header.
+    GridFocus::ColumnHeader(column) => find_element_by_key(grid, DATA_COLUMN_KEY_ATTRIBUTE, column),
+  }
+}
+
+/// Move DOM focus to the element matching the focus target.
+pub(crate) fn focus_grid_element(grid: &Element, focus: &GridFocus) {
+  if let Some(element) = find_grid_element(grid, focus) {
+    FocusableElement::from(element).focus();
+  }
+}
+
+/// Determine the focus target from an element within the grid.
+fn get_grid_focus(target: &Element) -> Option<GridFocus> {
+  let row = get_closest_key(target, DATA_KEY_ATTRIBUTE);
+  let column = get_closest_key(target, DATA_COLUMN_KEY_ATTRIBUTE);
+
+  match (row, column) {
+    (Some(row), Some(column)) => Some(GridFocus::Cell { row, column }),
+    (Some(row), None) => Some(GridFocus::Row(row)),
+    (None, Some(column)) => Some(GridFocus::ColumnHeader(column)),
+    (None, None) => None,
+  }
+}
+
+/// The number of rows which fit within the grid, used for paging.
+fn get_page_size(grid: &Element, focus: &GridFocus) -> usize {
+  let row_height = focus
+    .row_key()
+    .and_then(|row| find_element_by_key(grid, DATA_KEY_ATTRIBUTE, row))
+    .map(|row| row.get_bounding_client_rect().height())
+    .filter(|height| *height > 0.0);
+
+  match row_height {
+    Some(row_height) => {
+      (f64::from(grid.client_height()) / row_height)
+        .floor()
+        .max(1.0) as usize
+    }
+    None => 1,
+  }
+}
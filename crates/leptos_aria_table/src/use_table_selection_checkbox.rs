@@ -0,0 +1,149 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_selection::Key;
+use leptos_aria_selection::SelectionMode;
+
+use crate::table_state::TableCallback;
+use crate::TableState;
+use crate::SELECTION_COLUMN_KEY;
+
+#[derive(TypedBuilder)]
+pub struct UseTableSelectionCheckboxProps {
+  /// The key of the row the checkbox selects.
+  #[builder(setter(into))]
+  pub key: Key,
+}
+
+/// The props for a checkbox within the selection column of a table.
+#[derive(Clone)]
+pub struct TableSelectionCheckboxResult {
+  pub id: String,
+  pub aria_label: &'static str,
+  /// Combines the label with the row headers, e.g. "Select Alice".
+  pub aria_labelledby: Signal<Option<String>>,
+  pub is_selected: Signal<bool>,
+  pub is_indeterminate: Signal<bool>,
+  pub is_disabled: Signal<bool>,
+  /// Handler that should be called when the checkbox is toggled.
+  pub on_change: TableCallback<bool>,
+}
+
+/// Provides the behavior and accessibility implementation for a checkbox which
+/// selects a single row of a table.
+pub fn use_table_selection_checkbox(
+  cx: Scope,
+  state: &TableState,
+  props: UseTableSelectionCheckboxProps,
+) -> ReadSignal<TableSelectionCheckboxResult> {
+  let key = props.key;
+  let selection_manager = state.selection_manager.clone();
+  let id = format!(
+    "{}-checkbox",
+    state.cell_id(&key, &Key::from(SELECTION_COLUMN_KEY))
+  );
+
+  let aria_labelledby = {
+    let state = state.clone();
+    let key = key.clone();
+    let id = id.clone();
+    (move || state.row_header_ids(&key).map(|ids| format!("{id} {ids}"))).derive_signal(cx)
+  };
+
+  let is_selected = {
+    let selection_manager = selection_manager.clone();
+    let key = key.clone();
+    (move || selection_manager.is_selected(&key)).derive_signal(cx)
+  };
+
+  let is_disabled = {
+    let selection_manager = selection_manager.clone();
+    let key = key.clone();
+
+    (move || {
+      selection_manager.selection_mode.get() == SelectionMode::None
+        || selection_manager.is_disabled(&key)
+    })
+    .derive_signal(cx)
+  };
+
+  let on_change: TableCallback<bool> = {
+    let handler = move |_: bool| {
+      selection_manager.toggle_selection(&key);
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let (result, _) = create_signal(
+    cx,
+    TableSelectionCheckboxResult {
+      id,
+      aria_label: "Select",
+      aria_labelledby,
+      is_selected,
+      is_indeterminate: (move || false).derive_signal(cx),
+      is_disabled,
+      on_change,
+    },
+  );
+
+  result
+}
+
+/// Provides the behavior and accessibility implementation for the checkbox in
+/// the selection column header which selects all rows of a table.
+pub fn use_table_select_all_checkbox(
+  cx: Scope,
+  state: &TableState,
+) -> ReadSignal<TableSelectionCheckboxResult> {
+  let collection = state.collection;
+  let selection_manager = state.selection_manager.clone();
+
+  let is_selected = {
+    let selection_manager = selection_manager.clone();
+
+    (move || collection.with(|collection| selection_manager.is_select_all(&collection.row_keys())))
+      .derive_signal(cx)
+  };
+
+  let is_indeterminate = {
+    let selection_manager = selection_manager.clone();
+    (move || !is_selected.get() && !selection_manager.is_empty()).derive_signal(cx)
+  };
+
+  let is_disabled = {
+    let selection_mode = selection_manager.selection_mode;
+    (move || selection_mode.get() != SelectionMode::Multiple).derive_signal(cx)
+  };
+
+  let on_change: TableCallback<bool> = {
+    let handler = move |is_selected: bool| {
+      if is_selected {
+        selection_manager.select_all(collection.with(|collection| collection.row_keys()));
+      } else {
+        selection_manager.clear_selection();
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let (result, _) = create_signal(
+    cx,
+    TableSelectionCheckboxResult {
+      aria_label: "Select All",
+      is_selected,
+      is_indeterminate,
+      is_disabled,
+      on_change,
+    },
+  );
+
+  result
+}
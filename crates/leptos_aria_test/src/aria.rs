@@ -0,0 +1,508 @@
+use std::collections::HashSet;
+
+use leptos::document;
+use leptos::web_sys::Element;
+use leptos::web_sys::HtmlInputElement;
+use leptos::web_sys::Node;
+use leptos::window;
+use leptos::JsCast;
+
+/// The role of the element, either from the `role` attribute or the implicit
+/// role of the HTML element, e.g. `button` for `<button>` and `checkbox` for
+/// `<input type="checkbox">`.
+///
+/// Only the first token of the `role` attribute is used, since the others are
+/// fallbacks for older browsers.
+pub fn get_role(element: &Element) -> Option<String> {
+  if let Some(role) = element
+    .get_attribute("role")
+    .and_then(|role| role.split_whitespace().next().map(String::from))
+  {
+    return Some(role);
+  }
+
+  let tag_name = element.tag_name().to_lowercase();
+  let input_type = element
+    .get_attribute("type")
+    .unwrap_or_default()
+    .to_lowercase();
+  let has_name = element.has_attribute("aria-label") || element.has_attribute("aria-labelledby");
+
+  match tag_name.as_str() {
+    "a" | "area" if element.has_attribute("href") => Some("link".into()),
+    "img" if element.get_attribute("alt").as_deref() == Some("") => Some("presentation".into()),
+    "section" if !has_name => None,
+    "select" if element.has_attribute("multiple") => Some("listbox".into()),
+    _ => implicit_role(&tag_name, &input_type).map(String::from),
+  }
+}
+
+/// The accessible name of the element, following the steps of the [accessible
+/// name computation] which are relevant to the markup rendered by hooks.
+///
+/// * `aria-labelledby` and `aria-label`.
+/// * The `<label>` of form controls, `alt` of images, `<legend>` of fieldsets
+///   and `<caption>` of tables.
+/// * The text content for roles which are named by their content, such as
+///   `button` and `link`, without the hidden descendants.
+/// * The `title` attribute.
+///
+/// The whitespace of the name is collapsed.
+///
+/// [accessible name computation]: https://www.w3.org/TR/accname-1.2/
+pub fn get_accessible_name(element: &Element) -> String {
+  normalize_whitespace(&compute_name(element, &mut HashSet::new(), false, true))
+}
+
+/// The accessible description of the element from `aria-describedby`, or
+/// `aria-description`.
+pub fn get_accessible_description(element: &Element) -> String {
+  let description = match element.get_attribute("aria-describedby") {
+    Some(ids) => names_of_ids(&ids, &mut HashSet::new()),
+    None => {
+      element
+        .get_attribute("aria-description")
+        .unwrap_or_default()
+    }
+  };
+
+  normalize_whitespace(&description)
+}
+
+/// The value of the state or property, e.g. `expanded` for `aria-expanded`.
+///
+/// The native equivalents are used when the attribute is missing, so that
+/// `checked` is `"true"`, `"false"` or `"mixed"` for checkbox and radio
+/// inputs and `disabled` is `"true"` for disabled form controls.
+pub fn get_aria_state(element: &Element, state: &str) -> Option<String> {
+  if let Some(value) = element.get_attribute(&format!("aria-{state}")) {
+    return Some(value);
+  }
+
+  match state {
+    "checked" => {
+      let input = element.dyn_ref::<HtmlInputElement>()?;
+
+      match input.type_().as_str() {
+        "checkbox" if input.indeterminate() => Some("mixed".into()),
+        "checkbox" | "radio" => Some(input.checked().to_string()),
+        _ => None,
+      }
+    }
+    "disabled" if element.has_attribute("disabled") => Some("true".into()),
+    "required" if element.has_attribute("required") => Some("true".into()),
+    _ => None,
+  }
+}
+
+/// Whether the element is excluded from the accessibility tree, because it or
+/// one of its ancestors is hidden.
+pub fn is_inaccessible(element: &Element) -> bool {
+  let mut current = Some(element.clone());
+
+  while let Some(element) = current {
+    if is_hidden(&element) {
+      return true;
+    }
+
+    current = element.parent_element();
+  }
+
+  false
+}
+
+/// Assert that the element has the role.
+pub fn assert_role(element: &Element, role: &str) {
+  assert_eq!(
+    get_role(element).as_deref(),
+    Some(role),
+    "the role of {}",
+    describe(element)
+  );
+}
+
+/// Assert that the element has the accessible name.
+pub fn assert_accessible_name(element: &Element, name: &str) {
+  assert_eq!(
+    get_accessible_name(element),
+    name,
+    "the accessible name of {}",
+    describe(element)
+  );
+}
+
+/// Assert that the element has the accessible description.
+pub fn assert_accessible_description(element: &Element, description: &str) {
+  assert_eq!(
+    get_accessible_description(element),
+    description,
+    "the accessible description of {}",
+    describe(element)
+  );
+}
+
+/// Assert the value of the state or property, where `None` means it must not
+/// be set.
+///
+/// ```ignore
+/// assert_aria_state(&trigger, "expanded", Some("true"));
+/// assert_aria_state(&trigger, "controls", Some(&panel.id()));
+/// assert_aria_state(&option, "selected", None);
+/// ```
+pub fn assert_aria_state(element: &Element, state: &str, expected: Option<&str>) {
+  assert_eq!(
+    get_aria_state(element, state).as_deref(),
+    expected,
+    "`aria-{state}` of {}",
+    describe(element)
+  );
+}
+
+/// Assert that the element is in the accessibility tree.
+pub fn assert_accessible(element: &Element) {
+  assert!(
+    !is_inaccessible(element),
+    "{} is hidden from assistive technology",
+    describe(element)
+  );
+}
+
+/// Assert that the element is hidden from the accessibility tree.
+pub fn assert_inaccessible(element: &Element) {
+  assert!(
+    is_inaccessible(element),
+    "{} is exposed to assistive technology",
+    describe(element)
+  );
+}
+
+/// The implicit role of the HTML element. The roles which depend on more than
+/// the tag name and input type are handled by [`get_role`].
+fn implicit_role(tag_name: &str, input_type: &str) -> Option<&'static str> {
+  let role = match tag_name {
+    "article" => "article",
+    "aside" => "complementary",
+    "button" => "button",
+    "datalist" => "listbox",
+    "dd" => "definition",
+    "details" => "group",
+    "dialog" => "dialog",
+    "dt" => "term",
+    "fieldset" => "group",
+    "figure" => "figure",
+    "footer" => "contentinfo",
+    "form" => "form",
+    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+    "header" => "banner",
+    "hr" => "separator",
+    "img" => "img",
+    "input" => {
+      match input_type {
+        "button" | "image" | "reset" | "submit" => "button",
+        "checkbox" => "checkbox",
+        "number" => "spinbutton",
+        "radio" => "radio",
+        "range" => "slider",
+        "search" => "searchbox",
+        "" | "email" | "tel" | "text" | "url" => "textbox",
+        _ => return None,
+      }
+    }
+    "li" => "listitem",
+    "main" => "main",
+    "menu" | "ol" | "ul" => "list",
+    "meter" => "meter",
+    "nav" => "navigation",
+    "optgroup" => "group",
+    "option" => "option",
+    "output" => "status",
+    "progress" => "progressbar",
+    "section" => "region",
+    "select" => "combobox",
+    "table" => "table",
+    "tbody" | "tfoot" | "thead" => "rowgroup",
+    "td" => "cell",
+    "textarea" => "textbox",
+    "th" => "columnheader",
+    "tr" => "row",
+    _ => return None,
+  };
+
+  Some(role)
+}
+
+/// Whether the role takes its name from the content of the element.
+fn is_named_from_content(role: &str) -> bool {
+  matches!(
+    role,
+    "button"
+      | "cell"
+      | "checkbox"
+      | "columnheader"
+      | "gridcell"
+      | "heading"
+      | "link"
+      | "menuitem"
+      | "menuitemcheckbox"
+      | "menuitemradio"
+      | "option"
+      | "radio"
+      | "row"
+      | "rowheader"
+      | "switch"
+      | "tab"
+      | "tooltip"
+      | "treeitem"
+  )
+}
+
+/// Collapse runs of whitespace into a single space and trim the ends.
+fn normalize_whitespace(text: &str) -> String {
+  text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn compute_name(
+  element: &Element,
+  visited: &mut HashSet<String>,
+  is_referenced: bool,
+  is_root: bool,
+) -> String {
+  // Referenced elements are named even when hidden, e.g. a visually hidden
+  // label.
+  if !is_referenced && is_hidden(element) {
+    return String::new();
+  }
+
+  if !is_referenced {
+    if let Some(ids) = element.get_attribute("aria-labelledby") {
+      let name = names_of_ids(&ids, visited);
+
+      if !name.trim().is_empty() {
+        return name;
+      }
+    }
+  }
+
+  if let Some(label) = element
+    .get_attribute("aria-label")
+    .filter(|label| !label.trim().is_empty())
+  {
+    return label;
+  }
+
+  let native_name = native_name(element, visited);
+
+  if !native_name.trim().is_empty() {
+    return native_name;
+  }
+
+  let role = get_role(element).unwrap_or_default();
+
+  if !is_root || is_referenced || is_named_from_content(&role) {
+    let content = content_name(element, visited);
+
+    if !content.trim().is_empty() {
+      return content;
+    }
+  }
+
+  element.get_attribute("title").unwrap_or_default()
+}
+
+/// The names of the elements with the space separated ids, in order.
+fn names_of_ids(ids: &str, visited: &mut HashSet<String>) -> String {
+  ids
+    .split_whitespace()
+    .filter(|id| visited.insert(id.to_string()))
+    .filter_map(|id| document().get_element_by_id(id))
+    .map(|element| compute_name(&element, visited, true, false))
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// The name from the HTML semantics of the element.
+fn native_name(element: &Element, visited: &mut HashSet<String>) -> String {
+  let tag_name = element.tag_name().to_lowercase();
+  let input_type = element
+    .get_attribute("type")
+    .unwrap_or_default()
+    .to_lowercase();
+
+  match tag_name.as_str() {
+    "input" if matches!(input_type.as_str(), "button" | "reset" | "submit") => {
+      element.get_attribute("value").unwrap_or_default()
+    }
+    "input" if input_type == "image" => element.get_attribute("alt").unwrap_or_default(),
+    "input" | "select" | "textarea" | "meter" | "progress" | "output" => {
+      label_name(element, visited)
+    }
+    "img" | "area" => element.get_attribute("alt").unwrap_or_default(),
+    "fieldset" => first_child_name(element, "legend", visited),
+    "figure" => first_child_name(element, "figcaption", visited),
+    "table" => first_child_name(element, "caption", visited),
+    _ => String::new(),
+  }
+}
+
+/// The name of the labels of a form control, either wrapping the control or
+/// referencing its id.
+fn label_name(element: &Element, visited: &mut HashSet<String>) -> String {
+  let mut labels = vec![];
+
+  if let Some(label) = element.closest("label").ok().flatten() {
+    labels.push(label);
+  }
+
+  let id = element.id();
+
+  if !id.is_empty() {
+    if let Ok(nodes) = document().query_selector_all(&format!("label[for=\"{id}\"]")) {
+      for index in 0..nodes.length() {
+        if let Some(label) = nodes
+          .item(index)
+          .and_then(|node| node.dyn_into::<Element>().ok())
+        {
+          if !labels.contains(&label) {
+            labels.push(label);
+          }
+        }
+      }
+    }
+  }
+
+  labels
+    .iter()
+    .map(|label| content_name_without(label, element, visited))
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+fn first_child_name(element: &Element, tag_name: &str, visited: &mut HashSet<String>) -> String {
+  let children = element.children();
+
+  (0..children.length())
+    .filter_map(|index| children.item(index))
+    .find(|child| child.tag_name().eq_ignore_ascii_case(tag_name))
+    .map(|child| content_name(&child, visited))
+    .unwrap_or_default()
+}
+
+/// The text of the descendants, where the descendant elements are named
+/// recursively.
+fn content_name(element: &Element, visited: &mut HashSet<String>) -> String {
+  let mut name = String::new();
+  let nodes = element.child_nodes();
+
+  for index in 0..nodes.length() {
+    let Some(node) = nodes.item(index) else {
+      continue;
+    };
+
+    name.push_str(&node_name(&node, visited));
+  }
+
+  name
+}
+
+/// The content of a label without the control it labels, whose value would
+/// otherwise be part of its own name.
+fn content_name_without(
+  label: &Element,
+  control: &Element,
+  visited: &mut HashSet<String>,
+) -> String {
+  let mut name = String::new();
+  let nodes = label.child_nodes();
+
+  for index in 0..nodes.length() {
+    let Some(node) = nodes.item(index) else {
+      continue;
+    };
+
+    if node.is_same_node(Some(control.as_ref())) {
+      continue;
+    }
+
+    name.push_str(&node_name(&node, visited));
+  }
+
+  name
+}
+
+fn node_name(node: &Node, visited: &mut HashSet<String>) -> String {
+  match node.node_type() {
+    Node::TEXT_NODE => node.text_content().unwrap_or_default(),
+    Node::ELEMENT_NODE => {
+      let element: &Element = node.unchecked_ref();
+      let name = compute_name(element, visited, false, false);
+
+      // Block elements separate the words of their siblings.
+      if is_inline(element) {
+        name
+      } else {
+        format!(" {name} ")
+      }
+    }
+    _ => String::new(),
+  }
+}
+
+fn is_hidden(element: &Element) -> bool {
+  if element.get_attribute("aria-hidden").as_deref() == Some("true")
+    || element.has_attribute("hidden")
+  {
+    return true;
+  }
+
+  window()
+    .get_computed_style(element)
+    .ok()
+    .flatten()
+    .map_or(false, |style| {
+      style.get_property_value("display").ok().as_deref() == Some("none")
+        || style.get_property_value("visibility").ok().as_deref() == Some("hidden")
+    })
+}
+
+fn is_inline(element: &Element) -> bool {
+  window()
+    .get_computed_style(element)
+    .ok()
+    .flatten()
+    .and_then(|style| style.get_property_value("display").ok())
+    .map_or(true, |display| display.starts_with("inline"))
+}
+
+/// A short description of the element for the assertion messages, e.g.
+/// `<button id="trigger">`.
+fn describe(element: &Element) -> String {
+  let tag_name = element.tag_name().to_lowercase();
+  let id = element.id();
+
+  if id.is_empty() {
+    format!("<{tag_name}>")
+  } else {
+    format!("<{tag_name} id=\"{id}\">")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn implicit_roles_of_elements() {
+    assert_eq!(implicit_role("button", ""), Some("button"));
+    assert_eq!(implicit_role("input", ""), Some("textbox"));
+    assert_eq!(implicit_role("input", "checkbox"), Some("checkbox"));
+    assert_eq!(implicit_role("input", "submit"), Some("button"));
+    assert_eq!(implicit_role("input", "hidden"), None);
+    assert_eq!(implicit_role("h3", ""), Some("heading"));
+    assert_eq!(implicit_role("div", ""), None);
+  }
+
+  #[test]
+  fn collapses_whitespace() {
+    assert_eq!(normalize_whitespace("  Open \n  menu "), "Open menu");
+  }
+}
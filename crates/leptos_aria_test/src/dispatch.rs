@@ -0,0 +1,8 @@
+use leptos::web_sys::Event;
+use leptos::web_sys::EventTarget;
+
+/// Dispatch the event and return `true` when it wasn't canceled with
+/// `preventDefault`, in which case the browser would run its default action.
+pub(crate) fn dispatch(target: &EventTarget, event: impl AsRef<Event>) -> bool {
+  target.dispatch_event(event.as_ref()).unwrap_or(false)
+}
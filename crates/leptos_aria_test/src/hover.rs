@@ -0,0 +1,50 @@
+use leptos::web_sys::HtmlElement;
+
+use crate::dispatch::dispatch;
+use crate::pointer::create_mouse_event;
+use crate::pointer::create_pointer_event;
+use crate::Pointer;
+use crate::PointerOptions;
+
+/// Move the pointer onto the element.
+///
+/// A mouse or pen dispatches the pointer and mouse events. Touch can't hover,
+/// but browsers emulate the mouse events when the screen is tapped, so touch
+/// dispatches the pointer events followed by the emulated mouse events which
+/// hover hooks are expected to ignore.
+pub fn hover(element: &HtmlElement, pointer: Pointer) {
+  let options = PointerOptions::new(pointer);
+
+  for event_type in ["pointerover", "pointerenter", "pointermove"] {
+    dispatch(
+      element,
+      create_pointer_event(event_type, element, &options, 0),
+    );
+  }
+
+  for event_type in ["mouseover", "mouseenter", "mousemove"] {
+    dispatch(
+      element,
+      create_mouse_event(event_type, element, &options, 0),
+    );
+  }
+}
+
+/// Move the pointer off the element.
+pub fn unhover(element: &HtmlElement, pointer: Pointer) {
+  let options = PointerOptions::new(pointer);
+
+  for event_type in ["pointerout", "pointerleave"] {
+    dispatch(
+      element,
+      create_pointer_event(event_type, element, &options, 0),
+    );
+  }
+
+  for event_type in ["mouseout", "mouseleave"] {
+    dispatch(
+      element,
+      create_mouse_event(event_type, element, &options, 0),
+    );
+  }
+}
@@ -0,0 +1,85 @@
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::KeyboardEventInit;
+
+use crate::dispatch::dispatch;
+use crate::virtual_click;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyOptions {
+  pub shift_key: bool,
+  pub ctrl_key: bool,
+  pub alt_key: bool,
+  pub meta_key: bool,
+  /// Whether the key is held down and repeating.
+  pub repeat: bool,
+}
+
+/// Dispatch `keydown` with the `KeyboardEvent.key` value, e.g. `Enter`, ` `
+/// or `ArrowDown`. Returns `false` when it was canceled.
+pub fn key_down(element: &HtmlElement, key: &str, options: KeyOptions) -> bool {
+  dispatch(element, create_keyboard_event("keydown", key, &options))
+}
+
+/// Dispatch `keyup`. Returns `false` when it was canceled.
+pub fn key_up(element: &HtmlElement, key: &str, options: KeyOptions) -> bool {
+  dispatch(element, create_keyboard_event("keyup", key, &options))
+}
+
+/// Press and release the key on the element.
+///
+/// Like the browser, a native button is clicked when `Enter` is pressed or
+/// `Space` is released and the event wasn't canceled, as are links with
+/// `Enter`.
+pub fn press_key(element: &HtmlElement, key: &str) {
+  press_key_with_options(element, key, KeyOptions::default());
+}
+
+pub fn press_key_with_options(element: &HtmlElement, key: &str, options: KeyOptions) {
+  let activation = get_activation(element);
+
+  if key_down(element, key, options) && key == "Enter" && activation.is_some() {
+    virtual_click(element);
+  }
+
+  if key_up(element, key, options) && key == " " && activation == Some(Activation::Button) {
+    virtual_click(element);
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Activation {
+  Button,
+  Link,
+}
+
+/// How the browser activates the element with the keyboard, if at all.
+fn get_activation(element: &HtmlElement) -> Option<Activation> {
+  let input_type = element.get_attribute("type").unwrap_or_default();
+
+  match element.tag_name().as_str() {
+    "BUTTON" => Some(Activation::Button),
+    "INPUT" if matches!(input_type.as_str(), "button" | "submit" | "reset") => {
+      Some(Activation::Button)
+    }
+    "A" if element.has_attribute("href") => Some(Activation::Link),
+    _ => None,
+  }
+}
+
+fn create_keyboard_event(event_type: &str, key: &str, options: &KeyOptions) -> KeyboardEvent {
+  let mut init = KeyboardEventInit::new();
+
+  init
+    .bubbles(true)
+    .cancelable(true)
+    .composed(true)
+    .key(key)
+    .repeat(options.repeat)
+    .shift_key(options.shift_key)
+    .ctrl_key(options.ctrl_key)
+    .alt_key(options.alt_key)
+    .meta_key(options.meta_key);
+
+  KeyboardEvent::new_with_keyboard_event_init_dict(event_type, &init).unwrap()
+}
@@ -0,0 +1,12 @@
+pub use aria::*;
+pub use hover::*;
+pub use keyboard::*;
+pub use mount::*;
+pub use pointer::*;
+
+mod aria;
+mod dispatch;
+mod hover;
+mod keyboard;
+mod mount;
+mod pointer;
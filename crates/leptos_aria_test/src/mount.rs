@@ -0,0 +1,107 @@
+use leptos::document;
+use leptos::mount_to;
+use leptos::web_sys::HtmlElement;
+use leptos::IntoView;
+use leptos::JsCast;
+use leptos::Scope;
+
+use crate::get_accessible_name;
+use crate::get_role;
+use crate::is_inaccessible;
+
+/// A view mounted into its own container in the document, which is removed
+/// when this is dropped so that tests don't affect each other.
+pub struct Mounted {
+  container: HtmlElement,
+}
+
+/// Mount the view into a new container at the end of the body.
+///
+/// ```ignore
+/// wasm_bindgen_test_configure!(run_in_browser);
+///
+/// #[wasm_bindgen_test]
+/// fn presses_the_button() {
+///   let mounted = mount(|cx| view! { cx, <Button /> });
+///   let button = mounted.query("button");
+///
+///   press(&button, PointerOptions::default());
+///   press_key(&button, "Enter");
+/// }
+/// ```
+pub fn mount<F, N>(view: F) -> Mounted
+where
+  F: FnOnce(Scope) -> N + 'static,
+  N: IntoView,
+{
+  let container: HtmlElement = document()
+    .create_element("div")
+    .expect("the container to be created")
+    .unchecked_into();
+
+  document()
+    .body()
+    .expect("the document to have a body")
+    .append_child(&container)
+    .expect("the container to be added to the body");
+
+  mount_to(container.clone(), view);
+
+  Mounted { container }
+}
+
+impl Mounted {
+  pub fn container(&self) -> &HtmlElement {
+    &self.container
+  }
+
+  /// The first element within the view which matches the selector.
+  ///
+  /// Panics when there isn't one, since the test can't continue.
+  pub fn query(&self, selector: &str) -> HtmlElement {
+    self
+      .try_query(selector)
+      .unwrap_or_else(|| panic!("no element matches `{selector}`"))
+  }
+
+  /// The first element within the view which matches the selector.
+  pub fn try_query(&self, selector: &str) -> Option<HtmlElement> {
+    self
+      .container
+      .query_selector(selector)
+      .ok()
+      .flatten()
+      .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+  }
+
+  /// The first element within the view with the role and accessible name,
+  /// which is how assistive technology finds the element.
+  ///
+  /// Panics when there isn't one, since the test can't continue.
+  pub fn query_by_role(&self, role: &str, name: &str) -> HtmlElement {
+    self
+      .try_query_by_role(role, name)
+      .unwrap_or_else(|| panic!("no element has the role `{role}` and the name `{name}`"))
+  }
+
+  /// The first element within the view with the role and accessible name,
+  /// ignoring elements which are hidden from assistive technology.
+  pub fn try_query_by_role(&self, role: &str, name: &str) -> Option<HtmlElement> {
+    let elements = self.container.query_selector_all("*").ok()?;
+
+    (0..elements.length())
+      .filter_map(|index| elements.item(index))
+      .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+      .find(|element| {
+        get_role(element).as_deref() == Some(role)
+          && get_accessible_name(element) == name
+          && !is_inaccessible(element)
+      })
+  }
+}
+
+impl Drop for Mounted {
+  fn drop(&mut self) {
+    self.container.remove();
+  }
+}
@@ -0,0 +1,252 @@
+use leptos::web_sys::HtmlElement;
+use leptos::web_sys::MouseEvent;
+use leptos::web_sys::MouseEventInit;
+use leptos::web_sys::PointerEvent;
+use leptos::web_sys::PointerEventInit;
+
+use crate::dispatch::dispatch;
+
+/// The kind of pointer which interacts with the element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pointer {
+  #[default]
+  Mouse,
+  Pen,
+  Touch,
+}
+
+impl Pointer {
+  /// The value of `PointerEvent.pointerType`.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Mouse => "mouse",
+      Self::Pen => "pen",
+      Self::Touch => "touch",
+    }
+  }
+
+  /// Browsers use a different id for each pointer, and a new one for each
+  /// touch.
+  fn pointer_id(&self) -> i32 {
+    match self {
+      Self::Mouse => 1,
+      Self::Pen => 2,
+      Self::Touch => 3,
+    }
+  }
+
+  /// The size of the contact area, which is only larger than a pixel for
+  /// touch.
+  fn size(&self) -> i32 {
+    match self {
+      Self::Touch => 20,
+      Self::Mouse | Self::Pen => 1,
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PointerOptions {
+  pub pointer: Pointer,
+  /// The position in client coordinates. Defaults to the center of the
+  /// element.
+  pub position: Option<(f64, f64)>,
+  pub shift_key: bool,
+  pub ctrl_key: bool,
+  pub alt_key: bool,
+  pub meta_key: bool,
+}
+
+impl PointerOptions {
+  pub fn new(pointer: Pointer) -> Self {
+    Self {
+      pointer,
+      ..Default::default()
+    }
+  }
+
+  pub fn at(mut self, client_x: f64, client_y: f64) -> Self {
+    self.position = Some((client_x, client_y));
+    self
+  }
+
+  fn position_within(&self, element: &HtmlElement) -> (i32, i32) {
+    let (x, y) = self.position.unwrap_or_else(|| {
+      let rect = element.get_bounding_client_rect();
+      (
+        rect.left() + rect.width() / 2.0,
+        rect.top() + rect.height() / 2.0,
+      )
+    });
+
+    (x.round() as i32, y.round() as i32)
+  }
+}
+
+/// Press the pointer down on the element, as when a mouse button is pressed
+/// or a finger touches the screen.
+///
+/// Returns `false` when the `pointerdown` event was canceled. A mouse or pen
+/// also dispatches `mousedown` and focuses the element unless that is
+/// canceled. Browsers delay the mouse events of touch until the touch ends,
+/// which [`press`] simulates.
+pub fn pointer_down(element: &HtmlElement, options: PointerOptions) -> bool {
+  let is_allowed = dispatch(
+    element,
+    create_pointer_event("pointerdown", element, &options, 1),
+  );
+
+  if options.pointer != Pointer::Touch && is_allowed {
+    mouse_down(element, &options);
+  }
+
+  is_allowed
+}
+
+/// Release the pointer over the element.
+pub fn pointer_up(element: &HtmlElement, options: PointerOptions) -> bool {
+  let is_allowed = dispatch(
+    element,
+    create_pointer_event("pointerup", element, &options, 0),
+  );
+
+  if options.pointer != Pointer::Touch {
+    dispatch(element, create_mouse_event("mouseup", element, &options, 1));
+  }
+
+  is_allowed
+}
+
+/// Cancel the pointer, as when the browser starts scrolling during a touch.
+pub fn pointer_cancel(element: &HtmlElement, options: PointerOptions) -> bool {
+  dispatch(
+    element,
+    create_pointer_event("pointercancel", element, &options, 0),
+  )
+}
+
+/// Press and release the pointer on the element, followed by a `click`.
+///
+/// Touch dispatches the emulated mouse events after the pointer is released,
+/// as browsers do, which hooks must not mistake for a second press.
+pub fn press(element: &HtmlElement, options: PointerOptions) {
+  pointer_down(element, options);
+  pointer_up(element, options);
+
+  if options.pointer == Pointer::Touch {
+    mouse_down(element, &options);
+    dispatch(element, create_mouse_event("mouseup", element, &options, 1));
+  }
+
+  dispatch(element, create_mouse_event("click", element, &options, 1));
+}
+
+/// A click without any pointer events and with a `detail` of `0`, as
+/// dispatched by screen readers and `HTMLElement.click()`.
+pub fn virtual_click(element: &HtmlElement) {
+  let options = PointerOptions::default().at(0.0, 0.0);
+  dispatch(element, create_mouse_event("click", element, &options, 0));
+}
+
+/// A press by a screen reader which dispatches pointer events, such as
+/// TalkBack on Android, where the pointer has no size and no pressure.
+pub fn virtual_pointer_press(element: &HtmlElement) {
+  let options = PointerOptions::new(Pointer::Touch).at(0.0, 0.0);
+
+  for (event_type, buttons) in [("pointerdown", 1), ("pointerup", 0)] {
+    let mut init = pointer_event_init(event_type, element, &options, buttons);
+    init.width(0).height(0).pressure(0.0);
+    dispatch(
+      element,
+      PointerEvent::new_with_event_init_dict(event_type, &init).unwrap(),
+    );
+  }
+
+  virtual_click(element);
+}
+
+fn mouse_down(element: &HtmlElement, options: &PointerOptions) {
+  if dispatch(
+    element,
+    create_mouse_event("mousedown", element, options, 1),
+  ) {
+    element.focus().ok();
+  }
+}
+
+pub(crate) fn create_pointer_event(
+  event_type: &str,
+  element: &HtmlElement,
+  options: &PointerOptions,
+  buttons: u16,
+) -> PointerEvent {
+  PointerEvent::new_with_event_init_dict(
+    event_type,
+    &pointer_event_init(event_type, element, options, buttons),
+  )
+  .unwrap()
+}
+
+fn pointer_event_init(
+  event_type: &str,
+  element: &HtmlElement,
+  options: &PointerOptions,
+  buttons: u16,
+) -> PointerEventInit {
+  let (client_x, client_y) = options.position_within(element);
+  let size = options.pointer.size();
+  let mut init = PointerEventInit::new();
+
+  init
+    .bubbles(bubbles(event_type))
+    .cancelable(true)
+    .composed(true)
+    .pointer_id(options.pointer.pointer_id())
+    .pointer_type(options.pointer.as_str())
+    .is_primary(true)
+    .width(size)
+    .height(size)
+    .pressure(if buttons == 0 { 0.0 } else { 0.5 })
+    .client_x(client_x)
+    .client_y(client_y)
+    .button(0)
+    .buttons(buttons)
+    .shift_key(options.shift_key)
+    .ctrl_key(options.ctrl_key)
+    .alt_key(options.alt_key)
+    .meta_key(options.meta_key);
+
+  init
+}
+
+pub(crate) fn create_mouse_event(
+  event_type: &str,
+  element: &HtmlElement,
+  options: &PointerOptions,
+  detail: i32,
+) -> MouseEvent {
+  let (client_x, client_y) = options.position_within(element);
+  let mut init = MouseEventInit::new();
+
+  init
+    .bubbles(bubbles(event_type))
+    .cancelable(true)
+    .composed(true)
+    .detail(detail)
+    .client_x(client_x)
+    .client_y(client_y)
+    .button(0)
+    .buttons(u16::from(event_type == "mousedown"))
+    .shift_key(options.shift_key)
+    .ctrl_key(options.ctrl_key)
+    .alt_key(options.alt_key)
+    .meta_key(options.meta_key);
+
+  MouseEvent::new_with_mouse_event_init_dict(event_type, &init).unwrap()
+}
+
+/// The enter and leave events are dispatched on each element the pointer
+/// moves onto or off and don't bubble.
+fn bubbles(event_type: &str) -> bool {
+  !event_type.ends_with("enter") && !event_type.ends_with("leave")
+}
@@ -0,0 +1,58 @@
+use leptos::*;
+use leptos_aria_test::assert_accessible_description;
+use leptos_aria_test::assert_accessible_name;
+use leptos_aria_test::assert_aria_state;
+use leptos_aria_test::assert_inaccessible;
+use leptos_aria_test::assert_role;
+use leptos_aria_test::mount;
+use leptos_aria_test::press;
+use leptos_aria_test::PointerOptions;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn Disclosure(cx: Scope) -> impl IntoView {
+  let (is_expanded, set_expanded) = create_signal(cx, false);
+
+  view! { cx,
+    <h3 id="title">"Shipping " <span aria-hidden="true">"📦"</span></h3>
+    <button
+      aria-labelledby="title"
+      aria-describedby="hint"
+      aria-controls="panel"
+      aria-expanded=move || is_expanded.get().to_string()
+      on:click=move |_| set_expanded.update(|is_expanded| *is_expanded = !*is_expanded)
+    >
+      "Toggle"
+    </button>
+    <p id="hint">"Shows the delivery options"</p>
+    <div id="panel" hidden=move || !is_expanded.get()>
+      <label>"Express" <input type="checkbox" checked=true /></label>
+    </div>
+  }
+}
+
+#[wasm_bindgen_test]
+fn asserts_the_accessibility_tree() {
+  console_error_panic_hook::set_once();
+
+  let mounted = mount(|cx| view! { cx, <Disclosure /> });
+  let trigger = mounted.query_by_role("button", "Shipping");
+  let checkbox = mounted.query("input");
+
+  assert_role(&trigger, "button");
+  assert_accessible_name(&trigger, "Shipping");
+  assert_accessible_description(&trigger, "Shows the delivery options");
+  assert_aria_state(&trigger, "expanded", Some("false"));
+  assert_inaccessible(&checkbox);
+
+  press(&trigger, PointerOptions::default());
+
+  assert_aria_state(&trigger, "expanded", Some("true"));
+  assert_eq!(
+    mounted.try_query_by_role("checkbox", "Express"),
+    Some(checkbox.clone())
+  );
+  assert_aria_state(&checkbox, "checked", Some("true"));
+}
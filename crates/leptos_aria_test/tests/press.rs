@@ -0,0 +1,55 @@
+use leptos::*;
+use leptos_aria_interactions::use_press;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_interactions::UsePressProps;
+use leptos_aria_test::mount;
+use leptos_aria_test::press;
+use leptos_aria_test::press_key;
+use leptos_aria_test::virtual_click;
+use leptos_aria_test::PointerOptions;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn Counter(cx: Scope) -> impl IntoView {
+  let (count, set_count) = create_signal(cx, 0);
+  let props = UsePressProps::builder()
+    .on_press(Box::new(move |_: &PressEvent| {
+      set_count.update(|count| *count += 1)
+    }))
+    .build();
+  let press = use_press(cx, props);
+
+  view! { cx,
+    <button
+      data-count=move || count.get().to_string()
+      on:click=move |event| (press.get().on_click)(event)
+      on:keydown=move |event| (press.get().on_key_down)(event)
+      on:keyup=move |event| (press.get().on_key_up)(event)
+      on:mousedown=move |event| (press.get().on_mouse_down)(event)
+      on:pointerdown=move |event| (press.get().on_pointer_down)(event)
+      on:pointerup=move |event| (press.get().on_pointer_up)(event)
+    >
+      "Count"
+    </button>
+  }
+}
+
+#[wasm_bindgen_test]
+fn presses_with_each_input() {
+  console_error_panic_hook::set_once();
+
+  let mounted = mount(|cx| view! { cx, <Counter /> });
+  let button = mounted.query("button");
+  let count = || button.get_attribute("data-count").unwrap_or_default();
+
+  press(&button, PointerOptions::default());
+  assert_eq!(count(), "1");
+
+  press_key(&button, "Enter");
+  assert_eq!(count(), "2");
+
+  virtual_click(&button);
+  assert_eq!(count(), "3");
+}
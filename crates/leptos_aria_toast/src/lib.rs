@@ -0,0 +1,7 @@
+pub use toast_queue::*;
+pub use use_toast::*;
+pub use use_toast_region::*;
+
+mod toast_queue;
+mod use_toast;
+mod use_toast_region;
@@ -0,0 +1,260 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::create_rw_signal;
+use leptos::js_sys::Date;
+use leptos::set_timeout;
+use leptos::typed_builder::TypedBuilder;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::generate_id;
+
+/// The default number of toasts shown at once.
+pub const TOAST_MAX_VISIBLE: usize = 5;
+
+/// The minimum time in milliseconds a toast with a timeout stays visible, so
+/// that screen reader users have time to hear it.
+pub const TOAST_MIN_TIMEOUT: u64 = 5000;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseToastQueueProps {
+  /// The maximum number of toasts shown at once. Further toasts wait until a
+  /// visible toast is closed. Defaults to [`TOAST_MAX_VISIBLE`].
+  #[builder(default, setter(strip_option))]
+  pub max_visible: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, TypedBuilder)]
+pub struct ToastOptions {
+  /// Toasts with a higher priority are shown before toasts with a lower
+  /// priority, regardless of when they were added.
+  #[builder(default)]
+  pub priority: i32,
+
+  /// The time in milliseconds before the toast closes automatically. Values
+  /// below [`TOAST_MIN_TIMEOUT`] are raised to it. Toasts with actions should
+  /// not time out.
+  #[builder(default, setter(strip_option))]
+  pub timeout: Option<u64>,
+}
+
+/// A toast within the queue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuedToast<T> {
+  pub key: String,
+  pub content: T,
+  pub priority: i32,
+  pub timeout: Option<u64>,
+}
+
+/// The remaining time of a toast's auto dismiss timer.
+#[derive(Clone, Copy)]
+struct ToastTimer {
+  remaining: f64,
+  /// When the timer was last started. `None` while paused.
+  started_at: Option<f64>,
+  generation: usize,
+}
+
+/// A queue of toasts shared between the toast region and the toasts.
+pub struct ToastQueue<T: 'static> {
+  toasts: RwSignal<Vec<QueuedToast<T>>>,
+  max_visible: usize,
+  timers: Rc<RefCell<HashMap<String, ToastTimer>>>,
+  is_paused: Rc<RefCell<bool>>,
+}
+
+impl<T> Clone for ToastQueue<T> {
+  fn clone(&self) -> Self {
+    Self {
+      toasts: self.toasts,
+      max_visible: self.max_visible,
+      timers: self.timers.clone(),
+      is_paused: self.is_paused.clone(),
+    }
+  }
+}
+
+pub fn use_toast_queue<T>(cx: Scope, props: UseToastQueueProps) -> ToastQueue<T>
+where
+  T: Clone + 'static,
+{
+  ToastQueue {
+    toasts: create_rw_signal(cx, vec![]),
+    max_visible: props.max_visible.unwrap_or(TOAST_MAX_VISIBLE).max(1),
+    timers: Default::default(),
+    is_paused: Default::default(),
+  }
+}
+
+impl<T> ToastQueue<T>
+where
+  T: Clone + 'static,
+{
+  /// Add a toast to the queue and return its key.
+  pub fn add(&self, content: T, options: ToastOptions) -> String {
+    let key = generate_id();
+    let toast = QueuedToast {
+      key: key.clone(),
+      content,
+      priority: options.priority,
+      timeout: options
+        .timeout
+        .map(|timeout| timeout.max(TOAST_MIN_TIMEOUT)),
+    };
+
+    self.toasts.update(|toasts| toasts.push(toast));
+    self.start_visible_timers();
+
+    key
+  }
+
+  /// Close the toast with the provided key.
+  pub fn close(&self, key: &str) {
+    self.timers.borrow_mut().remove(key);
+    self
+      .toasts
+      .update(|toasts| toasts.retain(|toast| toast.key != key));
+    self.start_visible_timers();
+  }
+
+  /// Close every toast.
+  pub fn clear(&self) {
+    self.timers.borrow_mut().clear();
+    self.toasts.set(vec![]);
+  }
+
+  /// The toasts which should be rendered, ordered by priority and then by
+  /// when they were added.
+  pub fn visible_toasts(&self) -> Vec<QueuedToast<T>> {
+    self
+      .toasts
+      .with(|toasts| visible_toasts(toasts, self.max_visible))
+  }
+
+  pub(crate) fn visible_toasts_untracked(&self) -> Vec<QueuedToast<T>> {
+    visible_toasts(&self.toasts.get_untracked(), self.max_visible)
+  }
+
+  /// Pause the auto dismiss timers, e.g. while the region is hovered or
+  /// focused.
+  pub fn pause_all(&self) {
+    *self.is_paused.borrow_mut() = true;
+    let now = Date::now();
+
+    for timer in self.timers.borrow_mut().values_mut() {
+      if let Some(started_at) = timer.started_at.take() {
+        timer.remaining -= now - started_at;
+        timer.generation += 1;
+      }
+    }
+  }
+
+  /// Resume the auto dismiss timers with their remaining time.
+  pub fn resume_all(&self) {
+    *self.is_paused.borrow_mut() = false;
+    self.start_visible_timers();
+  }
+
+  /// Start the timers of visible toasts which aren't running yet. Queued
+  /// toasts don't time out until they are shown.
+  fn start_visible_timers(&self) {
+    if *self.is_paused.borrow() {
+      return;
+    }
+
+    let now = Date::now();
+
+    for toast in self.visible_toasts_untracked() {
+      let Some(timeout) = toast.timeout else {
+        continue;
+      };
+
+      let (remaining, generation) = {
+        let mut timers = self.timers.borrow_mut();
+        let timer = timers.entry(toast.key.clone()).or_insert(ToastTimer {
+          remaining: timeout as f64,
+          started_at: None,
+          generation: 0,
+        });
+
+        if timer.started_at.is_some() {
+          continue;
+        }
+
+        timer.started_at = Some(now);
+        timer.generation += 1;
+        (timer.remaining, timer.generation)
+      };
+
+      let queue = self.clone();
+      let key = toast.key;
+
+      set_timeout(
+        move || {
+          let is_current = queue
+            .timers
+            .borrow()
+            .get(&key)
+            .map_or(false, |timer| timer.generation == generation);
+
+          if is_current {
+            queue.close(&key);
+          }
+        },
+        Duration::from_millis(remaining.max(0.0) as u64),
+      );
+    }
+  }
+}
+
+/// The toasts which should be shown, ordered by descending priority and then
+/// by insertion order.
+pub fn visible_toasts<T: Clone>(
+  toasts: &[QueuedToast<T>],
+  max_visible: usize,
+) -> Vec<QueuedToast<T>> {
+  let mut sorted = toasts.iter().collect::<Vec<_>>();
+  // `sort_by` is stable so toasts with the same priority keep their order.
+  sorted.sort_by(|a, b| b.priority.cmp(&a.priority));
+  sorted.into_iter().take(max_visible).cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn toast(key: &str, priority: i32) -> QueuedToast<()> {
+    QueuedToast {
+      key: key.into(),
+      content: (),
+      priority,
+      timeout: None,
+    }
+  }
+
+  #[test]
+  fn orders_by_priority_then_insertion() {
+    let toasts = vec![toast("a", 0), toast("b", 1), toast("c", 0), toast("d", 1)];
+    let keys = visible_toasts(&toasts, 10)
+      .into_iter()
+      .map(|toast| toast.key)
+      .collect::<Vec<_>>();
+
+    assert_eq!(keys, vec!["b", "d", "a", "c"]);
+  }
+
+  #[test]
+  fn limits_the_visible_toasts() {
+    let toasts = vec![toast("a", 0), toast("b", 0), toast("c", 2)];
+    let keys = visible_toasts(&toasts, 2)
+      .into_iter()
+      .map(|toast| toast.key)
+      .collect::<Vec<_>>();
+
+    assert_eq!(keys, vec!["c", "a"]);
+  }
+}
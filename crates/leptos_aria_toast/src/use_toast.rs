@@ -0,0 +1,166 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::document;
+use leptos::request_animation_frame;
+use leptos::typed_builder::TypedBuilder;
+use leptos::use_context;
+use leptos::web_sys::MouseEvent;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos_aria_i18n::use_localized_string_formatter;
+use leptos_aria_utils::use_rendered_id;
+use leptos_aria_utils::FocusableElement;
+
+use crate::use_toast_region::RegionContext;
+use crate::use_toast_region::ToastCallback;
+use crate::ToastQueue;
+
+#[derive(TypedBuilder)]
+pub struct UseToastProps {
+  /// The key of the toast returned by [`ToastQueue::add`].
+  #[builder(setter(into))]
+  pub key: String,
+
+  /// The label of the close button. Defaults to `"Dismiss"`, translated for
+  /// the locale.
+  #[builder(default, setter(strip_option, into))]
+  pub close_label: Option<MaybeSignal<String>>,
+}
+
+#[derive(Clone)]
+pub struct ToastElementResult {
+  pub id: String,
+  pub role: &'static str,
+  pub aria_modal: bool,
+  pub aria_labelledby: String,
+  /// The id of the description while it is rendered.
+  pub aria_describedby: Signal<Option<String>>,
+  /// Toasts are focusable so they can be reached with the arrow keys and
+  /// <kbd>Tab</kbd> once the region has focus.
+  pub tab_index: i32,
+}
+
+#[derive(Clone)]
+pub struct ToastTitleResult {
+  pub id: String,
+}
+
+#[derive(Clone)]
+pub struct ToastDescriptionResult {
+  pub id: String,
+}
+
+#[derive(Clone)]
+pub struct ToastCloseButtonResult {
+  pub aria_label: Signal<String>,
+  pub on_click: ToastCallback<MouseEvent>,
+}
+
+#[derive(Clone)]
+pub struct ToastResult {
+  pub toast: ToastElementResult,
+  pub title: ToastTitleResult,
+  pub description: ToastDescriptionResult,
+  pub close_button: ToastCloseButtonResult,
+}
+
+/// Provides the accessibility implementation for a toast within a toast
+/// region. Must be called within the scope of
+/// [`use_toast_region`](crate::use_toast_region).
+///
+/// When a focused toast is closed focus moves to the next toast, or the
+/// previous toast when it was the last one. Once no toasts remain focus is
+/// restored to where it was before entering the region.
+pub fn use_toast<T>(
+  cx: Scope,
+  queue: &ToastQueue<T>,
+  props: UseToastProps,
+) -> ReadSignal<ToastResult>
+where
+  T: Clone + 'static,
+{
+  let key = props.key;
+  let title_id = format!("{key}-title");
+  let description_id = format!("{key}-description");
+  let close_label = match props.close_label {
+    Some(close_label) => (move || close_label.get()).derive_signal(cx),
+    None => {
+      let strings = use_localized_string_formatter(cx);
+      (move || strings.get().format("dismiss")).derive_signal(cx)
+    }
+  };
+  let region = use_context::<RegionContext>(cx);
+
+  let on_click: ToastCallback<MouseEvent> = {
+    let queue = queue.clone();
+    let key = key.clone();
+
+    Rc::new(Box::new(move |_: MouseEvent| {
+      let had_focus = document()
+        .get_element_by_id(&key)
+        .zip(document().active_element())
+        .map_or(false, |(toast, active)| {
+          toast.contains(Some(active.as_ref()))
+        });
+      let focus_target = had_focus.then(|| next_focus_key(&queue, &key)).flatten();
+
+      queue.close(&key);
+
+      if !had_focus {
+        return;
+      }
+
+      let region = region.clone();
+
+      // Wait for the toast to be removed from the DOM.
+      request_animation_frame(move || {
+        match focus_target.and_then(|key| document().get_element_by_id(&key)) {
+          Some(element) => FocusableElement::from(element).focus(),
+          None => {
+            if let Some(region) = region {
+              region.restore_focus();
+            }
+          }
+        }
+      });
+    }))
+  };
+
+  let (toast_result, _) = create_signal(
+    cx,
+    ToastResult {
+      toast: ToastElementResult {
+        id: key,
+        role: "alertdialog",
+        aria_modal: false,
+        aria_labelledby: title_id.clone(),
+        aria_describedby: use_rendered_id(cx, description_id.clone()),
+        tab_index: 0,
+      },
+      title: ToastTitleResult { id: title_id },
+      description: ToastDescriptionResult { id: description_id },
+      close_button: ToastCloseButtonResult {
+        aria_label: close_label,
+        on_click,
+      },
+    },
+  );
+
+  toast_result
+}
+
+/// The toast which should receive focus when the toast with the provided key
+/// is closed.
+fn next_focus_key<T: Clone + 'static>(queue: &ToastQueue<T>, key: &str) -> Option<String> {
+  let toasts = queue.visible_toasts_untracked();
+  let index = toasts.iter().position(|toast| toast.key == key)?;
+
+  toasts
+    .get(index + 1)
+    .or_else(|| index.checked_sub(1).and_then(|index| toasts.get(index)))
+    .map(|toast| toast.key.clone())
+}
@@ -0,0 +1,253 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_signal;
+use leptos::provide_context;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::Node;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_landmark::use_landmark;
+use leptos_aria_landmark::LandmarkRole;
+use leptos_aria_landmark::UseLandmarkProps;
+use leptos_aria_utils::trace_interaction;
+use leptos_aria_utils::use_document_visibility;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::use_window_focus;
+use leptos_aria_utils::FocusableElement;
+use leptos_aria_utils::InteractionKind;
+
+use crate::ToastQueue;
+
+/// The default label of the toast region.
+pub const TOAST_REGION_LABEL: &str = "Notifications";
+
+#[derive(TypedBuilder, Default)]
+pub struct UseToastRegionProps {
+  /// The id of the region element. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// An accessible label for the region. Defaults to [`TOAST_REGION_LABEL`].
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+}
+
+#[derive(Clone)]
+pub struct ToastRegionResult {
+  pub id: String,
+  pub role: &'static str,
+  pub aria_label: Signal<String>,
+  /// Allows the region to be focused with <kbd>F6</kbd>.
+  pub tab_index: i32,
+  pub on_pointer_enter: ToastCallback<PointerEvent>,
+  pub on_pointer_leave: ToastCallback<PointerEvent>,
+  pub on_focus_in: ToastCallback<FocusEvent>,
+  pub on_focus_out: ToastCallback<FocusEvent>,
+}
+
+/// Provides the accessibility implementation for the landmark region which
+/// contains the toasts.
+///
+/// * The auto dismiss timers pause while the region is hovered or contains
+///   focus, and while the tab is hidden or the window isn't focused.
+/// * The region is registered with [`use_landmark`], so <kbd>F6</kbd> and
+///   <kbd>Shift</kbd> + <kbd>F6</kbd> move focus to and from it along with the
+///   other landmarks of the page. Only render the region while it has toasts so
+///   that it is skipped when empty.
+pub fn use_toast_region<T>(
+  cx: Scope,
+  queue: &ToastQueue<T>,
+  props: UseToastRegionProps,
+) -> ReadSignal<ToastRegionResult>
+where
+  T: Clone + 'static,
+{
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let original_aria_label = props
+    .aria_label
+    .unwrap_or_else(|| TOAST_REGION_LABEL.to_string().into());
+  let aria_label = (move || original_aria_label.get()).derive_signal(cx);
+  let is_hovered = Rc::new(RefCell::new(false));
+  let is_focus_within = Rc::new(RefCell::new(false));
+  let context = RegionContext::new(&id);
+  let is_document_visible = use_document_visibility(cx);
+  let has_window_focus = use_window_focus(cx);
+  let landmark = use_landmark(
+    cx,
+    UseLandmarkProps::builder()
+      .role(LandmarkRole::Region)
+      .id(id.clone())
+      .aria_label(aria_label)
+      .build(),
+  )
+  .get_untracked();
+
+  let update_paused = {
+    let queue = queue.clone();
+    let is_hovered = is_hovered.clone();
+    let is_focus_within = is_focus_within.clone();
+
+    Rc::new(move || {
+      let is_page_active = is_document_visible.get_untracked() && has_window_focus.get_untracked();
+
+      if !is_page_active || *is_hovered.borrow() || *is_focus_within.borrow() {
+        queue.pause_all();
+      } else {
+        queue.resume_all();
+      }
+    })
+  };
+
+  // Toasts shouldn't be dismissed before the user has had a chance to see
+  // them.
+  {
+    let update_paused = update_paused.clone();
+
+    create_effect(cx, move |_| {
+      let _ = (is_document_visible.get(), has_window_focus.get());
+      update_paused();
+    });
+  }
+
+  let on_pointer_enter: ToastCallback<PointerEvent> = {
+    let is_hovered = is_hovered.clone();
+    let update_paused = update_paused.clone();
+
+    Rc::new(Box::new(move |event: PointerEvent| {
+      if event.pointer_type() != "touch" {
+        trace_interaction(
+          InteractionKind::Hover,
+          "hover_start",
+          event_target(&event).as_ref(),
+        );
+        *is_hovered.borrow_mut() = true;
+        update_paused();
+      }
+    }))
+  };
+
+  let on_pointer_leave: ToastCallback<PointerEvent> = {
+    let update_paused = update_paused.clone();
+
+    Rc::new(Box::new(move |event: PointerEvent| {
+      trace_interaction(
+        InteractionKind::Hover,
+        "hover_end",
+        event_target(&event).as_ref(),
+      );
+      *is_hovered.borrow_mut() = false;
+      update_paused();
+    }))
+  };
+
+  let on_focus_in: ToastCallback<FocusEvent> = {
+    let is_focus_within = is_focus_within.clone();
+    let update_paused = update_paused.clone();
+    let context = context.clone();
+    let on_landmark_focus_in = landmark.on_focus_in.clone();
+
+    Rc::new(Box::new(move |event: FocusEvent| {
+      on_landmark_focus_in(event.clone());
+
+      // Remember where focus came from so it can be restored when leaving the
+      // region or when the last toast is closed.
+      if !*is_focus_within.borrow() {
+        let previous = event
+          .related_target()
+          .and_then(|target| target.dyn_into::<Element>().ok());
+        context.set_previous_focus(previous);
+      }
+
+      *is_focus_within.borrow_mut() = true;
+      update_paused();
+    }))
+  };
+
+  let on_focus_out: ToastCallback<FocusEvent> = {
+    let update_paused = update_paused.clone();
+
+    Rc::new(Box::new(move |event: FocusEvent| {
+      let Some(region) = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+      else {
+        return;
+      };
+
+      let related_target: Option<Node> = event
+        .related_target()
+        .and_then(|target| target.dyn_into::<Node>().ok());
+
+      if !region.contains(related_target.as_ref()) {
+        *is_focus_within.borrow_mut() = false;
+        update_paused();
+      }
+    }))
+  };
+
+  provide_context(cx, context);
+
+  let (toast_region_result, _) = create_signal(
+    cx,
+    ToastRegionResult {
+      id,
+      role: "region",
+      aria_label,
+      tab_index: -1,
+      on_pointer_enter,
+      on_pointer_leave,
+      on_focus_in,
+      on_focus_out,
+    },
+  );
+
+  toast_region_result
+}
+
+pub(crate) type ToastCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// The region id and the element which had focus before the region. Provided
+/// to the toasts so they can restore focus when the last toast is closed.
+#[derive(Clone)]
+pub(crate) struct RegionContext {
+  pub(crate) id: String,
+  previous_focus: Rc<RefCell<Option<Element>>>,
+}
+
+impl RegionContext {
+  fn new(id: &str) -> Self {
+    Self {
+      id: id.to_string(),
+      previous_focus: Default::default(),
+    }
+  }
+
+  fn set_previous_focus(&self, element: Option<Element>) {
+    *self.previous_focus.borrow_mut() = element;
+  }
+
+  /// Move focus back to where it was before entering the region.
+  pub(crate) fn restore_focus(&self) {
+    if let Some(element) = self.previous_focus.borrow_mut().take() {
+      FocusableElement::from(element).focus();
+    }
+  }
+}
+
+/// The element handling the event, which is traced with the `tracing`
+/// feature.
+fn event_target(event: &PointerEvent) -> Option<Element> {
+  event
+    .current_target()
+    .and_then(|target| target.dyn_into::<Element>().ok())
+}
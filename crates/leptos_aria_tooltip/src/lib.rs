@@ -0,0 +1,11 @@
+pub use tooltip_trigger_state::*;
+pub use tooltip_warmup::*;
+pub use use_disabled_trigger_wrapper::*;
+pub use use_tooltip::*;
+pub use use_tooltip_trigger::*;
+
+mod tooltip_trigger_state;
+mod tooltip_warmup;
+mod use_disabled_trigger_wrapper;
+mod use_tooltip;
+mod use_tooltip_trigger;
@@ -0,0 +1,154 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::create_rw_signal;
+use leptos::set_timeout;
+use leptos::typed_builder::TypedBuilder;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos::UntrackedSettableSignal;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::use_id;
+use leptos_aria_utils::ControlledState;
+
+use crate::cool_down;
+use crate::is_tooltip_warm;
+use crate::warm_up;
+
+/// The default delay in milliseconds before the tooltip opens on hover.
+pub const TOOLTIP_DELAY: u64 = 1500;
+
+/// The default delay in milliseconds before the tooltip closes.
+pub const TOOLTIP_CLOSE_DELAY: u64 = 500;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseTooltipTriggerStateProps {
+  /// Whether the tooltip is open (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub is_open: Option<MaybeSignal<bool>>,
+
+  /// Whether the tooltip is open by default (uncontrolled).
+  #[builder(default)]
+  pub default_open: bool,
+
+  /// Handler that is called when the open state changes.
+  #[builder(default, setter(strip_option))]
+  pub on_open_change: Option<Box<dyn Fn(bool)>>,
+
+  /// The delay in milliseconds before the tooltip opens on hover.
+  #[builder(default, setter(strip_option))]
+  pub delay: Option<u64>,
+
+  /// The delay in milliseconds before the tooltip closes once the pointer
+  /// leaves the trigger.
+  #[builder(default, setter(strip_option))]
+  pub close_delay: Option<u64>,
+}
+
+/// The open state of a tooltip.
+#[derive(Clone)]
+pub struct TooltipTriggerState {
+  /// The id of the tooltip element which describes the trigger.
+  pub tooltip_id: String,
+  is_open: ControlledState<bool>,
+  delay: u64,
+  close_delay: u64,
+  /// Incremented whenever the pending open or close is cancelled.
+  generation: RwSignal<usize>,
+}
+
+pub fn use_tooltip_trigger_state(
+  cx: Scope,
+  props: UseTooltipTriggerStateProps,
+) -> TooltipTriggerState {
+  TooltipTriggerState {
+    tooltip_id: use_id(cx),
+    is_open: use_controlled_state(cx, props.is_open, props.default_open, props.on_open_change),
+    delay: props.delay.unwrap_or(TOOLTIP_DELAY),
+    close_delay: props.close_delay.unwrap_or(TOOLTIP_CLOSE_DELAY),
+    generation: create_rw_signal(cx, 0),
+  }
+}
+
+impl TooltipTriggerState {
+  /// Whether the tooltip is open.
+  pub fn is_open(&self) -> bool {
+    self.is_open.get()
+  }
+
+  /// The open state as a signal.
+  pub fn is_open_signal(&self) -> Signal<bool> {
+    self.is_open.signal()
+  }
+
+  /// Open the tooltip. Unless `immediate` is set the tooltip opens after the
+  /// configured delay. Once any tooltip has been shown, tooltips open
+  /// instantly until [`TOOLTIP_COOLDOWN`](crate::TOOLTIP_COOLDOWN) has passed
+  /// without a tooltip open.
+  pub fn open(&self, immediate: bool) {
+    let generation = self.cancel_pending();
+
+    if immediate || self.delay == 0 || is_tooltip_warm() {
+      self.show();
+      return;
+    }
+
+    let state = self.clone();
+    set_timeout(
+      move || {
+        if state.generation.get_untracked() == generation {
+          state.show();
+        }
+      },
+      Duration::from_millis(self.delay),
+    );
+  }
+
+  /// Close the tooltip. Unless `immediate` is set the tooltip closes after the
+  /// configured close delay.
+  pub fn close(&self, immediate: bool) {
+    let generation = self.cancel_pending();
+
+    if immediate || self.close_delay == 0 {
+      self.hide();
+      return;
+    }
+
+    let state = self.clone();
+    set_timeout(
+      move || {
+        if state.generation.get_untracked() == generation {
+          state.hide();
+        }
+      },
+      Duration::from_millis(self.close_delay),
+    );
+  }
+
+  fn show(&self) {
+    let state = self.clone();
+    warm_up(
+      &self.tooltip_id,
+      Rc::new(move || {
+        state.cancel_pending();
+        state.is_open.set(false);
+      }),
+    );
+    self.is_open.set(true);
+  }
+
+  fn hide(&self) {
+    cool_down(&self.tooltip_id);
+    self.is_open.set(false);
+  }
+
+  /// Cancel the pending open or close and return the new generation.
+  fn cancel_pending(&self) -> usize {
+    let generation = self.generation.get_untracked() + 1;
+    self.generation.set_untracked(generation);
+    generation
+  }
+}
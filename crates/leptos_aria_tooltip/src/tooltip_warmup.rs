@@ -0,0 +1,87 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::set_timeout;
+
+/// The delay in milliseconds after the last tooltip closes before tooltips
+/// stop opening instantly.
+pub const TOOLTIP_COOLDOWN: u64 = 500;
+
+#[derive(Default)]
+struct TooltipWarmup {
+  /// Whether a tooltip was recently shown, in which case the next tooltip opens
+  /// without a delay.
+  is_warm: bool,
+  /// Incremented to cancel a pending cool down.
+  generation: usize,
+  /// The id and close handler of the open tooltip.
+  open_tooltip: Option<(String, Rc<dyn Fn()>)>,
+}
+
+thread_local! {
+  /// The warm up state shared by every tooltip on the page.
+  static TOOLTIP_WARMUP: RefCell<TooltipWarmup> = RefCell::new(TooltipWarmup::default());
+}
+
+/// Whether tooltips currently open without a delay because another tooltip was
+/// shown recently.
+pub fn is_tooltip_warm() -> bool {
+  TOOLTIP_WARMUP.with(|warmup| warmup.borrow().is_warm)
+}
+
+/// Mark the tooltip as open. Only one tooltip is shown at a time so any other
+/// open tooltip is closed immediately.
+pub(crate) fn warm_up(id: &str, close: Rc<dyn Fn()>) {
+  let previous = TOOLTIP_WARMUP.with(|warmup| {
+    let mut warmup = warmup.borrow_mut();
+    warmup.is_warm = true;
+    warmup.generation += 1;
+    warmup.open_tooltip.replace((id.to_string(), close))
+  });
+
+  // Called outside the borrow since closing updates signals which may run
+  // effects that open another tooltip.
+  if let Some((previous_id, close_previous)) = previous {
+    if previous_id != id {
+      close_previous();
+    }
+  }
+}
+
+/// Mark the tooltip as closed and stop opening tooltips instantly once the
+/// cool down has passed without another tooltip opening.
+pub(crate) fn cool_down(id: &str) {
+  let generation = TOOLTIP_WARMUP.with(|warmup| {
+    let mut warmup = warmup.borrow_mut();
+
+    if warmup
+      .open_tooltip
+      .as_ref()
+      .map_or(true, |(open_id, _)| open_id != id)
+    {
+      return None;
+    }
+
+    warmup.open_tooltip = None;
+    warmup.generation += 1;
+    Some(warmup.generation)
+  });
+
+  let Some(generation) = generation else {
+    return;
+  };
+
+  set_timeout(
+    move || {
+      TOOLTIP_WARMUP.with(|warmup| {
+        let mut warmup = warmup.borrow_mut();
+
+        if warmup.generation == generation {
+          warmup.is_warm = false;
+        }
+      });
+    },
+    Duration::from_millis(TOOLTIP_COOLDOWN),
+  );
+}
@@ -0,0 +1,73 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+
+use crate::use_tooltip_trigger::create_trigger_result;
+use crate::TooltipTrigger;
+use crate::TooltipTriggerResult;
+use crate::TooltipTriggerState;
+
+#[derive(TypedBuilder)]
+pub struct UseDisabledTriggerWrapperProps {
+  /// Whether the wrapped trigger is disabled.
+  #[builder(setter(into))]
+  pub is_disabled: MaybeSignal<bool>,
+
+  /// The interactions which open the tooltip.
+  #[builder(default)]
+  pub trigger: TooltipTrigger,
+}
+
+#[derive(Clone)]
+pub struct DisabledTriggerWrapperResult {
+  /// The wrapper is only focusable while the trigger is disabled so keyboard
+  /// users can still reach the tooltip.
+  pub tab_index: Signal<Option<i32>>,
+  pub aria_disabled: Signal<Option<bool>>,
+  /// Style for the wrapped trigger. Disabled elements swallow pointer events
+  /// so they are disabled on the trigger to let the wrapper receive them.
+  pub trigger_style: Signal<Option<&'static str>>,
+  /// The tooltip trigger props for the wrapper. These are only active while
+  /// the trigger is disabled.
+  pub trigger: TooltipTriggerResult,
+}
+
+/// Allows a tooltip to describe a disabled control.
+///
+/// Disabled elements receive neither pointer nor focus events so their
+/// tooltips never open. Render the disabled control inside a `<span>` using
+/// these props. While the control is disabled the wrapper:
+///
+/// * becomes focusable and is announced with `aria-disabled="true"`,
+/// * opens the tooltip on hover and focus and is described by it,
+/// * disables pointer events on the control through `trigger_style` so the
+///   wrapper receives them.
+///
+/// The control itself should keep using
+/// [`use_tooltip_trigger`](crate::use_tooltip_trigger) with `is_disabled` set
+/// to the same value so only one of them handles the tooltip.
+pub fn use_disabled_trigger_wrapper(
+  cx: Scope,
+  state: &TooltipTriggerState,
+  props: UseDisabledTriggerWrapperProps,
+) -> ReadSignal<DisabledTriggerWrapperResult> {
+  let original_is_disabled = props.is_disabled;
+  let is_disabled = (move || original_is_disabled.get()).derive_signal(cx);
+
+  let (disabled_trigger_wrapper_result, _) = create_signal(
+    cx,
+    DisabledTriggerWrapperResult {
+      tab_index: (move || is_disabled.get().then_some(0)).derive_signal(cx),
+      aria_disabled: (move || is_disabled.get().then_some(true)).derive_signal(cx),
+      trigger_style: (move || is_disabled.get().then_some("pointer-events: none"))
+        .derive_signal(cx),
+      trigger: create_trigger_result(cx, state, is_disabled, props.trigger),
+    },
+  );
+
+  disabled_trigger_wrapper_result
+}
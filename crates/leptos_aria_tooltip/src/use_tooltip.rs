@@ -0,0 +1,25 @@
+use leptos::create_signal;
+use leptos::ReadSignal;
+use leptos::Scope;
+
+use crate::TooltipTriggerState;
+
+#[derive(Clone)]
+pub struct TooltipResult {
+  pub role: &'static str,
+  pub id: String,
+}
+
+/// Provides the accessibility implementation for the tooltip element. The
+/// tooltip should only be rendered while the state is open.
+pub fn use_tooltip(cx: Scope, state: &TooltipTriggerState) -> ReadSignal<TooltipResult> {
+  let (tooltip_result, _) = create_signal(
+    cx,
+    TooltipResult {
+      role: "tooltip",
+      id: state.tooltip_id.clone(),
+    },
+  );
+
+  tooltip_result
+}
@@ -0,0 +1,234 @@
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::Event;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::PointerEvent;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_utils::is_touch_only;
+use leptos_aria_utils::trace_interaction;
+use leptos_aria_utils::use_document_visibility;
+use leptos_aria_utils::use_window_focus;
+use leptos_aria_utils::InteractionKind;
+use leptos_aria_utils::KeyboardKey;
+
+use crate::TooltipTriggerState;
+
+/// The interactions which open the tooltip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TooltipTrigger {
+  /// Open on hover and on keyboard focus.
+  #[default]
+  Hover,
+  /// Only open on keyboard focus.
+  Focus,
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseTooltipTriggerProps {
+  /// Whether the tooltip is disabled. This doesn't need to be set when the
+  /// trigger is disabled and wrapped with `use_disabled_trigger_wrapper`.
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  /// The interactions which open the tooltip.
+  #[builder(default)]
+  pub trigger: TooltipTrigger,
+}
+
+#[derive(Clone)]
+pub struct TooltipTriggerResult {
+  /// The id of the tooltip while it is open.
+  pub aria_describedby: Signal<Option<String>>,
+  pub on_pointer_enter: TooltipCallback<PointerEvent>,
+  pub on_pointer_leave: TooltipCallback<PointerEvent>,
+  pub on_pointer_down: TooltipCallback<PointerEvent>,
+  pub on_focus: TooltipCallback<FocusEvent>,
+  pub on_blur: TooltipCallback<FocusEvent>,
+  pub on_key_down: TooltipCallback<KeyboardEvent>,
+}
+
+/// Provides the behavior for the element which triggers a tooltip. The tooltip
+/// opens when the trigger is hovered or receives visible (keyboard) focus and
+/// closes when the pointer leaves, the trigger is blurred or pressed,
+/// <kbd>Escape</kbd> is pressed, or the tab is hidden or the window loses
+/// focus.
+///
+/// Disabled elements don't receive pointer or focus events, so the tooltip of a
+/// disabled trigger should be attached to a wrapper with
+/// [`use_disabled_trigger_wrapper`](crate::use_disabled_trigger_wrapper).
+pub fn use_tooltip_trigger(
+  cx: Scope,
+  state: &TooltipTriggerState,
+  props: UseTooltipTriggerProps,
+) -> ReadSignal<TooltipTriggerResult> {
+  let original_is_disabled = props.is_disabled.unwrap_or(false.into());
+  let is_enabled = (move || !original_is_disabled.get()).derive_signal(cx);
+  let result = create_trigger_result(cx, state, is_enabled, props.trigger);
+  let (tooltip_trigger_result, _) = create_signal(cx, result);
+
+  tooltip_trigger_result
+}
+
+pub(crate) type TooltipCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// Create the trigger props which are only active while `is_active` is true.
+pub(crate) fn create_trigger_result(
+  cx: Scope,
+  state: &TooltipTriggerState,
+  is_active: Signal<bool>,
+  trigger: TooltipTrigger,
+) -> TooltipTriggerResult {
+  let aria_describedby = {
+    let state = state.clone();
+    (move || (is_active.get() && state.is_open()).then(|| state.tooltip_id.clone()))
+      .derive_signal(cx)
+  };
+
+  // The pointer leave and blur events don't fire when the user switches to
+  // another tab or window, so close the tooltip instead of leaving it open.
+  {
+    let state = state.clone();
+    let is_document_visible = use_document_visibility(cx);
+    let has_window_focus = use_window_focus(cx);
+
+    create_effect(cx, move |_| {
+      let is_page_active = is_document_visible.get() && has_window_focus.get();
+
+      if !is_page_active && state.is_open() {
+        state.close(true);
+      }
+    });
+  }
+
+  let on_pointer_enter: TooltipCallback<PointerEvent> = {
+    let state = state.clone();
+
+    let handler = move |event: PointerEvent| {
+      // Touch devices don't hover and show the tooltip on long press instead.
+      if !is_active.get_untracked()
+        || trigger == TooltipTrigger::Focus
+        || event.pointer_type() == "touch"
+        || is_touch_only()
+      {
+        return;
+      }
+
+      trace_interaction(
+        InteractionKind::Hover,
+        "hover_start",
+        event_target(&event).as_ref(),
+      );
+      state.open(false);
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let on_pointer_leave: TooltipCallback<PointerEvent> = {
+    let state = state.clone();
+    Rc::new(Box::new(move |event: PointerEvent| {
+      trace_interaction(
+        InteractionKind::Hover,
+        "hover_end",
+        event_target(&event).as_ref(),
+      );
+      state.close(false);
+    }))
+  };
+
+  let on_pointer_down: TooltipCallback<PointerEvent> = {
+    let state = state.clone();
+    Rc::new(Box::new(move |_: PointerEvent| state.close(true)))
+  };
+
+  let on_focus: TooltipCallback<FocusEvent> = {
+    let state = state.clone();
+
+    let handler = move |event: FocusEvent| {
+      if is_active.get_untracked() && is_focus_visible(&event) {
+        trace_interaction(
+          InteractionKind::Focus,
+          "focus",
+          event_target(&event).as_ref(),
+        );
+        state.open(true);
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let on_blur: TooltipCallback<FocusEvent> = {
+    let state = state.clone();
+    Rc::new(Box::new(move |event: FocusEvent| {
+      trace_interaction(
+        InteractionKind::Focus,
+        "blur",
+        event_target(&event).as_ref(),
+      );
+      state.close(true);
+    }))
+  };
+
+  let on_key_down: TooltipCallback<KeyboardEvent> = {
+    let state = state.clone();
+
+    let handler = move |event: KeyboardEvent| {
+      if !state.is_open_signal().get_untracked() {
+        return;
+      }
+
+      match KeyboardKey::from(&event) {
+        KeyboardKey::Escape => {
+          event.stop_propagation();
+          state.close(true);
+        }
+        // Pressing the trigger with the keyboard closes the tooltip.
+        KeyboardKey::Enter | KeyboardKey::Space => state.close(true),
+        _ => {}
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  TooltipTriggerResult {
+    aria_describedby,
+    on_pointer_enter,
+    on_pointer_leave,
+    on_pointer_down,
+    on_focus,
+    on_blur,
+    on_key_down,
+  }
+}
+
+/// The element handling the event, which is traced with the `tracing`
+/// feature.
+fn event_target(event: &Event) -> Option<Element> {
+  event
+    .current_target()
+    .and_then(|target| target.dyn_into::<Element>().ok())
+}
+
+/// Whether the focus came from the keyboard rather than a pointer press.
+/// Browsers without `:focus-visible` support are treated as visible.
+fn is_focus_visible(event: &FocusEvent) -> bool {
+  event
+    .target()
+    .and_then(|target| target.dyn_into::<Element>().ok())
+    .map_or(true, |element| {
+      element.matches(":focus-visible").unwrap_or(true)
+    })
+}
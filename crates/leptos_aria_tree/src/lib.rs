@@ -0,0 +1,9 @@
+pub use tree_collection::*;
+pub use tree_state::*;
+pub use use_tree::*;
+pub use use_tree_item::*;
+
+mod tree_collection;
+mod tree_state;
+mod use_tree;
+mod use_tree_item;
@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+
+use leptos_aria_selection::Key;
+
+/// A node within a tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode {
+  pub key: Key,
+
+  /// The text content of the node. Used for typeahead.
+  pub text_value: String,
+
+  /// Secondary text rendered below the label. Not matched by typeahead.
+  pub description: Option<String>,
+
+  /// The keyboard shortcut which activates the node, e.g. `Control+O`.
+  pub keyboard_shortcut: Option<String>,
+
+  pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+  pub fn new(key: impl Into<Key>, text_value: impl Into<String>) -> Self {
+    Self {
+      key: key.into(),
+      text_value: text_value.into(),
+      description: None,
+      keyboard_shortcut: None,
+      children: vec![],
+    }
+  }
+
+  pub fn children(mut self, children: Vec<TreeNode>) -> Self {
+    self.children = children;
+    self
+  }
+
+  pub fn description(mut self, description: impl Into<String>) -> Self {
+    self.description = Some(description.into());
+    self
+  }
+
+  pub fn keyboard_shortcut(mut self, keyboard_shortcut: impl Into<String>) -> Self {
+    self.keyboard_shortcut = Some(keyboard_shortcut.into());
+    self
+  }
+
+  /// Whether the node can be expanded.
+  pub fn has_children(&self) -> bool {
+    !self.children.is_empty()
+  }
+}
+
+/// The position of a node within the tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNodePosition {
+  /// The key of the parent node, `None` for root nodes.
+  pub parent: Option<Key>,
+
+  /// The 1-based depth of the node, used for `aria-level`.
+  pub level: usize,
+
+  /// The 1-based position of the node within its siblings, used for
+  /// `aria-posinset`.
+  pub pos_in_set: usize,
+
+  /// The number of siblings including the node, used for `aria-setsize`.
+  pub set_size: usize,
+}
+
+/// The nodes which make up a tree.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeCollection {
+  pub nodes: Vec<TreeNode>,
+}
+
+impl TreeCollection {
+  pub fn new(nodes: Vec<TreeNode>) -> Self {
+    Self { nodes }
+  }
+
+  /// Find the node with the provided key anywhere in the tree.
+  pub fn node(&self, key: &Key) -> Option<&TreeNode> {
+    fn find<'a>(nodes: &'a [TreeNode], key: &Key) -> Option<&'a TreeNode> {
+      nodes.iter().find_map(|node| {
+        if &node.key == key {
+          Some(node)
+        } else {
+          find(&node.children, key)
+        }
+      })
+    }
+
+    find(&self.nodes, key)
+  }
+
+  /// The position of the node with the provided key.
+  pub fn position(&self, key: &Key) -> Option<TreeNodePosition> {
+    fn find(
+      nodes: &[TreeNode],
+      key: &Key,
+      parent: Option<&Key>,
+      level: usize,
+    ) -> Option<TreeNodePosition> {
+      nodes.iter().enumerate().find_map(|(index, node)| {
+        if &node.key == key {
+          return Some(TreeNodePosition {
+            parent: parent.cloned(),
+            level,
+            pos_in_set: index + 1,
+            set_size: nodes.len(),
+          });
+        }
+
+        find(&node.children, key, Some(&node.key), level + 1)
+      })
+    }
+
+    find(&self.nodes, key, None, 1)
+  }
+
+  /// The keys of all nodes which have children.
+  pub fn parent_keys(&self) -> HashSet<Key> {
+    fn collect(nodes: &[TreeNode], keys: &mut HashSet<Key>) {
+      for node in nodes.iter().filter(|node| node.has_children()) {
+        keys.insert(node.key.clone());
+        collect(&node.children, keys);
+      }
+    }
+
+    let mut keys = HashSet::new();
+    collect(&self.nodes, &mut keys);
+    keys
+  }
+
+  /// The keys of the siblings of the node, including the node itself.
+  pub fn sibling_keys(&self, key: &Key) -> Vec<Key> {
+    let siblings = match self.position(key).and_then(|position| position.parent) {
+      Some(parent) => self.node(&parent).map(|node| node.children.as_slice()),
+      None => Some(self.nodes.as_slice()),
+    };
+
+    siblings
+      .unwrap_or_default()
+      .iter()
+      .map(|node| node.key.clone())
+      .collect()
+  }
+
+  /// The nodes which are currently visible in display order. Children are only
+  /// visible when all of their ancestors are expanded.
+  pub fn visible_nodes(&self, expanded_keys: &HashSet<Key>) -> Vec<&TreeNode> {
+    fn collect<'a>(
+      nodes: &'a [TreeNode],
+      expanded_keys: &HashSet<Key>,
+      visible: &mut Vec<&'a TreeNode>,
+    ) {
+      for node in nodes {
+        visible.push(node);
+
+        if expanded_keys.contains(&node.key) {
+          collect(&node.children, expanded_keys, visible);
+        }
+      }
+    }
+
+    let mut visible = vec![];
+    collect(&self.nodes, expanded_keys, &mut visible);
+    visible
+  }
+
+  /// The keys of the visible nodes in display order.
+  pub fn visible_keys(&self, expanded_keys: &HashSet<Key>) -> Vec<Key> {
+    self
+      .visible_nodes(expanded_keys)
+      .into_iter()
+      .map(|node| node.key.clone())
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn collection() -> TreeCollection {
+    TreeCollection::new(vec![
+      TreeNode::new("documents", "Documents").children(vec![
+        TreeNode::new("project", "Project").children(vec![TreeNode::new("report", "Report")]),
+        TreeNode::new("notes", "Notes"),
+      ]),
+      TreeNode::new("photos", "Photos"),
+    ])
+  }
+
+  #[test]
+  fn positions_describe_the_tree() {
+    let collection = collection();
+
+    assert_eq!(
+      collection.position(&"notes".into()),
+      Some(TreeNodePosition {
+        parent: Some("documents".into()),
+        level: 2,
+        pos_in_set: 2,
+        set_size: 2,
+      })
+    );
+    assert_eq!(collection.position(&"report".into()).unwrap().level, 3);
+    assert_eq!(collection.position(&"missing".into()), None);
+  }
+
+  #[test]
+  fn only_expanded_children_are_visible() {
+    let collection = collection();
+    let keys = |expanded: &[&str]| {
+      let expanded = expanded.iter().map(|key| Key::from(*key)).collect();
+      collection.visible_keys(&expanded)
+    };
+
+    assert_eq!(keys(&[]), vec![Key::from("documents"), Key::from("photos")]);
+    assert_eq!(
+      keys(&["project"]),
+      vec![Key::from("documents"), Key::from("photos")]
+    );
+    assert_eq!(
+      keys(&["documents", "project"]),
+      vec![
+        Key::from("documents"),
+        Key::from("project"),
+        Key::from("report"),
+        Key::from("notes"),
+        Key::from("photos"),
+      ]
+    );
+  }
+}
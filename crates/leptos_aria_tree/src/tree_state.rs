@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_selection::use_multiple_selection_state;
+use leptos_aria_selection::Key;
+use leptos_aria_selection::SelectionManager;
+use leptos_aria_selection::UseMultipleSelectionStateProps;
+use leptos_aria_utils::use_controlled_state;
+use leptos_aria_utils::ControlledState;
+use leptos_aria_virtualizer::Virtualizer;
+
+use crate::TreeCollection;
+
+#[derive(TypedBuilder)]
+pub struct UseTreeStateProps {
+  /// The nodes of the tree.
+  #[builder(setter(into))]
+  pub collection: MaybeSignal<TreeCollection>,
+
+  /// The currently expanded keys (controlled).
+  #[builder(default, setter(strip_option, into))]
+  pub expanded_keys: Option<MaybeSignal<HashSet<Key>>>,
+
+  /// The initial expanded keys (uncontrolled).
+  #[builder(default)]
+  pub default_expanded_keys: HashSet<Key>,
+
+  /// Handler that is called when nodes are expanded or collapsed.
+  #[builder(default, setter(strip_option))]
+  pub on_expanded_change: Option<Box<dyn Fn(HashSet<Key>)>>,
+
+  /// The selection props of the tree.
+  #[builder(default)]
+  pub selection: UseMultipleSelectionStateProps,
+
+  /// The virtualizer which renders a window of the visible nodes.
+  #[builder(default, setter(strip_option))]
+  pub virtualizer: Option<Virtualizer>,
+}
+
+/// The state of a tree shared between the tree hooks.
+#[derive(Clone)]
+pub struct TreeState {
+  pub collection: Signal<TreeCollection>,
+  pub selection_manager: SelectionManager,
+  pub virtualizer: Option<Virtualizer>,
+  expanded_keys: ControlledState<HashSet<Key>>,
+}
+
+pub fn use_tree_state(cx: Scope, props: UseTreeStateProps) -> TreeState {
+  let original_collection = props.collection;
+  let collection = (move || original_collection.get()).derive_signal(cx);
+
+  TreeState {
+    collection,
+    selection_manager: use_multiple_selection_state(cx, props.selection),
+    virtualizer: props.virtualizer,
+    expanded_keys: use_controlled_state(
+      cx,
+      props.expanded_keys,
+      props.default_expanded_keys,
+      props.on_expanded_change,
+    ),
+  }
+}
+
+impl TreeState {
+  /// The keys of the expanded nodes.
+  pub fn expanded_keys(&self) -> HashSet<Key> {
+    self.expanded_keys.get()
+  }
+
+  pub fn expanded_keys_untracked(&self) -> HashSet<Key> {
+    self.expanded_keys.get_untracked()
+  }
+
+  /// Whether the node with the provided key is expanded.
+  pub fn is_expanded(&self, key: &Key) -> bool {
+    self.expanded_keys.signal().with(|keys| keys.contains(key))
+  }
+
+  pub fn set_expanded_keys(&self, keys: HashSet<Key>) {
+    self.expanded_keys.set(keys);
+  }
+
+  /// Expand the node if it has children.
+  pub fn expand(&self, key: &Key) {
+    let has_children = self.collection.with(|collection| {
+      collection
+        .node(key)
+        .map_or(false, |node| node.has_children())
+    });
+
+    if !has_children {
+      return;
+    }
+
+    let mut keys = self.expanded_keys.get_untracked();
+
+    if keys.insert(key.clone()) {
+      self.expanded_keys.set(keys);
+    }
+  }
+
+  pub fn collapse(&self, key: &Key) {
+    let mut keys = self.expanded_keys.get_untracked();
+
+    if keys.remove(key) {
+      self.expanded_keys.set(keys);
+    }
+  }
+
+  /// Toggle the expansion of the node.
+  pub fn toggle_key(&self, key: &Key) {
+    if self.expanded_keys.get_untracked().contains(key) {
+      self.collapse(key);
+    } else {
+      self.expand(key);
+    }
+  }
+
+  /// The keys of the visible nodes in display order.
+  pub fn visible_keys(&self) -> Vec<Key> {
+    let expanded_keys = self.expanded_keys.get();
+    self
+      .collection
+      .with(|collection| collection.visible_keys(&expanded_keys))
+  }
+
+  pub fn visible_keys_untracked(&self) -> Vec<Key> {
+    let expanded_keys = self.expanded_keys.get_untracked();
+    self.collection.get_untracked().visible_keys(&expanded_keys)
+  }
+
+  /// Whether the node is the tab stop of the tree. This is the focused node,
+  /// or the first visible node when nothing has been focused yet.
+  pub fn is_tab_stop(&self, key: &Key) -> bool {
+    match self.selection_manager.focused_key() {
+      Some(focused_key) => &focused_key == key,
+      None => {
+        self
+          .collection
+          .with(|collection| collection.nodes.first().map(|node| &node.key == key))
+          .unwrap_or(false)
+      }
+    }
+  }
+}
+
+/// Shared callback type for the tree hooks.
+pub(crate) type TreeCallback<E> = Rc<Box<dyn Fn(E)>>;
@@ -0,0 +1,303 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::KeyboardEvent;
+use leptos::web_sys::Node;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_selection::find_element_by_key;
+use leptos_aria_selection::get_closest_key;
+use leptos_aria_selection::use_type_select;
+use leptos_aria_selection::Key;
+use leptos_aria_selection::Reorder;
+use leptos_aria_selection::SelectionMode;
+use leptos_aria_selection::DATA_KEY_ATTRIBUTE;
+use leptos_aria_utils::use_direction;
+use leptos_aria_utils::FocusableElement;
+use leptos_aria_utils::KeyboardKey;
+use leptos_aria_virtualizer::focus_when_mounted;
+
+use crate::tree_state::TreeCallback;
+use crate::TreeState;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseTreeProps {
+  /// An accessible label for the tree.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  /// The id of the element which labels the tree.
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+
+  /// Allows nodes to be reordered within their siblings with the keyboard.
+  #[builder(default, setter(strip_option))]
+  pub reorder: Option<Reorder>,
+}
+
+#[derive(Clone)]
+pub struct TreeResult {
+  pub role: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+  pub aria_multiselectable: Signal<Option<bool>>,
+  pub on_key_down: TreeCallback<KeyboardEvent>,
+  pub on_focus_in: TreeCallback<FocusEvent>,
+  pub on_focus_out: TreeCallback<FocusEvent>,
+}
+
+/// Provides the behavior and accessibility implementation for a tree view
+/// following the ARIA treeview pattern.
+///
+/// * <kbd>ArrowDown</kbd> and <kbd>ArrowUp</kbd> move between the visible
+///   nodes.
+/// * <kbd>ArrowRight</kbd> expands a collapsed node, or moves to the first
+///   child of an expanded node.
+/// * <kbd>ArrowLeft</kbd> collapses an expanded node, or moves to the parent
+///   node.
+/// * <kbd>ArrowLeft</kbd> and <kbd>ArrowRight</kbd> are swapped in right to
+///   left locales, where nested nodes are indented from the right.
+/// * <kbd>Home</kbd> and <kbd>End</kbd> move to the first and last visible
+///   nodes.
+/// * <kbd>*</kbd> expands all the siblings of the focused node.
+/// * Typing moves focus to the visible node matching the typed text.
+/// * <kbd>Shift</kbd> with the arrow keys extends the selection when multiple
+///   selection is enabled and <kbd>Ctrl</kbd> + <kbd>A</kbd> selects all
+///   visible nodes.
+///
+/// The props returned should be added to the element with `role="tree"`. Each
+/// node is set up with [`use_tree_item`](crate::use_tree_item).
+pub fn use_tree(cx: Scope, state: &TreeState, props: UseTreeProps) -> ReadSignal<TreeResult> {
+  let type_select = use_type_select(cx);
+  let direction = use_direction(cx);
+
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+  let aria_multiselectable = {
+    let selection_mode = state.selection_manager.selection_mode;
+    (move || (selection_mode.get() == SelectionMode::Multiple).then_some(true)).derive_signal(cx)
+  };
+
+  let on_key_down: TreeCallback<KeyboardEvent> = {
+    let state = state.clone();
+    let reorder = props.reorder;
+
+    let handler = move |event: KeyboardEvent| {
+      let Some(tree) = event
+        .current_target()
+        .map(|target| target.unchecked_into::<Element>())
+      else {
+        return;
+      };
+
+      let collection = state.collection.get_untracked();
+      let expanded_keys = state.expanded_keys_untracked();
+      let visible_keys = collection.visible_keys(&expanded_keys);
+      let manager = &state.selection_manager;
+      let Some(focused_key) = manager
+        .focused_key_untracked()
+        .or_else(|| visible_keys.first().cloned())
+      else {
+        return;
+      };
+
+      if let Some(ref reorder) = reorder {
+        let siblings = collection.sibling_keys(&focused_key);
+        let items = siblings.iter().filter_map(|key| {
+          collection
+            .node(key)
+            .map(|node| (&node.key, node.text_value.as_str()))
+        });
+
+        if reorder.handle_key_down(&event, &focused_key, items) {
+          // The node element may be recreated when it moves.
+          focus_when_mounted(move || find_element_by_key(&tree, DATA_KEY_ATTRIBUTE, &focused_key));
+          return;
+        }
+      }
+
+      let position = visible_keys.iter().position(|key| key == &focused_key);
+      let ctrl_key = event.ctrl_key() || event.meta_key();
+      let is_multiple = manager.selection_mode.get_untracked() == SelectionMode::Multiple;
+
+      let key = KeyboardKey::from(&event).logical(direction.get_untracked());
+      let next = match key {
+        KeyboardKey::ArrowDown => {
+          position
+            .and_then(|position| visible_keys.get(position + 1))
+            .cloned()
+        }
+        KeyboardKey::ArrowUp => {
+          position
+            .and_then(|position| position.checked_sub(1))
+            .and_then(|position| visible_keys.get(position))
+            .cloned()
+        }
+        KeyboardKey::ArrowRight => {
+          let Some(node) = collection.node(&focused_key) else {
+            return;
+          };
+
+          if !node.has_children() {
+            None
+          } else if expanded_keys.contains(&focused_key) {
+            node.children.first().map(|child| child.key.clone())
+          } else {
+            event.prevent_default();
+            state.expand(&focused_key);
+            return;
+          }
+        }
+        KeyboardKey::ArrowLeft => {
+          if expanded_keys.contains(&focused_key) {
+            event.prevent_default();
+            state.collapse(&focused_key);
+            return;
+          }
+
+          collection
+            .position(&focused_key)
+            .and_then(|position| position.parent)
+        }
+        KeyboardKey::Home => visible_keys.first().cloned(),
+        KeyboardKey::End => visible_keys.last().cloned(),
+        KeyboardKey::Character(character) if character == "*" => {
+          event.prevent_default();
+
+          for key in collection.sibling_keys(&focused_key) {
+            state.expand(&key);
+          }
+
+          return;
+        }
+        KeyboardKey::Character(character)
+          if character.eq_ignore_ascii_case("a") && ctrl_key && is_multiple =>
+        {
+          event.prevent_default();
+          manager.select_all(visible_keys);
+          return;
+        }
+        KeyboardKey::Escape if !manager.selected_keys_untracked().is_empty() => {
+          event.prevent_default();
+          manager.clear_selection();
+          return;
+        }
+        _ => {
+          let Some(character) = type_select.get_character(&event) else {
+            return;
+          };
+
+          let items = collection
+            .visible_nodes(&expanded_keys)
+            .into_iter()
+            .map(|node| (&node.key, node.text_value.as_str()))
+            .collect::<Vec<_>>();
+
+          type_select.search(&character, items, Some(&focused_key))
+        }
+      };
+
+      event.prevent_default();
+
+      let Some(next) = next else {
+        return;
+      };
+
+      manager.select_on_focus_move(&next, event.shift_key(), ctrl_key, &visible_keys);
+
+      match state.virtualizer {
+        Some(ref virtualizer) => {
+          virtualizer.scroll_to_key(&next, &visible_keys);
+
+          let key = next.clone();
+          focus_when_mounted(move || find_element_by_key(&tree, DATA_KEY_ATTRIBUTE, &key));
+        }
+        None => focus_tree_item(&tree, &next),
+      }
+
+      manager.set_focused_key(Some(next));
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let on_focus_in: TreeCallback<FocusEvent> = {
+    let selection_manager = state.selection_manager.clone();
+
+    let handler = move |event: FocusEvent| {
+      let Some(target) = event
+        .target()
+        .map(|target| target.unchecked_into::<Element>())
+      else {
+        return;
+      };
+
+      selection_manager.set_focused(true);
+
+      if let Some(key) = get_closest_key(&target, DATA_KEY_ATTRIBUTE) {
+        selection_manager.set_focused_key(Some(key));
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let on_focus_out: TreeCallback<FocusEvent> = {
+    let selection_manager = state.selection_manager.clone();
+
+    let handler = move |event: FocusEvent| {
+      let Some(tree) = event
+        .current_target()
+        .map(|target| target.unchecked_into::<Element>())
+      else {
+        return;
+      };
+
+      let related_target: Option<Node> = event
+        .related_target()
+        .and_then(|target| target.dyn_into::<Node>().ok());
+
+      if !tree.contains(related_target.as_ref()) {
+        selection_manager.set_focused(false);
+      }
+    };
+
+    Rc::new(Box::new(handler))
+  };
+
+  let (tree_result, _) = create_signal(
+    cx,
+    TreeResult {
+      role: "tree",
+      aria_label,
+      aria_labelledby,
+      aria_multiselectable,
+      on_key_down,
+      on_focus_in,
+      on_focus_out,
+    },
+  );
+
+  tree_result
+}
+
+/// Move DOM focus to the tree item with the provided key.
+fn focus_tree_item(tree: &Element, key: &Key) {
+  if let Some(element) = find_element_by_key(tree, DATA_KEY_ATTRIBUTE, key) {
+    FocusableElement::from(element).focus();
+  }
+}
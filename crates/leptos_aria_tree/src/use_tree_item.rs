@@ -0,0 +1,168 @@
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_interactions::use_press;
+use leptos_aria_interactions::PressEvent;
+use leptos_aria_interactions::PressResult;
+use leptos_aria_interactions::UsePressProps;
+use leptos_aria_selection::create_item_slots;
+use leptos_aria_selection::ItemSlots;
+use leptos_aria_selection::ItemSlotsOptions;
+use leptos_aria_selection::Key;
+use leptos_aria_selection::SelectionMode;
+use leptos_aria_utils::use_id;
+
+use crate::TreeState;
+
+#[derive(TypedBuilder)]
+pub struct UseTreeItemProps {
+  /// The key of the node.
+  #[builder(setter(into))]
+  pub key: Key,
+}
+
+#[derive(Clone)]
+pub struct TreeItemResult {
+  pub id: String,
+  pub role: &'static str,
+  /// The value for the `data-key` attribute which is used to find the node
+  /// when navigating with the keyboard.
+  pub data_key: String,
+  /// Only set for nodes with children.
+  pub aria_expanded: Signal<Option<bool>>,
+  pub aria_level: Signal<Option<usize>>,
+  pub aria_posinset: Signal<Option<usize>>,
+  pub aria_setsize: Signal<Option<usize>>,
+  pub aria_selected: Signal<Option<bool>>,
+  pub aria_disabled: Signal<Option<bool>>,
+  pub tab_index: Signal<i32>,
+  /// The press props which select the node. These should be added to the
+  /// node element.
+  pub press: ReadSignal<PressResult>,
+  /// The label, description and keyboard shortcut hint of the node.
+  pub slots: ItemSlots,
+}
+
+/// Provides the behavior and accessibility implementation for a node in a tree
+/// view.
+///
+/// The children of an expanded node should be rendered inside an element with
+/// `role="group"` nested within the node element.
+pub fn use_tree_item(
+  cx: Scope,
+  state: &TreeState,
+  props: UseTreeItemProps,
+) -> ReadSignal<TreeItemResult> {
+  let key = props.key;
+  let collection = state.collection;
+  let selection_manager = state.selection_manager.clone();
+  let id = use_id(cx);
+  let slots = collection.with_untracked(|collection| {
+    let options = collection
+      .node(&key)
+      .map(|node| {
+        ItemSlotsOptions {
+          has_description: node.description.is_some(),
+          keyboard_shortcut: node.keyboard_shortcut.clone(),
+        }
+      })
+      .unwrap_or_default();
+
+    create_item_slots(&id, options)
+  });
+
+  let aria_expanded = {
+    let state = state.clone();
+    let key = key.clone();
+
+    (move || {
+      let has_children = collection.with(|collection| {
+        collection
+          .node(&key)
+          .map_or(false, |node| node.has_children())
+      });
+      has_children.then(|| state.is_expanded(&key))
+    })
+    .derive_signal(cx)
+  };
+
+  let position = {
+    let key = key.clone();
+    move || collection.with(|collection| collection.position(&key))
+  };
+  let aria_level = {
+    let position = position.clone();
+    (move || position().map(|position| position.level)).derive_signal(cx)
+  };
+  let aria_posinset = {
+    let position = position.clone();
+    (move || position().map(|position| position.pos_in_set)).derive_signal(cx)
+  };
+  let aria_setsize = (move || position().map(|position| position.set_size)).derive_signal(cx);
+
+  let aria_selected = {
+    let selection_manager = selection_manager.clone();
+    let key = key.clone();
+
+    (move || {
+      (selection_manager.selection_mode.get() != SelectionMode::None)
+        .then(|| selection_manager.is_selected(&key))
+    })
+    .derive_signal(cx)
+  };
+
+  let is_disabled = {
+    let key = key.clone();
+    (move || selection_manager.is_disabled(&key)).derive_signal(cx)
+  };
+  let aria_disabled = (move || is_disabled.get().then_some(true)).derive_signal(cx);
+
+  let tab_index = {
+    let state = state.clone();
+    let key = key.clone();
+    (move || if state.is_tab_stop(&key) { 0 } else { -1 }).derive_signal(cx)
+  };
+
+  let on_press = {
+    let state = state.clone();
+    let key = key.clone();
+
+    move |event: &PressEvent| {
+      state
+        .selection_manager
+        .select(&key, event, &state.visible_keys_untracked());
+    }
+  };
+
+  let press = use_press(
+    cx,
+    UsePressProps::builder()
+      .on_press(Box::new(on_press))
+      .is_disabled(is_disabled)
+      .build(),
+  );
+
+  let (tree_item_result, _) = create_signal(
+    cx,
+    TreeItemResult {
+      id,
+      role: "treeitem",
+      data_key: key.to_string(),
+      aria_expanded,
+      aria_level,
+      aria_posinset,
+      aria_setsize,
+      aria_selected,
+      aria_disabled,
+      tab_index,
+      press,
+      slots,
+    },
+  );
+
+  tree_item_result
+}
@@ -0,0 +1,178 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::js_sys::Date;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::Scope;
+
+use crate::announce;
+use crate::debounce;
+use crate::Politeness;
+
+/// How rapid announcements are combined before they reach the live region.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypedBuilder)]
+pub struct AnnouncerBatching {
+  /// Messages arriving within this many milliseconds of each other are
+  /// announced together.
+  #[builder(default = 50)]
+  pub merge_window: u64,
+
+  /// The maximum number of messages announced together. The oldest messages
+  /// are dropped first.
+  #[builder(default = 3)]
+  pub max_queue_length: usize,
+
+  /// A message identical to one announced within this many milliseconds is
+  /// dropped.
+  #[builder(default = 1000)]
+  pub duplicate_window: u64,
+}
+
+impl Default for AnnouncerBatching {
+  fn default() -> Self {
+    Self::builder().build()
+  }
+}
+
+/// The pending messages of a batched announcer.
+#[derive(Clone, Debug, Default)]
+pub struct AnnouncementQueue {
+  batching: AnnouncerBatching,
+  pending: VecDeque<String>,
+  /// The recently announced messages and when they were announced.
+  recent: VecDeque<(String, f64)>,
+}
+
+impl AnnouncementQueue {
+  pub fn new(batching: AnnouncerBatching) -> Self {
+    Self {
+      batching,
+      ..Default::default()
+    }
+  }
+
+  /// Add a message to the queue. Returns `false` when the message is dropped
+  /// as a duplicate.
+  pub fn push(&mut self, message: String, now: f64) -> bool {
+    let duplicate_window = self.batching.duplicate_window as f64;
+    self
+      .recent
+      .retain(|(_, announced_at)| now - announced_at < duplicate_window);
+
+    let is_duplicate =
+      self.pending.contains(&message) || self.recent.iter().any(|(recent, _)| recent == &message);
+
+    if message.is_empty() || is_duplicate {
+      return false;
+    }
+
+    self.pending.push_back(message);
+
+    while self.pending.len() > self.batching.max_queue_length.max(1) {
+      self.pending.pop_front();
+    }
+
+    true
+  }
+
+  /// Take the pending messages as a single announcement.
+  pub fn flush(&mut self, now: f64) -> Option<String> {
+    if self.pending.is_empty() {
+      return None;
+    }
+
+    let messages = self.pending.drain(..).collect::<Vec<_>>();
+    let announcement = messages.join(" ");
+
+    self
+      .recent
+      .extend(messages.into_iter().map(|message| (message, now)));
+
+    Some(announcement)
+  }
+
+  pub fn clear(&mut self) {
+    self.pending.clear();
+  }
+}
+
+/// Create a polite announcer which merges messages arriving in quick
+/// succession, caps how many are announced together and drops duplicates.
+///
+/// Use this for announcements triggered while typing, such as the number of
+/// results in a filtered combobox, which would otherwise flood screen readers.
+/// Pending messages are discarded when the scope is cleaned up.
+pub fn use_announce_polite_batching(cx: Scope, batching: AnnouncerBatching) -> Rc<dyn Fn(String)> {
+  let queue = Rc::new(RefCell::new(AnnouncementQueue::new(batching)));
+
+  on_cleanup(cx, {
+    let queue = queue.clone();
+    move || queue.borrow_mut().clear()
+  });
+
+  // Every new message restarts the merge window.
+  let flush = {
+    let queue = queue.clone();
+
+    debounce(
+      cx,
+      Duration::from_millis(batching.merge_window),
+      move |()| {
+        let announcement = queue.borrow_mut().flush(Date::now());
+
+        if let Some(announcement) = announcement {
+          announce(announcement, Politeness::Polite);
+        }
+      },
+    )
+  };
+
+  Rc::new(move |message: String| {
+    if queue.borrow_mut().push(message, Date::now()) {
+      flush(());
+    }
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn merges_pending_messages() {
+    let mut queue = AnnouncementQueue::new(AnnouncerBatching::default());
+    queue.push("2 results".into(), 0.0);
+    queue.push("1 result".into(), 10.0);
+
+    assert_eq!(queue.flush(60.0), Some("2 results 1 result".into()));
+    assert_eq!(queue.flush(70.0), None);
+  }
+
+  #[test]
+  fn caps_the_queue_length() {
+    let mut queue =
+      AnnouncementQueue::new(AnnouncerBatching::builder().max_queue_length(2).build());
+
+    for message in ["a", "b", "c"] {
+      queue.push(message.into(), 0.0);
+    }
+
+    assert_eq!(queue.flush(0.0), Some("b c".into()));
+  }
+
+  #[test]
+  fn drops_recent_duplicates() {
+    let mut queue = AnnouncementQueue::new(AnnouncerBatching::default());
+    queue.push("5 results".into(), 0.0);
+
+    assert!(!queue.push("5 results".into(), 10.0));
+
+    queue.flush(50.0);
+
+    assert!(!queue.push("5 results".into(), 500.0));
+    assert!(queue.push("5 results".into(), 1100.0));
+  }
+}
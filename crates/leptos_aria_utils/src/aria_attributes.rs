@@ -0,0 +1,410 @@
+use std::fmt;
+
+use leptos::typed_builder::TypedBuilder;
+use leptos::Attribute;
+use leptos::IntoAttribute;
+use leptos::Scope;
+
+use crate::resolve_id;
+use crate::Politeness;
+
+/// The WAI-ARIA roles of the elements the hooks describe.
+///
+/// Use it directly as an attribute value, e.g. `role=Role::Button`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+  Alert,
+  AlertDialog,
+  Application,
+  Banner,
+  Button,
+  Cell,
+  Checkbox,
+  ColumnHeader,
+  Combobox,
+  Complementary,
+  ContentInfo,
+  Dialog,
+  Form,
+  Grid,
+  GridCell,
+  Group,
+  Heading,
+  Img,
+  Link,
+  List,
+  ListBox,
+  ListItem,
+  Log,
+  Main,
+  Menu,
+  MenuBar,
+  MenuItem,
+  MenuItemCheckbox,
+  MenuItemRadio,
+  Meter,
+  Navigation,
+  /// An option of a listbox.
+  Option,
+  Presentation,
+  ProgressBar,
+  Radio,
+  RadioGroup,
+  Region,
+  Row,
+  RowGroup,
+  RowHeader,
+  Search,
+  SearchBox,
+  Separator,
+  Slider,
+  SpinButton,
+  Status,
+  Switch,
+  Tab,
+  Table,
+  TabList,
+  TabPanel,
+  TextBox,
+  Timer,
+  Toolbar,
+  Tooltip,
+  Tree,
+  TreeGrid,
+  TreeItem,
+}
+
+impl Role {
+  /// The value of the `role` attribute.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Alert => "alert",
+      Self::AlertDialog => "alertdialog",
+      Self::Application => "application",
+      Self::Banner => "banner",
+      Self::Button => "button",
+      Self::Cell => "cell",
+      Self::Checkbox => "checkbox",
+      Self::ColumnHeader => "columnheader",
+      Self::Combobox => "combobox",
+      Self::Complementary => "complementary",
+      Self::ContentInfo => "contentinfo",
+      Self::Dialog => "dialog",
+      Self::Form => "form",
+      Self::Grid => "grid",
+      Self::GridCell => "gridcell",
+      Self::Group => "group",
+      Self::Heading => "heading",
+      Self::Img => "img",
+      Self::Link => "link",
+      Self::List => "list",
+      Self::ListBox => "listbox",
+      Self::ListItem => "listitem",
+      Self::Log => "log",
+      Self::Main => "main",
+      Self::Menu => "menu",
+      Self::MenuBar => "menubar",
+      Self::MenuItem => "menuitem",
+      Self::MenuItemCheckbox => "menuitemcheckbox",
+      Self::MenuItemRadio => "menuitemradio",
+      Self::Meter => "meter",
+      Self::Navigation => "navigation",
+      Self::Option => "option",
+      Self::Presentation => "presentation",
+      Self::ProgressBar => "progressbar",
+      Self::Radio => "radio",
+      Self::RadioGroup => "radiogroup",
+      Self::Region => "region",
+      Self::Row => "row",
+      Self::RowGroup => "rowgroup",
+      Self::RowHeader => "rowheader",
+      Self::Search => "search",
+      Self::SearchBox => "searchbox",
+      Self::Separator => "separator",
+      Self::Slider => "slider",
+      Self::SpinButton => "spinbutton",
+      Self::Status => "status",
+      Self::Switch => "switch",
+      Self::Tab => "tab",
+      Self::Table => "table",
+      Self::TabList => "tablist",
+      Self::TabPanel => "tabpanel",
+      Self::TextBox => "textbox",
+      Self::Timer => "timer",
+      Self::Toolbar => "toolbar",
+      Self::Tooltip => "tooltip",
+      Self::Tree => "tree",
+      Self::TreeGrid => "treegrid",
+      Self::TreeItem => "treeitem",
+    }
+  }
+}
+
+impl fmt::Display for Role {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl IntoAttribute for Role {
+  fn into_attribute(self, cx: Scope) -> Attribute {
+    self.as_str().to_string().into_attribute(cx)
+  }
+}
+
+/// The value of `aria-checked` and `aria-pressed`, which are `mixed` when
+/// only some of the controlled items are checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Tristate {
+  #[default]
+  False,
+  True,
+  Mixed,
+}
+
+impl Tristate {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::False => "false",
+      Self::True => "true",
+      Self::Mixed => "mixed",
+    }
+  }
+}
+
+impl From<bool> for Tristate {
+  fn from(value: bool) -> Self {
+    if value {
+      Self::True
+    } else {
+      Self::False
+    }
+  }
+}
+
+impl fmt::Display for Tristate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Rendered as `"true"`, `"false"` or `"mixed"`. Unlike a `bool`, which
+/// leptos renders by adding or removing the attribute, `"false"` is kept.
+impl IntoAttribute for Tristate {
+  fn into_attribute(self, cx: Scope) -> Attribute {
+    self.as_str().to_string().into_attribute(cx)
+  }
+}
+
+/// A list of element ids for attributes like `aria-labelledby` and
+/// `aria-describedby`, without duplicates.
+///
+/// Ids which were replaced by [`merge_ids`](crate::merge_ids) are resolved
+/// to the id the element is rendered with when the list is rendered, or with
+/// [`resolved`](Self::resolved). An empty list renders no attribute.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IdList(Vec<String>);
+
+impl IdList {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Add the ids of a space separated list, skipping the ones already in the
+  /// list.
+  pub fn push(&mut self, ids: impl AsRef<str>) {
+    for id in ids.as_ref().split_whitespace() {
+      if !self.contains(id) {
+        self.0.push(id.to_string());
+      }
+    }
+  }
+
+  /// The list with the ids which were replaced by
+  /// [`merge_ids`](crate::merge_ids) resolved to the id the element is
+  /// rendered with.
+  pub fn resolved(&self, cx: Scope) -> Self {
+    self.iter().map(|id| resolve_id(cx, id)).collect()
+  }
+
+  /// Add the ids and return the list, e.g. to chain optional ids.
+  pub fn with(mut self, ids: Option<impl AsRef<str>>) -> Self {
+    if let Some(ids) = ids {
+      self.push(ids);
+    }
+
+    self
+  }
+
+  pub fn contains(&self, id: &str) -> bool {
+    self.0.iter().any(|other| other == id)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &str> {
+    self.0.iter().map(String::as_str)
+  }
+
+  /// The value of the attribute, or `None` when the list is empty.
+  pub fn to_value(&self) -> Option<String> {
+    (!self.is_empty()).then(|| self.0.join(" "))
+  }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for IdList {
+  fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+    let mut list = Self::new();
+    list.extend(iter);
+    list
+  }
+}
+
+impl<S: AsRef<str>> Extend<S> for IdList {
+  fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
+    for ids in iter {
+      self.push(ids);
+    }
+  }
+}
+
+impl From<&str> for IdList {
+  fn from(ids: &str) -> Self {
+    Self::from_iter([ids])
+  }
+}
+
+impl From<String> for IdList {
+  fn from(ids: String) -> Self {
+    Self::from_iter([ids])
+  }
+}
+
+impl IntoAttribute for IdList {
+  fn into_attribute(self, cx: Scope) -> Attribute {
+    self.resolved(cx).to_value().into_attribute(cx)
+  }
+}
+
+/// The common ARIA attributes of an element, for hooks and components which
+/// build them up from several sources before rendering.
+///
+/// ```ignore
+/// let attributes = AriaAttributes::builder()
+///   .role(Role::Checkbox)
+///   .checked(Tristate::Mixed)
+///   .labelledby(IdList::from(label_id.as_str()))
+///   .build();
+///
+/// for (name, value) in attributes.to_attributes() {
+///   element.set_attribute(name, &value).unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, TypedBuilder)]
+pub struct AriaAttributes {
+  #[builder(default, setter(strip_option))]
+  pub role: Option<Role>,
+  #[builder(default, setter(strip_option, into))]
+  pub label: Option<String>,
+  #[builder(default, setter(into))]
+  pub labelledby: IdList,
+  #[builder(default, setter(into))]
+  pub describedby: IdList,
+  #[builder(default, setter(into))]
+  pub controls: IdList,
+  #[builder(default, setter(strip_option))]
+  pub expanded: Option<bool>,
+  #[builder(default, setter(strip_option, into))]
+  pub checked: Option<Tristate>,
+  #[builder(default, setter(strip_option, into))]
+  pub pressed: Option<Tristate>,
+  #[builder(default, setter(strip_option))]
+  pub selected: Option<bool>,
+  #[builder(default, setter(strip_option))]
+  pub disabled: Option<bool>,
+  #[builder(default, setter(strip_option))]
+  pub hidden: Option<bool>,
+  #[builder(default, setter(strip_option))]
+  pub live: Option<Politeness>,
+}
+
+impl AriaAttributes {
+  /// The attributes which are set, as `(name, value)` pairs in a stable
+  /// order. Booleans are rendered as `"true"` or `"false"`.
+  pub fn to_attributes(&self) -> Vec<(&'static str, String)> {
+    let bool_value = |value: bool| value.to_string();
+    let mut attributes = vec![];
+
+    if let Some(role) = self.role {
+      attributes.push(("role", role.as_str().to_string()));
+    }
+
+    for (name, value) in [
+      ("aria-label", self.label.clone()),
+      ("aria-labelledby", self.labelledby.to_value()),
+      ("aria-describedby", self.describedby.to_value()),
+      ("aria-controls", self.controls.to_value()),
+      ("aria-expanded", self.expanded.map(bool_value)),
+      (
+        "aria-checked",
+        self.checked.map(|checked| checked.as_str().to_string()),
+      ),
+      (
+        "aria-pressed",
+        self.pressed.map(|pressed| pressed.as_str().to_string()),
+      ),
+      ("aria-selected", self.selected.map(bool_value)),
+      ("aria-disabled", self.disabled.map(bool_value)),
+      ("aria-hidden", self.hidden.map(bool_value)),
+      ("aria-live", self.live.map(|live| live.as_str().to_string())),
+    ] {
+      if let Some(value) = value {
+        attributes.push((name, value));
+      }
+    }
+
+    attributes
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn id_lists_skip_duplicates() {
+    let mut list = IdList::from("label description");
+    list.push("description error");
+    list.extend(["", "hint"]);
+
+    assert_eq!(
+      list.to_value().as_deref(),
+      Some("label description error hint")
+    );
+    assert!(list.contains("error"));
+    assert_eq!(IdList::new().with(None::<&str>).to_value(), None);
+  }
+
+  #[test]
+  fn renders_the_set_attributes() {
+    let attributes = AriaAttributes::builder()
+      .role(Role::Checkbox)
+      .checked(Tristate::Mixed)
+      .labelledby("label")
+      .disabled(false)
+      .build();
+
+    assert_eq!(
+      attributes.to_attributes(),
+      vec![
+        ("role", "checkbox".to_string()),
+        ("aria-labelledby", "label".to_string()),
+        ("aria-checked", "mixed".to_string()),
+        ("aria-disabled", "false".to_string()),
+      ]
+    );
+    assert_eq!(Tristate::from(true).as_str(), "true");
+  }
+}
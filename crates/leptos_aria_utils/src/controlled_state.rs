@@ -0,0 +1,89 @@
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+/// State which can either be controlled by the consumer or managed internally.
+///
+/// When a `value` is provided to [`use_controlled_state`] it is the source of
+/// truth and calling [`ControlledState::set`] only notifies the `on_change`
+/// handler. Without a `value` the state is stored internally and updated
+/// before `on_change` is called. This is the equivalent of
+/// `useControlledState` from `react-stately`.
+#[derive(Clone)]
+pub struct ControlledState<T: 'static> {
+  value: Signal<T>,
+  internal: RwSignal<T>,
+  is_controlled: bool,
+  on_change: Option<Rc<Box<dyn Fn(T)>>>,
+}
+
+pub fn use_controlled_state<T>(
+  cx: Scope,
+  value: Option<MaybeSignal<T>>,
+  default_value: T,
+  on_change: Option<Box<dyn Fn(T)>>,
+) -> ControlledState<T>
+where
+  T: Clone + PartialEq + 'static,
+{
+  let internal = create_rw_signal(cx, default_value);
+  let is_controlled = value.is_some();
+  let value = match value {
+    Some(value) => (move || value.get()).derive_signal(cx),
+    None => (move || internal.get()).derive_signal(cx),
+  };
+
+  ControlledState {
+    value,
+    internal,
+    is_controlled,
+    on_change: on_change.map(Rc::new),
+  }
+}
+
+impl<T> ControlledState<T>
+where
+  T: Clone + PartialEq + 'static,
+{
+  /// Get the current value and track it.
+  pub fn get(&self) -> T {
+    self.value.get()
+  }
+
+  /// Get the current value without tracking it.
+  pub fn get_untracked(&self) -> T {
+    self.value.get_untracked()
+  }
+
+  /// Whether the value is owned by the consumer.
+  pub fn is_controlled(&self) -> bool {
+    self.is_controlled
+  }
+
+  /// Update the value. The `on_change` handler is only called when the value
+  /// has actually changed.
+  pub fn set(&self, value: T) {
+    if self.get_untracked() == value {
+      return;
+    }
+
+    if !self.is_controlled {
+      self.internal.set(value.clone());
+    }
+
+    if let Some(ref on_change) = self.on_change {
+      on_change(value);
+    }
+  }
+
+  /// The reactive value as a signal.
+  pub fn signal(&self) -> Signal<T> {
+    self.value
+  }
+}
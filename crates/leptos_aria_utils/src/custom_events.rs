@@ -0,0 +1,156 @@
+use std::cell::Cell;
+
+use leptos::create_effect;
+use leptos::document;
+use leptos::js_sys::Array;
+use leptos::js_sys::Object;
+use leptos::js_sys::Reflect;
+use leptos::wasm_bindgen::JsValue;
+use leptos::web_sys::CustomEvent;
+use leptos::web_sys::CustomEventInit;
+use leptos::web_sys::EventTarget;
+use leptos::Scope;
+use leptos::Signal;
+
+use crate::is_server;
+
+/// The prefix of the DOM events dispatched by the bridge, e.g.
+/// `leptos-aria:press`.
+pub const CUSTOM_EVENT_PREFIX: &str = "leptos-aria:";
+
+thread_local! {
+  static CUSTOM_EVENTS_ENABLED: Cell<bool> = Cell::new(false);
+}
+
+/// Opt in to dispatching hook lifecycle events as bubbling DOM
+/// `CustomEvent`s. This lets code outside of leptos, like analytics scripts,
+/// observe interactions with a plain `addEventListener`.
+///
+/// ```js
+/// document.addEventListener("leptos-aria:openchange", (event) => {
+///   console.log(event.target, event.detail.isOpen);
+/// });
+/// ```
+pub fn set_custom_events_enabled(is_enabled: bool) {
+  CUSTOM_EVENTS_ENABLED.with(|enabled| enabled.set(is_enabled));
+}
+
+/// Whether hook lifecycle events are dispatched as DOM events.
+pub fn are_custom_events_enabled() -> bool {
+  CUSTOM_EVENTS_ENABLED.with(Cell::get)
+}
+
+/// The lifecycle events which can be observed from the DOM. The fields become
+/// the camel cased properties of the event's `detail`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AriaCustomEvent {
+  /// `leptos-aria:press` with `{ pointerType }`.
+  Press { pointer_type: String },
+  /// `leptos-aria:openchange` with `{ isOpen }`.
+  OpenChange { is_open: bool },
+  /// `leptos-aria:selectionchange` with `{ selectedKeys }`.
+  SelectionChange { selected_keys: Vec<String> },
+}
+
+impl AriaCustomEvent {
+  /// The type of the DOM event.
+  pub fn event_type(&self) -> String {
+    let name = match self {
+      Self::Press { .. } => "press",
+      Self::OpenChange { .. } => "openchange",
+      Self::SelectionChange { .. } => "selectionchange",
+    };
+
+    format!("{CUSTOM_EVENT_PREFIX}{name}")
+  }
+
+  fn detail(&self) -> JsValue {
+    let detail = Object::new();
+    let set = |key: &str, value: JsValue| {
+      Reflect::set(&detail, &key.into(), &value).ok();
+    };
+
+    match self {
+      Self::Press { pointer_type } => set("pointerType", pointer_type.into()),
+      Self::OpenChange { is_open } => set("isOpen", (*is_open).into()),
+      Self::SelectionChange { selected_keys } => {
+        let keys = selected_keys.iter().map(JsValue::from).collect::<Array>();
+        set("selectedKeys", keys.into());
+      }
+    }
+
+    detail.into()
+  }
+}
+
+/// Dispatch the event on the target when custom events are enabled. The event
+/// bubbles so it can be observed from the document.
+pub fn dispatch_custom_event(target: &EventTarget, event: AriaCustomEvent) {
+  if is_server() || !are_custom_events_enabled() {
+    return;
+  }
+
+  let mut init = CustomEventInit::new();
+  init.bubbles(true).detail(&event.detail());
+
+  if let Ok(custom_event) = CustomEvent::new_with_event_init_dict(&event.event_type(), &init) {
+    target.dispatch_event(&custom_event).ok();
+  }
+}
+
+/// Dispatch the event on the element with the provided id.
+pub fn dispatch_custom_event_by_id(id: &str, event: AriaCustomEvent) {
+  if is_server() || !are_custom_events_enabled() {
+    return;
+  }
+
+  if let Some(element) = document().get_element_by_id(id) {
+    dispatch_custom_event(&element, event);
+  }
+}
+
+/// Dispatch an event on the element with the provided id every time the
+/// source signal changes. The initial value is not dispatched.
+pub fn use_custom_change_event<T, F>(cx: Scope, id: String, source: Signal<T>, to_event: F)
+where
+  T: Clone + PartialEq + 'static,
+  F: Fn(T) -> AriaCustomEvent + 'static,
+{
+  if is_server() {
+    return;
+  }
+
+  create_effect(cx, move |previous: Option<T>| {
+    let value = source.get();
+
+    if previous.map_or(false, |previous| previous != value) {
+      dispatch_custom_event_by_id(&id, to_event(value.clone()));
+    }
+
+    value
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn event_types_are_prefixed() {
+    let event = AriaCustomEvent::OpenChange { is_open: true };
+    assert_eq!(event.event_type(), "leptos-aria:openchange");
+
+    let event = AriaCustomEvent::SelectionChange {
+      selected_keys: vec![],
+    };
+    assert_eq!(event.event_type(), "leptos-aria:selectionchange");
+  }
+
+  #[test]
+  fn custom_events_are_opt_in() {
+    assert!(!are_custom_events_enabled());
+    set_custom_events_enabled(true);
+    assert!(are_custom_events_enabled());
+    set_custom_events_enabled(false);
+  }
+}
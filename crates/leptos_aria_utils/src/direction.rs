@@ -0,0 +1,83 @@
+use leptos::IntoSignal;
+use leptos::Scope;
+use leptos::Signal;
+
+use crate::use_locale;
+
+/// Scripts which are written from right to left.
+const RTL_SCRIPTS: [&str; 10] = [
+  "adlm", "arab", "hebr", "mand", "mend", "nkoo", "rohg", "samr", "syrc", "thaa",
+];
+
+/// Languages which are written from right to left when the locale doesn't
+/// include a script.
+const RTL_LANGUAGES: [&str; 19] = [
+  "ae", "ar", "arc", "bcc", "bqi", "ckb", "dv", "fa", "glk", "he", "ku", "mzn", "nqo", "pnb", "ps",
+  "sd", "ug", "ur", "yi",
+];
+
+/// The reading direction of a locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Direction {
+  #[default]
+  Ltr,
+  Rtl,
+}
+
+impl Direction {
+  /// The direction of a locale such as `"ar-EG"` or `"pa-Arab-PK"`. The
+  /// script decides when it is part of the locale, otherwise the language.
+  pub fn from_locale(locale: &str) -> Self {
+    let mut subtags = locale.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+    let script = subtags
+      .next()
+      .filter(|subtag| subtag.len() == 4)
+      .map(|subtag| subtag.to_ascii_lowercase());
+
+    let is_rtl = match script {
+      Some(script) => RTL_SCRIPTS.contains(&script.as_str()),
+      None => RTL_LANGUAGES.contains(&language.as_str()),
+    };
+
+    if is_rtl {
+      Self::Rtl
+    } else {
+      Self::Ltr
+    }
+  }
+
+  pub fn is_rtl(&self) -> bool {
+    *self == Self::Rtl
+  }
+
+  /// The value of the `dir` attribute.
+  pub fn as_attribute(&self) -> &'static str {
+    match self {
+      Self::Ltr => "ltr",
+      Self::Rtl => "rtl",
+    }
+  }
+}
+
+/// The reading direction of the locale from [`use_locale`].
+pub fn use_direction(cx: Scope) -> Signal<Direction> {
+  let locale = use_locale(cx);
+  (move || Direction::from_locale(&locale.get())).derive_signal(cx)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn direction_from_locale() {
+    assert_eq!(Direction::from_locale("en-US"), Direction::Ltr);
+    assert_eq!(Direction::from_locale("ar"), Direction::Rtl);
+    assert_eq!(Direction::from_locale("he-IL"), Direction::Rtl);
+    assert_eq!(Direction::from_locale("fa_IR"), Direction::Rtl);
+    assert_eq!(Direction::from_locale("pa-Arab-PK"), Direction::Rtl);
+    assert_eq!(Direction::from_locale("ku-Latn"), Direction::Ltr);
+    assert_eq!(Direction::from_locale(""), Direction::Ltr);
+  }
+}
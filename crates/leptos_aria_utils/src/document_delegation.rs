@@ -0,0 +1,261 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use leptos::document;
+use leptos::wasm_bindgen::prelude::Closure;
+use leptos::web_sys::Event;
+use leptos::JsCast;
+use slotmap::DefaultKey;
+use slotmap::SlotMap;
+
+use crate::is_server;
+
+/// The document events which are shared by the interaction hooks.
+///
+/// Hooks like `use_press` listen to the document while an interaction is
+/// active, since the pointer can be released outside of the element. Rather
+/// than each instance adding and removing its own document listeners for
+/// every press, a single listener for each event is added the first time it
+/// is needed and dispatches to the handlers of the active interactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DelegatedEvent {
+  PointerMove,
+  PointerUp,
+  PointerCancel,
+  KeyUp,
+}
+
+impl DelegatedEvent {
+  /// The type of the DOM event.
+  pub fn event_type(&self) -> &'static str {
+    match self {
+      Self::PointerMove => "pointermove",
+      Self::PointerUp => "pointerup",
+      Self::PointerCancel => "pointercancel",
+      Self::KeyUp => "keyup",
+    }
+  }
+}
+
+type DelegatedHandler = Rc<dyn Fn(Event)>;
+
+#[derive(Default)]
+struct Delegation {
+  handlers: SlotMap<DefaultKey, (DelegatedEvent, DelegatedHandler)>,
+  /// The document listeners, which are kept for the lifetime of the page once
+  /// they have been added.
+  listeners: HashMap<DelegatedEvent, Closure<dyn Fn(Event)>>,
+}
+
+thread_local! {
+  static DELEGATION: RefCell<Delegation> = RefCell::new(Delegation::default());
+}
+
+/// The handlers of an interaction for the delegated document events.
+///
+/// The handlers are removed together with
+/// [`remove_all_listeners`](Self::remove_all_listeners) once the interaction
+/// ends, or when this is dropped.
+///
+/// ```ignore
+/// let mut listeners = DelegatedListeners::default();
+///
+/// listeners.add(DelegatedEvent::PointerUp, move |event: PointerEvent| {
+///   end_interaction(event);
+/// });
+/// ```
+#[derive(Default)]
+pub struct DelegatedListeners(Vec<DefaultKey>);
+
+impl DelegatedListeners {
+  /// Call the handler for each of the events on the document until the
+  /// listeners are removed. Nothing is added on the server.
+  pub fn add<E>(&mut self, event: DelegatedEvent, handler: impl Fn(E) + 'static)
+  where
+    E: JsCast,
+  {
+    if is_server() {
+      return;
+    }
+
+    let handler: DelegatedHandler = Rc::new(move |event: Event| handler(event.unchecked_into()));
+    let key = DELEGATION.with(|delegation| {
+      let mut delegation = delegation.borrow_mut();
+      delegation
+        .listeners
+        .entry(event)
+        .or_insert_with(|| add_document_listener(event));
+      delegation.handlers.insert((event, handler))
+    });
+
+    self.0.push(key);
+  }
+
+  /// Whether the interaction has no handlers.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Remove all the handlers of the interaction. The document listeners are
+  /// kept for the next interaction.
+  pub fn remove_all_listeners(&mut self) {
+    if self.0.is_empty() {
+      return;
+    }
+
+    // The handlers are dropped once the registry is no longer borrowed, since
+    // they may own other listeners which are removed when dropped.
+    let _removed: Vec<_> = DELEGATION.with(|delegation| {
+      let mut delegation = delegation.borrow_mut();
+
+      self
+        .0
+        .drain(..)
+        .filter_map(|key| delegation.handlers.remove(key))
+        .collect()
+    });
+  }
+}
+
+impl Drop for DelegatedListeners {
+  fn drop(&mut self) {
+    self.remove_all_listeners();
+  }
+}
+
+fn add_document_listener(event: DelegatedEvent) -> Closure<dyn Fn(Event)> {
+  let closure = Closure::wrap(
+    Box::new(move |dom_event: Event| dispatch(event, dom_event)) as Box<dyn Fn(Event)>
+  );
+
+  document()
+    .add_event_listener_with_callback(event.event_type(), closure.as_ref().unchecked_ref())
+    .unwrap();
+
+  closure
+}
+
+/// Call the handlers of the event. The handlers are collected first, since
+/// they usually add or remove handlers when the interaction changes, and a
+/// handler which was removed by an earlier one isn't called.
+fn dispatch(event: DelegatedEvent, dom_event: Event) {
+  let handlers: Vec<(DefaultKey, DelegatedHandler)> = DELEGATION.with(|delegation| {
+    delegation
+      .borrow()
+      .handlers
+      .iter()
+      .filter(|(_, (handler_event, _))| *handler_event == event)
+      .map(|(key, (_, handler))| (key, handler.clone()))
+      .collect()
+  });
+
+  for (key, handler) in handlers {
+    let is_active = DELEGATION.with(|delegation| delegation.borrow().handlers.contains_key(key));
+
+    if is_active {
+      handler(dom_event.clone());
+    }
+  }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+  use std::cell::Cell;
+
+  use wasm_bindgen_test::*;
+
+  use super::*;
+
+  wasm_bindgen_test_configure!(run_in_browser);
+
+  fn dispatch_on_document(event: DelegatedEvent) {
+    let event = Event::new(event.event_type()).unwrap();
+    document().dispatch_event(&event).unwrap();
+  }
+
+  /// A handler which counts its calls.
+  fn counter() -> (Rc<Cell<u32>>, impl Fn(Event)) {
+    let count = Rc::new(Cell::new(0));
+    let handler = {
+      let count = count.clone();
+      move |_: Event| count.set(count.get() + 1)
+    };
+
+    (count, handler)
+  }
+
+  #[wasm_bindgen_test]
+  fn dispatches_to_the_handlers_of_the_event() {
+    let mut listeners = DelegatedListeners::default();
+    let (key_up, on_key_up) = counter();
+    let (pointer_up, on_pointer_up) = counter();
+    listeners.add(DelegatedEvent::KeyUp, on_key_up);
+    listeners.add(DelegatedEvent::PointerUp, on_pointer_up);
+
+    dispatch_on_document(DelegatedEvent::KeyUp);
+    assert_eq!((key_up.get(), pointer_up.get()), (1, 0));
+
+    listeners.remove_all_listeners();
+    assert!(listeners.is_empty());
+    dispatch_on_document(DelegatedEvent::KeyUp);
+    dispatch_on_document(DelegatedEvent::PointerUp);
+    assert_eq!((key_up.get(), pointer_up.get()), (1, 0));
+  }
+
+  #[wasm_bindgen_test]
+  fn handler_removed_during_dispatch_is_not_called() {
+    let count = Rc::new(Cell::new(0));
+    let first = Rc::new(RefCell::new(Some(DelegatedListeners::default())));
+    let second = Rc::new(RefCell::new(Some(DelegatedListeners::default())));
+
+    // Each handler removes the other interaction, so only the handler which
+    // is called first runs.
+    for (listeners, other) in [(&first, &second), (&second, &first)] {
+      let count = count.clone();
+      let other = other.clone();
+      listeners.borrow_mut().as_mut().unwrap().add(
+        DelegatedEvent::PointerCancel,
+        move |_: Event| {
+          count.set(count.get() + 1);
+          let _removed = other.borrow_mut().take();
+        },
+      );
+    }
+
+    dispatch_on_document(DelegatedEvent::PointerCancel);
+    assert_eq!(count.get(), 1);
+
+    first.borrow_mut().take();
+    second.borrow_mut().take();
+  }
+
+  #[wasm_bindgen_test]
+  fn document_listener_is_added_once_per_event() {
+    let mut first = DelegatedListeners::default();
+    let mut second = DelegatedListeners::default();
+    let (count, handler) = counter();
+    let handler = Rc::new(handler);
+
+    for listeners in [&mut first, &mut second] {
+      let handler = handler.clone();
+      listeners.add(DelegatedEvent::PointerMove, move |event: Event| {
+        handler(event)
+      });
+    }
+
+    // A second document listener would call every handler twice.
+    dispatch_on_document(DelegatedEvent::PointerMove);
+    assert_eq!(count.get(), 2);
+    DELEGATION.with(|delegation| {
+      assert!(delegation
+        .borrow()
+        .listeners
+        .contains_key(&DelegatedEvent::PointerMove));
+    });
+
+    drop(first);
+    dispatch_on_document(DelegatedEvent::PointerMove);
+    assert_eq!(count.get(), 3);
+  }
+}
@@ -0,0 +1,110 @@
+use leptos::wasm_bindgen::JsValue;
+use leptos::web_sys::Event;
+use leptos::web_sys::Node;
+use leptos::JsCast;
+
+/// Check whether the event originated within the provided element.
+///
+/// The composed path is used so that events from within a shadow root, which
+/// are retargeted to the shadow host by the time they reach the element, are
+/// still considered to be within it. Content rendered through a portal is
+/// only within the element when it is part of its DOM tree, so events which
+/// only bubbled through the component tree are ignored.
+///
+/// When the event is not being dispatched the composed path is empty and the
+/// target is checked instead.
+pub fn is_event_within(current_target: &Node, event: &Event) -> bool {
+  let path = event.composed_path();
+
+  if path.length() > 0 {
+    let current_target: &JsValue = current_target.as_ref();
+    return path.includes(current_target, 0);
+  }
+
+  let target = event
+    .target()
+    .and_then(|target| target.dyn_into::<Node>().ok());
+
+  current_target.contains(target.as_ref())
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+  use std::cell::Cell;
+  use std::rc::Rc;
+
+  use leptos::document;
+  use leptos::wasm_bindgen::prelude::Closure;
+  use leptos::web_sys::Element;
+  use leptos::web_sys::EventInit;
+  use leptos::web_sys::ShadowRootInit;
+  use leptos::web_sys::ShadowRootMode;
+  use wasm_bindgen_test::*;
+
+  use super::*;
+
+  wasm_bindgen_test_configure!(run_in_browser);
+
+  /// Dispatch a bubbling, composed event from `source` and record whether
+  /// `is_event_within(element, event)` when it reaches `listener`.
+  fn dispatch_within(source: &Element, listener: &Element, element: &Element) -> bool {
+    let within = Rc::new(Cell::new(false));
+    let callback = {
+      let within = within.clone();
+      let element = element.clone();
+      Closure::wrap(Box::new(move |event: Event| {
+        within.set(is_event_within(&element, &event));
+      }) as Box<dyn Fn(Event)>)
+    };
+
+    listener
+      .add_event_listener_with_callback("pointerdown", callback.as_ref().unchecked_ref())
+      .unwrap();
+
+    let mut init = EventInit::new();
+    init.bubbles(true).composed(true);
+    let event = Event::new_with_event_init_dict("pointerdown", &init).unwrap();
+    source.dispatch_event(&event).unwrap();
+
+    listener
+      .remove_event_listener_with_callback("pointerdown", callback.as_ref().unchecked_ref())
+      .unwrap();
+
+    within.get()
+  }
+
+  #[wasm_bindgen_test]
+  fn event_within_shadow_root() {
+    let host = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&host).unwrap();
+    let shadow_root = host
+      .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+      .unwrap();
+    let button = document().create_element("button").unwrap();
+    shadow_root.append_child(&button).unwrap();
+
+    // The event is retargeted to the host but the composed path still contains
+    // the button within the shadow root.
+    assert!(dispatch_within(&button, &host, &host));
+    assert!(dispatch_within(&button, &host, &button));
+
+    host.remove();
+  }
+
+  #[wasm_bindgen_test]
+  fn event_from_portal_is_not_within() {
+    let trigger = document().create_element("div").unwrap();
+    let portal = document().create_element("div").unwrap();
+    let body = document().body().unwrap();
+    body.append_child(&trigger).unwrap();
+    body.append_child(&portal).unwrap();
+
+    // The portal content is rendered outside of the trigger, so its events are
+    // never within the trigger, even though they bubble through the body.
+    assert!(!dispatch_within(&portal, &body, &trigger));
+    assert!(dispatch_within(&portal, &body, &portal));
+
+    trigger.remove();
+    portal.remove();
+  }
+}
@@ -1,5 +1,9 @@
 //! Extend the `web_sys` crate with methods that are not yet available in the
 //! `web_sys` crate.
 pub use elements::*;
+pub use resize_observer::*;
+pub use weak_ref::*;
 
 mod elements;
+mod resize_observer;
+mod weak_ref;
@@ -0,0 +1,34 @@
+//! `ResizeObserver` is only available behind `web_sys_unstable_apis` in the
+//! version of `web_sys` used by this crate.
+
+use leptos::js_sys::Function;
+use leptos::js_sys::Object;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(extends = Object, js_name = ResizeObserver, typescript_type = "ResizeObserver")]
+  #[derive(Debug, Clone, PartialEq, Eq)]
+  #[doc = "The `ResizeObserver` class."]
+  #[doc = ""]
+  #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver)"]
+  pub type ResizeObserver;
+
+  #[wasm_bindgen(catch, constructor, js_class = "ResizeObserver")]
+  #[doc = "The `new ResizeObserver(..)` constructor."]
+  pub fn new(callback: &Function) -> Result<ResizeObserver, JsValue>;
+
+  #[wasm_bindgen(method, structural, js_class = "ResizeObserver", js_name = observe)]
+  #[doc = "The `observe()` method."]
+  pub fn observe(this: &ResizeObserver, target: &Element);
+
+  #[wasm_bindgen(method, structural, js_class = "ResizeObserver", js_name = unobserve)]
+  #[doc = "The `unobserve()` method."]
+  pub fn unobserve(this: &ResizeObserver, target: &Element);
+
+  #[wasm_bindgen(method, structural, js_class = "ResizeObserver", js_name = disconnect)]
+  #[doc = "The `disconnect()` method."]
+  pub fn disconnect(this: &ResizeObserver);
+}
@@ -0,0 +1,24 @@
+//! `WeakRef` isn't available in the version of `js_sys` used by this crate.
+
+use leptos::js_sys::Object;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsValue;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(extends = Object, js_name = WeakRef, typescript_type = "WeakRef")]
+  #[derive(Debug, Clone, PartialEq, Eq)]
+  #[doc = "The `WeakRef` class."]
+  #[doc = ""]
+  #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef)"]
+  pub type WeakRef;
+
+  #[wasm_bindgen(constructor, js_class = "WeakRef")]
+  #[doc = "The `new WeakRef(..)` constructor."]
+  pub fn new(target: &Object) -> WeakRef;
+
+  #[wasm_bindgen(method, structural, js_class = "WeakRef", js_name = deref)]
+  #[doc = "The `deref()` method, which is `undefined` once the target has been"]
+  #[doc = "garbage collected."]
+  pub fn deref(this: &WeakRef) -> JsValue;
+}
@@ -0,0 +1,115 @@
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+/// The roles which support the `aria-readonly` attribute.
+const READ_ONLY_ROLES: [&str; 9] = [
+  "checkbox",
+  "combobox",
+  "grid",
+  "gridcell",
+  "listbox",
+  "radiogroup",
+  "slider",
+  "spinbutton",
+  "textbox",
+];
+
+/// The disabled and read only state shared by all form hooks.
+///
+/// * Disabled fields can't be focused or interacted with.
+/// * Read only fields stay focusable and are announced by screen readers, but
+///   any interaction which would change the value is ignored.
+///
+/// Validation is skipped for both since the user can't fix the value.
+#[derive(Clone, Copy)]
+pub struct FieldState {
+  pub is_disabled: Signal<bool>,
+  pub is_read_only: Signal<bool>,
+}
+
+pub fn use_field_state(
+  cx: Scope,
+  is_disabled: Option<MaybeSignal<bool>>,
+  is_read_only: Option<MaybeSignal<bool>>,
+) -> FieldState {
+  let original_is_disabled = is_disabled.unwrap_or(false.into());
+  let original_is_read_only = is_read_only.unwrap_or(false.into());
+
+  FieldState {
+    is_disabled: (move || original_is_disabled.get()).derive_signal(cx),
+    is_read_only: (move || original_is_read_only.get()).derive_signal(cx),
+  }
+}
+
+impl FieldState {
+  /// Whether the value can currently be changed by the user. Use this to guard
+  /// every handler which updates the value.
+  pub fn can_change(&self) -> bool {
+    !self.is_disabled.get_untracked() && !self.is_read_only.get_untracked()
+  }
+
+  /// Whether validation should run. Disabled and read only fields are never
+  /// reported as invalid.
+  pub fn should_validate(&self) -> bool {
+    !self.is_disabled.get() && !self.is_read_only.get()
+  }
+
+  /// The value of `aria-readonly` for an element with the provided `role`.
+  /// This is `None` for roles which don't support the attribute.
+  pub fn aria_readonly(&self, cx: Scope, role: &'static str) -> Signal<Option<bool>> {
+    let is_read_only = self.is_read_only;
+    let is_supported = READ_ONLY_ROLES.contains(&role);
+
+    (move || (is_supported && is_read_only.get()).then_some(true)).derive_signal(cx)
+  }
+
+  /// The value of `aria-disabled`.
+  pub fn aria_disabled(&self, cx: Scope) -> Signal<Option<bool>> {
+    let is_disabled = self.is_disabled;
+    (move || is_disabled.get().then_some(true)).derive_signal(cx)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+
+  #[test]
+  fn read_only_fields_stay_enabled_but_cannot_change() {
+    let (can_change, is_disabled) = run_scope(create_runtime(), |cx| {
+      let field = use_field_state(cx, None, Some(true.into()));
+      (field.can_change(), field.is_disabled.get())
+    });
+
+    assert!(!can_change);
+    assert!(!is_disabled);
+  }
+
+  #[test]
+  fn aria_readonly_is_only_set_for_supported_roles() {
+    let values = run_scope(create_runtime(), |cx| {
+      let field = use_field_state(cx, None, Some(true.into()));
+      let editable = use_field_state(cx, None, None);
+
+      [
+        field.aria_readonly(cx, "checkbox").get(),
+        field.aria_readonly(cx, "radiogroup").get(),
+        field.aria_readonly(cx, "spinbutton").get(),
+        field.aria_readonly(cx, "radio").get(),
+        field.aria_readonly(cx, "button").get(),
+        editable.aria_readonly(cx, "checkbox").get(),
+      ]
+    });
+
+    assert_eq!(
+      values,
+      [Some(true), Some(true), Some(true), None, None, None]
+    );
+  }
+}
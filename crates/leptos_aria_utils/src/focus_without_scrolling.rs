@@ -20,6 +20,7 @@ use leptos::Scope;
 use leptos::UntrackedGettableSignal;
 use leptos::UntrackedSettableSignal;
 
+use crate::is_server;
 use crate::ContextProvider;
 use crate::FocusOptions;
 use crate::HtmlElement;
@@ -98,7 +99,13 @@ impl FocusableElement {
 /// See https://github.com/calvellido/focus-options-polyfill
 ///
 /// TODO: check if supported like in `react-aria`
+///
+/// This does nothing on the server.
 pub fn focus_without_scrolling(cx: Scope, element: impl AsRef<Element>) {
+  if is_server() {
+    return;
+  }
+
   let element = element.to_focusable_element();
 
   if supports_prevent_scroll(cx) {
@@ -1,60 +1,455 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use leptos::js_sys::Function;
+use leptos::on_cleanup;
+use leptos::wasm_bindgen::prelude::Closure;
+use leptos::web_sys::AbortSignal;
+use leptos::web_sys::AddEventListenerOptions;
+use leptos::web_sys::Event;
 use leptos::web_sys::EventTarget;
+use leptos::JsCast;
+use leptos::Scope;
 use slotmap::DefaultKey;
 use slotmap::SlotMap;
 
-// type GlobalListenersMap = Map<Function, Tuple3<EventTarget, JsString,
-// Boolean>>;
+/// A closure which can be added as an event listener with
+/// [`GlobalListeners::add_closure_listener`].
+///
+/// It is reference counted so that a hook can create its closures once and
+/// add them for every interaction. Prefer [`add_event_listener`] for a
+/// listener which is only added once.
+pub type ListenerClosure = Rc<Closure<dyn Fn(Event)>>;
 
-#[derive(Default)]
-pub struct GlobalListeners(SlotMap<DefaultKey, (Function, EventTarget, String, bool)>);
+/// Create a [`ListenerClosure`] for a callback of a specific event type.
+pub fn create_listener_closure<E>(callback: impl Fn(E) + 'static) -> ListenerClosure
+where
+  E: JsCast,
+{
+  let closure = Closure::wrap(
+    Box::new(move |event: Event| callback(event.unchecked_into())) as Box<dyn Fn(Event)>,
+  );
 
-impl GlobalListeners {
-  /// Add a closure as an event listener.
-  pub fn add_listener(
-    &mut self,
+  Rc::new(closure)
+}
+
+/// The options of a listener added with
+/// [`GlobalListeners::add_listener_with_options`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ListenerOptions {
+  /// Listen in the capture phase.
+  pub capture: bool,
+
+  /// Whether the listener never calls `preventDefault`, which lets the
+  /// browser scroll without waiting for it, e.g. for `wheel` and `touchmove`.
+  /// Defaults to the browser's default for the event.
+  pub passive: Option<bool>,
+
+  /// Remove the listener once it has been called.
+  pub once: bool,
+
+  /// Remove the listener when the signal is aborted, which removes many
+  /// listeners at once with a single `AbortController`.
+  pub signal: Option<AbortSignal>,
+}
+
+impl ListenerOptions {
+  fn to_add_event_listener_options(&self) -> AddEventListenerOptions {
+    let mut options = AddEventListenerOptions::new();
+    options.capture(self.capture).once(self.once);
+
+    if let Some(passive) = self.passive {
+      options.passive(passive);
+    }
+
+    if let Some(ref signal) = self.signal {
+      options.signal(signal);
+    }
+
+    options
+  }
+}
+
+impl From<bool> for ListenerOptions {
+  /// Only set whether to listen in the capture phase.
+  fn from(capture: bool) -> Self {
+    Self {
+      capture,
+      ..Default::default()
+    }
+  }
+}
+
+/// An event listener which is removed when the handle is dropped.
+///
+/// Created with [`add_event_listener`], which owns the closure of the
+/// listener until it is removed.
+#[must_use = "the listener is removed as soon as the handle is dropped"]
+pub struct EventListenerHandle {
+  function: Function,
+  event_target: EventTarget,
+  event_type: String,
+  /// The options of the registration. The listener is only removed when the
+  /// `capture` option matches.
+  options: ListenerOptions,
+  /// Keeps the closure alive until the listener is removed.
+  _closure: Option<ListenerClosure>,
+}
+
+impl EventListenerHandle {
+  fn new(
     target: impl AsRef<EventTarget>,
     type_: impl Into<String>,
     function: Function,
-    capture: bool,
-  ) -> DefaultKey {
+    options: ListenerOptions,
+    closure: Option<ListenerClosure>,
+  ) -> Self {
     let event_target = target.as_ref().clone();
     let event_type: String = type_.into();
-    // let function = closure.as_ref().unchecked_ref::<Function>();
     event_target
-      .add_event_listener_with_callback_and_bool(event_type.as_str(), &function, capture)
+      .add_event_listener_with_callback_and_add_event_listener_options(
+        event_type.as_str(),
+        &function,
+        &options.to_add_event_listener_options(),
+      )
       .unwrap();
 
-    self.0.insert((function, event_target, event_type, capture))
+    Self {
+      function,
+      event_target,
+      event_type,
+      options,
+      _closure: closure,
+    }
+  }
+
+  /// Remove the listener, which is the same as dropping the handle.
+  pub fn remove(self) {
+    drop(self);
+  }
+}
+
+/// Add a typed event listener to the target.
+///
+/// The event is cast to `E` before the handler is called, and the closure is
+/// kept alive until the returned handle is dropped, which removes the
+/// listener. A handler may remove its own listener, since the closure is only
+/// freed once it returns.
+///
+/// ```ignore
+/// let handle = add_event_listener(document(), "keydown", move |event: KeyboardEvent| {
+///   on_key_down(event);
+/// }, true);
+///
+/// // Later, remove the listener.
+/// handle.remove();
+/// ```
+pub fn add_event_listener<E>(
+  target: impl AsRef<EventTarget>,
+  event_type: impl Into<String>,
+  handler: impl Fn(E) + 'static,
+  options: impl Into<ListenerOptions>,
+) -> EventListenerHandle
+where
+  E: JsCast,
+{
+  let closure = create_listener_closure(handler);
+  let function = (*closure).as_ref().unchecked_ref::<Function>().clone();
+
+  EventListenerHandle::new(target, event_type, function, options.into(), Some(closure))
+}
+
+/// The event listeners added by a hook, which are removed together.
+///
+/// This is a cheap handle which can be cloned into the event handlers that
+/// add and remove the listeners. The listeners are removed once every handle
+/// has been dropped, or when the scope of [`GlobalListeners::new`] is
+/// disposed.
+#[derive(Clone, Default)]
+pub struct GlobalListeners(Rc<RefCell<SlotMap<DefaultKey, EventListenerHandle>>>);
+
+impl GlobalListeners {
+  /// Create listeners which are removed when the scope is disposed, e.g. when
+  /// the component which owns the hook is unmounted.
+  pub fn new(cx: Scope) -> Self {
+    let listeners = Self::default();
+
+    {
+      let listeners = listeners.clone();
+      on_cleanup(cx, move || listeners.remove_all_listeners());
+    }
+
+    listeners
+  }
+
+  /// Add a function as an event listener.
+  ///
+  /// The function must outlive the listener. Prefer
+  /// [`add_closure_listener`](Self::add_closure_listener) for closures
+  /// created in Rust.
+  pub fn add_listener(
+    &self,
+    target: impl AsRef<EventTarget>,
+    type_: impl Into<String>,
+    function: Function,
+    capture: bool,
+  ) -> DefaultKey {
+    self.insert(target, type_, function, capture.into(), None)
+  }
+
+  /// Add a function as an event listener with the options, e.g. a passive
+  /// `wheel` listener or a listener which is called only once.
+  ///
+  /// ```ignore
+  /// listeners.add_listener_with_options(
+  ///   document(),
+  ///   "touchmove",
+  ///   on_touch_move,
+  ///   ListenerOptions {
+  ///     passive: Some(true),
+  ///     ..Default::default()
+  ///   },
+  /// );
+  /// ```
+  pub fn add_listener_with_options(
+    &self,
+    target: impl AsRef<EventTarget>,
+    type_: impl Into<String>,
+    function: Function,
+    options: ListenerOptions,
+  ) -> DefaultKey {
+    self.insert(target, type_, function, options, None)
+  }
+
+  /// Add a closure as an event listener. The closure is kept alive until the
+  /// listener is removed.
+  pub fn add_closure_listener(
+    &self,
+    target: impl AsRef<EventTarget>,
+    type_: impl Into<String>,
+    closure: impl Into<ListenerClosure>,
+    capture: bool,
+  ) -> DefaultKey {
+    let closure: ListenerClosure = closure.into();
+    let function = (*closure).as_ref().unchecked_ref::<Function>().clone();
+
+    self.insert(target, type_, function, capture.into(), Some(closure))
   }
 
-  pub fn remove_listener(&mut self, key: DefaultKey) {
-    if let Some((function, event_target, event_type, capture)) = self.0.get(key) {
-      event_target
-        .remove_event_listener_with_callback_and_bool(event_type.as_str(), function, *capture)
-        .unwrap();
-    };
+  /// Add a typed event listener, like [`add_event_listener`], which is
+  /// removed together with the other listeners.
+  pub fn add_event_listener<E>(
+    &self,
+    target: impl AsRef<EventTarget>,
+    type_: impl Into<String>,
+    handler: impl Fn(E) + 'static,
+    options: impl Into<ListenerOptions>,
+  ) -> DefaultKey
+  where
+    E: JsCast,
+  {
+    let listener = add_event_listener(target, type_, handler, options);
+    self.0.borrow_mut().insert(listener)
+  }
 
-    self.0.remove(key);
+  /// Remove the listener. Removed listeners are dropped once the listeners
+  /// are no longer borrowed, since dropping a closure can drop other
+  /// listeners.
+  pub fn remove_listener(&self, key: DefaultKey) {
+    let _listener = self.0.borrow_mut().remove(key);
   }
 
   /// Remove all the generated listeners.
-  pub fn remove_all_listeners(&mut self) {
-    self
-      .0
-      .values()
-      .for_each(|(function, event_target, event_type, capture)| {
-        event_target
-          .remove_event_listener_with_callback_and_bool(event_type.as_str(), function, *capture)
-          .unwrap();
-      });
+  pub fn remove_all_listeners(&self) {
+    let _listeners: Vec<_> = self.0.borrow_mut().drain().collect();
+  }
 
-    self.0.clear();
+  fn insert(
+    &self,
+    target: impl AsRef<EventTarget>,
+    type_: impl Into<String>,
+    function: Function,
+    options: ListenerOptions,
+    closure: Option<ListenerClosure>,
+  ) -> DefaultKey {
+    let listener = EventListenerHandle::new(target, type_, function, options, closure);
+    self.0.borrow_mut().insert(listener)
   }
 }
 
-impl Drop for GlobalListeners {
+impl Drop for EventListenerHandle {
+  /// Listeners are removed when dropped, including once the last handle of
+  /// the listeners is dropped.
   fn drop(&mut self) {
-    self.remove_all_listeners();
+    // Removing a listener only throws for a target which can no longer be
+    // used, e.g. from a closed window, which has dropped its listeners anyway.
+    let _ = self
+      .event_target
+      .remove_event_listener_with_callback_and_bool(
+        self.event_type.as_str(),
+        &self.function,
+        self.options.capture,
+      );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn capture_converts_to_options() {
+    assert_eq!(
+      ListenerOptions::from(true),
+      ListenerOptions {
+        capture: true,
+        passive: None,
+        once: false,
+        signal: None,
+      }
+    );
+    assert_eq!(ListenerOptions::from(false), ListenerOptions::default());
+  }
+
+  #[cfg(target_arch = "wasm32")]
+  mod browser {
+    use std::cell::Cell;
+
+    use leptos::create_runtime;
+    use leptos::create_scope;
+    use leptos::document;
+    use leptos::web_sys::Element;
+    use leptos::web_sys::EventInit;
+    use wasm_bindgen_test::*;
+
+    use super::super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn connected_div() -> Element {
+      let element = document().create_element("div").unwrap();
+      document().body().unwrap().append_child(&element).unwrap();
+      element
+    }
+
+    fn dispatch(target: &Element, event_type: &str) {
+      let mut init = EventInit::new();
+      init.bubbles(true);
+      let event = Event::new_with_event_init_dict(event_type, &init).unwrap();
+      target.dispatch_event(&event).unwrap();
+    }
+
+    /// A handler which counts its calls.
+    fn counter() -> (Rc<Cell<u32>>, impl Fn(Event)) {
+      let count = Rc::new(Cell::new(0));
+      let handler = {
+        let count = count.clone();
+        move |_: Event| count.set(count.get() + 1)
+      };
+
+      (count, handler)
+    }
+
+    #[wasm_bindgen_test]
+    fn listener_lives_as_long_as_the_handle() {
+      let element = connected_div();
+      let (count, handler) = counter();
+      let handle = add_event_listener(&element, "custom", handler, false);
+
+      dispatch(&element, "custom");
+      assert_eq!(count.get(), 1);
+
+      handle.remove();
+      dispatch(&element, "custom");
+      assert_eq!(count.get(), 1);
+
+      element.remove();
+    }
+
+    #[wasm_bindgen_test]
+    fn listener_can_remove_itself() {
+      let element = connected_div();
+      let count = Rc::new(Cell::new(0));
+      let handle = Rc::new(RefCell::new(None::<EventListenerHandle>));
+
+      *handle.borrow_mut() = Some(add_event_listener(
+        &element,
+        "custom",
+        {
+          let count = count.clone();
+          let handle = handle.clone();
+          move |_: Event| {
+            count.set(count.get() + 1);
+            let _listener = handle.borrow_mut().take();
+          }
+        },
+        false,
+      ));
+
+      dispatch(&element, "custom");
+      dispatch(&element, "custom");
+      assert_eq!(count.get(), 1);
+      assert!(handle.borrow().is_none());
+
+      element.remove();
+    }
+
+    #[wasm_bindgen_test]
+    fn options_are_applied_and_removed() {
+      let parent = connected_div();
+      let child = document().create_element("div").unwrap();
+      parent.append_child(&child).unwrap();
+      let order = Rc::new(RefCell::new(Vec::new()));
+      let listeners = GlobalListeners::default();
+
+      let record = |name: &'static str| {
+        let order = order.clone();
+        move |_: Event| order.borrow_mut().push(name)
+      };
+      listeners.add_event_listener(&child, "custom", record("target"), false);
+      let capture = listeners.add_event_listener(&parent, "custom", record("capture"), true);
+      listeners.add_event_listener(
+        &parent,
+        "custom",
+        record("once"),
+        ListenerOptions {
+          once: true,
+          ..Default::default()
+        },
+      );
+
+      dispatch(&child, "custom");
+      assert_eq!(*order.borrow(), ["capture", "target", "once"]);
+
+      // The capture listener is only removed when the `capture` option of the
+      // registration is used.
+      listeners.remove_listener(capture);
+      order.borrow_mut().clear();
+      dispatch(&child, "custom");
+      assert_eq!(*order.borrow(), ["target"]);
+
+      parent.remove();
+    }
+
+    #[wasm_bindgen_test]
+    fn listeners_are_removed_when_the_scope_is_disposed() {
+      let element = connected_div();
+      let (count, handler) = counter();
+      let disposer = create_scope(create_runtime(), {
+        let element = element.clone();
+        move |cx| {
+          GlobalListeners::new(cx).add_event_listener(&element, "custom", handler, false);
+        }
+      });
+
+      dispatch(&element, "custom");
+      assert_eq!(count.get(), 1);
+
+      disposer.dispose();
+      dispatch(&element, "custom");
+      assert_eq!(count.get(), 1);
+
+      element.remove();
+    }
   }
 }
@@ -0,0 +1,82 @@
+use std::fmt::Debug;
+
+use leptos::Signal;
+
+/// Warn in debug builds when the value of a hook prop which affects the
+/// rendered ARIA attributes changes while the page is being hydrated.
+///
+/// The client can't read the value the server rendered, so this compares the
+/// value the hook was created with against the value once the hydration task
+/// has finished. A prop which changes in between, e.g. because it's derived
+/// from `window` or local storage, likely didn't match the server either and
+/// the attributes the hook rendered during hydration are stale.
+///
+/// This is a no-op unless the `hydrate` feature is enabled and the crate is
+/// compiled with debug assertions.
+pub fn warn_on_change_during_hydration<T>(hook: &'static str, name: &'static str, value: Signal<T>)
+where
+  T: Clone + Debug + PartialEq + 'static,
+{
+  #[cfg(all(feature = "hydrate", debug_assertions))]
+  {
+    use leptos::UntrackedGettableSignal;
+
+    let initial_value = value.get_untracked();
+
+    leptos::set_timeout(
+      move || {
+        let hydrated_value = value.get_untracked();
+
+        if let Some(message) = change_during_hydration(hook, name, &initial_value, &hydrated_value)
+        {
+          leptos::warn!("{message}");
+        }
+      },
+      std::time::Duration::ZERO,
+    );
+  }
+
+  #[cfg(not(all(feature = "hydrate", debug_assertions)))]
+  let _ = (hook, name, value);
+}
+
+/// The warning when the prop changed from its initial value while hydrating.
+#[cfg(any(test, all(feature = "hydrate", debug_assertions)))]
+fn change_during_hydration<T>(
+  hook: &str,
+  name: &str,
+  initial_value: &T,
+  hydrated_value: &T,
+) -> Option<String>
+where
+  T: Debug + PartialEq,
+{
+  (initial_value != hydrated_value).then(|| {
+    format!(
+      "[{hook}] the `{name}` prop changed from `{initial_value:?}` to `{hydrated_value:?}` while \
+       the page was hydrating. The ARIA attributes rendered during hydration may not match the \
+       server until the next update."
+    )
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn warns_when_the_prop_changes_while_hydrating() {
+    assert_eq!(
+      change_during_hydration("use_press", "is_disabled", &false, &false),
+      None
+    );
+    assert_eq!(
+      change_during_hydration("use_press", "is_disabled", &false, &true).as_deref(),
+      Some(
+        "[use_press] the `is_disabled` prop changed from `false` to `true` while the page was \
+         hydrating. The ARIA attributes rendered during hydration may not match the server until \
+         the next update."
+      )
+    );
+  }
+}
@@ -0,0 +1,158 @@
+use std::rc::Rc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use leptos::create_rw_signal;
+use leptos::document;
+use leptos::js_sys::Array;
+use leptos::on_cleanup;
+use leptos::request_animation_frame;
+use leptos::use_context;
+use leptos::wasm_bindgen::prelude::Closure;
+use leptos::web_sys::MutationObserver;
+use leptos::web_sys::MutationObserverInit;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos::UntrackedSettableSignal;
+
+use crate::on_browser;
+use crate::ContextProvider;
+
+/// The prefix of the ids created with [`use_id`].
+const ID_PREFIX: &str = "leptos-aria-";
+
+/// The prefix of the ids created with [`generate_id`], which is separate from
+/// [`ID_PREFIX`] so the two never collide.
+const GENERATED_ID_PREFIX: &str = "leptos-aria-gen-";
+
+static GENERATED_ID_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Generate an id which is unique within the running application, for
+/// elements which are never rendered on the server, like the shared
+/// description of [`use_description`](crate::use_description) or the key of a
+/// toast.
+///
+/// These ids have their own prefix and counter, so creating them while the
+/// page hydrates doesn't shift the ids of [`use_id`]. Use [`use_id`] for
+/// anything which is part of the rendered markup.
+pub fn generate_id() -> String {
+  let count = GENERATED_ID_COUNT.fetch_add(1, Ordering::Relaxed);
+  format!("{GENERATED_ID_PREFIX}{count}")
+}
+
+/// The number of ids created with [`use_id`] within the scope where
+/// [`use_provider`](crate::use_provider) is called, which is the root of each
+/// render on the server and of the app in the browser.
+#[derive(Copy, Clone)]
+pub(crate) struct IdCountContext(RwSignal<usize>);
+
+impl ContextProvider for IdCountContext {
+  type Value = usize;
+
+  fn from_leptos_scope(cx: Scope) -> Self {
+    Self(create_rw_signal(cx, Self::Value::default()))
+  }
+
+  fn get(&self) -> Self::Value {
+    self.0.get_untracked()
+  }
+
+  fn set(&self, value: Self::Value) {
+    self.0.set_untracked(value);
+  }
+}
+
+/// Create an id which is the same when the markup is rendered on the server
+/// and when it is hydrated in the browser.
+///
+/// The ids are counted from the scope where
+/// [`use_provider`](crate::use_provider) is called, for each render on the
+/// server and from the start of the app in the browser. The hooks must be
+/// called in the same order in both places, which is the case for the same
+/// view.
+///
+/// Without [`use_provider`](crate::use_provider) the id comes from
+/// [`generate_id`], which is unique but won't match the server.
+pub fn use_id(cx: Scope) -> String {
+  match use_context::<IdCountContext>(cx) {
+    Some(context) => {
+      let count = context.get();
+      context.set(count + 1);
+      format!("{ID_PREFIX}{count}")
+    }
+    None => generate_id(),
+  }
+}
+
+/// The id of an optional part of a component, created with [`use_slot_id`].
+#[derive(Clone)]
+pub struct SlotId {
+  /// The id to set on the optional element.
+  pub id: String,
+  /// The id while the element is rendered, for attributes like
+  /// `aria-describedby` which shouldn't reference missing elements.
+  pub rendered_id: Signal<Option<String>>,
+}
+
+/// Create an id for an optional part of a component, such as a description.
+///
+/// The [`rendered_id`](SlotId::rendered_id) is `None` while the element with
+/// the id isn't rendered, and follows the element when it is rendered or
+/// removed later. It is always available on the server so that the rendered
+/// markup matches the first render in the browser.
+pub fn use_slot_id(cx: Scope) -> SlotId {
+  let id = use_id(cx);
+
+  SlotId {
+    rendered_id: use_rendered_id(cx, id.clone()),
+    id,
+  }
+}
+
+/// The `id` while an element with it is rendered, like
+/// [`SlotId::rendered_id`] for an id which was created elsewhere.
+pub fn use_rendered_id(cx: Scope, id: impl Into<String>) -> Signal<Option<String>> {
+  let id = id.into();
+  let is_rendered = create_rw_signal(cx, true);
+
+  on_browser(cx, {
+    let id = id.clone();
+
+    move || {
+      let update = Rc::new(move || {
+        let is_found = document().get_element_by_id(&id).is_some();
+
+        if is_rendered.get_untracked() != is_found {
+          is_rendered.set(is_found);
+        }
+      });
+
+      // The element is rendered after the hook runs.
+      request_animation_frame({
+        let update = update.clone();
+        move || update()
+      });
+
+      let callback = Closure::wrap(Box::new(move |_: Array, _: MutationObserver| update())
+        as Box<dyn Fn(Array, MutationObserver)>);
+      let Ok(observer) = MutationObserver::new(callback.as_ref().unchecked_ref()) else {
+        return;
+      };
+
+      let mut init = MutationObserverInit::new();
+      init.child_list(true).subtree(true);
+      observer.observe_with_options(&document(), &init).ok();
+
+      on_cleanup(cx, move || {
+        observer.disconnect();
+        drop(callback);
+      });
+    }
+  });
+
+  (move || is_rendered.get().then(|| id.clone())).derive_signal(cx)
+}
@@ -0,0 +1,281 @@
+use std::fmt;
+
+use leptos::web_sys::Element;
+
+/// The interactions which are traced with the `tracing` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InteractionKind {
+  Press,
+  Hover,
+  Focus,
+  Overlay,
+}
+
+impl InteractionKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Press => "press",
+      Self::Hover => "hover",
+      Self::Focus => "focus",
+      Self::Overlay => "overlay",
+    }
+  }
+}
+
+/// A traced interaction from its start until it ends, e.g. a press from the
+/// pointer going down until it is released.
+///
+/// With the `tracing` feature enabled this is an `interaction` span with the
+/// `kind`, `pointer_type` and `target` tag as fields, and the time since the
+/// start is recorded as `elapsed_ms` on every event. Use a subscriber such as
+/// `tracing-wasm` to log the spans to the browser console, or forward them to
+/// a collector. Without the feature it's an empty value and tracing compiles
+/// away.
+///
+/// ```ignore
+/// let span = InteractionSpan::start(InteractionKind::Press, "mouse", Some(&target));
+/// span.event("pointer_leave");
+/// span.end("cancel");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct InteractionSpan(imp::Span);
+
+impl InteractionSpan {
+  /// Start tracing an interaction with the target.
+  pub fn start(kind: InteractionKind, pointer_type: &str, target: Option<&Element>) -> Self {
+    Self(imp::start(kind, pointer_type, target))
+  }
+
+  /// Record an event within the interaction.
+  pub fn event(&self, name: &str) {
+    imp::event(&self.0, name);
+  }
+
+  /// End the interaction with its outcome, e.g. `"press"` or `"cancel"`.
+  pub fn end(&self, outcome: &str) {
+    imp::end(&self.0, outcome);
+  }
+}
+
+/// Trace an interaction event which doesn't last, e.g. an overlay opening.
+/// The name is only formatted with the `tracing` feature.
+///
+/// ```ignore
+/// trace_interaction(InteractionKind::Overlay, format_args!("open {reason:?}"), None);
+/// ```
+pub fn trace_interaction(kind: InteractionKind, name: impl fmt::Display, target: Option<&Element>) {
+  imp::trace(kind, name, target);
+}
+
+#[cfg(feature = "tracing")]
+mod imp {
+  use std::fmt;
+
+  use leptos::js_sys::Date;
+  use leptos::web_sys::Element;
+
+  use super::InteractionKind;
+
+  /// The span and when it started.
+  pub(super) type Span = Option<(tracing::Span, f64)>;
+
+  pub(super) fn start(kind: InteractionKind, pointer_type: &str, target: Option<&Element>) -> Span {
+    let span = tracing::info_span!(
+      "interaction",
+      kind = kind.as_str(),
+      pointer_type,
+      target = target_tag(target).as_str(),
+    );
+    tracing::debug!(parent: &span, elapsed_ms = 0.0, "start");
+
+    Some((span, Date::now()))
+  }
+
+  pub(super) fn event(span: &Span, name: &str) {
+    if let Some((span, started_at)) = span {
+      tracing::debug!(parent: span, elapsed_ms = Date::now() - started_at, "{name}");
+    }
+  }
+
+  pub(super) fn end(span: &Span, outcome: &str) {
+    if let Some((span, started_at)) = span {
+      tracing::info!(
+        parent: span,
+        elapsed_ms = Date::now() - started_at,
+        outcome,
+        "end"
+      );
+    }
+  }
+
+  pub(super) fn trace(kind: InteractionKind, name: impl fmt::Display, target: Option<&Element>) {
+    tracing::info!(
+      kind = kind.as_str(),
+      target = target_tag(target).as_str(),
+      "{name}"
+    );
+  }
+
+  /// The lowercase tag name of the target, with its id when it has one, e.g.
+  /// `button#save`.
+  fn target_tag(target: Option<&Element>) -> String {
+    let Some(target) = target else {
+      return String::new();
+    };
+
+    let tag = target.tag_name().to_lowercase();
+    let id = target.id();
+
+    if id.is_empty() {
+      tag
+    } else {
+      format!("{tag}#{id}")
+    }
+  }
+}
+
+#[cfg(not(feature = "tracing"))]
+mod imp {
+  use std::fmt;
+
+  use leptos::web_sys::Element;
+
+  use super::InteractionKind;
+
+  #[derive(Clone, Debug, Default)]
+  pub(super) struct Span;
+
+  pub(super) fn start(_: InteractionKind, _: &str, _: Option<&Element>) -> Span {
+    Span
+  }
+
+  pub(super) fn event(_: &Span, _: &str) {}
+
+  pub(super) fn end(_: &Span, _: &str) {}
+
+  pub(super) fn trace(_: InteractionKind, _: impl fmt::Display, _: Option<&Element>) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[cfg(not(feature = "tracing"))]
+  #[test]
+  fn compiles_away_without_the_feature() {
+    assert_eq!(std::mem::size_of::<InteractionSpan>(), 0);
+
+    let span = InteractionSpan::start(InteractionKind::Press, "mouse", None);
+    span.event("pointer_leave");
+    span.end("cancel");
+    trace_interaction(InteractionKind::Hover, "hover_start", None);
+  }
+
+  #[cfg(feature = "tracing")]
+  mod traced {
+    use std::fmt::Debug;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use tracing::field::Field;
+    use tracing::field::Visit;
+    use tracing::span::Attributes;
+    use tracing::span::Id;
+    use tracing::span::Record;
+    use tracing::Event;
+    use tracing::Metadata;
+    use tracing::Subscriber;
+
+    use super::super::*;
+
+    /// Records the names of the spans and the messages of the events.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Subscriber for Recorder {
+      fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+      }
+
+      fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut records = self.0.lock().unwrap();
+        records.push(format!("span {}", span.metadata().name()));
+        Id::from_u64(records.len() as u64)
+      }
+
+      fn record(&self, _: &Id, _: &Record<'_>) {}
+
+      fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+      fn event(&self, event: &Event<'_>) {
+        let mut message = Message::default();
+        event.record(&mut message);
+        self.0.lock().unwrap().push(format!("event {}", message.0));
+      }
+
+      fn enter(&self, _: &Id) {}
+
+      fn exit(&self, _: &Id) {}
+    }
+
+    #[derive(Default)]
+    struct Message(String);
+
+    impl Visit for Message {
+      fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+          self.0 = format!("{value:?}");
+        }
+      }
+    }
+
+    fn record(run: impl FnOnce()) -> Vec<String> {
+      let recorder = Recorder::default();
+      tracing::subscriber::with_default(recorder.clone(), run);
+
+      let records = recorder.0.lock().unwrap().clone();
+      records
+    }
+
+    #[test]
+    fn traces_interaction_events() {
+      let records = record(|| {
+        trace_interaction(
+          InteractionKind::Overlay,
+          format_args!("open {}", "Trigger"),
+          None,
+        );
+      });
+
+      assert_eq!(records, vec!["event open Trigger"]);
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    mod browser {
+      use wasm_bindgen_test::*;
+
+      use super::*;
+
+      wasm_bindgen_test_configure!(run_in_browser);
+
+      #[wasm_bindgen_test]
+      fn emits_interaction_spans() {
+        let records = record(|| {
+          let span = InteractionSpan::start(InteractionKind::Press, "mouse", None);
+          span.event("pointer_leave");
+          span.end("cancel");
+        });
+
+        assert_eq!(
+          records,
+          vec![
+            "span interaction",
+            "event start",
+            "event pointer_leave",
+            "event end"
+          ]
+        );
+      }
+    }
+  }
+}
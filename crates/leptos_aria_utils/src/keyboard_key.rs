@@ -0,0 +1,259 @@
+use std::fmt;
+
+use leptos::web_sys::KeyboardEvent;
+
+use crate::Direction;
+
+/// A key pressed on the keyboard, from the `KeyboardEvent.key` value.
+///
+/// Match on the variants instead of comparing strings, so that typos are
+/// caught by the compiler and legacy names such as `Esc` or `Spacebar` are
+/// handled in one place.
+///
+/// ```ignore
+/// match KeyboardKey::from(&event) {
+///   KeyboardKey::Enter | KeyboardKey::Space => press(),
+///   KeyboardKey::Escape => cancel(),
+///   KeyboardKey::Character(character) => type_select(&character),
+///   _ => {}
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum KeyboardKey {
+  ArrowUp,
+  ArrowDown,
+  ArrowLeft,
+  ArrowRight,
+  Home,
+  End,
+  PageUp,
+  PageDown,
+  Tab,
+
+  Enter,
+  Space,
+  Escape,
+  Backspace,
+  Delete,
+  Insert,
+
+  Shift,
+  Control,
+  Alt,
+  Meta,
+  CapsLock,
+
+  /// A function key from <kbd>F1</kbd> to <kbd>F24</kbd>.
+  F(u8),
+
+  /// A printable character other than a space, e.g. `a`, `A` or `?`.
+  Character(String),
+
+  /// Any other named key, e.g. `MediaPlayPause` or `Dead` while composing an
+  /// accent.
+  Other(String),
+
+  /// The browser couldn't identify the key.
+  #[default]
+  Unidentified,
+}
+
+impl KeyboardKey {
+  /// The `KeyboardEvent.key` value of the key.
+  pub fn as_str(&self) -> &str {
+    match self {
+      Self::ArrowUp => "ArrowUp",
+      Self::ArrowDown => "ArrowDown",
+      Self::ArrowLeft => "ArrowLeft",
+      Self::ArrowRight => "ArrowRight",
+      Self::Home => "Home",
+      Self::End => "End",
+      Self::PageUp => "PageUp",
+      Self::PageDown => "PageDown",
+      Self::Tab => "Tab",
+      Self::Enter => "Enter",
+      Self::Space => " ",
+      Self::Escape => "Escape",
+      Self::Backspace => "Backspace",
+      Self::Delete => "Delete",
+      Self::Insert => "Insert",
+      Self::Shift => "Shift",
+      Self::Control => "Control",
+      Self::Alt => "Alt",
+      Self::Meta => "Meta",
+      Self::CapsLock => "CapsLock",
+      Self::F(number) => {
+        FUNCTION_KEYS
+          .get(usize::from(*number).wrapping_sub(1))
+          .copied()
+          .unwrap_or("Unidentified")
+      }
+      Self::Character(character) | Self::Other(character) => character,
+      Self::Unidentified => "Unidentified",
+    }
+  }
+
+  /// The name of the key for `aria-keyshortcuts` and shortcut labels, which
+  /// is the same as [`KeyboardKey::as_str`] except for `Space`.
+  pub fn name(&self) -> &str {
+    match self {
+      Self::Space => "Space",
+      key => key.as_str(),
+    }
+  }
+
+  /// Whether the key moves focus or the selection.
+  pub fn is_navigation(&self) -> bool {
+    matches!(
+      self,
+      Self::ArrowUp
+        | Self::ArrowDown
+        | Self::ArrowLeft
+        | Self::ArrowRight
+        | Self::Home
+        | Self::End
+        | Self::PageUp
+        | Self::PageDown
+        | Self::Tab
+    )
+  }
+
+  pub fn is_modifier(&self) -> bool {
+    matches!(
+      self,
+      Self::Shift | Self::Control | Self::Alt | Self::Meta | Self::CapsLock
+    )
+  }
+
+  /// Whether the key types a character, including the space.
+  pub fn is_printable(&self) -> bool {
+    matches!(self, Self::Space | Self::Character(_))
+  }
+
+  /// The key as it would be pressed in a left to right layout.
+  ///
+  /// <kbd>ArrowLeft</kbd> and <kbd>ArrowRight</kbd> are swapped in right to
+  /// left locales, so keyboard handlers can treat <kbd>ArrowRight</kbd> as
+  /// "forward" in every locale. All other keys are returned unchanged.
+  pub fn logical(self, direction: Direction) -> Self {
+    match (direction, self) {
+      (Direction::Rtl, Self::ArrowLeft) => Self::ArrowRight,
+      (Direction::Rtl, Self::ArrowRight) => Self::ArrowLeft,
+      (_, key) => key,
+    }
+  }
+}
+
+const FUNCTION_KEYS: [&str; 24] = [
+  "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12", "F13", "F14", "F15",
+  "F16", "F17", "F18", "F19", "F20", "F21", "F22", "F23", "F24",
+];
+
+impl From<&str> for KeyboardKey {
+  fn from(key: &str) -> Self {
+    match key {
+      "ArrowUp" | "Up" => Self::ArrowUp,
+      "ArrowDown" | "Down" => Self::ArrowDown,
+      "ArrowLeft" | "Left" => Self::ArrowLeft,
+      "ArrowRight" | "Right" => Self::ArrowRight,
+      "Home" => Self::Home,
+      "End" => Self::End,
+      "PageUp" => Self::PageUp,
+      "PageDown" => Self::PageDown,
+      "Tab" => Self::Tab,
+      "Enter" => Self::Enter,
+      " " | "Space" | "Spacebar" => Self::Space,
+      "Escape" | "Esc" => Self::Escape,
+      "Backspace" => Self::Backspace,
+      "Delete" | "Del" => Self::Delete,
+      "Insert" => Self::Insert,
+      "Shift" => Self::Shift,
+      "Control" => Self::Control,
+      "Alt" => Self::Alt,
+      "Meta" | "OS" => Self::Meta,
+      "CapsLock" => Self::CapsLock,
+      "" | "Unidentified" => Self::Unidentified,
+      key => {
+        if let Some((number, _)) = (1..).zip(FUNCTION_KEYS).find(|(_, name)| *name == key) {
+          Self::F(number)
+        } else if key.chars().count() == 1 {
+          Self::Character(key.to_string())
+        } else {
+          Self::Other(key.to_string())
+        }
+      }
+    }
+  }
+}
+
+impl From<&KeyboardEvent> for KeyboardKey {
+  /// The key of the event. The space key is also recognised from the
+  /// `KeyboardEvent.code`, since some virtual keyboards report it as
+  /// `Unidentified`.
+  fn from(event: &KeyboardEvent) -> Self {
+    match Self::from(event.key().as_str()) {
+      Self::Unidentified if event.code() == "Space" => Self::Space,
+      key => key,
+    }
+  }
+}
+
+impl From<KeyboardEvent> for KeyboardKey {
+  fn from(event: KeyboardEvent) -> Self {
+    Self::from(&event)
+  }
+}
+
+impl fmt::Display for KeyboardKey {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_key_values() {
+    assert_eq!(KeyboardKey::from("Enter"), KeyboardKey::Enter);
+    assert_eq!(KeyboardKey::from(" "), KeyboardKey::Space);
+    assert_eq!(KeyboardKey::from("Esc"), KeyboardKey::Escape);
+    assert_eq!(KeyboardKey::from("F6"), KeyboardKey::F(6));
+    assert_eq!(KeyboardKey::from("a"), KeyboardKey::Character("a".into()));
+    assert_eq!(
+      KeyboardKey::from("MediaPlayPause"),
+      KeyboardKey::Other("MediaPlayPause".into())
+    );
+    assert_eq!(KeyboardKey::from(""), KeyboardKey::Unidentified);
+
+    for key in ["ArrowUp", " ", "F12", "?", "Dead", "Unidentified"] {
+      assert_eq!(KeyboardKey::from(key).as_str(), key);
+    }
+  }
+
+  #[test]
+  fn classifies_keys() {
+    assert!(KeyboardKey::Home.is_navigation() && !KeyboardKey::Enter.is_navigation());
+    assert!(KeyboardKey::Shift.is_modifier() && !KeyboardKey::Space.is_modifier());
+    assert!(KeyboardKey::Space.is_printable() && KeyboardKey::from("a").is_printable());
+    assert!(!KeyboardKey::F(1).is_printable());
+    assert_eq!(KeyboardKey::Space.name(), "Space");
+  }
+
+  #[test]
+  fn flips_horizontal_arrow_keys() {
+    assert_eq!(
+      KeyboardKey::ArrowLeft.logical(Direction::Ltr),
+      KeyboardKey::ArrowLeft
+    );
+    assert_eq!(
+      KeyboardKey::ArrowLeft.logical(Direction::Rtl),
+      KeyboardKey::ArrowRight
+    );
+    assert_eq!(
+      KeyboardKey::ArrowUp.logical(Direction::Rtl),
+      KeyboardKey::ArrowUp
+    );
+  }
+}
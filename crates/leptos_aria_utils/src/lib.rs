@@ -1,27 +1,98 @@
+pub use announcer_batching::*;
+pub use aria_attributes::*;
+pub use controlled_state::*;
+pub use custom_events::*;
+pub use direction::*;
+pub use document_delegation::*;
+pub use event_target::*;
 pub use extend::*;
+pub use field_state::*;
 pub use focus_without_scrolling::*;
 pub use global_listeners::*;
+pub use hydration::*;
+pub use id::*;
+pub use interaction_trace::*;
+pub use keyboard_key::*;
 use leptos::Scope;
+pub use live_announcer::*;
+pub use locale::*;
+pub use merge_props::*;
+pub use number::*;
+pub use orientation::*;
+pub use page_activity::*;
 pub use platform::*;
 pub use run_after_transition::*;
+pub use scheduling::*;
+pub use scroll_into_view::*;
+pub use scroll_parent::*;
 pub use silly_map::*;
+pub use ssr::*;
 pub use traits::*;
+pub use use_description::*;
+pub use use_intersection_observer::*;
+pub use use_media_query::*;
+pub use use_resize_observer::*;
+pub use use_text_field::*;
+pub use use_window_event::*;
+pub use value_label::*;
 pub use virtual_event::*;
+pub use visually_hidden::*;
+pub use weak_element_map::*;
 
+mod announcer_batching;
+mod aria_attributes;
+mod controlled_state;
+mod custom_events;
+mod direction;
+mod document_delegation;
+mod event_target;
 mod extend;
+mod field_state;
 mod focus_without_scrolling;
 mod global_listeners;
+mod hydration;
+mod id;
+mod interaction_trace;
+mod keyboard_key;
+mod live_announcer;
+mod locale;
+mod merge_props;
+mod number;
+mod orientation;
+mod page_activity;
 mod platform;
 mod run_after_transition;
+mod scheduling;
+mod scroll_into_view;
+mod scroll_parent;
 mod silly_map;
+mod ssr;
 mod traits;
+mod use_description;
+mod use_intersection_observer;
+mod use_media_query;
+mod use_resize_observer;
+mod use_text_field;
+mod use_window_event;
+mod value_label;
 mod virtual_event;
+mod visually_hidden;
+mod weak_element_map;
 
 /// Provide any context and values into the scope.
+///
+/// The global listeners are only added once the app is running in the
+/// browser, so this is safe to call while rendering on the server.
 pub fn use_provider(cx: Scope) {
   ElementTransitionsContext::provide(cx);
   TransitionCallbacksContext::provide(cx);
+  TransitionOptionsContext::provide(cx);
   SupportsPreventScrollContext::provide(cx);
+  IdCountContext::provide(cx);
+  IdLinksContext::provide(cx);
+  PlatformContext::provide(cx);
+  DocumentVisibilityContext::provide(cx);
+  WindowFocusContext::provide(cx);
 
-  setup_transition_listener(cx);
+  on_browser(cx, move || setup_transition_listener(cx));
 }
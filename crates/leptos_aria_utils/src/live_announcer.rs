@@ -0,0 +1,224 @@
+use std::cell::RefCell;
+use std::time::Duration;
+
+use leptos::document;
+use leptos::js_sys::Date;
+use leptos::set_timeout;
+use leptos::web_sys::Element;
+
+use crate::is_server;
+
+/// The attribute which identifies the live announcer in the document.
+const LIVE_ANNOUNCER_ATTRIBUTE: &str = "data-live-announcer";
+
+/// The time in milliseconds a message stays in the live region. Long enough
+/// for screen readers to pick it up, short enough not to be found when
+/// navigating the page.
+pub const LIVE_REGION_TIMEOUT: u64 = 7000;
+
+/// Identical messages with the same politeness announced within this many
+/// milliseconds are only announced once.
+pub const LIVE_REGION_DUPLICATE_WINDOW: f64 = 500.0;
+
+/// Styles which hide an element visually while keeping it in the
+/// accessibility tree. Used by the live announcer and screen reader only
+/// controls.
+pub const VISUALLY_HIDDEN_STYLE: &str = "border: 0; clip: rect(0 0 0 0); clip-path: inset(50%); \
+                                     height: 1px; margin: -1px; overflow: hidden; padding: 0; \
+                                     position: absolute; width: 1px; white-space: nowrap;";
+
+/// How urgently a message is announced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Politeness {
+  /// Announced once the screen reader is idle.
+  #[default]
+  Polite,
+  /// Interrupts the current announcement.
+  Assertive,
+}
+
+impl Politeness {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Politeness::Polite => "polite",
+      Politeness::Assertive => "assertive",
+    }
+  }
+}
+
+/// The last announced message.
+#[derive(Clone, Debug, PartialEq)]
+struct Announcement {
+  message: String,
+  politeness: Politeness,
+  announced_at: f64,
+}
+
+thread_local! {
+  static LAST_ANNOUNCEMENT: RefCell<Option<Announcement>> = RefCell::new(None);
+}
+
+/// Announce a message to screen readers through a visually hidden live region
+/// which is added to the document body on first use. The message is removed
+/// after [`LIVE_REGION_TIMEOUT`].
+pub fn announce(message: impl Into<String>, politeness: Politeness) {
+  announce_with_timeout(message, politeness, LIVE_REGION_TIMEOUT);
+}
+
+/// Announce a message which is removed from the live region after `timeout`
+/// milliseconds.
+///
+/// Identical messages announced in quick succession, e.g. by several hooks
+/// reacting to the same change, are only announced once.
+pub fn announce_with_timeout(message: impl Into<String>, politeness: Politeness, timeout: u64) {
+  if is_server() {
+    return;
+  }
+
+  let message: String = message.into();
+
+  if message.trim().is_empty() {
+    return;
+  }
+
+  let now = Date::now();
+  let is_duplicate = LAST_ANNOUNCEMENT.with(|last| {
+    let mut last = last.borrow_mut();
+    let is_duplicate = is_duplicate_announcement(last.as_ref(), &message, politeness, now);
+
+    *last = Some(Announcement {
+      message: message.clone(),
+      politeness,
+      announced_at: now,
+    });
+
+    is_duplicate
+  });
+
+  if is_duplicate {
+    return;
+  }
+
+  let Some(region) = get_live_region(politeness) else {
+    return;
+  };
+
+  let Ok(node) = document().create_element("div") else {
+    return;
+  };
+
+  node.set_text_content(Some(&message));
+  region.append_child(&node).ok();
+
+  set_timeout(move || node.remove(), Duration::from_millis(timeout));
+}
+
+fn is_duplicate_announcement(
+  last: Option<&Announcement>,
+  message: &str,
+  politeness: Politeness,
+  now: f64,
+) -> bool {
+  last.map_or(false, |last| {
+    last.message == message
+      && last.politeness == politeness
+      && now - last.announced_at < LIVE_REGION_DUPLICATE_WINDOW
+  })
+}
+
+/// Remove all pending messages with the provided politeness.
+pub fn clear_announcer(politeness: Politeness) {
+  LAST_ANNOUNCEMENT.with(|last| last.borrow_mut().take());
+
+  if let Some(region) = get_live_region(politeness) {
+    region.set_inner_html("");
+  }
+}
+
+/// Find or create the live region for the provided politeness.
+fn get_live_region(politeness: Politeness) -> Option<Element> {
+  let document = document();
+  let announcer = match document
+    .query_selector(&format!("[{LIVE_ANNOUNCER_ATTRIBUTE}]"))
+    .ok()
+    .flatten()
+  {
+    Some(announcer) => announcer,
+    None => {
+      let announcer = document.create_element("div").ok()?;
+      announcer
+        .set_attribute(LIVE_ANNOUNCER_ATTRIBUTE, "true")
+        .ok()?;
+      announcer
+        .set_attribute("style", VISUALLY_HIDDEN_STYLE)
+        .ok()?;
+
+      for politeness in [Politeness::Assertive, Politeness::Polite] {
+        let region = document.create_element("div").ok()?;
+        let role = match politeness {
+          Politeness::Assertive => "alert",
+          Politeness::Polite => "log",
+        };
+        region.set_attribute("role", role).ok()?;
+        region
+          .set_attribute("aria-live", politeness.as_str())
+          .ok()?;
+        region.set_attribute("aria-relevant", "additions").ok()?;
+        announcer.append_child(&region).ok()?;
+      }
+
+      document.body()?.prepend_with_node_1(&announcer).ok()?;
+      announcer
+    }
+  };
+
+  announcer
+    .query_selector(&format!("[aria-live=\"{}\"]", politeness.as_str()))
+    .ok()
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn skips_rapid_identical_messages() {
+    let last = Announcement {
+      message: "3 results".into(),
+      politeness: Politeness::Polite,
+      announced_at: 1000.0,
+    };
+
+    assert!(is_duplicate_announcement(
+      Some(&last),
+      "3 results",
+      Politeness::Polite,
+      1200.0
+    ));
+    assert!(!is_duplicate_announcement(
+      Some(&last),
+      "3 results",
+      Politeness::Polite,
+      1600.0
+    ));
+    assert!(!is_duplicate_announcement(
+      Some(&last),
+      "3 results",
+      Politeness::Assertive,
+      1200.0
+    ));
+    assert!(!is_duplicate_announcement(
+      Some(&last),
+      "4 results",
+      Politeness::Polite,
+      1200.0
+    ));
+    assert!(!is_duplicate_announcement(
+      None,
+      "3 results",
+      Politeness::Polite,
+      1200.0
+    ));
+  }
+}
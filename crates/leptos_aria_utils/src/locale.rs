@@ -0,0 +1,68 @@
+use leptos::create_rw_signal;
+use leptos::provide_context;
+use leptos::use_context;
+use leptos::window;
+use leptos::IntoSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+use crate::is_server;
+use crate::ContextProvider;
+
+/// The locale of a section of the application. Hooks which format values or
+/// depend on the reading direction read the locale from this context and
+/// fall back to the language of the browser.
+#[derive(Copy, Clone)]
+pub struct LocaleContext(RwSignal<String>);
+
+impl ContextProvider for LocaleContext {
+  type Value = String;
+
+  fn from_leptos_scope(cx: Scope) -> Self {
+    Self(create_rw_signal(cx, default_locale()))
+  }
+
+  fn get(&self) -> Self::Value {
+    self.0.get_untracked()
+  }
+
+  /// Change the locale. Unlike other contexts this notifies subscribers so
+  /// that formatted values and the reading direction update.
+  fn set(&self, value: Self::Value) {
+    self.0.set(value);
+  }
+}
+
+/// Provide the locale for the scope and its children, e.g. `"ar-EG"`. This
+/// overrides any locale provided by a parent scope.
+pub fn provide_locale(cx: Scope, locale: impl Into<String>) -> LocaleContext {
+  let context = LocaleContext(create_rw_signal(cx, locale.into()));
+  provide_context(cx, context);
+  context
+}
+
+/// The locale provided with [`provide_locale`], or the language of the
+/// browser when none was provided.
+pub fn use_locale(cx: Scope) -> Signal<String> {
+  match use_context::<LocaleContext>(cx) {
+    Some(LocaleContext(locale)) => (move || locale.get()).derive_signal(cx),
+    None => {
+      let locale = default_locale();
+      (move || locale.clone()).derive_signal(cx)
+    }
+  }
+}
+
+/// The language of the browser, or `en-US` when rendering on the server.
+pub fn default_locale() -> String {
+  if is_server() {
+    return "en-US".into();
+  }
+
+  window()
+    .navigator()
+    .language()
+    .unwrap_or_else(|| "en-US".into())
+}
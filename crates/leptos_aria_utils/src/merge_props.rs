@@ -0,0 +1,356 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use leptos::on_cleanup;
+use leptos::provide_context;
+use leptos::use_context;
+use leptos::web_sys::Event;
+use leptos::JsCast;
+use leptos::Scope;
+
+use crate::IdList;
+
+type PropsHandler = Rc<dyn Fn(Event)>;
+
+/// The ids which were replaced by [`merge_ids`], mapped to the id the element
+/// is rendered with.
+///
+/// Provided by [`use_provider`](crate::use_provider), so the links of one
+/// render on the server don't rewrite the ids of the next. Each link is
+/// removed when the scope which merged the ids is disposed.
+#[derive(Clone, Default)]
+pub(crate) struct IdLinksContext(Rc<RefCell<HashMap<String, String>>>);
+
+impl IdLinksContext {
+  pub(crate) fn provide(cx: Scope) {
+    if use_context::<Self>(cx).is_none() {
+      provide_context(cx, Self::default());
+    }
+  }
+}
+
+/// The props which several hooks may set on the same element, e.g. when a
+/// button uses `use_press` together with a tooltip trigger.
+///
+/// Combine them with [`merge_props`] or [`merge_props!`](crate::merge_props!)
+/// and render the result.
+///
+/// ```ignore
+/// let props = merge_props!(
+///   cx,
+///   ElementProps::default().on("keydown", move |event: KeyboardEvent| on_key_down(event)),
+///   ElementProps {
+///     class: Some("button".into()),
+///     ..Default::default()
+///   },
+/// );
+///
+/// view! { cx,
+///   <button class=props.class.clone() on:keydown=props.handler("keydown")>"Save"</button>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ElementProps {
+  pub id: Option<String>,
+  pub class: Option<String>,
+  pub style: Option<String>,
+  pub aria_describedby: Option<String>,
+  pub aria_labelledby: Option<String>,
+  handlers: Vec<(&'static str, PropsHandler)>,
+}
+
+impl ElementProps {
+  /// Add a handler for the event type. The event is cast to `E`.
+  pub fn on<E>(mut self, event_type: &'static str, handler: impl Fn(E) + 'static) -> Self
+  where
+    E: JsCast,
+  {
+    let handler: PropsHandler = Rc::new(move |event: Event| handler(event.unchecked_into()));
+    self.handlers.push((event_type, handler));
+    self
+  }
+
+  /// The event types which have handlers.
+  pub fn event_types(&self) -> Vec<&'static str> {
+    let mut event_types: Vec<&'static str> = vec![];
+
+    for (event_type, _) in &self.handlers {
+      if !event_types.contains(event_type) {
+        event_types.push(event_type);
+      }
+    }
+
+    event_types
+  }
+
+  /// A handler which calls every handler of the event type in the order they
+  /// were merged. The remaining handlers are skipped once one of them stops
+  /// the propagation of the event.
+  pub fn handler<E>(&self, event_type: &str) -> impl Fn(E) + 'static
+  where
+    E: AsRef<Event>,
+  {
+    let handlers: Vec<PropsHandler> = self
+      .handlers
+      .iter()
+      .filter(|(handler_event_type, _)| *handler_event_type == event_type)
+      .map(|(_, handler)| handler.clone())
+      .collect();
+
+    move |event: E| {
+      let event: &Event = event.as_ref();
+
+      for handler in &handlers {
+        handler(event.clone());
+
+        if event.cancel_bubble() {
+          break;
+        }
+      }
+    }
+  }
+}
+
+/// Merge the props of two hooks for the same element.
+///
+/// * Event handlers are chained, see [`ElementProps::handler`].
+/// * `class` and `style` are combined, and later styles take precedence.
+/// * Different ids are linked with [`merge_ids`].
+/// * The ids of `aria-describedby` and `aria-labelledby` are combined without
+///   duplicates.
+pub fn merge_props(cx: Scope, a: ElementProps, b: ElementProps) -> ElementProps {
+  let mut handlers = a.handlers;
+  handlers.extend(b.handlers);
+
+  ElementProps {
+    id: merge_ids(cx, a.id.as_deref(), b.id.as_deref()),
+    class: merge_classes([a.class.as_deref(), b.class.as_deref()]),
+    style: merge_styles([a.style.as_deref(), b.style.as_deref()]),
+    aria_describedby: merge_id_lists(
+      cx,
+      [a.aria_describedby.as_deref(), b.aria_describedby.as_deref()],
+    ),
+    aria_labelledby: merge_id_lists(
+      cx,
+      [a.aria_labelledby.as_deref(), b.aria_labelledby.as_deref()],
+    ),
+    handlers,
+  }
+}
+
+/// Merge the props of any number of hooks, from left to right.
+///
+/// ```ignore
+/// let props = merge_props!(cx, press_props, tooltip_props, focus_props);
+/// ```
+#[macro_export]
+macro_rules! merge_props {
+  ($cx:expr, $first:expr $(, $rest:expr)* $(,)?) => {{
+    let props = $first;
+    $(let props = $crate::merge_props($cx, props, $rest);)*
+    props
+  }};
+}
+
+/// Chain the callbacks of hook results, e.g. the `on_key_down` of two hooks
+/// for the same element, with the same rules as [`ElementProps::handler`].
+pub fn chain_handlers<E>(
+  handlers: impl IntoIterator<Item = Rc<Box<dyn Fn(E)>>>,
+) -> Rc<Box<dyn Fn(E)>>
+where
+  E: AsRef<Event> + Clone + 'static,
+{
+  let handlers: Vec<_> = handlers.into_iter().collect();
+
+  Rc::new(Box::new(move |event: E| {
+    for handler in &handlers {
+      handler(event.clone());
+
+      if event.as_ref().cancel_bubble() {
+        break;
+      }
+    }
+  }))
+}
+
+/// Combine class names, skipping duplicates.
+pub fn merge_classes<'a>(classes: impl IntoIterator<Item = Option<&'a str>>) -> Option<String> {
+  join_unique(
+    classes
+      .into_iter()
+      .flatten()
+      .flat_map(str::split_whitespace),
+  )
+}
+
+/// Combine inline styles. Later declarations take precedence over earlier
+/// declarations of the same property.
+pub fn merge_styles<'a>(styles: impl IntoIterator<Item = Option<&'a str>>) -> Option<String> {
+  let declarations: Vec<&str> = styles
+    .into_iter()
+    .flatten()
+    .flat_map(|style| style.split(';'))
+    .map(str::trim)
+    .filter(|declaration| !declaration.is_empty())
+    .collect();
+
+  (!declarations.is_empty()).then(|| format!("{};", declarations.join("; ")))
+}
+
+/// Combine lists of ids for attributes like `aria-describedby`, skipping
+/// duplicates. Ids which were replaced by [`merge_ids`] are resolved to the
+/// id the element is rendered with.
+pub fn merge_id_lists<'a>(
+  cx: Scope,
+  lists: impl IntoIterator<Item = Option<&'a str>>,
+) -> Option<String> {
+  lists
+    .into_iter()
+    .flatten()
+    .collect::<IdList>()
+    .resolved(cx)
+    .to_value()
+}
+
+/// Merge the ids two hooks generated for the same element. The first id is
+/// kept and the second is linked to it, so that the attributes which
+/// reference the element through the second id can be resolved with
+/// [`resolve_id`].
+///
+/// The link lasts until the scope is disposed. Ids aren't linked without
+/// [`use_provider`](crate::use_provider).
+pub fn merge_ids(cx: Scope, a: Option<&str>, b: Option<&str>) -> Option<String> {
+  match (a, b) {
+    (Some(a), Some(b)) => {
+      let a = resolve_id(cx, a);
+      let b = resolve_id(cx, b);
+
+      if let (true, Some(links)) = (a != b, use_context::<IdLinksContext>(cx)) {
+        links.0.borrow_mut().insert(b.clone(), a.clone());
+
+        let linked = a.clone();
+        on_cleanup(cx, move || {
+          let mut links = links.0.borrow_mut();
+
+          if links.get(&b) == Some(&linked) {
+            links.remove(&b);
+          }
+        });
+      }
+
+      Some(a)
+    }
+    (Some(id), None) | (None, Some(id)) => Some(resolve_id(cx, id)),
+    (None, None) => None,
+  }
+}
+
+/// The id an element is rendered with, after its ids were merged with
+/// [`merge_ids`].
+pub fn resolve_id(cx: Scope, id: &str) -> String {
+  let Some(links) = use_context::<IdLinksContext>(cx) else {
+    return id.to_string();
+  };
+
+  let links = links.0.borrow();
+  let mut id = id;
+
+  while let Some(linked) = links.get(id) {
+    id = linked;
+  }
+
+  id.to_string()
+}
+
+fn join_unique<'a>(values: impl IntoIterator<Item = &'a str>) -> Option<String> {
+  let mut unique: Vec<&str> = vec![];
+
+  for value in values {
+    if !unique.contains(&value) {
+      unique.push(value);
+    }
+  }
+
+  (!unique.is_empty()).then(|| unique.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+  use crate::use_provider;
+
+  #[test]
+  fn merges_classes_and_styles() {
+    assert_eq!(
+      merge_classes([Some("button  primary"), None, Some("primary is-pressed")]),
+      Some("button primary is-pressed".into())
+    );
+    assert_eq!(merge_classes([None, Some(" ")]), None);
+
+    assert_eq!(
+      merge_styles([Some("color: red;"), Some(" display: none ;; ")]),
+      Some("color: red; display: none;".into())
+    );
+    assert_eq!(merge_styles([Some(";"), None]), None);
+  }
+
+  #[test]
+  fn links_merged_ids() {
+    let (resolved, unlinked) = run_scope(create_runtime(), |cx| {
+      use_provider(cx);
+      let mut resolved = vec![];
+
+      let disposer = cx.child_scope(|cx| {
+        assert_eq!(merge_ids(cx, Some("field"), None), Some("field".into()));
+        assert_eq!(merge_ids(cx, None, None), None);
+        assert_eq!(
+          merge_ids(cx, Some("field"), Some("tooltip-trigger")),
+          Some("field".into())
+        );
+        assert_eq!(
+          merge_ids(cx, Some("label"), Some("label")),
+          Some("label".into())
+        );
+
+        resolved.push(resolve_id(cx, "tooltip-trigger"));
+        resolved.push(resolve_id(cx, "label"));
+        resolved.extend(merge_id_lists(
+          cx,
+          [Some("tooltip-trigger hint"), Some("field error")],
+        ));
+        assert_eq!(merge_id_lists(cx, [None, Some("")]), None);
+      });
+
+      disposer.dispose();
+      (resolved, resolve_id(cx, "tooltip-trigger"))
+    });
+
+    assert_eq!(resolved, vec!["field", "label", "field hint error"]);
+    assert_eq!(unlinked, "tooltip-trigger");
+  }
+
+  #[test]
+  fn renders_do_not_share_links() {
+    let first = run_scope(create_runtime(), |cx| {
+      use_provider(cx);
+      merge_ids(cx, Some("aria-0"), Some("aria-1"));
+      resolve_id(cx, "aria-1")
+    });
+    let second = run_scope(create_runtime(), |cx| {
+      use_provider(cx);
+      resolve_id(cx, "aria-1")
+    });
+    let without_provider = run_scope(create_runtime(), |cx| {
+      merge_ids(cx, Some("aria-0"), Some("aria-1"));
+      resolve_id(cx, "aria-1")
+    });
+
+    assert_eq!(first, "aria-0");
+    assert_eq!(second, "aria-1");
+    assert_eq!(without_provider, "aria-1");
+  }
+}
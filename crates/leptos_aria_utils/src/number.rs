@@ -0,0 +1,48 @@
+/// Snap `value` to the nearest step counted from `min_value` and clamp it to
+/// the limits. The result is rounded to the precision of the step so that
+/// floating point errors don't accumulate.
+pub fn clamp_value(value: f64, min_value: Option<f64>, max_value: Option<f64>, step: f64) -> f64 {
+  let base = min_value.unwrap_or(0.0);
+  let snapped = if step > 0.0 {
+    base + ((value - base) / step).round() * step
+  } else {
+    value
+  };
+
+  let precision = 10_f64.powi(decimal_places(step).max(decimal_places(base)) as i32);
+  let mut value = (snapped * precision).round() / precision;
+
+  if let Some(max_value) = max_value {
+    value = value.min(max_value);
+  }
+
+  if let Some(min_value) = min_value {
+    value = value.max(min_value);
+  }
+
+  value
+}
+
+fn decimal_places(value: f64) -> usize {
+  let formatted = value.to_string();
+  formatted
+    .split_once('.')
+    .map_or(0, |(_, decimals)| decimals.len())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn snaps_to_the_step() {
+    assert_eq!(clamp_value(0.1 + 0.2, None, None, 0.1), 0.3);
+    assert_eq!(clamp_value(7.0, Some(1.0), None, 5.0), 6.0);
+  }
+
+  #[test]
+  fn clamps_to_the_limits() {
+    assert_eq!(clamp_value(12.0, Some(1.0), Some(10.0), 1.0), 10.0);
+    assert_eq!(clamp_value(-3.0, Some(1.0), Some(10.0), 1.0), 1.0);
+  }
+}
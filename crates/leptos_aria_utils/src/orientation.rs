@@ -0,0 +1,17 @@
+/// The orientation of a component such as a radio group or slider.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Orientation {
+  #[default]
+  Horizontal,
+  Vertical,
+}
+
+impl Orientation {
+  /// The value of the `aria-orientation` attribute.
+  pub fn as_aria_orientation(&self) -> &'static str {
+    match self {
+      Orientation::Horizontal => "horizontal",
+      Orientation::Vertical => "vertical",
+    }
+  }
+}
@@ -0,0 +1,125 @@
+use leptos::create_rw_signal;
+use leptos::document;
+use leptos::web_sys::Event;
+use leptos::window;
+use leptos::IntoSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+use crate::on_browser;
+use crate::ContextProvider;
+use crate::GlobalListeners;
+
+/// Whether the document is visible, shared by the hooks within the scope.
+/// Follows `visibilitychange` events once the app is running in the browser.
+#[derive(Copy, Clone)]
+pub struct DocumentVisibilityContext(RwSignal<bool>);
+
+impl ContextProvider for DocumentVisibilityContext {
+  type Value = bool;
+
+  fn from_leptos_scope(cx: Scope) -> Self {
+    let is_visible = create_rw_signal(cx, true);
+
+    on_browser(cx, move || {
+      update(is_visible, !document().hidden());
+
+      let listeners = GlobalListeners::new(cx);
+      listeners.add_event_listener(
+        document(),
+        "visibilitychange",
+        move |_: Event| update(is_visible, !document().hidden()),
+        false,
+      );
+    });
+
+    Self(is_visible)
+  }
+
+  fn get(&self) -> Self::Value {
+    self.0.get_untracked()
+  }
+
+  fn set(&self, value: Self::Value) {
+    self.0.set(value);
+  }
+}
+
+/// Whether the window has focus, shared by the hooks within the scope.
+/// Follows the `focus` and `blur` events of the window once the app is running
+/// in the browser.
+#[derive(Copy, Clone)]
+pub struct WindowFocusContext(RwSignal<bool>);
+
+impl ContextProvider for WindowFocusContext {
+  type Value = bool;
+
+  fn from_leptos_scope(cx: Scope) -> Self {
+    let has_focus = create_rw_signal(cx, true);
+
+    on_browser(cx, move || {
+      update(has_focus, document().has_focus().unwrap_or(true));
+
+      let listeners = GlobalListeners::new(cx);
+      listeners.add_event_listener(
+        window(),
+        "focus",
+        move |_: Event| update(has_focus, true),
+        false,
+      );
+      listeners.add_event_listener(
+        window(),
+        "blur",
+        move |_: Event| update(has_focus, false),
+        false,
+      );
+    });
+
+    Self(has_focus)
+  }
+
+  fn get(&self) -> Self::Value {
+    self.0.get_untracked()
+  }
+
+  fn set(&self, value: Self::Value) {
+    self.0.set(value);
+  }
+}
+
+/// Whether the document is visible, which is `false` while the tab is in the
+/// background or the window is minimized.
+///
+/// ```ignore
+/// let is_visible = use_document_visibility(cx);
+/// create_effect(cx, move |_| {
+///   if !is_visible.get() {
+///     pause_video();
+///   }
+/// });
+/// ```
+///
+/// Always `true` on the server and during hydration.
+pub fn use_document_visibility(cx: Scope) -> Signal<bool> {
+  let context = DocumentVisibilityContext::provide(cx);
+
+  (move || context.0.get()).derive_signal(cx)
+}
+
+/// Whether the window has focus, which is `false` once the user switches to
+/// another window or application, or focuses an iframe.
+///
+/// Always `true` on the server and during hydration.
+pub fn use_window_focus(cx: Scope) -> Signal<bool> {
+  let context = WindowFocusContext::provide(cx);
+
+  (move || context.0.get()).derive_signal(cx)
+}
+
+fn update(signal: RwSignal<bool>, value: bool) {
+  if signal.get_untracked() != value {
+    signal.set(value);
+  }
+}
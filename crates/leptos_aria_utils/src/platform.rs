@@ -1,46 +1,151 @@
+use leptos::create_rw_signal;
 use leptos::js_sys::Array;
+use leptos::js_sys::Function;
+use leptos::js_sys::Promise;
 use leptos::js_sys::Reflect;
+use leptos::wasm_bindgen::closure::Closure;
+use leptos::wasm_bindgen::JsValue;
+use leptos::web_sys::Event;
 use leptos::window;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+use crate::is_server;
+use crate::on_browser;
+use crate::ContextProvider;
+use crate::GlobalListeners;
 
 pub fn is_ios() -> bool {
-  is_iphone() || is_ipad()
+  PlatformInfo::detect().is_ios()
 }
 
 pub fn is_iphone() -> bool {
-  test_platform("iphone")
+  PlatformInfo::detect().is_iphone()
 }
 
 pub fn is_ipad() -> bool {
-  test_platform("ipad")
+  PlatformInfo::detect().is_ipad()
 }
 
 pub fn is_android() -> bool {
-  test_user_agent("android")
+  PlatformInfo::detect().is_android()
 }
 
 pub fn is_chrome() -> bool {
-  test_user_agent("chrome")
+  PlatformInfo::detect().is_chrome()
 }
 
 pub fn is_mac() -> bool {
-  test_platform("mac")
+  PlatformInfo::detect().is_mac()
 }
 
 pub fn is_apple_device() -> bool {
-  is_ios() || is_mac()
+  PlatformInfo::detect().is_apple_device()
 }
 
 pub fn is_webkit() -> bool {
-  test_user_agent("applewebkit") && !is_chrome()
+  PlatformInfo::detect().is_webkit()
+}
+
+pub fn is_firefox() -> bool {
+  PlatformInfo::detect().is_firefox()
+}
+
+pub fn is_safari() -> bool {
+  PlatformInfo::detect().is_safari()
+}
+
+pub fn is_windows() -> bool {
+  PlatformInfo::detect().is_windows()
 }
 
-fn test_user_agent(search_text: impl AsRef<str>) -> bool {
-  match get_user_agent() {
-    Some(user_agent) => user_agent.to_lowercase().contains(search_text.as_ref()),
-    None => false,
+/// The platform of the device as reported by the browser.
+///
+/// The platform is unknown on the server, where every check is `false`. Use
+/// [`use_platform`] within hooks so that the first render in the browser
+/// matches the server and updates once the platform is known.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlatformInfo {
+  /// The platform, e.g. `macOS`, `MacIntel` or `iPhone`.
+  pub platform: String,
+  /// The brands of `navigator.userAgentData`, one per line, falling back to
+  /// `navigator.userAgent` when they aren't supported.
+  pub user_agent: String,
+  /// The model of the device from the high entropy values of
+  /// `navigator.userAgentData`, e.g. `Pixel 7`. Usually empty.
+  pub model: String,
+}
+
+impl PlatformInfo {
+  /// Read the platform from the `navigator` synchronously.
+  pub fn detect() -> Self {
+    if is_server() {
+      return Self::default();
+    }
+
+    Self {
+      platform: get_platform().unwrap_or_default(),
+      user_agent: get_user_agent().unwrap_or_default(),
+      model: String::new(),
+    }
+  }
+
+  pub fn is_ios(&self) -> bool {
+    self.is_iphone() || self.is_ipad()
+  }
+
+  pub fn is_iphone(&self) -> bool {
+    contains(&self.platform, "iphone") || contains(&self.model, "iphone")
+  }
+
+  pub fn is_ipad(&self) -> bool {
+    contains(&self.platform, "ipad") || contains(&self.model, "ipad")
+  }
+
+  pub fn is_android(&self) -> bool {
+    contains(&self.user_agent, "android") || contains(&self.platform, "android")
+  }
+
+  pub fn is_chrome(&self) -> bool {
+    contains(&self.user_agent, "chrome")
+  }
+
+  pub fn is_mac(&self) -> bool {
+    contains(&self.platform, "mac")
+  }
+
+  pub fn is_apple_device(&self) -> bool {
+    self.is_ios() || self.is_mac()
+  }
+
+  pub fn is_webkit(&self) -> bool {
+    contains(&self.user_agent, "applewebkit") && !self.is_chrome()
+  }
+
+  pub fn is_firefox(&self) -> bool {
+    contains(&self.user_agent, "firefox")
+  }
+
+  /// Safari, including every browser on iOS which is required to use its
+  /// engine.
+  pub fn is_safari(&self) -> bool {
+    self.is_webkit() && contains(&self.user_agent, "safari") && !self.is_android()
+  }
+
+  pub fn is_windows(&self) -> bool {
+    contains(&self.platform, "win")
   }
 }
 
+fn contains(value: &str, search_text: &str) -> bool {
+  value.to_lowercase().contains(search_text)
+}
+
+/// The brands of `navigator.userAgentData`, falling back to the user agent.
 fn get_user_agent() -> Option<String> {
   Reflect::get(&window().navigator(), &"userAgentData".into())
     .ok()
@@ -51,14 +156,7 @@ fn get_user_agent() -> Option<String> {
         .join("\n")
         .as_string()
     })
-    .or_else(|| window().navigator().platform().ok())
-}
-
-fn test_platform(search_text: impl AsRef<str>) -> bool {
-  match get_platform() {
-    Some(platform) => platform.to_lowercase().contains(search_text.as_ref()),
-    None => false,
-  }
+    .or_else(|| window().navigator().user_agent().ok())
 }
 
 fn get_platform() -> Option<String> {
@@ -68,3 +166,306 @@ fn get_platform() -> Option<String> {
     .and_then(|platform| platform.as_string())
     .or_else(|| window().navigator().platform().ok())
 }
+
+/// The platform of the device, shared by the hooks within the scope.
+///
+/// The platform is unknown until the app is running in the browser. It is
+/// then read from the `navigator`, and updated again once the asynchronous
+/// high entropy values of `navigator.userAgentData` resolve.
+#[derive(Copy, Clone)]
+pub struct PlatformContext(RwSignal<PlatformInfo>);
+
+impl ContextProvider for PlatformContext {
+  type Value = PlatformInfo;
+
+  fn from_leptos_scope(cx: Scope) -> Self {
+    let platform = create_rw_signal(cx, Self::Value::default());
+
+    on_browser(cx, move || {
+      platform.set(PlatformInfo::detect());
+      request_high_entropy_values(platform);
+    });
+
+    Self(platform)
+  }
+
+  fn get(&self) -> Self::Value {
+    self.0.get_untracked()
+  }
+
+  fn set(&self, value: Self::Value) {
+    self.0.set(value);
+  }
+}
+
+/// The platform of the device as a signal, which is unknown on the server and
+/// during hydration and updates once the app is running in the browser.
+pub fn use_platform(cx: Scope) -> Signal<PlatformInfo> {
+  let context = PlatformContext::provide(cx);
+
+  (move || context.0.get()).derive_signal(cx)
+}
+
+/// Ask for the platform and model with `userAgentData.getHighEntropyValues`,
+/// which resolves asynchronously in the browsers which support it.
+fn request_high_entropy_values(platform: RwSignal<PlatformInfo>) {
+  let Some(data) = Reflect::get(&window().navigator(), &"userAgentData".into())
+    .ok()
+    .filter(|data| data.is_object())
+  else {
+    return;
+  };
+  let Some(get_high_entropy_values) = Reflect::get(&data, &"getHighEntropyValues".into())
+    .ok()
+    .and_then(|function| function.dyn_into::<Function>().ok())
+  else {
+    return;
+  };
+  let hints = Array::of2(&"platform".into(), &"model".into());
+  let Some(promise) = get_high_entropy_values
+    .call1(&data, &hints)
+    .ok()
+    .and_then(|promise| promise.dyn_into::<Promise>().ok())
+  else {
+    return;
+  };
+
+  let on_resolve = Closure::once(move |values: JsValue| {
+    let read = |name: &str| {
+      Reflect::get(&values, &name.into())
+        .ok()
+        .and_then(|value| value.as_string())
+        .filter(|value| !value.is_empty())
+    };
+    let mut info = platform.get_untracked();
+
+    if let Some(value) = read("platform") {
+      info.platform = value;
+    }
+
+    if let Some(value) = read("model") {
+      info.model = value;
+    }
+
+    if info != platform.get_untracked() {
+      platform.set(info);
+    }
+  });
+
+  let _ = promise.then(&on_resolve);
+  on_resolve.forget();
+}
+
+/// The media queries which describe the input capabilities of the device.
+const ANY_HOVER_QUERY: &str = "(any-hover: hover)";
+const ANY_FINE_POINTER_QUERY: &str = "(any-pointer: fine)";
+const ANY_COARSE_POINTER_QUERY: &str = "(any-pointer: coarse)";
+const FINE_POINTER_QUERY: &str = "(pointer: fine)";
+const COARSE_POINTER_QUERY: &str = "(pointer: coarse)";
+
+/// The accuracy of a pointing device, as classified by the `pointer` media
+/// feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PointerAccuracy {
+  /// An accurate pointer such as a mouse, trackpad or stylus.
+  #[default]
+  Fine,
+  /// An inaccurate pointer such as a finger, which needs larger targets.
+  Coarse,
+  /// There is no pointing device, e.g. a TV navigated with a remote.
+  None,
+}
+
+/// The input capabilities of the device. Prefer these over user agent checks
+/// when deciding how an interaction should behave, since a tablet with a
+/// trackpad can hover and a touch screen laptop has both pointer types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PointerCapabilities {
+  /// Whether any input can hover over elements.
+  pub can_hover: bool,
+  /// Whether any input is an accurate pointer such as a mouse.
+  pub has_fine_pointer: bool,
+  /// Whether any input is an inaccurate pointer such as a finger.
+  pub has_coarse_pointer: bool,
+  /// The maximum number of simultaneous touch points.
+  pub max_touch_points: i32,
+  /// The accuracy of the primary input, e.g. [`PointerAccuracy::Coarse`] on a
+  /// phone even when a stylus is connected.
+  pub primary_pointer: PointerAccuracy,
+}
+
+impl Default for PointerCapabilities {
+  /// Assume a desktop device when the capabilities can't be detected, for
+  /// example while rendering on the server.
+  fn default() -> Self {
+    Self {
+      can_hover: true,
+      has_fine_pointer: true,
+      has_coarse_pointer: false,
+      max_touch_points: 0,
+      primary_pointer: PointerAccuracy::Fine,
+    }
+  }
+}
+
+impl PointerCapabilities {
+  /// Detect the current capabilities of the device.
+  pub fn detect() -> Self {
+    if is_server() {
+      return Self::default();
+    }
+
+    let defaults = Self::default();
+
+    Self {
+      can_hover: matches_media(ANY_HOVER_QUERY).unwrap_or(defaults.can_hover),
+      has_fine_pointer: matches_media(ANY_FINE_POINTER_QUERY).unwrap_or(defaults.has_fine_pointer),
+      has_coarse_pointer: matches_media(ANY_COARSE_POINTER_QUERY)
+        .unwrap_or(defaults.has_coarse_pointer),
+      max_touch_points: window().navigator().max_touch_points(),
+      primary_pointer: detect_primary_pointer().unwrap_or(defaults.primary_pointer),
+    }
+  }
+
+  /// Whether the device supports touch input.
+  pub fn supports_touch(&self) -> bool {
+    self.max_touch_points > 0 || self.has_coarse_pointer
+  }
+
+  /// Whether touch is the only way to interact with the device. Hover effects
+  /// such as tooltips should be skipped and overlays are better presented as
+  /// trays than popovers.
+  pub fn is_touch_only(&self) -> bool {
+    !self.can_hover && !self.has_fine_pointer && self.supports_touch()
+  }
+}
+
+/// Whether the device can hover. See [`PointerCapabilities::can_hover`].
+pub fn can_hover() -> bool {
+  PointerCapabilities::detect().can_hover
+}
+
+/// Whether the device supports touch input. See
+/// [`PointerCapabilities::supports_touch`].
+pub fn is_touch_device() -> bool {
+  PointerCapabilities::detect().supports_touch()
+}
+
+/// The accuracy of the primary input. See
+/// [`PointerCapabilities::primary_pointer`].
+pub fn primary_pointer() -> PointerAccuracy {
+  PointerCapabilities::detect().primary_pointer
+}
+
+/// Whether touch is the only input. See
+/// [`PointerCapabilities::is_touch_only`].
+pub fn is_touch_only() -> bool {
+  PointerCapabilities::detect().is_touch_only()
+}
+
+/// The input capabilities of the device as a signal which updates when an
+/// input is connected or removed, e.g. when a keyboard with a trackpad is
+/// attached to a tablet.
+pub fn use_pointer_capabilities(cx: Scope) -> Signal<PointerCapabilities> {
+  let capabilities = create_rw_signal(cx, PointerCapabilities::detect());
+
+  if is_server() {
+    return (move || capabilities.get()).derive_signal(cx);
+  }
+
+  let listeners = GlobalListeners::new(cx);
+
+  for query in [
+    ANY_HOVER_QUERY,
+    ANY_FINE_POINTER_QUERY,
+    ANY_COARSE_POINTER_QUERY,
+    FINE_POINTER_QUERY,
+    COARSE_POINTER_QUERY,
+  ] {
+    let Some(media_query_list) = window().match_media(query).ok().flatten() else {
+      continue;
+    };
+
+    let on_change = move |_: Event| {
+      let detected = PointerCapabilities::detect();
+
+      if capabilities.get_untracked() != detected {
+        capabilities.set(detected);
+      }
+    };
+
+    listeners.add_event_listener(media_query_list, "change", on_change, false);
+  }
+
+  (move || capabilities.get()).derive_signal(cx)
+}
+
+fn detect_primary_pointer() -> Option<PointerAccuracy> {
+  if matches_media(FINE_POINTER_QUERY)? {
+    Some(PointerAccuracy::Fine)
+  } else if matches_media(COARSE_POINTER_QUERY)? {
+    Some(PointerAccuracy::Coarse)
+  } else {
+    Some(PointerAccuracy::None)
+  }
+}
+
+fn matches_media(query: &str) -> Option<bool> {
+  window()
+    .match_media(query)
+    .ok()
+    .flatten()
+    .map(|media_query_list| media_query_list.matches())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn checks_the_platform_and_model() {
+    let mac = PlatformInfo {
+      platform: "macOS".into(),
+      user_agent: "Chromium\nGoogle Chrome".into(),
+      model: String::new(),
+    };
+    let ipad = PlatformInfo {
+      platform: "MacIntel".into(),
+      user_agent: "AppleWebKit".into(),
+      model: "iPad".into(),
+    };
+
+    assert!(mac.is_mac() && mac.is_apple_device() && mac.is_chrome());
+    assert!(!mac.is_ios() && !mac.is_webkit());
+    assert!(ipad.is_ipad() && ipad.is_ios() && ipad.is_webkit());
+    assert!(!PlatformInfo::default().is_apple_device());
+  }
+
+  #[test]
+  fn checks_the_browser_from_the_user_agent() {
+    let firefox = PlatformInfo {
+      platform: "Win32".into(),
+      user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:109.0) Gecko/20100101 \
+                   Firefox/115.0"
+        .into(),
+      model: String::new(),
+    };
+    let safari = PlatformInfo {
+      platform: "MacIntel".into(),
+      user_agent: "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, \
+                   like Gecko) Version/16.5 Safari/605.1.15"
+        .into(),
+      model: String::new(),
+    };
+    let chrome = PlatformInfo {
+      platform: "Windows".into(),
+      user_agent: "Not.A/Brand\nChromium\nGoogle Chrome".into(),
+      model: String::new(),
+    };
+
+    assert!(firefox.is_firefox() && firefox.is_windows());
+    assert!(!firefox.is_safari() && !firefox.is_webkit());
+    assert!(safari.is_safari() && safari.is_mac() && !safari.is_windows());
+    assert!(chrome.is_chrome() && chrome.is_windows() && !chrome.is_safari());
+  }
+}
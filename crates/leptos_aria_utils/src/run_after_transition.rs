@@ -1,12 +1,25 @@
 use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
 use std::ptr::eq;
 use std::rc::Rc;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+use std::time::Duration;
 
 use leptos::create_rw_signal;
 use leptos::document;
+use leptos::js_sys::Array;
+use leptos::js_sys::Function;
 use leptos::js_sys::JsString;
+use leptos::js_sys::Promise;
+use leptos::js_sys::Reflect;
 use leptos::request_animation_frame;
+use leptos::set_timeout;
 use leptos::wasm_bindgen::prelude::Closure;
+use leptos::wasm_bindgen::JsValue;
+use leptos::web_sys::AnimationEvent;
 use leptos::web_sys::Element;
 use leptos::web_sys::Event;
 use leptos::web_sys::TransitionEvent;
@@ -16,21 +29,27 @@ use leptos::Scope;
 use leptos::UntrackedGettableSignal;
 use leptos::UntrackedSettableSignal;
 
-use crate::silly_map::Map;
+use crate::is_server;
 use crate::silly_map::Set;
 use crate::ContextProvider;
-
-/// We store a global list of elements that are currently transitioning,
-/// mapped to a set of CSS properties that are transitioning for that element.
-/// This is necessary rather than a simple count of transitions because of
-/// browser bugs, e.g. Chrome sometimes fires both transitionend and
-/// transitioncancel rather than one or the other. So we need to track what's
-/// actually transitioning so that we can ignore these duplicate events.
+use crate::GlobalListeners;
+use crate::WeakElementMap;
+
+/// We store a global list of elements that are currently transitioning or
+/// running a CSS animation, mapped to a set of the CSS properties and
+/// animation names which are running for that element. This is necessary
+/// rather than a simple count of transitions because of browser bugs, e.g.
+/// Chrome sometimes fires both transitionend and transitioncancel rather than
+/// one or the other. So we need to track what's actually transitioning so
+/// that we can ignore these duplicate events.
+///
+/// Elements which are removed while transitioning never finish, so they are
+/// only held weakly and swept once they are disconnected.
 #[derive(Copy, Clone)]
-pub(crate) struct ElementTransitionsContext(RwSignal<Map<Element, Set<JsString>>>);
+pub(crate) struct ElementTransitionsContext(RwSignal<WeakElementMap<Set<JsString>>>);
 
 impl ContextProvider for ElementTransitionsContext {
-  type Value = Map<Element, Set<JsString>>;
+  type Value = WeakElementMap<Set<JsString>>;
 
   fn from_leptos_scope(cx: Scope) -> Self {
     Self(create_rw_signal(cx, Default::default()))
@@ -51,17 +70,63 @@ impl ContextProvider for ElementTransitionsContext {
   }
 }
 
-type TransitionCallback = Rc<Box<dyn Fn()>>;
+type TransitionCallback = Box<dyn FnOnce()>;
+
+#[derive(Default)]
+struct TransitionQueueState {
+  callbacks: Vec<TransitionCallback>,
+  /// The number of times the queue was flushed, so that the timeout of an
+  /// earlier batch of callbacks doesn't flush a later batch too early.
+  flush_count: usize,
+}
+
+/// The callbacks to call once there are no transitioning elements.
+///
+/// Clones share the same queue.
+#[derive(Clone, Default)]
+pub(crate) struct TransitionQueue(Rc<RefCell<TransitionQueueState>>);
+
+impl TransitionQueue {
+  /// Add a callback. Returns `true` when it is the first callback since the
+  /// last flush.
+  fn push(&self, callback: TransitionCallback) -> bool {
+    let mut state = self.0.borrow_mut();
+    state.callbacks.push(callback);
+    state.callbacks.len() == 1
+  }
+
+  fn flush_count(&self) -> usize {
+    self.0.borrow().flush_count
+  }
+
+  /// Call the queued callbacks. The queue isn't borrowed while they run, so
+  /// they can queue new callbacks.
+  fn flush(&self) {
+    let callbacks = {
+      let mut state = self.0.borrow_mut();
+
+      if state.callbacks.is_empty() {
+        return;
+      }
+
+      state.flush_count += 1;
+      std::mem::take(&mut state.callbacks)
+    };
+
+    for callback in callbacks {
+      callback();
+    }
+  }
+}
 
-/// A list of callbacks to call once there are no transitioning elements.
 #[derive(Copy, Clone)]
-pub(crate) struct TransitionCallbacksContext(RwSignal<Vec<TransitionCallback>>);
+pub(crate) struct TransitionCallbacksContext(RwSignal<TransitionQueue>);
 
 impl ContextProvider for TransitionCallbacksContext {
-  type Value = Vec<TransitionCallback>;
+  type Value = TransitionQueue;
 
   fn from_leptos_scope(cx: Scope) -> Self {
-    Self(create_rw_signal(cx, Default::default()))
+    Self(create_rw_signal(cx, Self::Value::default()))
   }
 
   fn get(&self) -> Self::Value {
@@ -69,106 +134,234 @@ impl ContextProvider for TransitionCallbacksContext {
   }
 
   fn set(&self, value: Self::Value) {
-    let reference = &value;
-    self.0.set_untracked(if eq(reference, &self.get()) {
-      // this happens when the value was directly mutated.
-      reference.clone()
-    } else {
-      value
-    });
+    self.0.set_untracked(value);
   }
 }
 
-fn setup_global_events(cx: Scope) {
-  type CallbackClosureType = Rc<RefCell<Closure<dyn Fn(TransitionEvent)>>>;
-  let closure: CallbackClosureType = Rc::new(RefCell::new(Closure::new(|_| {})));
-  let update_closure = closure.clone();
-  let other_closure = closure.clone();
+/// The options of [`run_after_transition`], provided with
+/// [`provide_transition_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransitionOptions {
+  /// The longest time to wait for transitions and animations before the
+  /// callbacks are run anyway, e.g. when an element is hidden with
+  /// `display: none` during a transition and never receives `transitionend`.
+  pub max_wait: Duration,
+}
 
-  let on_transition_end = move |event: TransitionEvent| {
-    let element: Element = event.target().unwrap().unchecked_into();
-    let transitions_context = ElementTransitionsContext::provide(cx);
-    let js_map = transitions_context.get();
+impl Default for TransitionOptions {
+  fn default() -> Self {
+    Self {
+      max_wait: Duration::from_secs(5),
+    }
+  }
+}
 
-    // Remove property from list of transitioning properties.
-    let Some(properties) = js_map.get(&element) else {
-      return;
-    };
+#[derive(Copy, Clone)]
+pub(crate) struct TransitionOptionsContext(RwSignal<TransitionOptions>);
 
-    properties.delete(&event.property_name().into());
+impl ContextProvider for TransitionOptionsContext {
+  type Value = TransitionOptions;
 
-    // If empty, remove transitioncancel event, and remove the element from the list
-    // of transitioning elements.
-    if properties.is_empty() {
-      element
-        .remove_event_listener_with_callback(
-          "transitioncancel",
-          other_closure.borrow().as_ref().unchecked_ref(),
-        )
-        .ok();
+  fn from_leptos_scope(cx: Scope) -> Self {
+    Self(create_rw_signal(cx, Self::Value::default()))
+  }
 
-      js_map.delete(&element);
-    }
+  fn get(&self) -> Self::Value {
+    self.0.get_untracked()
+  }
+
+  fn set(&self, value: Self::Value) {
+    self.0.set_untracked(value);
+  }
+}
 
-    if js_map.is_empty() {
-      let callbacks_context = TransitionCallbacksContext::provide(cx);
+/// Change the options of [`run_after_transition`] for the app. Call it in the
+/// same scope as [`use_provider`](crate::use_provider).
+pub fn provide_transition_options(cx: Scope, options: TransitionOptions) {
+  TransitionOptionsContext::provide(cx).set(options);
+}
 
-      for callback in callbacks_context.get().iter() {
-        callback.clone()();
-      }
+/// Run the callbacks which are waiting for transitions and animations now,
+/// e.g. before navigating away from the page.
+pub fn flush_transition_callbacks(cx: Scope) {
+  TransitionCallbacksContext::provide(cx).get().flush();
+}
 
-      callbacks_context.set(Vec::new());
+/// Start tracking the transition or animation of the element.
+fn start_tracking(cx: Scope, element: &Element, name: JsString) {
+  let transitions = ElementTransitionsContext::provide(cx).get();
+
+  match transitions.get(element) {
+    Some(set) => {
+      set.add(&name);
+    }
+    None => {
+      let set: Set<JsString> = Default::default();
+      set.add(&name);
+      transitions.set(element, &set);
     }
+  }
+}
+
+/// Stop tracking the transition or animation of the element, and run the
+/// callbacks once nothing is running.
+fn stop_tracking(cx: Scope, element: &Element, name: JsString) {
+  let transitions = ElementTransitionsContext::provide(cx).get();
+
+  // Remove property from list of transitioning properties.
+  let Some(properties) = transitions.get(element) else {
+    return;
   };
 
-  let on_transition_start = move |event: TransitionEvent| {
-    let element: Element = event.target().unwrap().unchecked_into();
-    let transitions_context = ElementTransitionsContext::provide(cx);
-    let js_map = transitions_context.get();
+  properties.delete(&name);
 
-    match js_map.get(&element) {
-      Some(set) => {
-        set.add(&event.property_name().into());
+  // If empty, remove the element from the list of transitioning elements.
+  if properties.is_empty() {
+    transitions.delete(element);
+  }
+
+  run_callbacks_when_idle(cx);
+}
+
+/// Whether no transitions or animations are running on the page.
+fn is_idle(cx: Scope) -> bool {
+  ElementTransitionsContext::provide(cx).get().is_empty() && running_animations().is_empty()
+}
+
+fn run_callbacks_when_idle(cx: Scope) {
+  if is_idle(cx) {
+    TransitionCallbacksContext::provide(cx).get().flush();
+  }
+}
+
+/// The animations of the Web Animations API which are running and will
+/// finish, including CSS transitions and animations. Infinite animations,
+/// such as loading spinners, are ignored since they never finish.
+fn running_animations() -> Vec<JsValue> {
+  let document: JsValue = document().into();
+  let Some(animations) = call_method(&document, "getAnimations") else {
+    return vec![];
+  };
+
+  Array::from(&animations)
+    .iter()
+    .filter(|animation| {
+      let play_state = Reflect::get(animation, &"playState".into())
+        .ok()
+        .and_then(|play_state| play_state.as_string());
+
+      if play_state.as_deref() != Some("running") {
+        return false;
       }
-      None => {
-        let set: Set<JsString> = Default::default();
-        set.add(&event.property_name().into());
-
-        element
-          .add_event_listener_with_callback(
-            "transitioncancel",
-            closure.borrow().as_ref().unchecked_ref(),
-          )
-          .ok();
-
-        js_map.set(&element, &set);
+
+      Reflect::get(animation, &"effect".into())
+        .ok()
+        .filter(|effect| effect.is_object())
+        .and_then(|effect| call_method(&effect, "getComputedTiming"))
+        .and_then(|timing| Reflect::get(&timing, &"endTime".into()).ok())
+        .and_then(|end_time| end_time.as_f64())
+        .map_or(false, f64::is_finite)
+    })
+    .collect()
+}
+
+/// Call a method which isn't available in every browser.
+fn call_method(target: &JsValue, name: &str) -> Option<JsValue> {
+  Reflect::get(target, &name.into())
+    .ok()
+    .and_then(|method| method.dyn_into::<Function>().ok())
+    .and_then(|method| method.call0(target).ok())
+}
+
+/// Check again once each of the animations has finished or was canceled.
+fn wait_for_animations(cx: Scope, animations: Vec<JsValue>) {
+  for animation in animations {
+    let Some(finished) = Reflect::get(&animation, &"finished".into())
+      .ok()
+      .and_then(|finished| finished.dyn_into::<Promise>().ok())
+    else {
+      continue;
+    };
+
+    let on_settled = Closure::once(move || run_callbacks_when_idle(cx));
+    let _ = finished.finally(&on_settled);
+    on_settled.forget();
+  }
+}
+
+/// Run the callbacks once the longest wait has passed, in case a transition
+/// or animation never reports that it has finished.
+fn schedule_timeout(cx: Scope) {
+  let queue = TransitionCallbacksContext::provide(cx).get();
+  let scheduled_count = queue.flush_count();
+  let max_wait = TransitionOptionsContext::provide(cx).get().max_wait;
+
+  set_timeout(
+    move || {
+      if queue.flush_count() != scheduled_count {
+        return;
       }
-    }
+
+      // The tracked elements are stuck, so stop waiting for them.
+      let transitions = ElementTransitionsContext::provide(cx).get();
+
+      for element in transitions.keys() {
+        transitions.delete(&element);
+      }
+
+      queue.flush();
+    },
+    max_wait,
+  );
+}
+
+fn setup_global_events(cx: Scope) {
+  let listeners = GlobalListeners::new(cx);
+  let body = document().body().unwrap();
+
+  let on_transition_start = move |event: TransitionEvent| {
+    let element: Element = event.target().unwrap().unchecked_into();
+    start_tracking(cx, &element, event.property_name().into());
   };
 
-  let document_transition_end: Closure<dyn Fn(TransitionEvent)> = Closure::new(on_transition_end);
-  let cloned = document_transition_end.as_ref().clone();
-  update_closure.replace(document_transition_end);
+  let on_transition_end = move |event: TransitionEvent| {
+    let element: Element = event.target().unwrap().unchecked_into();
+    stop_tracking(cx, &element, event.property_name().into());
+  };
 
-  let on_start_closure =
-    Closure::wrap(Box::new(on_transition_start) as Box<dyn Fn(TransitionEvent)>);
-  document()
-    .body()
-    .unwrap()
-    .add_event_listener_with_callback("transitionrun", on_start_closure.as_ref().unchecked_ref())
-    .ok();
+  let on_animation_start = move |event: AnimationEvent| {
+    let element: Element = event.target().unwrap().unchecked_into();
+    start_tracking(cx, &element, animation_key(&event));
+  };
 
-  document()
-    .body()
-    .unwrap()
-    .add_event_listener_with_callback("transitionend", cloned.unchecked_ref())
-    .ok();
+  let on_animation_end = move |event: AnimationEvent| {
+    let element: Element = event.target().unwrap().unchecked_into();
+    stop_tracking(cx, &element, animation_key(&event));
+  };
+
+  listeners.add_event_listener(&body, "transitionrun", on_transition_start, false);
+  listeners.add_event_listener(&body, "transitionend", on_transition_end, false);
+  listeners.add_event_listener(&body, "transitioncancel", on_transition_end, false);
+  listeners.add_event_listener(&body, "animationstart", on_animation_start, false);
+  listeners.add_event_listener(&body, "animationend", on_animation_end, false);
+  listeners.add_event_listener(&body, "animationcancel", on_animation_end, false);
+}
+
+/// Animations are tracked with the transitioning properties, so their names
+/// are prefixed to avoid clashing with a property.
+fn animation_key(event: &AnimationEvent) -> JsString {
+  let name = event.animation_name();
+  format!("@animation {name}").into()
 }
 
 /// Setup a listener for transition events on the page.
 ///
-/// This should only be run in the browser.
+/// This does nothing on the server.
 pub(crate) fn setup_transition_listener(cx: Scope) {
+  if is_server() {
+    return;
+  }
+
   if document().ready_state() != "loading" {
     setup_global_events(cx);
   } else {
@@ -178,33 +371,144 @@ pub(crate) fn setup_transition_listener(cx: Scope) {
     document()
       .add_event_listener_with_callback("DOMContentLoaded", closure.as_ref().unchecked_ref())
       .ok();
+    closure.forget();
   }
 }
 
-/// Perform a certain action after all CSS transitions have finished on the
-/// page.
+/// Perform a certain action after all CSS transitions, CSS animations and
+/// animations of the Web Animations API have finished on the page.
+///
+/// Infinite animations are ignored. The callback runs anyway once the
+/// [`TransitionOptions::max_wait`] has passed, so a transition which never
+/// reports that it has finished doesn't block it forever.
 ///
-/// This prevents
+/// The callback is never run on the server, where nothing transitions.
 pub fn run_after_transition<F>(cx: Scope, callback: F)
 where
-  F: Fn() + 'static,
+  F: FnOnce() + 'static,
 {
+  if is_server() {
+    return;
+  }
+
   let cb = move || {
-    let transitions_context = ElementTransitionsContext::provide(cx);
-    let transitions = transitions_context.get();
+    let animations = running_animations();
 
-    if transitions.is_empty() {
+    if ElementTransitionsContext::provide(cx).get().is_empty() && animations.is_empty() {
       // When no transitions are running, call the function immediately.
       // Otherwise, add it to a list of callbacks to run at the end of the animation.
       callback();
-    } else {
-      let callbacks_context = TransitionCallbacksContext::provide(cx);
-      let mut callbacks = callbacks_context.get();
-      let callback = Rc::new(Box::new(callback) as Box<dyn Fn() + 'static>);
-      callbacks.push(callback);
+      return;
+    }
+
+    let is_first = TransitionCallbacksContext::provide(cx)
+      .get()
+      .push(Box::new(callback));
+
+    wait_for_animations(cx, animations);
+
+    if is_first {
+      schedule_timeout(cx);
     }
   };
 
   // Wait one frame to see if an animation starts, e.g. a transition on mount.
   request_animation_frame(cb);
 }
+
+/// Wait until all transitions and animations have finished on the page, like
+/// [`run_after_transition`].
+///
+/// ```ignore
+/// spawn_local(async move {
+///   wait_for_transitions(cx).await;
+///   focus_first_item();
+/// });
+/// ```
+///
+/// The future is ready immediately on the server, where nothing transitions.
+pub fn wait_for_transitions(cx: Scope) -> TransitionsFinished {
+  let state = Rc::new(RefCell::new(TransitionsFinishedState {
+    is_finished: is_server(),
+    waker: None,
+  }));
+
+  if !is_server() {
+    let state = state.clone();
+
+    run_after_transition(cx, move || {
+      let waker = {
+        let mut state = state.borrow_mut();
+        state.is_finished = true;
+        state.waker.take()
+      };
+
+      if let Some(waker) = waker {
+        waker.wake();
+      }
+    });
+  }
+
+  TransitionsFinished(state)
+}
+
+struct TransitionsFinishedState {
+  is_finished: bool,
+  waker: Option<Waker>,
+}
+
+/// The future returned by [`wait_for_transitions`].
+#[must_use = "futures do nothing unless they are awaited"]
+pub struct TransitionsFinished(Rc<RefCell<TransitionsFinishedState>>);
+
+impl Future for TransitionsFinished {
+  type Output = ();
+
+  fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+    let mut state = self.0.borrow_mut();
+
+    if state.is_finished {
+      Poll::Ready(())
+    } else {
+      state.waker = Some(context.waker().clone());
+      Poll::Pending
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn flushes_the_queued_callbacks_once() {
+    let queue = TransitionQueue::default();
+    let calls = Rc::new(RefCell::new(vec![]));
+
+    for index in 0..2 {
+      let calls = calls.clone();
+      let is_first = queue.push(Box::new(move || calls.borrow_mut().push(index)));
+      assert_eq!(is_first, index == 0);
+    }
+
+    // A callback can queue another callback for the next flush.
+    {
+      let queue_clone = queue.clone();
+      let calls = calls.clone();
+      queue.push(Box::new(move || {
+        queue_clone.push(Box::new(move || calls.borrow_mut().push(3)));
+      }));
+    }
+
+    queue.flush();
+    assert_eq!(*calls.borrow(), vec![0, 1]);
+    assert_eq!(queue.flush_count(), 1);
+
+    queue.flush();
+    assert_eq!(*calls.borrow(), vec![0, 1, 3]);
+    assert_eq!(queue.flush_count(), 2);
+
+    queue.flush();
+    assert_eq!(queue.flush_count(), 2);
+  }
+}
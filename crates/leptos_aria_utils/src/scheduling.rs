@@ -0,0 +1,193 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::js_sys::Date;
+use leptos::js_sys::Function;
+use leptos::js_sys::Reflect;
+use leptos::on_cleanup;
+use leptos::set_timeout;
+use leptos::wasm_bindgen::prelude::Closure;
+use leptos::window;
+use leptos::JsCast;
+use leptos::Scope;
+
+use crate::is_server;
+
+/// Delay the callback until `wait` has passed without another call, e.g. to
+/// reset a typeahead search once the user stops typing. Only the value of the
+/// last call is passed to the callback.
+///
+/// The pending call is dropped when the scope is cleaned up.
+///
+/// ```ignore
+/// let save = debounce(cx, Duration::from_millis(300), move |query: String| {
+///   set_filter(query);
+/// });
+///
+/// save("app".to_string());
+/// save("apple".to_string()); // Only `apple` is saved.
+/// ```
+pub fn debounce<T: 'static>(
+  cx: Scope,
+  wait: Duration,
+  callback: impl Fn(T) + 'static,
+) -> Rc<dyn Fn(T)> {
+  let is_disposed = disposed_flag(cx);
+  let generation = Rc::new(Cell::new(0_usize));
+  let callback = Rc::new(callback);
+
+  Rc::new(move |value: T| {
+    if is_disposed.get() {
+      return;
+    }
+
+    // Every call restarts the wait.
+    let current = generation.get().wrapping_add(1);
+    generation.set(current);
+
+    let is_disposed = is_disposed.clone();
+    let generation = generation.clone();
+    let callback = callback.clone();
+
+    set_timeout(
+      move || {
+        if !is_disposed.get() && generation.get() == current {
+          callback(value);
+        }
+      },
+      wait,
+    );
+  })
+}
+
+/// Run the callback at most once per `interval`, e.g. while the window is
+/// being resized.
+///
+/// The first call runs immediately. Calls during the interval are combined
+/// into a single call with the latest value at the end of the interval, so
+/// the last update is never lost. The pending call is dropped when the scope
+/// is cleaned up.
+pub fn throttle<T: 'static>(
+  cx: Scope,
+  interval: Duration,
+  callback: impl Fn(T) + 'static,
+) -> Rc<dyn Fn(T)> {
+  let is_disposed = disposed_flag(cx);
+  let last_run = Rc::new(Cell::new(None::<f64>));
+  let pending = Rc::new(RefCell::new(None::<T>));
+  let is_scheduled = Rc::new(Cell::new(false));
+  let callback = Rc::new(callback);
+
+  Rc::new(move |value: T| {
+    if is_disposed.get() {
+      return;
+    }
+
+    let now = Date::now();
+
+    let Some(wait) = throttle_wait(last_run.get(), now, interval) else {
+      last_run.set(Some(now));
+      callback(value);
+      return;
+    };
+
+    *pending.borrow_mut() = Some(value);
+
+    if is_scheduled.replace(true) {
+      return;
+    }
+
+    let is_disposed = is_disposed.clone();
+    let last_run = last_run.clone();
+    let pending = pending.clone();
+    let is_scheduled = is_scheduled.clone();
+    let callback = callback.clone();
+
+    set_timeout(
+      move || {
+        is_scheduled.set(false);
+        let value = pending.take();
+
+        if is_disposed.get() {
+          return;
+        }
+
+        if let Some(value) = value {
+          last_run.set(Some(Date::now()));
+          callback(value);
+        }
+      },
+      wait,
+    );
+  })
+}
+
+/// Run the callback once the browser is idle, for work which shouldn't delay
+/// rendering or input, e.g. measuring offscreen items. Browsers without
+/// `requestIdleCallback` run it after a timeout instead.
+///
+/// The callback isn't run when the scope is cleaned up first, or on the
+/// server.
+pub fn run_when_idle(cx: Scope, callback: impl FnOnce() + 'static) {
+  if is_server() {
+    return;
+  }
+
+  let is_disposed = disposed_flag(cx);
+  let run = move || {
+    if !is_disposed.get() {
+      callback();
+    }
+  };
+
+  let request_idle_callback = Reflect::get(&window(), &"requestIdleCallback".into())
+    .ok()
+    .and_then(|method| method.dyn_into::<Function>().ok());
+
+  match request_idle_callback {
+    Some(request_idle_callback) => {
+      let _ = request_idle_callback.call1(&window(), &Closure::once_into_js(run));
+    }
+    None => set_timeout(run, Duration::ZERO),
+  }
+}
+
+/// A flag which is set once the scope is cleaned up.
+fn disposed_flag(cx: Scope) -> Rc<Cell<bool>> {
+  let is_disposed = Rc::new(Cell::new(false));
+
+  on_cleanup(cx, {
+    let is_disposed = is_disposed.clone();
+    move || is_disposed.set(true)
+  });
+
+  is_disposed
+}
+
+/// How long a throttled call waits for the end of the interval, or `None` when
+/// it can run straight away.
+fn throttle_wait(last_run: Option<f64>, now: f64, interval: Duration) -> Option<Duration> {
+  let elapsed = Duration::from_secs_f64((now - last_run?).max(0.0) / 1000.0);
+
+  (elapsed < interval).then(|| interval - elapsed)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn throttles_calls_within_the_interval() {
+    let interval = Duration::from_millis(100);
+
+    assert_eq!(throttle_wait(None, 0.0, interval), None);
+    assert_eq!(
+      throttle_wait(Some(0.0), 40.0, interval),
+      Some(Duration::from_millis(60))
+    );
+    assert_eq!(throttle_wait(Some(0.0), 100.0, interval), None);
+    assert_eq!(throttle_wait(Some(0.0), 250.0, interval), None);
+  }
+}
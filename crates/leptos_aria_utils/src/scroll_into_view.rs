@@ -0,0 +1,166 @@
+use leptos::document;
+use leptos::web_sys::Element;
+use leptos::window;
+
+use crate::get_scroll_parent;
+
+/// Where to align an element within a scrollable area along one axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollAlignment {
+  /// Align the start of the element with the start of the area.
+  Start,
+  /// Center the element within the area.
+  Center,
+  /// Align the end of the element with the end of the area.
+  End,
+  /// Only scroll when the element isn't fully visible, by the smallest
+  /// distance which reveals it.
+  #[default]
+  Nearest,
+}
+
+impl ScrollAlignment {
+  /// The distance to scroll an area spanning `area_start..area_end` to align
+  /// the element spanning `element_start..element_end`, in the same
+  /// coordinates.
+  fn scroll_delta(self, area: (f64, f64), element: (f64, f64)) -> f64 {
+    let (area_start, area_end) = area;
+    let (element_start, element_end) = element;
+
+    match self {
+      Self::Start => element_start - area_start,
+      Self::End => element_end - area_end,
+      Self::Center => (element_start + element_end - area_start - area_end) / 2.0,
+      Self::Nearest => {
+        let is_larger = element_end - element_start > area_end - area_start;
+
+        if element_start < area_start || is_larger {
+          element_start - area_start
+        } else if element_end > area_end {
+          element_end - area_end
+        } else {
+          0.0
+        }
+      }
+    }
+  }
+}
+
+/// How to align an element with [`scroll_into_viewport`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScrollIntoViewportOptions {
+  /// The vertical alignment within each scrollable ancestor.
+  pub block: ScrollAlignment,
+  /// The horizontal alignment within each scrollable ancestor.
+  pub inline: ScrollAlignment,
+}
+
+/// Scroll the element into view within each of its scrollable ancestors, from
+/// the closest to the furthest, e.g. to reveal the focused item of a listbox
+/// within a scrollable dialog.
+///
+/// Unlike `Element.scrollIntoView`, the page itself is only scrolled when the
+/// element is still outside of the window afterwards, and then by the smallest
+/// distance which reveals it.
+pub fn scroll_into_viewport(element: &Element, options: ScrollIntoViewportOptions) {
+  let root = document().scrolling_element();
+  let mut parent = get_scroll_parent(element);
+
+  // The page is only scrolled as a last resort below.
+  while let Some(scrollable) = parent.filter(|scrollable| Some(scrollable) != root.as_ref()) {
+    scroll_within(&scrollable, element, options);
+    parent = get_scroll_parent(&scrollable);
+  }
+
+  let width = window().inner_width().ok().and_then(|width| width.as_f64());
+  let height = window()
+    .inner_height()
+    .ok()
+    .and_then(|height| height.as_f64());
+  let (Some(width), Some(height)) = (width, height) else {
+    return;
+  };
+
+  let rect = element.get_bounding_client_rect();
+  let nearest = ScrollAlignment::Nearest;
+  let x = nearest.scroll_delta((0.0, width), (rect.left(), rect.right()));
+  let y = nearest.scroll_delta((0.0, height), (rect.top(), rect.bottom()));
+
+  if x != 0.0 || y != 0.0 {
+    window().scroll_by_with_x_and_y(x, y);
+  }
+}
+
+/// Scroll the `container` to align the `element`, which is one of its
+/// descendants.
+fn scroll_within(container: &Element, element: &Element, options: ScrollIntoViewportOptions) {
+  let container_rect = container.get_bounding_client_rect();
+  let rect = element.get_bounding_client_rect();
+
+  // The visible area excludes the borders and scrollbars of the container.
+  let left = container_rect.left() + f64::from(container.client_left());
+  let top = container_rect.top() + f64::from(container.client_top());
+  let area_x = (left, left + f64::from(container.client_width()));
+  let area_y = (top, top + f64::from(container.client_height()));
+
+  let x = options
+    .inline
+    .scroll_delta(area_x, (rect.left(), rect.right()));
+  let y = options
+    .block
+    .scroll_delta(area_y, (rect.top(), rect.bottom()));
+
+  if x != 0.0 || y != 0.0 {
+    container.scroll_by_with_x_and_y(x, y);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn scroll_delta_for_each_alignment() {
+    let area = (100.0, 300.0);
+
+    // Below the area.
+    assert_eq!(
+      ScrollAlignment::Start.scroll_delta(area, (350.0, 400.0)),
+      250.0
+    );
+    assert_eq!(
+      ScrollAlignment::End.scroll_delta(area, (350.0, 400.0)),
+      100.0
+    );
+    assert_eq!(
+      ScrollAlignment::Center.scroll_delta(area, (350.0, 400.0)),
+      175.0
+    );
+    assert_eq!(
+      ScrollAlignment::Nearest.scroll_delta(area, (350.0, 400.0)),
+      100.0
+    );
+
+    // Above the area.
+    assert_eq!(
+      ScrollAlignment::Nearest.scroll_delta(area, (50.0, 80.0)),
+      -50.0
+    );
+
+    // Already visible.
+    assert_eq!(
+      ScrollAlignment::Nearest.scroll_delta(area, (150.0, 200.0)),
+      0.0
+    );
+    assert_eq!(
+      ScrollAlignment::Start.scroll_delta(area, (150.0, 200.0)),
+      50.0
+    );
+
+    // Larger than the area shows its start.
+    assert_eq!(
+      ScrollAlignment::Nearest.scroll_delta(area, (150.0, 500.0)),
+      50.0
+    );
+  }
+}
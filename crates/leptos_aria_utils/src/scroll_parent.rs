@@ -0,0 +1,79 @@
+use leptos::document;
+use leptos::web_sys::Element;
+use leptos::window;
+
+/// Whether the computed `overflow` of the element lets its content scroll, even
+/// when it currently fits, e.g. a listbox with `overflow: auto` which clips any
+/// items added later.
+pub fn is_scroll_container(element: &Element) -> bool {
+  let Ok(Some(style)) = window().get_computed_style(element) else {
+    return false;
+  };
+
+  ["overflow", "overflow-x", "overflow-y"]
+    .iter()
+    .any(|property| {
+      style
+        .get_property_value(property)
+        .map_or(false, |value| allows_scrolling(&value))
+    })
+}
+
+/// Whether the content of the element overflows and can be scrolled.
+///
+/// The `document.scrollingElement` scrolls the page whenever it overflows,
+/// whatever its computed `overflow`.
+pub fn is_scrollable(element: &Element) -> bool {
+  let overflows = element.scroll_height() > element.client_height()
+    || element.scroll_width() > element.client_width();
+
+  overflows && (is_scrolling_element(element) || is_scroll_container(element))
+}
+
+/// The nearest ancestor of the element which is scrollable, falling back to
+/// the `document.scrollingElement` which scrolls the page.
+pub fn get_scroll_parent(element: &Element) -> Option<Element> {
+  let mut parent = element.parent_element();
+
+  while let Some(ancestor) = parent {
+    if is_scrolling_element(&ancestor) {
+      break;
+    }
+
+    if is_scrollable(&ancestor) {
+      return Some(ancestor);
+    }
+
+    parent = ancestor.parent_element();
+  }
+
+  document().scrolling_element()
+}
+
+fn is_scrolling_element(element: &Element) -> bool {
+  document().scrolling_element().as_ref() == Some(element)
+}
+
+/// Whether a computed `overflow` value allows scrolling. The shorthand
+/// computes to one value per axis when they differ, e.g. `hidden auto`.
+fn allows_scrolling(value: &str) -> bool {
+  value
+    .split_whitespace()
+    .any(|value| matches!(value, "auto" | "scroll" | "overlay"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn overflow_values_which_allow_scrolling() {
+    assert!(allows_scrolling("auto"));
+    assert!(allows_scrolling("scroll"));
+    assert!(allows_scrolling("overlay"));
+    assert!(allows_scrolling("hidden auto"));
+    assert!(!allows_scrolling("visible"));
+    assert!(!allows_scrolling("hidden clip"));
+    assert!(!allows_scrolling(""));
+  }
+}
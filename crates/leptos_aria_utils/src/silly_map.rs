@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 use leptos::js_sys::Number;
 use leptos::js_sys::{self};
 use leptos::wasm_bindgen::JsValue;
+use leptos::JsCast;
 
 /// `web_sys::Element` is not hashable meaning it's not possible to us it as a
 /// key in a `HashMap`. This is a silly map implementation with no regard for
@@ -116,9 +117,64 @@ where
     self.size() == 0
   }
 
-  // pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
-  //   self.0.iter_mut().map(|(k, v)| (k, v))
-  // }
+  /// The keys of the map in insertion order.
+  ///
+  /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/keys)
+  pub fn keys(&self) -> impl Iterator<Item = K> {
+    iterate(self.0.keys()).map(K::from)
+  }
+
+  /// The values of the map in insertion order.
+  ///
+  /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/values)
+  pub fn values(&self) -> impl Iterator<Item = V> {
+    iterate(self.0.values()).map(V::from)
+  }
+
+  /// The key-value pairs of the map in insertion order.
+  ///
+  /// ```ignore
+  /// for (element, transitions) in map.entries() {
+  ///   // Do something here...
+  /// }
+  /// ```
+  ///
+  /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/entries)
+  pub fn entries(&self) -> impl Iterator<Item = (K, V)> {
+    iterate(self.0.entries()).map(|entry| {
+      let entry: js_sys::Array = entry.unchecked_into();
+      (entry.get(0).into(), entry.get(1).into())
+    })
+  }
+
+  /// Copy the key-value pairs into a `Vec` in insertion order.
+  pub fn to_vec(&self) -> Vec<(K, V)> {
+    self.entries().collect()
+  }
+}
+
+impl<K, V> FromIterator<(K, V)> for Map<K, V>
+where
+  K: AsRef<JsValue> + From<JsValue>,
+  V: AsRef<JsValue> + From<JsValue>,
+{
+  fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    let mut map = Self::new();
+    map.extend(iter);
+    map
+  }
+}
+
+impl<K, V> Extend<(K, V)> for Map<K, V>
+where
+  K: AsRef<JsValue> + From<JsValue>,
+  V: AsRef<JsValue> + From<JsValue>,
+{
+  fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+    for (key, value) in iter {
+      self.set(&key, &value);
+    }
+  }
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -213,6 +269,66 @@ where
   pub fn is_empty(&self) -> bool {
     self.0.size() == 0
   }
+
+  /// The values of the set in insertion order. An alias of
+  /// [`values`](Self::values) to match [`Map::keys`].
+  ///
+  /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/keys)
+  pub fn keys(&self) -> impl Iterator<Item = T> {
+    iterate(self.0.keys()).map(T::from)
+  }
+
+  /// The values of the set in insertion order.
+  ///
+  /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values)
+  pub fn values(&self) -> impl Iterator<Item = T> {
+    iterate(self.0.values()).map(T::from)
+  }
+
+  /// The values of the set in insertion order as `(value, value)` pairs, to
+  /// match [`Map::entries`].
+  ///
+  /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/entries)
+  pub fn entries(&self) -> impl Iterator<Item = (T, T)> {
+    iterate(self.0.entries()).map(|entry| {
+      let entry: js_sys::Array = entry.unchecked_into();
+      (entry.get(0).into(), entry.get(1).into())
+    })
+  }
+
+  /// Copy the values into a `Vec` in insertion order.
+  pub fn to_vec(&self) -> Vec<T> {
+    self.values().collect()
+  }
+}
+
+impl<T> FromIterator<T> for Set<T>
+where
+  T: AsRef<JsValue> + From<JsValue>,
+{
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut set = Self::new();
+    set.extend(iter);
+    set
+  }
+}
+
+impl<T> Extend<T> for Set<T>
+where
+  T: AsRef<JsValue> + From<JsValue>,
+{
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for value in iter {
+      self.add(&value);
+    }
+  }
+}
+
+/// Iterate over a JavaScript iterator. Iteration stops at the first error
+/// instead of panicking, e.g. when the iterator of a map which was modified
+/// from JavaScript throws.
+fn iterate(iterator: js_sys::Iterator) -> impl Iterator<Item = JsValue> {
+  iterator.into_iter().map_while(Result::ok)
 }
 
 /// Wrap a value so it can be used as a JsValue.
@@ -291,3 +407,85 @@ where
     Self(value.into(), PhantomData)
   }
 }
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+  use leptos::js_sys::JsString;
+  use wasm_bindgen_test::*;
+
+  use super::*;
+
+  wasm_bindgen_test_configure!(run_in_browser);
+
+  fn js(value: &str) -> JsString {
+    value.into()
+  }
+
+  #[wasm_bindgen_test]
+  fn iterates_map_in_insertion_order() {
+    let map = Map::<JsString, JsString>::new();
+    map.set(&js("b"), &js("2"));
+    map.set(&js("a"), &js("1"));
+    map.set(&js("b"), &js("3"));
+
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![js("b"), js("a")]);
+    assert_eq!(map.values().collect::<Vec<_>>(), vec![js("3"), js("1")]);
+    assert_eq!(
+      map.entries().collect::<Vec<_>>(),
+      vec![(js("b"), js("3")), (js("a"), js("1"))]
+    );
+    assert_eq!(map.to_vec(), map.entries().collect::<Vec<_>>());
+  }
+
+  #[wasm_bindgen_test]
+  fn iterates_empty_map() {
+    let map = Map::<JsString, JsString>::new();
+
+    assert_eq!(map.keys().count(), 0);
+    assert_eq!(map.values().count(), 0);
+    assert_eq!(map.entries().count(), 0);
+  }
+
+  #[wasm_bindgen_test]
+  fn collects_and_extends_map() {
+    let mut map: Map<JsString, JsString> = [(js("a"), js("1")), (js("b"), js("2"))]
+      .into_iter()
+      .collect();
+
+    map.extend([(js("a"), js("3")), (js("c"), js("4"))]);
+
+    assert_eq!(map.size(), 3);
+    assert_eq!(map.get(&js("a")), Some(js("3")));
+    assert_eq!(
+      map.to_vec(),
+      vec![(js("a"), js("3")), (js("b"), js("2")), (js("c"), js("4"))]
+    );
+  }
+
+  #[wasm_bindgen_test]
+  fn iterates_set_in_insertion_order() {
+    let set = Set::<JsString>::new();
+    set.add(&js("b"));
+    set.add(&js("a"));
+    set.add(&js("b"));
+
+    assert_eq!(set.keys().collect::<Vec<_>>(), vec![js("b"), js("a")]);
+    assert_eq!(set.values().collect::<Vec<_>>(), vec![js("b"), js("a")]);
+    assert_eq!(
+      set.entries().collect::<Vec<_>>(),
+      vec![(js("b"), js("b")), (js("a"), js("a"))]
+    );
+    assert_eq!(set.to_vec(), vec![js("b"), js("a")]);
+  }
+
+  #[wasm_bindgen_test]
+  fn collects_and_extends_set() {
+    let mut set: Set<JsString> = [js("a"), js("b"), js("a")].into_iter().collect();
+
+    set.extend([js("c"), js("b")]);
+
+    assert_eq!(set.size(), 3);
+    assert!(set.has(&js("c")));
+    assert_eq!(set.to_vec(), vec![js("a"), js("b"), js("c")]);
+  }
+}
@@ -0,0 +1,43 @@
+use std::cell::Cell;
+
+use leptos::create_effect;
+use leptos::Scope;
+
+/// Whether the code is running on the server, where there is no `window` or
+/// `document`.
+///
+/// Hooks should return inert results on the server, with the values which
+/// are rendered into the markup, and skip anything which touches the DOM.
+/// Native targets are always treated as the server, which allows the state
+/// hooks to be unit tested with `cargo test` without a browser.
+pub fn is_server() -> bool {
+  cfg!(feature = "ssr") || !cfg!(target_arch = "wasm32")
+}
+
+/// Whether the code is running in the browser, either rendering on the
+/// client or hydrating the markup rendered by the server.
+pub fn is_browser() -> bool {
+  !is_server()
+}
+
+/// Run the callback once in the browser after the hook has been created,
+/// e.g. to add global listeners. When hydrating, this waits until the
+/// markup has been hydrated.
+///
+/// The callback never runs on the server.
+pub fn on_browser<F>(cx: Scope, callback: F)
+where
+  F: FnOnce() + 'static,
+{
+  if is_server() {
+    return;
+  }
+
+  let callback = Cell::new(Some(callback));
+
+  create_effect(cx, move |_| {
+    if let Some(callback) = callback.take() {
+      callback();
+    }
+  });
+}
@@ -0,0 +1,138 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_rw_signal;
+use leptos::document;
+use leptos::on_cleanup;
+use leptos::web_sys::Element;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+
+use crate::generate_id;
+use crate::is_server;
+use crate::VISUALLY_HIDDEN_STYLE;
+
+struct DescriptionNode {
+  element: Element,
+  ref_count: usize,
+}
+
+thread_local! {
+  /// The rendered descriptions by message, shared by every hook which
+  /// describes an element with the same message.
+  static DESCRIPTIONS: RefCell<HashMap<String, DescriptionNode>> = RefCell::new(HashMap::new());
+}
+
+/// Describe an element with a message which isn't part of the page, such as
+/// the instructions for starting a keyboard drag. Returns the id for the
+/// `aria-describedby` attribute.
+///
+/// The message is rendered into a visually hidden element at the end of the
+/// `<body>`, which is shared by every hook with the same message and removed
+/// once none of them use it.
+///
+/// ```ignore
+/// let aria_describedby = use_description(cx, Some("Long press to open the menu".to_string()));
+///
+/// view! { cx, <button aria-describedby=move || aria_describedby.get()>"Options"</button> }
+/// ```
+///
+/// The id is `None` on the server, for an empty message and until the
+/// description is rendered in the browser.
+pub fn use_description(
+  cx: Scope,
+  description: impl Into<MaybeSignal<Option<String>>>,
+) -> Signal<Option<String>> {
+  let description = description.into();
+  let id = create_rw_signal(cx, None::<String>);
+
+  if is_server() {
+    return (move || id.get()).derive_signal(cx);
+  }
+
+  let current = Rc::new(RefCell::new(None::<String>));
+
+  {
+    let current = current.clone();
+
+    create_effect(cx, move |_| {
+      let next = description.get().filter(|message| !message.is_empty());
+      let previous = current.replace(next.clone());
+
+      if previous == next {
+        return;
+      }
+
+      // Acquire the next message first so that an element shared with
+      // another hook is never removed and recreated.
+      let next_id = next.as_deref().and_then(acquire_description);
+
+      if let Some(ref previous) = previous {
+        release_description(previous);
+      }
+
+      id.set(next_id);
+    });
+  }
+
+  on_cleanup(cx, move || {
+    if let Some(ref message) = current.take() {
+      release_description(message);
+    }
+  });
+
+  (move || id.get()).derive_signal(cx)
+}
+
+/// The id of the element which renders the message, created for the first
+/// user.
+fn acquire_description(message: &str) -> Option<String> {
+  DESCRIPTIONS.with(|descriptions| {
+    let mut descriptions = descriptions.borrow_mut();
+
+    if let Some(node) = descriptions.get_mut(message) {
+      node.ref_count += 1;
+      return Some(node.element.id());
+    }
+
+    let id = generate_id();
+    let element = document().create_element("div").ok()?;
+    element.set_id(&id);
+    element.set_attribute("style", VISUALLY_HIDDEN_STYLE).ok()?;
+    element.set_text_content(Some(message));
+    document().body()?.append_child(&element).ok()?;
+
+    descriptions.insert(
+      message.to_string(),
+      DescriptionNode {
+        element,
+        ref_count: 1,
+      },
+    );
+
+    Some(id)
+  })
+}
+
+/// Remove the element which renders the message once its last user is gone.
+fn release_description(message: &str) {
+  DESCRIPTIONS.with(|descriptions| {
+    let mut descriptions = descriptions.borrow_mut();
+
+    let Some(node) = descriptions.get_mut(message) else {
+      return;
+    };
+
+    node.ref_count -= 1;
+
+    if node.ref_count == 0 {
+      if let Some(node) = descriptions.remove(message) {
+        node.element.remove();
+      }
+    }
+  });
+}
@@ -0,0 +1,145 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_rw_signal;
+use leptos::js_sys::Array;
+use leptos::on_cleanup;
+use leptos::typed_builder::TypedBuilder;
+use leptos::wasm_bindgen::prelude::Closure;
+use leptos::wasm_bindgen::JsValue;
+use leptos::web_sys::Element;
+use leptos::web_sys::IntersectionObserver;
+use leptos::web_sys::IntersectionObserverEntry;
+use leptos::web_sys::IntersectionObserverInit;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+use crate::is_server;
+
+#[derive(TypedBuilder, Default)]
+pub struct UseIntersectionObserverProps {
+  /// The scroll container which the target intersects with. Defaults to the
+  /// viewport.
+  #[builder(default, setter(strip_option))]
+  pub root: Option<Element>,
+
+  /// Grow or shrink the root before computing intersections, with the syntax
+  /// of the CSS `margin` property, e.g. `200px 0px` to load items before they
+  /// are scrolled into view.
+  #[builder(default, setter(strip_option, into))]
+  pub root_margin: Option<String>,
+
+  /// The ratios of the target's visibility at which the observer reports a
+  /// change. Defaults to `0.0`, which reports as soon as a single pixel is
+  /// visible.
+  #[builder(default, setter(into))]
+  pub threshold: Vec<f64>,
+}
+
+#[derive(Clone)]
+pub struct IntersectionObserverResult {
+  /// Whether the target intersects with the root.
+  pub is_intersecting: Signal<bool>,
+  /// The latest entry reported for the target.
+  pub entry: Signal<Option<IntersectionObserverEntry>>,
+}
+
+/// Observe whether an element is visible within a scroll container or the
+/// viewport with an `IntersectionObserver`.
+///
+/// The `target` is tracked, so the observer follows the element when it
+/// changes. The observer is disconnected when the scope is disposed.
+///
+/// ```ignore
+/// // Load the next page once the sentinel after the last item is close.
+/// let sentinel_id = use_id(cx);
+/// let result = use_intersection_observer(
+///   cx,
+///   move || document().get_element_by_id(&sentinel_id),
+///   UseIntersectionObserverProps::builder().root_margin("200px").build(),
+/// );
+///
+/// create_effect(cx, move |_| {
+///   if result.is_intersecting.get() {
+///     list.load_more();
+///   }
+/// });
+/// ```
+///
+/// Nothing is observed on the server, where the target never intersects.
+pub fn use_intersection_observer(
+  cx: Scope,
+  target: impl Fn() -> Option<Element> + 'static,
+  props: UseIntersectionObserverProps,
+) -> IntersectionObserverResult {
+  let entry = create_rw_signal(cx, None::<IntersectionObserverEntry>);
+  let is_intersecting =
+    (move || entry.get().map_or(false, |entry| entry.is_intersecting())).derive_signal(cx);
+  let result = IntersectionObserverResult {
+    is_intersecting,
+    entry: (move || entry.get()).derive_signal(cx),
+  };
+
+  if is_server() {
+    return result;
+  }
+
+  let on_intersect = Closure::wrap(Box::new(move |entries: Array| {
+    if let Some(last) = entries.iter().last() {
+      entry.set(Some(last.unchecked_into()));
+    }
+  }) as Box<dyn Fn(Array)>);
+
+  let mut init = IntersectionObserverInit::new();
+  init.root(props.root.as_ref());
+
+  if let Some(ref root_margin) = props.root_margin {
+    init.root_margin(root_margin);
+  }
+
+  if !props.threshold.is_empty() {
+    let threshold: Array = props.threshold.iter().copied().map(JsValue::from).collect();
+    init.threshold(&threshold);
+  }
+
+  let observer = Rc::new(RefCell::new(None::<IntersectionObserver>));
+
+  {
+    let observer = observer.clone();
+
+    create_effect(cx, move |_| {
+      disconnect(&observer);
+
+      let Some(element) = target() else {
+        if entry.get_untracked().is_some() {
+          entry.set(None);
+        }
+
+        return;
+      };
+
+      let Ok(intersection_observer) =
+        IntersectionObserver::new_with_options(on_intersect.as_ref().unchecked_ref(), &init)
+      else {
+        return;
+      };
+
+      intersection_observer.observe(&element);
+      *observer.borrow_mut() = Some(intersection_observer);
+    });
+  }
+
+  on_cleanup(cx, move || disconnect(&observer));
+
+  result
+}
+
+fn disconnect(observer: &RefCell<Option<IntersectionObserver>>) {
+  if let Some(observer) = observer.borrow_mut().take() {
+    observer.disconnect();
+  }
+}
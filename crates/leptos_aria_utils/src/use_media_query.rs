@@ -0,0 +1,86 @@
+use leptos::create_rw_signal;
+use leptos::web_sys::Event;
+use leptos::window;
+use leptos::IntoSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+use crate::is_server;
+use crate::GlobalListeners;
+
+/// Whether the media query matches, as a signal which updates when the result
+/// changes, e.g. when the window is resized or a system setting changes.
+///
+/// ```ignore
+/// let is_wide = use_media_query(cx, "(min-width: 768px)");
+/// ```
+///
+/// The query never matches on the server or when it is invalid.
+pub fn use_media_query(cx: Scope, query: impl Into<String>) -> Signal<bool> {
+  let matches = create_rw_signal(cx, false);
+
+  if is_server() {
+    return (move || matches.get()).derive_signal(cx);
+  }
+
+  let query: String = query.into();
+  let Some(media_query_list) = window().match_media(&query).ok().flatten() else {
+    return (move || matches.get()).derive_signal(cx);
+  };
+
+  matches.set(media_query_list.matches());
+
+  let listeners = GlobalListeners::new(cx);
+  let on_change = {
+    let media_query_list = media_query_list.clone();
+
+    move |_: Event| {
+      let is_match = media_query_list.matches();
+
+      if matches.get_untracked() != is_match {
+        matches.set(is_match);
+      }
+    }
+  };
+
+  listeners.add_event_listener(media_query_list, "change", on_change, false);
+
+  (move || matches.get()).derive_signal(cx)
+}
+
+/// Whether the user asked for less motion, in which case animations and
+/// transitions should be reduced or removed.
+pub fn use_prefers_reduced_motion(cx: Scope) -> Signal<bool> {
+  use_media_query(cx, "(prefers-reduced-motion: reduce)")
+}
+
+/// The color scheme preferred by the user.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorScheme {
+  #[default]
+  Light,
+  Dark,
+}
+
+/// The color scheme preferred by the user. Defaults to
+/// [`ColorScheme::Light`] on the server.
+pub fn use_color_scheme(cx: Scope) -> Signal<ColorScheme> {
+  let prefers_dark = use_media_query(cx, "(prefers-color-scheme: dark)");
+
+  (move || {
+    if prefers_dark.get() {
+      ColorScheme::Dark
+    } else {
+      ColorScheme::Light
+    }
+  })
+  .derive_signal(cx)
+}
+
+/// Whether the browser forces a limited color palette, like Windows High
+/// Contrast Mode, in which case custom colors are replaced and focus or
+/// selection must be visible without them, e.g. with outlines.
+pub fn use_forced_colors(cx: Scope) -> Signal<bool> {
+  use_media_query(cx, "(forced-colors: active)")
+}
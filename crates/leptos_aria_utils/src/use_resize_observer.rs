@@ -0,0 +1,142 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use leptos::create_effect;
+use leptos::create_rw_signal;
+use leptos::js_sys::Array;
+use leptos::js_sys::Reflect;
+use leptos::on_cleanup;
+use leptos::request_animation_frame;
+use leptos::wasm_bindgen::prelude::Closure;
+use leptos::wasm_bindgen::JsValue;
+use leptos::web_sys::Element;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+use crate::is_server;
+use crate::ResizeObserver;
+
+/// The size of the content box of an observed element, excluding its padding
+/// and border.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ContentRect {
+  pub x: f64,
+  pub y: f64,
+  pub width: f64,
+  pub height: f64,
+}
+
+impl ContentRect {
+  /// Read the `contentRect` of a `ResizeObserverEntry`.
+  fn from_entry(entry: &JsValue) -> Option<Self> {
+    let rect = Reflect::get(entry, &"contentRect".into()).ok()?;
+    let read = |name: &str| Reflect::get(&rect, &name.into()).ok()?.as_f64();
+
+    Some(Self {
+      x: read("x")?,
+      y: read("y")?,
+      width: read("width")?,
+      height: read("height")?,
+    })
+  }
+}
+
+/// Observe the size of an element with a `ResizeObserver`.
+///
+/// The `target` is tracked, so the observer follows the element when it
+/// changes, e.g. the element of a `NodeRef` once it is mounted. The content
+/// rect is `None` until the element has been measured and while there is no
+/// element, and is updated at most once per animation frame. The observer is
+/// disconnected when the scope is disposed.
+///
+/// ```ignore
+/// let container = create_node_ref::<Div>(cx);
+/// let rect = use_resize_observer(cx, move || {
+///   container.get().map(|element| element.unchecked_ref::<Element>().clone())
+/// });
+/// let viewport_size = move || rect.get().map_or(0.0, |rect| rect.height);
+/// ```
+///
+/// Nothing is observed on the server.
+pub fn use_resize_observer(
+  cx: Scope,
+  target: impl Fn() -> Option<Element> + 'static,
+) -> Signal<Option<ContentRect>> {
+  let content_rect = create_rw_signal(cx, None::<ContentRect>);
+
+  if is_server() {
+    return (move || content_rect.get()).derive_signal(cx);
+  }
+
+  let pending = Rc::new(Cell::new(None::<ContentRect>));
+  let is_scheduled = Rc::new(Cell::new(false));
+
+  let on_resize = Closure::wrap(Box::new(move |entries: Array| {
+    let Some(rect) = entries
+      .iter()
+      .last()
+      .and_then(|entry| ContentRect::from_entry(&entry))
+    else {
+      return;
+    };
+
+    pending.set(Some(rect));
+
+    if is_scheduled.replace(true) {
+      return;
+    }
+
+    let pending = pending.clone();
+    let is_scheduled = is_scheduled.clone();
+
+    // Batch the resizes within a frame into a single update.
+    request_animation_frame(move || {
+      is_scheduled.set(false);
+
+      if let Some(rect) = pending.take() {
+        if content_rect.get_untracked() != Some(rect) {
+          content_rect.set(Some(rect));
+        }
+      }
+    });
+  }) as Box<dyn Fn(Array)>);
+
+  let observer = Rc::new(RefCell::new(None::<ResizeObserver>));
+
+  {
+    let observer = observer.clone();
+
+    create_effect(cx, move |_| {
+      disconnect(&observer);
+
+      let Some(element) = target() else {
+        if content_rect.get_untracked().is_some() {
+          content_rect.set(None);
+        }
+
+        return;
+      };
+
+      let Ok(resize_observer) = ResizeObserver::new(on_resize.as_ref().unchecked_ref()) else {
+        return;
+      };
+
+      resize_observer.observe(&element);
+      *observer.borrow_mut() = Some(resize_observer);
+    });
+  }
+
+  on_cleanup(cx, move || disconnect(&observer));
+
+  (move || content_rect.get()).derive_signal(cx)
+}
+
+fn disconnect(observer: &RefCell<Option<ResizeObserver>>) {
+  if let Some(observer) = observer.borrow_mut().take() {
+    observer.disconnect();
+  }
+}
@@ -0,0 +1,173 @@
+use std::rc::Rc;
+
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Event;
+use leptos::web_sys::HtmlInputElement;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+use crate::use_field_state;
+use crate::use_id;
+use crate::visually_hidden::VisuallyHiddenCallback;
+use crate::FieldState;
+
+#[derive(TypedBuilder)]
+pub struct UseTextFieldProps {
+  /// The id of the input. Generated when not provided.
+  #[builder(default, setter(strip_option, into))]
+  pub id: Option<String>,
+
+  /// The text of the input.
+  #[builder(setter(into))]
+  pub value: MaybeSignal<String>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub aria_label: Option<MaybeSignal<String>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub aria_labelledby: Option<MaybeSignal<String>>,
+
+  /// The name of the input, submitted with the form.
+  #[builder(default, setter(strip_option, into))]
+  pub name: Option<String>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_invalid: Option<MaybeSignal<bool>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_disabled: Option<MaybeSignal<bool>>,
+
+  #[builder(default, setter(strip_option, into))]
+  pub is_read_only: Option<MaybeSignal<bool>>,
+
+  /// Handler that is called with the text of the input after each edit.
+  /// Returning `false` rejects the text and the input shows `value` again.
+  #[builder(default, setter(strip_option))]
+  pub on_input: Option<Box<dyn Fn(String) -> bool>>,
+}
+
+#[derive(Clone)]
+pub struct TextFieldResult {
+  pub id: String,
+  pub type_: &'static str,
+  pub auto_complete: &'static str,
+  pub auto_correct: &'static str,
+  pub spell_check: &'static str,
+  pub aria_label: Signal<Option<String>>,
+  pub aria_labelledby: Signal<Option<String>>,
+  pub aria_invalid: Signal<Option<bool>>,
+  pub aria_readonly: Signal<Option<bool>>,
+  pub disabled: Signal<bool>,
+  pub read_only: Signal<bool>,
+  pub name: Option<String>,
+  pub value: Signal<String>,
+  /// Whether the field is invalid, for styling. Disabled and read only fields
+  /// are never invalid.
+  pub is_invalid: Signal<bool>,
+  /// The disabled and read only state, to guard the other handlers of the
+  /// input.
+  pub field: FieldState,
+  pub on_input: VisuallyHiddenCallback<Event>,
+}
+
+/// Provides the attributes and input handling shared by text inputs whose
+/// text is parsed into a value, such as color and number fields.
+///
+/// Autocomplete, autocorrect and spellcheck are turned off since the text
+/// isn't prose.
+pub fn use_text_field(cx: Scope, props: UseTextFieldProps) -> ReadSignal<TextFieldResult> {
+  let id = props.id.unwrap_or_else(|| use_id(cx));
+  let field = use_field_state(cx, props.is_disabled, props.is_read_only);
+  let original_value = props.value;
+  let value = (move || original_value.get()).derive_signal(cx);
+
+  let aria_label = match props.aria_label {
+    Some(aria_label) => (move || Some(aria_label.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+
+  let aria_labelledby = match props.aria_labelledby {
+    Some(aria_labelledby) => (move || Some(aria_labelledby.get())).derive_signal(cx),
+    None => (move || None).derive_signal(cx),
+  };
+
+  let original_is_invalid = props.is_invalid.unwrap_or(false.into());
+  let is_invalid = (move || field.should_validate() && original_is_invalid.get()).derive_signal(cx);
+
+  let on_input: VisuallyHiddenCallback<Event> = {
+    let handler = props.on_input;
+
+    Rc::new(Box::new(move |event: Event| {
+      let Some(input) = event
+        .target()
+        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+      else {
+        return;
+      };
+
+      let Some(handler) = &handler else {
+        return;
+      };
+
+      // Rejected text is replaced right away so the input never shows it.
+      if !handler(input.value()) {
+        input.set_value(&value.get_untracked());
+      }
+    }))
+  };
+
+  let (text_field_result, _) = create_signal(
+    cx,
+    TextFieldResult {
+      id,
+      type_: "text",
+      auto_complete: "off",
+      auto_correct: "off",
+      spell_check: "false",
+      aria_label,
+      aria_labelledby,
+      aria_invalid: (move || is_invalid.get().then_some(true)).derive_signal(cx),
+      aria_readonly: field.aria_readonly(cx, "textbox"),
+      disabled: field.is_disabled,
+      read_only: field.is_read_only,
+      name: props.name,
+      value,
+      is_invalid,
+      field,
+      on_input,
+    },
+  );
+
+  text_field_result
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::run_scope;
+
+  use super::*;
+
+  #[test]
+  fn read_only_fields_are_not_invalid() {
+    let (aria_invalid, aria_readonly) = run_scope(create_runtime(), |cx| {
+      let props = UseTextFieldProps::builder()
+        .value("#ff".to_string())
+        .is_invalid(true)
+        .is_read_only(true)
+        .build();
+      let result = use_text_field(cx, props).get_untracked();
+
+      (result.aria_invalid.get(), result.aria_readonly.get())
+    });
+
+    assert_eq!(aria_invalid, None);
+    assert_eq!(aria_readonly, Some(true));
+  }
+}
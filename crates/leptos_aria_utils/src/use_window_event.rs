@@ -0,0 +1,38 @@
+use leptos::window;
+use leptos::JsCast;
+use leptos::Scope;
+
+use crate::is_server;
+use crate::GlobalListeners;
+
+/// The options of a listener added with [`use_window_event`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindowEventOptions {
+  /// Listen in the capture phase, e.g. to receive `scroll` events from every
+  /// scrollable element.
+  pub capture: bool,
+}
+
+/// Listen to an event on the window for as long as the scope is alive. The
+/// event is cast to `E`, so make sure it matches the event type.
+///
+/// ```ignore
+/// use_window_event::<KeyboardEvent>(cx, "keydown", move |event| {
+///   log!("{}", event.key());
+/// }, WindowEventOptions::default());
+/// ```
+pub fn use_window_event<E>(
+  cx: Scope,
+  event_type: &str,
+  handler: impl Fn(E) + 'static,
+  options: WindowEventOptions,
+) where
+  E: JsCast + 'static,
+{
+  if is_server() {
+    return;
+  }
+
+  let listeners = GlobalListeners::new(cx);
+  listeners.add_event_listener(window(), event_type, handler, options.capture);
+}
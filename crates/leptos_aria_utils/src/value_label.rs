@@ -0,0 +1,137 @@
+use std::rc::Rc;
+
+use leptos::typed_builder::TypedBuilder;
+use leptos::IntoSignal;
+use leptos::MaybeSignal;
+use leptos::Scope;
+use leptos::Signal;
+
+/// Options which control how the value of a value based hook (sliders, number
+/// fields, meters) is described to assistive technology.
+#[derive(TypedBuilder, Default)]
+pub struct ValueLabelOptions {
+  /// Return the text which describes the value, e.g. `"50 percent battery"`.
+  /// Takes precedence over `unit`.
+  #[builder(default, setter(strip_option))]
+  pub get_value_label: Option<Box<dyn Fn(f64) -> String>>,
+
+  /// A unit which is appended to the value, e.g. `"percent battery"`.
+  #[builder(default, setter(strip_option, into))]
+  pub unit: Option<MaybeSignal<String>>,
+
+  /// Format the number before the unit is appended, e.g. with a locale aware
+  /// number formatter. Defaults to the `Display` of the number.
+  #[builder(default, setter(strip_option))]
+  pub format_value: Option<Box<dyn Fn(f64) -> String>>,
+}
+
+/// Describes values with the same text for `aria-valuetext` and for any
+/// announcements made by the hook.
+#[derive(Clone)]
+pub struct ValueLabel {
+  get_value_label: Option<Rc<Box<dyn Fn(f64) -> String>>>,
+  unit: Option<Signal<String>>,
+  format_value: Option<Rc<Box<dyn Fn(f64) -> String>>>,
+}
+
+pub fn use_value_label(cx: Scope, options: ValueLabelOptions) -> ValueLabel {
+  let unit = options
+    .unit
+    .map(|unit| (move || unit.get()).derive_signal(cx));
+
+  ValueLabel {
+    get_value_label: options.get_value_label.map(Rc::new),
+    unit,
+    format_value: options.format_value.map(Rc::new),
+  }
+}
+
+impl ValueLabel {
+  /// The text which describes the value.
+  pub fn label(&self, value: f64) -> String {
+    if let Some(ref get_value_label) = self.get_value_label {
+      return get_value_label(value);
+    }
+
+    let value = match self.format_value {
+      Some(ref format_value) => format_value(value),
+      None => value.to_string(),
+    };
+
+    match self.unit {
+      Some(unit) => format!("{value} {}", unit.get()),
+      None => value,
+    }
+  }
+
+  /// The `aria-valuetext` for the value. This is `None` when neither a label
+  /// callback nor a unit was provided, since the value is already announced
+  /// through `aria-valuenow`.
+  pub fn aria_valuetext(&self, cx: Scope, value: Signal<f64>) -> Signal<Option<String>> {
+    let value_label = self.clone();
+
+    (move || {
+      let is_customized = value_label.get_value_label.is_some() || value_label.unit.is_some();
+      is_customized.then(|| value_label.label(value.get()))
+    })
+    .derive_signal(cx)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use leptos::create_runtime;
+  use leptos::create_signal;
+  use leptos::run_scope;
+
+  use super::*;
+
+  #[test]
+  fn labels_the_value() {
+    let labels = run_scope(create_runtime(), |cx| {
+      let plain = use_value_label(cx, ValueLabelOptions::default());
+      let unit = use_value_label(
+        cx,
+        ValueLabelOptions::builder()
+          .unit("percent".to_string())
+          .format_value(Box::new(|value| format!("{value:.1}")))
+          .build(),
+      );
+      let custom = use_value_label(
+        cx,
+        ValueLabelOptions::builder()
+          .get_value_label(Box::new(|value| format!("{value} of 100")))
+          .unit("percent".to_string())
+          .build(),
+      );
+
+      [plain.label(50.0), unit.label(50.0), custom.label(50.0)]
+    });
+
+    assert_eq!(labels, ["50", "50.0 percent", "50 of 100"]);
+  }
+
+  #[test]
+  fn only_customized_values_have_text() {
+    let texts = run_scope(create_runtime(), |cx| {
+      let (value, set_value) = create_signal(cx, 20.0);
+      let plain =
+        use_value_label(cx, ValueLabelOptions::default()).aria_valuetext(cx, value.into());
+      let unit = use_value_label(
+        cx,
+        ValueLabelOptions::builder()
+          .unit("items".to_string())
+          .build(),
+      )
+      .aria_valuetext(cx, value.into());
+
+      let before = (plain.get(), unit.get());
+      set_value.set(30.0);
+
+      (before, (plain.get(), unit.get()))
+    });
+
+    assert_eq!(texts.0, (None, Some("20 items".to_string())));
+    assert_eq!(texts.1, (None, Some("30 items".to_string())));
+  }
+}
@@ -3,7 +3,7 @@ use leptos::web_sys::MouseEvent;
 use leptos::JsCast;
 use web_sys::PointerEvent;
 
-use crate::is_android;
+use crate::PlatformInfo;
 
 /// Keyboards, Assistive Technologies, and element.click() all produce a
 /// "virtual" click event. This is a method of inferring such clicks. Every
@@ -12,7 +12,9 @@ use crate::is_android;
 /// For IE 11 we rely on the quirk that it produces click events that are of
 /// type PointerEvent, and where only the "virtual" click lacks a pointerType
 /// field.
-pub fn is_virtual_click(event: impl AsRef<MouseEvent>) -> bool {
+///
+/// The `platform` is usually read from [`use_platform`](crate::use_platform).
+pub fn is_virtual_click(event: impl AsRef<MouseEvent>, platform: &PlatformInfo) -> bool {
   let event = event.as_ref();
 
   let mozilla_input_source = Reflect::get(event, &"mozInputSource".into())
@@ -29,7 +31,7 @@ pub fn is_virtual_click(event: impl AsRef<MouseEvent>) -> bool {
   // is defined, event is from a click listener. For events from mousedown
   // listener, detail === 0 is a sufficient check to detect TalkBack virtual
   // clicks.
-  if is_android() && event.is_instance_of::<PointerEvent>() {
+  if platform.is_android() && event.is_instance_of::<PointerEvent>() {
     return event.type_() == "click" && event.buttons() == 1;
   }
 
@@ -0,0 +1,123 @@
+use std::rc::Rc;
+
+use leptos::component;
+use leptos::create_rw_signal;
+use leptos::create_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::view;
+use leptos::web_sys::FocusEvent;
+use leptos::web_sys::Node;
+use leptos::Fragment;
+use leptos::IntoSignal;
+use leptos::IntoView;
+use leptos::JsCast;
+use leptos::ReadSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+
+use crate::VISUALLY_HIDDEN_STYLE;
+
+pub(crate) type VisuallyHiddenCallback<E> = Rc<Box<dyn Fn(E)>>;
+
+/// The inline styles which hide an element visually while keeping it
+/// available to screen readers.
+pub fn visually_hidden_style() -> &'static str {
+  VISUALLY_HIDDEN_STYLE
+}
+
+#[derive(TypedBuilder, Default)]
+pub struct UseVisuallyHiddenProps {
+  /// Whether the element is shown while it, or an element within it, has
+  /// focus. Used for skip links.
+  #[builder(default)]
+  pub is_focusable: bool,
+}
+
+#[derive(Clone)]
+pub struct VisuallyHiddenResult {
+  /// `None` while a focusable element is focused.
+  pub style: Signal<Option<&'static str>>,
+  pub on_focus_in: VisuallyHiddenCallback<FocusEvent>,
+  pub on_focus_out: VisuallyHiddenCallback<FocusEvent>,
+}
+
+/// Hide an element visually while keeping its content available to screen
+/// readers.
+pub fn use_visually_hidden(
+  cx: Scope,
+  props: UseVisuallyHiddenProps,
+) -> ReadSignal<VisuallyHiddenResult> {
+  let is_focusable = props.is_focusable;
+  let is_focus_within = create_rw_signal(cx, false);
+
+  let on_focus_in: VisuallyHiddenCallback<FocusEvent> = Rc::new(Box::new(move |_| {
+    if is_focusable {
+      is_focus_within.set(true);
+    }
+  }));
+
+  let on_focus_out: VisuallyHiddenCallback<FocusEvent> = Rc::new(Box::new(move |event| {
+    if !is_focusable || !is_focus_within.get_untracked() {
+      return;
+    }
+
+    // Stay visible while focus moves between elements within.
+    let current_target = event
+      .current_target()
+      .and_then(|target| target.dyn_into::<Node>().ok());
+    let related_target = event
+      .related_target()
+      .and_then(|target| target.dyn_into::<Node>().ok());
+    let is_within = current_target.map_or(false, |current_target| {
+      related_target.is_some() && current_target.contains(related_target.as_ref())
+    });
+
+    if !is_within {
+      is_focus_within.set(false);
+    }
+  }));
+
+  let (visually_hidden_result, _) = create_signal(
+    cx,
+    VisuallyHiddenResult {
+      style: (move || (!is_focus_within.get()).then_some(VISUALLY_HIDDEN_STYLE)).derive_signal(cx),
+      on_focus_in,
+      on_focus_out,
+    },
+  );
+
+  visually_hidden_result
+}
+
+/// Renders its children in a `<div>` which is hidden visually but available to
+/// screen readers. With `is_focusable` the content is shown while it has
+/// focus.
+#[component]
+pub fn VisuallyHidden(
+  cx: Scope,
+  children: Box<dyn Fn(Scope) -> Fragment>,
+  #[prop(optional)] is_focusable: bool,
+) -> impl IntoView {
+  let visually_hidden = use_visually_hidden(
+    cx,
+    UseVisuallyHiddenProps::builder()
+      .is_focusable(is_focusable)
+      .build(),
+  )
+  .get_untracked();
+  let style = visually_hidden.style;
+  let on_focus_in = visually_hidden.on_focus_in;
+  let on_focus_out = visually_hidden.on_focus_out;
+
+  view! {
+    cx,
+    <div
+      style=move || style.get()
+      on:focusin=move |event| on_focus_in(event)
+      on:focusout=move |event| on_focus_out(event)
+    >
+      {children(cx)}
+    </div>
+  }
+}
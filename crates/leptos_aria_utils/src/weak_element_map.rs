@@ -0,0 +1,252 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use leptos::js_sys::Object;
+use leptos::js_sys::WeakMap;
+use leptos::wasm_bindgen::JsValue;
+use leptos::web_sys::Element;
+use leptos::JsCast;
+
+use crate::Set;
+use crate::WeakRef;
+
+/// The number of tracked keys before the first sweep when an element is added.
+const MIN_SWEEP_SIZE: u32 = 32;
+
+/// A map with elements as keys which doesn't keep removed elements alive.
+///
+/// Unlike [`Map`](crate::Map), the values are stored in a `WeakMap` and the
+/// keys are tracked with a `WeakRef` for [`size`](Self::size) and iteration,
+/// so an element which is removed from the document can be garbage collected
+/// along with its value. Elements which are no longer connected to the
+/// document are skipped, and swept when the keys are read or once the number
+/// of tracked keys has doubled since the last sweep, e.g. when an element was
+/// removed during a transition and never received `transitionend`.
+///
+/// Clones share the same entries.
+#[derive(Clone)]
+pub struct WeakElementMap<V> {
+  values: WeakMap,
+  /// The `WeakRef` of every element with a value, so adding an element twice
+  /// doesn't track it twice.
+  refs: WeakMap,
+  keys: Set<WeakRef>,
+  sweep_at: Rc<Cell<u32>>,
+  _value: PhantomData<V>,
+}
+
+impl<V> Default for WeakElementMap<V> {
+  fn default() -> Self {
+    Self {
+      values: WeakMap::new(),
+      refs: WeakMap::new(),
+      keys: Set::default(),
+      sweep_at: Rc::new(Cell::new(MIN_SWEEP_SIZE)),
+      _value: PhantomData,
+    }
+  }
+}
+
+impl<V> PartialEq for WeakElementMap<V> {
+  fn eq(&self, other: &Self) -> bool {
+    self.values == other.values
+  }
+}
+
+impl<V> Eq for WeakElementMap<V> {}
+
+impl<V> WeakElementMap<V>
+where
+  V: AsRef<JsValue> + From<JsValue>,
+{
+  pub fn new() -> Self {
+    Default::default()
+  }
+
+  /// The value of the element.
+  pub fn get(&self, element: &Element) -> Option<V> {
+    let key: &Object = element.as_ref();
+    self.values.has(key).then(|| self.values.get(key).into())
+  }
+
+  /// Add or update the value of the element.
+  pub fn set(&self, element: &Element, value: &V) {
+    let key: &Object = element.as_ref();
+
+    if !self.refs.has(key) {
+      if self.keys.size() >= self.sweep_at.get() {
+        self.sweep();
+      }
+
+      let weak_ref = WeakRef::new(key);
+      self.refs.set(key, &weak_ref);
+      self.keys.add(&weak_ref);
+    }
+
+    self.values.set(key, value.as_ref());
+  }
+
+  /// Whether the element has a value.
+  pub fn has(&self, element: &Element) -> bool {
+    self.values.has(element.as_ref())
+  }
+
+  /// Remove the value of the element. Returns `false` when the element had no
+  /// value.
+  pub fn delete(&self, element: &Element) -> bool {
+    let key: &Object = element.as_ref();
+
+    if let Some(weak_ref) = self.refs.get(key).dyn_ref::<WeakRef>() {
+      self.keys.delete(weak_ref);
+    }
+
+    self.refs.delete(key);
+    self.values.delete(key)
+  }
+
+  /// Remove the elements which are no longer connected to the document or
+  /// have been garbage collected.
+  pub fn sweep(&self) {
+    for weak_ref in self.keys.to_vec() {
+      match connected_element(&weak_ref) {
+        Ok(_) => {}
+        Err(Some(element)) => {
+          self.delete(&element);
+        }
+        Err(None) => {
+          self.keys.delete(&weak_ref);
+        }
+      }
+    }
+
+    self.sweep_at.set(next_sweep_size(self.keys.size()));
+  }
+
+  /// The number of connected elements with a value.
+  pub fn size(&self) -> u32 {
+    self.sweep();
+    self.keys.size()
+  }
+
+  /// Whether no connected element has a value. Stops at the first connected
+  /// element instead of sweeping.
+  pub fn is_empty(&self) -> bool {
+    !self
+      .keys
+      .values()
+      .any(|weak_ref| connected_element(&weak_ref).is_ok())
+  }
+
+  /// The connected elements with a value in insertion order.
+  pub fn keys(&self) -> Vec<Element> {
+    self.sweep();
+    self
+      .keys
+      .values()
+      .filter_map(|weak_ref| connected_element(&weak_ref).ok())
+      .collect()
+  }
+
+  /// The connected elements and their values in insertion order.
+  pub fn entries(&self) -> Vec<(Element, V)> {
+    self
+      .keys()
+      .into_iter()
+      .filter_map(|element| {
+        let value = self.get(&element)?;
+        Some((element, value))
+      })
+      .collect()
+  }
+}
+
+/// The element of the reference when it's still connected to the document.
+/// Otherwise the disconnected element, or `None` when it has been garbage
+/// collected.
+fn connected_element(weak_ref: &WeakRef) -> Result<Element, Option<Element>> {
+  let element = weak_ref.deref().dyn_into::<Element>().ok();
+
+  match element {
+    Some(element) if element.is_connected() => Ok(element),
+    element => Err(element),
+  }
+}
+
+/// The number of tracked keys which triggers the next sweep, so adding
+/// elements only sweeps once the keys have doubled.
+fn next_sweep_size(size: u32) -> u32 {
+  size.saturating_mul(2).max(MIN_SWEEP_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sweeps_once_the_keys_have_doubled() {
+    assert_eq!(next_sweep_size(0), MIN_SWEEP_SIZE);
+    assert_eq!(next_sweep_size(10), MIN_SWEEP_SIZE);
+    assert_eq!(next_sweep_size(40), 80);
+    assert_eq!(next_sweep_size(u32::MAX), u32::MAX);
+  }
+
+  #[cfg(target_arch = "wasm32")]
+  mod browser {
+    use leptos::document;
+    use leptos::js_sys::JsString;
+    use wasm_bindgen_test::*;
+
+    use super::super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn connected_div() -> Element {
+      let element = document().create_element("div").unwrap();
+      document().body().unwrap().append_child(&element).unwrap();
+      element
+    }
+
+    #[wasm_bindgen_test]
+    fn stores_values_by_element() {
+      let map = WeakElementMap::<JsString>::new();
+      let first = connected_div();
+      let second = connected_div();
+
+      assert!(map.is_empty());
+
+      map.set(&first, &"first".into());
+      map.set(&second, &"second".into());
+      map.set(&first, &"updated".into());
+
+      assert_eq!(map.get(&first), Some("updated".into()));
+      assert_eq!(map.size(), 2);
+      assert_eq!(map.keys(), vec![first.clone(), second.clone()]);
+      assert!(!map.is_empty());
+
+      assert!(map.delete(&first));
+      assert!(!map.delete(&first));
+      assert!(!map.has(&first));
+      assert_eq!(map.entries(), vec![(second.clone(), "second".into())]);
+
+      second.remove();
+    }
+
+    #[wasm_bindgen_test]
+    fn skips_disconnected_elements() {
+      let map = WeakElementMap::<JsString>::new();
+      let clone = map.clone();
+      let element = connected_div();
+
+      map.set(&element, &"value".into());
+      assert!(!clone.is_empty());
+
+      element.remove();
+
+      assert!(clone.is_empty());
+      assert!(map.keys().is_empty());
+      assert_eq!(map.size(), 0);
+      assert!(!map.has(&element));
+    }
+  }
+}
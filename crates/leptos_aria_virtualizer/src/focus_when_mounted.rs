@@ -0,0 +1,26 @@
+use leptos::request_animation_frame;
+use leptos::web_sys::Element;
+use leptos_aria_utils::FocusableElement;
+
+/// The number of animation frames to wait for the element to be rendered.
+const MAX_FRAMES: usize = 3;
+
+/// Focus the element returned by `find`, waiting for it to be rendered when it
+/// isn't in the DOM yet. This restores focus after keyboard navigation scrolls
+/// a virtualized item into view.
+pub fn focus_when_mounted(find: impl Fn() -> Option<Element> + 'static) {
+  focus_on_frame(find, 0);
+}
+
+fn focus_on_frame(find: impl Fn() -> Option<Element> + 'static, frame: usize) {
+  if let Some(element) = find() {
+    FocusableElement::from(element).focus();
+    return;
+  }
+
+  if frame >= MAX_FRAMES {
+    return;
+  }
+
+  request_animation_frame(move || focus_on_frame(find, frame + 1));
+}
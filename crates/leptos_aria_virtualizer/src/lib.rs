@@ -0,0 +1,9 @@
+pub use focus_when_mounted::*;
+pub use scroll_anchor::*;
+pub use use_virtualizer::*;
+pub use virtual_layout::*;
+
+mod focus_when_mounted;
+mod scroll_anchor;
+mod use_virtualizer;
+mod virtual_layout;
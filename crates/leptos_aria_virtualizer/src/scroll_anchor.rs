@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use leptos::web_sys::Element;
+use leptos::web_sys::ScrollIntoViewOptions;
+use leptos::web_sys::ScrollLogicalPosition;
+use leptos::Scope;
+use leptos_aria_selection::Key;
+use leptos_aria_utils::run_after_transition;
+
+use crate::Virtualizer;
+
+/// What to keep in view when the items of a collection change drastically,
+/// e.g. after filtering.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ScrollAnchor {
+  /// Keep the focused item in view, or the first selected item when the
+  /// focused item was removed.
+  #[default]
+  FocusedItem,
+  /// Scroll back to the first item.
+  Top,
+  /// Leave the scroll offset untouched.
+  PreserveOffset,
+}
+
+impl ScrollAnchor {
+  /// The key of the item which should be scrolled into view after the items
+  /// changed to `keys`. `None` when nothing should be scrolled.
+  pub fn target_key(
+    &self,
+    keys: &[Key],
+    focused_key: Option<&Key>,
+    selected_keys: &HashSet<Key>,
+  ) -> Option<Key> {
+    match self {
+      ScrollAnchor::FocusedItem => {
+        focused_key
+          .filter(|focused_key| keys.contains(focused_key))
+          .or_else(|| keys.iter().find(|key| selected_keys.contains(key)))
+          .cloned()
+      }
+      ScrollAnchor::Top => keys.first().cloned(),
+      ScrollAnchor::PreserveOffset => None,
+    }
+  }
+}
+
+/// Scroll the item with the provided key into view once the DOM has updated
+/// and any running transitions have finished.
+///
+/// Virtualized collections scroll through the `virtualizer` since the item may
+/// not be rendered yet. Otherwise the element returned by `find_element` is
+/// scrolled into view.
+pub fn restore_scroll_anchor(
+  cx: Scope,
+  key: Key,
+  ordered_keys: Vec<Key>,
+  virtualizer: Option<Virtualizer>,
+  find_element: impl Fn(&Key) -> Option<Element> + 'static,
+) {
+  run_after_transition(cx, move || {
+    match virtualizer {
+      Some(ref virtualizer) => virtualizer.scroll_to_key(&key, &ordered_keys),
+      None => {
+        if let Some(element) = find_element(&key) {
+          let mut options = ScrollIntoViewOptions::new();
+          options.block(ScrollLogicalPosition::Nearest);
+          element.scroll_into_view_with_scroll_into_view_options(&options);
+        }
+      }
+    }
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn keys(values: &[&str]) -> Vec<Key> {
+    values.iter().map(|value| Key::from(*value)).collect()
+  }
+
+  #[test]
+  fn focused_item_falls_back_to_the_first_selected_item() {
+    let keys = keys(&["a", "b", "c"]);
+    let selected = HashSet::from([Key::from("c"), Key::from("b")]);
+
+    assert_eq!(
+      ScrollAnchor::FocusedItem.target_key(&keys, Some(&Key::from("c")), &selected),
+      Some(Key::from("c"))
+    );
+    assert_eq!(
+      ScrollAnchor::FocusedItem.target_key(&keys, Some(&Key::from("z")), &selected),
+      Some(Key::from("b"))
+    );
+    assert_eq!(
+      ScrollAnchor::FocusedItem.target_key(&keys, None, &HashSet::new()),
+      None
+    );
+  }
+
+  #[test]
+  fn top_and_preserve_offset() {
+    let keys = keys(&["a", "b"]);
+
+    assert_eq!(
+      ScrollAnchor::Top.target_key(&keys, None, &HashSet::new()),
+      Some(Key::from("a"))
+    );
+    assert_eq!(
+      ScrollAnchor::PreserveOffset.target_key(&keys, Some(&Key::from("a")), &HashSet::new()),
+      None
+    );
+  }
+}
@@ -0,0 +1,146 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use leptos::create_rw_signal;
+use leptos::typed_builder::TypedBuilder;
+use leptos::web_sys::Element;
+use leptos::web_sys::Event;
+use leptos::IntoSignal;
+use leptos::JsCast;
+use leptos::MaybeSignal;
+use leptos::RwSignal;
+use leptos::Scope;
+use leptos::Signal;
+use leptos::UntrackedGettableSignal;
+use leptos_aria_selection::Key;
+
+use crate::VirtualLayout;
+
+#[derive(TypedBuilder)]
+pub struct UseVirtualizerProps {
+  /// The number of items in the full collection.
+  #[builder(setter(into))]
+  pub item_count: MaybeSignal<usize>,
+
+  /// The size of each item along the scroll axis in pixels.
+  #[builder(setter(into))]
+  pub item_size: MaybeSignal<f64>,
+
+  /// The initial size of the viewport. This is updated from the scroll
+  /// container on every scroll event.
+  #[builder(default, setter(strip_option))]
+  pub viewport_size: Option<f64>,
+
+  /// The number of extra items rendered before and after the visible items.
+  /// Defaults to `3`.
+  #[builder(default, setter(strip_option))]
+  pub overscan: Option<usize>,
+}
+
+/// A windowing layer for collections with fixed size items.
+///
+/// Only the items within [`Virtualizer::visible_range`] should be rendered.
+/// Add [`Virtualizer::on_scroll`] as the scroll handler of the scroll container
+/// and bind [`Virtualizer::scroll_offset`] to its `scrollTop` property so that
+/// [`Virtualizer::scroll_to_index`] can scroll items which haven't been
+/// rendered yet into view.
+///
+/// The collection hooks accept a virtualizer in their state props which makes
+/// them emit `aria-rowcount` / `aria-setsize` for the full collection and
+/// restore focus when keyboard navigation targets an item which isn't
+/// rendered.
+#[derive(Clone)]
+pub struct Virtualizer {
+  pub item_count: Signal<usize>,
+  pub item_size: Signal<f64>,
+  viewport_size: RwSignal<f64>,
+  scroll_offset: RwSignal<f64>,
+  overscan: usize,
+  /// The scroll handler for the scroll container.
+  pub on_scroll: Rc<Box<dyn Fn(Event)>>,
+}
+
+pub fn use_virtualizer(cx: Scope, props: UseVirtualizerProps) -> Virtualizer {
+  let original_item_count = props.item_count;
+  let item_count = (move || original_item_count.get()).derive_signal(cx);
+  let original_item_size = props.item_size;
+  let item_size = (move || original_item_size.get()).derive_signal(cx);
+  let viewport_size = create_rw_signal(cx, props.viewport_size.unwrap_or(0.0));
+  let scroll_offset = create_rw_signal(cx, 0.0);
+
+  let on_scroll = move |event: Event| {
+    let Some(container) = event
+      .current_target()
+      .and_then(|target| target.dyn_into::<Element>().ok())
+    else {
+      return;
+    };
+
+    viewport_size.set(f64::from(container.client_height()));
+    scroll_offset.set(f64::from(container.scroll_top()));
+  };
+
+  Virtualizer {
+    item_count,
+    item_size,
+    viewport_size,
+    scroll_offset,
+    overscan: props.overscan.unwrap_or(3),
+    on_scroll: Rc::new(Box::new(on_scroll)),
+  }
+}
+
+impl Virtualizer {
+  /// The layout for the current scroll position.
+  pub fn layout(&self) -> VirtualLayout {
+    VirtualLayout {
+      item_count: self.item_count.get(),
+      item_size: self.item_size.get(),
+      viewport_size: self.viewport_size.get(),
+      scroll_offset: self.scroll_offset.get(),
+      overscan: self.overscan,
+    }
+  }
+
+  fn layout_untracked(&self) -> VirtualLayout {
+    VirtualLayout {
+      item_count: self.item_count.get_untracked(),
+      item_size: self.item_size.get_untracked(),
+      viewport_size: self.viewport_size.get_untracked(),
+      scroll_offset: self.scroll_offset.get_untracked(),
+      overscan: self.overscan,
+    }
+  }
+
+  /// The indexes of the items which should be rendered.
+  pub fn visible_range(&self) -> Range<usize> {
+    self.layout().visible_range()
+  }
+
+  /// The scroll position which should be bound to the `scrollTop` property of
+  /// the scroll container.
+  pub fn scroll_offset(&self) -> Signal<f64> {
+    self.scroll_offset.into()
+  }
+
+  /// Whether the item at the provided index is currently rendered.
+  pub fn is_rendered(&self, index: usize) -> bool {
+    self.layout_untracked().visible_range().contains(&index)
+  }
+
+  /// Scroll the item at the provided index into view. This also works for
+  /// items which haven't been rendered yet.
+  pub fn scroll_to_index(&self, index: usize) {
+    if let Some(offset) = self.layout_untracked().scroll_offset_to_reveal(index) {
+      self.scroll_offset.set(offset);
+    }
+  }
+
+  /// Scroll the item with the provided key into view. The `ordered_keys` are
+  /// the keys of the full collection in display order.
+  pub fn scroll_to_key(&self, key: &Key, ordered_keys: &[Key]) {
+    if let Some(index) = ordered_keys.iter().position(|item| item == key) {
+      self.scroll_to_index(index);
+    }
+  }
+}
@@ -0,0 +1,115 @@
+use std::ops::Range;
+
+/// Calculates which items of a collection with fixed size items should be
+/// rendered for the current scroll position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VirtualLayout {
+  /// The number of items in the full collection.
+  pub item_count: usize,
+
+  /// The size of each item along the scroll axis in pixels.
+  pub item_size: f64,
+
+  /// The size of the scroll container's viewport in pixels.
+  pub viewport_size: f64,
+
+  /// The current scroll position of the scroll container in pixels.
+  pub scroll_offset: f64,
+
+  /// The number of extra items rendered before and after the visible items.
+  pub overscan: usize,
+}
+
+impl VirtualLayout {
+  /// The range of item indexes which should be rendered.
+  pub fn visible_range(&self) -> Range<usize> {
+    if self.item_count == 0 || self.item_size <= 0.0 {
+      return 0..0;
+    }
+
+    let first = (self.scroll_offset.max(0.0) / self.item_size).floor() as usize;
+    let visible = (self.viewport_size.max(0.0) / self.item_size).ceil() as usize + 1;
+    let start = first.saturating_sub(self.overscan).min(self.item_count);
+    let end = (first + visible + self.overscan).min(self.item_count);
+
+    start..end
+  }
+
+  /// The size of the full collection. Use this for the size of the scrollable
+  /// content so the scrollbar represents every item.
+  pub fn total_size(&self) -> f64 {
+    self.item_count as f64 * self.item_size
+  }
+
+  /// The offset of the item at the provided index from the start of the
+  /// content.
+  pub fn offset_of(&self, index: usize) -> f64 {
+    index as f64 * self.item_size
+  }
+
+  /// The size of the space before the rendered items.
+  pub fn before_size(&self) -> f64 {
+    self.offset_of(self.visible_range().start)
+  }
+
+  /// The size of the space after the rendered items.
+  pub fn after_size(&self) -> f64 {
+    self.total_size() - self.offset_of(self.visible_range().end)
+  }
+
+  /// The scroll offset which brings the item at the provided index into view
+  /// with the smallest possible scroll, or `None` when it is already fully
+  /// visible.
+  pub fn scroll_offset_to_reveal(&self, index: usize) -> Option<f64> {
+    let start = self.offset_of(index);
+    let end = start + self.item_size;
+
+    if start < self.scroll_offset {
+      Some(start)
+    } else if end > self.scroll_offset + self.viewport_size {
+      Some((end - self.viewport_size).max(0.0))
+    } else {
+      None
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn layout(scroll_offset: f64) -> VirtualLayout {
+    VirtualLayout {
+      item_count: 1000,
+      item_size: 20.0,
+      viewport_size: 100.0,
+      scroll_offset,
+      overscan: 2,
+    }
+  }
+
+  #[test]
+  fn visible_range_includes_overscan() {
+    assert_eq!(layout(0.0).visible_range(), 0..8);
+    assert_eq!(layout(200.0).visible_range(), 8..18);
+    assert_eq!(layout(19_990.0).visible_range(), 997..1000);
+  }
+
+  #[test]
+  fn spacers_fill_the_content() {
+    let layout = layout(200.0);
+
+    assert_eq!(layout.before_size(), 160.0);
+    assert_eq!(layout.after_size(), 20_000.0 - 360.0);
+    assert_eq!(layout.total_size(), 20_000.0);
+  }
+
+  #[test]
+  fn reveals_with_the_smallest_scroll() {
+    let layout = layout(200.0);
+
+    assert_eq!(layout.scroll_offset_to_reveal(11), None);
+    assert_eq!(layout.scroll_offset_to_reveal(5), Some(100.0));
+    assert_eq!(layout.scroll_offset_to_reveal(20), Some(320.0));
+  }
+}